@@ -103,6 +103,15 @@ pub fn build_args<'a, 'b>(out: App<'a, 'b>) -> App<'a, 'b> {
             .help("Package prefix to use when generating classes"),
     );
 
+    let out = out.arg(
+        Arg::with_name("cfg")
+            .long("cfg")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .help("Features to enable for `#[cfg(feature = \"...\")]` conditions"),
+    );
+
     let out = out.arg(
         Arg::with_name("file")
             .long("file")
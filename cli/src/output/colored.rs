@@ -75,6 +75,10 @@ where
         self.print_positional(source, span, m, Colour::Yellow)
     }
 
+    fn print_warning(&self, source: &Source, span: &Span, m: &str) -> Result<()> {
+        self.print_positional(source, span, m, Colour::Purple)
+    }
+
     fn print_error(&self, source: &Source, span: &Span, m: &str) -> Result<()> {
         self.print_positional(source, span, m, Colour::Red)
     }
@@ -132,6 +132,10 @@ where
         self.print_diagnostics(source, p, m)
     }
 
+    fn print_warning(&self, source: &Source, p: &Span, m: &str) -> Result<()> {
+        self.print_diagnostics(source, p, m)
+    }
+
     fn print_error(&self, source: &Source, p: &Span, m: &str) -> Result<()> {
         self.print_diagnostics(source, p, m)
     }
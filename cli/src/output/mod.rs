@@ -68,6 +68,19 @@ pub trait Output {
             } => {
                 self.print_info(source, span, message.as_str())?;
             }
+            Diagnostic::Warning {
+                ref span,
+                ref message,
+            } => {
+                self.print_warning(source, span, message.as_str())?;
+            }
+            Diagnostic::Suggestion {
+                ref span,
+                ref message,
+                ..
+            } => {
+                self.print_info(source, span, message.as_str())?;
+            }
             Diagnostic::Error {
                 ref span,
                 ref message,
@@ -81,6 +94,8 @@ pub trait Output {
             } => {
                 self.print_symbol(source, *kind, span, name)?;
             }
+            // inactive regions are only of interest to editors.
+            Diagnostic::Inactive { .. } => {}
         }
 
         Ok(())
@@ -129,6 +144,8 @@ pub trait Output {
 
     fn print_info(&self, source: &Source, p: &Span, m: &str) -> Result<()>;
 
+    fn print_warning(&self, source: &Source, p: &Span, m: &str) -> Result<()>;
+
     fn print_error(&self, source: &Source, p: &Span, m: &str) -> Result<()>;
 
     fn print_symbol(
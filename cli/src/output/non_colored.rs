@@ -85,6 +85,10 @@ where
         self.print_positional(source, span, m)
     }
 
+    fn print_warning(&self, source: &Source, span: &Span, m: &str) -> Result<()> {
+        self.print_positional(source, span, m)
+    }
+
     fn print_error(&self, source: &Source, span: &Span, m: &str) -> Result<()> {
         self.print_positional(source, span, m)
     }
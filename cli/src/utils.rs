@@ -85,6 +85,13 @@ pub fn load_manifest<'a>(m: &ArgMatches<'a>) -> Result<Manifest> {
             manifest.package_prefix = Some(package_prefix);
         }
 
+        manifest.features.extend(
+            m.values_of("cfg")
+                .into_iter()
+                .flat_map(|it| it)
+                .map(ToOwned::to_owned),
+        );
+
         if let Some(id_converter) = m.value_of("id-converter") {
             manifest.id_converter = Some(id_converter.to_string());
         }
@@ -140,6 +147,8 @@ where
 
     let mut session = lang
         .into_session(package_prefix, reporter, resolver)?
+        .with_cfg(manifest.cfg())
+        .with_lints(manifest.lints())
         .with_path_hook(path_hook);
 
     let mut errors: Vec<Error> = Vec::new();
@@ -357,6 +366,13 @@ pub fn semck_check(
             AddVariant(c, field) => {
                 diag.err(source_to, field, format!("{}: variant added", c.describe()));
             }
+            AddSubType(c, sub_type) => {
+                diag.err(
+                    source_to,
+                    sub_type,
+                    format!("{}: sub-type added", c.describe()),
+                );
+            }
             FieldTypeChange(c, from_type, from, to_type, to) => {
                 diag.err(
                     source_to,
@@ -389,6 +405,34 @@ pub fn semck_check(
                 );
                 diag.info(current, from, "from here");
             }
+            FieldValidateTightened(c, from, to) => {
+                diag.err(
+                    source_to,
+                    to,
+                    format!("{}: validation rules tightened", c.describe()),
+                );
+                diag.info(current, from, "from here");
+            }
+            FieldEncodingChange(c, from_encoding, from, to_encoding, to) => {
+                diag.err(
+                    source_to,
+                    to,
+                    format!("{}: encoding changed to `{}`", c.describe(), to_encoding),
+                );
+                diag.info(current, from, format!("from `{}`", from_encoding));
+            }
+            FieldBytesEncodingChange(c, from_encoding, from, to_encoding, to) => {
+                diag.err(
+                    source_to,
+                    to,
+                    format!(
+                        "{}: bytes encoding changed to `{}`",
+                        c.describe(),
+                        to_encoding
+                    ),
+                );
+                diag.info(current, from, format!("from `{}`", from_encoding));
+            }
             AddRequiredField(c, field) => {
                 diag.err(
                     source_to,
@@ -442,6 +486,26 @@ pub fn semck_check(
                     format!("from `{}`", FmtChannel(from_channel.as_ref())),
                 );
             }
+            RemoveEndpointError(c, status, span) => {
+                diag.err(
+                    current,
+                    span,
+                    format!("{}: error for status `{}` removed", c.describe(), status),
+                );
+            }
+            EndpointErrorChange(c, status, from_type, from, to_type, to) => {
+                diag.err(
+                    source_to,
+                    to,
+                    format!(
+                        "{}: error for status `{}` changed to `{}`",
+                        c.describe(),
+                        status,
+                        to_type
+                    ),
+                );
+                diag.info(current, from, format!("from `{}`", from_type));
+            }
         }
 
         return Ok(());
@@ -0,0 +1,115 @@
+using Newtonsoft.Json;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("id")]
+        public ulong? id {
+            get;
+        }
+
+        [JsonProperty("user")]
+        public String user {
+            get;
+        }
+
+        [JsonProperty("tags")]
+        public List<String> tags {
+            get;
+        }
+
+        [JsonProperty("owners")]
+        public Dictionary<String, ulong> owners {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("id")] ulong? id,
+            [JsonProperty("user")] String user,
+            [JsonProperty("tags")] List<String> tags,
+            [JsonProperty("owners")] Dictionary<String, ulong> owners
+        ) {
+            this.id = id;
+            this.user = user;
+            this.tags = tags;
+            this.owners = owners;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.id.Equals(o.id)) {
+                return false;
+            }
+
+            if (this.user == null) {
+                if (o.user != null) {
+                    return false;
+                }
+            } else {
+                if (!this.user.Equals(o.user)) {
+                    return false;
+                }
+            }
+
+            if (this.tags == null) {
+                if (o.tags != null) {
+                    return false;
+                }
+            } else {
+                if (!this.tags.Equals(o.tags)) {
+                    return false;
+                }
+            }
+
+            if (this.owners == null) {
+                if (o.owners != null) {
+                    return false;
+                }
+            } else {
+                if (!this.owners.Equals(o.owners)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.id.GetHashCode();
+            result = result * 31 + this.user.GetHashCode();
+            result = result * 31 + this.tags.GetHashCode();
+            result = result * 31 + this.owners.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("id=");
+            b.Append(this.id);
+            b.Append(", ");
+            b.Append("user=");
+            b.Append(this.user);
+            b.Append(", ");
+            b.Append("tags=");
+            b.Append(this.tags);
+            b.Append(", ");
+            b.Append("owners=");
+            b.Append(this.owners);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
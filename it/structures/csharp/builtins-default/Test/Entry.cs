@@ -0,0 +1,163 @@
+using Newtonsoft.Json;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("date")]
+        public String date {
+            get;
+        }
+
+        [JsonProperty("time")]
+        public String time {
+            get;
+        }
+
+        [JsonProperty("duration")]
+        public String duration {
+            get;
+        }
+
+        [JsonProperty("uuid")]
+        public String uuid {
+            get;
+        }
+
+        [JsonProperty("decimal")]
+        public String _decimal {
+            get;
+        }
+
+        [JsonProperty("dates")]
+        public List<String> dates {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("date")] String date,
+            [JsonProperty("time")] String time,
+            [JsonProperty("duration")] String duration,
+            [JsonProperty("uuid")] String uuid,
+            [JsonProperty("decimal")] String _decimal,
+            [JsonProperty("dates")] List<String> dates
+        ) {
+            this.date = date;
+            this.time = time;
+            this.duration = duration;
+            this.uuid = uuid;
+            this._decimal = _decimal;
+            this.dates = dates;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.date == null) {
+                if (o.date != null) {
+                    return false;
+                }
+            } else {
+                if (!this.date.Equals(o.date)) {
+                    return false;
+                }
+            }
+
+            if (this.time == null) {
+                if (o.time != null) {
+                    return false;
+                }
+            } else {
+                if (!this.time.Equals(o.time)) {
+                    return false;
+                }
+            }
+
+            if (this.duration == null) {
+                if (o.duration != null) {
+                    return false;
+                }
+            } else {
+                if (!this.duration.Equals(o.duration)) {
+                    return false;
+                }
+            }
+
+            if (this.uuid == null) {
+                if (o.uuid != null) {
+                    return false;
+                }
+            } else {
+                if (!this.uuid.Equals(o.uuid)) {
+                    return false;
+                }
+            }
+
+            if (this._decimal == null) {
+                if (o._decimal != null) {
+                    return false;
+                }
+            } else {
+                if (!this._decimal.Equals(o._decimal)) {
+                    return false;
+                }
+            }
+
+            if (this.dates == null) {
+                if (o.dates != null) {
+                    return false;
+                }
+            } else {
+                if (!this.dates.Equals(o.dates)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.date.GetHashCode();
+            result = result * 31 + this.time.GetHashCode();
+            result = result * 31 + this.duration.GetHashCode();
+            result = result * 31 + this.uuid.GetHashCode();
+            result = result * 31 + this._decimal.GetHashCode();
+            result = result * 31 + this.dates.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("date=");
+            b.Append(this.date);
+            b.Append(", ");
+            b.Append("time=");
+            b.Append(this.time);
+            b.Append(", ");
+            b.Append("duration=");
+            b.Append(this.duration);
+            b.Append(", ");
+            b.Append("uuid=");
+            b.Append(this.uuid);
+            b.Append(", ");
+            b.Append("decimal=");
+            b.Append(this._decimal);
+            b.Append(", ");
+            b.Append("dates=");
+            b.Append(this.dates);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
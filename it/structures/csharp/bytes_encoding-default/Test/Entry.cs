@@ -0,0 +1,212 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("standard")]
+        public byte[] standard {
+            get;
+        }
+
+        [JsonProperty("url")]
+        [JsonConverter(typeof(Json_Net_BytesConverter), "base64url")]
+        public byte[] url {
+            get;
+        }
+
+        [JsonProperty("token")]
+        [JsonConverter(typeof(Json_Net_BytesConverter), "base64url_nopad")]
+        public byte[] token {
+            get;
+        }
+
+        [JsonProperty("digest")]
+        [JsonConverter(typeof(Json_Net_BytesConverter), "hex")]
+        public byte[] digest {
+            get;
+        }
+
+        [JsonProperty("pair")]
+        public Pair pair {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("standard")] byte[] standard,
+            [JsonProperty("url")] byte[] url,
+            [JsonProperty("token")] byte[] token,
+            [JsonProperty("digest")] byte[] digest,
+            [JsonProperty("pair")] Pair pair
+        ) {
+            this.standard = standard;
+            this.url = url;
+            this.token = token;
+            this.digest = digest;
+            this.pair = pair;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.standard == null) {
+                if (o.standard != null) {
+                    return false;
+                }
+            } else {
+                if (!this.standard.Equals(o.standard)) {
+                    return false;
+                }
+            }
+
+            if (this.url == null) {
+                if (o.url != null) {
+                    return false;
+                }
+            } else {
+                if (!this.url.Equals(o.url)) {
+                    return false;
+                }
+            }
+
+            if (this.token == null) {
+                if (o.token != null) {
+                    return false;
+                }
+            } else {
+                if (!this.token.Equals(o.token)) {
+                    return false;
+                }
+            }
+
+            if (this.digest == null) {
+                if (o.digest != null) {
+                    return false;
+                }
+            } else {
+                if (!this.digest.Equals(o.digest)) {
+                    return false;
+                }
+            }
+
+            if (this.pair == null) {
+                if (o.pair != null) {
+                    return false;
+                }
+            } else {
+                if (!this.pair.Equals(o.pair)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.standard.GetHashCode();
+            result = result * 31 + this.url.GetHashCode();
+            result = result * 31 + this.token.GetHashCode();
+            result = result * 31 + this.digest.GetHashCode();
+            result = result * 31 + this.pair.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("standard=");
+            b.Append(this.standard);
+            b.Append(", ");
+            b.Append("url=");
+            b.Append(this.url);
+            b.Append(", ");
+            b.Append("token=");
+            b.Append(this.token);
+            b.Append(", ");
+            b.Append("digest=");
+            b.Append(this.digest);
+            b.Append(", ");
+            b.Append("pair=");
+            b.Append(this.pair);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_BytesConverter : JsonConverter {
+            private readonly string encoding;
+
+            public Json_Net_BytesConverter(string encoding) {
+                this.encoding = encoding;
+            }
+
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(byte[]);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                writer.WriteValue(Encode((byte[])obj, this.encoding));
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                if (reader.TokenType == JsonToken.Null) {
+                    return null;
+                }
+
+                return Decode((string)reader.Value, this.encoding);
+            }
+
+            public static string Encode(byte[] value, string encoding) {
+                if (encoding == "hex") {
+                    StringBuilder b = new StringBuilder(value.Length * 2);
+
+                    foreach (byte v in value) {
+                        b.Append(v.ToString("x2"));
+                    }
+
+                    return b.ToString();
+                }
+
+                string base64 = System.Convert.ToBase64String(value).Replace('+', '-').Replace('/', '_');
+                return encoding == "base64url_nopad" ? base64.TrimEnd('=') : base64;
+            }
+
+            public static byte[] Decode(string value, string encoding) {
+                if (encoding == "hex") {
+                    if (value.Length % 2 != 0) {
+                        throw new FormatException("expected an even number of characters");
+                    }
+
+                    byte[] bytes = new byte[value.Length / 2];
+
+                    for (int i = 0; i < bytes.Length; i++) {
+                        bytes[i] = System.Convert.ToByte(value.Substring(i * 2, 2), 16);
+                    }
+
+                    return bytes;
+                }
+
+                string base64 = value.Replace('-', '+').Replace('_', '/');
+
+                switch (base64.Length % 4) {
+                case 2:
+                    base64 += "==";
+                    break;
+                case 3:
+                    base64 += "=";
+                    break;
+                }
+
+                return System.Convert.FromBase64String(base64);
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Pair.Json_Net_Converter))]
+    public class Pair {
+        [JsonProperty("key")]
+        [JsonConverter(typeof(Json_Net_BytesConverter), "hex")]
+        public byte[] key {
+            get;
+        }
+
+        [JsonProperty("value")]
+        public byte[] value {
+            get;
+        }
+
+        [JsonConstructor]
+        public Pair (
+            [JsonProperty("key")] byte[] key,
+            [JsonProperty("value")] byte[] value
+        ) {
+            this.key = key;
+            this.value = value;
+        }
+
+        public override bool Equals(Object other) {
+            Pair o = other as Pair;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.key == null) {
+                if (o.key != null) {
+                    return false;
+                }
+            } else {
+                if (!this.key.Equals(o.key)) {
+                    return false;
+                }
+            }
+
+            if (this.value == null) {
+                if (o.value != null) {
+                    return false;
+                }
+            } else {
+                if (!this.value.Equals(o.value)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.key.GetHashCode();
+            result = result * 31 + this.value.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Pair(");
+            b.Append("key=");
+            b.Append(this.key);
+            b.Append(", ");
+            b.Append("value=");
+            b.Append(this.value);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_Converter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(Pair);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                Pair o = (Pair)obj;
+                JArray array = new JArray();
+
+                array.Add(JToken.FromObject(Json_Net_BytesConverter.Encode(o.key, "hex"), serializer));
+
+                array.Add(JToken.FromObject(o.value, serializer));
+
+                array.WriteTo(writer);
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JArray array = JArray.Load(reader);
+                IEnumerator<JToken> enumerator = array.GetEnumerator();
+
+                if (!enumerator.MoveNext()) {
+                    throw new InvalidOperationException("expected more items in array");
+                }
+
+                byte[] key = Json_Net_BytesConverter.Decode(enumerator.Current.ToObject<string>(serializer), "hex");
+
+                if (!enumerator.MoveNext()) {
+                    throw new InvalidOperationException("expected more items in array");
+                }
+
+                byte[] value = enumerator.Current.ToObject<byte[]>(serializer);
+
+                return new Pair(key, value);
+            }
+        }
+
+        public class Json_Net_BytesConverter : JsonConverter {
+            private readonly string encoding;
+
+            public Json_Net_BytesConverter(string encoding) {
+                this.encoding = encoding;
+            }
+
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(byte[]);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                writer.WriteValue(Encode((byte[])obj, this.encoding));
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                if (reader.TokenType == JsonToken.Null) {
+                    return null;
+                }
+
+                return Decode((string)reader.Value, this.encoding);
+            }
+
+            public static string Encode(byte[] value, string encoding) {
+                if (encoding == "hex") {
+                    StringBuilder b = new StringBuilder(value.Length * 2);
+
+                    foreach (byte v in value) {
+                        b.Append(v.ToString("x2"));
+                    }
+
+                    return b.ToString();
+                }
+
+                string base64 = System.Convert.ToBase64String(value).Replace('+', '-').Replace('/', '_');
+                return encoding == "base64url_nopad" ? base64.TrimEnd('=') : base64;
+            }
+
+            public static byte[] Decode(string value, string encoding) {
+                if (encoding == "hex") {
+                    if (value.Length % 2 != 0) {
+                        throw new FormatException("expected an even number of characters");
+                    }
+
+                    byte[] bytes = new byte[value.Length / 2];
+
+                    for (int i = 0; i < bytes.Length; i++) {
+                        bytes[i] = System.Convert.ToByte(value.Substring(i * 2, 2), 16);
+                    }
+
+                    return bytes;
+                }
+
+                string base64 = value.Replace('-', '+').Replace('_', '/');
+
+                switch (base64.Length % 4) {
+                case 2:
+                    base64 += "==";
+                    break;
+                case 3:
+                    base64 += "=";
+                    break;
+                }
+
+                return System.Convert.FromBase64String(base64);
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using System.Runtime.Serialization;
+
+namespace Test {
+    [JsonConverter(typeof(StringEnumConverter))]
+    public enum Color {
+        [EnumMember(Value = "red")]
+        RED
+    }
+}
@@ -0,0 +1,88 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("id")]
+        public String id {
+            get;
+        }
+
+        /// Not available when building for Python or JavaScript.
+        [JsonProperty("typed")]
+        public uint? typed {
+            get;
+        }
+
+        [JsonProperty("color")]
+        public Color? color {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("id")] String id,
+            [JsonProperty("typed")] uint? typed,
+            [JsonProperty("color")] Color? color
+        ) {
+            this.id = id;
+            this.typed = typed;
+            this.color = color;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.id == null) {
+                if (o.id != null) {
+                    return false;
+                }
+            } else {
+                if (!this.id.Equals(o.id)) {
+                    return false;
+                }
+            }
+
+            if (!this.typed.Equals(o.typed)) {
+                return false;
+            }
+
+            if (!this.color.Equals(o.color)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.id.GetHashCode();
+            result = result * 31 + this.typed.GetHashCode();
+            result = result * 31 + this.color.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("id=");
+            b.Append(this.id);
+            b.Append(", ");
+            b.Append("typed=");
+            b.Append(this.typed);
+            b.Append(", ");
+            b.Append("color=");
+            b.Append(this.color);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,121 @@
+using Newtonsoft.Json;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("by_color")]
+        public Dictionary<Color, uint> byColor {
+            get;
+        }
+
+        [JsonProperty("by_id")]
+        public Dictionary<ulong, String> byId {
+            get;
+        }
+
+        [JsonProperty("tags")]
+        public List<String> tags {
+            get;
+        }
+
+        [JsonProperty("levels")]
+        public List<Level> levels {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("by_color")] Dictionary<Color, uint> byColor,
+            [JsonProperty("by_id")] Dictionary<ulong, String> byId,
+            [JsonProperty("tags")] List<String> tags,
+            [JsonProperty("levels")] List<Level> levels
+        ) {
+            this.byColor = byColor;
+            this.byId = byId;
+            this.tags = tags;
+            this.levels = levels;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.byColor == null) {
+                if (o.byColor != null) {
+                    return false;
+                }
+            } else {
+                if (!this.byColor.Equals(o.byColor)) {
+                    return false;
+                }
+            }
+
+            if (this.byId == null) {
+                if (o.byId != null) {
+                    return false;
+                }
+            } else {
+                if (!this.byId.Equals(o.byId)) {
+                    return false;
+                }
+            }
+
+            if (this.tags == null) {
+                if (o.tags != null) {
+                    return false;
+                }
+            } else {
+                if (!this.tags.Equals(o.tags)) {
+                    return false;
+                }
+            }
+
+            if (this.levels == null) {
+                if (o.levels != null) {
+                    return false;
+                }
+            } else {
+                if (!this.levels.Equals(o.levels)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.byColor.GetHashCode();
+            result = result * 31 + this.byId.GetHashCode();
+            result = result * 31 + this.tags.GetHashCode();
+            result = result * 31 + this.levels.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("by_color=");
+            b.Append(this.byColor);
+            b.Append(", ");
+            b.Append("by_id=");
+            b.Append(this.byId);
+            b.Append(", ");
+            b.Append("tags=");
+            b.Append(this.tags);
+            b.Append(", ");
+            b.Append("levels=");
+            b.Append(this.levels);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,6 @@
+namespace Test {
+    public enum Level {
+        LOW = 1,
+        HIGH = 2
+    }
+}
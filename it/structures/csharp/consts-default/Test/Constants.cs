@@ -0,0 +1,21 @@
+using System;
+
+namespace Test {
+    public static class Constants {
+        /// The default number of entries to return.
+        public const uint DefaultLimit = 100U;
+
+        public const long MaxOffset = -1L;
+
+        public const String Greeting = "hello";
+
+        public const double Ratio = 0.5D;
+
+        public const float Scale = 2F;
+
+        public const bool Enabled = true;
+
+        [Obsolete("deprecated: use DefaultLimit")]
+        public const uint OldLimit = 10U;
+    }
+}
@@ -0,0 +1,54 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("limit")]
+        public uint? limit {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("limit")] uint? limit
+        ) {
+            this.limit = limit;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.limit.Equals(o.limit)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.limit.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("limit=");
+            b.Append(this.limit);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        /// Maximum number of entries in a single page.
+        public const uint PageSize = 20U;
+    }
+}
@@ -0,0 +1,174 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("name")]
+        public String name {
+            get;
+        }
+
+        [JsonProperty("count")]
+        public uint? count {
+            get;
+        }
+
+        [JsonProperty("big")]
+        public long? big {
+            get;
+        }
+
+        [JsonProperty("ratio")]
+        public double? ratio {
+            get;
+        }
+
+        [JsonProperty("scale")]
+        public float? scale {
+            get;
+        }
+
+        [JsonProperty("enabled")]
+        public bool? enabled {
+            get;
+        }
+
+        [JsonProperty("label")]
+        public String label {
+            get;
+        }
+
+        [JsonProperty("shape")]
+        public Shape shape {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("name")] String name,
+            [JsonProperty("count")] uint? count,
+            [JsonProperty("big")] long? big,
+            [JsonProperty("ratio")] double? ratio,
+            [JsonProperty("scale")] float? scale,
+            [JsonProperty("enabled")] bool? enabled,
+            [JsonProperty("label")] String label,
+            [JsonProperty("shape")] Shape shape
+        ) {
+            this.name = name;
+            this.count = count;
+            this.big = big;
+            this.ratio = ratio;
+            this.scale = scale;
+            this.enabled = enabled;
+            this.label = label;
+            this.shape = shape;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.name == null) {
+                if (o.name != null) {
+                    return false;
+                }
+            } else {
+                if (!this.name.Equals(o.name)) {
+                    return false;
+                }
+            }
+
+            if (!this.count.Equals(o.count)) {
+                return false;
+            }
+
+            if (!this.big.Equals(o.big)) {
+                return false;
+            }
+
+            if (!this.ratio.Equals(o.ratio)) {
+                return false;
+            }
+
+            if (!this.scale.Equals(o.scale)) {
+                return false;
+            }
+
+            if (!this.enabled.Equals(o.enabled)) {
+                return false;
+            }
+
+            if (this.label == null) {
+                if (o.label != null) {
+                    return false;
+                }
+            } else {
+                if (!this.label.Equals(o.label)) {
+                    return false;
+                }
+            }
+
+            if (this.shape == null) {
+                if (o.shape != null) {
+                    return false;
+                }
+            } else {
+                if (!this.shape.Equals(o.shape)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.name.GetHashCode();
+            result = result * 31 + this.count.GetHashCode();
+            result = result * 31 + this.big.GetHashCode();
+            result = result * 31 + this.ratio.GetHashCode();
+            result = result * 31 + this.scale.GetHashCode();
+            result = result * 31 + this.enabled.GetHashCode();
+            result = result * 31 + this.label.GetHashCode();
+            result = result * 31 + this.shape.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("name=");
+            b.Append(this.name);
+            b.Append(", ");
+            b.Append("count=");
+            b.Append(this.count);
+            b.Append(", ");
+            b.Append("big=");
+            b.Append(this.big);
+            b.Append(", ");
+            b.Append("ratio=");
+            b.Append(this.ratio);
+            b.Append(", ");
+            b.Append("scale=");
+            b.Append(this.scale);
+            b.Append(", ");
+            b.Append("enabled=");
+            b.Append(this.enabled);
+            b.Append(", ");
+            b.Append("label=");
+            b.Append(this.label);
+            b.Append(", ");
+            b.Append("shape=");
+            b.Append(this.shape);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
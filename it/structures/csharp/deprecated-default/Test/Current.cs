@@ -0,0 +1,57 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Current {
+        [JsonProperty("value")]
+        public String value {
+            get;
+        }
+
+        [JsonConstructor]
+        public Current (
+            [JsonProperty("value")] String value
+        ) {
+            this.value = value;
+        }
+
+        public override bool Equals(Object other) {
+            Current o = other as Current;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.value == null) {
+                if (o.value != null) {
+                    return false;
+                }
+            } else {
+                if (!this.value.Equals(o.value)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.value.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Current(");
+            b.Append("value=");
+            b.Append(this.value);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,137 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        /// Kept for compatibility.
+        [JsonProperty("legacy")]
+        [Obsolete("deprecated since 1.1.0: use `current` instead")]
+        public Legacy legacy {
+            get;
+        }
+
+        [JsonProperty("current")]
+        public Current current {
+            get;
+        }
+
+        [JsonProperty("status")]
+        public Status? status {
+            get;
+        }
+
+        [JsonProperty("point")]
+        public Point point {
+            get;
+        }
+
+        [JsonProperty("shape")]
+        public Shape shape {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("legacy")] Legacy legacy,
+            [JsonProperty("current")] Current current,
+            [JsonProperty("status")] Status? status,
+            [JsonProperty("point")] Point point,
+            [JsonProperty("shape")] Shape shape
+        ) {
+            this.legacy = legacy;
+            this.current = current;
+            this.status = status;
+            this.point = point;
+            this.shape = shape;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.legacy == null) {
+                if (o.legacy != null) {
+                    return false;
+                }
+            } else {
+                if (!this.legacy.Equals(o.legacy)) {
+                    return false;
+                }
+            }
+
+            if (this.current == null) {
+                if (o.current != null) {
+                    return false;
+                }
+            } else {
+                if (!this.current.Equals(o.current)) {
+                    return false;
+                }
+            }
+
+            if (!this.status.Equals(o.status)) {
+                return false;
+            }
+
+            if (this.point == null) {
+                if (o.point != null) {
+                    return false;
+                }
+            } else {
+                if (!this.point.Equals(o.point)) {
+                    return false;
+                }
+            }
+
+            if (this.shape == null) {
+                if (o.shape != null) {
+                    return false;
+                }
+            } else {
+                if (!this.shape.Equals(o.shape)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.legacy.GetHashCode();
+            result = result * 31 + this.current.GetHashCode();
+            result = result * 31 + this.status.GetHashCode();
+            result = result * 31 + this.point.GetHashCode();
+            result = result * 31 + this.shape.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("legacy=");
+            b.Append(this.legacy);
+            b.Append(", ");
+            b.Append("current=");
+            b.Append(this.current);
+            b.Append(", ");
+            b.Append("status=");
+            b.Append(this.status);
+            b.Append(", ");
+            b.Append("point=");
+            b.Append(this.point);
+            b.Append(", ");
+            b.Append("shape=");
+            b.Append(this.shape);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
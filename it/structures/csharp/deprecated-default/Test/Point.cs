@@ -0,0 +1,105 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Point.Json_Net_Converter))]
+    [Obsolete("deprecated since 1.0.0")]
+    public class Point {
+        [JsonProperty("x")]
+        public int x {
+            get;
+        }
+
+        [JsonProperty("y")]
+        public int y {
+            get;
+        }
+
+        [JsonConstructor]
+        public Point (
+            [JsonProperty("x")] int x,
+            [JsonProperty("y")] int y
+        ) {
+            this.x = x;
+            this.y = y;
+        }
+
+        public override bool Equals(Object other) {
+            Point o = other as Point;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.x.Equals(o.x)) {
+                return false;
+            }
+
+            if (!this.y.Equals(o.y)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.x.GetHashCode();
+            result = result * 31 + this.y.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Point(");
+            b.Append("x=");
+            b.Append(this.x);
+            b.Append(", ");
+            b.Append("y=");
+            b.Append(this.y);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_Converter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(Point);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                Point o = (Point)obj;
+                JArray array = new JArray();
+
+                array.Add(JToken.FromObject(o.x, serializer));
+
+                array.Add(JToken.FromObject(o.y, serializer));
+
+                array.WriteTo(writer);
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JArray array = JArray.Load(reader);
+                IEnumerator<JToken> enumerator = array.GetEnumerator();
+
+                if (!enumerator.MoveNext()) {
+                    throw new InvalidOperationException("expected more items in array");
+                }
+
+                int x = enumerator.Current.ToObject<int>(serializer);
+
+                if (!enumerator.MoveNext()) {
+                    throw new InvalidOperationException("expected more items in array");
+                }
+
+                int y = enumerator.Current.ToObject<int>(serializer);
+
+                return new Point(x, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+using JsonSubTypes;
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(JsonSubtypes), "type")]
+    [JsonSubtypes.KnownSubType(typeof(Shape.Circle), "Circle")]
+    [JsonSubtypes.KnownSubType(typeof(Shape.Round), "Round")]
+    public abstract class Shape {
+        [JsonProperty("type", Required = Required.DisallowNull)]
+        private String TypeField {
+            get;
+        }
+
+        public Shape(String TypeField) {
+            this.TypeField = TypeField;
+        }
+
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class Circle : Shape {
+            [JsonProperty("radius")]
+            public double radius {
+                get;
+            }
+
+            [JsonConstructor]
+            public Circle (
+                [JsonProperty("type", Required = Required.DisallowNull)] String TypeField,
+                [JsonProperty("radius")] double radius
+            ) : base(TypeField) {
+                this.radius = radius;
+            }
+
+            public override bool Equals(Object other) {
+                Circle o = other as Circle;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (!this.radius.Equals(o.radius)) {
+                    return false;
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.radius.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Circle(");
+                b.Append("radius=");
+                b.Append(this.radius);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        [Obsolete("deprecated: use `Circle` instead")]
+        public class Round : Shape {
+            [JsonProperty("radius")]
+            public double radius {
+                get;
+            }
+
+            [JsonConstructor]
+            public Round (
+                [JsonProperty("type", Required = Required.DisallowNull)] String TypeField,
+                [JsonProperty("radius")] double radius
+            ) : base(TypeField) {
+                this.radius = radius;
+            }
+
+            public override bool Equals(Object other) {
+                Round o = other as Round;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (!this.radius.Equals(o.radius)) {
+                    return false;
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.radius.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Round(");
+                b.Append("radius=");
+                b.Append(this.radius);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+    }
+}
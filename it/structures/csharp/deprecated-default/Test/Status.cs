@@ -0,0 +1,15 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Converters;
+using System;
+using System.Runtime.Serialization;
+
+namespace Test {
+    [JsonConverter(typeof(StringEnumConverter))]
+    public enum Status {
+        [EnumMember(Value = "active")]
+        ACTIVE,
+        [EnumMember(Value = "retired")]
+        [Obsolete("deprecated")]
+        RETIRED
+    }
+}
@@ -0,0 +1,120 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("page")]
+        public PageItem page {
+            get;
+        }
+
+        [JsonProperty("names")]
+        public PageString names {
+            get;
+        }
+
+        [JsonProperty("pair")]
+        public PairItemU32 pair {
+            get;
+        }
+
+        [JsonProperty("outcome")]
+        public OutcomeItem outcome {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("page")] PageItem page,
+            [JsonProperty("names")] PageString names,
+            [JsonProperty("pair")] PairItemU32 pair,
+            [JsonProperty("outcome")] OutcomeItem outcome
+        ) {
+            this.page = page;
+            this.names = names;
+            this.pair = pair;
+            this.outcome = outcome;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.page == null) {
+                if (o.page != null) {
+                    return false;
+                }
+            } else {
+                if (!this.page.Equals(o.page)) {
+                    return false;
+                }
+            }
+
+            if (this.names == null) {
+                if (o.names != null) {
+                    return false;
+                }
+            } else {
+                if (!this.names.Equals(o.names)) {
+                    return false;
+                }
+            }
+
+            if (this.pair == null) {
+                if (o.pair != null) {
+                    return false;
+                }
+            } else {
+                if (!this.pair.Equals(o.pair)) {
+                    return false;
+                }
+            }
+
+            if (this.outcome == null) {
+                if (o.outcome != null) {
+                    return false;
+                }
+            } else {
+                if (!this.outcome.Equals(o.outcome)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.page.GetHashCode();
+            result = result * 31 + this.names.GetHashCode();
+            result = result * 31 + this.pair.GetHashCode();
+            result = result * 31 + this.outcome.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("page=");
+            b.Append(this.page);
+            b.Append(", ");
+            b.Append("names=");
+            b.Append(this.names);
+            b.Append(", ");
+            b.Append("pair=");
+            b.Append(this.pair);
+            b.Append(", ");
+            b.Append("outcome=");
+            b.Append(this.outcome);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
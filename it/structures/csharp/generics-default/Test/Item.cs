@@ -0,0 +1,57 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Item {
+        [JsonProperty("name")]
+        public String name {
+            get;
+        }
+
+        [JsonConstructor]
+        public Item (
+            [JsonProperty("name")] String name
+        ) {
+            this.name = name;
+        }
+
+        public override bool Equals(Object other) {
+            Item o = other as Item;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.name == null) {
+                if (o.name != null) {
+                    return false;
+                }
+            } else {
+                if (!this.name.Equals(o.name)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.name.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Item(");
+            b.Append("name=");
+            b.Append(this.name);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
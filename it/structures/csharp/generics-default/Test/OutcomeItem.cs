@@ -0,0 +1,125 @@
+using JsonSubTypes;
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(JsonSubtypes), "type")]
+    [JsonSubtypes.KnownSubType(typeof(OutcomeItem.Ok), "Ok")]
+    [JsonSubtypes.KnownSubType(typeof(OutcomeItem.Error), "Error")]
+    public abstract class OutcomeItem {
+        [JsonProperty("type", Required = Required.DisallowNull)]
+        private String TypeField {
+            get;
+        }
+
+        public OutcomeItem(String TypeField) {
+            this.TypeField = TypeField;
+        }
+
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class Ok : OutcomeItem {
+            [JsonProperty("value")]
+            public Item value {
+                get;
+            }
+
+            [JsonConstructor]
+            public Ok (
+                [JsonProperty("type", Required = Required.DisallowNull)] String TypeField,
+                [JsonProperty("value")] Item value
+            ) : base(TypeField) {
+                this.value = value;
+            }
+
+            public override bool Equals(Object other) {
+                Ok o = other as Ok;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.value == null) {
+                    if (o.value != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.value.Equals(o.value)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.value.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Ok(");
+                b.Append("value=");
+                b.Append(this.value);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class Error : OutcomeItem {
+            [JsonProperty("message")]
+            public String message {
+                get;
+            }
+
+            [JsonConstructor]
+            public Error (
+                [JsonProperty("type", Required = Required.DisallowNull)] String TypeField,
+                [JsonProperty("message")] String message
+            ) : base(TypeField) {
+                this.message = message;
+            }
+
+            public override bool Equals(Object other) {
+                Error o = other as Error;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.message == null) {
+                    if (o.message != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.message.Equals(o.message)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.message.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Error(");
+                b.Append("message=");
+                b.Append(this.message);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+    }
+}
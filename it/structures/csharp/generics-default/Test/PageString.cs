@@ -0,0 +1,80 @@
+using Newtonsoft.Json;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    /// A single page of results.
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class PageString {
+        [JsonProperty("items")]
+        public List<String> items {
+            get;
+        }
+
+        [JsonProperty("next")]
+        public String next {
+            get;
+        }
+
+        [JsonConstructor]
+        public PageString (
+            [JsonProperty("items")] List<String> items,
+            [JsonProperty("next")] String next
+        ) {
+            this.items = items;
+            this.next = next;
+        }
+
+        public override bool Equals(Object other) {
+            PageString o = other as PageString;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.items == null) {
+                if (o.items != null) {
+                    return false;
+                }
+            } else {
+                if (!this.items.Equals(o.items)) {
+                    return false;
+                }
+            }
+
+            if (this.next == null) {
+                if (o.next != null) {
+                    return false;
+                }
+            } else {
+                if (!this.next.Equals(o.next)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.items.GetHashCode();
+            result = result * 31 + this.next.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("PageString(");
+            b.Append("items=");
+            b.Append(this.items);
+            b.Append(", ");
+            b.Append("next=");
+            b.Append(this.next);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
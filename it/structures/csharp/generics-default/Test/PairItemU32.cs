@@ -0,0 +1,72 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class PairItemU32 {
+        [JsonProperty("first")]
+        public Item first {
+            get;
+        }
+
+        [JsonProperty("second")]
+        public uint second {
+            get;
+        }
+
+        [JsonConstructor]
+        public PairItemU32 (
+            [JsonProperty("first")] Item first,
+            [JsonProperty("second")] uint second
+        ) {
+            this.first = first;
+            this.second = second;
+        }
+
+        public override bool Equals(Object other) {
+            PairItemU32 o = other as PairItemU32;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.first == null) {
+                if (o.first != null) {
+                    return false;
+                }
+            } else {
+                if (!this.first.Equals(o.first)) {
+                    return false;
+                }
+            }
+
+            if (!this.second.Equals(o.second)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.first.GetHashCode();
+            result = result * 31 + this.second.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("PairItemU32(");
+            b.Append("first=");
+            b.Append(this.first);
+            b.Append(", ");
+            b.Append("second=");
+            b.Append(this.second);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
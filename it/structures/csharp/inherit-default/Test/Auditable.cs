@@ -0,0 +1,101 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    /// Fields shared by all audited types.
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Auditable {
+        [JsonProperty("created_at")]
+        public String createdAt {
+            get;
+        }
+
+        [JsonProperty("updated_at")]
+        public String updatedAt {
+            get;
+        }
+
+        /// Who last modified the entry.
+        [JsonProperty("updated_by")]
+        public String updatedBy {
+            get;
+        }
+
+        [JsonConstructor]
+        public Auditable (
+            [JsonProperty("created_at")] String createdAt,
+            [JsonProperty("updated_at")] String updatedAt,
+            [JsonProperty("updated_by")] String updatedBy
+        ) {
+            this.createdAt = createdAt;
+            this.updatedAt = updatedAt;
+            this.updatedBy = updatedBy;
+        }
+
+        public override bool Equals(Object other) {
+            Auditable o = other as Auditable;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.createdAt == null) {
+                if (o.createdAt != null) {
+                    return false;
+                }
+            } else {
+                if (!this.createdAt.Equals(o.createdAt)) {
+                    return false;
+                }
+            }
+
+            if (this.updatedAt == null) {
+                if (o.updatedAt != null) {
+                    return false;
+                }
+            } else {
+                if (!this.updatedAt.Equals(o.updatedAt)) {
+                    return false;
+                }
+            }
+
+            if (this.updatedBy == null) {
+                if (o.updatedBy != null) {
+                    return false;
+                }
+            } else {
+                if (!this.updatedBy.Equals(o.updatedBy)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.createdAt.GetHashCode();
+            result = result * 31 + this.updatedAt.GetHashCode();
+            result = result * 31 + this.updatedBy.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Auditable(");
+            b.Append("created_at=");
+            b.Append(this.createdAt);
+            b.Append(", ");
+            b.Append("updated_at=");
+            b.Append(this.updatedAt);
+            b.Append(", ");
+            b.Append("updated_by=");
+            b.Append(this.updatedBy);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
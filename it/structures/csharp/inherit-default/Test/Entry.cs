@@ -0,0 +1,142 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("created_at")]
+        public String createdAt {
+            get;
+        }
+
+        [JsonProperty("updated_at")]
+        public String updatedAt {
+            get;
+        }
+
+        /// Who last modified the entry.
+        [JsonProperty("updated_by")]
+        public String updatedBy {
+            get;
+        }
+
+        [JsonProperty("id")]
+        public String id {
+            get;
+        }
+
+        [JsonProperty("name")]
+        public String name {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("created_at")] String createdAt,
+            [JsonProperty("updated_at")] String updatedAt,
+            [JsonProperty("updated_by")] String updatedBy,
+            [JsonProperty("id")] String id,
+            [JsonProperty("name")] String name
+        ) {
+            this.createdAt = createdAt;
+            this.updatedAt = updatedAt;
+            this.updatedBy = updatedBy;
+            this.id = id;
+            this.name = name;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.createdAt == null) {
+                if (o.createdAt != null) {
+                    return false;
+                }
+            } else {
+                if (!this.createdAt.Equals(o.createdAt)) {
+                    return false;
+                }
+            }
+
+            if (this.updatedAt == null) {
+                if (o.updatedAt != null) {
+                    return false;
+                }
+            } else {
+                if (!this.updatedAt.Equals(o.updatedAt)) {
+                    return false;
+                }
+            }
+
+            if (this.updatedBy == null) {
+                if (o.updatedBy != null) {
+                    return false;
+                }
+            } else {
+                if (!this.updatedBy.Equals(o.updatedBy)) {
+                    return false;
+                }
+            }
+
+            if (this.id == null) {
+                if (o.id != null) {
+                    return false;
+                }
+            } else {
+                if (!this.id.Equals(o.id)) {
+                    return false;
+                }
+            }
+
+            if (this.name == null) {
+                if (o.name != null) {
+                    return false;
+                }
+            } else {
+                if (!this.name.Equals(o.name)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.createdAt.GetHashCode();
+            result = result * 31 + this.updatedAt.GetHashCode();
+            result = result * 31 + this.updatedBy.GetHashCode();
+            result = result * 31 + this.id.GetHashCode();
+            result = result * 31 + this.name.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("created_at=");
+            b.Append(this.createdAt);
+            b.Append(", ");
+            b.Append("updated_at=");
+            b.Append(this.updatedAt);
+            b.Append(", ");
+            b.Append("updated_by=");
+            b.Append(this.updatedBy);
+            b.Append(", ");
+            b.Append("id=");
+            b.Append(this.id);
+            b.Append(", ");
+            b.Append("name=");
+            b.Append(this.name);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
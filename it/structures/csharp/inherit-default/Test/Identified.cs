@@ -0,0 +1,57 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Identified {
+        [JsonProperty("id")]
+        public String id {
+            get;
+        }
+
+        [JsonConstructor]
+        public Identified (
+            [JsonProperty("id")] String id
+        ) {
+            this.id = id;
+        }
+
+        public override bool Equals(Object other) {
+            Identified o = other as Identified;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.id == null) {
+                if (o.id != null) {
+                    return false;
+                }
+            } else {
+                if (!this.id.Equals(o.id)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.id.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Identified(");
+            b.Append("id=");
+            b.Append(this.id);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
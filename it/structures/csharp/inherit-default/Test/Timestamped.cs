@@ -0,0 +1,78 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Timestamped {
+        [JsonProperty("created_at")]
+        public String createdAt {
+            get;
+        }
+
+        [JsonProperty("updated_at")]
+        public String updatedAt {
+            get;
+        }
+
+        [JsonConstructor]
+        public Timestamped (
+            [JsonProperty("created_at")] String createdAt,
+            [JsonProperty("updated_at")] String updatedAt
+        ) {
+            this.createdAt = createdAt;
+            this.updatedAt = updatedAt;
+        }
+
+        public override bool Equals(Object other) {
+            Timestamped o = other as Timestamped;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.createdAt == null) {
+                if (o.createdAt != null) {
+                    return false;
+                }
+            } else {
+                if (!this.createdAt.Equals(o.createdAt)) {
+                    return false;
+                }
+            }
+
+            if (this.updatedAt == null) {
+                if (o.updatedAt != null) {
+                    return false;
+                }
+            } else {
+                if (!this.updatedAt.Equals(o.updatedAt)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.createdAt.GetHashCode();
+            result = result * 31 + this.updatedAt.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Timestamped(");
+            b.Append("created_at=");
+            b.Append(this.createdAt);
+            b.Append(", ");
+            b.Append("updated_at=");
+            b.Append(this.updatedAt);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,139 @@
+using Newtonsoft.Json;
+using System;
+using System.Globalization;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("id")]
+        [JsonConverter(typeof(Json_Net_StringConverter))]
+        public ulong id {
+            get;
+        }
+
+        [JsonProperty("offset")]
+        [JsonConverter(typeof(Json_Net_StringConverter))]
+        public long? offset {
+            get;
+        }
+
+        [JsonProperty("size")]
+        public long? size {
+            get;
+        }
+
+        [JsonProperty("count")]
+        public ulong? count {
+            get;
+        }
+
+        [JsonProperty("pair")]
+        public Pair pair {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("id")] ulong id,
+            [JsonProperty("offset")] long? offset,
+            [JsonProperty("size")] long? size,
+            [JsonProperty("count")] ulong? count,
+            [JsonProperty("pair")] Pair pair
+        ) {
+            this.id = id;
+            this.offset = offset;
+            this.size = size;
+            this.count = count;
+            this.pair = pair;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.id.Equals(o.id)) {
+                return false;
+            }
+
+            if (!this.offset.Equals(o.offset)) {
+                return false;
+            }
+
+            if (!this.size.Equals(o.size)) {
+                return false;
+            }
+
+            if (!this.count.Equals(o.count)) {
+                return false;
+            }
+
+            if (this.pair == null) {
+                if (o.pair != null) {
+                    return false;
+                }
+            } else {
+                if (!this.pair.Equals(o.pair)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.id.GetHashCode();
+            result = result * 31 + this.offset.GetHashCode();
+            result = result * 31 + this.size.GetHashCode();
+            result = result * 31 + this.count.GetHashCode();
+            result = result * 31 + this.pair.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("id=");
+            b.Append(this.id);
+            b.Append(", ");
+            b.Append("offset=");
+            b.Append(this.offset);
+            b.Append(", ");
+            b.Append("size=");
+            b.Append(this.size);
+            b.Append(", ");
+            b.Append("count=");
+            b.Append(this.count);
+            b.Append(", ");
+            b.Append("pair=");
+            b.Append(this.pair);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_StringConverter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return true;
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                writer.WriteValue(System.Convert.ToString(obj, CultureInfo.InvariantCulture));
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                if (reader.TokenType == JsonToken.Null) {
+                    return null;
+                }
+
+                System.Type type = System.Nullable.GetUnderlyingType(objectType) ?? objectType;
+                return System.Convert.ChangeType(reader.Value, type, CultureInfo.InvariantCulture);
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Pair.Json_Net_Converter))]
+    public class Pair {
+        [JsonProperty("left")]
+        [JsonConverter(typeof(Json_Net_StringConverter))]
+        public ulong left {
+            get;
+        }
+
+        [JsonProperty("right")]
+        public ulong right {
+            get;
+        }
+
+        [JsonConstructor]
+        public Pair (
+            [JsonProperty("left")] ulong left,
+            [JsonProperty("right")] ulong right
+        ) {
+            this.left = left;
+            this.right = right;
+        }
+
+        public override bool Equals(Object other) {
+            Pair o = other as Pair;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.left.Equals(o.left)) {
+                return false;
+            }
+
+            if (!this.right.Equals(o.right)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.left.GetHashCode();
+            result = result * 31 + this.right.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Pair(");
+            b.Append("left=");
+            b.Append(this.left);
+            b.Append(", ");
+            b.Append("right=");
+            b.Append(this.right);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_Converter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(Pair);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                Pair o = (Pair)obj;
+                JArray array = new JArray();
+
+                array.Add(JToken.FromObject(System.Convert.ToString(o.left, CultureInfo.InvariantCulture), serializer));
+
+                array.Add(JToken.FromObject(o.right, serializer));
+
+                array.WriteTo(writer);
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JArray array = JArray.Load(reader);
+                IEnumerator<JToken> enumerator = array.GetEnumerator();
+
+                if (!enumerator.MoveNext()) {
+                    throw new InvalidOperationException("expected more items in array");
+                }
+
+                ulong left = enumerator.Current.ToObject<ulong>(serializer);
+
+                if (!enumerator.MoveNext()) {
+                    throw new InvalidOperationException("expected more items in array");
+                }
+
+                ulong right = enumerator.Current.ToObject<ulong>(serializer);
+
+                return new Pair(left, right);
+            }
+        }
+
+        public class Json_Net_StringConverter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return true;
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                writer.WriteValue(System.Convert.ToString(obj, CultureInfo.InvariantCulture));
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                if (reader.TokenType == JsonToken.Null) {
+                    return null;
+                }
+
+                System.Type type = System.Nullable.GetUnderlyingType(objectType) ?? objectType;
+                return System.Convert.ChangeType(reader.Value, type, CultureInfo.InvariantCulture);
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("adjacent")]
+        public Adjacent adjacent {
+            get;
+        }
+
+        [JsonProperty("adjacent_default")]
+        public AdjacentDefault adjacentDefault {
+            get;
+        }
+
+        [JsonProperty("external")]
+        public External external {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("adjacent")] Adjacent adjacent,
+            [JsonProperty("adjacent_default")] AdjacentDefault adjacentDefault,
+            [JsonProperty("external")] External external
+        ) {
+            this.adjacent = adjacent;
+            this.adjacentDefault = adjacentDefault;
+            this.external = external;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.adjacent == null) {
+                if (o.adjacent != null) {
+                    return false;
+                }
+            } else {
+                if (!this.adjacent.Equals(o.adjacent)) {
+                    return false;
+                }
+            }
+
+            if (this.adjacentDefault == null) {
+                if (o.adjacentDefault != null) {
+                    return false;
+                }
+            } else {
+                if (!this.adjacentDefault.Equals(o.adjacentDefault)) {
+                    return false;
+                }
+            }
+
+            if (this.external == null) {
+                if (o.external != null) {
+                    return false;
+                }
+            } else {
+                if (!this.external.Equals(o.external)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.adjacent.GetHashCode();
+            result = result * 31 + this.adjacentDefault.GetHashCode();
+            result = result * 31 + this.external.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("adjacent=");
+            b.Append(this.adjacent);
+            b.Append(", ");
+            b.Append("adjacent_default=");
+            b.Append(this.adjacentDefault);
+            b.Append(", ");
+            b.Append("external=");
+            b.Append(this.external);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,206 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(External.Json_Net_Converter))]
+    public abstract class External {
+
+        public class Json_Net_Converter : JsonConverter {
+            [ThreadStatic]
+            private static bool _isInside;
+            public override bool CanWrite {
+                get { return !_isInside; }
+            }
+            public override bool CanRead {
+                get { return !_isInside; }
+            }
+
+            public override bool CanConvert(System.Type objectType) {
+                return false;
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                JToken value;
+
+                _isInside = true;
+                try {
+                    value = JToken.FromObject(obj, serializer);
+                } finally {
+                    _isInside = false;
+                }
+
+                JObject o = new JObject();
+
+                if (obj is A) {
+                    o.Add("foo", value);
+                    o.WriteTo(writer);
+                    return;
+                }
+
+                if (obj is B) {
+                    o.Add("B", value);
+                    o.WriteTo(writer);
+                    return;
+                }
+
+                throw new InvalidOperationException("unsupported sub-type");
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JObject o = JObject.Load(reader);
+
+                if (o.Count != 1) {
+                    throw new InvalidOperationException("expected exactly one field");
+                }
+
+                foreach (JProperty property in o.Properties()) {
+                    switch (property.Name) {
+                    case "foo":
+                        return ReadSubType(property.Value, typeof(A), serializer);
+                    case "B":
+                        return ReadSubType(property.Value, typeof(B), serializer);
+                    default:
+                        throw new InvalidOperationException("bad type: " + property.Name);
+                    }
+                }
+
+                throw new InvalidOperationException("expected exactly one field");
+            }
+
+            private static Object ReadSubType(JToken value, System.Type type, JsonSerializer serializer) {
+                _isInside = true;
+                try {
+                    return serializer.Deserialize(value.CreateReader(), type);
+                } finally {
+                    _isInside = false;
+                }
+            }
+        }
+
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class A : External {
+            [JsonProperty("shared")]
+            public String shared {
+                get;
+            }
+
+            [JsonConstructor]
+            public A (
+                [JsonProperty("shared")] String shared
+            ) {
+                this.shared = shared;
+            }
+
+            public override bool Equals(Object other) {
+                A o = other as A;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.shared == null) {
+                    if (o.shared != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.shared.Equals(o.shared)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.shared.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("A(");
+                b.Append("shared=");
+                b.Append(this.shared);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class B : External {
+            [JsonProperty("shared")]
+            public String shared {
+                get;
+            }
+
+            [JsonProperty("b")]
+            public String b {
+                get;
+            }
+
+            [JsonConstructor]
+            public B (
+                [JsonProperty("shared")] String shared,
+                [JsonProperty("b")] String b
+            ) {
+                this.shared = shared;
+                this.b = b;
+            }
+
+            public override bool Equals(Object other) {
+                B o = other as B;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.shared == null) {
+                    if (o.shared != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.shared.Equals(o.shared)) {
+                        return false;
+                    }
+                }
+
+                if (this.b == null) {
+                    if (o.b != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.b.Equals(o.b)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.shared.GetHashCode();
+                result = result * 31 + this.b.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("B(");
+                b.Append("shared=");
+                b.Append(this.shared);
+                b.Append(", ");
+                b.Append("b=");
+                b.Append(this.b);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+    }
+}
@@ -0,0 +1,478 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Collections.Generic;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        /// Either a name or a numeric identifier.
+        [JsonProperty("value")]
+        public Entry.Value value {
+            get;
+        }
+
+        [JsonProperty("shape")]
+        public Entry.Shape shape {
+            get;
+        }
+
+        [JsonProperty("flag")]
+        public Entry.Flag flag {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("value")] Entry.Value value,
+            [JsonProperty("shape")] Entry.Shape shape,
+            [JsonProperty("flag")] Entry.Flag flag
+        ) {
+            this.value = value;
+            this.shape = shape;
+            this.flag = flag;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.value == null) {
+                if (o.value != null) {
+                    return false;
+                }
+            } else {
+                if (!this.value.Equals(o.value)) {
+                    return false;
+                }
+            }
+
+            if (this.shape == null) {
+                if (o.shape != null) {
+                    return false;
+                }
+            } else {
+                if (!this.shape.Equals(o.shape)) {
+                    return false;
+                }
+            }
+
+            if (this.flag == null) {
+                if (o.flag != null) {
+                    return false;
+                }
+            } else {
+                if (!this.flag.Equals(o.flag)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.value.GetHashCode();
+            result = result * 31 + this.shape.GetHashCode();
+            result = result * 31 + this.flag.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("value=");
+            b.Append(this.value);
+            b.Append(", ");
+            b.Append("shape=");
+            b.Append(this.shape);
+            b.Append(", ");
+            b.Append("flag=");
+            b.Append(this.flag);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        [JsonConverter(typeof(Value.Json_Net_Converter))]
+        public abstract class Value {
+            public class StringValue : Value {
+                public String value {
+                    get;
+                }
+
+                public StringValue(String value) {
+                    this.value = value;
+                }
+
+                public override bool Equals(Object other) {
+                    StringValue o = other as StringValue;
+
+                    if (o == null) {
+                        return false;
+                    }
+
+                    if (this.value == null) {
+                        if (o.value != null) {
+                            return false;
+                        }
+                    } else {
+                        if (!this.value.Equals(o.value)) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+
+                public override int GetHashCode() {
+                    int result = 1;
+                    result = result * 31 + this.value.GetHashCode();
+                    return result;
+                }
+
+                public override String ToString() {
+                    StringBuilder b = new StringBuilder();
+
+                    b.Append("StringValue(");
+                    b.Append("value=");
+                    b.Append(this.value);
+                    b.Append(")");
+
+                    return b.ToString();
+                }
+            }
+
+            public class U64Value : Value {
+                public ulong value {
+                    get;
+                }
+
+                public U64Value(ulong value) {
+                    this.value = value;
+                }
+
+                public override bool Equals(Object other) {
+                    U64Value o = other as U64Value;
+
+                    if (o == null) {
+                        return false;
+                    }
+
+                    if (!this.value.Equals(o.value)) {
+                        return false;
+                    }
+
+                    return true;
+                }
+
+                public override int GetHashCode() {
+                    int result = 1;
+                    result = result * 31 + this.value.GetHashCode();
+                    return result;
+                }
+
+                public override String ToString() {
+                    StringBuilder b = new StringBuilder();
+
+                    b.Append("U64Value(");
+                    b.Append("value=");
+                    b.Append(this.value);
+                    b.Append(")");
+
+                    return b.ToString();
+                }
+            }
+
+            public class Json_Net_Converter : JsonConverter {
+                public override bool CanConvert(System.Type objectType) {
+                    return typeof(Value).IsAssignableFrom(objectType);
+                }
+
+                public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                    if (obj is StringValue) {
+                        serializer.Serialize(writer, ((StringValue)obj).value);
+                        return;
+                    }
+
+                    if (obj is U64Value) {
+                        serializer.Serialize(writer, ((U64Value)obj).value);
+                        return;
+                    }
+
+                    throw new InvalidOperationException("unsupported variant");
+                }
+
+                public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                    JToken token = JToken.Load(reader);
+
+                    switch (token.Type) {
+                    case JTokenType.String:
+                        return new StringValue(token.ToObject<String>(serializer));
+                    case JTokenType.Integer:
+                        return new U64Value(token.ToObject<ulong>(serializer));
+                    default:
+                        throw new InvalidOperationException("no alternative can be read from: " + token.Type);
+                    }
+                }
+            }
+        }
+        [JsonConverter(typeof(Shape.Json_Net_Converter))]
+        public abstract class Shape {
+            public class PointValue : Shape {
+                public Point value {
+                    get;
+                }
+
+                public PointValue(Point value) {
+                    this.value = value;
+                }
+
+                public override bool Equals(Object other) {
+                    PointValue o = other as PointValue;
+
+                    if (o == null) {
+                        return false;
+                    }
+
+                    if (this.value == null) {
+                        if (o.value != null) {
+                            return false;
+                        }
+                    } else {
+                        if (!this.value.Equals(o.value)) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+
+                public override int GetHashCode() {
+                    int result = 1;
+                    result = result * 31 + this.value.GetHashCode();
+                    return result;
+                }
+
+                public override String ToString() {
+                    StringBuilder b = new StringBuilder();
+
+                    b.Append("PointValue(");
+                    b.Append("value=");
+                    b.Append(this.value);
+                    b.Append(")");
+
+                    return b.ToString();
+                }
+            }
+
+            public class DoubleArrayValue : Shape {
+                public List<double> value {
+                    get;
+                }
+
+                public DoubleArrayValue(List<double> value) {
+                    this.value = value;
+                }
+
+                public override bool Equals(Object other) {
+                    DoubleArrayValue o = other as DoubleArrayValue;
+
+                    if (o == null) {
+                        return false;
+                    }
+
+                    if (this.value == null) {
+                        if (o.value != null) {
+                            return false;
+                        }
+                    } else {
+                        if (!this.value.Equals(o.value)) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+
+                public override int GetHashCode() {
+                    int result = 1;
+                    result = result * 31 + this.value.GetHashCode();
+                    return result;
+                }
+
+                public override String ToString() {
+                    StringBuilder b = new StringBuilder();
+
+                    b.Append("DoubleArrayValue(");
+                    b.Append("value=");
+                    b.Append(this.value);
+                    b.Append(")");
+
+                    return b.ToString();
+                }
+            }
+
+            public class Json_Net_Converter : JsonConverter {
+                public override bool CanConvert(System.Type objectType) {
+                    return typeof(Shape).IsAssignableFrom(objectType);
+                }
+
+                public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                    if (obj is PointValue) {
+                        serializer.Serialize(writer, ((PointValue)obj).value);
+                        return;
+                    }
+
+                    if (obj is DoubleArrayValue) {
+                        serializer.Serialize(writer, ((DoubleArrayValue)obj).value);
+                        return;
+                    }
+
+                    throw new InvalidOperationException("unsupported variant");
+                }
+
+                public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                    JToken token = JToken.Load(reader);
+
+                    switch (token.Type) {
+                    case JTokenType.Object:
+                        return new PointValue(token.ToObject<Point>(serializer));
+                    case JTokenType.Array:
+                        return new DoubleArrayValue(token.ToObject<List<double>>(serializer));
+                    default:
+                        throw new InvalidOperationException("no alternative can be read from: " + token.Type);
+                    }
+                }
+            }
+        }
+        [JsonConverter(typeof(Flag.Json_Net_Converter))]
+        public abstract class Flag {
+            public class BooleanValue : Flag {
+                public bool value {
+                    get;
+                }
+
+                public BooleanValue(bool value) {
+                    this.value = value;
+                }
+
+                public override bool Equals(Object other) {
+                    BooleanValue o = other as BooleanValue;
+
+                    if (o == null) {
+                        return false;
+                    }
+
+                    if (!this.value.Equals(o.value)) {
+                        return false;
+                    }
+
+                    return true;
+                }
+
+                public override int GetHashCode() {
+                    int result = 1;
+                    result = result * 31 + this.value.GetHashCode();
+                    return result;
+                }
+
+                public override String ToString() {
+                    StringBuilder b = new StringBuilder();
+
+                    b.Append("BooleanValue(");
+                    b.Append("value=");
+                    b.Append(this.value);
+                    b.Append(")");
+
+                    return b.ToString();
+                }
+            }
+
+            public class StringValue : Flag {
+                public String value {
+                    get;
+                }
+
+                public StringValue(String value) {
+                    this.value = value;
+                }
+
+                public override bool Equals(Object other) {
+                    StringValue o = other as StringValue;
+
+                    if (o == null) {
+                        return false;
+                    }
+
+                    if (this.value == null) {
+                        if (o.value != null) {
+                            return false;
+                        }
+                    } else {
+                        if (!this.value.Equals(o.value)) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+
+                public override int GetHashCode() {
+                    int result = 1;
+                    result = result * 31 + this.value.GetHashCode();
+                    return result;
+                }
+
+                public override String ToString() {
+                    StringBuilder b = new StringBuilder();
+
+                    b.Append("StringValue(");
+                    b.Append("value=");
+                    b.Append(this.value);
+                    b.Append(")");
+
+                    return b.ToString();
+                }
+            }
+
+            public class Json_Net_Converter : JsonConverter {
+                public override bool CanConvert(System.Type objectType) {
+                    return typeof(Flag).IsAssignableFrom(objectType);
+                }
+
+                public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                    if (obj is BooleanValue) {
+                        serializer.Serialize(writer, ((BooleanValue)obj).value);
+                        return;
+                    }
+
+                    if (obj is StringValue) {
+                        serializer.Serialize(writer, ((StringValue)obj).value);
+                        return;
+                    }
+
+                    throw new InvalidOperationException("unsupported variant");
+                }
+
+                public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                    JToken token = JToken.Load(reader);
+
+                    switch (token.Type) {
+                    case JTokenType.Boolean:
+                        return new BooleanValue(token.ToObject<bool>(serializer));
+                    case JTokenType.String:
+                        return new StringValue(token.ToObject<String>(serializer));
+                    default:
+                        throw new InvalidOperationException("no alternative can be read from: " + token.Type);
+                    }
+                }
+            }
+        }
+    }
+}
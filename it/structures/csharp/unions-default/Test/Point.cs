@@ -0,0 +1,66 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Point {
+        [JsonProperty("x")]
+        public double x {
+            get;
+        }
+
+        [JsonProperty("y")]
+        public double y {
+            get;
+        }
+
+        [JsonConstructor]
+        public Point (
+            [JsonProperty("x")] double x,
+            [JsonProperty("y")] double y
+        ) {
+            this.x = x;
+            this.y = y;
+        }
+
+        public override bool Equals(Object other) {
+            Point o = other as Point;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.x.Equals(o.x)) {
+                return false;
+            }
+
+            if (!this.y.Equals(o.y)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.x.GetHashCode();
+            result = result * 31 + this.y.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Point(");
+            b.Append("x=");
+            b.Append(this.x);
+            b.Append(", ");
+            b.Append("y=");
+            b.Append(this.y);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,152 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Adjacent.Json_Net_Converter))]
+    public abstract class Adjacent {
+
+        public class Json_Net_Converter : JsonConverter {
+            [ThreadStatic]
+            private static bool _isInside;
+            public override bool CanWrite {
+                get { return !_isInside; }
+            }
+            public override bool CanRead {
+                get { return !_isInside; }
+            }
+
+            public override bool CanConvert(System.Type objectType) {
+                return false;
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                if (obj is Other) {
+                    serializer.Serialize(writer, ((Other)obj).Value);
+                    return;
+                }
+
+                JToken value;
+
+                _isInside = true;
+                try {
+                    value = JToken.FromObject(obj, serializer);
+                } finally {
+                    _isInside = false;
+                }
+
+                JObject o = new JObject();
+
+                if (obj is A) {
+                    o.Add("type", "A");
+                    o.Add("data", value);
+                    o.WriteTo(writer);
+                    return;
+                }
+
+                throw new InvalidOperationException("unsupported sub-type");
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JObject o = JObject.Load(reader);
+
+                JToken tag = o["type"];
+                JToken content = o["data"];
+
+                if (tag == null || content == null) {
+                    throw new InvalidOperationException("expected fields `type` and `data`");
+                }
+
+                switch ((string)tag) {
+                case "A":
+                    return ReadSubType(content, typeof(A), serializer);
+                default:
+                    return new Other(o);
+                }
+            }
+
+            private static Object ReadSubType(JToken value, System.Type type, JsonSerializer serializer) {
+                _isInside = true;
+                try {
+                    return serializer.Deserialize(value.CreateReader(), type);
+                } finally {
+                    _isInside = false;
+                }
+            }
+        }
+
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class A : Adjacent {
+
+            [JsonConstructor]
+            public A () {}
+
+            public override bool Equals(Object other) {
+                A o = other as A;
+
+                if (o == null) {
+                    return false;
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                return result;
+            }
+
+            public override String ToString() {
+                return "A()";
+            }
+        }
+
+        public class Other : Adjacent {
+            public Object Value {
+                get;
+            }
+
+            public Other(Object Value) {
+                this.Value = Value;
+            }
+
+            public override bool Equals(Object other) {
+                Other o = other as Other;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.Value == null) {
+                    if (o.Value != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.Value.Equals(o.Value)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.Value.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Other(");
+                b.Append("Value=");
+                b.Append(this.Value);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Color.Json_Net_Converter))]
+    public class Color {
+        public static readonly Color RED = new Color("red");
+
+        public static readonly Color GREEN = new Color("green");
+
+        public String Value {
+            get;
+        }
+
+        private Color(String Value) {
+            this.Value = Value;
+        }
+
+        public static Color[] Values() {
+            return new Color[] {
+                RED,
+                GREEN
+            };
+        }
+
+        /// A color which is not known to this version of the schema.
+        public bool IsOther {
+            get {
+                foreach (Color v in Values()) {
+                    if (Object.ReferenceEquals(v, this)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+        }
+
+        public override bool Equals(Object other) {
+            Color o = other as Color;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.Value == null) {
+                if (o.Value != null) {
+                    return false;
+                }
+            } else {
+                if (!this.Value.Equals(o.Value)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.Value.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Color(");
+            b.Append("Value=");
+            b.Append(this.Value);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_Converter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(Color);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                serializer.Serialize(writer, ((Color)obj).Value);
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                String value = serializer.Deserialize<String>(reader);
+
+                foreach (Color v in Color.Values()) {
+                    if (v.Value.Equals(value)) {
+                        return v;
+                    }
+                }
+
+                return new Color(value);
+            }
+        }
+    }
+}
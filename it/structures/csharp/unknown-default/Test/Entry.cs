@@ -0,0 +1,141 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("color")]
+        public Color color {
+            get;
+        }
+
+        [JsonProperty("priority")]
+        public Priority priority {
+            get;
+        }
+
+        [JsonProperty("tagged")]
+        public Tagged tagged {
+            get;
+        }
+
+        [JsonProperty("adjacent")]
+        public Adjacent adjacent {
+            get;
+        }
+
+        [JsonProperty("external")]
+        public External external {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("color")] Color color,
+            [JsonProperty("priority")] Priority priority,
+            [JsonProperty("tagged")] Tagged tagged,
+            [JsonProperty("adjacent")] Adjacent adjacent,
+            [JsonProperty("external")] External external
+        ) {
+            this.color = color;
+            this.priority = priority;
+            this.tagged = tagged;
+            this.adjacent = adjacent;
+            this.external = external;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.color == null) {
+                if (o.color != null) {
+                    return false;
+                }
+            } else {
+                if (!this.color.Equals(o.color)) {
+                    return false;
+                }
+            }
+
+            if (this.priority == null) {
+                if (o.priority != null) {
+                    return false;
+                }
+            } else {
+                if (!this.priority.Equals(o.priority)) {
+                    return false;
+                }
+            }
+
+            if (this.tagged == null) {
+                if (o.tagged != null) {
+                    return false;
+                }
+            } else {
+                if (!this.tagged.Equals(o.tagged)) {
+                    return false;
+                }
+            }
+
+            if (this.adjacent == null) {
+                if (o.adjacent != null) {
+                    return false;
+                }
+            } else {
+                if (!this.adjacent.Equals(o.adjacent)) {
+                    return false;
+                }
+            }
+
+            if (this.external == null) {
+                if (o.external != null) {
+                    return false;
+                }
+            } else {
+                if (!this.external.Equals(o.external)) {
+                    return false;
+                }
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.color.GetHashCode();
+            result = result * 31 + this.priority.GetHashCode();
+            result = result * 31 + this.tagged.GetHashCode();
+            result = result * 31 + this.adjacent.GetHashCode();
+            result = result * 31 + this.external.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("color=");
+            b.Append(this.color);
+            b.Append(", ");
+            b.Append("priority=");
+            b.Append(this.priority);
+            b.Append(", ");
+            b.Append("tagged=");
+            b.Append(this.tagged);
+            b.Append(", ");
+            b.Append("adjacent=");
+            b.Append(this.adjacent);
+            b.Append(", ");
+            b.Append("external=");
+            b.Append(this.external);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
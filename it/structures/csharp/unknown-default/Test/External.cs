@@ -0,0 +1,152 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(External.Json_Net_Converter))]
+    public abstract class External {
+
+        public class Json_Net_Converter : JsonConverter {
+            [ThreadStatic]
+            private static bool _isInside;
+            public override bool CanWrite {
+                get { return !_isInside; }
+            }
+            public override bool CanRead {
+                get { return !_isInside; }
+            }
+
+            public override bool CanConvert(System.Type objectType) {
+                return false;
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                if (obj is Other) {
+                    serializer.Serialize(writer, ((Other)obj).Value);
+                    return;
+                }
+
+                JToken value;
+
+                _isInside = true;
+                try {
+                    value = JToken.FromObject(obj, serializer);
+                } finally {
+                    _isInside = false;
+                }
+
+                JObject o = new JObject();
+
+                if (obj is A) {
+                    o.Add("A", value);
+                    o.WriteTo(writer);
+                    return;
+                }
+
+                throw new InvalidOperationException("unsupported sub-type");
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JObject o = JObject.Load(reader);
+
+                if (o.Count != 1) {
+                    throw new InvalidOperationException("expected exactly one field");
+                }
+
+                foreach (JProperty property in o.Properties()) {
+                    switch (property.Name) {
+                    case "A":
+                        return ReadSubType(property.Value, typeof(A), serializer);
+                    default:
+                        return new Other(o);
+                    }
+                }
+
+                throw new InvalidOperationException("expected exactly one field");
+            }
+
+            private static Object ReadSubType(JToken value, System.Type type, JsonSerializer serializer) {
+                _isInside = true;
+                try {
+                    return serializer.Deserialize(value.CreateReader(), type);
+                } finally {
+                    _isInside = false;
+                }
+            }
+        }
+
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class A : External {
+
+            [JsonConstructor]
+            public A () {}
+
+            public override bool Equals(Object other) {
+                A o = other as A;
+
+                if (o == null) {
+                    return false;
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                return result;
+            }
+
+            public override String ToString() {
+                return "A()";
+            }
+        }
+
+        public class Other : External {
+            public Object Value {
+                get;
+            }
+
+            public Other(Object Value) {
+                this.Value = Value;
+            }
+
+            public override bool Equals(Object other) {
+                Other o = other as Other;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.Value == null) {
+                    if (o.Value != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.Value.Equals(o.Value)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.Value.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Other(");
+                b.Append("Value=");
+                b.Append(this.Value);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+    }
+}
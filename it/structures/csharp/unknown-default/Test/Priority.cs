@@ -0,0 +1,92 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Priority.Json_Net_Converter))]
+    public class Priority {
+        public static readonly Priority LOW = new Priority(1);
+
+        public static readonly Priority HIGH = new Priority(2);
+
+        public int Value {
+            get;
+        }
+
+        private Priority(int Value) {
+            this.Value = Value;
+        }
+
+        public static Priority[] Values() {
+            return new Priority[] {
+                LOW,
+                HIGH
+            };
+        }
+
+        public bool IsOther {
+            get {
+                foreach (Priority v in Values()) {
+                    if (Object.ReferenceEquals(v, this)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+        }
+
+        public override bool Equals(Object other) {
+            Priority o = other as Priority;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (!this.Value.Equals(o.Value)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.Value.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Priority(");
+            b.Append("Value=");
+            b.Append(this.Value);
+            b.Append(")");
+
+            return b.ToString();
+        }
+
+        public class Json_Net_Converter : JsonConverter {
+            public override bool CanConvert(System.Type objectType) {
+                return objectType == typeof(Priority);
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                serializer.Serialize(writer, ((Priority)obj).Value);
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                int value = serializer.Deserialize<int>(reader);
+
+                foreach (Priority v in Priority.Values()) {
+                    if (v.Value.Equals(value)) {
+                        return v;
+                    }
+                }
+
+                return new Priority(value);
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+using Newtonsoft.Json;
+using Newtonsoft.Json.Linq;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonConverter(typeof(Tagged.Json_Net_Converter))]
+    public abstract class Tagged {
+        [JsonProperty("type", Required = Required.DisallowNull)]
+        private String TypeField {
+            get;
+        }
+
+        public Tagged(String TypeField) {
+            this.TypeField = TypeField;
+        }
+
+        public class Json_Net_Converter : JsonConverter {
+            [ThreadStatic]
+            private static bool _isInside;
+            public override bool CanWrite {
+                get { return !_isInside; }
+            }
+            public override bool CanRead {
+                get { return !_isInside; }
+            }
+
+            public override bool CanConvert(System.Type objectType) {
+                return false;
+            }
+
+            public override void WriteJson(JsonWriter writer, Object obj, JsonSerializer serializer) {
+                if (obj is Other) {
+                    serializer.Serialize(writer, ((Other)obj).Value);
+                    return;
+                }
+
+                _isInside = true;
+                try {
+                    serializer.Serialize(writer, obj);
+                } finally {
+                    _isInside = false;
+                }
+            }
+
+            public override Object ReadJson(JsonReader reader, System.Type objectType, Object existingValue, JsonSerializer serializer) {
+                JObject o = JObject.Load(reader);
+                JToken tag = o["type"];
+
+                if (tag == null) {
+                    throw new InvalidOperationException("expected field `type`");
+                }
+
+                switch ((string)tag) {
+                case "foo":
+                    _isInside = true;
+                    try {
+                        return serializer.Deserialize(o.CreateReader(), typeof(A));
+                    } finally {
+                        _isInside = false;
+                    }
+                case "B":
+                    _isInside = true;
+                    try {
+                        return serializer.Deserialize(o.CreateReader(), typeof(B));
+                    } finally {
+                        _isInside = false;
+                    }
+                default:
+                    return new Other(o);
+                }
+            }
+        }
+
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class A : Tagged {
+            [JsonProperty("shared")]
+            public String shared {
+                get;
+            }
+
+            [JsonConstructor]
+            public A (
+                [JsonProperty("type", Required = Required.DisallowNull)] String TypeField,
+                [JsonProperty("shared")] String shared
+            ) : base(TypeField) {
+                this.shared = shared;
+            }
+
+            public override bool Equals(Object other) {
+                A o = other as A;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.shared == null) {
+                    if (o.shared != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.shared.Equals(o.shared)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.shared.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("A(");
+                b.Append("shared=");
+                b.Append(this.shared);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+        [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+        public class B : Tagged {
+            [JsonProperty("shared")]
+            public String shared {
+                get;
+            }
+
+            [JsonProperty("b")]
+            public String b {
+                get;
+            }
+
+            [JsonConstructor]
+            public B (
+                [JsonProperty("type", Required = Required.DisallowNull)] String TypeField,
+                [JsonProperty("shared")] String shared,
+                [JsonProperty("b")] String b
+            ) : base(TypeField) {
+                this.shared = shared;
+                this.b = b;
+            }
+
+            public override bool Equals(Object other) {
+                B o = other as B;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.shared == null) {
+                    if (o.shared != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.shared.Equals(o.shared)) {
+                        return false;
+                    }
+                }
+
+                if (this.b == null) {
+                    if (o.b != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.b.Equals(o.b)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.shared.GetHashCode();
+                result = result * 31 + this.b.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("B(");
+                b.Append("shared=");
+                b.Append(this.shared);
+                b.Append(", ");
+                b.Append("b=");
+                b.Append(this.b);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+
+        /// A sub-type which is not known to this version of the schema.
+        public class Other : Tagged {
+            public Object Value {
+                get;
+            }
+
+            public Other(Object Value) : base(null) {
+                this.Value = Value;
+            }
+
+            public override bool Equals(Object other) {
+                Other o = other as Other;
+
+                if (o == null) {
+                    return false;
+                }
+
+                if (this.Value == null) {
+                    if (o.Value != null) {
+                        return false;
+                    }
+                } else {
+                    if (!this.Value.Equals(o.Value)) {
+                        return false;
+                    }
+                }
+
+                return true;
+            }
+
+            public override int GetHashCode() {
+                int result = 1;
+                result = result * 31 + this.Value.GetHashCode();
+                return result;
+            }
+
+            public override String ToString() {
+                StringBuilder b = new StringBuilder();
+
+                b.Append("Other(");
+                b.Append("Value=");
+                b.Append(this.Value);
+                b.Append(")");
+
+                return b.ToString();
+            }
+        }
+    }
+}
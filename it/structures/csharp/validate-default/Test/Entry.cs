@@ -0,0 +1,129 @@
+using Newtonsoft.Json;
+using System;
+using System.Text;
+
+namespace Test {
+    [JsonObject(ItemNullValueHandling = NullValueHandling.Ignore)]
+    public class Entry {
+        [JsonProperty("name")]
+        public String name {
+            get;
+        }
+
+        [JsonProperty("code")]
+        public String code {
+            get;
+        }
+
+        [JsonProperty("label")]
+        public String label {
+            get;
+        }
+
+        [JsonProperty("count")]
+        public uint? count {
+            get;
+        }
+
+        [JsonProperty("offset")]
+        public long? offset {
+            get;
+        }
+
+        [JsonConstructor]
+        public Entry (
+            [JsonProperty("name")] String name,
+            [JsonProperty("code")] String code,
+            [JsonProperty("label")] String label,
+            [JsonProperty("count")] uint? count,
+            [JsonProperty("offset")] long? offset
+        ) {
+            this.name = name;
+            this.code = code;
+            this.label = label;
+            this.count = count;
+            this.offset = offset;
+        }
+
+        public override bool Equals(Object other) {
+            Entry o = other as Entry;
+
+            if (o == null) {
+                return false;
+            }
+
+            if (this.name == null) {
+                if (o.name != null) {
+                    return false;
+                }
+            } else {
+                if (!this.name.Equals(o.name)) {
+                    return false;
+                }
+            }
+
+            if (this.code == null) {
+                if (o.code != null) {
+                    return false;
+                }
+            } else {
+                if (!this.code.Equals(o.code)) {
+                    return false;
+                }
+            }
+
+            if (this.label == null) {
+                if (o.label != null) {
+                    return false;
+                }
+            } else {
+                if (!this.label.Equals(o.label)) {
+                    return false;
+                }
+            }
+
+            if (!this.count.Equals(o.count)) {
+                return false;
+            }
+
+            if (!this.offset.Equals(o.offset)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public override int GetHashCode() {
+            int result = 1;
+            result = result * 31 + this.name.GetHashCode();
+            result = result * 31 + this.code.GetHashCode();
+            result = result * 31 + this.label.GetHashCode();
+            result = result * 31 + this.count.GetHashCode();
+            result = result * 31 + this.offset.GetHashCode();
+            return result;
+        }
+
+        public override String ToString() {
+            StringBuilder b = new StringBuilder();
+
+            b.Append("Entry(");
+            b.Append("name=");
+            b.Append(this.name);
+            b.Append(", ");
+            b.Append("code=");
+            b.Append(this.code);
+            b.Append(", ");
+            b.Append("label=");
+            b.Append(this.label);
+            b.Append(", ");
+            b.Append("count=");
+            b.Append(this.count);
+            b.Append(", ");
+            b.Append("offset=");
+            b.Append(this.offset);
+            b.Append(")");
+
+            return b.ToString();
+        }
+    }
+}
@@ -0,0 +1,88 @@
+class Entry {
+  int id;
+  String user;
+  List<String> tags;
+  Map<String, int> owners;
+
+  Entry(this.id, this.user, this.tags, this.owners);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var id_dyn = _data["id"];
+
+    int id = null;
+
+    if (id_dyn != null) {
+      if (!(id_dyn is int)) {
+        throw "expected $int, but was: $id_dyn";
+      }
+      id = id_dyn;
+    }
+
+    var user_dyn = _data["user"];
+
+    String user = null;
+
+    if (user_dyn != null) {
+      if (!(user_dyn is String)) {
+        throw "expected $String, but was: $user_dyn";
+      }
+      user = user_dyn;
+    }
+
+    var tags_dyn = _data["tags"];
+
+    List<String> tags = null;
+
+    if (tags_dyn != null) {
+      if (!(tags_dyn is List<dynamic>)) {
+        throw "expected list, but was: $tags_dyn";
+      }
+      tags = List.of((tags_dyn as List<dynamic>).map((e) {
+        return e;
+      }));
+    }
+
+    var owners_dyn = _data["owners"];
+
+    Map<String, int> owners = null;
+
+    if (owners_dyn != null) {
+      if (!(owners_dyn is Map<String, dynamic>)) {
+        throw "expected map, but was: $owners_dyn";
+      }
+      owners = Map.fromEntries((owners_dyn as Map<String, dynamic>).entries.map((e) {
+        return MapEntry(e.key, e.value);
+      }));
+    }
+
+    return Entry(id, user, tags, owners);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.id != null) {
+      _data["id"] = this.id;
+    }
+
+    if (this.user != null) {
+      _data["user"] = this.user;
+    }
+
+    if (this.tags != null) {
+      _data["tags"] = List.from(this.tags.map((e) => e));
+    }
+
+    if (this.owners != null) {
+      _data["owners"] = Map.fromEntries(this.owners.entries.map((e) => MapEntry(e.key, e.value)));
+    }
+
+    return _data;
+  }
+}
@@ -0,0 +1,118 @@
+class Entry {
+  String date;
+  String time;
+  String duration;
+  String uuid;
+  String decimal;
+  List<String> dates;
+
+  Entry(this.date, this.time, this.duration, this.uuid, this.decimal, this.dates);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var date_dyn = _data["date"];
+
+    String date = null;
+
+    if (date_dyn != null) {
+      if (!(date_dyn is String)) {
+        throw "expected $String, but was: $date_dyn";
+      }
+      date = date_dyn;
+    }
+
+    var time_dyn = _data["time"];
+
+    String time = null;
+
+    if (time_dyn != null) {
+      if (!(time_dyn is String)) {
+        throw "expected $String, but was: $time_dyn";
+      }
+      time = time_dyn;
+    }
+
+    var duration_dyn = _data["duration"];
+
+    String duration = null;
+
+    if (duration_dyn != null) {
+      if (!(duration_dyn is String)) {
+        throw "expected $String, but was: $duration_dyn";
+      }
+      duration = duration_dyn;
+    }
+
+    var uuid_dyn = _data["uuid"];
+
+    String uuid = null;
+
+    if (uuid_dyn != null) {
+      if (!(uuid_dyn is String)) {
+        throw "expected $String, but was: $uuid_dyn";
+      }
+      uuid = uuid_dyn;
+    }
+
+    var decimal_dyn = _data["decimal"];
+
+    String decimal = null;
+
+    if (decimal_dyn != null) {
+      if (!(decimal_dyn is String)) {
+        throw "expected $String, but was: $decimal_dyn";
+      }
+      decimal = decimal_dyn;
+    }
+
+    var dates_dyn = _data["dates"];
+
+    List<String> dates = null;
+
+    if (dates_dyn != null) {
+      if (!(dates_dyn is List<dynamic>)) {
+        throw "expected list, but was: $dates_dyn";
+      }
+      dates = List.of((dates_dyn as List<dynamic>).map((e) {
+        return e;
+      }));
+    }
+
+    return Entry(date, time, duration, uuid, decimal, dates);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.date != null) {
+      _data["date"] = this.date;
+    }
+
+    if (this.time != null) {
+      _data["time"] = this.time;
+    }
+
+    if (this.duration != null) {
+      _data["duration"] = this.duration;
+    }
+
+    if (this.uuid != null) {
+      _data["uuid"] = this.uuid;
+    }
+
+    if (this.decimal != null) {
+      _data["decimal"] = this.decimal;
+    }
+
+    if (this.dates != null) {
+      _data["dates"] = List.from(this.dates.map((e) => e));
+    }
+
+    return _data;
+  }
+}
@@ -0,0 +1,150 @@
+class Entry {
+  String standard;
+  String url;
+  String token;
+  String digest;
+  Pair pair;
+
+  Entry(this.standard, this.url, this.token, this.digest, this.pair);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var standard_dyn = _data["standard"];
+
+    String standard = null;
+
+    if (standard_dyn != null) {
+      if (!(standard_dyn is String)) {
+        throw "expected $String, but was: $standard_dyn";
+      }
+      standard = standard_dyn;
+    }
+
+    var url_dyn = _data["url"];
+
+    String url = null;
+
+    if (url_dyn != null) {
+      if (!(url_dyn is String)) {
+        throw "expected $String, but was: $url_dyn";
+      }
+      url = url_dyn;
+    }
+
+    var token_dyn = _data["token"];
+
+    String token = null;
+
+    if (token_dyn != null) {
+      if (!(token_dyn is String)) {
+        throw "expected $String, but was: $token_dyn";
+      }
+      token = token_dyn;
+    }
+
+    var digest_dyn = _data["digest"];
+
+    String digest = null;
+
+    if (digest_dyn != null) {
+      if (!(digest_dyn is String)) {
+        throw "expected $String, but was: $digest_dyn";
+      }
+      digest = digest_dyn;
+    }
+
+    var pair_dyn = _data["pair"];
+
+    Pair pair = null;
+
+    if (pair_dyn != null) {
+      pair = Pair.decode(pair_dyn);
+    }
+
+    return Entry(standard, url, token, digest, pair);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.standard != null) {
+      _data["standard"] = this.standard;
+    }
+
+    if (this.url != null) {
+      _data["url"] = this.url;
+    }
+
+    if (this.token != null) {
+      _data["token"] = this.token;
+    }
+
+    if (this.digest != null) {
+      _data["digest"] = this.digest;
+    }
+
+    if (this.pair != null) {
+      _data["pair"] = this.pair.encode();
+    }
+
+    return _data;
+  }
+}
+
+class Pair {
+  String key;
+  String value;
+
+  Pair(this.key, this.value);
+
+  static Pair decode(dynamic _dataDyn) {
+    if (!(_dataDyn is List<dynamic>)) {
+      throw "expected List<dynamic> but got $_dataDyn";
+    }
+
+    List<dynamic> _data = _dataDyn;
+
+    if (_data.length != 2) {
+      throw "expected array of length 2, but was $_data.length";
+    }
+
+    var key_dyn = _data[0];
+
+    if (key_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(key_dyn is String)) {
+      throw "expected $String, but was: $key_dyn";
+    }
+    final String key = key_dyn;
+
+    var value_dyn = _data[1];
+
+    if (value_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(value_dyn is String)) {
+      throw "expected $String, but was: $value_dyn";
+    }
+    final String value = value_dyn;
+
+    return Pair(key, value);
+  }
+
+  List<dynamic> encode() {
+    List<dynamic> _data = List();
+
+    _data.add(this.key);
+
+    _data.add(this.value);
+
+    return _data;
+  }
+}
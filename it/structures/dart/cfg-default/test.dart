@@ -0,0 +1,92 @@
+class Entry {
+  String id;
+  /// Not available when building for Python or JavaScript.
+  int typed;
+  Color color;
+
+  Entry(this.id, this.typed, this.color);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var id_dyn = _data["id"];
+
+    String id = null;
+
+    if (id_dyn != null) {
+      if (!(id_dyn is String)) {
+        throw "expected $String, but was: $id_dyn";
+      }
+      id = id_dyn;
+    }
+
+    var typed_dyn = _data["typed"];
+
+    int typed = null;
+
+    if (typed_dyn != null) {
+      if (!(typed_dyn is int)) {
+        throw "expected $int, but was: $typed_dyn";
+      }
+      typed = typed_dyn;
+    }
+
+    var color_dyn = _data["color"];
+
+    Color color = null;
+
+    if (color_dyn != null) {
+      color = Color.decode(color_dyn);
+    }
+
+    return Entry(id, typed, color);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.id != null) {
+      _data["id"] = this.id;
+    }
+
+    if (this.typed != null) {
+      _data["typed"] = this.typed;
+    }
+
+    if (this.color != null) {
+      _data["color"] = this.color.encode();
+    }
+
+    return _data;
+  }
+}
+
+class Color {
+  final _value;
+  const Color._new(this._value);
+
+  toString() => "Color.$_value";
+
+  static const Red = const Color._new("red");
+
+  static Color decode(dynamic data) {
+    if (!(data is String)) {
+      throw "expected $String but got $data";
+    }
+
+    switch (data as String) {
+      case "red":
+        return Color.Red;
+      default:
+        throw "unexpected Color value: $data";
+    }
+  }
+
+  String encode() {
+    return _value;
+  }
+}
@@ -0,0 +1,148 @@
+class Entry {
+  Map<Color, int> byColor;
+  Map<int, String> byId;
+  List<String> tags;
+  List<Level> levels;
+
+  Entry(this.byColor, this.byId, this.tags, this.levels);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var byColor_dyn = _data["by_color"];
+
+    Map<Color, int> byColor = null;
+
+    if (byColor_dyn != null) {
+      if (!(byColor_dyn is Map<String, dynamic>)) {
+        throw "expected map, but was: $byColor_dyn";
+      }
+      byColor = Map.fromEntries((byColor_dyn as Map<String, dynamic>).entries.map((e) {
+        return MapEntry(Color.decode(e.key), e.value);
+      }));
+    }
+
+    var byId_dyn = _data["by_id"];
+
+    Map<int, String> byId = null;
+
+    if (byId_dyn != null) {
+      if (!(byId_dyn is Map<String, dynamic>)) {
+        throw "expected map, but was: $byId_dyn";
+      }
+      byId = Map.fromEntries((byId_dyn as Map<String, dynamic>).entries.map((e) {
+        return MapEntry(int.parse(e.key), e.value);
+      }));
+    }
+
+    var tags_dyn = _data["tags"];
+
+    List<String> tags = null;
+
+    if (tags_dyn != null) {
+      if (!(tags_dyn is List<dynamic>)) {
+        throw "expected list, but was: $tags_dyn";
+      }
+      tags = List.of((tags_dyn as List<dynamic>).map((e) {
+        return e;
+      }));
+    }
+
+    var levels_dyn = _data["levels"];
+
+    List<Level> levels = null;
+
+    if (levels_dyn != null) {
+      if (!(levels_dyn is List<dynamic>)) {
+        throw "expected list, but was: $levels_dyn";
+      }
+      levels = List.of((levels_dyn as List<dynamic>).map((e) => Level.decode(e)));
+    }
+
+    return Entry(byColor, byId, tags, levels);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.byColor != null) {
+      _data["by_color"] = Map.fromEntries(this.byColor.entries.map((e) => MapEntry(e.key.encode(), e.value)));
+    }
+
+    if (this.byId != null) {
+      _data["by_id"] = Map.fromEntries(this.byId.entries.map((e) => MapEntry(e.key.toString(), e.value)));
+    }
+
+    if (this.tags != null) {
+      _data["tags"] = List.from(this.tags.map((e) => e));
+    }
+
+    if (this.levels != null) {
+      _data["levels"] = List.from(this.levels.map((e) => e.encode()));
+    }
+
+    return _data;
+  }
+}
+
+class Color {
+  final _value;
+  const Color._new(this._value);
+
+  toString() => "Color.$_value";
+
+  static const Red = const Color._new("red");
+  static const Green = const Color._new("green");
+
+  static Color decode(dynamic data) {
+    if (!(data is String)) {
+      throw "expected $String but got $data";
+    }
+
+    switch (data as String) {
+      case "red":
+        return Color.Red;
+      case "green":
+        return Color.Green;
+      default:
+        throw "unexpected Color value: $data";
+    }
+  }
+
+  String encode() {
+    return _value;
+  }
+}
+
+class Level {
+  final _value;
+  const Level._new(this._value);
+
+  toString() => "Level.$_value";
+
+  static const Low = const Level._new(1);
+  static const High = const Level._new(2);
+
+  static Level decode(dynamic data) {
+    if (!(data is int)) {
+      throw "expected $int but got $data";
+    }
+
+    switch (data as int) {
+      case 1:
+        return Level.Low;
+      case 2:
+        return Level.High;
+      default:
+        throw "unexpected Level value: $data";
+    }
+  }
+
+  int encode() {
+    return _value;
+  }
+}
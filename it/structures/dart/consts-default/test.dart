@@ -0,0 +1,55 @@
+/// The default number of entries to return.
+const int DefaultLimit = 100;
+
+const int MaxOffset = -1;
+
+const String Greeting = "hello";
+
+const double Ratio = 0.5;
+
+const double Scale = 2;
+
+const bool Enabled = true;
+
+@Deprecated("deprecated: use DefaultLimit")
+const int OldLimit = 10;
+
+class Entry {
+  int limit;
+
+  Entry(this.limit);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var limit_dyn = _data["limit"];
+
+    int limit = null;
+
+    if (limit_dyn != null) {
+      if (!(limit_dyn is int)) {
+        throw "expected $int, but was: $limit_dyn";
+      }
+      limit = limit_dyn;
+    }
+
+    return Entry(limit);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.limit != null) {
+      _data["limit"] = this.limit;
+    }
+
+    return _data;
+  }
+}
+
+/// Maximum number of entries in a single page.
+const int Entry_PageSize = 20;
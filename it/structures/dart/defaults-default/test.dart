@@ -0,0 +1,205 @@
+class Entry {
+  String name;
+  int count;
+  int big;
+  double ratio;
+  double scale;
+  bool enabled;
+  String label;
+  Shape shape;
+
+  Entry(this.name, this.count, this.big, this.ratio, this.scale, this.enabled, this.label, this.shape);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var name_dyn = _data["name"];
+
+    String name = null;
+
+    if (name_dyn != null) {
+      if (!(name_dyn is String)) {
+        throw "expected $String, but was: $name_dyn";
+      }
+      name = name_dyn;
+    }
+
+    var count_dyn = _data["count"];
+
+    int count = null;
+
+    if (count_dyn != null) {
+      if (!(count_dyn is int)) {
+        throw "expected $int, but was: $count_dyn";
+      }
+      count = count_dyn;
+    }
+
+    var big_dyn = _data["big"];
+
+    int big = null;
+
+    if (big_dyn != null) {
+      if (!(big_dyn is int)) {
+        throw "expected $int, but was: $big_dyn";
+      }
+      big = big_dyn;
+    }
+
+    var ratio_dyn = _data["ratio"];
+
+    double ratio = null;
+
+    if (ratio_dyn != null) {
+      if (!(ratio_dyn is double)) {
+        throw "expected $double, but was: $ratio_dyn";
+      }
+      ratio = ratio_dyn;
+    }
+
+    var scale_dyn = _data["scale"];
+
+    double scale = null;
+
+    if (scale_dyn != null) {
+      if (!(scale_dyn is double)) {
+        throw "expected $double, but was: $scale_dyn";
+      }
+      scale = scale_dyn;
+    }
+
+    var enabled_dyn = _data["enabled"];
+
+    bool enabled = null;
+
+    if (enabled_dyn != null) {
+      if (!(enabled_dyn is bool)) {
+        throw "expected $bool, but was: $enabled_dyn";
+      }
+      enabled = enabled_dyn;
+    }
+
+    var label_dyn = _data["label"];
+
+    String label = null;
+
+    if (label_dyn != null) {
+      if (!(label_dyn is String)) {
+        throw "expected $String, but was: $label_dyn";
+      }
+      label = label_dyn;
+    }
+
+    var shape_dyn = _data["shape"];
+
+    Shape shape = null;
+
+    if (shape_dyn != null) {
+      shape = Shape.decode(shape_dyn);
+    }
+
+    return Entry(name, count, big, ratio, scale, enabled, label, shape);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.name != null) {
+      _data["name"] = this.name;
+    }
+
+    if (this.count != null) {
+      _data["count"] = this.count;
+    }
+
+    if (this.big != null) {
+      _data["big"] = this.big;
+    }
+
+    if (this.ratio != null) {
+      _data["ratio"] = this.ratio;
+    }
+
+    if (this.scale != null) {
+      _data["scale"] = this.scale;
+    }
+
+    if (this.enabled != null) {
+      _data["enabled"] = this.enabled;
+    }
+
+    if (this.label != null) {
+      _data["label"] = this.label;
+    }
+
+    if (this.shape != null) {
+      _data["shape"] = this.shape.encode();
+    }
+
+    return _data;
+  }
+}
+
+abstract class Shape {
+  static Shape decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["type"];
+
+    switch (tag) {
+      case "Circle":
+        return Shape_Circle.decode(_data);
+      default:
+        throw "bad tag: $tag";
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class Shape_Circle extends Shape {
+  double radius;
+
+  Shape_Circle(this.radius);
+
+  static Shape_Circle decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var radius_dyn = _data["radius"];
+
+    double radius = null;
+
+    if (radius_dyn != null) {
+      if (!(radius_dyn is double)) {
+        throw "expected $double, but was: $radius_dyn";
+      }
+      radius = radius_dyn;
+    }
+
+    return Shape_Circle(radius);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "Circle";
+
+    if (this.radius != null) {
+      _data["radius"] = this.radius;
+    }
+
+    return _data;
+  }
+}
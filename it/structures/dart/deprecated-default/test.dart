@@ -0,0 +1,340 @@
+class Entry {
+  /// Kept for compatibility.
+  @Deprecated("deprecated since 1.1.0: use `current` instead")
+  Legacy legacy;
+  Current current;
+  Status status;
+  Point point;
+  Shape shape;
+
+  Entry(this.legacy, this.current, this.status, this.point, this.shape);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var legacy_dyn = _data["legacy"];
+
+    Legacy legacy = null;
+
+    if (legacy_dyn != null) {
+      legacy = Legacy.decode(legacy_dyn);
+    }
+
+    var current_dyn = _data["current"];
+
+    Current current = null;
+
+    if (current_dyn != null) {
+      current = Current.decode(current_dyn);
+    }
+
+    var status_dyn = _data["status"];
+
+    Status status = null;
+
+    if (status_dyn != null) {
+      status = Status.decode(status_dyn);
+    }
+
+    var point_dyn = _data["point"];
+
+    Point point = null;
+
+    if (point_dyn != null) {
+      point = Point.decode(point_dyn);
+    }
+
+    var shape_dyn = _data["shape"];
+
+    Shape shape = null;
+
+    if (shape_dyn != null) {
+      shape = Shape.decode(shape_dyn);
+    }
+
+    return Entry(legacy, current, status, point, shape);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.legacy != null) {
+      _data["legacy"] = this.legacy.encode();
+    }
+
+    if (this.current != null) {
+      _data["current"] = this.current.encode();
+    }
+
+    if (this.status != null) {
+      _data["status"] = this.status.encode();
+    }
+
+    if (this.point != null) {
+      _data["point"] = this.point.encode();
+    }
+
+    if (this.shape != null) {
+      _data["shape"] = this.shape.encode();
+    }
+
+    return _data;
+  }
+}
+
+@Deprecated("deprecated: use `Current` instead")
+class Legacy {
+  String value;
+
+  Legacy(this.value);
+
+  static Legacy decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var value_dyn = _data["value"];
+
+    if (value_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(value_dyn is String)) {
+      throw "expected $String, but was: $value_dyn";
+    }
+    final String value = value_dyn;
+
+    return Legacy(value);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["value"] = this.value;
+
+    return _data;
+  }
+}
+
+class Current {
+  String value;
+
+  Current(this.value);
+
+  static Current decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var value_dyn = _data["value"];
+
+    if (value_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(value_dyn is String)) {
+      throw "expected $String, but was: $value_dyn";
+    }
+    final String value = value_dyn;
+
+    return Current(value);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["value"] = this.value;
+
+    return _data;
+  }
+}
+
+class Status {
+  final _value;
+  const Status._new(this._value);
+
+  toString() => "Status.$_value";
+
+  static const Active = const Status._new("active");
+  @Deprecated("deprecated")
+  static const Retired = const Status._new("retired");
+
+  static Status decode(dynamic data) {
+    if (!(data is String)) {
+      throw "expected $String but got $data";
+    }
+
+    switch (data as String) {
+      case "active":
+        return Status.Active;
+      case "retired":
+        return Status.Retired;
+      default:
+        throw "unexpected Status value: $data";
+    }
+  }
+
+  String encode() {
+    return _value;
+  }
+}
+
+@Deprecated("deprecated since 1.0.0")
+class Point {
+  int x;
+  int y;
+
+  Point(this.x, this.y);
+
+  static Point decode(dynamic _dataDyn) {
+    if (!(_dataDyn is List<dynamic>)) {
+      throw "expected List<dynamic> but got $_dataDyn";
+    }
+
+    List<dynamic> _data = _dataDyn;
+
+    if (_data.length != 2) {
+      throw "expected array of length 2, but was $_data.length";
+    }
+
+    var x_dyn = _data[0];
+
+    if (x_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(x_dyn is int)) {
+      throw "expected $int, but was: $x_dyn";
+    }
+    final int x = x_dyn;
+
+    var y_dyn = _data[1];
+
+    if (y_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(y_dyn is int)) {
+      throw "expected $int, but was: $y_dyn";
+    }
+    final int y = y_dyn;
+
+    return Point(x, y);
+  }
+
+  List<dynamic> encode() {
+    List<dynamic> _data = List();
+
+    _data.add(this.x);
+
+    _data.add(this.y);
+
+    return _data;
+  }
+}
+
+abstract class Shape {
+  static Shape decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["type"];
+
+    switch (tag) {
+      case "Circle":
+        return Shape_Circle.decode(_data);
+      case "Round":
+        return Shape_Round.decode(_data);
+      default:
+        throw "bad tag: $tag";
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class Shape_Circle extends Shape {
+  double radius;
+
+  Shape_Circle(this.radius);
+
+  static Shape_Circle decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var radius_dyn = _data["radius"];
+
+    if (radius_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(radius_dyn is double)) {
+      throw "expected $double, but was: $radius_dyn";
+    }
+    final double radius = radius_dyn;
+
+    return Shape_Circle(radius);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "Circle";
+
+    _data["radius"] = this.radius;
+
+    return _data;
+  }
+}
+
+@Deprecated("deprecated: use `Circle` instead")
+class Shape_Round extends Shape {
+  double radius;
+
+  Shape_Round(this.radius);
+
+  static Shape_Round decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var radius_dyn = _data["radius"];
+
+    if (radius_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(radius_dyn is double)) {
+      throw "expected $double, but was: $radius_dyn";
+    }
+    final double radius = radius_dyn;
+
+    return Shape_Round(radius);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "Round";
+
+    _data["radius"] = this.radius;
+
+    return _data;
+  }
+}
@@ -0,0 +1,353 @@
+class Entry {
+  PageItem page;
+  PageString names;
+  PairItemU32 pair;
+  OutcomeItem outcome;
+
+  Entry(this.page, this.names, this.pair, this.outcome);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var page_dyn = _data["page"];
+
+    PageItem page = null;
+
+    if (page_dyn != null) {
+      page = PageItem.decode(page_dyn);
+    }
+
+    var names_dyn = _data["names"];
+
+    PageString names = null;
+
+    if (names_dyn != null) {
+      names = PageString.decode(names_dyn);
+    }
+
+    var pair_dyn = _data["pair"];
+
+    PairItemU32 pair = null;
+
+    if (pair_dyn != null) {
+      pair = PairItemU32.decode(pair_dyn);
+    }
+
+    var outcome_dyn = _data["outcome"];
+
+    OutcomeItem outcome = null;
+
+    if (outcome_dyn != null) {
+      outcome = OutcomeItem.decode(outcome_dyn);
+    }
+
+    return Entry(page, names, pair, outcome);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.page != null) {
+      _data["page"] = this.page.encode();
+    }
+
+    if (this.names != null) {
+      _data["names"] = this.names.encode();
+    }
+
+    if (this.pair != null) {
+      _data["pair"] = this.pair.encode();
+    }
+
+    if (this.outcome != null) {
+      _data["outcome"] = this.outcome.encode();
+    }
+
+    return _data;
+  }
+}
+
+class Item {
+  String name;
+
+  Item(this.name);
+
+  static Item decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var name_dyn = _data["name"];
+
+    if (name_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(name_dyn is String)) {
+      throw "expected $String, but was: $name_dyn";
+    }
+    final String name = name_dyn;
+
+    return Item(name);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["name"] = this.name;
+
+    return _data;
+  }
+}
+
+/// A single page of results.
+class PageItem {
+  List<Item> items;
+  String next;
+
+  PageItem(this.items, this.next);
+
+  static PageItem decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var items_dyn = _data["items"];
+
+    if (items_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(items_dyn is List<dynamic>)) {
+      throw "expected list, but was: $items_dyn";
+    }
+    final List<Item> items = List.of((items_dyn as List<dynamic>).map((e) => Item.decode(e)));
+
+    var next_dyn = _data["next"];
+
+    String next = null;
+
+    if (next_dyn != null) {
+      if (!(next_dyn is String)) {
+        throw "expected $String, but was: $next_dyn";
+      }
+      next = next_dyn;
+    }
+
+    return PageItem(items, next);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["items"] = List.from(this.items.map((e) => e.encode()));
+
+    if (this.next != null) {
+      _data["next"] = this.next;
+    }
+
+    return _data;
+  }
+}
+
+/// A single page of results.
+class PageString {
+  List<String> items;
+  String next;
+
+  PageString(this.items, this.next);
+
+  static PageString decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var items_dyn = _data["items"];
+
+    if (items_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(items_dyn is List<dynamic>)) {
+      throw "expected list, but was: $items_dyn";
+    }
+    final List<String> items = List.of((items_dyn as List<dynamic>).map((e) {
+      return e;
+    }));
+
+    var next_dyn = _data["next"];
+
+    String next = null;
+
+    if (next_dyn != null) {
+      if (!(next_dyn is String)) {
+        throw "expected $String, but was: $next_dyn";
+      }
+      next = next_dyn;
+    }
+
+    return PageString(items, next);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["items"] = List.from(this.items.map((e) => e));
+
+    if (this.next != null) {
+      _data["next"] = this.next;
+    }
+
+    return _data;
+  }
+}
+
+class PairItemU32 {
+  Item first;
+  int second;
+
+  PairItemU32(this.first, this.second);
+
+  static PairItemU32 decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var first_dyn = _data["first"];
+
+    if (first_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    final Item first = Item.decode(first_dyn);
+
+    var second_dyn = _data["second"];
+
+    if (second_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(second_dyn is int)) {
+      throw "expected $int, but was: $second_dyn";
+    }
+    final int second = second_dyn;
+
+    return PairItemU32(first, second);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["first"] = this.first.encode();
+
+    _data["second"] = this.second;
+
+    return _data;
+  }
+}
+
+abstract class OutcomeItem {
+  static OutcomeItem decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["type"];
+
+    switch (tag) {
+      case "Ok":
+        return OutcomeItem_Ok.decode(_data);
+      case "Error":
+        return OutcomeItem_Error.decode(_data);
+      default:
+        throw "bad tag: $tag";
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class OutcomeItem_Ok extends OutcomeItem {
+  Item value;
+
+  OutcomeItem_Ok(this.value);
+
+  static OutcomeItem_Ok decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var value_dyn = _data["value"];
+
+    if (value_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    final Item value = Item.decode(value_dyn);
+
+    return OutcomeItem_Ok(value);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "Ok";
+
+    _data["value"] = this.value.encode();
+
+    return _data;
+  }
+}
+
+class OutcomeItem_Error extends OutcomeItem {
+  String message;
+
+  OutcomeItem_Error(this.message);
+
+  static OutcomeItem_Error decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var message_dyn = _data["message"];
+
+    if (message_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(message_dyn is String)) {
+      throw "expected $String, but was: $message_dyn";
+    }
+    final String message = message_dyn;
+
+    return OutcomeItem_Error(message);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "Error";
+
+    _data["message"] = this.message;
+
+    return _data;
+  }
+}
@@ -0,0 +1,262 @@
+class Entry {
+  String createdAt;
+  String updatedAt;
+  /// Who last modified the entry.
+  String updatedBy;
+  String id;
+  String name;
+
+  Entry(this.createdAt, this.updatedAt, this.updatedBy, this.id, this.name);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var createdAt_dyn = _data["created_at"];
+
+    String createdAt = null;
+
+    if (createdAt_dyn != null) {
+      if (!(createdAt_dyn is String)) {
+        throw "expected $String, but was: $createdAt_dyn";
+      }
+      createdAt = createdAt_dyn;
+    }
+
+    var updatedAt_dyn = _data["updated_at"];
+
+    String updatedAt = null;
+
+    if (updatedAt_dyn != null) {
+      if (!(updatedAt_dyn is String)) {
+        throw "expected $String, but was: $updatedAt_dyn";
+      }
+      updatedAt = updatedAt_dyn;
+    }
+
+    var updatedBy_dyn = _data["updated_by"];
+
+    String updatedBy = null;
+
+    if (updatedBy_dyn != null) {
+      if (!(updatedBy_dyn is String)) {
+        throw "expected $String, but was: $updatedBy_dyn";
+      }
+      updatedBy = updatedBy_dyn;
+    }
+
+    var id_dyn = _data["id"];
+
+    String id = null;
+
+    if (id_dyn != null) {
+      if (!(id_dyn is String)) {
+        throw "expected $String, but was: $id_dyn";
+      }
+      id = id_dyn;
+    }
+
+    var name_dyn = _data["name"];
+
+    String name = null;
+
+    if (name_dyn != null) {
+      if (!(name_dyn is String)) {
+        throw "expected $String, but was: $name_dyn";
+      }
+      name = name_dyn;
+    }
+
+    return Entry(createdAt, updatedAt, updatedBy, id, name);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.createdAt != null) {
+      _data["created_at"] = this.createdAt;
+    }
+
+    if (this.updatedAt != null) {
+      _data["updated_at"] = this.updatedAt;
+    }
+
+    if (this.updatedBy != null) {
+      _data["updated_by"] = this.updatedBy;
+    }
+
+    if (this.id != null) {
+      _data["id"] = this.id;
+    }
+
+    if (this.name != null) {
+      _data["name"] = this.name;
+    }
+
+    return _data;
+  }
+}
+
+/// Fields shared by all audited types.
+class Auditable {
+  String createdAt;
+  String updatedAt;
+  /// Who last modified the entry.
+  String updatedBy;
+
+  Auditable(this.createdAt, this.updatedAt, this.updatedBy);
+
+  static Auditable decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var createdAt_dyn = _data["created_at"];
+
+    String createdAt = null;
+
+    if (createdAt_dyn != null) {
+      if (!(createdAt_dyn is String)) {
+        throw "expected $String, but was: $createdAt_dyn";
+      }
+      createdAt = createdAt_dyn;
+    }
+
+    var updatedAt_dyn = _data["updated_at"];
+
+    String updatedAt = null;
+
+    if (updatedAt_dyn != null) {
+      if (!(updatedAt_dyn is String)) {
+        throw "expected $String, but was: $updatedAt_dyn";
+      }
+      updatedAt = updatedAt_dyn;
+    }
+
+    var updatedBy_dyn = _data["updated_by"];
+
+    String updatedBy = null;
+
+    if (updatedBy_dyn != null) {
+      if (!(updatedBy_dyn is String)) {
+        throw "expected $String, but was: $updatedBy_dyn";
+      }
+      updatedBy = updatedBy_dyn;
+    }
+
+    return Auditable(createdAt, updatedAt, updatedBy);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.createdAt != null) {
+      _data["created_at"] = this.createdAt;
+    }
+
+    if (this.updatedAt != null) {
+      _data["updated_at"] = this.updatedAt;
+    }
+
+    if (this.updatedBy != null) {
+      _data["updated_by"] = this.updatedBy;
+    }
+
+    return _data;
+  }
+}
+
+class Timestamped {
+  String createdAt;
+  String updatedAt;
+
+  Timestamped(this.createdAt, this.updatedAt);
+
+  static Timestamped decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var createdAt_dyn = _data["created_at"];
+
+    String createdAt = null;
+
+    if (createdAt_dyn != null) {
+      if (!(createdAt_dyn is String)) {
+        throw "expected $String, but was: $createdAt_dyn";
+      }
+      createdAt = createdAt_dyn;
+    }
+
+    var updatedAt_dyn = _data["updated_at"];
+
+    String updatedAt = null;
+
+    if (updatedAt_dyn != null) {
+      if (!(updatedAt_dyn is String)) {
+        throw "expected $String, but was: $updatedAt_dyn";
+      }
+      updatedAt = updatedAt_dyn;
+    }
+
+    return Timestamped(createdAt, updatedAt);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.createdAt != null) {
+      _data["created_at"] = this.createdAt;
+    }
+
+    if (this.updatedAt != null) {
+      _data["updated_at"] = this.updatedAt;
+    }
+
+    return _data;
+  }
+}
+
+class Identified {
+  String id;
+
+  Identified(this.id);
+
+  static Identified decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var id_dyn = _data["id"];
+
+    String id = null;
+
+    if (id_dyn != null) {
+      if (!(id_dyn is String)) {
+        throw "expected $String, but was: $id_dyn";
+      }
+      id = id_dyn;
+    }
+
+    return Identified(id);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.id != null) {
+      _data["id"] = this.id;
+    }
+
+    return _data;
+  }
+}
@@ -0,0 +1,148 @@
+class Entry {
+  int id;
+  int offset;
+  int size;
+  int count;
+  Pair pair;
+
+  Entry(this.id, this.offset, this.size, this.count, this.pair);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var id_dyn = _data["id"];
+
+    if (id_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(id_dyn is String)) {
+      throw "expected String, but was: $id_dyn";
+    }
+    final int id = int.parse(id_dyn);
+
+    var offset_dyn = _data["offset"];
+
+    int offset = null;
+
+    if (offset_dyn != null) {
+      if (!(offset_dyn is String)) {
+        throw "expected String, but was: $offset_dyn";
+      }
+      offset = int.parse(offset_dyn);
+    }
+
+    var size_dyn = _data["size"];
+
+    int size = null;
+
+    if (size_dyn != null) {
+      if (!(size_dyn is int)) {
+        throw "expected $int, but was: $size_dyn";
+      }
+      size = size_dyn;
+    }
+
+    var count_dyn = _data["count"];
+
+    int count = null;
+
+    if (count_dyn != null) {
+      if (!(count_dyn is int)) {
+        throw "expected $int, but was: $count_dyn";
+      }
+      count = count_dyn;
+    }
+
+    var pair_dyn = _data["pair"];
+
+    Pair pair = null;
+
+    if (pair_dyn != null) {
+      pair = Pair.decode(pair_dyn);
+    }
+
+    return Entry(id, offset, size, count, pair);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["id"] = this.id.toString();
+
+    if (this.offset != null) {
+      _data["offset"] = this.offset.toString();
+    }
+
+    if (this.size != null) {
+      _data["size"] = this.size;
+    }
+
+    if (this.count != null) {
+      _data["count"] = this.count;
+    }
+
+    if (this.pair != null) {
+      _data["pair"] = this.pair.encode();
+    }
+
+    return _data;
+  }
+}
+
+class Pair {
+  int left;
+  int right;
+
+  Pair(this.left, this.right);
+
+  static Pair decode(dynamic _dataDyn) {
+    if (!(_dataDyn is List<dynamic>)) {
+      throw "expected List<dynamic> but got $_dataDyn";
+    }
+
+    List<dynamic> _data = _dataDyn;
+
+    if (_data.length != 2) {
+      throw "expected array of length 2, but was $_data.length";
+    }
+
+    var left_dyn = _data[0];
+
+    if (left_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(left_dyn is String)) {
+      throw "expected String, but was: $left_dyn";
+    }
+    final int left = int.parse(left_dyn);
+
+    var right_dyn = _data[1];
+
+    if (right_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(right_dyn is int)) {
+      throw "expected $int, but was: $right_dyn";
+    }
+    final int right = right_dyn;
+
+    return Pair(left, right);
+  }
+
+  List<dynamic> encode() {
+    List<dynamic> _data = List();
+
+    _data.add(this.left.toString());
+
+    _data.add(this.right);
+
+    return _data;
+  }
+}
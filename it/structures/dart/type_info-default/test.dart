@@ -0,0 +1,321 @@
+class Entry {
+  Adjacent adjacent;
+  AdjacentDefault adjacentDefault;
+  External external_;
+
+  Entry(this.adjacent, this.adjacentDefault, this.external_);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var adjacent_dyn = _data["adjacent"];
+
+    Adjacent adjacent = null;
+
+    if (adjacent_dyn != null) {
+      adjacent = Adjacent.decode(adjacent_dyn);
+    }
+
+    var adjacentDefault_dyn = _data["adjacent_default"];
+
+    AdjacentDefault adjacentDefault = null;
+
+    if (adjacentDefault_dyn != null) {
+      adjacentDefault = AdjacentDefault.decode(adjacentDefault_dyn);
+    }
+
+    var external__dyn = _data["external"];
+
+    External external_ = null;
+
+    if (external__dyn != null) {
+      external_ = External.decode(external__dyn);
+    }
+
+    return Entry(adjacent, adjacentDefault, external_);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.adjacent != null) {
+      _data["adjacent"] = this.adjacent.encode();
+    }
+
+    if (this.adjacentDefault != null) {
+      _data["adjacent_default"] = this.adjacentDefault.encode();
+    }
+
+    if (this.external_ != null) {
+      _data["external"] = this.external_.encode();
+    }
+
+    return _data;
+  }
+}
+
+abstract class Adjacent {
+  static Adjacent decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["kind"];
+    var content = _data["value"];
+
+    switch (tag) {
+      case "foo":
+        return Adjacent_A.decode(content);
+      case "B":
+        return Adjacent_B.decode(content);
+      default:
+        throw "bad tag: $tag";
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class Adjacent_A extends Adjacent {
+  String shared;
+
+  Adjacent_A(this.shared);
+
+  static Adjacent_A decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var shared_dyn = _data["shared"];
+
+    if (shared_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(shared_dyn is String)) {
+      throw "expected $String, but was: $shared_dyn";
+    }
+    final String shared = shared_dyn;
+
+    return Adjacent_A(shared);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["shared"] = this.shared;
+
+    return {"kind": "foo", "value": _data};
+  }
+}
+
+class Adjacent_B extends Adjacent {
+  String shared;
+  String b;
+
+  Adjacent_B(this.shared, this.b);
+
+  static Adjacent_B decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var shared_dyn = _data["shared"];
+
+    if (shared_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(shared_dyn is String)) {
+      throw "expected $String, but was: $shared_dyn";
+    }
+    final String shared = shared_dyn;
+
+    var b_dyn = _data["b"];
+
+    if (b_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(b_dyn is String)) {
+      throw "expected $String, but was: $b_dyn";
+    }
+    final String b = b_dyn;
+
+    return Adjacent_B(shared, b);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["shared"] = this.shared;
+
+    _data["b"] = this.b;
+
+    return {"kind": "B", "value": _data};
+  }
+}
+
+abstract class AdjacentDefault {
+  static AdjacentDefault decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["type"];
+    var content = _data["data"];
+
+    switch (tag) {
+      case "A":
+        return AdjacentDefault_A.decode(content);
+      default:
+        throw "bad tag: $tag";
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class AdjacentDefault_A extends AdjacentDefault {
+
+  AdjacentDefault_A();
+
+  static AdjacentDefault_A decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    return AdjacentDefault_A();
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    return {"type": "A", "data": _data};
+  }
+}
+
+abstract class External {
+  static External decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    if (_data.length != 1) {
+      throw "expected exactly one key";
+    }
+
+    var tag = _data.keys.first;
+
+    switch (tag) {
+      case "foo":
+        return External_A.decode(_data[tag]);
+      case "B":
+        return External_B.decode(_data[tag]);
+      default:
+        throw "bad tag: $tag";
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class External_A extends External {
+  String shared;
+
+  External_A(this.shared);
+
+  static External_A decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var shared_dyn = _data["shared"];
+
+    if (shared_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(shared_dyn is String)) {
+      throw "expected $String, but was: $shared_dyn";
+    }
+    final String shared = shared_dyn;
+
+    return External_A(shared);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["shared"] = this.shared;
+
+    return {"foo": _data};
+  }
+}
+
+class External_B extends External {
+  String shared;
+  String b;
+
+  External_B(this.shared, this.b);
+
+  static External_B decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var shared_dyn = _data["shared"];
+
+    if (shared_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(shared_dyn is String)) {
+      throw "expected $String, but was: $shared_dyn";
+    }
+    final String shared = shared_dyn;
+
+    var b_dyn = _data["b"];
+
+    if (b_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(b_dyn is String)) {
+      throw "expected $String, but was: $b_dyn";
+    }
+    final String b = b_dyn;
+
+    return External_B(shared, b);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["shared"] = this.shared;
+
+    _data["b"] = this.b;
+
+    return {"B": _data};
+  }
+}
@@ -0,0 +1,219 @@
+class Point {
+  double x;
+  double y;
+
+  Point(this.x, this.y);
+
+  static Point decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var x_dyn = _data["x"];
+
+    if (x_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(x_dyn is double)) {
+      throw "expected $double, but was: $x_dyn";
+    }
+    final double x = x_dyn;
+
+    var y_dyn = _data["y"];
+
+    if (y_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(y_dyn is double)) {
+      throw "expected $double, but was: $y_dyn";
+    }
+    final double y = y_dyn;
+
+    return Point(x, y);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["x"] = this.x;
+
+    _data["y"] = this.y;
+
+    return _data;
+  }
+}
+
+class Entry {
+  /// Either a name or a numeric identifier.
+  Entry_Value value;
+  Entry_Shape shape;
+  Entry_Flag flag;
+
+  Entry(this.value, this.shape, this.flag);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var value_dyn = _data["value"];
+
+    Entry_Value value = null;
+
+    if (value_dyn != null) {
+      value = Entry_Value.decode(value_dyn);
+    }
+
+    var shape_dyn = _data["shape"];
+
+    Entry_Shape shape = null;
+
+    if (shape_dyn != null) {
+      shape = Entry_Shape.decode(shape_dyn);
+    }
+
+    var flag_dyn = _data["flag"];
+
+    Entry_Flag flag = null;
+
+    if (flag_dyn != null) {
+      flag = Entry_Flag.decode(flag_dyn);
+    }
+
+    return Entry(value, shape, flag);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.value != null) {
+      _data["value"] = this.value.encode();
+    }
+
+    if (this.shape != null) {
+      _data["shape"] = this.shape.encode();
+    }
+
+    if (this.flag != null) {
+      _data["flag"] = this.flag.encode();
+    }
+
+    return _data;
+  }
+}
+
+class Entry_Value {
+  String stringValue;
+  int u64Value;
+
+  Entry_Value({this.stringValue, this.u64Value});
+
+  static Entry_Value decode(dynamic _data) {
+    if (_data is String) {
+      if (!(_data is String)) {
+        throw "expected $String, but was: $_data";
+      }
+      return Entry_Value(stringValue: _data);
+    }
+
+    if (_data is int) {
+      if (!(_data is int)) {
+        throw "expected $int, but was: $_data";
+      }
+      return Entry_Value(u64Value: _data);
+    }
+
+    throw "no alternative of the union matches the value";
+  }
+
+  dynamic encode() {
+    if (this.stringValue != null) {
+      return this.stringValue;
+    }
+
+    if (this.u64Value != null) {
+      return this.u64Value;
+    }
+
+    throw "no alternative of the union is set";
+  }
+}
+
+class Entry_Shape {
+  Point pointValue;
+  List<double> doubleArrayValue;
+
+  Entry_Shape({this.pointValue, this.doubleArrayValue});
+
+  static Entry_Shape decode(dynamic _data) {
+    if (_data is Map<String, dynamic>) {
+      return Entry_Shape(pointValue: Point.decode(_data));
+    }
+
+    if (_data is List<dynamic>) {
+      if (!(_data is List<dynamic>)) {
+        throw "expected list, but was: $_data";
+      }
+      return Entry_Shape(doubleArrayValue: List.of((_data as List<dynamic>).map((e) {
+        return e;
+      })));
+    }
+
+    throw "no alternative of the union matches the value";
+  }
+
+  dynamic encode() {
+    if (this.pointValue != null) {
+      return this.pointValue.encode();
+    }
+
+    if (this.doubleArrayValue != null) {
+      return List.from(this.doubleArrayValue.map((e) => e));
+    }
+
+    throw "no alternative of the union is set";
+  }
+}
+
+class Entry_Flag {
+  bool booleanValue;
+  String stringValue;
+
+  Entry_Flag({this.booleanValue, this.stringValue});
+
+  static Entry_Flag decode(dynamic _data) {
+    if (_data is bool) {
+      if (!(_data is bool)) {
+        throw "expected $bool, but was: $_data";
+      }
+      return Entry_Flag(booleanValue: _data);
+    }
+
+    if (_data is String) {
+      if (!(_data is String)) {
+        throw "expected $String, but was: $_data";
+      }
+      return Entry_Flag(stringValue: _data);
+    }
+
+    throw "no alternative of the union matches the value";
+  }
+
+  dynamic encode() {
+    if (this.booleanValue != null) {
+      return this.booleanValue;
+    }
+
+    if (this.stringValue != null) {
+      return this.stringValue;
+    }
+
+    throw "no alternative of the union is set";
+  }
+}
@@ -0,0 +1,374 @@
+class Entry {
+  Color color;
+  Priority priority;
+  Tagged tagged;
+  Adjacent adjacent;
+  External external_;
+
+  Entry(this.color, this.priority, this.tagged, this.adjacent, this.external_);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var color_dyn = _data["color"];
+
+    Color color = null;
+
+    if (color_dyn != null) {
+      color = Color.decode(color_dyn);
+    }
+
+    var priority_dyn = _data["priority"];
+
+    Priority priority = null;
+
+    if (priority_dyn != null) {
+      priority = Priority.decode(priority_dyn);
+    }
+
+    var tagged_dyn = _data["tagged"];
+
+    Tagged tagged = null;
+
+    if (tagged_dyn != null) {
+      tagged = Tagged.decode(tagged_dyn);
+    }
+
+    var adjacent_dyn = _data["adjacent"];
+
+    Adjacent adjacent = null;
+
+    if (adjacent_dyn != null) {
+      adjacent = Adjacent.decode(adjacent_dyn);
+    }
+
+    var external__dyn = _data["external"];
+
+    External external_ = null;
+
+    if (external__dyn != null) {
+      external_ = External.decode(external__dyn);
+    }
+
+    return Entry(color, priority, tagged, adjacent, external_);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.color != null) {
+      _data["color"] = this.color.encode();
+    }
+
+    if (this.priority != null) {
+      _data["priority"] = this.priority.encode();
+    }
+
+    if (this.tagged != null) {
+      _data["tagged"] = this.tagged.encode();
+    }
+
+    if (this.adjacent != null) {
+      _data["adjacent"] = this.adjacent.encode();
+    }
+
+    if (this.external_ != null) {
+      _data["external"] = this.external_.encode();
+    }
+
+    return _data;
+  }
+}
+
+class Color {
+  final _value;
+  const Color._new(this._value);
+
+  toString() => "Color.$_value";
+
+  static const Red = const Color._new("red");
+  static const Green = const Color._new("green");
+
+  static Color decode(dynamic data) {
+    if (!(data is String)) {
+      throw "expected $String but got $data";
+    }
+
+    switch (data as String) {
+      case "red":
+        return Color.Red;
+      case "green":
+        return Color.Green;
+      default:
+        return Color._new(data);
+    }
+  }
+
+  String encode() {
+    return _value;
+  }
+}
+
+class Priority {
+  final _value;
+  const Priority._new(this._value);
+
+  toString() => "Priority.$_value";
+
+  static const Low = const Priority._new(1);
+  static const High = const Priority._new(2);
+
+  static Priority decode(dynamic data) {
+    if (!(data is int)) {
+      throw "expected $int but got $data";
+    }
+
+    switch (data as int) {
+      case 1:
+        return Priority.Low;
+      case 2:
+        return Priority.High;
+      default:
+        return Priority._new(data);
+    }
+  }
+
+  int encode() {
+    return _value;
+  }
+}
+
+abstract class Tagged {
+  static Tagged decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["type"];
+
+    switch (tag) {
+      case "foo":
+        return Tagged_A.decode(_data);
+      case "B":
+        return Tagged_B.decode(_data);
+      default:
+        return Tagged_Other(_data);
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class Tagged_A extends Tagged {
+  String shared;
+
+  Tagged_A(this.shared);
+
+  static Tagged_A decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var shared_dyn = _data["shared"];
+
+    if (shared_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(shared_dyn is String)) {
+      throw "expected $String, but was: $shared_dyn";
+    }
+    final String shared = shared_dyn;
+
+    return Tagged_A(shared);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "foo";
+
+    _data["shared"] = this.shared;
+
+    return _data;
+  }
+}
+
+class Tagged_B extends Tagged {
+  String shared;
+  String b;
+
+  Tagged_B(this.shared, this.b);
+
+  static Tagged_B decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var shared_dyn = _data["shared"];
+
+    if (shared_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(shared_dyn is String)) {
+      throw "expected $String, but was: $shared_dyn";
+    }
+    final String shared = shared_dyn;
+
+    var b_dyn = _data["b"];
+
+    if (b_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(b_dyn is String)) {
+      throw "expected $String, but was: $b_dyn";
+    }
+    final String b = b_dyn;
+
+    return Tagged_B(shared, b);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    _data["type"] = "B";
+
+    _data["shared"] = this.shared;
+
+    _data["b"] = this.b;
+
+    return _data;
+  }
+}
+
+/// A sub-type which is not known to this version of the schema.
+class Tagged_Other extends Tagged {
+  Map<String, dynamic> data;
+
+  Tagged_Other(this.data);
+
+  Map<String, dynamic> encode() {
+    return data;
+  }
+}
+
+abstract class Adjacent {
+  static Adjacent decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var tag = _data["type"];
+    var content = _data["data"];
+
+    switch (tag) {
+      case "A":
+        return Adjacent_A.decode(content);
+      default:
+        return Adjacent_Other(_data);
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class Adjacent_A extends Adjacent {
+
+  Adjacent_A();
+
+  static Adjacent_A decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    return Adjacent_A();
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    return {"type": "A", "data": _data};
+  }
+}
+
+class Adjacent_Other extends Adjacent {
+  Map<String, dynamic> data;
+
+  Adjacent_Other(this.data);
+
+  Map<String, dynamic> encode() {
+    return data;
+  }
+}
+
+abstract class External {
+  static External decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    if (_data.length != 1) {
+      throw "expected exactly one key";
+    }
+
+    var tag = _data.keys.first;
+
+    switch (tag) {
+      case "A":
+        return External_A.decode(_data[tag]);
+      default:
+        return External_Other(_data);
+    }
+  }
+
+  Map<String, dynamic> encode();
+}
+
+class External_A extends External {
+
+  External_A();
+
+  static External_A decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    return External_A();
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    return {"A": _data};
+  }
+}
+
+class External_Other extends External {
+  Map<String, dynamic> data;
+
+  External_Other(this.data);
+
+  Map<String, dynamic> encode() {
+    return data;
+  }
+}
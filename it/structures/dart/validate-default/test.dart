@@ -0,0 +1,98 @@
+class Entry {
+  String name;
+  String code;
+  String label;
+  int count;
+  int offset;
+
+  Entry(this.name, this.code, this.label, this.count, this.offset);
+
+  static Entry decode(dynamic _dataDyn) {
+    if (!(_dataDyn is Map<String, dynamic>)) {
+      throw "expected Map<String, dynamic> but got $_dataDyn";
+    }
+
+    Map<String, dynamic> _data = _dataDyn;
+
+    var name_dyn = _data["name"];
+
+    String name = null;
+
+    if (name_dyn != null) {
+      if (!(name_dyn is String)) {
+        throw "expected $String, but was: $name_dyn";
+      }
+      name = name_dyn;
+    }
+
+    var code_dyn = _data["code"];
+
+    String code = null;
+
+    if (code_dyn != null) {
+      if (!(code_dyn is String)) {
+        throw "expected $String, but was: $code_dyn";
+      }
+      code = code_dyn;
+    }
+
+    var label_dyn = _data["label"];
+
+    if (label_dyn == null) {
+      throw "expected value but was null";
+    }
+
+    if (!(label_dyn is String)) {
+      throw "expected $String, but was: $label_dyn";
+    }
+    final String label = label_dyn;
+
+    var count_dyn = _data["count"];
+
+    int count = null;
+
+    if (count_dyn != null) {
+      if (!(count_dyn is int)) {
+        throw "expected $int, but was: $count_dyn";
+      }
+      count = count_dyn;
+    }
+
+    var offset_dyn = _data["offset"];
+
+    int offset = null;
+
+    if (offset_dyn != null) {
+      if (!(offset_dyn is int)) {
+        throw "expected $int, but was: $offset_dyn";
+      }
+      offset = offset_dyn;
+    }
+
+    return Entry(name, code, label, count, offset);
+  }
+
+  Map<String, dynamic> encode() {
+    Map<String, dynamic> _data = Map();
+
+    if (this.name != null) {
+      _data["name"] = this.name;
+    }
+
+    if (this.code != null) {
+      _data["code"] = this.code;
+    }
+
+    _data["label"] = this.label;
+
+    if (this.count != null) {
+      _data["count"] = this.count;
+    }
+
+    if (this.offset != null) {
+      _data["offset"] = this.offset;
+    }
+
+    return _data;
+  }
+}
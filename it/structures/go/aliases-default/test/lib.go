@@ -0,0 +1,38 @@
+package test
+
+import "encoding/json"
+import "errors"
+import "regexp"
+
+type Entry struct {
+  Id *uint64 `json:"id,omitempty"`
+  User *string `json:"user,omitempty"`
+  Tags *[]string `json:"tags,omitempty"`
+  Owners *map[string]uint64 `json:"owners,omitempty"`
+}
+
+var Entry_UserPattern = regexp.MustCompile("^(?:[a-z]+)$")
+
+// Validate checks that the fields of Entry satisfy their validation rules.
+func (this *Entry) Validate() error {
+  if this.User != nil {
+    value := *this.User
+    if !Entry_UserPattern.MatchString(value) {
+      return errors.New("Entry.user: must match pattern `[a-z]+`")
+    }
+  }
+
+  return nil
+}
+
+func (this *Entry) UnmarshalJSON(b []byte) error {
+  type raw Entry
+  var value raw
+
+  if err := json.Unmarshal(b, &value); err != nil {
+    return err
+  }
+
+  *this = Entry(value)
+  return this.Validate()
+}
@@ -0,0 +1,10 @@
+package test
+
+type Entry struct {
+  Date *string `json:"date,omitempty"`
+  Time *string `json:"time,omitempty"`
+  Duration *string `json:"duration,omitempty"`
+  Uuid *string `json:"uuid,omitempty"`
+  Decimal *string `json:"decimal,omitempty"`
+  Dates *[]string `json:"dates,omitempty"`
+}
@@ -0,0 +1,64 @@
+package test
+
+import "encoding/json"
+
+type Entry struct {
+  Standard *string `json:"standard,omitempty"`
+  Url *string `json:"url,omitempty"`
+  Token *string `json:"token,omitempty"`
+  Digest *string `json:"digest,omitempty"`
+  Pair *Pair `json:"pair,omitempty"`
+}
+
+type Pair struct {
+  Key string
+  Value string
+}
+
+func (this *Pair) UnmarshalJSON(b []byte) error {
+  var array []json.RawMessage
+
+  if err := json.Unmarshal(b, &array); err != nil {
+    return err
+  }
+
+  var Key string
+
+  if err := json.Unmarshal(array[0], &Key); err != nil {
+    return err
+  }
+
+  this.Key = Key
+
+  var Value string
+
+  if err := json.Unmarshal(array[1], &Value); err != nil {
+    return err
+  }
+
+  this.Value = Value
+
+  return nil
+}
+
+func (this Pair) MarshalJSON() ([]byte, error) {
+  var array []json.RawMessage
+
+  Key, err := json.Marshal(this.Key)
+
+  if err != nil {
+    return nil, err
+  }
+
+  array = append(array, Key)
+
+  Value, err := json.Marshal(this.Value)
+
+  if err != nil {
+    return nil, err
+  }
+
+  array = append(array, Value)
+
+  return json.Marshal(array)
+}
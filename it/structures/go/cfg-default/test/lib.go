@@ -0,0 +1,45 @@
+package test
+
+import "errors"
+
+type Entry struct {
+  Id *string `json:"id,omitempty"`
+  // Not available when building for Python or JavaScript.
+  Typed *uint32 `json:"typed,omitempty"`
+  Color *Color `json:"color,omitempty"`
+}
+
+type Color int
+
+const (
+  Color_Red Color = iota
+  Color_Green
+)
+
+func (this *Color) UnmarshalText(b []byte) error {
+  switch string(b) {
+  case "red":
+    *this = Color_Red
+  case "green":
+    *this = Color_Green
+  default:
+    return errors.New("bad value")
+  }
+
+  return nil
+}
+
+func (this Color) MarshalText() ([]byte, error) {
+  var s string
+
+  switch this {
+  case Color_Red:
+    s = "red"
+  case Color_Green:
+    s = "green"
+  default:
+    return nil, errors.New("bad value")
+  }
+
+  return []byte(s), nil
+}
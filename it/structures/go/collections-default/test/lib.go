@@ -0,0 +1,87 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Entry struct {
+  ByColor *map[Color]uint32 `json:"by_color,omitempty"`
+  ById *map[uint64]string `json:"by_id,omitempty"`
+  Tags *[]string `json:"tags,omitempty"`
+  Levels *[]Level `json:"levels,omitempty"`
+}
+
+type Color int
+
+const (
+  Color_Red Color = iota
+  Color_Green
+)
+
+func (this *Color) UnmarshalText(b []byte) error {
+  switch string(b) {
+  case "red":
+    *this = Color_Red
+  case "green":
+    *this = Color_Green
+  default:
+    return errors.New("bad value")
+  }
+
+  return nil
+}
+
+func (this Color) MarshalText() ([]byte, error) {
+  var s string
+
+  switch this {
+  case Color_Red:
+    s = "red"
+  case Color_Green:
+    s = "green"
+  default:
+    return nil, errors.New("bad value")
+  }
+
+  return []byte(s), nil
+}
+
+type Level int
+
+const (
+  Level_Low Level = iota
+  Level_High
+)
+
+func (this *Level) UnmarshalJSON(b []byte) error {
+  var s uint32
+
+  if err := json.Unmarshal(b, &s); err != nil {
+    return err
+  }
+
+  switch s {
+  case 1:
+    *this = Level_Low
+  case 2:
+    *this = Level_High
+  default:
+    return errors.New("bad value")
+  }
+
+  return nil
+}
+
+func (this Level) MarshalJSON() ([]byte, error) {
+  var s uint32
+
+  switch this {
+  case Level_Low:
+    s = 1
+  case Level_High:
+    s = 2
+  default:
+    return nil, errors.New("bad value")
+  }
+
+  return json.Marshal(s)
+}
@@ -0,0 +1,24 @@
+package test
+
+// The default number of entries to return.
+const DefaultLimit uint32 = 100
+
+const MaxOffset int64 = -1
+
+const Greeting string = "hello"
+
+const Ratio float64 = 0.5
+
+const Scale float32 = 2
+
+const Enabled bool = true
+
+// Deprecated: use DefaultLimit
+const OldLimit uint32 = 10
+
+type Entry struct {
+  Limit *uint32 `json:"limit,omitempty"`
+}
+
+// Maximum number of entries in a single page.
+const Entry_PageSize uint32 = 20
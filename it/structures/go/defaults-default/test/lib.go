@@ -0,0 +1,89 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Entry struct {
+  Name *string `json:"name,omitempty"`
+  Count *uint32 `json:"count,omitempty"`
+  Big *int64 `json:"big,omitempty"`
+  Ratio *float64 `json:"ratio,omitempty"`
+  Scale *float32 `json:"scale,omitempty"`
+  Enabled *bool `json:"enabled,omitempty"`
+  Label *string `json:"label,omitempty"`
+  Shape *Shape `json:"shape,omitempty"`
+}
+
+type Shape struct {
+  Value interface {
+    IsShape()
+  }
+}
+
+type Shape_Circle struct {
+  Radius *float64 `json:"radius,omitempty"`
+}
+
+func (this Shape_Circle) IsShape() {
+}
+
+func (this *Shape) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["type"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  switch (tag) {
+  case "Circle":
+    sub := Shape_Circle{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    return errors.New("bad tag")
+  }
+}
+
+func (this Shape) MarshalJSON() ([]byte, error) {
+  var b []byte
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *Shape_Circle:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("Circle"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  default:
+    return nil, errors.New("Shape: no sub-type set")
+  }
+}
@@ -0,0 +1,218 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Entry struct {
+  // Kept for compatibility.
+  // Deprecated: use `current` instead (since 1.1.0)
+  Legacy *Legacy `json:"legacy,omitempty"`
+  Current *Current `json:"current,omitempty"`
+  Status *Status `json:"status,omitempty"`
+  Point *Point `json:"point,omitempty"`
+  Shape *Shape `json:"shape,omitempty"`
+}
+
+// Deprecated: use `Current` instead
+type Legacy struct {
+  Value string `json:"value"`
+}
+
+type Current struct {
+  Value string `json:"value"`
+}
+
+type Status int
+
+const (
+  Status_Active Status = iota
+  // Deprecated: do not use.
+  Status_Retired
+)
+
+func (this *Status) UnmarshalText(b []byte) error {
+  switch string(b) {
+  case "active":
+    *this = Status_Active
+  case "retired":
+    *this = Status_Retired
+  default:
+    return errors.New("bad value")
+  }
+
+  return nil
+}
+
+func (this Status) MarshalText() ([]byte, error) {
+  var s string
+
+  switch this {
+  case Status_Active:
+    s = "active"
+  case Status_Retired:
+    s = "retired"
+  default:
+    return nil, errors.New("bad value")
+  }
+
+  return []byte(s), nil
+}
+
+// Deprecated: since 1.0.0
+type Point struct {
+  X int32
+  Y int32
+}
+
+func (this *Point) UnmarshalJSON(b []byte) error {
+  var array []json.RawMessage
+
+  if err := json.Unmarshal(b, &array); err != nil {
+    return err
+  }
+
+  var X int32
+
+  if err := json.Unmarshal(array[0], &X); err != nil {
+    return err
+  }
+
+  this.X = X
+
+  var Y int32
+
+  if err := json.Unmarshal(array[1], &Y); err != nil {
+    return err
+  }
+
+  this.Y = Y
+
+  return nil
+}
+
+func (this Point) MarshalJSON() ([]byte, error) {
+  var array []json.RawMessage
+
+  X, err := json.Marshal(this.X)
+
+  if err != nil {
+    return nil, err
+  }
+
+  array = append(array, X)
+
+  Y, err := json.Marshal(this.Y)
+
+  if err != nil {
+    return nil, err
+  }
+
+  array = append(array, Y)
+
+  return json.Marshal(array)
+}
+
+type Shape struct {
+  Value interface {
+    IsShape()
+  }
+}
+
+type Shape_Circle struct {
+  Radius float64 `json:"radius"`
+}
+
+func (this Shape_Circle) IsShape() {
+}
+
+// Deprecated: use `Circle` instead
+type Shape_Round struct {
+  Radius float64 `json:"radius"`
+}
+
+func (this Shape_Round) IsShape() {
+}
+
+func (this *Shape) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["type"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  switch (tag) {
+  case "Circle":
+    sub := Shape_Circle{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  case "Round":
+    sub := Shape_Round{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    return errors.New("bad tag")
+  }
+}
+
+func (this Shape) MarshalJSON() ([]byte, error) {
+  var b []byte
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *Shape_Circle:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("Circle"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *Shape_Round:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("Round"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  default:
+    return nil, errors.New("Shape: no sub-type set")
+  }
+}
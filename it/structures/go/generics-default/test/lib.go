@@ -0,0 +1,136 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Entry struct {
+  Page *PageItem `json:"page,omitempty"`
+  Names *PageString `json:"names,omitempty"`
+  Pair *PairItemU32 `json:"pair,omitempty"`
+  Outcome *OutcomeItem `json:"outcome,omitempty"`
+}
+
+type Item struct {
+  Name string `json:"name"`
+}
+
+// A single page of results.
+type PageItem struct {
+  Items []Item `json:"items"`
+  Next *string `json:"next,omitempty"`
+}
+
+// A single page of results.
+type PageString struct {
+  Items []string `json:"items"`
+  Next *string `json:"next,omitempty"`
+}
+
+type PairItemU32 struct {
+  First Item `json:"first"`
+  Second uint32 `json:"second"`
+}
+
+type OutcomeItem struct {
+  Value interface {
+    IsOutcomeItem()
+  }
+}
+
+type OutcomeItem_Ok struct {
+  Value Item `json:"value"`
+}
+
+func (this OutcomeItem_Ok) IsOutcomeItem() {
+}
+
+type OutcomeItem_Error struct {
+  Message string `json:"message"`
+}
+
+func (this OutcomeItem_Error) IsOutcomeItem() {
+}
+
+func (this *OutcomeItem) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["type"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  switch (tag) {
+  case "Ok":
+    sub := OutcomeItem_Ok{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  case "Error":
+    sub := OutcomeItem_Error{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    return errors.New("bad tag")
+  }
+}
+
+func (this OutcomeItem) MarshalJSON() ([]byte, error) {
+  var b []byte
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *OutcomeItem_Ok:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("Ok"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *OutcomeItem_Error:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("Error"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  default:
+    return nil, errors.New("OutcomeItem: no sub-type set")
+  }
+}
@@ -0,0 +1,27 @@
+package test
+
+type Entry struct {
+  CreatedAt *string `json:"created_at,omitempty"`
+  UpdatedAt *string `json:"updated_at,omitempty"`
+  // Who last modified the entry.
+  UpdatedBy *string `json:"updated_by,omitempty"`
+  Id *string `json:"id,omitempty"`
+  Name *string `json:"name,omitempty"`
+}
+
+// Fields shared by all audited types.
+type Auditable struct {
+  CreatedAt *string `json:"created_at,omitempty"`
+  UpdatedAt *string `json:"updated_at,omitempty"`
+  // Who last modified the entry.
+  UpdatedBy *string `json:"updated_by,omitempty"`
+}
+
+type Timestamped struct {
+  CreatedAt *string `json:"created_at,omitempty"`
+  UpdatedAt *string `json:"updated_at,omitempty"`
+}
+
+type Identified struct {
+  Id *string `json:"id,omitempty"`
+}
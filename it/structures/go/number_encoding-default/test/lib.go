@@ -0,0 +1,71 @@
+package test
+
+import "encoding/json"
+import "fmt"
+
+type Entry struct {
+  Id uint64 `json:"id,string"`
+  Offset *int64 `json:"offset,omitempty,string"`
+  Size *int64 `json:"size,omitempty"`
+  Count *uint64 `json:"count,omitempty"`
+  Pair *Pair `json:"pair,omitempty"`
+}
+
+type Pair struct {
+  Left uint64
+  Right uint64
+}
+
+func (this *Pair) UnmarshalJSON(b []byte) error {
+  var array []json.RawMessage
+
+  if err := json.Unmarshal(b, &array); err != nil {
+    return err
+  }
+
+  var Left uint64
+
+  var LeftString string
+
+  if err := json.Unmarshal(array[0], &LeftString); err != nil {
+    return err
+  }
+
+  if _, err := fmt.Sscan(LeftString, &Left); err != nil {
+    return err
+  }
+
+  this.Left = Left
+
+  var Right uint64
+
+  if err := json.Unmarshal(array[1], &Right); err != nil {
+    return err
+  }
+
+  this.Right = Right
+
+  return nil
+}
+
+func (this Pair) MarshalJSON() ([]byte, error) {
+  var array []json.RawMessage
+
+  Left, err := json.Marshal(fmt.Sprint(this.Left))
+
+  if err != nil {
+    return nil, err
+  }
+
+  array = append(array, Left)
+
+  Right, err := json.Marshal(this.Right)
+
+  if err != nil {
+    return nil, err
+  }
+
+  array = append(array, Right)
+
+  return json.Marshal(array)
+}
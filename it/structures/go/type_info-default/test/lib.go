@@ -0,0 +1,268 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Entry struct {
+  Adjacent *Adjacent `json:"adjacent,omitempty"`
+  AdjacentDefault *AdjacentDefault `json:"adjacent_default,omitempty"`
+  External *External `json:"external,omitempty"`
+}
+
+type Adjacent struct {
+  Value interface {
+    IsAdjacent()
+  }
+}
+
+type Adjacent_A struct {
+  Shared string `json:"shared"`
+}
+
+func (this Adjacent_A) IsAdjacent() {
+}
+
+type Adjacent_B struct {
+  Shared string `json:"shared"`
+  B string `json:"b"`
+}
+
+func (this Adjacent_B) IsAdjacent() {
+}
+
+func (this *Adjacent) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["kind"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  var content json.RawMessage
+
+  if content, ok = env["value"]; !ok {
+    return errors.New("missing content")
+  }
+
+  switch (tag) {
+  case "foo":
+    sub := Adjacent_A{}
+
+    if err = json.Unmarshal(content, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  case "B":
+    sub := Adjacent_B{}
+
+    if err = json.Unmarshal(content, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    return errors.New("bad tag")
+  }
+}
+
+func (this Adjacent) MarshalJSON() ([]byte, error) {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *Adjacent_A:
+    if env["kind"], err = json.Marshal("foo"); err != nil {
+      return nil, err
+    }
+
+    if env["value"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *Adjacent_B:
+    if env["kind"], err = json.Marshal("B"); err != nil {
+      return nil, err
+    }
+
+    if env["value"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  default:
+    return nil, errors.New("Adjacent: no sub-type set")
+  }
+}
+
+type AdjacentDefault struct {
+  Value interface {
+    IsAdjacentDefault()
+  }
+}
+
+type AdjacentDefault_A struct {}
+
+func (this AdjacentDefault_A) IsAdjacentDefault() {
+}
+
+func (this *AdjacentDefault) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["type"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  var content json.RawMessage
+
+  if content, ok = env["data"]; !ok {
+    return errors.New("missing content")
+  }
+
+  switch (tag) {
+  case "A":
+    sub := AdjacentDefault_A{}
+
+    if err = json.Unmarshal(content, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    return errors.New("bad tag")
+  }
+}
+
+func (this AdjacentDefault) MarshalJSON() ([]byte, error) {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *AdjacentDefault_A:
+    if env["type"], err = json.Marshal("A"); err != nil {
+      return nil, err
+    }
+
+    if env["data"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  default:
+    return nil, errors.New("AdjacentDefault: no sub-type set")
+  }
+}
+
+type External struct {
+  Value interface {
+    IsExternal()
+  }
+}
+
+type External_A struct {
+  Shared string `json:"shared"`
+}
+
+func (this External_A) IsExternal() {
+}
+
+type External_B struct {
+  Shared string `json:"shared"`
+  B string `json:"b"`
+}
+
+func (this External_B) IsExternal() {
+}
+
+func (this *External) UnmarshalJSON(b []byte) error {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  if len(env) != 1 {
+    return errors.New("expected exactly one key")
+  }
+
+  for tag, content := range env {
+    switch (tag) {
+    case "foo":
+      sub := External_A{}
+
+      if err = json.Unmarshal(content, &sub); err != nil {
+        return err
+      }
+
+      this.Value = &sub
+      return nil
+    case "B":
+      sub := External_B{}
+
+      if err = json.Unmarshal(content, &sub); err != nil {
+        return err
+      }
+
+      this.Value = &sub
+      return nil
+    default:
+      return errors.New("bad tag")
+    }
+  }
+
+  return errors.New("expected exactly one key")
+}
+
+func (this External) MarshalJSON() ([]byte, error) {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *External_A:
+    if env["foo"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *External_B:
+    if env["B"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  default:
+    return nil, errors.New("External: no sub-type set")
+  }
+}
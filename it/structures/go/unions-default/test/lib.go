@@ -0,0 +1,160 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Point struct {
+  X float64 `json:"x"`
+  Y float64 `json:"y"`
+}
+
+type Entry struct {
+  // Either a name or a numeric identifier.
+  Value *Entry_Value `json:"value,omitempty"`
+  Shape *Entry_Shape `json:"shape,omitempty"`
+  Flag *Entry_Flag `json:"flag,omitempty"`
+}
+
+type Entry_Value struct {
+  String *string
+  U64 *uint64
+}
+
+func (this *Entry_Value) UnmarshalJSON(b []byte) error {
+  var value interface{}
+
+  if err := json.Unmarshal(b, &value); err != nil {
+    return err
+  }
+
+  switch value.(type) {
+  case string:
+    var v string
+
+    if err := json.Unmarshal(b, &v); err != nil {
+      return err
+    }
+
+    this.String = &v
+    return nil
+  case float64:
+    var v uint64
+
+    if err := json.Unmarshal(b, &v); err != nil {
+      return err
+    }
+
+    this.U64 = &v
+    return nil
+  }
+
+  return errors.New("Entry_Value: no alternative of the union matches the value")
+}
+
+func (this Entry_Value) MarshalJSON() ([]byte, error) {
+  if this.String != nil {
+    return json.Marshal(this.String)
+  }
+
+  if this.U64 != nil {
+    return json.Marshal(this.U64)
+  }
+
+  return nil, errors.New("Entry_Value: no alternative of the union is set")
+}
+
+type Entry_Shape struct {
+  Point *Point
+  DoubleArray *[]float64
+}
+
+func (this *Entry_Shape) UnmarshalJSON(b []byte) error {
+  var value interface{}
+
+  if err := json.Unmarshal(b, &value); err != nil {
+    return err
+  }
+
+  switch value.(type) {
+  case map[string]interface{}:
+    var v Point
+
+    if err := json.Unmarshal(b, &v); err != nil {
+      return err
+    }
+
+    this.Point = &v
+    return nil
+  case []interface{}:
+    var v []float64
+
+    if err := json.Unmarshal(b, &v); err != nil {
+      return err
+    }
+
+    this.DoubleArray = &v
+    return nil
+  }
+
+  return errors.New("Entry_Shape: no alternative of the union matches the value")
+}
+
+func (this Entry_Shape) MarshalJSON() ([]byte, error) {
+  if this.Point != nil {
+    return json.Marshal(this.Point)
+  }
+
+  if this.DoubleArray != nil {
+    return json.Marshal(this.DoubleArray)
+  }
+
+  return nil, errors.New("Entry_Shape: no alternative of the union is set")
+}
+
+type Entry_Flag struct {
+  Boolean *bool
+  String *string
+}
+
+func (this *Entry_Flag) UnmarshalJSON(b []byte) error {
+  var value interface{}
+
+  if err := json.Unmarshal(b, &value); err != nil {
+    return err
+  }
+
+  switch value.(type) {
+  case bool:
+    var v bool
+
+    if err := json.Unmarshal(b, &v); err != nil {
+      return err
+    }
+
+    this.Boolean = &v
+    return nil
+  case string:
+    var v string
+
+    if err := json.Unmarshal(b, &v); err != nil {
+      return err
+    }
+
+    this.String = &v
+    return nil
+  }
+
+  return errors.New("Entry_Flag: no alternative of the union matches the value")
+}
+
+func (this Entry_Flag) MarshalJSON() ([]byte, error) {
+  if this.Boolean != nil {
+    return json.Marshal(this.Boolean)
+  }
+
+  if this.String != nil {
+    return json.Marshal(this.String)
+  }
+
+  return nil, errors.New("Entry_Flag: no alternative of the union is set")
+}
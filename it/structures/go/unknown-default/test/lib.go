@@ -0,0 +1,331 @@
+package test
+
+import "encoding/json"
+import "errors"
+
+type Entry struct {
+  Color *Color `json:"color,omitempty"`
+  Priority *Priority `json:"priority,omitempty"`
+  Tagged *Tagged `json:"tagged,omitempty"`
+  Adjacent *Adjacent `json:"adjacent,omitempty"`
+  External *External `json:"external,omitempty"`
+}
+
+type Color string
+
+const (
+  Color_Red Color = "red"
+  Color_Green Color = "green"
+)
+
+// IsOther reports if the value isn't one of the known variants.
+func (this Color) IsOther() bool {
+  switch this {
+  case Color_Red, Color_Green:
+    return false
+  default:
+    return true
+  }
+}
+
+type Priority uint32
+
+const (
+  Priority_Low Priority = 1
+  Priority_High Priority = 2
+)
+
+// IsOther reports if the value isn't one of the known variants.
+func (this Priority) IsOther() bool {
+  switch this {
+  case Priority_Low, Priority_High:
+    return false
+  default:
+    return true
+  }
+}
+
+type Tagged struct {
+  Value interface {
+    IsTagged()
+  }
+}
+
+type Tagged_A struct {
+  Shared string `json:"shared"`
+}
+
+func (this Tagged_A) IsTagged() {
+}
+
+type Tagged_B struct {
+  Shared string `json:"shared"`
+  B string `json:"b"`
+}
+
+func (this Tagged_B) IsTagged() {
+}
+
+// A sub-type which is not known to this version of the schema.
+type Tagged_Other struct {
+  Value map[string]interface{}
+}
+
+func (this Tagged_Other) IsTagged() {
+}
+
+func (this *Tagged) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["type"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  switch (tag) {
+  case "foo":
+    sub := Tagged_A{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  case "B":
+    sub := Tagged_B{}
+
+    if err = json.Unmarshal(b, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    sub := Tagged_Other{}
+
+    if err = json.Unmarshal(b, &sub.Value); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  }
+}
+
+func (this Tagged) MarshalJSON() ([]byte, error) {
+  var b []byte
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *Tagged_A:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("foo"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *Tagged_B:
+    if b, err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    if err = json.Unmarshal(b, &env); err != nil {
+      return nil, err
+    }
+
+    if env["type"], err = json.Marshal("B"); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *Tagged_Other:
+    return json.Marshal(v.Value)
+  default:
+    return nil, errors.New("Tagged: no sub-type set")
+  }
+}
+
+type Adjacent struct {
+  Value interface {
+    IsAdjacent()
+  }
+}
+
+type Adjacent_A struct {}
+
+func (this Adjacent_A) IsAdjacent() {
+}
+
+type Adjacent_Other struct {
+  Value map[string]interface{}
+}
+
+func (this Adjacent_Other) IsAdjacent() {
+}
+
+func (this *Adjacent) UnmarshalJSON(b []byte) error {
+  var err error
+  var ok bool
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  var raw_tag json.RawMessage
+
+  if raw_tag, ok = env["type"]; !ok {
+    return errors.New("missing tag")
+  }
+
+  var tag string
+
+  if err = json.Unmarshal(raw_tag, &tag); err != nil {
+    return err
+  }
+
+  var content json.RawMessage
+
+  if content, ok = env["data"]; !ok {
+    return errors.New("missing content")
+  }
+
+  switch (tag) {
+  case "A":
+    sub := Adjacent_A{}
+
+    if err = json.Unmarshal(content, &sub); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  default:
+    sub := Adjacent_Other{}
+
+    if err = json.Unmarshal(b, &sub.Value); err != nil {
+      return err
+    }
+
+    this.Value = &sub
+    return nil
+  }
+}
+
+func (this Adjacent) MarshalJSON() ([]byte, error) {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *Adjacent_A:
+    if env["type"], err = json.Marshal("A"); err != nil {
+      return nil, err
+    }
+
+    if env["data"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *Adjacent_Other:
+    return json.Marshal(v.Value)
+  default:
+    return nil, errors.New("Adjacent: no sub-type set")
+  }
+}
+
+type External struct {
+  Value interface {
+    IsExternal()
+  }
+}
+
+type External_A struct {}
+
+func (this External_A) IsExternal() {
+}
+
+type External_Other struct {
+  Value map[string]interface{}
+}
+
+func (this External_Other) IsExternal() {
+}
+
+func (this *External) UnmarshalJSON(b []byte) error {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  if err := json.Unmarshal(b, &env); err != nil {
+    return err
+  }
+
+  if len(env) != 1 {
+    return errors.New("expected exactly one key")
+  }
+
+  for tag, content := range env {
+    switch (tag) {
+    case "A":
+      sub := External_A{}
+
+      if err = json.Unmarshal(content, &sub); err != nil {
+        return err
+      }
+
+      this.Value = &sub
+      return nil
+    default:
+      sub := External_Other{}
+
+      if err = json.Unmarshal(b, &sub.Value); err != nil {
+        return err
+      }
+
+      this.Value = &sub
+      return nil
+    }
+  }
+
+  return errors.New("expected exactly one key")
+}
+
+func (this External) MarshalJSON() ([]byte, error) {
+  var err error
+  env := make(map[string]json.RawMessage)
+
+  switch v := this.Value.(type) {
+  case *External_A:
+    if env["A"], err = json.Marshal(v); err != nil {
+      return nil, err
+    }
+
+    return json.Marshal(env)
+  case *External_Other:
+    return json.Marshal(v.Value)
+  default:
+    return nil, errors.New("External: no sub-type set")
+  }
+}
@@ -0,0 +1,73 @@
+package test
+
+import "encoding/json"
+import "errors"
+import "regexp"
+import "unicode/utf8"
+
+type Entry struct {
+  Name *string `json:"name,omitempty"`
+  Code *string `json:"code,omitempty"`
+  Label string `json:"label"`
+  Count *uint32 `json:"count,omitempty"`
+  Offset *int64 `json:"offset,omitempty"`
+}
+
+var Entry_NamePattern = regexp.MustCompile("^(?:[a-z]+)$")
+
+// Validate checks that the fields of Entry satisfy their validation rules.
+func (this *Entry) Validate() error {
+  if this.Name != nil {
+    value := *this.Name
+    if utf8.RuneCountInString(value) > 16 {
+      return errors.New("Entry.name: length must be at most 16")
+    }
+    if !Entry_NamePattern.MatchString(value) {
+      return errors.New("Entry.name: must match pattern `[a-z]+`")
+    }
+  }
+  if this.Code != nil {
+    value := *this.Code
+    if utf8.RuneCountInString(value) < 2 {
+      return errors.New("Entry.code: length must be at least 2")
+    }
+  }
+  {
+    value := this.Label
+    if utf8.RuneCountInString(value) < 1 {
+      return errors.New("Entry.label: length must be at least 1")
+    }
+  }
+  if this.Count != nil {
+    value := *this.Count
+    if value < 1 {
+      return errors.New("Entry.count: must be at least 1")
+    }
+    if value > 100 {
+      return errors.New("Entry.count: must be at most 100")
+    }
+  }
+  if this.Offset != nil {
+    value := *this.Offset
+    if value <= -10 {
+      return errors.New("Entry.offset: must be greater than -10")
+    }
+    if value >= 10 {
+      return errors.New("Entry.offset: must be less than 10")
+    }
+  }
+
+  return nil
+}
+
+func (this *Entry) UnmarshalJSON(b []byte) error {
+  type raw Entry
+  var value raw
+
+  if err := json.Unmarshal(b, &value); err != nil {
+    return err
+  }
+
+  *this = Entry(value)
+  return this.Validate()
+}
@@ -0,0 +1,170 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+import java.util.regex.Pattern;
+
+public class Entry {
+    @JsonProperty("id")
+    final Optional<EntryId> id;
+    @JsonProperty("user")
+    final Optional<String> user;
+    @JsonProperty("tags")
+    final Optional<List<String>> tags;
+    @JsonProperty("owners")
+    final Optional<Map<String, EntryId>> owners;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("id") Optional<EntryId> id,
+        @JsonProperty("user") Optional<String> user,
+        @JsonProperty("tags") Optional<List<String>> tags,
+        @JsonProperty("owners") Optional<Map<String, EntryId>> owners
+    ) {
+        this.id = id;
+        if (user.isPresent() && !Pattern.matches("[a-z]+", user.get())) {
+            throw new IllegalArgumentException("Entry.user: must match pattern `[a-z]+`");
+        }
+        this.user = user;
+        this.tags = tags;
+        this.owners = owners;
+    }
+
+    @JsonProperty("id")
+    public Optional<EntryId> getId() {
+        return this.id;
+    }
+
+    @JsonProperty("user")
+    public Optional<String> getUser() {
+        return this.user;
+    }
+
+    @JsonProperty("tags")
+    public Optional<List<String>> getTags() {
+        return this.tags;
+    }
+
+    @JsonProperty("owners")
+    public Optional<Map<String, EntryId>> getOwners() {
+        return this.owners;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("id=");
+        b.append(this.id.toString());
+        b.append(", ");
+        b.append("user=");
+        b.append(this.user.toString());
+        b.append(", ");
+        b.append("tags=");
+        b.append(this.tags.toString());
+        b.append(", ");
+        b.append("owners=");
+        b.append(this.owners.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.id.hashCode();
+        result = result * 31 + this.user.hashCode();
+        result = result * 31 + this.tags.hashCode();
+        result = result * 31 + this.owners.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.id.equals(o_.id)) {
+            return false;
+        }
+
+        if (!this.user.equals(o_.user)) {
+            return false;
+        }
+
+        if (!this.tags.equals(o_.tags)) {
+            return false;
+        }
+
+        if (!this.owners.equals(o_.owners)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<EntryId> id;
+        private Optional<String> user;
+        private Optional<List<String>> tags;
+        private Optional<Map<String, EntryId>> owners;
+
+        private Builder() {
+            this.id = Optional.empty();
+            this.user = Optional.empty();
+            this.tags = Optional.empty();
+            this.owners = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.id,
+                this.user,
+                this.tags,
+                this.owners
+            );
+        }
+
+        public Builder id(final EntryId id) {
+            this.id = Optional.of(id);
+            return this;
+        }
+
+        public Builder user(final String user) {
+            this.user = Optional.of(user);
+            return this;
+        }
+
+        public Builder tags(final List<String> tags) {
+            this.tags = Optional.of(tags);
+            return this;
+        }
+
+        public Builder owners(final Map<String, EntryId> owners) {
+            this.owners = Optional.of(owners);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
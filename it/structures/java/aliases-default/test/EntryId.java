@@ -0,0 +1,41 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+/**
+ * Identifier of an entry, generated as a distinct type where supported.
+ */
+public final class EntryId {
+    private final long value;
+
+    @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+    public EntryId(final long value) {
+        this.value = value;
+    }
+
+    @JsonValue
+    public long getValue() {
+        return this.value;
+    }
+
+    @Override
+    public String toString() {
+        return Long.toString(this.value);
+    }
+
+    @Override
+    public int hashCode() {
+        return Long.valueOf(this.value).hashCode();
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (!(other_ instanceof EntryId)) {
+            return false;
+        }
+
+        final EntryId o_ = (EntryId)other_;
+        return this.value == o_.value;
+    }
+}
@@ -0,0 +1,226 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonFormat;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.math.BigDecimal;
+import java.time.Duration;
+import java.time.LocalDate;
+import java.time.LocalTime;
+import java.util.List;
+import java.util.Optional;
+import java.util.UUID;
+
+public class Entry {
+    @JsonProperty("date")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final Optional<LocalDate> date;
+    @JsonProperty("time")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final Optional<LocalTime> time;
+    @JsonProperty("duration")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final Optional<Duration> duration;
+    @JsonProperty("uuid")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final Optional<UUID> uuid;
+    @JsonProperty("decimal")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final Optional<BigDecimal> decimal;
+    @JsonProperty("dates")
+    final Optional<List<LocalDate>> dates;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("date") Optional<LocalDate> date,
+        @JsonProperty("time") Optional<LocalTime> time,
+        @JsonProperty("duration") Optional<Duration> duration,
+        @JsonProperty("uuid") Optional<UUID> uuid,
+        @JsonProperty("decimal") Optional<BigDecimal> decimal,
+        @JsonProperty("dates") Optional<List<LocalDate>> dates
+    ) {
+        this.date = date;
+        this.time = time;
+        this.duration = duration;
+        this.uuid = uuid;
+        this.decimal = decimal;
+        this.dates = dates;
+    }
+
+    @JsonProperty("date")
+    public Optional<LocalDate> getDate() {
+        return this.date;
+    }
+
+    @JsonProperty("time")
+    public Optional<LocalTime> getTime() {
+        return this.time;
+    }
+
+    @JsonProperty("duration")
+    public Optional<Duration> getDuration() {
+        return this.duration;
+    }
+
+    @JsonProperty("uuid")
+    public Optional<UUID> getUuid() {
+        return this.uuid;
+    }
+
+    @JsonProperty("decimal")
+    public Optional<BigDecimal> getDecimal() {
+        return this.decimal;
+    }
+
+    @JsonProperty("dates")
+    public Optional<List<LocalDate>> getDates() {
+        return this.dates;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("date=");
+        b.append(this.date.toString());
+        b.append(", ");
+        b.append("time=");
+        b.append(this.time.toString());
+        b.append(", ");
+        b.append("duration=");
+        b.append(this.duration.toString());
+        b.append(", ");
+        b.append("uuid=");
+        b.append(this.uuid.toString());
+        b.append(", ");
+        b.append("decimal=");
+        b.append(this.decimal.toString());
+        b.append(", ");
+        b.append("dates=");
+        b.append(this.dates.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.date.hashCode();
+        result = result * 31 + this.time.hashCode();
+        result = result * 31 + this.duration.hashCode();
+        result = result * 31 + this.uuid.hashCode();
+        result = result * 31 + this.decimal.hashCode();
+        result = result * 31 + this.dates.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.date.equals(o_.date)) {
+            return false;
+        }
+
+        if (!this.time.equals(o_.time)) {
+            return false;
+        }
+
+        if (!this.duration.equals(o_.duration)) {
+            return false;
+        }
+
+        if (!this.uuid.equals(o_.uuid)) {
+            return false;
+        }
+
+        if (!this.decimal.equals(o_.decimal)) {
+            return false;
+        }
+
+        if (!this.dates.equals(o_.dates)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<LocalDate> date;
+        private Optional<LocalTime> time;
+        private Optional<Duration> duration;
+        private Optional<UUID> uuid;
+        private Optional<BigDecimal> decimal;
+        private Optional<List<LocalDate>> dates;
+
+        private Builder() {
+            this.date = Optional.empty();
+            this.time = Optional.empty();
+            this.duration = Optional.empty();
+            this.uuid = Optional.empty();
+            this.decimal = Optional.empty();
+            this.dates = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.date,
+                this.time,
+                this.duration,
+                this.uuid,
+                this.decimal,
+                this.dates
+            );
+        }
+
+        public Builder date(final LocalDate date) {
+            this.date = Optional.of(date);
+            return this;
+        }
+
+        public Builder time(final LocalTime time) {
+            this.time = Optional.of(time);
+            return this;
+        }
+
+        public Builder duration(final Duration duration) {
+            this.duration = Optional.of(duration);
+            return this;
+        }
+
+        public Builder uuid(final UUID uuid) {
+            this.uuid = Optional.of(uuid);
+            return this;
+        }
+
+        public Builder decimal(final BigDecimal decimal) {
+            this.decimal = Optional.of(decimal);
+            return this;
+        }
+
+        public Builder dates(final List<LocalDate> dates) {
+            this.dates = Optional.of(dates);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,310 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.core.JsonToken;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.io.IOException;
+import java.nio.ByteBuffer;
+import java.util.Base64;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("standard")
+    final Optional<ByteBuffer> standard;
+    @JsonProperty("url")
+    @JsonSerialize(contentUsing = Base64UrlSerializer.class)
+    @JsonDeserialize(contentUsing = Base64UrlDeserializer.class)
+    final Optional<ByteBuffer> url;
+    @JsonProperty("token")
+    @JsonSerialize(contentUsing = Base64UrlNopadSerializer.class)
+    @JsonDeserialize(contentUsing = Base64UrlNopadDeserializer.class)
+    final Optional<ByteBuffer> token;
+    @JsonProperty("digest")
+    @JsonSerialize(contentUsing = HexSerializer.class)
+    @JsonDeserialize(contentUsing = HexDeserializer.class)
+    final Optional<ByteBuffer> digest;
+    @JsonProperty("pair")
+    final Optional<Pair> pair;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("standard") Optional<ByteBuffer> standard,
+        @JsonProperty("url") Optional<ByteBuffer> url,
+        @JsonProperty("token") Optional<ByteBuffer> token,
+        @JsonProperty("digest") Optional<ByteBuffer> digest,
+        @JsonProperty("pair") Optional<Pair> pair
+    ) {
+        this.standard = standard;
+        this.url = url;
+        this.token = token;
+        this.digest = digest;
+        this.pair = pair;
+    }
+
+    @JsonProperty("standard")
+    public Optional<ByteBuffer> getStandard() {
+        return this.standard;
+    }
+
+    @JsonProperty("url")
+    public Optional<ByteBuffer> getUrl() {
+        return this.url;
+    }
+
+    @JsonProperty("token")
+    public Optional<ByteBuffer> getToken() {
+        return this.token;
+    }
+
+    @JsonProperty("digest")
+    public Optional<ByteBuffer> getDigest() {
+        return this.digest;
+    }
+
+    @JsonProperty("pair")
+    public Optional<Pair> getPair() {
+        return this.pair;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("standard=");
+        b.append(this.standard.toString());
+        b.append(", ");
+        b.append("url=");
+        b.append(this.url.toString());
+        b.append(", ");
+        b.append("token=");
+        b.append(this.token.toString());
+        b.append(", ");
+        b.append("digest=");
+        b.append(this.digest.toString());
+        b.append(", ");
+        b.append("pair=");
+        b.append(this.pair.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.standard.hashCode();
+        result = result * 31 + this.url.hashCode();
+        result = result * 31 + this.token.hashCode();
+        result = result * 31 + this.digest.hashCode();
+        result = result * 31 + this.pair.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.standard.equals(o_.standard)) {
+            return false;
+        }
+
+        if (!this.url.equals(o_.url)) {
+            return false;
+        }
+
+        if (!this.token.equals(o_.token)) {
+            return false;
+        }
+
+        if (!this.digest.equals(o_.digest)) {
+            return false;
+        }
+
+        if (!this.pair.equals(o_.pair)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<ByteBuffer> standard;
+        private Optional<ByteBuffer> url;
+        private Optional<ByteBuffer> token;
+        private Optional<ByteBuffer> digest;
+        private Optional<Pair> pair;
+
+        private Builder() {
+            this.standard = Optional.empty();
+            this.url = Optional.empty();
+            this.token = Optional.empty();
+            this.digest = Optional.empty();
+            this.pair = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.standard,
+                this.url,
+                this.token,
+                this.digest,
+                this.pair
+            );
+        }
+
+        public Builder standard(final ByteBuffer standard) {
+            this.standard = Optional.of(standard);
+            return this;
+        }
+
+        public Builder url(final ByteBuffer url) {
+            this.url = Optional.of(url);
+            return this;
+        }
+
+        public Builder token(final ByteBuffer token) {
+            this.token = Optional.of(token);
+            return this;
+        }
+
+        public Builder digest(final ByteBuffer digest) {
+            this.digest = Optional.of(digest);
+            return this;
+        }
+
+        public Builder pair(final Pair pair) {
+            this.pair = Optional.of(pair);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    public static class Base64UrlSerializer extends JsonSerializer<ByteBuffer> {
+        @Override
+        public void serialize(final ByteBuffer value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            final ByteBuffer buffer_ = value_.duplicate();
+            final byte[] bytes_ = new byte[buffer_.remaining()];
+            buffer_.get(bytes_);
+
+            gen_.writeString(Base64.getUrlEncoder().encodeToString(bytes_));
+        }
+    }
+
+    public static class Base64UrlDeserializer extends JsonDeserializer<ByteBuffer> {
+        @Override
+        public ByteBuffer deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.currentToken() != JsonToken.VALUE_STRING) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_STRING, null);
+            }
+
+            final String text_ = parser_.getText();
+
+            try {
+                return ByteBuffer.wrap(Base64.getUrlDecoder().decode(text_));
+            } catch (final IllegalArgumentException e) {
+                throw ctxt_.weirdStringException(text_, ByteBuffer.class, e.getMessage());
+            }
+        }
+    }
+
+    public static class Base64UrlNopadSerializer extends JsonSerializer<ByteBuffer> {
+        @Override
+        public void serialize(final ByteBuffer value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            final ByteBuffer buffer_ = value_.duplicate();
+            final byte[] bytes_ = new byte[buffer_.remaining()];
+            buffer_.get(bytes_);
+
+            gen_.writeString(Base64.getUrlEncoder().withoutPadding().encodeToString(bytes_));
+        }
+    }
+
+    public static class Base64UrlNopadDeserializer extends JsonDeserializer<ByteBuffer> {
+        @Override
+        public ByteBuffer deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.currentToken() != JsonToken.VALUE_STRING) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_STRING, null);
+            }
+
+            final String text_ = parser_.getText();
+
+            try {
+                return ByteBuffer.wrap(Base64.getUrlDecoder().decode(text_));
+            } catch (final IllegalArgumentException e) {
+                throw ctxt_.weirdStringException(text_, ByteBuffer.class, e.getMessage());
+            }
+        }
+    }
+
+    public static class HexSerializer extends JsonSerializer<ByteBuffer> {
+        @Override
+        public void serialize(final ByteBuffer value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            final ByteBuffer buffer_ = value_.duplicate();
+            final byte[] bytes_ = new byte[buffer_.remaining()];
+            buffer_.get(bytes_);
+
+            final StringBuilder b = new StringBuilder();
+
+            for (final byte value : bytes_) {
+                b.append(String.format("%02x", value));
+            }
+
+            gen_.writeString(b.toString());
+        }
+    }
+
+    public static class HexDeserializer extends JsonDeserializer<ByteBuffer> {
+        @Override
+        public ByteBuffer deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.currentToken() != JsonToken.VALUE_STRING) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_STRING, null);
+            }
+
+            final String text_ = parser_.getText();
+
+            if (text_.length() % 2 != 0) {
+                throw ctxt_.weirdStringException(text_, ByteBuffer.class, "expected an even number of characters");
+            }
+
+            final byte[] bytes_ = new byte[text_.length() / 2];
+
+            for (int i = 0; i < bytes_.length; i++) {
+                final int high_ = Character.digit(text_.charAt(i * 2), 16);
+                final int low_ = Character.digit(text_.charAt(i * 2 + 1), 16);
+
+                if (high_ < 0 || low_ < 0) {
+                    throw ctxt_.weirdStringException(text_, ByteBuffer.class, "expected hexadecimal characters");
+                }
+
+                bytes_[i] = (byte) ((high_ << 4) | low_);
+            }
+
+            return ByteBuffer.wrap(bytes_);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.core.JsonToken;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.io.IOException;
+import java.nio.ByteBuffer;
+import java.util.Objects;
+import java.util.Optional;
+
+@JsonSerialize(using = Pair.Serializer.class)
+@JsonDeserialize(using = Pair.Deserializer.class)
+public class Pair {
+    @JsonProperty("key")
+    @JsonSerialize(using = HexSerializer.class)
+    @JsonDeserialize(using = HexDeserializer.class)
+    final ByteBuffer key;
+    @JsonProperty("value")
+    final ByteBuffer value;
+
+    @JsonCreator
+    public Pair(
+        @JsonProperty("key") ByteBuffer key,
+        @JsonProperty("value") ByteBuffer value
+    ) {
+        Objects.requireNonNull(key, "key: must not be null");
+        this.key = key;
+        Objects.requireNonNull(value, "value: must not be null");
+        this.value = value;
+    }
+
+    @JsonProperty("key")
+    public ByteBuffer getKey() {
+        return this.key;
+    }
+
+    @JsonProperty("value")
+    public ByteBuffer getValue() {
+        return this.value;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Pair(");
+        b.append("key=");
+        b.append(this.key.toString());
+        b.append(", ");
+        b.append("value=");
+        b.append(this.value.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.key.hashCode();
+        result = result * 31 + this.value.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Pair)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Pair o_ = (Pair)other_;
+
+        if (!this.key.equals(o_.key)) {
+            return false;
+        }
+
+        if (!this.value.equals(o_.value)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<ByteBuffer> key;
+        private Optional<ByteBuffer> value;
+
+        private Builder() {
+            this.key = Optional.empty();
+            this.value = Optional.empty();
+        }
+
+        public Pair build() {
+            final ByteBuffer key = this.key
+                .orElseThrow(() -> new RuntimeException("key: missing required value"));
+            final ByteBuffer value = this.value
+                .orElseThrow(() -> new RuntimeException("value: missing required value"));
+
+            return new Pair(
+                key,
+                value
+            );
+        }
+
+        public Builder key(final ByteBuffer key) {
+            this.key = Optional.of(key);
+            return this;
+        }
+
+        public Builder value(final ByteBuffer value) {
+            this.value = Optional.of(value);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    public static class HexSerializer extends JsonSerializer<ByteBuffer> {
+        @Override
+        public void serialize(final ByteBuffer value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            final ByteBuffer buffer_ = value_.duplicate();
+            final byte[] bytes_ = new byte[buffer_.remaining()];
+            buffer_.get(bytes_);
+
+            final StringBuilder b = new StringBuilder();
+
+            for (final byte value : bytes_) {
+                b.append(String.format("%02x", value));
+            }
+
+            gen_.writeString(b.toString());
+        }
+    }
+
+    public static class HexDeserializer extends JsonDeserializer<ByteBuffer> {
+        @Override
+        public ByteBuffer deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.currentToken() != JsonToken.VALUE_STRING) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_STRING, null);
+            }
+
+            final String text_ = parser_.getText();
+
+            if (text_.length() % 2 != 0) {
+                throw ctxt_.weirdStringException(text_, ByteBuffer.class, "expected an even number of characters");
+            }
+
+            final byte[] bytes_ = new byte[text_.length() / 2];
+
+            for (int i = 0; i < bytes_.length; i++) {
+                final int high_ = Character.digit(text_.charAt(i * 2), 16);
+                final int low_ = Character.digit(text_.charAt(i * 2 + 1), 16);
+
+                if (high_ < 0 || low_ < 0) {
+                    throw ctxt_.weirdStringException(text_, ByteBuffer.class, "expected hexadecimal characters");
+                }
+
+                bytes_[i] = (byte) ((high_ << 4) | low_);
+            }
+
+            return ByteBuffer.wrap(bytes_);
+        }
+    }
+
+    public static class Serializer extends JsonSerializer<Pair> {
+        @Override
+        public void serialize(final Pair value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            gen_.writeStartArray();
+
+            new HexSerializer().serialize(value_.key, gen_, provider_);
+
+            gen_.writeObject(value_.value);
+
+            gen_.writeEndArray();
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<Pair> {
+        @Override
+        public Pair deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.getCurrentToken() != JsonToken.START_ARRAY) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.START_ARRAY, null);
+            }
+
+            parser_.nextToken();
+
+            final ByteBuffer key = new HexDeserializer().deserialize(parser_, ctxt_);
+
+            parser_.nextToken();
+
+            final ByteBuffer value = parser_.readValueAs(ByteBuffer.class);
+
+            if (parser_.nextToken() != JsonToken.END_ARRAY) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.END_ARRAY, null);
+            }
+
+            return new Pair(key, value);
+        }
+    }
+}
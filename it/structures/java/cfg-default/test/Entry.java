@@ -0,0 +1,170 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("id")
+    final Optional<String> id;
+    @JsonProperty("java_only")
+    final Optional<String> javaOnly;
+    @JsonProperty("typed")
+    final Optional<Integer> typed;
+    @JsonProperty("color")
+    final Optional<Color> color;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("id") Optional<String> id,
+        @JsonProperty("java_only") Optional<String> javaOnly,
+        @JsonProperty("typed") Optional<Integer> typed,
+        @JsonProperty("color") Optional<Color> color
+    ) {
+        this.id = id;
+        this.javaOnly = javaOnly;
+        this.typed = typed;
+        this.color = color;
+    }
+
+    @JsonProperty("id")
+    public Optional<String> getId() {
+        return this.id;
+    }
+
+    /**
+     * Only available when building for Java.
+     */
+    @JsonProperty("java_only")
+    public Optional<String> getJavaOnly() {
+        return this.javaOnly;
+    }
+
+    /**
+     * Not available when building for Python or JavaScript.
+     */
+    @JsonProperty("typed")
+    public Optional<Integer> getTyped() {
+        return this.typed;
+    }
+
+    @JsonProperty("color")
+    public Optional<Color> getColor() {
+        return this.color;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("id=");
+        b.append(this.id.toString());
+        b.append(", ");
+        b.append("java_only=");
+        b.append(this.javaOnly.toString());
+        b.append(", ");
+        b.append("typed=");
+        b.append(this.typed.toString());
+        b.append(", ");
+        b.append("color=");
+        b.append(this.color.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.id.hashCode();
+        result = result * 31 + this.javaOnly.hashCode();
+        result = result * 31 + this.typed.hashCode();
+        result = result * 31 + this.color.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.id.equals(o_.id)) {
+            return false;
+        }
+
+        if (!this.javaOnly.equals(o_.javaOnly)) {
+            return false;
+        }
+
+        if (!this.typed.equals(o_.typed)) {
+            return false;
+        }
+
+        if (!this.color.equals(o_.color)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> id;
+        private Optional<String> javaOnly;
+        private Optional<Integer> typed;
+        private Optional<Color> color;
+
+        private Builder() {
+            this.id = Optional.empty();
+            this.javaOnly = Optional.empty();
+            this.typed = Optional.empty();
+            this.color = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.id,
+                this.javaOnly,
+                this.typed,
+                this.color
+            );
+        }
+
+        public Builder id(final String id) {
+            this.id = Optional.of(id);
+            return this;
+        }
+
+        public Builder javaOnly(final String javaOnly) {
+            this.javaOnly = Optional.of(javaOnly);
+            return this;
+        }
+
+        public Builder typed(final int typed) {
+            this.typed = Optional.of(typed);
+            return this;
+        }
+
+        public Builder color(final Color color) {
+            this.color = Optional.of(color);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,31 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum Color {
+    Red("red"),
+    Green("green");
+
+    String value;
+
+    Color(final String value) {
+        this.value = value;
+    }
+
+    @JsonCreator
+    public static Color fromValue(final String value) {
+        for (final Color v : values()) {
+            if (v.value.equals(value)) {
+                return v;
+            }
+        }
+
+        throw new IllegalArgumentException("value");
+    }
+
+    @JsonValue
+    public String toValue() {
+        return this.value;
+    }
+}
@@ -0,0 +1,166 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Map;
+import java.util.Optional;
+import java.util.Set;
+
+public class Entry {
+    @JsonProperty("by_color")
+    final Optional<Map<Color, Integer>> byColor;
+    @JsonProperty("by_id")
+    final Optional<Map<Long, String>> byId;
+    @JsonProperty("tags")
+    final Optional<Set<String>> tags;
+    @JsonProperty("levels")
+    final Optional<Set<Level>> levels;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("by_color") Optional<Map<Color, Integer>> byColor,
+        @JsonProperty("by_id") Optional<Map<Long, String>> byId,
+        @JsonProperty("tags") Optional<Set<String>> tags,
+        @JsonProperty("levels") Optional<Set<Level>> levels
+    ) {
+        this.byColor = byColor;
+        this.byId = byId;
+        this.tags = tags;
+        this.levels = levels;
+    }
+
+    @JsonProperty("by_color")
+    public Optional<Map<Color, Integer>> getByColor() {
+        return this.byColor;
+    }
+
+    @JsonProperty("by_id")
+    public Optional<Map<Long, String>> getById() {
+        return this.byId;
+    }
+
+    @JsonProperty("tags")
+    public Optional<Set<String>> getTags() {
+        return this.tags;
+    }
+
+    @JsonProperty("levels")
+    public Optional<Set<Level>> getLevels() {
+        return this.levels;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("by_color=");
+        b.append(this.byColor.toString());
+        b.append(", ");
+        b.append("by_id=");
+        b.append(this.byId.toString());
+        b.append(", ");
+        b.append("tags=");
+        b.append(this.tags.toString());
+        b.append(", ");
+        b.append("levels=");
+        b.append(this.levels.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.byColor.hashCode();
+        result = result * 31 + this.byId.hashCode();
+        result = result * 31 + this.tags.hashCode();
+        result = result * 31 + this.levels.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.byColor.equals(o_.byColor)) {
+            return false;
+        }
+
+        if (!this.byId.equals(o_.byId)) {
+            return false;
+        }
+
+        if (!this.tags.equals(o_.tags)) {
+            return false;
+        }
+
+        if (!this.levels.equals(o_.levels)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Map<Color, Integer>> byColor;
+        private Optional<Map<Long, String>> byId;
+        private Optional<Set<String>> tags;
+        private Optional<Set<Level>> levels;
+
+        private Builder() {
+            this.byColor = Optional.empty();
+            this.byId = Optional.empty();
+            this.tags = Optional.empty();
+            this.levels = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.byColor,
+                this.byId,
+                this.tags,
+                this.levels
+            );
+        }
+
+        public Builder byColor(final Map<Color, Integer> byColor) {
+            this.byColor = Optional.of(byColor);
+            return this;
+        }
+
+        public Builder byId(final Map<Long, String> byId) {
+            this.byId = Optional.of(byId);
+            return this;
+        }
+
+        public Builder tags(final Set<String> tags) {
+            this.tags = Optional.of(tags);
+            return this;
+        }
+
+        public Builder levels(final Set<Level> levels) {
+            this.levels = Optional.of(levels);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
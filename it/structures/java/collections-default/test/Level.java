@@ -0,0 +1,31 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum Level {
+    Low(1),
+    High(2);
+
+    int value;
+
+    Level(final int value) {
+        this.value = value;
+    }
+
+    @JsonCreator
+    public static Level fromValue(final int value) {
+        for (final Level v : values()) {
+            if (v.value == value) {
+                return v;
+            }
+        }
+
+        throw new IllegalArgumentException("value");
+    }
+
+    @JsonValue
+    public int toValue() {
+        return this.value;
+    }
+}
@@ -0,0 +1,24 @@
+package test;
+
+public final class Constants {
+    private Constants() {
+    }
+
+    /**
+     * The default number of entries to return.
+     */
+    public static final int DEFAULT_LIMIT = 100;
+
+    public static final long MAX_OFFSET = -1L;
+
+    public static final String GREETING = "hello";
+
+    public static final double RATIO = 0.5D;
+
+    public static final float SCALE = 2F;
+
+    public static final boolean ENABLED = true;
+
+    @Deprecated
+    public static final int OLD_LIMIT = 10;
+}
@@ -0,0 +1,94 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("limit")
+    final Optional<Integer> limit;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("limit") Optional<Integer> limit
+    ) {
+        this.limit = limit;
+    }
+
+    @JsonProperty("limit")
+    public Optional<Integer> getLimit() {
+        return this.limit;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("limit=");
+        b.append(this.limit.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.limit.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.limit.equals(o_.limit)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Integer> limit;
+
+        private Builder() {
+            this.limit = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.limit
+            );
+        }
+
+        public Builder limit(final int limit) {
+            this.limit = Optional.of(limit);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Maximum number of entries in a single page.
+     */
+    public static final int PAGE_SIZE = 20;
+}
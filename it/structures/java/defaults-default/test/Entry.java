@@ -0,0 +1,264 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("name")
+    final Optional<String> name;
+    @JsonProperty("count")
+    final Optional<Integer> count;
+    @JsonProperty("big")
+    final Optional<Long> big;
+    @JsonProperty("ratio")
+    final Optional<Double> ratio;
+    @JsonProperty("scale")
+    final Optional<Float> scale;
+    @JsonProperty("enabled")
+    final Optional<Boolean> enabled;
+    @JsonProperty("label")
+    final Optional<String> label;
+    @JsonProperty("shape")
+    final Optional<Shape> shape;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("name") Optional<String> name,
+        @JsonProperty("count") Optional<Integer> count,
+        @JsonProperty("big") Optional<Long> big,
+        @JsonProperty("ratio") Optional<Double> ratio,
+        @JsonProperty("scale") Optional<Float> scale,
+        @JsonProperty("enabled") Optional<Boolean> enabled,
+        @JsonProperty("label") Optional<String> label,
+        @JsonProperty("shape") Optional<Shape> shape
+    ) {
+        this.name = Optional.of(name.orElse("unknown"));
+        this.count = Optional.of(count.orElse(42));
+        this.big = Optional.of(big.orElse(-1L));
+        this.ratio = Optional.of(ratio.orElse(0.5D));
+        this.scale = Optional.of(scale.orElse(2F));
+        this.enabled = Optional.of(enabled.orElse(true));
+        this.label = label;
+        this.shape = shape;
+    }
+
+    @JsonProperty("name")
+    public Optional<String> getName() {
+        return this.name;
+    }
+
+    @JsonProperty("count")
+    public Optional<Integer> getCount() {
+        return this.count;
+    }
+
+    @JsonProperty("big")
+    public Optional<Long> getBig() {
+        return this.big;
+    }
+
+    @JsonProperty("ratio")
+    public Optional<Double> getRatio() {
+        return this.ratio;
+    }
+
+    @JsonProperty("scale")
+    public Optional<Float> getScale() {
+        return this.scale;
+    }
+
+    @JsonProperty("enabled")
+    public Optional<Boolean> getEnabled() {
+        return this.enabled;
+    }
+
+    @JsonProperty("label")
+    public Optional<String> getLabel() {
+        return this.label;
+    }
+
+    @JsonProperty("shape")
+    public Optional<Shape> getShape() {
+        return this.shape;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("name=");
+        b.append(this.name.toString());
+        b.append(", ");
+        b.append("count=");
+        b.append(this.count.toString());
+        b.append(", ");
+        b.append("big=");
+        b.append(this.big.toString());
+        b.append(", ");
+        b.append("ratio=");
+        b.append(this.ratio.toString());
+        b.append(", ");
+        b.append("scale=");
+        b.append(this.scale.toString());
+        b.append(", ");
+        b.append("enabled=");
+        b.append(this.enabled.toString());
+        b.append(", ");
+        b.append("label=");
+        b.append(this.label.toString());
+        b.append(", ");
+        b.append("shape=");
+        b.append(this.shape.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.name.hashCode();
+        result = result * 31 + this.count.hashCode();
+        result = result * 31 + this.big.hashCode();
+        result = result * 31 + this.ratio.hashCode();
+        result = result * 31 + this.scale.hashCode();
+        result = result * 31 + this.enabled.hashCode();
+        result = result * 31 + this.label.hashCode();
+        result = result * 31 + this.shape.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.name.equals(o_.name)) {
+            return false;
+        }
+
+        if (!this.count.equals(o_.count)) {
+            return false;
+        }
+
+        if (!this.big.equals(o_.big)) {
+            return false;
+        }
+
+        if (!this.ratio.equals(o_.ratio)) {
+            return false;
+        }
+
+        if (!this.scale.equals(o_.scale)) {
+            return false;
+        }
+
+        if (!this.enabled.equals(o_.enabled)) {
+            return false;
+        }
+
+        if (!this.label.equals(o_.label)) {
+            return false;
+        }
+
+        if (!this.shape.equals(o_.shape)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> name;
+        private Optional<Integer> count;
+        private Optional<Long> big;
+        private Optional<Double> ratio;
+        private Optional<Float> scale;
+        private Optional<Boolean> enabled;
+        private Optional<String> label;
+        private Optional<Shape> shape;
+
+        private Builder() {
+            this.name = Optional.empty();
+            this.count = Optional.empty();
+            this.big = Optional.empty();
+            this.ratio = Optional.empty();
+            this.scale = Optional.empty();
+            this.enabled = Optional.empty();
+            this.label = Optional.empty();
+            this.shape = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.name,
+                this.count,
+                this.big,
+                this.ratio,
+                this.scale,
+                this.enabled,
+                this.label,
+                this.shape
+            );
+        }
+
+        public Builder name(final String name) {
+            this.name = Optional.of(name);
+            return this;
+        }
+
+        public Builder count(final int count) {
+            this.count = Optional.of(count);
+            return this;
+        }
+
+        public Builder big(final long big) {
+            this.big = Optional.of(big);
+            return this;
+        }
+
+        public Builder ratio(final double ratio) {
+            this.ratio = Optional.of(ratio);
+            return this;
+        }
+
+        public Builder scale(final float scale) {
+            this.scale = Optional.of(scale);
+            return this;
+        }
+
+        public Builder enabled(final boolean enabled) {
+            this.enabled = Optional.of(enabled);
+            return this;
+        }
+
+        public Builder label(final String label) {
+            this.label = Optional.of(label);
+            return this;
+        }
+
+        public Builder shape(final Shape shape) {
+            this.shape = Optional.of(shape);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
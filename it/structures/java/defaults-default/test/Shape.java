@@ -0,0 +1,98 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import java.util.Optional;
+
+@JsonTypeInfo(use=JsonTypeInfo.Id.NAME, include=JsonTypeInfo.As.PROPERTY, property="type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(name="Circle", value=Shape.Circle.class),
+})
+public interface Shape {
+
+    public static class Circle implements Shape {
+        @JsonProperty("radius")
+        final Optional<Double> radius;
+
+        @JsonCreator
+        public Circle(
+            @JsonProperty("radius") Optional<Double> radius
+        ) {
+            this.radius = Optional.of(radius.orElse(1D));
+        }
+
+        @JsonProperty("radius")
+        public Optional<Double> getRadius() {
+            return this.radius;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Circle(");
+            b.append("radius=");
+            b.append(this.radius.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.radius.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Circle)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Circle o_ = (Circle)other_;
+
+            if (!this.radius.equals(o_.radius)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<Double> radius;
+
+            private Builder() {
+                this.radius = Optional.empty();
+            }
+
+            public Circle build() {
+
+                return new Circle(
+                    this.radius
+                );
+            }
+
+            public Builder radius(final double radius) {
+                this.radius = Optional.of(radius);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+}
@@ -0,0 +1,93 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Current {
+    @JsonProperty("value")
+    final String value;
+
+    @JsonCreator
+    public Current(
+        @JsonProperty("value") String value
+    ) {
+        Objects.requireNonNull(value, "value: must not be null");
+        this.value = value;
+    }
+
+    @JsonProperty("value")
+    public String getValue() {
+        return this.value;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Current(");
+        b.append("value=");
+        b.append(this.value.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.value.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Current)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Current o_ = (Current)other_;
+
+        if (!this.value.equals(o_.value)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> value;
+
+        private Builder() {
+            this.value = Optional.empty();
+        }
+
+        public Current build() {
+            final String value = this.value
+                .orElseThrow(() -> new RuntimeException("value: missing required value"));
+
+            return new Current(
+                value
+            );
+        }
+
+        public Builder value(final String value) {
+            this.value = Optional.of(value);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,194 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("legacy")
+    @Deprecated
+    final Optional<Legacy> legacy;
+    @JsonProperty("current")
+    final Optional<Current> current;
+    @JsonProperty("status")
+    final Optional<Status> status;
+    @JsonProperty("point")
+    final Optional<Point> point;
+    @JsonProperty("shape")
+    final Optional<Shape> shape;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("legacy") Optional<Legacy> legacy,
+        @JsonProperty("current") Optional<Current> current,
+        @JsonProperty("status") Optional<Status> status,
+        @JsonProperty("point") Optional<Point> point,
+        @JsonProperty("shape") Optional<Shape> shape
+    ) {
+        this.legacy = legacy;
+        this.current = current;
+        this.status = status;
+        this.point = point;
+        this.shape = shape;
+    }
+
+    /**
+     * Kept for compatibility.
+     */
+    @JsonProperty("legacy")
+    @Deprecated
+    public Optional<Legacy> getLegacy() {
+        return this.legacy;
+    }
+
+    @JsonProperty("current")
+    public Optional<Current> getCurrent() {
+        return this.current;
+    }
+
+    @JsonProperty("status")
+    public Optional<Status> getStatus() {
+        return this.status;
+    }
+
+    @JsonProperty("point")
+    public Optional<Point> getPoint() {
+        return this.point;
+    }
+
+    @JsonProperty("shape")
+    public Optional<Shape> getShape() {
+        return this.shape;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("legacy=");
+        b.append(this.legacy.toString());
+        b.append(", ");
+        b.append("current=");
+        b.append(this.current.toString());
+        b.append(", ");
+        b.append("status=");
+        b.append(this.status.toString());
+        b.append(", ");
+        b.append("point=");
+        b.append(this.point.toString());
+        b.append(", ");
+        b.append("shape=");
+        b.append(this.shape.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.legacy.hashCode();
+        result = result * 31 + this.current.hashCode();
+        result = result * 31 + this.status.hashCode();
+        result = result * 31 + this.point.hashCode();
+        result = result * 31 + this.shape.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.legacy.equals(o_.legacy)) {
+            return false;
+        }
+
+        if (!this.current.equals(o_.current)) {
+            return false;
+        }
+
+        if (!this.status.equals(o_.status)) {
+            return false;
+        }
+
+        if (!this.point.equals(o_.point)) {
+            return false;
+        }
+
+        if (!this.shape.equals(o_.shape)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Legacy> legacy;
+        private Optional<Current> current;
+        private Optional<Status> status;
+        private Optional<Point> point;
+        private Optional<Shape> shape;
+
+        private Builder() {
+            this.legacy = Optional.empty();
+            this.current = Optional.empty();
+            this.status = Optional.empty();
+            this.point = Optional.empty();
+            this.shape = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.legacy,
+                this.current,
+                this.status,
+                this.point,
+                this.shape
+            );
+        }
+
+        public Builder legacy(final Legacy legacy) {
+            this.legacy = Optional.of(legacy);
+            return this;
+        }
+
+        public Builder current(final Current current) {
+            this.current = Optional.of(current);
+            return this;
+        }
+
+        public Builder status(final Status status) {
+            this.status = Optional.of(status);
+            return this;
+        }
+
+        public Builder point(final Point point) {
+            this.point = Optional.of(point);
+            return this;
+        }
+
+        public Builder shape(final Shape shape) {
+            this.shape = Optional.of(shape);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,94 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+@Deprecated
+public class Legacy {
+    @JsonProperty("value")
+    final String value;
+
+    @JsonCreator
+    public Legacy(
+        @JsonProperty("value") String value
+    ) {
+        Objects.requireNonNull(value, "value: must not be null");
+        this.value = value;
+    }
+
+    @JsonProperty("value")
+    public String getValue() {
+        return this.value;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Legacy(");
+        b.append("value=");
+        b.append(this.value.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.value.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Legacy)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Legacy o_ = (Legacy)other_;
+
+        if (!this.value.equals(o_.value)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> value;
+
+        private Builder() {
+            this.value = Optional.empty();
+        }
+
+        public Legacy build() {
+            final String value = this.value
+                .orElseThrow(() -> new RuntimeException("value: missing required value"));
+
+            return new Legacy(
+                value
+            );
+        }
+
+        public Builder value(final String value) {
+            this.value = Optional.of(value);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,171 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.core.JsonToken;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.io.IOException;
+import java.util.Optional;
+
+@JsonSerialize(using = Point.Serializer.class)
+@JsonDeserialize(using = Point.Deserializer.class)
+@Deprecated
+public class Point {
+    @JsonProperty("x")
+    final int x;
+    @JsonProperty("y")
+    final int y;
+
+    @JsonCreator
+    public Point(
+        @JsonProperty("x") int x,
+        @JsonProperty("y") int y
+    ) {
+        this.x = x;
+        this.y = y;
+    }
+
+    @JsonProperty("x")
+    public int getX() {
+        return this.x;
+    }
+
+    @JsonProperty("y")
+    public int getY() {
+        return this.y;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Point(");
+        b.append("x=");
+        b.append(Integer.toString(this.x));
+        b.append(", ");
+        b.append("y=");
+        b.append(Integer.toString(this.y));
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + Integer.valueOf(this.x).hashCode();
+        result = result * 31 + Integer.valueOf(this.y).hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Point)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Point o_ = (Point)other_;
+
+        if (this.x != o_.x) {
+            return false;
+        }
+
+        if (this.y != o_.y) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Integer> x;
+        private Optional<Integer> y;
+
+        private Builder() {
+            this.x = Optional.empty();
+            this.y = Optional.empty();
+        }
+
+        public Point build() {
+            final int x = this.x
+                .orElseThrow(() -> new RuntimeException("x: missing required value"));
+            final int y = this.y
+                .orElseThrow(() -> new RuntimeException("y: missing required value"));
+
+            return new Point(
+                x,
+                y
+            );
+        }
+
+        public Builder x(final int x) {
+            this.x = Optional.of(x);
+            return this;
+        }
+
+        public Builder y(final int y) {
+            this.y = Optional.of(y);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    public static class Serializer extends JsonSerializer<Point> {
+        @Override
+        public void serialize(final Point value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            gen_.writeStartArray();
+
+            gen_.writeNumber(value_.x);
+
+            gen_.writeNumber(value_.y);
+
+            gen_.writeEndArray();
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<Point> {
+        @Override
+        public Point deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.getCurrentToken() != JsonToken.START_ARRAY) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.START_ARRAY, null);
+            }
+
+            if (parser_.nextToken() != JsonToken.VALUE_NUMBER_INT) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_NUMBER_INT, null);
+            }
+
+            final int x = parser_.getIntValue();
+
+            if (parser_.nextToken() != JsonToken.VALUE_NUMBER_INT) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_NUMBER_INT, null);
+            }
+
+            final int y = parser_.getIntValue();
+
+            if (parser_.nextToken() != JsonToken.END_ARRAY) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.END_ARRAY, null);
+            }
+
+            return new Point(x, y);
+        }
+    }
+}
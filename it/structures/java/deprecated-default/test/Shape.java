@@ -0,0 +1,188 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import java.util.Optional;
+
+@JsonTypeInfo(use=JsonTypeInfo.Id.NAME, include=JsonTypeInfo.As.PROPERTY, property="type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(name="Circle", value=Shape.Circle.class),
+    @JsonSubTypes.Type(name="Round", value=Shape.Round.class),
+})
+public interface Shape {
+
+    public static class Circle implements Shape {
+        @JsonProperty("radius")
+        final double radius;
+
+        @JsonCreator
+        public Circle(
+            @JsonProperty("radius") double radius
+        ) {
+            this.radius = radius;
+        }
+
+        @JsonProperty("radius")
+        public double getRadius() {
+            return this.radius;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Circle(");
+            b.append("radius=");
+            b.append(Double.toString(this.radius));
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + Double.valueOf(this.radius).hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Circle)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Circle o_ = (Circle)other_;
+
+            if (this.radius != o_.radius) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<Double> radius;
+
+            private Builder() {
+                this.radius = Optional.empty();
+            }
+
+            public Circle build() {
+                final double radius = this.radius
+                    .orElseThrow(() -> new RuntimeException("radius: missing required value"));
+
+                return new Circle(
+                    radius
+                );
+            }
+
+            public Builder radius(final double radius) {
+                this.radius = Optional.of(radius);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    }
+
+    @Deprecated
+    public static class Round implements Shape {
+        @JsonProperty("radius")
+        final double radius;
+
+        @JsonCreator
+        public Round(
+            @JsonProperty("radius") double radius
+        ) {
+            this.radius = radius;
+        }
+
+        @JsonProperty("radius")
+        public double getRadius() {
+            return this.radius;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Round(");
+            b.append("radius=");
+            b.append(Double.toString(this.radius));
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + Double.valueOf(this.radius).hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Round)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Round o_ = (Round)other_;
+
+            if (this.radius != o_.radius) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<Double> radius;
+
+            private Builder() {
+                this.radius = Optional.empty();
+            }
+
+            public Round build() {
+                final double radius = this.radius
+                    .orElseThrow(() -> new RuntimeException("radius: missing required value"));
+
+                return new Round(
+                    radius
+                );
+            }
+
+            public Builder radius(final double radius) {
+                this.radius = Optional.of(radius);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+}
@@ -0,0 +1,31 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum Status {
+    Active("active"),
+    @Deprecated Retired("retired");
+
+    String value;
+
+    Status(final String value) {
+        this.value = value;
+    }
+
+    @JsonCreator
+    public static Status fromValue(final String value) {
+        for (final Status v : values()) {
+            if (v.value.equals(value)) {
+                return v;
+            }
+        }
+
+        throw new IllegalArgumentException("value");
+    }
+
+    @JsonValue
+    public String toValue() {
+        return this.value;
+    }
+}
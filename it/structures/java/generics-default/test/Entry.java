@@ -0,0 +1,164 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("page")
+    final Optional<Page<Item>> page;
+    @JsonProperty("names")
+    final Optional<Page<String>> names;
+    @JsonProperty("pair")
+    final Optional<Pair<Item, Integer>> pair;
+    @JsonProperty("outcome")
+    final Optional<Outcome<Item>> outcome;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("page") Optional<Page<Item>> page,
+        @JsonProperty("names") Optional<Page<String>> names,
+        @JsonProperty("pair") Optional<Pair<Item, Integer>> pair,
+        @JsonProperty("outcome") Optional<Outcome<Item>> outcome
+    ) {
+        this.page = page;
+        this.names = names;
+        this.pair = pair;
+        this.outcome = outcome;
+    }
+
+    @JsonProperty("page")
+    public Optional<Page<Item>> getPage() {
+        return this.page;
+    }
+
+    @JsonProperty("names")
+    public Optional<Page<String>> getNames() {
+        return this.names;
+    }
+
+    @JsonProperty("pair")
+    public Optional<Pair<Item, Integer>> getPair() {
+        return this.pair;
+    }
+
+    @JsonProperty("outcome")
+    public Optional<Outcome<Item>> getOutcome() {
+        return this.outcome;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("page=");
+        b.append(this.page.toString());
+        b.append(", ");
+        b.append("names=");
+        b.append(this.names.toString());
+        b.append(", ");
+        b.append("pair=");
+        b.append(this.pair.toString());
+        b.append(", ");
+        b.append("outcome=");
+        b.append(this.outcome.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.page.hashCode();
+        result = result * 31 + this.names.hashCode();
+        result = result * 31 + this.pair.hashCode();
+        result = result * 31 + this.outcome.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.page.equals(o_.page)) {
+            return false;
+        }
+
+        if (!this.names.equals(o_.names)) {
+            return false;
+        }
+
+        if (!this.pair.equals(o_.pair)) {
+            return false;
+        }
+
+        if (!this.outcome.equals(o_.outcome)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Page<Item>> page;
+        private Optional<Page<String>> names;
+        private Optional<Pair<Item, Integer>> pair;
+        private Optional<Outcome<Item>> outcome;
+
+        private Builder() {
+            this.page = Optional.empty();
+            this.names = Optional.empty();
+            this.pair = Optional.empty();
+            this.outcome = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.page,
+                this.names,
+                this.pair,
+                this.outcome
+            );
+        }
+
+        public Builder page(final Page<Item> page) {
+            this.page = Optional.of(page);
+            return this;
+        }
+
+        public Builder names(final Page<String> names) {
+            this.names = Optional.of(names);
+            return this;
+        }
+
+        public Builder pair(final Pair<Item, Integer> pair) {
+            this.pair = Optional.of(pair);
+            return this;
+        }
+
+        public Builder outcome(final Outcome<Item> outcome) {
+            this.outcome = Optional.of(outcome);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,93 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Item {
+    @JsonProperty("name")
+    final String name;
+
+    @JsonCreator
+    public Item(
+        @JsonProperty("name") String name
+    ) {
+        Objects.requireNonNull(name, "name: must not be null");
+        this.name = name;
+    }
+
+    @JsonProperty("name")
+    public String getName() {
+        return this.name;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Item(");
+        b.append("name=");
+        b.append(this.name.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.name.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Item)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Item o_ = (Item)other_;
+
+        if (!this.name.equals(o_.name)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> name;
+
+        private Builder() {
+            this.name = Optional.empty();
+        }
+
+        public Item build() {
+            final String name = this.name
+                .orElseThrow(() -> new RuntimeException("name: missing required value"));
+
+            return new Item(
+                name
+            );
+        }
+
+        public Builder name(final String name) {
+            this.name = Optional.of(name);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
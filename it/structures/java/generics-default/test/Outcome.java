@@ -0,0 +1,190 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import java.util.Objects;
+import java.util.Optional;
+
+@JsonTypeInfo(use=JsonTypeInfo.Id.NAME, include=JsonTypeInfo.As.PROPERTY, property="type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(name="Ok", value=Outcome.Ok.class),
+    @JsonSubTypes.Type(name="Error", value=Outcome.Error.class),
+})
+public interface Outcome<T> {
+
+    public static class Ok<T> implements Outcome<T> {
+        @JsonProperty("value")
+        final T value;
+
+        @JsonCreator
+        public Ok(
+            @JsonProperty("value") T value
+        ) {
+            Objects.requireNonNull(value, "value: must not be null");
+            this.value = value;
+        }
+
+        @JsonProperty("value")
+        public T getValue() {
+            return this.value;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Ok(");
+            b.append("value=");
+            b.append(this.value.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.value.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Ok)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Ok o_ = (Ok)other_;
+
+            if (!this.value.equals(o_.value)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder<T> {
+            private Optional<T> value;
+
+            private Builder() {
+                this.value = Optional.empty();
+            }
+
+            public Ok<T> build() {
+                final T value = this.value
+                    .orElseThrow(() -> new RuntimeException("value: missing required value"));
+
+                return new Ok<>(
+                    value
+                );
+            }
+
+            public Builder<T> value(final T value) {
+                this.value = Optional.of(value);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static <T> Builder<T> builder() {
+            return new Builder<>();
+        }
+    }
+
+    public static class Error<T> implements Outcome<T> {
+        @JsonProperty("message")
+        final String message;
+
+        @JsonCreator
+        public Error(
+            @JsonProperty("message") String message
+        ) {
+            Objects.requireNonNull(message, "message: must not be null");
+            this.message = message;
+        }
+
+        @JsonProperty("message")
+        public String getMessage() {
+            return this.message;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Error(");
+            b.append("message=");
+            b.append(this.message.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.message.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Error)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Error o_ = (Error)other_;
+
+            if (!this.message.equals(o_.message)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder<T> {
+            private Optional<String> message;
+
+            private Builder() {
+                this.message = Optional.empty();
+            }
+
+            public Error<T> build() {
+                final String message = this.message
+                    .orElseThrow(() -> new RuntimeException("message: missing required value"));
+
+                return new Error<>(
+                    message
+                );
+            }
+
+            public Builder<T> message(final String message) {
+                this.message = Optional.of(message);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static <T> Builder<T> builder() {
+            return new Builder<>();
+        }
+    };
+}
@@ -0,0 +1,122 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+/**
+ * A single page of results.
+ */
+public class Page<T> {
+    @JsonProperty("items")
+    final List<T> items;
+    @JsonProperty("next")
+    final Optional<String> next;
+
+    @JsonCreator
+    public Page(
+        @JsonProperty("items") List<T> items,
+        @JsonProperty("next") Optional<String> next
+    ) {
+        Objects.requireNonNull(items, "items: must not be null");
+        this.items = items;
+        this.next = next;
+    }
+
+    @JsonProperty("items")
+    public List<T> getItems() {
+        return this.items;
+    }
+
+    @JsonProperty("next")
+    public Optional<String> getNext() {
+        return this.next;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Page(");
+        b.append("items=");
+        b.append(this.items.toString());
+        b.append(", ");
+        b.append("next=");
+        b.append(this.next.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.items.hashCode();
+        result = result * 31 + this.next.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Page)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Page o_ = (Page)other_;
+
+        if (!this.items.equals(o_.items)) {
+            return false;
+        }
+
+        if (!this.next.equals(o_.next)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder<T> {
+        private Optional<List<T>> items;
+        private Optional<String> next;
+
+        private Builder() {
+            this.items = Optional.empty();
+            this.next = Optional.empty();
+        }
+
+        public Page<T> build() {
+            final List<T> items = this.items
+                .orElseThrow(() -> new RuntimeException("items: missing required value"));
+
+            return new Page<>(
+                items,
+                this.next
+            );
+        }
+
+        public Builder<T> items(final List<T> items) {
+            this.items = Optional.of(items);
+            return this;
+        }
+
+        public Builder<T> next(final String next) {
+            this.next = Optional.of(next);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static <T> Builder<T> builder() {
+        return new Builder<>();
+    }
+}
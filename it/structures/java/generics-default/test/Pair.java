@@ -0,0 +1,121 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Pair<A, B> {
+    @JsonProperty("first")
+    final A first;
+    @JsonProperty("second")
+    final B second;
+
+    @JsonCreator
+    public Pair(
+        @JsonProperty("first") A first,
+        @JsonProperty("second") B second
+    ) {
+        Objects.requireNonNull(first, "first: must not be null");
+        this.first = first;
+        Objects.requireNonNull(second, "second: must not be null");
+        this.second = second;
+    }
+
+    @JsonProperty("first")
+    public A getFirst() {
+        return this.first;
+    }
+
+    @JsonProperty("second")
+    public B getSecond() {
+        return this.second;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Pair(");
+        b.append("first=");
+        b.append(this.first.toString());
+        b.append(", ");
+        b.append("second=");
+        b.append(this.second.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.first.hashCode();
+        result = result * 31 + this.second.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Pair)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Pair o_ = (Pair)other_;
+
+        if (!this.first.equals(o_.first)) {
+            return false;
+        }
+
+        if (!this.second.equals(o_.second)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder<A, B> {
+        private Optional<A> first;
+        private Optional<B> second;
+
+        private Builder() {
+            this.first = Optional.empty();
+            this.second = Optional.empty();
+        }
+
+        public Pair<A, B> build() {
+            final A first = this.first
+                .orElseThrow(() -> new RuntimeException("first: missing required value"));
+            final B second = this.second
+                .orElseThrow(() -> new RuntimeException("second: missing required value"));
+
+            return new Pair<>(
+                first,
+                second
+            );
+        }
+
+        public Builder<A, B> first(final A first) {
+            this.first = Optional.of(first);
+            return this;
+        }
+
+        public Builder<A, B> second(final B second) {
+            this.second = Optional.of(second);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static <A, B> Builder<A, B> builder() {
+        return new Builder<>();
+    }
+}
@@ -0,0 +1,145 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+/**
+ * Fields shared by all audited types.
+ */
+public class Auditable {
+    @JsonProperty("created_at")
+    final Optional<String> createdAt;
+    @JsonProperty("updated_at")
+    final Optional<String> updatedAt;
+    @JsonProperty("updated_by")
+    final Optional<String> updatedBy;
+
+    @JsonCreator
+    public Auditable(
+        @JsonProperty("created_at") Optional<String> createdAt,
+        @JsonProperty("updated_at") Optional<String> updatedAt,
+        @JsonProperty("updated_by") Optional<String> updatedBy
+    ) {
+        this.createdAt = createdAt;
+        this.updatedAt = updatedAt;
+        this.updatedBy = updatedBy;
+    }
+
+    @JsonProperty("created_at")
+    public Optional<String> getCreatedAt() {
+        return this.createdAt;
+    }
+
+    @JsonProperty("updated_at")
+    public Optional<String> getUpdatedAt() {
+        return this.updatedAt;
+    }
+
+    /**
+     * Who last modified the entry.
+     */
+    @JsonProperty("updated_by")
+    public Optional<String> getUpdatedBy() {
+        return this.updatedBy;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Auditable(");
+        b.append("created_at=");
+        b.append(this.createdAt.toString());
+        b.append(", ");
+        b.append("updated_at=");
+        b.append(this.updatedAt.toString());
+        b.append(", ");
+        b.append("updated_by=");
+        b.append(this.updatedBy.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.createdAt.hashCode();
+        result = result * 31 + this.updatedAt.hashCode();
+        result = result * 31 + this.updatedBy.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Auditable)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Auditable o_ = (Auditable)other_;
+
+        if (!this.createdAt.equals(o_.createdAt)) {
+            return false;
+        }
+
+        if (!this.updatedAt.equals(o_.updatedAt)) {
+            return false;
+        }
+
+        if (!this.updatedBy.equals(o_.updatedBy)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> createdAt;
+        private Optional<String> updatedAt;
+        private Optional<String> updatedBy;
+
+        private Builder() {
+            this.createdAt = Optional.empty();
+            this.updatedAt = Optional.empty();
+            this.updatedBy = Optional.empty();
+        }
+
+        public Auditable build() {
+
+            return new Auditable(
+                this.createdAt,
+                this.updatedAt,
+                this.updatedBy
+            );
+        }
+
+        public Builder createdAt(final String createdAt) {
+            this.createdAt = Optional.of(createdAt);
+            return this;
+        }
+
+        public Builder updatedAt(final String updatedAt) {
+            this.updatedAt = Optional.of(updatedAt);
+            return this;
+        }
+
+        public Builder updatedBy(final String updatedBy) {
+            this.updatedBy = Optional.of(updatedBy);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,192 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("created_at")
+    final Optional<String> createdAt;
+    @JsonProperty("updated_at")
+    final Optional<String> updatedAt;
+    @JsonProperty("updated_by")
+    final Optional<String> updatedBy;
+    @JsonProperty("id")
+    final Optional<String> id;
+    @JsonProperty("name")
+    final Optional<String> name;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("created_at") Optional<String> createdAt,
+        @JsonProperty("updated_at") Optional<String> updatedAt,
+        @JsonProperty("updated_by") Optional<String> updatedBy,
+        @JsonProperty("id") Optional<String> id,
+        @JsonProperty("name") Optional<String> name
+    ) {
+        this.createdAt = createdAt;
+        this.updatedAt = updatedAt;
+        this.updatedBy = updatedBy;
+        this.id = id;
+        this.name = name;
+    }
+
+    @JsonProperty("created_at")
+    public Optional<String> getCreatedAt() {
+        return this.createdAt;
+    }
+
+    @JsonProperty("updated_at")
+    public Optional<String> getUpdatedAt() {
+        return this.updatedAt;
+    }
+
+    /**
+     * Who last modified the entry.
+     */
+    @JsonProperty("updated_by")
+    public Optional<String> getUpdatedBy() {
+        return this.updatedBy;
+    }
+
+    @JsonProperty("id")
+    public Optional<String> getId() {
+        return this.id;
+    }
+
+    @JsonProperty("name")
+    public Optional<String> getName() {
+        return this.name;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("created_at=");
+        b.append(this.createdAt.toString());
+        b.append(", ");
+        b.append("updated_at=");
+        b.append(this.updatedAt.toString());
+        b.append(", ");
+        b.append("updated_by=");
+        b.append(this.updatedBy.toString());
+        b.append(", ");
+        b.append("id=");
+        b.append(this.id.toString());
+        b.append(", ");
+        b.append("name=");
+        b.append(this.name.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.createdAt.hashCode();
+        result = result * 31 + this.updatedAt.hashCode();
+        result = result * 31 + this.updatedBy.hashCode();
+        result = result * 31 + this.id.hashCode();
+        result = result * 31 + this.name.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.createdAt.equals(o_.createdAt)) {
+            return false;
+        }
+
+        if (!this.updatedAt.equals(o_.updatedAt)) {
+            return false;
+        }
+
+        if (!this.updatedBy.equals(o_.updatedBy)) {
+            return false;
+        }
+
+        if (!this.id.equals(o_.id)) {
+            return false;
+        }
+
+        if (!this.name.equals(o_.name)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> createdAt;
+        private Optional<String> updatedAt;
+        private Optional<String> updatedBy;
+        private Optional<String> id;
+        private Optional<String> name;
+
+        private Builder() {
+            this.createdAt = Optional.empty();
+            this.updatedAt = Optional.empty();
+            this.updatedBy = Optional.empty();
+            this.id = Optional.empty();
+            this.name = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.createdAt,
+                this.updatedAt,
+                this.updatedBy,
+                this.id,
+                this.name
+            );
+        }
+
+        public Builder createdAt(final String createdAt) {
+            this.createdAt = Optional.of(createdAt);
+            return this;
+        }
+
+        public Builder updatedAt(final String updatedAt) {
+            this.updatedAt = Optional.of(updatedAt);
+            return this;
+        }
+
+        public Builder updatedBy(final String updatedBy) {
+            this.updatedBy = Optional.of(updatedBy);
+            return this;
+        }
+
+        public Builder id(final String id) {
+            this.id = Optional.of(id);
+            return this;
+        }
+
+        public Builder name(final String name) {
+            this.name = Optional.of(name);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,89 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Identified {
+    @JsonProperty("id")
+    final Optional<String> id;
+
+    @JsonCreator
+    public Identified(
+        @JsonProperty("id") Optional<String> id
+    ) {
+        this.id = id;
+    }
+
+    @JsonProperty("id")
+    public Optional<String> getId() {
+        return this.id;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Identified(");
+        b.append("id=");
+        b.append(this.id.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.id.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Identified)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Identified o_ = (Identified)other_;
+
+        if (!this.id.equals(o_.id)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> id;
+
+        private Builder() {
+            this.id = Optional.empty();
+        }
+
+        public Identified build() {
+
+            return new Identified(
+                this.id
+            );
+        }
+
+        public Builder id(final String id) {
+            this.id = Optional.of(id);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
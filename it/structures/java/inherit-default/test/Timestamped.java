@@ -0,0 +1,114 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Timestamped {
+    @JsonProperty("created_at")
+    final Optional<String> createdAt;
+    @JsonProperty("updated_at")
+    final Optional<String> updatedAt;
+
+    @JsonCreator
+    public Timestamped(
+        @JsonProperty("created_at") Optional<String> createdAt,
+        @JsonProperty("updated_at") Optional<String> updatedAt
+    ) {
+        this.createdAt = createdAt;
+        this.updatedAt = updatedAt;
+    }
+
+    @JsonProperty("created_at")
+    public Optional<String> getCreatedAt() {
+        return this.createdAt;
+    }
+
+    @JsonProperty("updated_at")
+    public Optional<String> getUpdatedAt() {
+        return this.updatedAt;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Timestamped(");
+        b.append("created_at=");
+        b.append(this.createdAt.toString());
+        b.append(", ");
+        b.append("updated_at=");
+        b.append(this.updatedAt.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.createdAt.hashCode();
+        result = result * 31 + this.updatedAt.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Timestamped)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Timestamped o_ = (Timestamped)other_;
+
+        if (!this.createdAt.equals(o_.createdAt)) {
+            return false;
+        }
+
+        if (!this.updatedAt.equals(o_.updatedAt)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> createdAt;
+        private Optional<String> updatedAt;
+
+        private Builder() {
+            this.createdAt = Optional.empty();
+            this.updatedAt = Optional.empty();
+        }
+
+        public Timestamped build() {
+
+            return new Timestamped(
+                this.createdAt,
+                this.updatedAt
+            );
+        }
+
+        public Builder createdAt(final String createdAt) {
+            this.createdAt = Optional.of(createdAt);
+            return this;
+        }
+
+        public Builder updatedAt(final String updatedAt) {
+            this.updatedAt = Optional.of(updatedAt);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
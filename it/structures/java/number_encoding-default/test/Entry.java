@@ -0,0 +1,194 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonFormat;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("id")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final long id;
+    @JsonProperty("offset")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final Optional<Long> offset;
+    @JsonProperty("size")
+    final Optional<Long> size;
+    @JsonProperty("count")
+    final Optional<Long> count;
+    @JsonProperty("pair")
+    final Optional<Pair> pair;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("id") long id,
+        @JsonProperty("offset") Optional<Long> offset,
+        @JsonProperty("size") Optional<Long> size,
+        @JsonProperty("count") Optional<Long> count,
+        @JsonProperty("pair") Optional<Pair> pair
+    ) {
+        this.id = id;
+        this.offset = offset;
+        this.size = size;
+        this.count = count;
+        this.pair = pair;
+    }
+
+    @JsonProperty("id")
+    public long getId() {
+        return this.id;
+    }
+
+    @JsonProperty("offset")
+    public Optional<Long> getOffset() {
+        return this.offset;
+    }
+
+    @JsonProperty("size")
+    public Optional<Long> getSize() {
+        return this.size;
+    }
+
+    @JsonProperty("count")
+    public Optional<Long> getCount() {
+        return this.count;
+    }
+
+    @JsonProperty("pair")
+    public Optional<Pair> getPair() {
+        return this.pair;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("id=");
+        b.append(Long.toString(this.id));
+        b.append(", ");
+        b.append("offset=");
+        b.append(this.offset.toString());
+        b.append(", ");
+        b.append("size=");
+        b.append(this.size.toString());
+        b.append(", ");
+        b.append("count=");
+        b.append(this.count.toString());
+        b.append(", ");
+        b.append("pair=");
+        b.append(this.pair.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + Long.valueOf(this.id).hashCode();
+        result = result * 31 + this.offset.hashCode();
+        result = result * 31 + this.size.hashCode();
+        result = result * 31 + this.count.hashCode();
+        result = result * 31 + this.pair.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (this.id != o_.id) {
+            return false;
+        }
+
+        if (!this.offset.equals(o_.offset)) {
+            return false;
+        }
+
+        if (!this.size.equals(o_.size)) {
+            return false;
+        }
+
+        if (!this.count.equals(o_.count)) {
+            return false;
+        }
+
+        if (!this.pair.equals(o_.pair)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Long> id;
+        private Optional<Long> offset;
+        private Optional<Long> size;
+        private Optional<Long> count;
+        private Optional<Pair> pair;
+
+        private Builder() {
+            this.id = Optional.empty();
+            this.offset = Optional.empty();
+            this.size = Optional.empty();
+            this.count = Optional.empty();
+            this.pair = Optional.empty();
+        }
+
+        public Entry build() {
+            final long id = this.id
+                .orElseThrow(() -> new RuntimeException("id: missing required value"));
+
+            return new Entry(
+                id,
+                this.offset,
+                this.size,
+                this.count,
+                this.pair
+            );
+        }
+
+        public Builder id(final long id) {
+            this.id = Optional.of(id);
+            return this;
+        }
+
+        public Builder offset(final long offset) {
+            this.offset = Optional.of(offset);
+            return this;
+        }
+
+        public Builder size(final long size) {
+            this.size = Optional.of(size);
+            return this;
+        }
+
+        public Builder count(final long count) {
+            this.count = Optional.of(count);
+            return this;
+        }
+
+        public Builder pair(final Pair pair) {
+            this.pair = Optional.of(pair);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
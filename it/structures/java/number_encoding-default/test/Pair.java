@@ -0,0 +1,172 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonFormat;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.core.JsonToken;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import java.io.IOException;
+import java.util.Optional;
+
+@JsonSerialize(using = Pair.Serializer.class)
+@JsonDeserialize(using = Pair.Deserializer.class)
+public class Pair {
+    @JsonProperty("left")
+    @JsonFormat(shape = JsonFormat.Shape.STRING)
+    final long left;
+    @JsonProperty("right")
+    final long right;
+
+    @JsonCreator
+    public Pair(
+        @JsonProperty("left") long left,
+        @JsonProperty("right") long right
+    ) {
+        this.left = left;
+        this.right = right;
+    }
+
+    @JsonProperty("left")
+    public long getLeft() {
+        return this.left;
+    }
+
+    @JsonProperty("right")
+    public long getRight() {
+        return this.right;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Pair(");
+        b.append("left=");
+        b.append(Long.toString(this.left));
+        b.append(", ");
+        b.append("right=");
+        b.append(Long.toString(this.right));
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + Long.valueOf(this.left).hashCode();
+        result = result * 31 + Long.valueOf(this.right).hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Pair)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Pair o_ = (Pair)other_;
+
+        if (this.left != o_.left) {
+            return false;
+        }
+
+        if (this.right != o_.right) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Long> left;
+        private Optional<Long> right;
+
+        private Builder() {
+            this.left = Optional.empty();
+            this.right = Optional.empty();
+        }
+
+        public Pair build() {
+            final long left = this.left
+                .orElseThrow(() -> new RuntimeException("left: missing required value"));
+            final long right = this.right
+                .orElseThrow(() -> new RuntimeException("right: missing required value"));
+
+            return new Pair(
+                left,
+                right
+            );
+        }
+
+        public Builder left(final long left) {
+            this.left = Optional.of(left);
+            return this;
+        }
+
+        public Builder right(final long right) {
+            this.right = Optional.of(right);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    public static class Serializer extends JsonSerializer<Pair> {
+        @Override
+        public void serialize(final Pair value_, final JsonGenerator gen_, final SerializerProvider provider_) throws IOException {
+            gen_.writeStartArray();
+
+            gen_.writeString(String.valueOf(value_.left));
+
+            gen_.writeNumber(value_.right);
+
+            gen_.writeEndArray();
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<Pair> {
+        @Override
+        public Pair deserialize(final JsonParser parser_, final DeserializationContext ctxt_) throws IOException {
+            if (parser_.getCurrentToken() != JsonToken.START_ARRAY) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.START_ARRAY, null);
+            }
+
+            if (parser_.nextToken() != JsonToken.VALUE_STRING) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_STRING, null);
+            }
+
+            final long left = Long.parseLong(parser_.getText());
+
+            if (parser_.nextToken() != JsonToken.VALUE_NUMBER_INT) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.VALUE_NUMBER_INT, null);
+            }
+
+            final long right = parser_.getLongValue();
+
+            if (parser_.nextToken() != JsonToken.END_ARRAY) {
+                throw ctxt_.wrongTokenException(parser_, JsonToken.END_ARRAY, null);
+            }
+
+            return new Pair(left, right);
+        }
+    }
+}
@@ -0,0 +1,275 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import com.fasterxml.jackson.databind.node.TreeTraversingParser;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.Optional;
+
+@JsonSerialize(using = Adjacent.Serializer.class)
+@JsonDeserialize(using = Adjacent.Deserializer.class)
+public interface Adjacent {
+    public String getShared();
+
+    @JsonSerialize(using = JsonSerializer.None.class)
+    @JsonDeserialize(using = JsonDeserializer.None.class)
+    public static class A implements Adjacent {
+        @JsonProperty("shared")
+        final String shared;
+
+        @JsonCreator
+        public A(
+            @JsonProperty("shared") String shared
+        ) {
+            Objects.requireNonNull(shared, "shared: must not be null");
+            this.shared = shared;
+        }
+
+        @JsonProperty("shared")
+        @Override
+        public String getShared() {
+            return this.shared;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("A(");
+            b.append("shared=");
+            b.append(this.shared.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.shared.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof A)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final A o_ = (A)other_;
+
+            if (!this.shared.equals(o_.shared)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<String> shared;
+
+            private Builder() {
+                this.shared = Optional.empty();
+            }
+
+            public A build() {
+                final String shared = this.shared
+                    .orElseThrow(() -> new RuntimeException("shared: missing required value"));
+
+                return new A(
+                    shared
+                );
+            }
+
+            public Builder shared(final String shared) {
+                this.shared = Optional.of(shared);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    }
+
+    @JsonSerialize(using = JsonSerializer.None.class)
+    @JsonDeserialize(using = JsonDeserializer.None.class)
+    public static class B implements Adjacent {
+        @JsonProperty("shared")
+        final String shared;
+        @JsonProperty("b")
+        final String b;
+
+        @JsonCreator
+        public B(
+            @JsonProperty("shared") String shared,
+            @JsonProperty("b") String b
+        ) {
+            Objects.requireNonNull(shared, "shared: must not be null");
+            this.shared = shared;
+            Objects.requireNonNull(b, "b: must not be null");
+            this.b = b;
+        }
+
+        @JsonProperty("shared")
+        @Override
+        public String getShared() {
+            return this.shared;
+        }
+
+        @JsonProperty("b")
+        public String getB() {
+            return this.b;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("B(");
+            b.append("shared=");
+            b.append(this.shared.toString());
+            b.append(", ");
+            b.append("b=");
+            b.append(this.b.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.shared.hashCode();
+            result = result * 31 + this.b.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof B)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final B o_ = (B)other_;
+
+            if (!this.shared.equals(o_.shared)) {
+                return false;
+            }
+
+            if (!this.b.equals(o_.b)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<String> shared;
+            private Optional<String> b;
+
+            private Builder() {
+                this.shared = Optional.empty();
+                this.b = Optional.empty();
+            }
+
+            public B build() {
+                final String shared = this.shared
+                    .orElseThrow(() -> new RuntimeException("shared: missing required value"));
+                final String b = this.b
+                    .orElseThrow(() -> new RuntimeException("b: missing required value"));
+
+                return new B(
+                    shared,
+                    b
+                );
+            }
+
+            public Builder shared(final String shared) {
+                this.shared = Optional.of(shared);
+                return this;
+            }
+
+            public Builder b(final String b) {
+                this.b = Optional.of(b);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+
+    public static class Serializer extends JsonSerializer<Adjacent> {
+        @Override
+        public void serialize(final Adjacent value, final JsonGenerator generator, final SerializerProvider provider) throws IOException {
+            final String tag;
+
+            if (value instanceof Adjacent.A) {
+                tag = "foo";
+            } else if (value instanceof Adjacent.B) {
+                tag = "B";
+            } else {
+                throw new IllegalArgumentException("unsupported sub-type: " + value);
+            }
+
+            generator.writeStartObject();
+            generator.writeStringField("kind", tag);
+            generator.writeObjectField("value", value);
+            generator.writeEndObject();
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<Adjacent> {
+        @Override
+        public Adjacent deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+            final ObjectNode object = parser.readValueAs(ObjectNode.class);
+
+            final JsonNode tag = object.get("kind");
+            final JsonNode content = object.get("value");
+
+            if (tag == null || content == null) {
+                throw ctxt.mappingException("expected fields `kind` and `value`");
+            }
+
+            switch (tag.asText()) {
+            case "foo":
+                return new TreeTraversingParser(content, parser.getCodec()).readValueAs(Adjacent.A.class);
+            case "B":
+                return new TreeTraversingParser(content, parser.getCodec()).readValueAs(Adjacent.B.class);
+            default:
+                throw ctxt.mappingException("bad type: " + tag.asText());
+            }
+        }
+    }
+}
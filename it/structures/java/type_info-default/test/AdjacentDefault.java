@@ -0,0 +1,112 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import com.fasterxml.jackson.databind.node.TreeTraversingParser;
+import java.io.IOException;
+
+@JsonSerialize(using = AdjacentDefault.Serializer.class)
+@JsonDeserialize(using = AdjacentDefault.Deserializer.class)
+public interface AdjacentDefault {
+
+    @JsonSerialize(using = JsonSerializer.None.class)
+    @JsonDeserialize(using = JsonDeserializer.None.class)
+    public static class A implements AdjacentDefault {
+
+        @JsonCreator
+        public A() {}
+
+        @Override
+        public String toString() {
+            return "A()";
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof A)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final A o_ = (A)other_;
+
+            return true;
+        }
+
+        public static class Builder {
+
+            private Builder() {}
+
+            public A build() {
+
+                return new A();
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+
+    public static class Serializer extends JsonSerializer<AdjacentDefault> {
+        @Override
+        public void serialize(final AdjacentDefault value, final JsonGenerator generator, final SerializerProvider provider) throws IOException {
+            final String tag;
+
+            if (value instanceof AdjacentDefault.A) {
+                tag = "A";
+            } else {
+                throw new IllegalArgumentException("unsupported sub-type: " + value);
+            }
+
+            generator.writeStartObject();
+            generator.writeStringField("type", tag);
+            generator.writeObjectField("data", value);
+            generator.writeEndObject();
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<AdjacentDefault> {
+        @Override
+        public AdjacentDefault deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+            final ObjectNode object = parser.readValueAs(ObjectNode.class);
+
+            final JsonNode tag = object.get("type");
+            final JsonNode content = object.get("data");
+
+            if (tag == null || content == null) {
+                throw ctxt.mappingException("expected fields `type` and `data`");
+            }
+
+            switch (tag.asText()) {
+            case "A":
+                return new TreeTraversingParser(content, parser.getCodec()).readValueAs(AdjacentDefault.A.class);
+            default:
+                throw ctxt.mappingException("bad type: " + tag.asText());
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("adjacent")
+    final Optional<Adjacent> adjacent;
+    @JsonProperty("adjacent_default")
+    final Optional<AdjacentDefault> adjacentDefault;
+    @JsonProperty("external")
+    final Optional<External> external;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("adjacent") Optional<Adjacent> adjacent,
+        @JsonProperty("adjacent_default") Optional<AdjacentDefault> adjacentDefault,
+        @JsonProperty("external") Optional<External> external
+    ) {
+        this.adjacent = adjacent;
+        this.adjacentDefault = adjacentDefault;
+        this.external = external;
+    }
+
+    @JsonProperty("adjacent")
+    public Optional<Adjacent> getAdjacent() {
+        return this.adjacent;
+    }
+
+    @JsonProperty("adjacent_default")
+    public Optional<AdjacentDefault> getAdjacentDefault() {
+        return this.adjacentDefault;
+    }
+
+    @JsonProperty("external")
+    public Optional<External> getExternal() {
+        return this.external;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("adjacent=");
+        b.append(this.adjacent.toString());
+        b.append(", ");
+        b.append("adjacent_default=");
+        b.append(this.adjacentDefault.toString());
+        b.append(", ");
+        b.append("external=");
+        b.append(this.external.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.adjacent.hashCode();
+        result = result * 31 + this.adjacentDefault.hashCode();
+        result = result * 31 + this.external.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.adjacent.equals(o_.adjacent)) {
+            return false;
+        }
+
+        if (!this.adjacentDefault.equals(o_.adjacentDefault)) {
+            return false;
+        }
+
+        if (!this.external.equals(o_.external)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Adjacent> adjacent;
+        private Optional<AdjacentDefault> adjacentDefault;
+        private Optional<External> external;
+
+        private Builder() {
+            this.adjacent = Optional.empty();
+            this.adjacentDefault = Optional.empty();
+            this.external = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.adjacent,
+                this.adjacentDefault,
+                this.external
+            );
+        }
+
+        public Builder adjacent(final Adjacent adjacent) {
+            this.adjacent = Optional.of(adjacent);
+            return this;
+        }
+
+        public Builder adjacentDefault(final AdjacentDefault adjacentDefault) {
+            this.adjacentDefault = Optional.of(adjacentDefault);
+            return this;
+        }
+
+        public Builder external(final External external) {
+            this.external = Optional.of(external);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,221 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import java.util.Objects;
+import java.util.Optional;
+
+@JsonTypeInfo(use=JsonTypeInfo.Id.NAME, include=JsonTypeInfo.As.WRAPPER_OBJECT)
+@JsonSubTypes({
+    @JsonSubTypes.Type(name="foo", value=External.A.class),
+    @JsonSubTypes.Type(name="B", value=External.B.class),
+})
+public interface External {
+    public String getShared();
+
+    public static class A implements External {
+        @JsonProperty("shared")
+        final String shared;
+
+        @JsonCreator
+        public A(
+            @JsonProperty("shared") String shared
+        ) {
+            Objects.requireNonNull(shared, "shared: must not be null");
+            this.shared = shared;
+        }
+
+        @JsonProperty("shared")
+        @Override
+        public String getShared() {
+            return this.shared;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("A(");
+            b.append("shared=");
+            b.append(this.shared.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.shared.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof A)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final A o_ = (A)other_;
+
+            if (!this.shared.equals(o_.shared)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<String> shared;
+
+            private Builder() {
+                this.shared = Optional.empty();
+            }
+
+            public A build() {
+                final String shared = this.shared
+                    .orElseThrow(() -> new RuntimeException("shared: missing required value"));
+
+                return new A(
+                    shared
+                );
+            }
+
+            public Builder shared(final String shared) {
+                this.shared = Optional.of(shared);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    }
+
+    public static class B implements External {
+        @JsonProperty("shared")
+        final String shared;
+        @JsonProperty("b")
+        final String b;
+
+        @JsonCreator
+        public B(
+            @JsonProperty("shared") String shared,
+            @JsonProperty("b") String b
+        ) {
+            Objects.requireNonNull(shared, "shared: must not be null");
+            this.shared = shared;
+            Objects.requireNonNull(b, "b: must not be null");
+            this.b = b;
+        }
+
+        @JsonProperty("shared")
+        @Override
+        public String getShared() {
+            return this.shared;
+        }
+
+        @JsonProperty("b")
+        public String getB() {
+            return this.b;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("B(");
+            b.append("shared=");
+            b.append(this.shared.toString());
+            b.append(", ");
+            b.append("b=");
+            b.append(this.b.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.shared.hashCode();
+            result = result * 31 + this.b.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof B)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final B o_ = (B)other_;
+
+            if (!this.shared.equals(o_.shared)) {
+                return false;
+            }
+
+            if (!this.b.equals(o_.b)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<String> shared;
+            private Optional<String> b;
+
+            private Builder() {
+                this.shared = Optional.empty();
+                this.b = Optional.empty();
+            }
+
+            public B build() {
+                final String shared = this.shared
+                    .orElseThrow(() -> new RuntimeException("shared: missing required value"));
+                final String b = this.b
+                    .orElseThrow(() -> new RuntimeException("b: missing required value"));
+
+                return new B(
+                    shared,
+                    b
+                );
+            }
+
+            public Builder shared(final String shared) {
+                this.shared = Optional.of(shared);
+                return this;
+            }
+
+            public Builder b(final String b) {
+                this.b = Optional.of(b);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+}
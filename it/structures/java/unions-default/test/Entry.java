@@ -0,0 +1,426 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.annotation.JsonValue;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import java.io.IOException;
+import java.util.List;
+import java.util.Objects;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("value")
+    final Optional<Entry.Value> value;
+    @JsonProperty("shape")
+    final Optional<Entry.Shape> shape;
+    @JsonProperty("flag")
+    final Optional<Entry.Flag> flag;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("value") Optional<Entry.Value> value,
+        @JsonProperty("shape") Optional<Entry.Shape> shape,
+        @JsonProperty("flag") Optional<Entry.Flag> flag
+    ) {
+        this.value = value;
+        this.shape = shape;
+        this.flag = flag;
+    }
+
+    /**
+     * Either a name or a numeric identifier.
+     */
+    @JsonProperty("value")
+    public Optional<Entry.Value> getValue() {
+        return this.value;
+    }
+
+    @JsonProperty("shape")
+    public Optional<Entry.Shape> getShape() {
+        return this.shape;
+    }
+
+    @JsonProperty("flag")
+    public Optional<Entry.Flag> getFlag() {
+        return this.flag;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("value=");
+        b.append(this.value.toString());
+        b.append(", ");
+        b.append("shape=");
+        b.append(this.shape.toString());
+        b.append(", ");
+        b.append("flag=");
+        b.append(this.flag.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.value.hashCode();
+        result = result * 31 + this.shape.hashCode();
+        result = result * 31 + this.flag.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.value.equals(o_.value)) {
+            return false;
+        }
+
+        if (!this.shape.equals(o_.shape)) {
+            return false;
+        }
+
+        if (!this.flag.equals(o_.flag)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Entry.Value> value;
+        private Optional<Entry.Shape> shape;
+        private Optional<Entry.Flag> flag;
+
+        private Builder() {
+            this.value = Optional.empty();
+            this.shape = Optional.empty();
+            this.flag = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.value,
+                this.shape,
+                this.flag
+            );
+        }
+
+        public Builder value(final Entry.Value value) {
+            this.value = Optional.of(value);
+            return this;
+        }
+
+        public Builder shape(final Entry.Shape shape) {
+            this.shape = Optional.of(shape);
+            return this;
+        }
+
+        public Builder flag(final Entry.Flag flag) {
+            this.flag = Optional.of(flag);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    @JsonDeserialize(using = Value.Deserializer.class)
+    public static abstract class Value {
+        private Value() {
+        }
+
+        @JsonDeserialize(using = JsonDeserializer.None.class)
+        public static final class StringValue extends Value {
+            private final String value;
+
+            @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+            public StringValue(final String value) {
+                Objects.requireNonNull(value, "value: must not be null");
+                this.value = value;
+            }
+
+            @JsonValue
+            public String getValue() {
+                return this.value;
+            }
+
+            @Override
+            public String toString() {
+                return this.value.toString();
+            }
+
+            @Override
+            public int hashCode() {
+                return this.value.hashCode();
+            }
+
+            @Override
+            public boolean equals(final Object other_) {
+                if (!(other_ instanceof StringValue)) {
+                    return false;
+                }
+
+                final StringValue o_ = (StringValue)other_;
+                return this.value.equals(o_.value);
+            }
+        }
+
+        @JsonDeserialize(using = JsonDeserializer.None.class)
+        public static final class U64Value extends Value {
+            private final long value;
+
+            @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+            public U64Value(final long value) {
+                this.value = value;
+            }
+
+            @JsonValue
+            public long getValue() {
+                return this.value;
+            }
+
+            @Override
+            public String toString() {
+                return Long.toString(this.value);
+            }
+
+            @Override
+            public int hashCode() {
+                return Long.valueOf(this.value).hashCode();
+            }
+
+            @Override
+            public boolean equals(final Object other_) {
+                if (!(other_ instanceof U64Value)) {
+                    return false;
+                }
+
+                final U64Value o_ = (U64Value)other_;
+                return this.value == o_.value;
+            }
+        }
+
+        public static class Deserializer extends JsonDeserializer<Value> {
+            @Override
+            public Value deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+                switch (parser.getCurrentToken()) {
+                case VALUE_STRING:
+                    return new StringValue(parser.getText());
+                case VALUE_NUMBER_INT:
+                    return new U64Value(parser.getLongValue());
+                default:
+                    throw ctxt.mappingException("no alternative of the union matches the value");
+                }
+            }
+        }
+    }
+
+    @JsonDeserialize(using = Shape.Deserializer.class)
+    public static abstract class Shape {
+        private Shape() {
+        }
+
+        @JsonDeserialize(using = JsonDeserializer.None.class)
+        public static final class PointValue extends Shape {
+            private final Point value;
+
+            @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+            public PointValue(final Point value) {
+                Objects.requireNonNull(value, "value: must not be null");
+                this.value = value;
+            }
+
+            @JsonValue
+            public Point getValue() {
+                return this.value;
+            }
+
+            @Override
+            public String toString() {
+                return this.value.toString();
+            }
+
+            @Override
+            public int hashCode() {
+                return this.value.hashCode();
+            }
+
+            @Override
+            public boolean equals(final Object other_) {
+                if (!(other_ instanceof PointValue)) {
+                    return false;
+                }
+
+                final PointValue o_ = (PointValue)other_;
+                return this.value.equals(o_.value);
+            }
+        }
+
+        @JsonDeserialize(using = JsonDeserializer.None.class)
+        public static final class DoubleArrayValue extends Shape {
+            private final List<Double> value;
+
+            @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+            public DoubleArrayValue(final List<Double> value) {
+                Objects.requireNonNull(value, "value: must not be null");
+                this.value = value;
+            }
+
+            @JsonValue
+            public List<Double> getValue() {
+                return this.value;
+            }
+
+            @Override
+            public String toString() {
+                return this.value.toString();
+            }
+
+            @Override
+            public int hashCode() {
+                return this.value.hashCode();
+            }
+
+            @Override
+            public boolean equals(final Object other_) {
+                if (!(other_ instanceof DoubleArrayValue)) {
+                    return false;
+                }
+
+                final DoubleArrayValue o_ = (DoubleArrayValue)other_;
+                return this.value.equals(o_.value);
+            }
+        }
+
+        public static class Deserializer extends JsonDeserializer<Shape> {
+            @Override
+            public Shape deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+                switch (parser.getCurrentToken()) {
+                case START_OBJECT:
+                    return new PointValue(parser.readValueAs(Point.class));
+                case START_ARRAY:
+                    return new DoubleArrayValue(parser.readValueAs(new TypeReference<List<Double>>() {}));
+                default:
+                    throw ctxt.mappingException("no alternative of the union matches the value");
+                }
+            }
+        }
+    }
+
+    @JsonDeserialize(using = Flag.Deserializer.class)
+    public static abstract class Flag {
+        private Flag() {
+        }
+
+        @JsonDeserialize(using = JsonDeserializer.None.class)
+        public static final class BooleanValue extends Flag {
+            private final boolean value;
+
+            @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+            public BooleanValue(final boolean value) {
+                this.value = value;
+            }
+
+            @JsonValue
+            public boolean getValue() {
+                return this.value;
+            }
+
+            @Override
+            public String toString() {
+                return Boolean.toString(this.value);
+            }
+
+            @Override
+            public int hashCode() {
+                return Boolean.valueOf(this.value).hashCode();
+            }
+
+            @Override
+            public boolean equals(final Object other_) {
+                if (!(other_ instanceof BooleanValue)) {
+                    return false;
+                }
+
+                final BooleanValue o_ = (BooleanValue)other_;
+                return this.value == o_.value;
+            }
+        }
+
+        @JsonDeserialize(using = JsonDeserializer.None.class)
+        public static final class StringValue extends Flag {
+            private final String value;
+
+            @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+            public StringValue(final String value) {
+                Objects.requireNonNull(value, "value: must not be null");
+                this.value = value;
+            }
+
+            @JsonValue
+            public String getValue() {
+                return this.value;
+            }
+
+            @Override
+            public String toString() {
+                return this.value.toString();
+            }
+
+            @Override
+            public int hashCode() {
+                return this.value.hashCode();
+            }
+
+            @Override
+            public boolean equals(final Object other_) {
+                if (!(other_ instanceof StringValue)) {
+                    return false;
+                }
+
+                final StringValue o_ = (StringValue)other_;
+                return this.value.equals(o_.value);
+            }
+        }
+
+        public static class Deserializer extends JsonDeserializer<Flag> {
+            @Override
+            public Flag deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+                switch (parser.getCurrentToken()) {
+                case VALUE_TRUE:
+                case VALUE_FALSE:
+                    return new BooleanValue(parser.getBooleanValue());
+                case VALUE_STRING:
+                    return new StringValue(parser.getText());
+                default:
+                    throw ctxt.mappingException("no alternative of the union matches the value");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Point {
+    @JsonProperty("x")
+    final double x;
+    @JsonProperty("y")
+    final double y;
+
+    @JsonCreator
+    public Point(
+        @JsonProperty("x") double x,
+        @JsonProperty("y") double y
+    ) {
+        this.x = x;
+        this.y = y;
+    }
+
+    @JsonProperty("x")
+    public double getX() {
+        return this.x;
+    }
+
+    @JsonProperty("y")
+    public double getY() {
+        return this.y;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Point(");
+        b.append("x=");
+        b.append(Double.toString(this.x));
+        b.append(", ");
+        b.append("y=");
+        b.append(Double.toString(this.y));
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + Double.valueOf(this.x).hashCode();
+        result = result * 31 + Double.valueOf(this.y).hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Point)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Point o_ = (Point)other_;
+
+        if (this.x != o_.x) {
+            return false;
+        }
+
+        if (this.y != o_.y) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Double> x;
+        private Optional<Double> y;
+
+        private Builder() {
+            this.x = Optional.empty();
+            this.y = Optional.empty();
+        }
+
+        public Point build() {
+            final double x = this.x
+                .orElseThrow(() -> new RuntimeException("x: missing required value"));
+            final double y = this.y
+                .orElseThrow(() -> new RuntimeException("y: missing required value"));
+
+            return new Point(
+                x,
+                y
+            );
+        }
+
+        public Builder x(final double x) {
+            this.x = Optional.of(x);
+            return this;
+        }
+
+        public Builder y(final double y) {
+            this.y = Optional.of(y);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
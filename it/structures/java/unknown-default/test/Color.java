@@ -0,0 +1,88 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public final class Color {
+    public static final Color Red = new Color("red");
+    public static final Color Green = new Color("green");
+
+    final String value;
+
+    private Color(final String value) {
+        this.value = value;
+    }
+
+    public static Color[] values() {
+        return new Color[] {Red, Green};
+    }
+
+    /**
+     * A color which is not known to this version of the schema.
+     */
+    public boolean isOther() {
+        for (final Color v : values()) {
+            if (v == this) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Color(");
+        b.append("value=");
+        b.append(this.value.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.value.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Color)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Color o_ = (Color)other_;
+
+        if (!this.value.equals(o_.value)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    @JsonCreator
+    public static Color fromValue(final String value) {
+        for (final Color v : values()) {
+            if (v.value.equals(value)) {
+                return v;
+            }
+        }
+
+        return new Color(value);
+    }
+
+    @JsonValue
+    public String toValue() {
+        return this.value;
+    }
+}
@@ -0,0 +1,189 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Optional;
+
+public class Entry {
+    @JsonProperty("color")
+    final Optional<Color> color;
+    @JsonProperty("priority")
+    final Optional<Priority> priority;
+    @JsonProperty("tagged")
+    final Optional<Tagged> tagged;
+    @JsonProperty("adjacent")
+    final Optional<Adjacent> adjacent;
+    @JsonProperty("external")
+    final Optional<External> external;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("color") Optional<Color> color,
+        @JsonProperty("priority") Optional<Priority> priority,
+        @JsonProperty("tagged") Optional<Tagged> tagged,
+        @JsonProperty("adjacent") Optional<Adjacent> adjacent,
+        @JsonProperty("external") Optional<External> external
+    ) {
+        this.color = color;
+        this.priority = priority;
+        this.tagged = tagged;
+        this.adjacent = adjacent;
+        this.external = external;
+    }
+
+    @JsonProperty("color")
+    public Optional<Color> getColor() {
+        return this.color;
+    }
+
+    @JsonProperty("priority")
+    public Optional<Priority> getPriority() {
+        return this.priority;
+    }
+
+    @JsonProperty("tagged")
+    public Optional<Tagged> getTagged() {
+        return this.tagged;
+    }
+
+    @JsonProperty("adjacent")
+    public Optional<Adjacent> getAdjacent() {
+        return this.adjacent;
+    }
+
+    @JsonProperty("external")
+    public Optional<External> getExternal() {
+        return this.external;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("color=");
+        b.append(this.color.toString());
+        b.append(", ");
+        b.append("priority=");
+        b.append(this.priority.toString());
+        b.append(", ");
+        b.append("tagged=");
+        b.append(this.tagged.toString());
+        b.append(", ");
+        b.append("adjacent=");
+        b.append(this.adjacent.toString());
+        b.append(", ");
+        b.append("external=");
+        b.append(this.external.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.color.hashCode();
+        result = result * 31 + this.priority.hashCode();
+        result = result * 31 + this.tagged.hashCode();
+        result = result * 31 + this.adjacent.hashCode();
+        result = result * 31 + this.external.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.color.equals(o_.color)) {
+            return false;
+        }
+
+        if (!this.priority.equals(o_.priority)) {
+            return false;
+        }
+
+        if (!this.tagged.equals(o_.tagged)) {
+            return false;
+        }
+
+        if (!this.adjacent.equals(o_.adjacent)) {
+            return false;
+        }
+
+        if (!this.external.equals(o_.external)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<Color> color;
+        private Optional<Priority> priority;
+        private Optional<Tagged> tagged;
+        private Optional<Adjacent> adjacent;
+        private Optional<External> external;
+
+        private Builder() {
+            this.color = Optional.empty();
+            this.priority = Optional.empty();
+            this.tagged = Optional.empty();
+            this.adjacent = Optional.empty();
+            this.external = Optional.empty();
+        }
+
+        public Entry build() {
+
+            return new Entry(
+                this.color,
+                this.priority,
+                this.tagged,
+                this.adjacent,
+                this.external
+            );
+        }
+
+        public Builder color(final Color color) {
+            this.color = Optional.of(color);
+            return this;
+        }
+
+        public Builder priority(final Priority priority) {
+            this.priority = Optional.of(priority);
+            return this;
+        }
+
+        public Builder tagged(final Tagged tagged) {
+            this.tagged = Optional.of(tagged);
+            return this;
+        }
+
+        public Builder adjacent(final Adjacent adjacent) {
+            this.adjacent = Optional.of(adjacent);
+            return this;
+        }
+
+        public Builder external(final External external) {
+            this.external = Optional.of(external);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,168 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import com.fasterxml.jackson.databind.node.TreeTraversingParser;
+import java.io.IOException;
+import java.util.Objects;
+
+@JsonSerialize(using = External.Serializer.class)
+@JsonDeserialize(using = External.Deserializer.class)
+public interface External {
+
+    @JsonSerialize(using = JsonSerializer.None.class)
+    @JsonDeserialize(using = JsonDeserializer.None.class)
+    public static class A implements External {
+
+        @JsonCreator
+        public A() {}
+
+        @Override
+        public String toString() {
+            return "A()";
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof A)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final A o_ = (A)other_;
+
+            return true;
+        }
+
+        public static class Builder {
+
+            private Builder() {}
+
+            public A build() {
+
+                return new A();
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+
+    public static class Other implements External {
+        final Object value;
+
+        public Other(final Object value) {
+            Objects.requireNonNull(value, "value: must not be null");
+            this.value = value;
+        }
+
+        public Object getValue() {
+            return this.value;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Other(");
+            b.append("value=");
+            b.append(this.value.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.value.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Other)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Other o_ = (Other)other_;
+
+            if (!this.value.equals(o_.value)) {
+                return false;
+            }
+
+            return true;
+        }
+    }
+
+    public static class Serializer extends JsonSerializer<External> {
+        @Override
+        public void serialize(final External value, final JsonGenerator generator, final SerializerProvider provider) throws IOException {
+            if (value instanceof External.Other) {
+                generator.writeObject(((External.Other) value).getValue());
+                return;
+            }
+
+            final String tag;
+
+            if (value instanceof External.A) {
+                tag = "A";
+            } else {
+                throw new IllegalArgumentException("unsupported sub-type: " + value);
+            }
+
+            generator.writeStartObject();
+            generator.writeObjectField(tag, value);
+            generator.writeEndObject();
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<External> {
+        @Override
+        public External deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+            final ObjectNode object = parser.readValueAs(ObjectNode.class);
+
+            if (object.size() != 1) {
+                throw ctxt.mappingException("expected exactly one field");
+            }
+
+            final String tag = object.fieldNames().next();
+
+            switch (tag) {
+            case "A":
+                return new TreeTraversingParser(object.get(tag), parser.getCodec()).readValueAs(External.A.class);
+            default:
+                return new External.Other(object);
+            }
+        }
+    }
+}
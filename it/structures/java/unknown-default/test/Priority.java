@@ -0,0 +1,85 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonValue;
+
+public final class Priority {
+    public static final Priority Low = new Priority(1);
+    public static final Priority High = new Priority(2);
+
+    final int value;
+
+    private Priority(final int value) {
+        this.value = value;
+    }
+
+    public static Priority[] values() {
+        return new Priority[] {Low, High};
+    }
+
+    public boolean isOther() {
+        for (final Priority v : values()) {
+            if (v == this) {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Priority(");
+        b.append("value=");
+        b.append(Integer.toString(this.value));
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + Integer.valueOf(this.value).hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Priority)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Priority o_ = (Priority)other_;
+
+        if (this.value != o_.value) {
+            return false;
+        }
+
+        return true;
+    }
+
+    @JsonCreator
+    public static Priority fromValue(final int value) {
+        for (final Priority v : values()) {
+            if (v.value == value) {
+                return v;
+            }
+        }
+
+        return new Priority(value);
+    }
+
+    @JsonValue
+    public int toValue() {
+        return this.value;
+    }
+}
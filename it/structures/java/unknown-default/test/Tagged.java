@@ -0,0 +1,345 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.core.JsonGenerator;
+import com.fasterxml.jackson.core.JsonParser;
+import com.fasterxml.jackson.databind.DeserializationContext;
+import com.fasterxml.jackson.databind.JsonDeserializer;
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.JsonSerializer;
+import com.fasterxml.jackson.databind.SerializerProvider;
+import com.fasterxml.jackson.databind.annotation.JsonDeserialize;
+import com.fasterxml.jackson.databind.annotation.JsonSerialize;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import com.fasterxml.jackson.databind.node.TreeTraversingParser;
+import com.fasterxml.jackson.databind.util.TokenBuffer;
+import java.io.IOException;
+import java.util.Objects;
+import java.util.Optional;
+
+@JsonSerialize(using = Tagged.Serializer.class)
+@JsonDeserialize(using = Tagged.Deserializer.class)
+public interface Tagged {
+    public String getShared();
+
+    @JsonSerialize(using = JsonSerializer.None.class)
+    @JsonDeserialize(using = JsonDeserializer.None.class)
+    public static class A implements Tagged {
+        @JsonProperty("shared")
+        final String shared;
+
+        @JsonCreator
+        public A(
+            @JsonProperty("shared") String shared
+        ) {
+            Objects.requireNonNull(shared, "shared: must not be null");
+            this.shared = shared;
+        }
+
+        @JsonProperty("shared")
+        @Override
+        public String getShared() {
+            return this.shared;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("A(");
+            b.append("shared=");
+            b.append(this.shared.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.shared.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof A)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final A o_ = (A)other_;
+
+            if (!this.shared.equals(o_.shared)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<String> shared;
+
+            private Builder() {
+                this.shared = Optional.empty();
+            }
+
+            public A build() {
+                final String shared = this.shared
+                    .orElseThrow(() -> new RuntimeException("shared: missing required value"));
+
+                return new A(
+                    shared
+                );
+            }
+
+            public Builder shared(final String shared) {
+                this.shared = Optional.of(shared);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    }
+
+    @JsonSerialize(using = JsonSerializer.None.class)
+    @JsonDeserialize(using = JsonDeserializer.None.class)
+    public static class B implements Tagged {
+        @JsonProperty("shared")
+        final String shared;
+        @JsonProperty("b")
+        final String b;
+
+        @JsonCreator
+        public B(
+            @JsonProperty("shared") String shared,
+            @JsonProperty("b") String b
+        ) {
+            Objects.requireNonNull(shared, "shared: must not be null");
+            this.shared = shared;
+            Objects.requireNonNull(b, "b: must not be null");
+            this.b = b;
+        }
+
+        @JsonProperty("shared")
+        @Override
+        public String getShared() {
+            return this.shared;
+        }
+
+        @JsonProperty("b")
+        public String getB() {
+            return this.b;
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("B(");
+            b.append("shared=");
+            b.append(this.shared.toString());
+            b.append(", ");
+            b.append("b=");
+            b.append(this.b.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.shared.hashCode();
+            result = result * 31 + this.b.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof B)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final B o_ = (B)other_;
+
+            if (!this.shared.equals(o_.shared)) {
+                return false;
+            }
+
+            if (!this.b.equals(o_.b)) {
+                return false;
+            }
+
+            return true;
+        }
+
+        public static class Builder {
+            private Optional<String> shared;
+            private Optional<String> b;
+
+            private Builder() {
+                this.shared = Optional.empty();
+                this.b = Optional.empty();
+            }
+
+            public B build() {
+                final String shared = this.shared
+                    .orElseThrow(() -> new RuntimeException("shared: missing required value"));
+                final String b = this.b
+                    .orElseThrow(() -> new RuntimeException("b: missing required value"));
+
+                return new B(
+                    shared,
+                    b
+                );
+            }
+
+            public Builder shared(final String shared) {
+                this.shared = Optional.of(shared);
+                return this;
+            }
+
+            public Builder b(final String b) {
+                this.b = Optional.of(b);
+                return this;
+            }
+        }
+
+        /**
+         * Construct a new builder.
+         */
+        public static Builder builder() {
+            return new Builder();
+        }
+    };
+
+    /**
+     * A sub-type which is not known to this version of the schema.
+     */
+    public static class Other implements Tagged {
+        final Object value;
+
+        public Other(final Object value) {
+            Objects.requireNonNull(value, "value: must not be null");
+            this.value = value;
+        }
+
+        public Object getValue() {
+            return this.value;
+        }
+
+        @Override
+        public String getShared() {
+            throw new UnsupportedOperationException("shared: not available for unknown sub-types");
+        }
+
+        @Override
+        public String toString() {
+            final StringBuilder b = new StringBuilder();
+
+            b.append("Other(");
+            b.append("value=");
+            b.append(this.value.toString());
+            b.append(")");
+
+            return b.toString();
+        }
+
+        @Override
+        public int hashCode() {
+            int result = 1;
+            final StringBuilder b = new StringBuilder();
+            result = result * 31 + this.value.hashCode();
+            return result;
+        }
+
+        @Override
+        public boolean equals(final Object other_) {
+            if (other_ == null) {
+                return false;
+            }
+
+            if (!(other_ instanceof Other)) {
+                return false;
+            }
+
+            @SuppressWarnings("unchecked")
+            final Other o_ = (Other)other_;
+
+            if (!this.value.equals(o_.value)) {
+                return false;
+            }
+
+            return true;
+        }
+    }
+
+    public static class Serializer extends JsonSerializer<Tagged> {
+        @Override
+        public void serialize(final Tagged value, final JsonGenerator generator, final SerializerProvider provider) throws IOException {
+            if (value instanceof Tagged.Other) {
+                generator.writeObject(((Tagged.Other) value).getValue());
+                return;
+            }
+
+            final String tag;
+
+            if (value instanceof Tagged.A) {
+                tag = "foo";
+            } else if (value instanceof Tagged.B) {
+                tag = "B";
+            } else {
+                throw new IllegalArgumentException("unsupported sub-type: " + value);
+            }
+
+            final TokenBuffer buffer = new TokenBuffer(generator.getCodec(), false);
+            buffer.writeObject(value);
+
+            final ObjectNode object = buffer.asParser().readValueAsTree();
+            object.put("type", tag);
+            generator.writeTree(object);
+        }
+    }
+
+    public static class Deserializer extends JsonDeserializer<Tagged> {
+        @Override
+        public Tagged deserialize(final JsonParser parser, final DeserializationContext ctxt) throws IOException {
+            final ObjectNode object = parser.readValueAs(ObjectNode.class);
+
+            final JsonNode tag = object.get("type");
+
+            if (tag == null) {
+                throw ctxt.mappingException("expected field `type`");
+            }
+
+            switch (tag.asText()) {
+            case "foo":
+                object.remove("type");
+                return new TreeTraversingParser(object, parser.getCodec()).readValueAs(Tagged.A.class);
+            case "B":
+                object.remove("type");
+                return new TreeTraversingParser(object, parser.getCodec()).readValueAs(Tagged.B.class);
+            default:
+                return new Tagged.Other(object);
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+package test;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+import java.util.Optional;
+import java.util.regex.Pattern;
+
+public class Entry {
+    @JsonProperty("name")
+    final Optional<String> name;
+    @JsonProperty("code")
+    final Optional<String> code;
+    @JsonProperty("label")
+    final String label;
+    @JsonProperty("count")
+    final Optional<Integer> count;
+    @JsonProperty("offset")
+    final Optional<Long> offset;
+
+    @JsonCreator
+    public Entry(
+        @JsonProperty("name") Optional<String> name,
+        @JsonProperty("code") Optional<String> code,
+        @JsonProperty("label") String label,
+        @JsonProperty("count") Optional<Integer> count,
+        @JsonProperty("offset") Optional<Long> offset
+    ) {
+        if (name.isPresent() && name.get().codePointCount(0, name.get().length()) > 16) {
+            throw new IllegalArgumentException("Entry.name: length must be at most 16");
+        }
+        if (name.isPresent() && !Pattern.matches("[a-z]+", name.get())) {
+            throw new IllegalArgumentException("Entry.name: must match pattern `[a-z]+`");
+        }
+        this.name = name;
+        if (code.isPresent() && code.get().codePointCount(0, code.get().length()) < 2) {
+            throw new IllegalArgumentException("Entry.code: length must be at least 2");
+        }
+        this.code = code;
+        Objects.requireNonNull(label, "label: must not be null");
+        if (label.codePointCount(0, label.length()) < 1) {
+            throw new IllegalArgumentException("Entry.label: length must be at least 1");
+        }
+        this.label = label;
+        if (count.isPresent() && count.get() < 1) {
+            throw new IllegalArgumentException("Entry.count: must be at least 1");
+        }
+        if (count.isPresent() && count.get() > 100) {
+            throw new IllegalArgumentException("Entry.count: must be at most 100");
+        }
+        this.count = count;
+        if (offset.isPresent() && offset.get() <= -10L) {
+            throw new IllegalArgumentException("Entry.offset: must be greater than -10");
+        }
+        if (offset.isPresent() && offset.get() >= 10L) {
+            throw new IllegalArgumentException("Entry.offset: must be less than 10");
+        }
+        this.offset = offset;
+    }
+
+    @JsonProperty("name")
+    public Optional<String> getName() {
+        return this.name;
+    }
+
+    @JsonProperty("code")
+    public Optional<String> getCode() {
+        return this.code;
+    }
+
+    @JsonProperty("label")
+    public String getLabel() {
+        return this.label;
+    }
+
+    @JsonProperty("count")
+    public Optional<Integer> getCount() {
+        return this.count;
+    }
+
+    @JsonProperty("offset")
+    public Optional<Long> getOffset() {
+        return this.offset;
+    }
+
+    @Override
+    public String toString() {
+        final StringBuilder b = new StringBuilder();
+
+        b.append("Entry(");
+        b.append("name=");
+        b.append(this.name.toString());
+        b.append(", ");
+        b.append("code=");
+        b.append(this.code.toString());
+        b.append(", ");
+        b.append("label=");
+        b.append(this.label.toString());
+        b.append(", ");
+        b.append("count=");
+        b.append(this.count.toString());
+        b.append(", ");
+        b.append("offset=");
+        b.append(this.offset.toString());
+        b.append(")");
+
+        return b.toString();
+    }
+
+    @Override
+    public int hashCode() {
+        int result = 1;
+        final StringBuilder b = new StringBuilder();
+        result = result * 31 + this.name.hashCode();
+        result = result * 31 + this.code.hashCode();
+        result = result * 31 + this.label.hashCode();
+        result = result * 31 + this.count.hashCode();
+        result = result * 31 + this.offset.hashCode();
+        return result;
+    }
+
+    @Override
+    public boolean equals(final Object other_) {
+        if (other_ == null) {
+            return false;
+        }
+
+        if (!(other_ instanceof Entry)) {
+            return false;
+        }
+
+        @SuppressWarnings("unchecked")
+        final Entry o_ = (Entry)other_;
+
+        if (!this.name.equals(o_.name)) {
+            return false;
+        }
+
+        if (!this.code.equals(o_.code)) {
+            return false;
+        }
+
+        if (!this.label.equals(o_.label)) {
+            return false;
+        }
+
+        if (!this.count.equals(o_.count)) {
+            return false;
+        }
+
+        if (!this.offset.equals(o_.offset)) {
+            return false;
+        }
+
+        return true;
+    }
+
+    public static class Builder {
+        private Optional<String> name;
+        private Optional<String> code;
+        private Optional<String> label;
+        private Optional<Integer> count;
+        private Optional<Long> offset;
+
+        private Builder() {
+            this.name = Optional.empty();
+            this.code = Optional.empty();
+            this.label = Optional.empty();
+            this.count = Optional.empty();
+            this.offset = Optional.empty();
+        }
+
+        public Entry build() {
+            final String label = this.label
+                .orElseThrow(() -> new RuntimeException("label: missing required value"));
+
+            return new Entry(
+                this.name,
+                this.code,
+                label,
+                this.count,
+                this.offset
+            );
+        }
+
+        public Builder name(final String name) {
+            this.name = Optional.of(name);
+            return this;
+        }
+
+        public Builder code(final String code) {
+            this.code = Optional.of(code);
+            return this;
+        }
+
+        public Builder label(final String label) {
+            this.label = Optional.of(label);
+            return this;
+        }
+
+        public Builder count(final int count) {
+            this.count = Optional.of(count);
+            return this;
+        }
+
+        public Builder offset(final long offset) {
+            this.offset = Optional.of(offset);
+            return this;
+        }
+    }
+
+    /**
+     * Construct a new builder.
+     */
+    public static Builder builder() {
+        return new Builder();
+    }
+}
@@ -0,0 +1,111 @@
+export class Entry {
+  constructor(id, user, tags, owners) {
+    this.id = id;
+    this.user = user;
+    this.tags = tags;
+    this.owners = owners;
+  }
+
+  static decode(data) {
+    let v_id = data["id"];
+
+    if (v_id !== null && v_id !== undefined) {
+      if (!Number.isInteger(v_id)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_id = null;
+    }
+
+    let v_user = data["user"];
+
+    if (v_user !== null && v_user !== undefined) {
+      if (typeof v_user !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_user = null;
+    }
+
+    let v_tags = data["tags"];
+
+    if (v_tags !== null && v_tags !== undefined) {
+      if (!Array.isArray(v_tags)) {
+        throw Error("expected array");
+      }
+
+      let o0 = [];
+
+      for (let i0 = 0, l0 = v_tags.length; i0 < l0; i0++) {
+        let v0 = v_tags[i0];
+
+        if (typeof v0 !== "string") {
+          throw Error("expected string");
+        }
+
+        o0.push(v0);
+      }
+
+      v_tags = o0;
+    } else {
+      v_tags = null;
+    }
+
+    let v_owners = data["owners"];
+
+    if (v_owners !== null && v_owners !== undefined) {
+      if (typeof v_owners !== "object") {
+        throw Error("expected object");
+      }
+
+      let o0 = {};
+
+      for (let [k0, v0] of Object.entries(v_owners)) {
+        if (typeof k0 !== "string") {
+          throw Error("expected string");
+        }
+        if (!Number.isInteger(v0)) {
+          throw Error("expected integer");
+        }
+
+        o0[k0] = v0;
+      }
+
+      v_owners = o0;
+    } else {
+      v_owners = null;
+    }
+
+    return new Entry(v_id, v_user, v_tags, v_owners);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.id !== null && this.id !== undefined) {
+      data["id"] = this.id;
+    }
+
+    if (this.user !== null && this.user !== undefined) {
+      data["user"] = this.user;
+    }
+
+    if (this.tags !== null && this.tags !== undefined) {
+      data["tags"] = this.tags.map(function(v) { return v; });
+    }
+
+    if (this.owners !== null && this.owners !== undefined) {
+      data["owners"] = (function(data) {
+        let o = {};
+
+        for (let k in data) {
+          o[k] = data[k];
+        }
+
+        return o;
+      })(this.owners);
+    }
+
+    return data;
+  }
+}
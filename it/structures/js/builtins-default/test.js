@@ -0,0 +1,118 @@
+export class Entry {
+  constructor(date, time, duration, uuid, decimal, dates) {
+    this.date = date;
+    this.time = time;
+    this.duration = duration;
+    this.uuid = uuid;
+    this.decimal = decimal;
+    this.dates = dates;
+  }
+
+  static decode(data) {
+    let v_date = data["date"];
+
+    if (v_date !== null && v_date !== undefined) {
+      if (typeof v_date !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_date = null;
+    }
+
+    let v_time = data["time"];
+
+    if (v_time !== null && v_time !== undefined) {
+      if (typeof v_time !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_time = null;
+    }
+
+    let v_duration = data["duration"];
+
+    if (v_duration !== null && v_duration !== undefined) {
+      if (typeof v_duration !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_duration = null;
+    }
+
+    let v_uuid = data["uuid"];
+
+    if (v_uuid !== null && v_uuid !== undefined) {
+      if (typeof v_uuid !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_uuid = null;
+    }
+
+    let v_decimal = data["decimal"];
+
+    if (v_decimal !== null && v_decimal !== undefined) {
+      if (typeof v_decimal !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_decimal = null;
+    }
+
+    let v_dates = data["dates"];
+
+    if (v_dates !== null && v_dates !== undefined) {
+      if (!Array.isArray(v_dates)) {
+        throw Error("expected array");
+      }
+
+      let o0 = [];
+
+      for (let i0 = 0, l0 = v_dates.length; i0 < l0; i0++) {
+        let v0 = v_dates[i0];
+
+        if (typeof v0 !== "string") {
+          throw Error("expected string");
+        }
+
+        o0.push(v0);
+      }
+
+      v_dates = o0;
+    } else {
+      v_dates = null;
+    }
+
+    return new Entry(v_date, v_time, v_duration, v_uuid, v_decimal, v_dates);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.date !== null && this.date !== undefined) {
+      data["date"] = this.date;
+    }
+
+    if (this.time !== null && this.time !== undefined) {
+      data["time"] = this.time;
+    }
+
+    if (this.duration !== null && this.duration !== undefined) {
+      data["duration"] = this.duration;
+    }
+
+    if (this.uuid !== null && this.uuid !== undefined) {
+      data["uuid"] = this.uuid;
+    }
+
+    if (this.decimal !== null && this.decimal !== undefined) {
+      data["decimal"] = this.decimal;
+    }
+
+    if (this.dates !== null && this.dates !== undefined) {
+      data["dates"] = this.dates.map(function(v) { return v; });
+    }
+
+    return data;
+  }
+}
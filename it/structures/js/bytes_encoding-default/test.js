@@ -0,0 +1,130 @@
+export class Entry {
+  constructor(standard, url, token, digest, pair) {
+    this.standard = standard;
+    this.url = url;
+    this.token = token;
+    this.digest = digest;
+    this.pair = pair;
+  }
+
+  static decode(data) {
+    let v_standard = data["standard"];
+
+    if (v_standard !== null && v_standard !== undefined) {
+      if (typeof v_standard !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_standard = null;
+    }
+
+    let v_url = data["url"];
+
+    if (v_url !== null && v_url !== undefined) {
+      if (typeof v_url !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_url = null;
+    }
+
+    let v_token = data["token"];
+
+    if (v_token !== null && v_token !== undefined) {
+      if (typeof v_token !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_token = null;
+    }
+
+    let v_digest = data["digest"];
+
+    if (v_digest !== null && v_digest !== undefined) {
+      if (typeof v_digest !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_digest = null;
+    }
+
+    let v_pair = data["pair"];
+
+    if (v_pair !== null && v_pair !== undefined) {
+      v_pair = Pair.decode(v_pair);
+    } else {
+      v_pair = null;
+    }
+
+    return new Entry(v_standard, v_url, v_token, v_digest, v_pair);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.standard !== null && this.standard !== undefined) {
+      data["standard"] = this.standard;
+    }
+
+    if (this.url !== null && this.url !== undefined) {
+      data["url"] = this.url;
+    }
+
+    if (this.token !== null && this.token !== undefined) {
+      data["token"] = this.token;
+    }
+
+    if (this.digest !== null && this.digest !== undefined) {
+      data["digest"] = this.digest;
+    }
+
+    if (this.pair !== null && this.pair !== undefined) {
+      data["pair"] = this.pair.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Pair {
+  constructor(key, value) {
+    this.key = key;
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_key = data[0];
+
+    if (v_key === null || v_key === undefined) {
+      throw new Error(0 + ": required field");
+    }
+
+    if (typeof v_key !== "string") {
+      throw Error("expected string");
+    }
+
+    let v_value = data[1];
+
+    if (v_value === null || v_value === undefined) {
+      throw new Error(1 + ": required field");
+    }
+
+    if (typeof v_value !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Pair(v_key, v_value);
+  }
+
+  encode() {
+    if (this.key === null || this.key === undefined) {
+      throw new Error("key: is a required field");
+    }
+
+    if (this.value === null || this.value === undefined) {
+      throw new Error("value: is a required field");
+    }
+
+    return [this.key, this.value];
+  }
+}
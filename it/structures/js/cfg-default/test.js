@@ -0,0 +1,69 @@
+export class Entry {
+  constructor(id, color) {
+    this.id = id;
+    this.color = color;
+  }
+
+  static decode(data) {
+    let v_id = data["id"];
+
+    if (v_id !== null && v_id !== undefined) {
+      if (typeof v_id !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_id = null;
+    }
+
+    let v_color = data["color"];
+
+    if (v_color !== null && v_color !== undefined) {
+      v_color = Color.decode(v_color);
+    } else {
+      v_color = null;
+    }
+
+    return new Entry(v_id, v_color);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.id !== null && this.id !== undefined) {
+      data["id"] = this.id;
+    }
+
+    if (this.color !== null && this.color !== undefined) {
+      data["color"] = this.color.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Color {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+
+  static decode(data) {
+    for (let i = 0, l = Color.values.length; i < l; i++) {
+      const member = Color.values[i];
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no value matching: " + data);
+  }
+}
+
+Color.Red = new Color("Red", "red");
+
+Color.values = [Color.Red];
@@ -0,0 +1,200 @@
+export class Entry {
+  constructor(by_color, by_id, tags, levels) {
+    this.by_color = by_color;
+    this.by_id = by_id;
+    this.tags = tags;
+    this.levels = levels;
+  }
+
+  static decode(data) {
+    let v_by_color = data["by_color"];
+
+    if (v_by_color !== null && v_by_color !== undefined) {
+      if (typeof v_by_color !== "object") {
+        throw Error("expected object");
+      }
+
+      let o0 = {};
+
+      for (let [k0, v0] of Object.entries(v_by_color)) {
+        Color.decode(k0);
+        if (!Number.isInteger(v0)) {
+          throw Error("expected integer");
+        }
+
+        o0[k0] = v0;
+      }
+
+      v_by_color = o0;
+    } else {
+      v_by_color = null;
+    }
+
+    let v_by_id = data["by_id"];
+
+    if (v_by_id !== null && v_by_id !== undefined) {
+      if (typeof v_by_id !== "object") {
+        throw Error("expected object");
+      }
+
+      let o0 = {};
+
+      for (let [k0, v0] of Object.entries(v_by_id)) {
+        if (!Number.isInteger(Number(k0))) {
+          throw Error("expected integer");
+        }
+        if (typeof v0 !== "string") {
+          throw Error("expected string");
+        }
+
+        o0[k0] = v0;
+      }
+
+      v_by_id = o0;
+    } else {
+      v_by_id = null;
+    }
+
+    let v_tags = data["tags"];
+
+    if (v_tags !== null && v_tags !== undefined) {
+      if (!Array.isArray(v_tags)) {
+        throw Error("expected array");
+      }
+
+      let o0 = [];
+
+      for (let i0 = 0, l0 = v_tags.length; i0 < l0; i0++) {
+        let v0 = v_tags[i0];
+
+        if (typeof v0 !== "string") {
+          throw Error("expected string");
+        }
+
+        o0.push(v0);
+      }
+
+      v_tags = o0;
+    } else {
+      v_tags = null;
+    }
+
+    let v_levels = data["levels"];
+
+    if (v_levels !== null && v_levels !== undefined) {
+      if (!Array.isArray(v_levels)) {
+        throw Error("expected array");
+      }
+
+      let o0 = [];
+
+      for (let i0 = 0, l0 = v_levels.length; i0 < l0; i0++) {
+        let v0 = v_levels[i0];
+
+        v0 = Level.decode(v0);
+
+        o0.push(v0);
+      }
+
+      v_levels = o0;
+    } else {
+      v_levels = null;
+    }
+
+    return new Entry(v_by_color, v_by_id, v_tags, v_levels);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.by_color !== null && this.by_color !== undefined) {
+      data["by_color"] = (function(data) {
+        let o = {};
+
+        for (let k in data) {
+          o[k] = data[k];
+        }
+
+        return o;
+      })(this.by_color);
+    }
+
+    if (this.by_id !== null && this.by_id !== undefined) {
+      data["by_id"] = (function(data) {
+        let o = {};
+
+        for (let k in data) {
+          o[k] = data[k];
+        }
+
+        return o;
+      })(this.by_id);
+    }
+
+    if (this.tags !== null && this.tags !== undefined) {
+      data["tags"] = this.tags.map(function(v) { return v; });
+    }
+
+    if (this.levels !== null && this.levels !== undefined) {
+      data["levels"] = this.levels.map(function(v) { return v.encode(); });
+    }
+
+    return data;
+  }
+}
+
+export class Color {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+
+  static decode(data) {
+    for (let i = 0, l = Color.values.length; i < l; i++) {
+      const member = Color.values[i];
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no value matching: " + data);
+  }
+}
+
+Color.Red = new Color("Red", "red");
+Color.Green = new Color("Green", "green");
+
+Color.values = [Color.Red, Color.Green];
+
+export class Level {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+
+  static decode(data) {
+    for (let i = 0, l = Level.values.length; i < l; i++) {
+      const member = Level.values[i];
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no value matching: " + data);
+  }
+}
+
+Level.Low = new Level("Low", 1);
+Level.High = new Level("High", 2);
+
+Level.values = [Level.Low, Level.High];
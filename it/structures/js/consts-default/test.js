@@ -0,0 +1,46 @@
+export const DEFAULT_LIMIT = 100;
+
+export const MAX_OFFSET = -1;
+
+export const GREETING = "hello";
+
+export const RATIO = 0.5;
+
+export const SCALE = 2;
+
+export const ENABLED = true;
+
+/** @deprecated use DefaultLimit */
+export const OLD_LIMIT = 10;
+
+export class Entry {
+  constructor(limit) {
+    this.limit = limit;
+  }
+
+  static decode(data) {
+    let v_limit = data["limit"];
+
+    if (v_limit !== null && v_limit !== undefined) {
+      if (!Number.isInteger(v_limit)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_limit = null;
+    }
+
+    return new Entry(v_limit);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.limit !== null && this.limit !== undefined) {
+      data["limit"] = this.limit;
+    }
+
+    return data;
+  }
+}
+
+export const ENTRY_PAGE_SIZE = 20;
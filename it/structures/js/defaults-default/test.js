@@ -0,0 +1,180 @@
+export class Entry {
+  constructor(name, count, big, ratio, scale, enabled, label, shape) {
+    this.name = name;
+    this.count = count;
+    this.big = big;
+    this.ratio = ratio;
+    this.scale = scale;
+    this.enabled = enabled;
+    this.label = label;
+    this.shape = shape;
+  }
+
+  static decode(data) {
+    let v_name = data["name"];
+
+    if (v_name !== null && v_name !== undefined) {
+      if (typeof v_name !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_name = "unknown";
+    }
+
+    let v_count = data["count"];
+
+    if (v_count !== null && v_count !== undefined) {
+      if (!Number.isInteger(v_count)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_count = 42;
+    }
+
+    let v_big = data["big"];
+
+    if (v_big !== null && v_big !== undefined) {
+      if (!Number.isInteger(v_big)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_big = -1;
+    }
+
+    let v_ratio = data["ratio"];
+
+    if (v_ratio !== null && v_ratio !== undefined) {
+      if (!Number.isFinite(v_ratio)) {
+        throw Error("expected float");
+      }
+    } else {
+      v_ratio = 0.5;
+    }
+
+    let v_scale = data["scale"];
+
+    if (v_scale !== null && v_scale !== undefined) {
+      if (!Number.isFinite(v_scale)) {
+        throw Error("expected float");
+      }
+    } else {
+      v_scale = 2;
+    }
+
+    let v_enabled = data["enabled"];
+
+    if (v_enabled !== null && v_enabled !== undefined) {
+      if (typeof v_enabled !== "boolean") {
+        throw Error("expected boolean");
+      }
+    } else {
+      v_enabled = true;
+    }
+
+    let v_label = data["label"];
+
+    if (v_label !== null && v_label !== undefined) {
+      if (typeof v_label !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_label = null;
+    }
+
+    let v_shape = data["shape"];
+
+    if (v_shape !== null && v_shape !== undefined) {
+      v_shape = Shape.decode(v_shape);
+    } else {
+      v_shape = null;
+    }
+
+    return new Entry(v_name, v_count, v_big, v_ratio, v_scale, v_enabled, v_label, v_shape);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.name !== null && this.name !== undefined) {
+      data["name"] = this.name;
+    }
+
+    if (this.count !== null && this.count !== undefined) {
+      data["count"] = this.count;
+    }
+
+    if (this.big !== null && this.big !== undefined) {
+      data["big"] = this.big;
+    }
+
+    if (this.ratio !== null && this.ratio !== undefined) {
+      data["ratio"] = this.ratio;
+    }
+
+    if (this.scale !== null && this.scale !== undefined) {
+      data["scale"] = this.scale;
+    }
+
+    if (this.enabled !== null && this.enabled !== undefined) {
+      data["enabled"] = this.enabled;
+    }
+
+    if (this.label !== null && this.label !== undefined) {
+      data["label"] = this.label;
+    }
+
+    if (this.shape !== null && this.shape !== undefined) {
+      data["shape"] = this.shape.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Shape {
+  static decode(data) {
+    const f_tag = data["type"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: type");
+    }
+
+    if (f_tag === "Circle") {
+      return Shape_Circle.decode(data);
+    }
+
+    throw new Error("bad sub-type: " + f_tag);
+  }
+}
+
+export class Shape_Circle {
+  constructor(radius) {
+    this.radius = radius;
+  }
+
+  static decode(data) {
+    let v_radius = data["radius"];
+
+    if (v_radius !== null && v_radius !== undefined) {
+      if (!Number.isFinite(v_radius)) {
+        throw Error("expected float");
+      }
+    } else {
+      v_radius = 1;
+    }
+
+    return new Shape_Circle(v_radius);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "Circle";
+
+    if (this.radius !== null && this.radius !== undefined) {
+      data["radius"] = this.radius;
+    }
+
+    return data;
+  }
+}
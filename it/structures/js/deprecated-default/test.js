@@ -0,0 +1,303 @@
+export class Entry {
+  constructor(legacy, current, status, point, shape) {
+    this.legacy = legacy;
+    this.current = current;
+    this.status = status;
+    this.point = point;
+    this.shape = shape;
+  }
+
+  static decode(data) {
+    let v_legacy = data["legacy"];
+
+    if (v_legacy !== null && v_legacy !== undefined) {
+      v_legacy = Legacy.decode(v_legacy);
+    } else {
+      v_legacy = null;
+    }
+
+    let v_current = data["current"];
+
+    if (v_current !== null && v_current !== undefined) {
+      v_current = Current.decode(v_current);
+    } else {
+      v_current = null;
+    }
+
+    let v_status = data["status"];
+
+    if (v_status !== null && v_status !== undefined) {
+      v_status = Status.decode(v_status);
+    } else {
+      v_status = null;
+    }
+
+    let v_point = data["point"];
+
+    if (v_point !== null && v_point !== undefined) {
+      v_point = Point.decode(v_point);
+    } else {
+      v_point = null;
+    }
+
+    let v_shape = data["shape"];
+
+    if (v_shape !== null && v_shape !== undefined) {
+      v_shape = Shape.decode(v_shape);
+    } else {
+      v_shape = null;
+    }
+
+    return new Entry(v_legacy, v_current, v_status, v_point, v_shape);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.legacy !== null && this.legacy !== undefined) {
+      data["legacy"] = this.legacy.encode();
+    }
+
+    if (this.current !== null && this.current !== undefined) {
+      data["current"] = this.current.encode();
+    }
+
+    if (this.status !== null && this.status !== undefined) {
+      data["status"] = this.status.encode();
+    }
+
+    if (this.point !== null && this.point !== undefined) {
+      data["point"] = this.point.encode();
+    }
+
+    if (this.shape !== null && this.shape !== undefined) {
+      data["shape"] = this.shape.encode();
+    }
+
+    return data;
+  }
+}
+
+/** @deprecated use `Current` instead */
+export class Legacy {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value === null || v_value === undefined) {
+      throw new Error("value" + ": required field");
+    }
+
+    if (typeof v_value !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Legacy(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value === null || this.value === undefined) {
+      throw new Error("value: is a required field");
+    }
+
+    data["value"] = this.value;
+
+    return data;
+  }
+}
+
+export class Current {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value === null || v_value === undefined) {
+      throw new Error("value" + ": required field");
+    }
+
+    if (typeof v_value !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Current(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value === null || this.value === undefined) {
+      throw new Error("value: is a required field");
+    }
+
+    data["value"] = this.value;
+
+    return data;
+  }
+}
+
+export class Status {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+
+  static decode(data) {
+    for (let i = 0, l = Status.values.length; i < l; i++) {
+      const member = Status.values[i];
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    throw new Error("no value matching: " + data);
+  }
+}
+
+Status.Active = new Status("Active", "active");
+/** @deprecated */
+Status.Retired = new Status("Retired", "retired");
+
+Status.values = [Status.Active, Status.Retired];
+
+/** @deprecated since 1.0.0 */
+export class Point {
+  constructor(x, y) {
+    this.x = x;
+    this.y = y;
+  }
+
+  static decode(data) {
+    let v_x = data[0];
+
+    if (v_x === null || v_x === undefined) {
+      throw new Error(0 + ": required field");
+    }
+
+    if (!Number.isInteger(v_x)) {
+      throw Error("expected integer");
+    }
+
+    let v_y = data[1];
+
+    if (v_y === null || v_y === undefined) {
+      throw new Error(1 + ": required field");
+    }
+
+    if (!Number.isInteger(v_y)) {
+      throw Error("expected integer");
+    }
+
+    return new Point(v_x, v_y);
+  }
+
+  encode() {
+    if (this.x === null || this.x === undefined) {
+      throw new Error("x: is a required field");
+    }
+
+    if (this.y === null || this.y === undefined) {
+      throw new Error("y: is a required field");
+    }
+
+    return [this.x, this.y];
+  }
+}
+
+export class Shape {
+  static decode(data) {
+    const f_tag = data["type"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: type");
+    }
+
+    if (f_tag === "Circle") {
+      return Shape_Circle.decode(data);
+    }if (f_tag === "Round") {
+      return Shape_Round.decode(data);
+    }
+
+    throw new Error("bad sub-type: " + f_tag);
+  }
+}
+
+export class Shape_Circle {
+  constructor(radius) {
+    this.radius = radius;
+  }
+
+  static decode(data) {
+    let v_radius = data["radius"];
+
+    if (v_radius === null || v_radius === undefined) {
+      throw new Error("radius" + ": required field");
+    }
+
+    if (!Number.isFinite(v_radius)) {
+      throw Error("expected float");
+    }
+
+    return new Shape_Circle(v_radius);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "Circle";
+
+    if (this.radius === null || this.radius === undefined) {
+      throw new Error("radius: is a required field");
+    }
+
+    data["radius"] = this.radius;
+
+    return data;
+  }
+}
+/** @deprecated use `Circle` instead */
+export class Shape_Round {
+  constructor(radius) {
+    this.radius = radius;
+  }
+
+  static decode(data) {
+    let v_radius = data["radius"];
+
+    if (v_radius === null || v_radius === undefined) {
+      throw new Error("radius" + ": required field");
+    }
+
+    if (!Number.isFinite(v_radius)) {
+      throw Error("expected float");
+    }
+
+    return new Shape_Round(v_radius);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "Round";
+
+    if (this.radius === null || this.radius === undefined) {
+      throw new Error("radius: is a required field");
+    }
+
+    data["radius"] = this.radius;
+
+    return data;
+  }
+}
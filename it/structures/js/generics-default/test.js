@@ -0,0 +1,348 @@
+export class Entry {
+  constructor(page, names, pair, outcome) {
+    this.page = page;
+    this.names = names;
+    this.pair = pair;
+    this.outcome = outcome;
+  }
+
+  static decode(data) {
+    let v_page = data["page"];
+
+    if (v_page !== null && v_page !== undefined) {
+      v_page = PageItem.decode(v_page);
+    } else {
+      v_page = null;
+    }
+
+    let v_names = data["names"];
+
+    if (v_names !== null && v_names !== undefined) {
+      v_names = PageString.decode(v_names);
+    } else {
+      v_names = null;
+    }
+
+    let v_pair = data["pair"];
+
+    if (v_pair !== null && v_pair !== undefined) {
+      v_pair = PairItemU32.decode(v_pair);
+    } else {
+      v_pair = null;
+    }
+
+    let v_outcome = data["outcome"];
+
+    if (v_outcome !== null && v_outcome !== undefined) {
+      v_outcome = OutcomeItem.decode(v_outcome);
+    } else {
+      v_outcome = null;
+    }
+
+    return new Entry(v_page, v_names, v_pair, v_outcome);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.page !== null && this.page !== undefined) {
+      data["page"] = this.page.encode();
+    }
+
+    if (this.names !== null && this.names !== undefined) {
+      data["names"] = this.names.encode();
+    }
+
+    if (this.pair !== null && this.pair !== undefined) {
+      data["pair"] = this.pair.encode();
+    }
+
+    if (this.outcome !== null && this.outcome !== undefined) {
+      data["outcome"] = this.outcome.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Item {
+  constructor(name) {
+    this.name = name;
+  }
+
+  static decode(data) {
+    let v_name = data["name"];
+
+    if (v_name === null || v_name === undefined) {
+      throw new Error("name" + ": required field");
+    }
+
+    if (typeof v_name !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Item(v_name);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.name === null || this.name === undefined) {
+      throw new Error("name: is a required field");
+    }
+
+    data["name"] = this.name;
+
+    return data;
+  }
+}
+
+export class PageItem {
+  constructor(items, next) {
+    this.items = items;
+    this.next = next;
+  }
+
+  static decode(data) {
+    let v_items = data["items"];
+
+    if (v_items === null || v_items === undefined) {
+      throw new Error("items" + ": required field");
+    }
+
+    if (!Array.isArray(v_items)) {
+      throw Error("expected array");
+    }
+
+    let o0 = [];
+
+    for (let i0 = 0, l0 = v_items.length; i0 < l0; i0++) {
+      let v0 = v_items[i0];
+
+      v0 = Item.decode(v0);
+
+      o0.push(v0);
+    }
+
+    v_items = o0;
+
+    let v_next = data["next"];
+
+    if (v_next !== null && v_next !== undefined) {
+      if (typeof v_next !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_next = null;
+    }
+
+    return new PageItem(v_items, v_next);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.items === null || this.items === undefined) {
+      throw new Error("items: is a required field");
+    }
+
+    data["items"] = this.items.map(function(v) { return v.encode(); });
+
+    if (this.next !== null && this.next !== undefined) {
+      data["next"] = this.next;
+    }
+
+    return data;
+  }
+}
+
+export class PageString {
+  constructor(items, next) {
+    this.items = items;
+    this.next = next;
+  }
+
+  static decode(data) {
+    let v_items = data["items"];
+
+    if (v_items === null || v_items === undefined) {
+      throw new Error("items" + ": required field");
+    }
+
+    if (!Array.isArray(v_items)) {
+      throw Error("expected array");
+    }
+
+    let o0 = [];
+
+    for (let i0 = 0, l0 = v_items.length; i0 < l0; i0++) {
+      let v0 = v_items[i0];
+
+      if (typeof v0 !== "string") {
+        throw Error("expected string");
+      }
+
+      o0.push(v0);
+    }
+
+    v_items = o0;
+
+    let v_next = data["next"];
+
+    if (v_next !== null && v_next !== undefined) {
+      if (typeof v_next !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_next = null;
+    }
+
+    return new PageString(v_items, v_next);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.items === null || this.items === undefined) {
+      throw new Error("items: is a required field");
+    }
+
+    data["items"] = this.items.map(function(v) { return v; });
+
+    if (this.next !== null && this.next !== undefined) {
+      data["next"] = this.next;
+    }
+
+    return data;
+  }
+}
+
+export class PairItemU32 {
+  constructor(first, second) {
+    this.first = first;
+    this.second = second;
+  }
+
+  static decode(data) {
+    let v_first = data["first"];
+
+    if (v_first === null || v_first === undefined) {
+      throw new Error("first" + ": required field");
+    }
+
+    v_first = Item.decode(v_first);
+
+    let v_second = data["second"];
+
+    if (v_second === null || v_second === undefined) {
+      throw new Error("second" + ": required field");
+    }
+
+    if (!Number.isInteger(v_second)) {
+      throw Error("expected integer");
+    }
+
+    return new PairItemU32(v_first, v_second);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.first === null || this.first === undefined) {
+      throw new Error("first: is a required field");
+    }
+
+    data["first"] = this.first.encode();
+
+    if (this.second === null || this.second === undefined) {
+      throw new Error("second: is a required field");
+    }
+
+    data["second"] = this.second;
+
+    return data;
+  }
+}
+
+export class OutcomeItem {
+  static decode(data) {
+    const f_tag = data["type"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: type");
+    }
+
+    if (f_tag === "Ok") {
+      return OutcomeItem_Ok.decode(data);
+    }if (f_tag === "Error") {
+      return OutcomeItem_Error.decode(data);
+    }
+
+    throw new Error("bad sub-type: " + f_tag);
+  }
+}
+
+export class OutcomeItem_Ok {
+  constructor(value) {
+    this.value = value;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value === null || v_value === undefined) {
+      throw new Error("value" + ": required field");
+    }
+
+    v_value = Item.decode(v_value);
+
+    return new OutcomeItem_Ok(v_value);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "Ok";
+
+    if (this.value === null || this.value === undefined) {
+      throw new Error("value: is a required field");
+    }
+
+    data["value"] = this.value.encode();
+
+    return data;
+  }
+}
+export class OutcomeItem_Error {
+  constructor(message) {
+    this.message = message;
+  }
+
+  static decode(data) {
+    let v_message = data["message"];
+
+    if (v_message === null || v_message === undefined) {
+      throw new Error("message" + ": required field");
+    }
+
+    if (typeof v_message !== "string") {
+      throw Error("expected string");
+    }
+
+    return new OutcomeItem_Error(v_message);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "Error";
+
+    if (this.message === null || this.message === undefined) {
+      throw new Error("message: is a required field");
+    }
+
+    data["message"] = this.message;
+
+    return data;
+  }
+}
@@ -0,0 +1,224 @@
+export class Entry {
+  constructor(created_at, updated_at, updated_by, id, name) {
+    this.created_at = created_at;
+    this.updated_at = updated_at;
+    this.updated_by = updated_by;
+    this.id = id;
+    this.name = name;
+  }
+
+  static decode(data) {
+    let v_created_at = data["created_at"];
+
+    if (v_created_at !== null && v_created_at !== undefined) {
+      if (typeof v_created_at !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_created_at = null;
+    }
+
+    let v_updated_at = data["updated_at"];
+
+    if (v_updated_at !== null && v_updated_at !== undefined) {
+      if (typeof v_updated_at !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_updated_at = null;
+    }
+
+    let v_updated_by = data["updated_by"];
+
+    if (v_updated_by !== null && v_updated_by !== undefined) {
+      if (typeof v_updated_by !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_updated_by = null;
+    }
+
+    let v_id = data["id"];
+
+    if (v_id !== null && v_id !== undefined) {
+      if (typeof v_id !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_id = null;
+    }
+
+    let v_name = data["name"];
+
+    if (v_name !== null && v_name !== undefined) {
+      if (typeof v_name !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_name = null;
+    }
+
+    return new Entry(v_created_at, v_updated_at, v_updated_by, v_id, v_name);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.created_at !== null && this.created_at !== undefined) {
+      data["created_at"] = this.created_at;
+    }
+
+    if (this.updated_at !== null && this.updated_at !== undefined) {
+      data["updated_at"] = this.updated_at;
+    }
+
+    if (this.updated_by !== null && this.updated_by !== undefined) {
+      data["updated_by"] = this.updated_by;
+    }
+
+    if (this.id !== null && this.id !== undefined) {
+      data["id"] = this.id;
+    }
+
+    if (this.name !== null && this.name !== undefined) {
+      data["name"] = this.name;
+    }
+
+    return data;
+  }
+}
+
+export class Auditable {
+  constructor(created_at, updated_at, updated_by) {
+    this.created_at = created_at;
+    this.updated_at = updated_at;
+    this.updated_by = updated_by;
+  }
+
+  static decode(data) {
+    let v_created_at = data["created_at"];
+
+    if (v_created_at !== null && v_created_at !== undefined) {
+      if (typeof v_created_at !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_created_at = null;
+    }
+
+    let v_updated_at = data["updated_at"];
+
+    if (v_updated_at !== null && v_updated_at !== undefined) {
+      if (typeof v_updated_at !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_updated_at = null;
+    }
+
+    let v_updated_by = data["updated_by"];
+
+    if (v_updated_by !== null && v_updated_by !== undefined) {
+      if (typeof v_updated_by !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_updated_by = null;
+    }
+
+    return new Auditable(v_created_at, v_updated_at, v_updated_by);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.created_at !== null && this.created_at !== undefined) {
+      data["created_at"] = this.created_at;
+    }
+
+    if (this.updated_at !== null && this.updated_at !== undefined) {
+      data["updated_at"] = this.updated_at;
+    }
+
+    if (this.updated_by !== null && this.updated_by !== undefined) {
+      data["updated_by"] = this.updated_by;
+    }
+
+    return data;
+  }
+}
+
+export class Timestamped {
+  constructor(created_at, updated_at) {
+    this.created_at = created_at;
+    this.updated_at = updated_at;
+  }
+
+  static decode(data) {
+    let v_created_at = data["created_at"];
+
+    if (v_created_at !== null && v_created_at !== undefined) {
+      if (typeof v_created_at !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_created_at = null;
+    }
+
+    let v_updated_at = data["updated_at"];
+
+    if (v_updated_at !== null && v_updated_at !== undefined) {
+      if (typeof v_updated_at !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_updated_at = null;
+    }
+
+    return new Timestamped(v_created_at, v_updated_at);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.created_at !== null && this.created_at !== undefined) {
+      data["created_at"] = this.created_at;
+    }
+
+    if (this.updated_at !== null && this.updated_at !== undefined) {
+      data["updated_at"] = this.updated_at;
+    }
+
+    return data;
+  }
+}
+
+export class Identified {
+  constructor(id) {
+    this.id = id;
+  }
+
+  static decode(data) {
+    let v_id = data["id"];
+
+    if (v_id !== null && v_id !== undefined) {
+      if (typeof v_id !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_id = null;
+    }
+
+    return new Identified(v_id);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.id !== null && this.id !== undefined) {
+      data["id"] = this.id;
+    }
+
+    return data;
+  }
+}
@@ -0,0 +1,147 @@
+export class Entry {
+  constructor() {}
+
+  static decode(data) {
+
+    return new Entry();
+  }
+
+  encode() {
+    const data = {};
+
+    return data;
+  }
+}
+
+export class Message {
+  constructor(text) {
+    this.text = text;
+  }
+
+  static decode(data) {
+    let v_text = data["text"];
+
+    if (v_text === null || v_text === undefined) {
+      throw new Error("text" + ": required field");
+    }
+
+    if (typeof v_text !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Message(v_text);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.text === null || this.text === undefined) {
+      throw new Error("text: is a required field");
+    }
+
+    data["text"] = this.text;
+
+    return data;
+  }
+}
+
+class MyService_WebSocket_Sender {
+  constructor(socket, endpoint, encode) {
+    this.socket = socket;
+    this.endpoint = endpoint;
+    this.encode = encode;
+  }
+
+  send(value) {
+    this.socket.send(JSON.stringify({endpoint: this.endpoint, body: this.encode(value)}));
+  }
+
+  close() {
+    this.socket.close();
+  }
+}
+
+class MyService_WebSocket_Receiver {
+  constructor(socket, endpoint, decode) {
+    this.queue = [];
+    this.waiting = [];
+    this.closed = false;
+
+    socket.addEventListener("message", (event) => {
+      if (typeof event.data !== "string") {
+        return;
+      }
+
+      let envelope;
+
+      try {
+        envelope = JSON.parse(event.data);
+      } catch (e) {
+        this.push(() => { throw e; });
+        return;
+      }
+
+      if (envelope === null || typeof envelope !== "object" || envelope.endpoint !== endpoint || !("body" in envelope)) {
+        return;
+      }
+
+      this.push(() => decode(envelope.body));
+    });
+
+    socket.addEventListener("close", () => {
+      this.closed = true;
+
+      for (const resolve of this.waiting.splice(0)) {
+        resolve(() => null);
+      }
+    });
+  }
+
+  recv() {
+    if (this.queue.length > 0) {
+      return Promise.resolve().then(this.queue.shift());
+    }
+
+    if (this.closed) {
+      return Promise.resolve(null);
+    }
+
+    return new Promise((resolve) => this.waiting.push(resolve)).then((next) => next());
+  }
+
+  push(next) {
+    if (this.waiting.length > 0) {
+      this.waiting.shift()(next);
+    } else {
+      this.queue.push(next);
+    }
+  }
+}
+
+export class MyService_WebSocket {
+  constructor(url, WebSocketImpl) {
+    if (url === null || url === undefined) {
+      url = "ws://example.com";
+    }
+
+    this.url = url;
+    this.WebSocket = WebSocketImpl || WebSocket;
+  }
+
+  chat() {
+    const socket = new this.WebSocket(this.url + "/chat");
+
+    const sender = new MyService_WebSocket_Sender(socket, "chat", (value) => value.encode());
+
+    const receiver = new MyService_WebSocket_Receiver(socket, "chat", (data) => {
+      data = Message.decode(data);
+
+      return data;
+    });
+
+    return new Promise((resolve, reject) => {
+      socket.addEventListener("open", () => resolve([sender, receiver]));
+      socket.addEventListener("error", (e) => reject(e));
+    });
+  }
+}
@@ -0,0 +1,138 @@
+export class Entry {
+  constructor(id, offset, size, count, pair) {
+    this.id = id;
+    this.offset = offset;
+    this.size = size;
+    this.count = count;
+    this.pair = pair;
+  }
+
+  static decode(data) {
+    let v_id = data["id"];
+
+    if (v_id === null || v_id === undefined) {
+      throw new Error("id" + ": required field");
+    }
+
+    if (typeof v_id !== "string") {
+      throw Error("expected string");
+    }
+
+    v_id = BigInt(v_id);
+
+    let v_offset = data["offset"];
+
+    if (v_offset !== null && v_offset !== undefined) {
+      if (typeof v_offset !== "string") {
+        throw Error("expected string");
+      }
+
+      v_offset = BigInt(v_offset);
+    } else {
+      v_offset = null;
+    }
+
+    let v_size = data["size"];
+
+    if (v_size !== null && v_size !== undefined) {
+      if (!Number.isInteger(v_size)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_size = null;
+    }
+
+    let v_count = data["count"];
+
+    if (v_count !== null && v_count !== undefined) {
+      if (!Number.isInteger(v_count)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_count = null;
+    }
+
+    let v_pair = data["pair"];
+
+    if (v_pair !== null && v_pair !== undefined) {
+      v_pair = Pair.decode(v_pair);
+    } else {
+      v_pair = null;
+    }
+
+    return new Entry(v_id, v_offset, v_size, v_count, v_pair);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.id === null || this.id === undefined) {
+      throw new Error("id: is a required field");
+    }
+
+    data["id"] = this.id.toString();
+
+    if (this.offset !== null && this.offset !== undefined) {
+      data["offset"] = this.offset.toString();
+    }
+
+    if (this.size !== null && this.size !== undefined) {
+      data["size"] = this.size;
+    }
+
+    if (this.count !== null && this.count !== undefined) {
+      data["count"] = this.count;
+    }
+
+    if (this.pair !== null && this.pair !== undefined) {
+      data["pair"] = this.pair.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Pair {
+  constructor(left, right) {
+    this.left = left;
+    this.right = right;
+  }
+
+  static decode(data) {
+    let v_left = data[0];
+
+    if (v_left === null || v_left === undefined) {
+      throw new Error(0 + ": required field");
+    }
+
+    if (typeof v_left !== "string") {
+      throw Error("expected string");
+    }
+
+    v_left = BigInt(v_left);
+
+    let v_right = data[1];
+
+    if (v_right === null || v_right === undefined) {
+      throw new Error(1 + ": required field");
+    }
+
+    if (!Number.isInteger(v_right)) {
+      throw Error("expected integer");
+    }
+
+    return new Pair(v_left, v_right);
+  }
+
+  encode() {
+    if (this.left === null || this.left === undefined) {
+      throw new Error("left: is a required field");
+    }
+
+    if (this.right === null || this.right === undefined) {
+      throw new Error("right: is a required field");
+    }
+
+    return [this.left.toString(), this.right];
+  }
+}
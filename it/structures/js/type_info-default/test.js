@@ -0,0 +1,298 @@
+export class Entry {
+  constructor(adjacent, adjacent_default, external) {
+    this.adjacent = adjacent;
+    this.adjacent_default = adjacent_default;
+    this.external = external;
+  }
+
+  static decode(data) {
+    let v_adjacent = data["adjacent"];
+
+    if (v_adjacent !== null && v_adjacent !== undefined) {
+      v_adjacent = Adjacent.decode(v_adjacent);
+    } else {
+      v_adjacent = null;
+    }
+
+    let v_adjacent_default = data["adjacent_default"];
+
+    if (v_adjacent_default !== null && v_adjacent_default !== undefined) {
+      v_adjacent_default = AdjacentDefault.decode(v_adjacent_default);
+    } else {
+      v_adjacent_default = null;
+    }
+
+    let v_external = data["external"];
+
+    if (v_external !== null && v_external !== undefined) {
+      v_external = External.decode(v_external);
+    } else {
+      v_external = null;
+    }
+
+    return new Entry(v_adjacent, v_adjacent_default, v_external);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.adjacent !== null && this.adjacent !== undefined) {
+      data["adjacent"] = this.adjacent.encode();
+    }
+
+    if (this.adjacent_default !== null && this.adjacent_default !== undefined) {
+      data["adjacent_default"] = this.adjacent_default.encode();
+    }
+
+    if (this.external !== null && this.external !== undefined) {
+      data["external"] = this.external.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Adjacent {
+  static decode(data) {
+    const f_tag = data["kind"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: kind");
+    }
+
+    const f_content = data["value"];
+
+    if (f_content === null || f_content === undefined) {
+      throw new Error("missing content field: value");
+    }
+
+    if (f_tag === "foo") {
+      return Adjacent_A.decode(f_content);
+    }
+
+    if (f_tag === "B") {
+      return Adjacent_B.decode(f_content);
+    }
+
+    throw new Error("bad sub-type: " + f_tag);
+  }
+}
+
+export class Adjacent_A {
+  constructor(shared) {
+    this.shared = shared;
+  }
+
+  static decode(data) {
+    let v_shared = data["shared"];
+
+    if (v_shared === null || v_shared === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    if (typeof v_shared !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Adjacent_A(v_shared);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.shared === null || this.shared === undefined) {
+      throw new Error("shared: is a required field");
+    }
+
+    data["shared"] = this.shared;
+
+    return {"kind": "foo", "value": data};
+  }
+}
+export class Adjacent_B {
+  constructor(shared, b) {
+    this.shared = shared;
+    this.b = b;
+  }
+
+  static decode(data) {
+    let v_shared = data["shared"];
+
+    if (v_shared === null || v_shared === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    if (typeof v_shared !== "string") {
+      throw Error("expected string");
+    }
+
+    let v_b = data["b"];
+
+    if (v_b === null || v_b === undefined) {
+      throw new Error("b" + ": required field");
+    }
+
+    if (typeof v_b !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Adjacent_B(v_shared, v_b);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.shared === null || this.shared === undefined) {
+      throw new Error("shared: is a required field");
+    }
+
+    data["shared"] = this.shared;
+
+    if (this.b === null || this.b === undefined) {
+      throw new Error("b: is a required field");
+    }
+
+    data["b"] = this.b;
+
+    return {"kind": "B", "value": data};
+  }
+}
+
+export class AdjacentDefault {
+  static decode(data) {
+    const f_tag = data["type"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: type");
+    }
+
+    const f_content = data["data"];
+
+    if (f_content === null || f_content === undefined) {
+      throw new Error("missing content field: data");
+    }
+
+    if (f_tag === "A") {
+      return AdjacentDefault_A.decode(f_content);
+    }
+
+    throw new Error("bad sub-type: " + f_tag);
+  }
+}
+
+export class AdjacentDefault_A {
+  constructor() {}
+
+  static decode(data) {
+
+    return new AdjacentDefault_A();
+  }
+
+  encode() {
+    const data = {};
+
+    return {"type": "A", "data": data};
+  }
+}
+
+export class External {
+  static decode(data) {
+    const keys = Object.keys(data);
+
+    if (keys.length !== 1) {
+      throw new Error("expected exactly one key");
+    }
+
+    const f_tag = keys[0];
+
+    if (f_tag === "foo") {
+      return External_A.decode(data[f_tag]);
+    }
+
+    if (f_tag === "B") {
+      return External_B.decode(data[f_tag]);
+    }
+
+    throw new Error("bad sub-type: " + f_tag);
+  }
+}
+
+export class External_A {
+  constructor(shared) {
+    this.shared = shared;
+  }
+
+  static decode(data) {
+    let v_shared = data["shared"];
+
+    if (v_shared === null || v_shared === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    if (typeof v_shared !== "string") {
+      throw Error("expected string");
+    }
+
+    return new External_A(v_shared);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.shared === null || this.shared === undefined) {
+      throw new Error("shared: is a required field");
+    }
+
+    data["shared"] = this.shared;
+
+    return {"foo": data};
+  }
+}
+export class External_B {
+  constructor(shared, b) {
+    this.shared = shared;
+    this.b = b;
+  }
+
+  static decode(data) {
+    let v_shared = data["shared"];
+
+    if (v_shared === null || v_shared === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    if (typeof v_shared !== "string") {
+      throw Error("expected string");
+    }
+
+    let v_b = data["b"];
+
+    if (v_b === null || v_b === undefined) {
+      throw new Error("b" + ": required field");
+    }
+
+    if (typeof v_b !== "string") {
+      throw Error("expected string");
+    }
+
+    return new External_B(v_shared, v_b);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.shared === null || this.shared === undefined) {
+      throw new Error("shared: is a required field");
+    }
+
+    data["shared"] = this.shared;
+
+    if (this.b === null || this.b === undefined) {
+      throw new Error("b: is a required field");
+    }
+
+    data["b"] = this.b;
+
+    return {"B": data};
+  }
+}
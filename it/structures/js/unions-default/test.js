@@ -0,0 +1,243 @@
+export class Point {
+  constructor(x, y) {
+    this.x = x;
+    this.y = y;
+  }
+
+  static decode(data) {
+    let v_x = data["x"];
+
+    if (v_x === null || v_x === undefined) {
+      throw new Error("x" + ": required field");
+    }
+
+    if (!Number.isFinite(v_x)) {
+      throw Error("expected float");
+    }
+
+    let v_y = data["y"];
+
+    if (v_y === null || v_y === undefined) {
+      throw new Error("y" + ": required field");
+    }
+
+    if (!Number.isFinite(v_y)) {
+      throw Error("expected float");
+    }
+
+    return new Point(v_x, v_y);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.x === null || this.x === undefined) {
+      throw new Error("x: is a required field");
+    }
+
+    data["x"] = this.x;
+
+    if (this.y === null || this.y === undefined) {
+      throw new Error("y: is a required field");
+    }
+
+    data["y"] = this.y;
+
+    return data;
+  }
+}
+
+export class Entry {
+  constructor(value, shape, flag) {
+    this.value = value;
+    this.shape = shape;
+    this.flag = flag;
+  }
+
+  static decode(data) {
+    let v_value = data["value"];
+
+    if (v_value !== null && v_value !== undefined) {
+      v_value = Entry_Value.decode(v_value);
+    } else {
+      v_value = null;
+    }
+
+    let v_shape = data["shape"];
+
+    if (v_shape !== null && v_shape !== undefined) {
+      v_shape = Entry_Shape.decode(v_shape);
+    } else {
+      v_shape = null;
+    }
+
+    let v_flag = data["flag"];
+
+    if (v_flag !== null && v_flag !== undefined) {
+      v_flag = Entry_Flag.decode(v_flag);
+    } else {
+      v_flag = null;
+    }
+
+    return new Entry(v_value, v_shape, v_flag);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.value !== null && this.value !== undefined) {
+      data["value"] = this.value.encode();
+    }
+
+    if (this.shape !== null && this.shape !== undefined) {
+      data["shape"] = this.shape.encode();
+    }
+
+    if (this.flag !== null && this.flag !== undefined) {
+      data["flag"] = this.flag.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Entry_Value {
+  constructor(values) {
+    this.string = values.string;
+    this.u64 = values.u64;
+  }
+
+  static decode(data) {
+    if (typeof data === "string") {
+      let v = data;
+
+      if (typeof v !== "string") {
+        throw Error("expected string");
+      }
+
+      return new Entry_Value({string: v});
+    }
+
+    if (Number.isInteger(data)) {
+      let v = data;
+
+      if (!Number.isInteger(v)) {
+        throw Error("expected integer");
+      }
+
+      return new Entry_Value({u64: v});
+    }
+
+    throw new Error("no alternative of the union matches the value");
+  }
+
+  encode() {
+    if (this.string !== null && this.string !== undefined) {
+      return this.string;
+    }
+
+    if (this.u64 !== null && this.u64 !== undefined) {
+      return this.u64;
+    }
+
+    throw new Error("no alternative of the union is set");
+  }
+}
+
+export class Entry_Shape {
+  constructor(values) {
+    this.point = values.point;
+    this.double_array = values.double_array;
+  }
+
+  static decode(data) {
+    if (data !== null && typeof data === "object" && !Array.isArray(data)) {
+      let v = data;
+
+      v = Point.decode(v);
+
+      return new Entry_Shape({point: v});
+    }
+
+    if (Array.isArray(data)) {
+      let v = data;
+
+      if (!Array.isArray(v)) {
+        throw Error("expected array");
+      }
+
+      let o0 = [];
+
+      for (let i0 = 0, l0 = v.length; i0 < l0; i0++) {
+        let v0 = v[i0];
+
+        if (!Number.isFinite(v0)) {
+          throw Error("expected float");
+        }
+
+        o0.push(v0);
+      }
+
+      v = o0;
+
+      return new Entry_Shape({double_array: v});
+    }
+
+    throw new Error("no alternative of the union matches the value");
+  }
+
+  encode() {
+    if (this.point !== null && this.point !== undefined) {
+      return this.point.encode();
+    }
+
+    if (this.double_array !== null && this.double_array !== undefined) {
+      return this.double_array.map(function(v) { return v; });
+    }
+
+    throw new Error("no alternative of the union is set");
+  }
+}
+
+export class Entry_Flag {
+  constructor(values) {
+    this.boolean = values.boolean;
+    this.string = values.string;
+  }
+
+  static decode(data) {
+    if (typeof data === "boolean") {
+      let v = data;
+
+      if (typeof v !== "boolean") {
+        throw Error("expected boolean");
+      }
+
+      return new Entry_Flag({boolean: v});
+    }
+
+    if (typeof data === "string") {
+      let v = data;
+
+      if (typeof v !== "string") {
+        throw Error("expected string");
+      }
+
+      return new Entry_Flag({string: v});
+    }
+
+    throw new Error("no alternative of the union matches the value");
+  }
+
+  encode() {
+    if (this.boolean !== null && this.boolean !== undefined) {
+      return this.boolean;
+    }
+
+    if (this.string !== null && this.string !== undefined) {
+      return this.string;
+    }
+
+    throw new Error("no alternative of the union is set");
+  }
+}
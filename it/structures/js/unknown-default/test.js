@@ -0,0 +1,337 @@
+export class Entry {
+  constructor(color, priority, tagged, adjacent, external) {
+    this.color = color;
+    this.priority = priority;
+    this.tagged = tagged;
+    this.adjacent = adjacent;
+    this.external = external;
+  }
+
+  static decode(data) {
+    let v_color = data["color"];
+
+    if (v_color !== null && v_color !== undefined) {
+      v_color = Color.decode(v_color);
+    } else {
+      v_color = null;
+    }
+
+    let v_priority = data["priority"];
+
+    if (v_priority !== null && v_priority !== undefined) {
+      v_priority = Priority.decode(v_priority);
+    } else {
+      v_priority = null;
+    }
+
+    let v_tagged = data["tagged"];
+
+    if (v_tagged !== null && v_tagged !== undefined) {
+      v_tagged = Tagged.decode(v_tagged);
+    } else {
+      v_tagged = null;
+    }
+
+    let v_adjacent = data["adjacent"];
+
+    if (v_adjacent !== null && v_adjacent !== undefined) {
+      v_adjacent = Adjacent.decode(v_adjacent);
+    } else {
+      v_adjacent = null;
+    }
+
+    let v_external = data["external"];
+
+    if (v_external !== null && v_external !== undefined) {
+      v_external = External.decode(v_external);
+    } else {
+      v_external = null;
+    }
+
+    return new Entry(v_color, v_priority, v_tagged, v_adjacent, v_external);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.color !== null && this.color !== undefined) {
+      data["color"] = this.color.encode();
+    }
+
+    if (this.priority !== null && this.priority !== undefined) {
+      data["priority"] = this.priority.encode();
+    }
+
+    if (this.tagged !== null && this.tagged !== undefined) {
+      data["tagged"] = this.tagged.encode();
+    }
+
+    if (this.adjacent !== null && this.adjacent !== undefined) {
+      data["adjacent"] = this.adjacent.encode();
+    }
+
+    if (this.external !== null && this.external !== undefined) {
+      data["external"] = this.external.encode();
+    }
+
+    return data;
+  }
+}
+
+export class Color {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+
+  static decode(data) {
+    for (let i = 0, l = Color.values.length; i < l; i++) {
+      const member = Color.values[i];
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    return new Color("Other", data);
+  }
+}
+
+Color.Red = new Color("Red", "red");
+Color.Green = new Color("Green", "green");
+
+Color.values = [Color.Red, Color.Green];
+
+export class Priority {
+  constructor(name, value) {
+    this.name = name;
+    this.value = value;
+  }
+
+  encode() {
+    return this.value;
+  }
+
+  static decode(data) {
+    for (let i = 0, l = Priority.values.length; i < l; i++) {
+      const member = Priority.values[i];
+
+      if (member.value === data) {
+        return member;
+      }
+    }
+
+    return new Priority("Other", data);
+  }
+}
+
+Priority.Low = new Priority("Low", 1);
+Priority.High = new Priority("High", 2);
+
+Priority.values = [Priority.Low, Priority.High];
+
+export class Tagged {
+  static decode(data) {
+    const f_tag = data["type"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: type");
+    }
+
+    if (f_tag === "foo") {
+      return Tagged_A.decode(data);
+    }if (f_tag === "B") {
+      return Tagged_B.decode(data);
+    }
+
+    return new Tagged_Other(data);
+  }
+}
+
+export class Tagged_A {
+  constructor(shared) {
+    this.shared = shared;
+  }
+
+  static decode(data) {
+    let v_shared = data["shared"];
+
+    if (v_shared === null || v_shared === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    if (typeof v_shared !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Tagged_A(v_shared);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "foo";
+
+    if (this.shared === null || this.shared === undefined) {
+      throw new Error("shared: is a required field");
+    }
+
+    data["shared"] = this.shared;
+
+    return data;
+  }
+}
+export class Tagged_B {
+  constructor(shared, b) {
+    this.shared = shared;
+    this.b = b;
+  }
+
+  static decode(data) {
+    let v_shared = data["shared"];
+
+    if (v_shared === null || v_shared === undefined) {
+      throw new Error("shared" + ": required field");
+    }
+
+    if (typeof v_shared !== "string") {
+      throw Error("expected string");
+    }
+
+    let v_b = data["b"];
+
+    if (v_b === null || v_b === undefined) {
+      throw new Error("b" + ": required field");
+    }
+
+    if (typeof v_b !== "string") {
+      throw Error("expected string");
+    }
+
+    return new Tagged_B(v_shared, v_b);
+  }
+
+  encode() {
+    const data = {};
+
+    data["type"] = "B";
+
+    if (this.shared === null || this.shared === undefined) {
+      throw new Error("shared: is a required field");
+    }
+
+    data["shared"] = this.shared;
+
+    if (this.b === null || this.b === undefined) {
+      throw new Error("b: is a required field");
+    }
+
+    data["b"] = this.b;
+
+    return data;
+  }
+}
+
+export class Tagged_Other {
+  constructor(data) {
+    this.data = data;
+  }
+
+  encode() {
+    return this.data;
+  }
+}
+
+export class Adjacent {
+  static decode(data) {
+    const f_tag = data["type"];
+
+    if (f_tag === null || f_tag === undefined) {
+      throw new Error("missing tag field: type");
+    }
+
+    const f_content = data["data"];
+
+    if (f_content === null || f_content === undefined) {
+      throw new Error("missing content field: data");
+    }
+
+    if (f_tag === "A") {
+      return Adjacent_A.decode(f_content);
+    }
+
+    return new Adjacent_Other(data);
+  }
+}
+
+export class Adjacent_A {
+  constructor() {}
+
+  static decode(data) {
+
+    return new Adjacent_A();
+  }
+
+  encode() {
+    const data = {};
+
+    return {"type": "A", "data": data};
+  }
+}
+
+export class Adjacent_Other {
+  constructor(data) {
+    this.data = data;
+  }
+
+  encode() {
+    return this.data;
+  }
+}
+
+export class External {
+  static decode(data) {
+    const keys = Object.keys(data);
+
+    if (keys.length !== 1) {
+      throw new Error("expected exactly one key");
+    }
+
+    const f_tag = keys[0];
+
+    if (f_tag === "A") {
+      return External_A.decode(data[f_tag]);
+    }
+
+    return new External_Other(data);
+  }
+}
+
+export class External_A {
+  constructor() {}
+
+  static decode(data) {
+
+    return new External_A();
+  }
+
+  encode() {
+    const data = {};
+
+    return {"A": data};
+  }
+}
+
+export class External_Other {
+  constructor(data) {
+    this.data = data;
+  }
+
+  encode() {
+    return this.data;
+  }
+}
@@ -0,0 +1,91 @@
+export class Entry {
+  constructor(name, code, label, count, offset) {
+    this.name = name;
+    this.code = code;
+    this.label = label;
+    this.count = count;
+    this.offset = offset;
+  }
+
+  static decode(data) {
+    let v_name = data["name"];
+
+    if (v_name !== null && v_name !== undefined) {
+      if (typeof v_name !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_name = null;
+    }
+
+    let v_code = data["code"];
+
+    if (v_code !== null && v_code !== undefined) {
+      if (typeof v_code !== "string") {
+        throw Error("expected string");
+      }
+    } else {
+      v_code = null;
+    }
+
+    let v_label = data["label"];
+
+    if (v_label === null || v_label === undefined) {
+      throw new Error("label" + ": required field");
+    }
+
+    if (typeof v_label !== "string") {
+      throw Error("expected string");
+    }
+
+    let v_count = data["count"];
+
+    if (v_count !== null && v_count !== undefined) {
+      if (!Number.isInteger(v_count)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_count = null;
+    }
+
+    let v_offset = data["offset"];
+
+    if (v_offset !== null && v_offset !== undefined) {
+      if (!Number.isInteger(v_offset)) {
+        throw Error("expected integer");
+      }
+    } else {
+      v_offset = null;
+    }
+
+    return new Entry(v_name, v_code, v_label, v_count, v_offset);
+  }
+
+  encode() {
+    const data = {};
+
+    if (this.name !== null && this.name !== undefined) {
+      data["name"] = this.name;
+    }
+
+    if (this.code !== null && this.code !== undefined) {
+      data["code"] = this.code;
+    }
+
+    if (this.label === null || this.label === undefined) {
+      throw new Error("label: is a required field");
+    }
+
+    data["label"] = this.label;
+
+    if (this.count !== null && this.count !== undefined) {
+      data["count"] = this.count;
+    }
+
+    if (this.offset !== null && this.offset !== undefined) {
+      data["offset"] = this.offset;
+    }
+
+    return data;
+  }
+}
@@ -0,0 +1,125 @@
+import re
+
+class Entry:
+  def __init__(self, id, user, tags, owners):
+    self.__id = id
+    self.__user = user
+    self.__tags = tags
+    self.__owners = owners
+
+  @property
+  def id(self):
+    return self.__id
+
+  @id.setter
+  def id(self, id):
+    self.__id = id
+
+  @property
+  def user(self):
+    return self.__user
+
+  @user.setter
+  def user(self, user):
+    self.__user = user
+
+  @property
+  def tags(self):
+    return self.__tags
+
+  @tags.setter
+  def tags(self, tags):
+    self.__tags = tags
+
+  @property
+  def owners(self):
+    return self.__owners
+
+  @owners.setter
+  def owners(self, owners):
+    self.__owners = owners
+
+  @staticmethod
+  def decode(data):
+    f_id = None
+
+    if "id" in data:
+      f_id = data["id"]
+
+      if f_id is not None:
+        if not isinstance(f_id, int):
+          raise Exception("not an integer")
+
+    f_user = None
+
+    if "user" in data:
+      f_user = data["user"]
+
+      if f_user is not None:
+        if not isinstance(f_user, unicode):
+          raise Exception("not a string")
+
+    if f_user is not None:
+      if re.match("(?:[a-z]+)\\Z", f_user) is None:
+        raise ValueError("Entry.user: must match pattern `[a-z]+`")
+
+    f_tags = None
+
+    if "tags" in data:
+      f_tags = data["tags"]
+
+      if f_tags is not None:
+        if not isinstance(f_tags, list):
+          raise Exception("not an array")
+
+        _a0 = []
+
+        for _v0 in f_tags:
+          if not isinstance(_v0, unicode):
+            raise Exception("not a string")
+
+          _a0.append(_v0)
+
+        f_tags = _a0
+
+    f_owners = None
+
+    if "owners" in data:
+      f_owners = data["owners"]
+
+      if f_owners is not None:
+        if not isinstance(f_owners, dict):
+          raise Exception("not an object")
+
+        _o0 = {}
+
+        for _k0, _v0 in f_owners.items():
+          if not isinstance(_k0, unicode):
+            raise Exception("not a string")
+          if not isinstance(_v0, int):
+            raise Exception("not an integer")
+          _o0[_k0] = _v0
+
+        f_owners = _o0
+
+    return Entry(f_id, f_user, f_tags, f_owners)
+
+  def encode(self):
+    data = dict()
+
+    if self.id is not None:
+      data["id"] = self.id
+
+    if self.user is not None:
+      data["user"] = self.user
+
+    if self.tags is not None:
+      data["tags"] = self.tags
+
+    if self.owners is not None:
+      data["owners"] = self.owners
+
+    return data
+
+  def __repr__(self):
+    return "<Entry id:{!r}, user:{!r}, tags:{!r}, owners:{!r}>".format(self.id, self.user, self.tags, self.owners)
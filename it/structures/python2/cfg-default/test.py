@@ -0,0 +1,85 @@
+import enum
+
+class Entry:
+  def __init__(self, id, color):
+    self.__id = id
+    self.__color = color
+
+  @property
+  def id(self):
+    return self.__id
+
+  @id.setter
+  def id(self, id):
+    self.__id = id
+
+  @property
+  def color(self):
+    return self.__color
+
+  @color.setter
+  def color(self, color):
+    self.__color = color
+
+  @staticmethod
+  def decode(data):
+    f_id = None
+
+    if "id" in data:
+      f_id = data["id"]
+
+      if f_id is not None:
+        if not isinstance(f_id, unicode):
+          raise Exception("not a string")
+
+    f_color = None
+
+    if "color" in data:
+      f_color = data["color"]
+
+      if f_color is not None:
+        f_color = Color.decode(f_color)
+
+    return Entry(f_id, f_color)
+
+  def encode(self):
+    data = dict()
+
+    if self.id is not None:
+      data["id"] = self.id
+
+    if self.color is not None:
+      data["color"] = self.color.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry id:{!r}, color:{!r}>".format(self.id, self.color)
+
+class Color:
+  def __init__(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  @property
+  def _ordinal(self):
+    return self.__ordinal
+
+  @_ordinal.setter
+  def _ordinal(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  def encode(self):
+    return self._ordinal
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._ordinal == data:
+        return value
+
+    raise Exception("data does not match enum")
+
+  def __repr__(self):
+    return "<Color ordinal:{!r}>".format(self._ordinal)
+
+Color = enum.Enum("Color", [("Red", "red")], type=Color)
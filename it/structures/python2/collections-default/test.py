@@ -0,0 +1,195 @@
+import enum
+
+class Entry:
+  def __init__(self, by_color, by_id, tags, levels):
+    self.__by_color = by_color
+    self.__by_id = by_id
+    self.__tags = tags
+    self.__levels = levels
+
+  @property
+  def by_color(self):
+    return self.__by_color
+
+  @by_color.setter
+  def by_color(self, by_color):
+    self.__by_color = by_color
+
+  @property
+  def by_id(self):
+    return self.__by_id
+
+  @by_id.setter
+  def by_id(self, by_id):
+    self.__by_id = by_id
+
+  @property
+  def tags(self):
+    return self.__tags
+
+  @tags.setter
+  def tags(self, tags):
+    self.__tags = tags
+
+  @property
+  def levels(self):
+    return self.__levels
+
+  @levels.setter
+  def levels(self, levels):
+    self.__levels = levels
+
+  @staticmethod
+  def decode(data):
+    f_by_color = None
+
+    if "by_color" in data:
+      f_by_color = data["by_color"]
+
+      if f_by_color is not None:
+        if not isinstance(f_by_color, dict):
+          raise Exception("not an object")
+
+        _o0 = {}
+
+        for _k0, _v0 in f_by_color.items():
+          _k0 = Color.decode(_k0)
+          if not isinstance(_v0, int):
+            raise Exception("not an integer")
+          _o0[_k0] = _v0
+
+        f_by_color = _o0
+
+    f_by_id = None
+
+    if "by_id" in data:
+      f_by_id = data["by_id"]
+
+      if f_by_id is not None:
+        if not isinstance(f_by_id, dict):
+          raise Exception("not an object")
+
+        _o0 = {}
+
+        for _k0, _v0 in f_by_id.items():
+          _k0 = int(_k0)
+          if not isinstance(_v0, unicode):
+            raise Exception("not a string")
+          _o0[_k0] = _v0
+
+        f_by_id = _o0
+
+    f_tags = None
+
+    if "tags" in data:
+      f_tags = data["tags"]
+
+      if f_tags is not None:
+        if not isinstance(f_tags, list):
+          raise Exception("not an array")
+
+        _a0 = set()
+
+        for _v0 in f_tags:
+          if not isinstance(_v0, unicode):
+            raise Exception("not a string")
+
+          _a0.add(_v0)
+
+        f_tags = _a0
+
+    f_levels = None
+
+    if "levels" in data:
+      f_levels = data["levels"]
+
+      if f_levels is not None:
+        if not isinstance(f_levels, list):
+          raise Exception("not an array")
+
+        _a0 = set()
+
+        for _v0 in f_levels:
+          _v0 = Level.decode(_v0)
+
+          _a0.add(_v0)
+
+        f_levels = _a0
+
+    return Entry(f_by_color, f_by_id, f_tags, f_levels)
+
+  def encode(self):
+    data = dict()
+
+    if self.by_color is not None:
+      data["by_color"] = dict((k.encode(), v) for (k, v) in self.by_color.items())
+
+    if self.by_id is not None:
+      data["by_id"] = dict((str(k), v) for (k, v) in self.by_id.items())
+
+    if self.tags is not None:
+      data["tags"] = [v for v in self.tags]
+
+    if self.levels is not None:
+      data["levels"] = [v.encode() for v in self.levels]
+
+    return data
+
+  def __repr__(self):
+    return "<Entry by_color:{!r}, by_id:{!r}, tags:{!r}, levels:{!r}>".format(self.by_color, self.by_id, self.tags, self.levels)
+
+class Color:
+  def __init__(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  @property
+  def _ordinal(self):
+    return self.__ordinal
+
+  @_ordinal.setter
+  def _ordinal(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  def encode(self):
+    return self._ordinal
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._ordinal == data:
+        return value
+
+    raise Exception("data does not match enum")
+
+  def __repr__(self):
+    return "<Color ordinal:{!r}>".format(self._ordinal)
+
+class Level:
+  def __init__(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  @property
+  def _ordinal(self):
+    return self.__ordinal
+
+  @_ordinal.setter
+  def _ordinal(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  def encode(self):
+    return self._ordinal
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._ordinal == data:
+        return value
+
+    raise Exception("data does not match enum")
+
+  def __repr__(self):
+    return "<Level ordinal:{!r}>".format(self._ordinal)
+
+Color = enum.Enum("Color", [("Red", "red"), ("Green", "green")], type=Color)
+
+Level = enum.Enum("Level", [("Low", 1), ("High", 2)], type=Level)
@@ -0,0 +1,255 @@
+class Entry:
+  def __init__(self, name, count, big, ratio, scale, enabled, label, shape):
+    self.__name = name
+    self.__count = count
+    self.__big = big
+    self.__ratio = ratio
+    self.__scale = scale
+    self.__enabled = enabled
+    self.__label = label
+    self.__shape = shape
+
+  @property
+  def name(self):
+    return self.__name
+
+  @name.setter
+  def name(self, name):
+    self.__name = name
+
+  @property
+  def count(self):
+    return self.__count
+
+  @count.setter
+  def count(self, count):
+    self.__count = count
+
+  @property
+  def big(self):
+    return self.__big
+
+  @big.setter
+  def big(self, big):
+    self.__big = big
+
+  @property
+  def ratio(self):
+    return self.__ratio
+
+  @ratio.setter
+  def ratio(self, ratio):
+    self.__ratio = ratio
+
+  @property
+  def scale(self):
+    return self.__scale
+
+  @scale.setter
+  def scale(self, scale):
+    self.__scale = scale
+
+  @property
+  def enabled(self):
+    return self.__enabled
+
+  @enabled.setter
+  def enabled(self, enabled):
+    self.__enabled = enabled
+
+  @property
+  def label(self):
+    return self.__label
+
+  @label.setter
+  def label(self, label):
+    self.__label = label
+
+  @property
+  def shape(self):
+    return self.__shape
+
+  @shape.setter
+  def shape(self, shape):
+    self.__shape = shape
+
+  @staticmethod
+  def decode(data):
+    f_name = None
+
+    if "name" in data:
+      f_name = data["name"]
+
+      if f_name is not None:
+        if not isinstance(f_name, unicode):
+          raise Exception("not a string")
+
+    if f_name is None:
+      f_name = "unknown"
+
+    f_count = None
+
+    if "count" in data:
+      f_count = data["count"]
+
+      if f_count is not None:
+        if not isinstance(f_count, int):
+          raise Exception("not an integer")
+
+    if f_count is None:
+      f_count = 42
+
+    f_big = None
+
+    if "big" in data:
+      f_big = data["big"]
+
+      if f_big is not None:
+        if not isinstance(f_big, int):
+          raise Exception("not an integer")
+
+    if f_big is None:
+      f_big = -1
+
+    f_ratio = None
+
+    if "ratio" in data:
+      f_ratio = data["ratio"]
+
+      if f_ratio is not None:
+        if not isinstance(f_ratio, float):
+          raise Exception("not a float")
+
+    if f_ratio is None:
+      f_ratio = 0.5
+
+    f_scale = None
+
+    if "scale" in data:
+      f_scale = data["scale"]
+
+      if f_scale is not None:
+        if not isinstance(f_scale, float):
+          raise Exception("not a float")
+
+    if f_scale is None:
+      f_scale = 2
+
+    f_enabled = None
+
+    if "enabled" in data:
+      f_enabled = data["enabled"]
+
+      if f_enabled is not None:
+        if not isinstance(f_enabled, bool):
+          raise Exception("not a boolean")
+
+    if f_enabled is None:
+      f_enabled = True
+
+    f_label = None
+
+    if "label" in data:
+      f_label = data["label"]
+
+      if f_label is not None:
+        if not isinstance(f_label, unicode):
+          raise Exception("not a string")
+
+    f_shape = None
+
+    if "shape" in data:
+      f_shape = data["shape"]
+
+      if f_shape is not None:
+        f_shape = Shape.decode(f_shape)
+
+    return Entry(f_name, f_count, f_big, f_ratio, f_scale, f_enabled, f_label, f_shape)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is not None:
+      data["name"] = self.name
+
+    if self.count is not None:
+      data["count"] = self.count
+
+    if self.big is not None:
+      data["big"] = self.big
+
+    if self.ratio is not None:
+      data["ratio"] = self.ratio
+
+    if self.scale is not None:
+      data["scale"] = self.scale
+
+    if self.enabled is not None:
+      data["enabled"] = self.enabled
+
+    if self.label is not None:
+      data["label"] = self.label
+
+    if self.shape is not None:
+      data["shape"] = self.shape.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry name:{!r}, count:{!r}, big:{!r}, ratio:{!r}, scale:{!r}, enabled:{!r}, label:{!r}, shape:{!r}>".format(self.name, self.count, self.big, self.ratio, self.scale, self.enabled, self.label, self.shape)
+
+class Shape:
+  @staticmethod
+  def decode(data):
+    if "type" not in data:
+      raise Exception("missing tag field type")
+
+    f_tag = data["type"]
+
+    if f_tag == "Circle":
+      return Shape_Circle.decode(data)
+
+    raise Exception("no sub type matching tag: " + f_tag)
+
+class Shape_Circle(Shape):
+  TYPE = "Circle"
+
+  def __init__(self, radius):
+    self.__radius = radius
+
+  @property
+  def radius(self):
+    return self.__radius
+
+  @radius.setter
+  def radius(self, radius):
+    self.__radius = radius
+
+  @staticmethod
+  def decode(data):
+    f_radius = None
+
+    if "radius" in data:
+      f_radius = data["radius"]
+
+      if f_radius is not None:
+        if not isinstance(f_radius, float):
+          raise Exception("not a float")
+
+    if f_radius is None:
+      f_radius = 1
+
+    return Shape_Circle(f_radius)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "Circle"
+
+    if self.radius is not None:
+      data["radius"] = self.radius
+
+    return data
+
+  def __repr__(self):
+    return "<Shape_Circle radius:{!r}>".format(self.radius)
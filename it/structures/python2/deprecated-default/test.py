@@ -0,0 +1,363 @@
+import enum
+import warnings
+
+class Entry:
+  def __init__(self, legacy, current, status, point, shape):
+    self.__legacy = legacy
+    self.__current = current
+    self.__status = status
+    self.__point = point
+    self.__shape = shape
+
+  @property
+  def legacy(self):
+    """
+    Kept for compatibility.
+    """
+    warnings.warn("legacy is deprecated since 1.1.0: use `current` instead", DeprecationWarning, stacklevel=2)
+    return self.__legacy
+
+  @legacy.setter
+  def legacy(self, legacy):
+    self.__legacy = legacy
+
+  @property
+  def current(self):
+    return self.__current
+
+  @current.setter
+  def current(self, current):
+    self.__current = current
+
+  @property
+  def status(self):
+    return self.__status
+
+  @status.setter
+  def status(self, status):
+    self.__status = status
+
+  @property
+  def point(self):
+    return self.__point
+
+  @point.setter
+  def point(self, point):
+    self.__point = point
+
+  @property
+  def shape(self):
+    return self.__shape
+
+  @shape.setter
+  def shape(self, shape):
+    self.__shape = shape
+
+  @staticmethod
+  def decode(data):
+    f_legacy = None
+
+    if "legacy" in data:
+      f_legacy = data["legacy"]
+
+      if f_legacy is not None:
+        f_legacy = Legacy.decode(f_legacy)
+
+    f_current = None
+
+    if "current" in data:
+      f_current = data["current"]
+
+      if f_current is not None:
+        f_current = Current.decode(f_current)
+
+    f_status = None
+
+    if "status" in data:
+      f_status = data["status"]
+
+      if f_status is not None:
+        f_status = Status.decode(f_status)
+
+    f_point = None
+
+    if "point" in data:
+      f_point = data["point"]
+
+      if f_point is not None:
+        f_point = Point.decode(f_point)
+
+    f_shape = None
+
+    if "shape" in data:
+      f_shape = data["shape"]
+
+      if f_shape is not None:
+        f_shape = Shape.decode(f_shape)
+
+    return Entry(f_legacy, f_current, f_status, f_point, f_shape)
+
+  def encode(self):
+    data = dict()
+
+    if self.legacy is not None:
+      data["legacy"] = self.legacy.encode()
+
+    if self.current is not None:
+      data["current"] = self.current.encode()
+
+    if self.status is not None:
+      data["status"] = self.status.encode()
+
+    if self.point is not None:
+      data["point"] = self.point.encode()
+
+    if self.shape is not None:
+      data["shape"] = self.shape.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry legacy:{!r}, current:{!r}, status:{!r}, point:{!r}, shape:{!r}>".format(self.legacy, self.current, self.status, self.point, self.shape)
+
+class Legacy:
+  def __init__(self, value):
+    warnings.warn("Legacy is deprecated: use `Current` instead", DeprecationWarning, stacklevel=2)
+    self.__value = value
+
+  @property
+  def value(self):
+    return self.__value
+
+  @value.setter
+  def value(self, value):
+    self.__value = value
+
+  @staticmethod
+  def decode(data):
+    f_value = data["value"]
+
+    if not isinstance(f_value, unicode):
+      raise Exception("not a string")
+
+    return Legacy(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is None:
+      raise Exception("missing required field: value")
+
+    data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Legacy value:{!r}>".format(self.value)
+
+class Current:
+  def __init__(self, value):
+    self.__value = value
+
+  @property
+  def value(self):
+    return self.__value
+
+  @value.setter
+  def value(self, value):
+    self.__value = value
+
+  @staticmethod
+  def decode(data):
+    f_value = data["value"]
+
+    if not isinstance(f_value, unicode):
+      raise Exception("not a string")
+
+    return Current(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is None:
+      raise Exception("missing required field: value")
+
+    data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Current value:{!r}>".format(self.value)
+
+class Status:
+  def __init__(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  @property
+  def _ordinal(self):
+    return self.__ordinal
+
+  @_ordinal.setter
+  def _ordinal(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  def encode(self):
+    return self._ordinal
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._ordinal == data:
+        return value
+
+    raise Exception("data does not match enum")
+
+  def __repr__(self):
+    return "<Status ordinal:{!r}>".format(self._ordinal)
+
+class Point:
+  def __init__(self, x, y):
+    warnings.warn("Point is deprecated since 1.0.0", DeprecationWarning, stacklevel=2)
+    self.__x = x
+    self.__y = y
+
+  @property
+  def x(self):
+    return self.__x
+
+  @x.setter
+  def x(self, x):
+    self.__x = x
+
+  @property
+  def y(self):
+    return self.__y
+
+  @y.setter
+  def y(self, y):
+    self.__y = y
+
+  @staticmethod
+  def decode(data):
+    f_x = data[0]
+
+    if not isinstance(f_x, int):
+      raise Exception("not an integer")
+
+    f_y = data[1]
+
+    if not isinstance(f_y, int):
+      raise Exception("not an integer")
+
+    return Point(f_x, f_y)
+
+  def encode(self):
+    if self.x is None:
+      raise Exception("missing required field: x")
+
+    x = self.x
+
+    if self.y is None:
+      raise Exception("missing required field: y")
+
+    y = self.y
+
+    return (x, y)
+
+  def __repr__(self):
+    return "<Point x:{!r}, y:{!r}>".format(self.x, self.y)
+
+class Shape:
+  @staticmethod
+  def decode(data):
+    if "type" not in data:
+      raise Exception("missing tag field type")
+
+    f_tag = data["type"]
+
+    if f_tag == "Circle":
+      return Shape_Circle.decode(data)
+
+    if f_tag == "Round":
+      return Shape_Round.decode(data)
+
+    raise Exception("no sub type matching tag: " + f_tag)
+
+class Shape_Circle(Shape):
+  TYPE = "Circle"
+
+  def __init__(self, radius):
+    self.__radius = radius
+
+  @property
+  def radius(self):
+    return self.__radius
+
+  @radius.setter
+  def radius(self, radius):
+    self.__radius = radius
+
+  @staticmethod
+  def decode(data):
+    f_radius = data["radius"]
+
+    if not isinstance(f_radius, float):
+      raise Exception("not a float")
+
+    return Shape_Circle(f_radius)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "Circle"
+
+    if self.radius is None:
+      raise Exception("missing required field: radius")
+
+    data["radius"] = self.radius
+
+    return data
+
+  def __repr__(self):
+    return "<Shape_Circle radius:{!r}>".format(self.radius)
+
+class Shape_Round(Shape):
+  TYPE = "Round"
+
+  def __init__(self, radius):
+    warnings.warn("Shape_Round is deprecated: use `Circle` instead", DeprecationWarning, stacklevel=2)
+    self.__radius = radius
+
+  @property
+  def radius(self):
+    return self.__radius
+
+  @radius.setter
+  def radius(self, radius):
+    self.__radius = radius
+
+  @staticmethod
+  def decode(data):
+    f_radius = data["radius"]
+
+    if not isinstance(f_radius, float):
+      raise Exception("not a float")
+
+    return Shape_Round(f_radius)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "Round"
+
+    if self.radius is None:
+      raise Exception("missing required field: radius")
+
+    data["radius"] = self.radius
+
+    return data
+
+  def __repr__(self):
+    return "<Shape_Round radius:{!r}>".format(self.radius)
+
+Status = enum.Enum("Status", [("Active", "active"), ("Retired", "retired")], type=Status)
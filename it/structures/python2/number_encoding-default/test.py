@@ -0,0 +1,173 @@
+class Entry:
+  def __init__(self, id, offset, size, count, pair):
+    self.__id = id
+    self.__offset = offset
+    self.__size = size
+    self.__count = count
+    self.__pair = pair
+
+  @property
+  def id(self):
+    return self.__id
+
+  @id.setter
+  def id(self, id):
+    self.__id = id
+
+  @property
+  def offset(self):
+    return self.__offset
+
+  @offset.setter
+  def offset(self, offset):
+    self.__offset = offset
+
+  @property
+  def size(self):
+    return self.__size
+
+  @size.setter
+  def size(self, size):
+    self.__size = size
+
+  @property
+  def count(self):
+    return self.__count
+
+  @count.setter
+  def count(self, count):
+    self.__count = count
+
+  @property
+  def pair(self):
+    return self.__pair
+
+  @pair.setter
+  def pair(self, pair):
+    self.__pair = pair
+
+  @staticmethod
+  def decode(data):
+    f_id = data["id"]
+
+    if not isinstance(f_id, unicode):
+      raise Exception("not a string")
+
+    f_id = int(f_id)
+
+    f_offset = None
+
+    if "offset" in data:
+      f_offset = data["offset"]
+
+      if f_offset is not None:
+        if not isinstance(f_offset, unicode):
+          raise Exception("not a string")
+
+        f_offset = int(f_offset)
+
+    f_size = None
+
+    if "size" in data:
+      f_size = data["size"]
+
+      if f_size is not None:
+        if not isinstance(f_size, int):
+          raise Exception("not an integer")
+
+    f_count = None
+
+    if "count" in data:
+      f_count = data["count"]
+
+      if f_count is not None:
+        if not isinstance(f_count, int):
+          raise Exception("not an integer")
+
+    f_pair = None
+
+    if "pair" in data:
+      f_pair = data["pair"]
+
+      if f_pair is not None:
+        f_pair = Pair.decode(f_pair)
+
+    return Entry(f_id, f_offset, f_size, f_count, f_pair)
+
+  def encode(self):
+    data = dict()
+
+    if self.id is None:
+      raise Exception("missing required field: id")
+
+    data["id"] = str(self.id)
+
+    if self.offset is not None:
+      data["offset"] = str(self.offset)
+
+    if self.size is not None:
+      data["size"] = self.size
+
+    if self.count is not None:
+      data["count"] = self.count
+
+    if self.pair is not None:
+      data["pair"] = self.pair.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry id:{!r}, offset:{!r}, size:{!r}, count:{!r}, pair:{!r}>".format(self.id, self.offset, self.size, self.count, self.pair)
+
+class Pair:
+  def __init__(self, left, right):
+    self.__left = left
+    self.__right = right
+
+  @property
+  def left(self):
+    return self.__left
+
+  @left.setter
+  def left(self, left):
+    self.__left = left
+
+  @property
+  def right(self):
+    return self.__right
+
+  @right.setter
+  def right(self, right):
+    self.__right = right
+
+  @staticmethod
+  def decode(data):
+    f_left = data[0]
+
+    if not isinstance(f_left, unicode):
+      raise Exception("not a string")
+
+    f_left = int(f_left)
+
+    f_right = data[1]
+
+    if not isinstance(f_right, int):
+      raise Exception("not an integer")
+
+    return Pair(f_left, f_right)
+
+  def encode(self):
+    if self.left is None:
+      raise Exception("missing required field: left")
+
+    left = str(self.left)
+
+    if self.right is None:
+      raise Exception("missing required field: right")
+
+    right = self.right
+
+    return (left, right)
+
+  def __repr__(self):
+    return "<Pair left:{!r}, right:{!r}>".format(self.left, self.right)
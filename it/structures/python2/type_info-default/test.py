@@ -0,0 +1,327 @@
+class Entry:
+  def __init__(self, adjacent, adjacent_default, external):
+    self.__adjacent = adjacent
+    self.__adjacent_default = adjacent_default
+    self.__external = external
+
+  @property
+  def adjacent(self):
+    return self.__adjacent
+
+  @adjacent.setter
+  def adjacent(self, adjacent):
+    self.__adjacent = adjacent
+
+  @property
+  def adjacent_default(self):
+    return self.__adjacent_default
+
+  @adjacent_default.setter
+  def adjacent_default(self, adjacent_default):
+    self.__adjacent_default = adjacent_default
+
+  @property
+  def external(self):
+    return self.__external
+
+  @external.setter
+  def external(self, external):
+    self.__external = external
+
+  @staticmethod
+  def decode(data):
+    f_adjacent = None
+
+    if "adjacent" in data:
+      f_adjacent = data["adjacent"]
+
+      if f_adjacent is not None:
+        f_adjacent = Adjacent.decode(f_adjacent)
+
+    f_adjacent_default = None
+
+    if "adjacent_default" in data:
+      f_adjacent_default = data["adjacent_default"]
+
+      if f_adjacent_default is not None:
+        f_adjacent_default = AdjacentDefault.decode(f_adjacent_default)
+
+    f_external = None
+
+    if "external" in data:
+      f_external = data["external"]
+
+      if f_external is not None:
+        f_external = External.decode(f_external)
+
+    return Entry(f_adjacent, f_adjacent_default, f_external)
+
+  def encode(self):
+    data = dict()
+
+    if self.adjacent is not None:
+      data["adjacent"] = self.adjacent.encode()
+
+    if self.adjacent_default is not None:
+      data["adjacent_default"] = self.adjacent_default.encode()
+
+    if self.external is not None:
+      data["external"] = self.external.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry adjacent:{!r}, adjacent_default:{!r}, external:{!r}>".format(self.adjacent, self.adjacent_default, self.external)
+
+class Adjacent:
+  @staticmethod
+  def decode(data):
+    if "kind" not in data:
+      raise Exception("missing tag field kind")
+
+    if "value" not in data:
+      raise Exception("missing content field value")
+
+    f_tag = data["kind"]
+    f_content = data["value"]
+
+    if f_tag == "foo":
+      return Adjacent_A.decode(f_content)
+
+    if f_tag == "B":
+      return Adjacent_B.decode(f_content)
+
+    raise Exception("no sub type matching tag: " + f_tag)
+
+class Adjacent_A(Adjacent):
+  TYPE = "foo"
+
+  def __init__(self, shared):
+    self.__shared = shared
+
+  @property
+  def shared(self):
+    return self.__shared
+
+  @shared.setter
+  def shared(self, shared):
+    self.__shared = shared
+
+  @staticmethod
+  def decode(data):
+    f_shared = data["shared"]
+
+    if not isinstance(f_shared, unicode):
+      raise Exception("not a string")
+
+    return Adjacent_A(f_shared)
+
+  def encode(self):
+    data = dict()
+
+    if self.shared is None:
+      raise Exception("missing required field: shared")
+
+    data["shared"] = self.shared
+
+    return {"kind": "foo", "value": data}
+
+  def __repr__(self):
+    return "<Adjacent_A shared:{!r}>".format(self.shared)
+
+class Adjacent_B(Adjacent):
+  TYPE = "B"
+
+  def __init__(self, shared, b):
+    self.__shared = shared
+    self.__b = b
+
+  @property
+  def shared(self):
+    return self.__shared
+
+  @shared.setter
+  def shared(self, shared):
+    self.__shared = shared
+
+  @property
+  def b(self):
+    return self.__b
+
+  @b.setter
+  def b(self, b):
+    self.__b = b
+
+  @staticmethod
+  def decode(data):
+    f_shared = data["shared"]
+
+    if not isinstance(f_shared, unicode):
+      raise Exception("not a string")
+
+    f_b = data["b"]
+
+    if not isinstance(f_b, unicode):
+      raise Exception("not a string")
+
+    return Adjacent_B(f_shared, f_b)
+
+  def encode(self):
+    data = dict()
+
+    if self.shared is None:
+      raise Exception("missing required field: shared")
+
+    data["shared"] = self.shared
+
+    if self.b is None:
+      raise Exception("missing required field: b")
+
+    data["b"] = self.b
+
+    return {"kind": "B", "value": data}
+
+  def __repr__(self):
+    return "<Adjacent_B shared:{!r}, b:{!r}>".format(self.shared, self.b)
+
+class AdjacentDefault:
+  @staticmethod
+  def decode(data):
+    if "type" not in data:
+      raise Exception("missing tag field type")
+
+    if "data" not in data:
+      raise Exception("missing content field data")
+
+    f_tag = data["type"]
+    f_content = data["data"]
+
+    if f_tag == "A":
+      return AdjacentDefault_A.decode(f_content)
+
+    raise Exception("no sub type matching tag: " + f_tag)
+
+class AdjacentDefault_A(AdjacentDefault):
+  TYPE = "A"
+
+  def __init__(self):
+    pass
+
+  @staticmethod
+  def decode(data):
+    return AdjacentDefault_A()
+
+  def encode(self):
+    data = dict()
+
+    return {"type": "A", "data": data}
+
+  def __repr__(self):
+    return "<AdjacentDefault_A>"
+
+class External:
+  @staticmethod
+  def decode(data):
+    if len(data) != 1:
+      raise Exception("expected exactly one key")
+
+    f_tag, f_content = list(data.items())[0]
+
+    if f_tag == "foo":
+      return External_A.decode(f_content)
+
+    if f_tag == "B":
+      return External_B.decode(f_content)
+
+    raise Exception("no sub type matching tag: " + f_tag)
+
+class External_A(External):
+  TYPE = "foo"
+
+  def __init__(self, shared):
+    self.__shared = shared
+
+  @property
+  def shared(self):
+    return self.__shared
+
+  @shared.setter
+  def shared(self, shared):
+    self.__shared = shared
+
+  @staticmethod
+  def decode(data):
+    f_shared = data["shared"]
+
+    if not isinstance(f_shared, unicode):
+      raise Exception("not a string")
+
+    return External_A(f_shared)
+
+  def encode(self):
+    data = dict()
+
+    if self.shared is None:
+      raise Exception("missing required field: shared")
+
+    data["shared"] = self.shared
+
+    return {"foo": data}
+
+  def __repr__(self):
+    return "<External_A shared:{!r}>".format(self.shared)
+
+class External_B(External):
+  TYPE = "B"
+
+  def __init__(self, shared, b):
+    self.__shared = shared
+    self.__b = b
+
+  @property
+  def shared(self):
+    return self.__shared
+
+  @shared.setter
+  def shared(self, shared):
+    self.__shared = shared
+
+  @property
+  def b(self):
+    return self.__b
+
+  @b.setter
+  def b(self, b):
+    self.__b = b
+
+  @staticmethod
+  def decode(data):
+    f_shared = data["shared"]
+
+    if not isinstance(f_shared, unicode):
+      raise Exception("not a string")
+
+    f_b = data["b"]
+
+    if not isinstance(f_b, unicode):
+      raise Exception("not a string")
+
+    return External_B(f_shared, f_b)
+
+  def encode(self):
+    data = dict()
+
+    if self.shared is None:
+      raise Exception("missing required field: shared")
+
+    data["shared"] = self.shared
+
+    if self.b is None:
+      raise Exception("missing required field: b")
+
+    data["b"] = self.b
+
+    return {"B": data}
+
+  def __repr__(self):
+    return "<External_B shared:{!r}, b:{!r}>".format(self.shared, self.b)
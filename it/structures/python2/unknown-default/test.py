@@ -0,0 +1,387 @@
+import enum
+
+class Entry:
+  def __init__(self, color, priority, tagged, adjacent, external):
+    self.__color = color
+    self.__priority = priority
+    self.__tagged = tagged
+    self.__adjacent = adjacent
+    self.__external = external
+
+  @property
+  def color(self):
+    return self.__color
+
+  @color.setter
+  def color(self, color):
+    self.__color = color
+
+  @property
+  def priority(self):
+    return self.__priority
+
+  @priority.setter
+  def priority(self, priority):
+    self.__priority = priority
+
+  @property
+  def tagged(self):
+    return self.__tagged
+
+  @tagged.setter
+  def tagged(self, tagged):
+    self.__tagged = tagged
+
+  @property
+  def adjacent(self):
+    return self.__adjacent
+
+  @adjacent.setter
+  def adjacent(self, adjacent):
+    self.__adjacent = adjacent
+
+  @property
+  def external(self):
+    return self.__external
+
+  @external.setter
+  def external(self, external):
+    self.__external = external
+
+  @staticmethod
+  def decode(data):
+    f_color = None
+
+    if "color" in data:
+      f_color = data["color"]
+
+      if f_color is not None:
+        f_color = Color.decode(f_color)
+
+    f_priority = None
+
+    if "priority" in data:
+      f_priority = data["priority"]
+
+      if f_priority is not None:
+        f_priority = Priority.decode(f_priority)
+
+    f_tagged = None
+
+    if "tagged" in data:
+      f_tagged = data["tagged"]
+
+      if f_tagged is not None:
+        f_tagged = Tagged.decode(f_tagged)
+
+    f_adjacent = None
+
+    if "adjacent" in data:
+      f_adjacent = data["adjacent"]
+
+      if f_adjacent is not None:
+        f_adjacent = Adjacent.decode(f_adjacent)
+
+    f_external = None
+
+    if "external" in data:
+      f_external = data["external"]
+
+      if f_external is not None:
+        f_external = External.decode(f_external)
+
+    return Entry(f_color, f_priority, f_tagged, f_adjacent, f_external)
+
+  def encode(self):
+    data = dict()
+
+    if self.color is not None:
+      data["color"] = self.color.encode()
+
+    if self.priority is not None:
+      data["priority"] = self.priority.encode()
+
+    if self.tagged is not None:
+      data["tagged"] = self.tagged.encode()
+
+    if self.adjacent is not None:
+      data["adjacent"] = self.adjacent.encode()
+
+    if self.external is not None:
+      data["external"] = self.external.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry color:{!r}, priority:{!r}, tagged:{!r}, adjacent:{!r}, external:{!r}>".format(self.color, self.priority, self.tagged, self.adjacent, self.external)
+
+class Color:
+  def __init__(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  @property
+  def _ordinal(self):
+    return self.__ordinal
+
+  @_ordinal.setter
+  def _ordinal(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  def encode(self):
+    return self._ordinal
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._ordinal == data:
+        return value
+
+    value = object.__new__(cls)
+    value._name_ = "Other"
+    value._value_ = data
+    value._ordinal = data
+    return value
+
+  def __repr__(self):
+    return "<Color ordinal:{!r}>".format(self._ordinal)
+
+class Priority:
+  def __init__(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  @property
+  def _ordinal(self):
+    return self.__ordinal
+
+  @_ordinal.setter
+  def _ordinal(self, _ordinal):
+    self.__ordinal = _ordinal
+
+  def encode(self):
+    return self._ordinal
+
+  @classmethod
+  def decode(cls, data):
+    for value in cls.__members__.values():
+      if value._ordinal == data:
+        return value
+
+    value = object.__new__(cls)
+    value._name_ = "Other"
+    value._value_ = data
+    value._ordinal = data
+    return value
+
+  def __repr__(self):
+    return "<Priority ordinal:{!r}>".format(self._ordinal)
+
+class Tagged:
+  @staticmethod
+  def decode(data):
+    if "type" not in data:
+      raise Exception("missing tag field type")
+
+    f_tag = data["type"]
+
+    if f_tag == "foo":
+      return Tagged_A.decode(data)
+
+    if f_tag == "B":
+      return Tagged_B.decode(data)
+
+    return Tagged_Other(data)
+
+class Tagged_A(Tagged):
+  TYPE = "foo"
+
+  def __init__(self, shared):
+    self.__shared = shared
+
+  @property
+  def shared(self):
+    return self.__shared
+
+  @shared.setter
+  def shared(self, shared):
+    self.__shared = shared
+
+  @staticmethod
+  def decode(data):
+    f_shared = data["shared"]
+
+    if not isinstance(f_shared, unicode):
+      raise Exception("not a string")
+
+    return Tagged_A(f_shared)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "foo"
+
+    if self.shared is None:
+      raise Exception("missing required field: shared")
+
+    data["shared"] = self.shared
+
+    return data
+
+  def __repr__(self):
+    return "<Tagged_A shared:{!r}>".format(self.shared)
+
+class Tagged_B(Tagged):
+  TYPE = "B"
+
+  def __init__(self, shared, b):
+    self.__shared = shared
+    self.__b = b
+
+  @property
+  def shared(self):
+    return self.__shared
+
+  @shared.setter
+  def shared(self, shared):
+    self.__shared = shared
+
+  @property
+  def b(self):
+    return self.__b
+
+  @b.setter
+  def b(self, b):
+    self.__b = b
+
+  @staticmethod
+  def decode(data):
+    f_shared = data["shared"]
+
+    if not isinstance(f_shared, unicode):
+      raise Exception("not a string")
+
+    f_b = data["b"]
+
+    if not isinstance(f_b, unicode):
+      raise Exception("not a string")
+
+    return Tagged_B(f_shared, f_b)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "B"
+
+    if self.shared is None:
+      raise Exception("missing required field: shared")
+
+    data["shared"] = self.shared
+
+    if self.b is None:
+      raise Exception("missing required field: b")
+
+    data["b"] = self.b
+
+    return data
+
+  def __repr__(self):
+    return "<Tagged_B shared:{!r}, b:{!r}>".format(self.shared, self.b)
+
+class Tagged_Other(Tagged):
+  def __init__(self, data):
+    self.data = data
+
+  def encode(self):
+    return self.data
+
+  def __repr__(self):
+    return "<Tagged_Other data:{!r}>".format(self.data)
+
+class Adjacent:
+  @staticmethod
+  def decode(data):
+    if "type" not in data:
+      raise Exception("missing tag field type")
+
+    if "data" not in data:
+      raise Exception("missing content field data")
+
+    f_tag = data["type"]
+    f_content = data["data"]
+
+    if f_tag == "A":
+      return Adjacent_A.decode(f_content)
+
+    return Adjacent_Other(data)
+
+class Adjacent_A(Adjacent):
+  TYPE = "A"
+
+  def __init__(self):
+    pass
+
+  @staticmethod
+  def decode(data):
+    return Adjacent_A()
+
+  def encode(self):
+    data = dict()
+
+    return {"type": "A", "data": data}
+
+  def __repr__(self):
+    return "<Adjacent_A>"
+
+class Adjacent_Other(Adjacent):
+  def __init__(self, data):
+    self.data = data
+
+  def encode(self):
+    return self.data
+
+  def __repr__(self):
+    return "<Adjacent_Other data:{!r}>".format(self.data)
+
+class External:
+  @staticmethod
+  def decode(data):
+    if len(data) != 1:
+      raise Exception("expected exactly one key")
+
+    f_tag, f_content = list(data.items())[0]
+
+    if f_tag == "A":
+      return External_A.decode(f_content)
+
+    return External_Other(data)
+
+class External_A(External):
+  TYPE = "A"
+
+  def __init__(self):
+    pass
+
+  @staticmethod
+  def decode(data):
+    return External_A()
+
+  def encode(self):
+    data = dict()
+
+    return {"A": data}
+
+  def __repr__(self):
+    return "<External_A>"
+
+class External_Other(External):
+  def __init__(self, data):
+    self.data = data
+
+  def encode(self):
+    return self.data
+
+  def __repr__(self):
+    return "<External_Other data:{!r}>".format(self.data)
+
+Color = enum.Enum("Color", [("Red", "red"), ("Green", "green")], type=Color)
+
+Priority = enum.Enum("Priority", [("Low", 1), ("High", 2)], type=Priority)
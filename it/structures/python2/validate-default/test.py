@@ -0,0 +1,144 @@
+import re
+
+class Entry:
+  def __init__(self, name, code, label, count, offset):
+    self.__name = name
+    self.__code = code
+    self.__label = label
+    self.__count = count
+    self.__offset = offset
+
+  @property
+  def name(self):
+    return self.__name
+
+  @name.setter
+  def name(self, name):
+    self.__name = name
+
+  @property
+  def code(self):
+    return self.__code
+
+  @code.setter
+  def code(self, code):
+    self.__code = code
+
+  @property
+  def label(self):
+    return self.__label
+
+  @label.setter
+  def label(self, label):
+    self.__label = label
+
+  @property
+  def count(self):
+    return self.__count
+
+  @count.setter
+  def count(self, count):
+    self.__count = count
+
+  @property
+  def offset(self):
+    return self.__offset
+
+  @offset.setter
+  def offset(self, offset):
+    self.__offset = offset
+
+  @staticmethod
+  def decode(data):
+    f_name = None
+
+    if "name" in data:
+      f_name = data["name"]
+
+      if f_name is not None:
+        if not isinstance(f_name, unicode):
+          raise Exception("not a string")
+
+    if f_name is not None:
+      if len(f_name) > 16:
+        raise ValueError("Entry.name: length must be at most 16")
+      if re.match("(?:[a-z]+)\\Z", f_name) is None:
+        raise ValueError("Entry.name: must match pattern `[a-z]+`")
+
+    f_code = None
+
+    if "code" in data:
+      f_code = data["code"]
+
+      if f_code is not None:
+        if not isinstance(f_code, unicode):
+          raise Exception("not a string")
+
+    if f_code is not None:
+      if len(f_code) < 2:
+        raise ValueError("Entry.code: length must be at least 2")
+
+    f_label = data["label"]
+
+    if not isinstance(f_label, unicode):
+      raise Exception("not a string")
+
+    if len(f_label) < 1:
+      raise ValueError("Entry.label: length must be at least 1")
+
+    f_count = None
+
+    if "count" in data:
+      f_count = data["count"]
+
+      if f_count is not None:
+        if not isinstance(f_count, int):
+          raise Exception("not an integer")
+
+    if f_count is not None:
+      if f_count < 1:
+        raise ValueError("Entry.count: must be at least 1")
+      if f_count > 100:
+        raise ValueError("Entry.count: must be at most 100")
+
+    f_offset = None
+
+    if "offset" in data:
+      f_offset = data["offset"]
+
+      if f_offset is not None:
+        if not isinstance(f_offset, int):
+          raise Exception("not an integer")
+
+    if f_offset is not None:
+      if f_offset <= -10:
+        raise ValueError("Entry.offset: must be greater than -10")
+      if f_offset >= 10:
+        raise ValueError("Entry.offset: must be less than 10")
+
+    return Entry(f_name, f_code, f_label, f_count, f_offset)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is not None:
+      data["name"] = self.name
+
+    if self.code is not None:
+      data["code"] = self.code
+
+    if self.label is None:
+      raise Exception("missing required field: label")
+
+    data["label"] = self.label
+
+    if self.count is not None:
+      data["count"] = self.count
+
+    if self.offset is not None:
+      data["offset"] = self.offset
+
+    return data
+
+  def __repr__(self):
+    return "<Entry name:{!r}, code:{!r}, label:{!r}, count:{!r}, offset:{!r}>".format(self.name, self.code, self.label, self.count, self.offset)
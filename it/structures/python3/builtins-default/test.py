@@ -0,0 +1,157 @@
+from decimal import Decimal
+from uuid import UUID
+
+class Entry:
+  def __init__(self, date, time, duration, uuid, decimal, dates):
+    self.__date = date
+    self.__time = time
+    self.__duration = duration
+    self.__uuid = uuid
+    self.__decimal = decimal
+    self.__dates = dates
+
+  @property
+  def date(self):
+    return self.__date
+
+  @date.setter
+  def date(self, date):
+    self.__date = date
+
+  @property
+  def time(self):
+    return self.__time
+
+  @time.setter
+  def time(self, time):
+    self.__time = time
+
+  @property
+  def duration(self):
+    return self.__duration
+
+  @duration.setter
+  def duration(self, duration):
+    self.__duration = duration
+
+  @property
+  def uuid(self):
+    return self.__uuid
+
+  @uuid.setter
+  def uuid(self, uuid):
+    self.__uuid = uuid
+
+  @property
+  def decimal(self):
+    return self.__decimal
+
+  @decimal.setter
+  def decimal(self, decimal):
+    self.__decimal = decimal
+
+  @property
+  def dates(self):
+    return self.__dates
+
+  @dates.setter
+  def dates(self, dates):
+    self.__dates = dates
+
+  @staticmethod
+  def decode(data):
+    f_date = None
+
+    if "date" in data:
+      f_date = data["date"]
+
+      if f_date is not None:
+        if not isinstance(f_date, str):
+          raise Exception("not a string")
+
+    f_time = None
+
+    if "time" in data:
+      f_time = data["time"]
+
+      if f_time is not None:
+        if not isinstance(f_time, str):
+          raise Exception("not a string")
+
+    f_duration = None
+
+    if "duration" in data:
+      f_duration = data["duration"]
+
+      if f_duration is not None:
+        if not isinstance(f_duration, str):
+          raise Exception("not a string")
+
+    f_uuid = None
+
+    if "uuid" in data:
+      f_uuid = data["uuid"]
+
+      if f_uuid is not None:
+        if not isinstance(f_uuid, str):
+          raise Exception("not a string")
+
+        f_uuid = UUID(f_uuid)
+
+    f_decimal = None
+
+    if "decimal" in data:
+      f_decimal = data["decimal"]
+
+      if f_decimal is not None:
+        if not isinstance(f_decimal, str):
+          raise Exception("not a string")
+
+        f_decimal = Decimal(f_decimal)
+
+    f_dates = None
+
+    if "dates" in data:
+      f_dates = data["dates"]
+
+      if f_dates is not None:
+        if not isinstance(f_dates, list):
+          raise Exception("not an array")
+
+        _a0 = []
+
+        for _v0 in f_dates:
+          if not isinstance(_v0, str):
+            raise Exception("not a string")
+
+          _a0.append(_v0)
+
+        f_dates = _a0
+
+    return Entry(f_date, f_time, f_duration, f_uuid, f_decimal, f_dates)
+
+  def encode(self):
+    data = dict()
+
+    if self.date is not None:
+      data["date"] = self.date
+
+    if self.time is not None:
+      data["time"] = self.time
+
+    if self.duration is not None:
+      data["duration"] = self.duration
+
+    if self.uuid is not None:
+      data["uuid"] = str(self.uuid)
+
+    if self.decimal is not None:
+      data["decimal"] = str(self.decimal)
+
+    if self.dates is not None:
+      data["dates"] = self.dates
+
+    return data
+
+  def __repr__(self):
+    return "<Entry date:{!r}, time:{!r}, duration:{!r}, uuid:{!r}, decimal:{!r}, dates:{!r}>".format(self.date, self.time, self.duration, self.uuid, self.decimal, self.dates)
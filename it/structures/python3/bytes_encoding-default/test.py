@@ -0,0 +1,169 @@
+class Entry:
+  def __init__(self, standard, url, token, digest, pair):
+    self.__standard = standard
+    self.__url = url
+    self.__token = token
+    self.__digest = digest
+    self.__pair = pair
+
+  @property
+  def standard(self):
+    return self.__standard
+
+  @standard.setter
+  def standard(self, standard):
+    self.__standard = standard
+
+  @property
+  def url(self):
+    return self.__url
+
+  @url.setter
+  def url(self, url):
+    self.__url = url
+
+  @property
+  def token(self):
+    return self.__token
+
+  @token.setter
+  def token(self, token):
+    self.__token = token
+
+  @property
+  def digest(self):
+    return self.__digest
+
+  @digest.setter
+  def digest(self, digest):
+    self.__digest = digest
+
+  @property
+  def pair(self):
+    return self.__pair
+
+  @pair.setter
+  def pair(self, pair):
+    self.__pair = pair
+
+  @staticmethod
+  def decode(data):
+    f_standard = None
+
+    if "standard" in data:
+      f_standard = data["standard"]
+
+      if f_standard is not None:
+        if not isinstance(f_standard, str):
+          raise Exception("not a string")
+
+    f_url = None
+
+    if "url" in data:
+      f_url = data["url"]
+
+      if f_url is not None:
+        if not isinstance(f_url, str):
+          raise Exception("not a string")
+
+    f_token = None
+
+    if "token" in data:
+      f_token = data["token"]
+
+      if f_token is not None:
+        if not isinstance(f_token, str):
+          raise Exception("not a string")
+
+    f_digest = None
+
+    if "digest" in data:
+      f_digest = data["digest"]
+
+      if f_digest is not None:
+        if not isinstance(f_digest, str):
+          raise Exception("not a string")
+
+    f_pair = None
+
+    if "pair" in data:
+      f_pair = data["pair"]
+
+      if f_pair is not None:
+        f_pair = Pair.decode(f_pair)
+
+    return Entry(f_standard, f_url, f_token, f_digest, f_pair)
+
+  def encode(self):
+    data = dict()
+
+    if self.standard is not None:
+      data["standard"] = self.standard
+
+    if self.url is not None:
+      data["url"] = self.url
+
+    if self.token is not None:
+      data["token"] = self.token
+
+    if self.digest is not None:
+      data["digest"] = self.digest
+
+    if self.pair is not None:
+      data["pair"] = self.pair.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry standard:{!r}, url:{!r}, token:{!r}, digest:{!r}, pair:{!r}>".format(self.standard, self.url, self.token, self.digest, self.pair)
+
+class Pair:
+  def __init__(self, key, value):
+    self.__key = key
+    self.__value = value
+
+  @property
+  def key(self):
+    return self.__key
+
+  @key.setter
+  def key(self, key):
+    self.__key = key
+
+  @property
+  def value(self):
+    return self.__value
+
+  @value.setter
+  def value(self, value):
+    self.__value = value
+
+  @staticmethod
+  def decode(data):
+    f_key = data[0]
+
+    if not isinstance(f_key, str):
+      raise Exception("not a string")
+
+    f_value = data[1]
+
+    if not isinstance(f_value, str):
+      raise Exception("not a string")
+
+    return Pair(f_key, f_value)
+
+  def encode(self):
+    if self.key is None:
+      raise Exception("missing required field: key")
+
+    key = self.key
+
+    if self.value is None:
+      raise Exception("missing required field: value")
+
+    value = self.value
+
+    return (key, value)
+
+  def __repr__(self):
+    return "<Pair key:{!r}, value:{!r}>".format(self.key, self.value)
@@ -0,0 +1,51 @@
+DEFAULT_LIMIT = 100
+
+MAX_OFFSET = -1
+
+GREETING = "hello"
+
+RATIO = 0.5
+
+SCALE = 2
+
+ENABLED = True
+
+OLD_LIMIT = 10
+
+class Entry:
+  def __init__(self, limit):
+    self.__limit = limit
+
+  @property
+  def limit(self):
+    return self.__limit
+
+  @limit.setter
+  def limit(self, limit):
+    self.__limit = limit
+
+  @staticmethod
+  def decode(data):
+    f_limit = None
+
+    if "limit" in data:
+      f_limit = data["limit"]
+
+      if f_limit is not None:
+        if not isinstance(f_limit, int):
+          raise Exception("not an integer")
+
+    return Entry(f_limit)
+
+  def encode(self):
+    data = dict()
+
+    if self.limit is not None:
+      data["limit"] = self.limit
+
+    return data
+
+  def __repr__(self):
+    return "<Entry limit:{!r}>".format(self.limit)
+
+ENTRY_PAGE_SIZE = 20
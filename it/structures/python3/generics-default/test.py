@@ -0,0 +1,400 @@
+class Entry:
+  def __init__(self, page, names, pair, outcome):
+    self.__page = page
+    self.__names = names
+    self.__pair = pair
+    self.__outcome = outcome
+
+  @property
+  def page(self):
+    return self.__page
+
+  @page.setter
+  def page(self, page):
+    self.__page = page
+
+  @property
+  def names(self):
+    return self.__names
+
+  @names.setter
+  def names(self, names):
+    self.__names = names
+
+  @property
+  def pair(self):
+    return self.__pair
+
+  @pair.setter
+  def pair(self, pair):
+    self.__pair = pair
+
+  @property
+  def outcome(self):
+    return self.__outcome
+
+  @outcome.setter
+  def outcome(self, outcome):
+    self.__outcome = outcome
+
+  @staticmethod
+  def decode(data):
+    f_page = None
+
+    if "page" in data:
+      f_page = data["page"]
+
+      if f_page is not None:
+        f_page = PageItem.decode(f_page)
+
+    f_names = None
+
+    if "names" in data:
+      f_names = data["names"]
+
+      if f_names is not None:
+        f_names = PageString.decode(f_names)
+
+    f_pair = None
+
+    if "pair" in data:
+      f_pair = data["pair"]
+
+      if f_pair is not None:
+        f_pair = PairItemU32.decode(f_pair)
+
+    f_outcome = None
+
+    if "outcome" in data:
+      f_outcome = data["outcome"]
+
+      if f_outcome is not None:
+        f_outcome = OutcomeItem.decode(f_outcome)
+
+    return Entry(f_page, f_names, f_pair, f_outcome)
+
+  def encode(self):
+    data = dict()
+
+    if self.page is not None:
+      data["page"] = self.page.encode()
+
+    if self.names is not None:
+      data["names"] = self.names.encode()
+
+    if self.pair is not None:
+      data["pair"] = self.pair.encode()
+
+    if self.outcome is not None:
+      data["outcome"] = self.outcome.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry page:{!r}, names:{!r}, pair:{!r}, outcome:{!r}>".format(self.page, self.names, self.pair, self.outcome)
+
+class Item:
+  def __init__(self, name):
+    self.__name = name
+
+  @property
+  def name(self):
+    return self.__name
+
+  @name.setter
+  def name(self, name):
+    self.__name = name
+
+  @staticmethod
+  def decode(data):
+    f_name = data["name"]
+
+    if not isinstance(f_name, str):
+      raise Exception("not a string")
+
+    return Item(f_name)
+
+  def encode(self):
+    data = dict()
+
+    if self.name is None:
+      raise Exception("missing required field: name")
+
+    data["name"] = self.name
+
+    return data
+
+  def __repr__(self):
+    return "<Item name:{!r}>".format(self.name)
+
+class PageItem:
+  def __init__(self, items, next):
+    self.__items = items
+    self.__next = next
+
+  @property
+  def items(self):
+    return self.__items
+
+  @items.setter
+  def items(self, items):
+    self.__items = items
+
+  @property
+  def next(self):
+    return self.__next
+
+  @next.setter
+  def next(self, next):
+    self.__next = next
+
+  @staticmethod
+  def decode(data):
+    f_items = data["items"]
+
+    if not isinstance(f_items, list):
+      raise Exception("not an array")
+
+    _a0 = []
+
+    for _v0 in f_items:
+      _v0 = Item.decode(_v0)
+
+      _a0.append(_v0)
+
+    f_items = _a0
+
+    f_next = None
+
+    if "next" in data:
+      f_next = data["next"]
+
+      if f_next is not None:
+        if not isinstance(f_next, str):
+          raise Exception("not a string")
+
+    return PageItem(f_items, f_next)
+
+  def encode(self):
+    data = dict()
+
+    if self.items is None:
+      raise Exception("missing required field: items")
+
+    data["items"] = [v.encode() for v in self.items]
+
+    if self.next is not None:
+      data["next"] = self.next
+
+    return data
+
+  def __repr__(self):
+    return "<PageItem items:{!r}, next:{!r}>".format(self.items, self.next)
+
+class PageString:
+  def __init__(self, items, next):
+    self.__items = items
+    self.__next = next
+
+  @property
+  def items(self):
+    return self.__items
+
+  @items.setter
+  def items(self, items):
+    self.__items = items
+
+  @property
+  def next(self):
+    return self.__next
+
+  @next.setter
+  def next(self, next):
+    self.__next = next
+
+  @staticmethod
+  def decode(data):
+    f_items = data["items"]
+
+    if not isinstance(f_items, list):
+      raise Exception("not an array")
+
+    _a0 = []
+
+    for _v0 in f_items:
+      if not isinstance(_v0, str):
+        raise Exception("not a string")
+
+      _a0.append(_v0)
+
+    f_items = _a0
+
+    f_next = None
+
+    if "next" in data:
+      f_next = data["next"]
+
+      if f_next is not None:
+        if not isinstance(f_next, str):
+          raise Exception("not a string")
+
+    return PageString(f_items, f_next)
+
+  def encode(self):
+    data = dict()
+
+    if self.items is None:
+      raise Exception("missing required field: items")
+
+    data["items"] = self.items
+
+    if self.next is not None:
+      data["next"] = self.next
+
+    return data
+
+  def __repr__(self):
+    return "<PageString items:{!r}, next:{!r}>".format(self.items, self.next)
+
+class PairItemU32:
+  def __init__(self, first, second):
+    self.__first = first
+    self.__second = second
+
+  @property
+  def first(self):
+    return self.__first
+
+  @first.setter
+  def first(self, first):
+    self.__first = first
+
+  @property
+  def second(self):
+    return self.__second
+
+  @second.setter
+  def second(self, second):
+    self.__second = second
+
+  @staticmethod
+  def decode(data):
+    f_first = data["first"]
+
+    f_first = Item.decode(f_first)
+
+    f_second = data["second"]
+
+    if not isinstance(f_second, int):
+      raise Exception("not an integer")
+
+    return PairItemU32(f_first, f_second)
+
+  def encode(self):
+    data = dict()
+
+    if self.first is None:
+      raise Exception("missing required field: first")
+
+    data["first"] = self.first.encode()
+
+    if self.second is None:
+      raise Exception("missing required field: second")
+
+    data["second"] = self.second
+
+    return data
+
+  def __repr__(self):
+    return "<PairItemU32 first:{!r}, second:{!r}>".format(self.first, self.second)
+
+class OutcomeItem:
+  @staticmethod
+  def decode(data):
+    if "type" not in data:
+      raise Exception("missing tag field type")
+
+    f_tag = data["type"]
+
+    if f_tag == "Ok":
+      return OutcomeItem_Ok.decode(data)
+
+    if f_tag == "Error":
+      return OutcomeItem_Error.decode(data)
+
+    raise Exception("no sub type matching tag: " + f_tag)
+
+class OutcomeItem_Ok(OutcomeItem):
+  TYPE = "Ok"
+
+  def __init__(self, value):
+    self.__value = value
+
+  @property
+  def value(self):
+    return self.__value
+
+  @value.setter
+  def value(self, value):
+    self.__value = value
+
+  @staticmethod
+  def decode(data):
+    f_value = data["value"]
+
+    f_value = Item.decode(f_value)
+
+    return OutcomeItem_Ok(f_value)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "Ok"
+
+    if self.value is None:
+      raise Exception("missing required field: value")
+
+    data["value"] = self.value.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<OutcomeItem_Ok value:{!r}>".format(self.value)
+
+class OutcomeItem_Error(OutcomeItem):
+  TYPE = "Error"
+
+  def __init__(self, message):
+    self.__message = message
+
+  @property
+  def message(self):
+    return self.__message
+
+  @message.setter
+  def message(self, message):
+    self.__message = message
+
+  @staticmethod
+  def decode(data):
+    f_message = data["message"]
+
+    if not isinstance(f_message, str):
+      raise Exception("not a string")
+
+    return OutcomeItem_Error(f_message)
+
+  def encode(self):
+    data = dict()
+
+    data["type"] = "Error"
+
+    if self.message is None:
+      raise Exception("missing required field: message")
+
+    data["message"] = self.message
+
+    return data
+
+  def __repr__(self):
+    return "<OutcomeItem_Error message:{!r}>".format(self.message)
@@ -0,0 +1,296 @@
+class Entry:
+  def __init__(self, created_at, updated_at, updated_by, id, name):
+    self.__created_at = created_at
+    self.__updated_at = updated_at
+    self.__updated_by = updated_by
+    self.__id = id
+    self.__name = name
+
+  @property
+  def created_at(self):
+    return self.__created_at
+
+  @created_at.setter
+  def created_at(self, created_at):
+    self.__created_at = created_at
+
+  @property
+  def updated_at(self):
+    return self.__updated_at
+
+  @updated_at.setter
+  def updated_at(self, updated_at):
+    self.__updated_at = updated_at
+
+  @property
+  def updated_by(self):
+    """
+    Who last modified the entry.
+    """
+    return self.__updated_by
+
+  @updated_by.setter
+  def updated_by(self, updated_by):
+    self.__updated_by = updated_by
+
+  @property
+  def id(self):
+    return self.__id
+
+  @id.setter
+  def id(self, id):
+    self.__id = id
+
+  @property
+  def name(self):
+    return self.__name
+
+  @name.setter
+  def name(self, name):
+    self.__name = name
+
+  @staticmethod
+  def decode(data):
+    f_created_at = None
+
+    if "created_at" in data:
+      f_created_at = data["created_at"]
+
+      if f_created_at is not None:
+        if not isinstance(f_created_at, str):
+          raise Exception("not a string")
+
+    f_updated_at = None
+
+    if "updated_at" in data:
+      f_updated_at = data["updated_at"]
+
+      if f_updated_at is not None:
+        if not isinstance(f_updated_at, str):
+          raise Exception("not a string")
+
+    f_updated_by = None
+
+    if "updated_by" in data:
+      f_updated_by = data["updated_by"]
+
+      if f_updated_by is not None:
+        if not isinstance(f_updated_by, str):
+          raise Exception("not a string")
+
+    f_id = None
+
+    if "id" in data:
+      f_id = data["id"]
+
+      if f_id is not None:
+        if not isinstance(f_id, str):
+          raise Exception("not a string")
+
+    f_name = None
+
+    if "name" in data:
+      f_name = data["name"]
+
+      if f_name is not None:
+        if not isinstance(f_name, str):
+          raise Exception("not a string")
+
+    return Entry(f_created_at, f_updated_at, f_updated_by, f_id, f_name)
+
+  def encode(self):
+    data = dict()
+
+    if self.created_at is not None:
+      data["created_at"] = self.created_at
+
+    if self.updated_at is not None:
+      data["updated_at"] = self.updated_at
+
+    if self.updated_by is not None:
+      data["updated_by"] = self.updated_by
+
+    if self.id is not None:
+      data["id"] = self.id
+
+    if self.name is not None:
+      data["name"] = self.name
+
+    return data
+
+  def __repr__(self):
+    return "<Entry created_at:{!r}, updated_at:{!r}, updated_by:{!r}, id:{!r}, name:{!r}>".format(self.created_at, self.updated_at, self.updated_by, self.id, self.name)
+
+class Auditable:
+  def __init__(self, created_at, updated_at, updated_by):
+    self.__created_at = created_at
+    self.__updated_at = updated_at
+    self.__updated_by = updated_by
+
+  @property
+  def created_at(self):
+    return self.__created_at
+
+  @created_at.setter
+  def created_at(self, created_at):
+    self.__created_at = created_at
+
+  @property
+  def updated_at(self):
+    return self.__updated_at
+
+  @updated_at.setter
+  def updated_at(self, updated_at):
+    self.__updated_at = updated_at
+
+  @property
+  def updated_by(self):
+    """
+    Who last modified the entry.
+    """
+    return self.__updated_by
+
+  @updated_by.setter
+  def updated_by(self, updated_by):
+    self.__updated_by = updated_by
+
+  @staticmethod
+  def decode(data):
+    f_created_at = None
+
+    if "created_at" in data:
+      f_created_at = data["created_at"]
+
+      if f_created_at is not None:
+        if not isinstance(f_created_at, str):
+          raise Exception("not a string")
+
+    f_updated_at = None
+
+    if "updated_at" in data:
+      f_updated_at = data["updated_at"]
+
+      if f_updated_at is not None:
+        if not isinstance(f_updated_at, str):
+          raise Exception("not a string")
+
+    f_updated_by = None
+
+    if "updated_by" in data:
+      f_updated_by = data["updated_by"]
+
+      if f_updated_by is not None:
+        if not isinstance(f_updated_by, str):
+          raise Exception("not a string")
+
+    return Auditable(f_created_at, f_updated_at, f_updated_by)
+
+  def encode(self):
+    data = dict()
+
+    if self.created_at is not None:
+      data["created_at"] = self.created_at
+
+    if self.updated_at is not None:
+      data["updated_at"] = self.updated_at
+
+    if self.updated_by is not None:
+      data["updated_by"] = self.updated_by
+
+    return data
+
+  def __repr__(self):
+    return "<Auditable created_at:{!r}, updated_at:{!r}, updated_by:{!r}>".format(self.created_at, self.updated_at, self.updated_by)
+
+class Timestamped:
+  def __init__(self, created_at, updated_at):
+    self.__created_at = created_at
+    self.__updated_at = updated_at
+
+  @property
+  def created_at(self):
+    return self.__created_at
+
+  @created_at.setter
+  def created_at(self, created_at):
+    self.__created_at = created_at
+
+  @property
+  def updated_at(self):
+    return self.__updated_at
+
+  @updated_at.setter
+  def updated_at(self, updated_at):
+    self.__updated_at = updated_at
+
+  @staticmethod
+  def decode(data):
+    f_created_at = None
+
+    if "created_at" in data:
+      f_created_at = data["created_at"]
+
+      if f_created_at is not None:
+        if not isinstance(f_created_at, str):
+          raise Exception("not a string")
+
+    f_updated_at = None
+
+    if "updated_at" in data:
+      f_updated_at = data["updated_at"]
+
+      if f_updated_at is not None:
+        if not isinstance(f_updated_at, str):
+          raise Exception("not a string")
+
+    return Timestamped(f_created_at, f_updated_at)
+
+  def encode(self):
+    data = dict()
+
+    if self.created_at is not None:
+      data["created_at"] = self.created_at
+
+    if self.updated_at is not None:
+      data["updated_at"] = self.updated_at
+
+    return data
+
+  def __repr__(self):
+    return "<Timestamped created_at:{!r}, updated_at:{!r}>".format(self.created_at, self.updated_at)
+
+class Identified:
+  def __init__(self, id):
+    self.__id = id
+
+  @property
+  def id(self):
+    return self.__id
+
+  @id.setter
+  def id(self, id):
+    self.__id = id
+
+  @staticmethod
+  def decode(data):
+    f_id = None
+
+    if "id" in data:
+      f_id = data["id"]
+
+      if f_id is not None:
+        if not isinstance(f_id, str):
+          raise Exception("not a string")
+
+    return Identified(f_id)
+
+  def encode(self):
+    data = dict()
+
+    if self.id is not None:
+      data["id"] = self.id
+
+    return data
+
+  def __repr__(self):
+    return "<Identified id:{!r}>".format(self.id)
@@ -0,0 +1,188 @@
+import json
+import requests
+
+class Entry:
+  def __init__(self, value):
+    self.__value = value
+
+  @property
+  def value(self):
+    return self.__value
+
+  @value.setter
+  def value(self, value):
+    self.__value = value
+
+  @staticmethod
+  def decode(data):
+    f_value = None
+
+    if "value" in data:
+      f_value = data["value"]
+
+      if f_value is not None:
+        if not isinstance(f_value, str):
+          raise Exception("not a string")
+
+    return Entry(f_value)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is not None:
+      data["value"] = self.value
+
+    return data
+
+  def __repr__(self):
+    return "<Entry value:{!r}>".format(self.value)
+
+class NotFound:
+  def __init__(self, message):
+    self.__message = message
+
+  @property
+  def message(self):
+    return self.__message
+
+  @message.setter
+  def message(self, message):
+    self.__message = message
+
+  @staticmethod
+  def decode(data):
+    f_message = data["message"]
+
+    if not isinstance(f_message, str):
+      raise Exception("not a string")
+
+    return NotFound(f_message)
+
+  def encode(self):
+    data = dict()
+
+    if self.message is None:
+      raise Exception("missing required field: message")
+
+    data["message"] = self.message
+
+    return data
+
+  def __repr__(self):
+    return "<NotFound message:{!r}>".format(self.message)
+
+class MyService_Events_Error(Exception):
+  """
+  Error response of `events`, with the status code and decoded body.
+  """
+  def __init__(self, status, body):
+    Exception.__init__(self, status, body)
+    self.status = status
+    self.body = body
+
+class MyService_Requests:
+  def __init__(self, **kw):
+    url = kw.pop("url", None)
+
+    if url is None:
+      url = "http://example.com"
+
+    session = kw.pop("session", None)
+
+    if session is None:
+      session = requests
+
+    self.url = url
+    self.session = session
+
+  @staticmethod
+  def _ndjson(r):
+    """
+    Decode a response with one JSON document per line.
+    """
+    if r.encoding is None:
+      r.encoding = "utf-8"
+
+    for line in r.iter_lines(decode_unicode=True):
+      if line:
+        yield json.loads(line)
+
+  @staticmethod
+  def _sse(r):
+    """
+    Decode a response of Server-Sent Events, with one JSON document in the data of each event.
+    """
+    if r.encoding is None:
+      r.encoding = "utf-8"
+
+    data = None
+
+    for line in r.iter_lines(decode_unicode=True):
+      if not line:
+        if data is not None:
+          yield json.loads(data)
+          data = None
+
+        continue
+
+      if line.startswith("data:"):
+        value = line[5:]
+
+        if value.startswith(" "):
+          value = value[1:]
+
+        data = value if data is None else data + "\n" + value
+
+    if data is not None:
+      yield json.loads(data)
+
+  def events(self, id):
+    """
+    SERVER_SENT_EVENTS
+    """
+    path = list()
+
+    path.append(self.url)
+    path.append("/")
+    path.append("events")
+    path.append("/")
+    path.append(str(id))
+
+    url = "/".join(path)
+
+    r = self.session.request("GET", url=url, stream=True)
+
+    if r.status_code == 404:
+      data = r.json()
+
+      data = NotFound.decode(data)
+
+      raise MyService_Events_Error(404, data)
+
+    r.raise_for_status()
+
+    for data in self._sse(r):
+      data = Entry.decode(data)
+
+      yield data
+
+  def entries(self, request):
+    """
+    NDJSON
+    """
+    path = list()
+
+    path.append(self.url)
+    path.append("/")
+    path.append("entries")
+
+    url = "/".join(path)
+
+    r = self.session.request("POST", url=url, stream=True, json=request.encode())
+
+    r.raise_for_status()
+
+    for data in self._ndjson(r):
+      data = Entry.decode(data)
+
+      yield data
@@ -0,0 +1,141 @@
+from websockets.exceptions import ConnectionClosedOK
+import json
+import websockets
+
+class Entry:
+  def __init__(self):
+    pass
+
+  @staticmethod
+  def decode(data):
+    return Entry()
+
+  def encode(self):
+    data = dict()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry>"
+
+class Message:
+  def __init__(self, text):
+    self.__text = text
+
+  @property
+  def text(self):
+    return self.__text
+
+  @text.setter
+  def text(self, text):
+    self.__text = text
+
+  @staticmethod
+  def decode(data):
+    f_text = data["text"]
+
+    if not isinstance(f_text, str):
+      raise Exception("not a string")
+
+    return Message(f_text)
+
+  def encode(self):
+    data = dict()
+
+    if self.text is None:
+      raise Exception("missing required field: text")
+
+    data["text"] = self.text
+
+    return data
+
+  def __repr__(self):
+    return "<Message text:{!r}>".format(self.text)
+
+class MyService_WebSocket:
+  def __init__(self, **kw):
+    url = kw.pop("url", None)
+
+    if url is None:
+      url = "ws://example.com"
+
+    self.url = url
+
+  class Sender:
+    """
+    The half of a bi-directional endpoint which sends requests.
+    """
+    def __init__(self, socket, endpoint, encode):
+      self.socket = socket
+      self.endpoint = endpoint
+      self.encode = encode
+
+    async def send(self, value):
+      """
+      Send a request.
+      """
+      await self.socket.send(json.dumps(dict(endpoint=self.endpoint, body=self.encode(value))))
+
+    async def close(self):
+      """
+      Close the connection, after which no more requests can be sent.
+      """
+      await self.socket.close()
+
+  class Receiver:
+    """
+    The half of a bi-directional endpoint which receives responses.
+    Responses can also be received with `async for`.
+    """
+    def __init__(self, socket, endpoint, decode):
+      self.socket = socket
+      self.endpoint = endpoint
+      self.decode = decode
+
+    async def recv(self):
+      """
+      Receive the next response, or None once the connection has been closed.
+      Messages for other endpoints are ignored.
+      """
+      while True:
+        try:
+          message = await self.socket.recv()
+        except ConnectionClosedOK:
+          return None
+
+        if not isinstance(message, str):
+          continue
+
+        envelope = json.loads(message)
+
+        if not isinstance(envelope, dict) or envelope.get("endpoint") != self.endpoint or "body" not in envelope:
+          continue
+
+        return self.decode(envelope["body"])
+
+    def __aiter__(self):
+      return self
+
+    async def __anext__(self):
+      value = await self.recv()
+
+      if value is None:
+        raise StopAsyncIteration
+
+      return value
+
+  async def chat(self):
+    """
+    BIDI_STREAMING
+    """
+    socket = await websockets.connect(self.url + "/chat")
+
+    def encode(value):
+      return value.encode()
+
+    def decode(data):
+      data = Message.decode(data)
+
+      return data
+
+    return (self.Sender(socket, "chat", encode), self.Receiver(socket, "chat", decode))
@@ -0,0 +1,292 @@
+class Point:
+  def __init__(self, x, y):
+    self.__x = x
+    self.__y = y
+
+  @property
+  def x(self):
+    return self.__x
+
+  @x.setter
+  def x(self, x):
+    self.__x = x
+
+  @property
+  def y(self):
+    return self.__y
+
+  @y.setter
+  def y(self, y):
+    self.__y = y
+
+  @staticmethod
+  def decode(data):
+    f_x = data["x"]
+
+    if not isinstance(f_x, float):
+      raise Exception("not a float")
+
+    f_y = data["y"]
+
+    if not isinstance(f_y, float):
+      raise Exception("not a float")
+
+    return Point(f_x, f_y)
+
+  def encode(self):
+    data = dict()
+
+    if self.x is None:
+      raise Exception("missing required field: x")
+
+    data["x"] = self.x
+
+    if self.y is None:
+      raise Exception("missing required field: y")
+
+    data["y"] = self.y
+
+    return data
+
+  def __repr__(self):
+    return "<Point x:{!r}, y:{!r}>".format(self.x, self.y)
+
+class Entry:
+  def __init__(self, value, shape, flag):
+    self.__value = value
+    self.__shape = shape
+    self.__flag = flag
+
+  @property
+  def value(self):
+    """
+    Either a name or a numeric identifier.
+    """
+    return self.__value
+
+  @value.setter
+  def value(self, value):
+    self.__value = value
+
+  @property
+  def shape(self):
+    return self.__shape
+
+  @shape.setter
+  def shape(self, shape):
+    self.__shape = shape
+
+  @property
+  def flag(self):
+    return self.__flag
+
+  @flag.setter
+  def flag(self, flag):
+    self.__flag = flag
+
+  @staticmethod
+  def decode(data):
+    f_value = None
+
+    if "value" in data:
+      f_value = data["value"]
+
+      if f_value is not None:
+        f_value = Entry_Value.decode(f_value)
+
+    f_shape = None
+
+    if "shape" in data:
+      f_shape = data["shape"]
+
+      if f_shape is not None:
+        f_shape = Entry_Shape.decode(f_shape)
+
+    f_flag = None
+
+    if "flag" in data:
+      f_flag = data["flag"]
+
+      if f_flag is not None:
+        f_flag = Entry_Flag.decode(f_flag)
+
+    return Entry(f_value, f_shape, f_flag)
+
+  def encode(self):
+    data = dict()
+
+    if self.value is not None:
+      data["value"] = self.value.encode()
+
+    if self.shape is not None:
+      data["shape"] = self.shape.encode()
+
+    if self.flag is not None:
+      data["flag"] = self.flag.encode()
+
+    return data
+
+  def __repr__(self):
+    return "<Entry value:{!r}, shape:{!r}, flag:{!r}>".format(self.value, self.shape, self.flag)
+
+class Entry_Value:
+  def __init__(self, string=None, u64=None):
+    self.__string = string
+    self.__u64 = u64
+
+  @property
+  def string(self):
+    return self.__string
+
+  @property
+  def u64(self):
+    return self.__u64
+
+  @staticmethod
+  def decode(data):
+    if isinstance(data, str):
+      v = data
+
+      if not isinstance(v, str):
+        raise Exception("not a string")
+
+      return Entry_Value(string=v)
+
+    if isinstance(data, int) and not isinstance(data, bool):
+      v = data
+
+      if not isinstance(v, int):
+        raise Exception("not an integer")
+
+      return Entry_Value(u64=v)
+
+    raise Exception("no alternative of the union matches the value")
+
+  def encode(self):
+    if self.__string is not None:
+      return self.__string
+
+    if self.__u64 is not None:
+      return self.__u64
+
+    raise Exception("no alternative of the union is set")
+
+  def __repr__(self):
+    if self.__string is not None:
+      return "<Entry_Value string:{!r}>".format(self.__string)
+
+    if self.__u64 is not None:
+      return "<Entry_Value u64:{!r}>".format(self.__u64)
+
+    return "<Entry_Value>"
+
+class Entry_Shape:
+  def __init__(self, point=None, double_array=None):
+    self.__point = point
+    self.__double_array = double_array
+
+  @property
+  def point(self):
+    return self.__point
+
+  @property
+  def double_array(self):
+    return self.__double_array
+
+  @staticmethod
+  def decode(data):
+    if isinstance(data, dict):
+      v = data
+
+      v = Point.decode(v)
+
+      return Entry_Shape(point=v)
+
+    if isinstance(data, list):
+      v = data
+
+      if not isinstance(v, list):
+        raise Exception("not an array")
+
+      _a0 = []
+
+      for _v0 in v:
+        if not isinstance(_v0, float):
+          raise Exception("not a float")
+
+        _a0.append(_v0)
+
+      v = _a0
+
+      return Entry_Shape(double_array=v)
+
+    raise Exception("no alternative of the union matches the value")
+
+  def encode(self):
+    if self.__point is not None:
+      return self.__point.encode()
+
+    if self.__double_array is not None:
+      return self.__double_array
+
+    raise Exception("no alternative of the union is set")
+
+  def __repr__(self):
+    if self.__point is not None:
+      return "<Entry_Shape point:{!r}>".format(self.__point)
+
+    if self.__double_array is not None:
+      return "<Entry_Shape double_array:{!r}>".format(self.__double_array)
+
+    return "<Entry_Shape>"
+
+class Entry_Flag:
+  def __init__(self, boolean=None, string=None):
+    self.__boolean = boolean
+    self.__string = string
+
+  @property
+  def boolean(self):
+    return self.__boolean
+
+  @property
+  def string(self):
+    return self.__string
+
+  @staticmethod
+  def decode(data):
+    if isinstance(data, bool):
+      v = data
+
+      if not isinstance(v, bool):
+        raise Exception("not a boolean")
+
+      return Entry_Flag(boolean=v)
+
+    if isinstance(data, str):
+      v = data
+
+      if not isinstance(v, str):
+        raise Exception("not a string")
+
+      return Entry_Flag(string=v)
+
+    raise Exception("no alternative of the union matches the value")
+
+  def encode(self):
+    if self.__boolean is not None:
+      return self.__boolean
+
+    if self.__string is not None:
+      return self.__string
+
+    raise Exception("no alternative of the union is set")
+
+  def __repr__(self):
+    if self.__boolean is not None:
+      return "<Entry_Flag boolean:{!r}>".format(self.__boolean)
+
+    if self.__string is not None:
+      return "<Entry_Flag string:{!r}>".format(self.__string)
+
+    return "<Entry_Flag>"
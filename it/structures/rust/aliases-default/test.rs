@@ -0,0 +1,36 @@
+use once_cell::sync;
+use serde::{Deserialize, Serialize};
+use std::collections;
+
+/// Identifier of an entry, generated as a distinct type where supported.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EntryId(pub u64);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub id: Option<EntryId>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub user: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub tags: Option<Vec<String>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub owners: Option<collections::HashMap<String, EntryId>>,
+}
+
+impl Entry {
+  /// Check that the fields of `Entry` satisfy their validation rules.
+  pub fn validate(&self) -> Result<(), String> {
+    if let Some(value) = &self.user {
+      static USER_PATTERN: sync::Lazy<regex::Regex> = sync::Lazy::new(|| regex::Regex::new("^(?:[a-z]+)$").expect("bad pattern"));
+      if !USER_PATTERN.is_match(value) {
+        return Err("Entry.user: must match pattern `[a-z]+`".to_string());
+      }
+    }
+
+    Ok(())
+  }
+}
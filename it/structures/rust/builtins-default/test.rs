@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub date: Option<chrono::NaiveDate>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub time: Option<chrono::NaiveTime>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub duration: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub uuid: Option<uuid::Uuid>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub decimal: Option<rust_decimal::Decimal>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub dates: Option<Vec<chrono::NaiveDate>>,
+}
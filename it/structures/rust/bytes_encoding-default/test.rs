@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub standard: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub url: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub token: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub digest: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub pair: Option<Pair>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Pair(pub String, pub String);
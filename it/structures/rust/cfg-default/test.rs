@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub id: Option<String>,
+
+  /// Not available when building for Python or JavaScript.
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub typed: Option<u32>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub color: Option<Color>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Color {
+  #[serde(rename = "red")]
+  Red,
+  #[serde(rename = "green")]
+  Green,
+}
+
+impl Color {
+  pub fn value(&self) -> &'static str {
+    match self {
+      Self::Red => "red",
+      Self::Green => "green",
+    }
+  }
+}
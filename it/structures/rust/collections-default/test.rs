@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde::de;
+use std::collections;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub by_color: Option<collections::HashMap<Color, u32>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub by_id: Option<collections::HashMap<u64, String>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub tags: Option<collections::HashSet<String>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub levels: Option<collections::HashSet<Level>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Color {
+  #[serde(rename = "red")]
+  Red,
+  #[serde(rename = "green")]
+  Green,
+}
+
+impl Color {
+  pub fn value(&self) -> &'static str {
+    match self {
+      Self::Red => "red",
+      Self::Green => "green",
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Level {
+  Low,
+  High,
+}
+
+impl Level {
+  pub fn value(&self) -> u32 {
+    match self {
+      Self::Low => 1,
+      Self::High => 2,
+    }
+  }
+}
+
+impl serde::Serialize for Level {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    let o = match self {
+      Level::Low => 1u32,
+      Level::High => 2u32,
+    };
+
+    s.serialize_u32(o)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for Level {
+  fn deserialize<D>(d: D) -> Result<Level, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    struct Visitor;
+
+    impl<'de> de::Visitor<'de> for Visitor {
+      type Value = Level;
+
+      fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Level, one of: 1, 2")
+      }
+
+      fn visit_u32<E>(self, value: u32) -> Result<Level, E>
+        where E: de::Error
+      {
+        match value {
+          1u32 => Ok(Level::Low),
+          2u32 => Ok(Level::High),
+          value => Err(E::custom(format!("Level: unknown value: {}", value))),
+        }
+      }
+
+      fn visit_u64<E>(self, value: u64) -> Result<Level, E>
+        where E: de::Error
+      {
+        self.visit_u32(value as u32)
+      }
+    }
+
+    d.deserialize_u32(Visitor)
+  }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// The default number of entries to return.
+pub const DEFAULT_LIMIT: u32 = 100;
+
+pub const MAX_OFFSET: i64 = -1;
+
+pub const GREETING: &'static str = "hello";
+
+pub const RATIO: f64 = 0.5f64;
+
+pub const SCALE: f32 = 2f32;
+
+pub const ENABLED: bool = true;
+
+#[deprecated(note = "use DefaultLimit")]
+pub const OLD_LIMIT: u32 = 10;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub limit: Option<u32>,
+}
+
+/// Maximum number of entries in a single page.
+pub const ENTRY_PAGE_SIZE: u32 = 20;
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Entry::default_name")]
+  pub name: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Entry::default_count")]
+  pub count: Option<u32>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Entry::default_big")]
+  pub big: Option<i64>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Entry::default_ratio")]
+  pub ratio: Option<f64>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Entry::default_scale")]
+  pub scale: Option<f32>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Entry::default_enabled")]
+  pub enabled: Option<bool>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub label: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub shape: Option<Shape>,
+}
+
+impl Entry {
+  fn default_name() -> Option<String> {
+    Some("unknown".to_string())
+  }
+
+  fn default_count() -> Option<u32> {
+    Some(42)
+  }
+
+  fn default_big() -> Option<i64> {
+    Some(-1)
+  }
+
+  fn default_ratio() -> Option<f64> {
+    Some(0.5f64)
+  }
+
+  fn default_scale() -> Option<f32> {
+    Some(2f32)
+  }
+
+  fn default_enabled() -> Option<bool> {
+    Some(true)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Shape {
+  Circle(Shape_Circle),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Shape_Circle {
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(default = "Shape_Circle::default_radius")]
+  pub radius: Option<f64>,
+}
+
+impl Shape_Circle {
+  fn default_radius() -> Option<f64> {
+    Some(1f64)
+  }
+}
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  /// Kept for compatibility.
+  #[deprecated(since = "1.1.0", note = "use `current` instead")]
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub legacy: Option<Legacy>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub current: Option<Current>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub status: Option<Status>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub point: Option<Point>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub shape: Option<Shape>,
+}
+
+#[deprecated(note = "use `Current` instead")]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Legacy {
+  pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Current {
+  pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Status {
+  #[serde(rename = "active")]
+  Active,
+  #[deprecated]
+  #[serde(rename = "retired")]
+  Retired,
+}
+
+impl Status {
+  pub fn value(&self) -> &'static str {
+    match self {
+      Self::Active => "active",
+      Self::Retired => "retired",
+    }
+  }
+}
+
+#[deprecated(since = "1.0.0")]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Point(pub i32, pub i32);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Shape {
+  Circle(Shape_Circle),
+
+  Round(Shape_Round),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Shape_Circle {
+  pub radius: f64,
+}
+
+#[deprecated(note = "use `Circle` instead")]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Shape_Round {
+  pub radius: f64,
+}
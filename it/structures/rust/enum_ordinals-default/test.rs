@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde::de;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub mixed: Option<EnumMixed>,
+}
+
+/// Implicit values follow the previous explicit value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnumMixed {
+  A,
+  B,
+  C,
+  D,
+  E,
+}
+
+impl EnumMixed {
+  pub fn value(&self) -> u32 {
+    match self {
+      Self::A => 0,
+      Self::B => 5,
+      Self::C => 6,
+      Self::D => 2,
+      Self::E => 3,
+    }
+  }
+}
+
+impl serde::Serialize for EnumMixed {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    let o = match self {
+      EnumMixed::A => 0u32,
+      EnumMixed::B => 5u32,
+      EnumMixed::C => 6u32,
+      EnumMixed::D => 2u32,
+      EnumMixed::E => 3u32,
+    };
+
+    s.serialize_u32(o)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for EnumMixed {
+  fn deserialize<D>(d: D) -> Result<EnumMixed, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    struct Visitor;
+
+    impl<'de> de::Visitor<'de> for Visitor {
+      type Value = EnumMixed;
+
+      fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("EnumMixed, one of: 0, 5, 6, 2, 3")
+      }
+
+      fn visit_u32<E>(self, value: u32) -> Result<EnumMixed, E>
+        where E: de::Error
+      {
+        match value {
+          0u32 => Ok(EnumMixed::A),
+          5u32 => Ok(EnumMixed::B),
+          6u32 => Ok(EnumMixed::C),
+          2u32 => Ok(EnumMixed::D),
+          3u32 => Ok(EnumMixed::E),
+          value => Err(E::custom(format!("EnumMixed: unknown value: {}", value))),
+        }
+      }
+
+      fn visit_u64<E>(self, value: u64) -> Result<EnumMixed, E>
+        where E: de::Error
+      {
+        self.visit_u32(value as u32)
+      }
+    }
+
+    d.deserialize_u32(Visitor)
+  }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub page: Option<Page<Item>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub names: Option<Page<String>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub pair: Option<Pair<Item, u32>>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub outcome: Option<Outcome<Item>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+  pub name: String,
+}
+
+/// A single page of results.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Page<T> {
+  pub items: Vec<T>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub next: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Pair<A, B> {
+  pub first: A,
+
+  pub second: B,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Outcome<T> {
+  Ok(Outcome_Ok<T>),
+
+  Error(Outcome_Error),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Outcome_Ok<T> {
+  pub value: T,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Outcome_Error {
+  pub message: String,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub created_at: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub updated_at: Option<String>,
+
+  /// Who last modified the entry.
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub updated_by: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub id: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub name: Option<String>,
+}
+
+/// Fields shared by all audited types.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Auditable {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub created_at: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub updated_at: Option<String>,
+
+  /// Who last modified the entry.
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub updated_by: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Timestamped {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub created_at: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub updated_at: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Identified {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub id: Option<String>,
+}
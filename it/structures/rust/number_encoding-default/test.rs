@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use serde::de;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(serialize_with = "Entry::serialize_id", deserialize_with = "Entry::deserialize_id")]
+  pub id: u64,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  #[serde(serialize_with = "Entry::serialize_offset", deserialize_with = "Entry::deserialize_offset")]
+  #[serde(default)]
+  pub offset: Option<i64>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub size: Option<i64>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub count: Option<u64>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub pair: Option<Pair>,
+}
+
+impl Entry {
+  fn serialize_id<S>(value: &u64, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    s.collect_str(value)
+  }
+
+  fn deserialize_id<'de, D>(d: D) -> Result<u64, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    <String as serde::Deserialize>::deserialize(d)?.parse().map_err(de::Error::custom)
+  }
+
+  fn serialize_offset<S>(value: &Option<i64>, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    match value {
+      Some(value) => s.collect_str(value),
+      None => s.serialize_none(),
+    }
+  }
+
+  fn deserialize_offset<'de, D>(d: D) -> Result<Option<i64>, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    match <Option<String> as serde::Deserialize>::deserialize(d)? {
+      Some(value) => value.parse().map(Some).map_err(de::Error::custom),
+      None => Ok(None),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Pair(#[serde(serialize_with = "Pair::serialize_left", deserialize_with = "Pair::deserialize_left")] pub u64, pub u64);
+
+impl Pair {
+  fn serialize_left<S>(value: &u64, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    s.collect_str(value)
+  }
+
+  fn deserialize_left<'de, D>(d: D) -> Result<u64, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    <String as serde::Deserialize>::deserialize(d)?.parse().map_err(de::Error::custom)
+  }
+}
@@ -0,0 +1,148 @@
+use futures::stream;
+use serde::de;
+use serde_json as json;
+use std::fmt;
+use std::mem;
+use std::result;
+
+#[derive(Debug)]
+pub enum Error {
+  ReqwestError(reqwest::Error),
+  UrlParseError(url::ParseError),
+  FormatError(fmt::Error),
+  JsonError(serde_json::Error)
+}
+
+pub type Result<T, E = Error> = result::Result<T, E>;
+
+impl From<reqwest::Error> for Error {
+  fn from(value: reqwest::Error) -> Self {
+    Error::ReqwestError(value)
+  }
+}
+
+impl From<url::ParseError> for Error {
+  fn from(value: url::ParseError) -> Self {
+    Error::UrlParseError(value)
+  }
+}
+
+impl From<fmt::Error> for Error {
+  fn from(value: fmt::Error) -> Self {
+    Error::FormatError(value)
+  }
+}
+
+impl From<serde_json::Error> for Error {
+  fn from(value: serde_json::Error) -> Self {
+    Error::JsonError(value)
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Error::ReqwestError(e) => e.fmt(fmt),
+      Error::UrlParseError(e) => e.fmt(fmt),
+      Error::FormatError(e) => e.fmt(fmt),
+      Error::JsonError(e) => e.fmt(fmt),
+    }
+  }
+}
+
+pub struct PathEncode<T>(pub T);
+
+impl<T> fmt::Display for PathEncode<T>
+where
+  T: fmt::Display
+{
+  fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+    write!(fmt, "{}", percent_encoding::utf8_percent_encode(&self.0.to_string(), percent_encoding::NON_ALPHANUMERIC))
+  }
+}
+
+/// Read the lines of a response as they arrive, without their line endings.
+fn lines(res: reqwest::Response) -> impl stream::Stream<Item = Result<String>> {
+  stream::try_unfold((res, Vec::new(), false), |(mut res, mut buf, mut done)| async move {
+    loop {
+      if let Some(n) = buf.iter().position(|b| *b == b'\n') {
+        let mut line = buf.drain(..=n).collect::<Vec<u8>>();
+        line.pop();
+
+        if line.last() == Some(&b'\r') {
+          line.pop();
+        }
+
+        let line = String::from_utf8_lossy(&line).into_owned();
+        return Ok(Some((line, (res, buf, done))));
+      }
+
+      if done {
+        if buf.is_empty() {
+          return Ok(None);
+        }
+
+        let line = String::from_utf8_lossy(&mem::take(&mut buf)).into_owned();
+        return Ok(Some((line, (res, buf, done))));
+      }
+
+      match res.chunk().await? {
+        Some(chunk) => buf.extend_from_slice(&chunk),
+        None => done = true,
+      }
+    }
+  })
+}
+
+/// Decode a response with one JSON document per line.
+pub fn ndjson<T>(res: reqwest::Response) -> impl stream::Stream<Item = Result<T>>
+where
+  T: de::DeserializeOwned,
+{
+  stream::try_unfold(Box::pin(lines(res)), |mut lines| async move {
+    while let Some(line) = stream::TryStreamExt::try_next(&mut lines).await? {
+      if !line.is_empty() {
+        return Ok(Some((json::from_str(&line)?, lines)));
+      }
+    }
+
+    Ok(None)
+  })
+}
+
+/// Decode a response of Server-Sent Events, with one JSON document in the data of each event.
+pub fn sse<T>(res: reqwest::Response) -> impl stream::Stream<Item = Result<T>>
+where
+  T: de::DeserializeOwned,
+{
+  stream::try_unfold(Box::pin(lines(res)), |mut lines| async move {
+    let mut data = None::<String>;
+
+    while let Some(line) = stream::TryStreamExt::try_next(&mut lines).await? {
+      if line.is_empty() {
+        if let Some(data) = data.take() {
+          return Ok(Some((json::from_str(&data)?, lines)));
+        }
+
+        continue;
+      }
+
+      if let Some(value) = line.strip_prefix("data:") {
+        let value = value.strip_prefix(' ').unwrap_or(value);
+
+        match &mut data {
+          Some(data) => {
+            data.push('\n');
+            data.push_str(value);
+          }
+          None => data = Some(value.to_string()),
+        }
+      }
+    }
+
+    match data {
+      Some(data) => Ok(Some((json::from_str(&data)?, lines))),
+      None => Ok(None),
+    }
+  })
+}
@@ -0,0 +1,116 @@
+use crate::reproto;
+use futures::stream;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NotFound {
+  pub message: String,
+}
+
+#[allow(non_camel_case_types)]
+pub struct MyService_Reqwest {
+  client: reqwest::Client,
+  url: reqwest::Url,
+}
+
+impl MyService_Reqwest {
+  pub fn new(client: reqwest::Client, url: Option<reqwest::Url>) -> reproto::Result<Self> {
+    let url = match url {
+      Some(url) => url,
+      None => reqwest::Url::parse("http://example.com")?,
+    };
+
+    Ok(Self { client, url })
+  }
+
+  /// SERVER_SENT_EVENTS
+  pub async fn events(&self, id: u32) -> reproto::Result<impl stream::Stream<Item = reproto::Result<Entry>>, MyService_Events_Error> {
+    use std::fmt::Write as _;
+
+    let mut path_ = String::new();
+
+    path_.push_str("/");
+    path_.push_str("events");
+    path_.push_str("/");
+    write!(path_, "{}", reproto::PathEncode(id))?;
+
+    let url_ = self.url.join(&path_)?;
+
+    let req_ = self.client
+      .request(reqwest::Method::GET, url_);
+
+    let res_ = req_.send().await?;
+
+    match res_.status().as_u16() {
+      404 => return Err(MyService_Events_Error::Status404(res_.json().await?)),
+      _ => {}
+    }
+
+    let res_ = res_.error_for_status()?;
+    Ok(reproto::sse(res_))
+  }
+
+  /// NDJSON
+  pub async fn entries(&self, request: Entry) -> reproto::Result<impl stream::Stream<Item = reproto::Result<Entry>>> {
+    let mut path_ = String::new();
+
+    path_.push_str("/");
+    path_.push_str("entries");
+
+    let url_ = self.url.join(&path_)?;
+
+    let req_ = self.client
+      .request(reqwest::Method::POST, url_)
+      .json(&request);
+
+    let res_ = req_.send().await?.error_for_status()?;
+    Ok(reproto::ndjson(res_))
+  }
+}
+
+/// Errors returned by `MyService_Reqwest::events`.
+#[derive(Debug)]
+#[allow(non_camel_case_types)]
+pub enum MyService_Events_Error {
+  /// Error response with status `404`.
+  Status404(NotFound),
+  /// Any other error, including unexpected responses.
+  Other(reproto::Error),
+}
+
+impl From<reproto::Error> for MyService_Events_Error {
+  fn from(value: reproto::Error) -> Self {
+    MyService_Events_Error::Other(value)
+  }
+}
+
+impl From<reqwest::Error> for MyService_Events_Error {
+  fn from(value: reqwest::Error) -> Self {
+    MyService_Events_Error::Other(value.into())
+  }
+}
+
+impl From<url::ParseError> for MyService_Events_Error {
+  fn from(value: url::ParseError) -> Self {
+    MyService_Events_Error::Other(value.into())
+  }
+}
+
+impl From<fmt::Error> for MyService_Events_Error {
+  fn from(value: fmt::Error) -> Self {
+    MyService_Events_Error::Other(value.into())
+  }
+}
+
+impl From<serde_json::Error> for MyService_Events_Error {
+  fn from(value: serde_json::Error) -> Self {
+    MyService_Events_Error::Other(value.into())
+  }
+}
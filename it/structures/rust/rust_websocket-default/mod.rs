@@ -0,0 +1,2 @@
+pub mod reproto_websocket;
+pub mod test;
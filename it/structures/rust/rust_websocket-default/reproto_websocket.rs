@@ -0,0 +1,138 @@
+use futures::sink;
+use futures::stream;
+use serde::de;
+use serde_json as json;
+use std::fmt;
+use std::marker;
+use std::pin;
+use std::result;
+use tokio_tungstenite::tungstenite;
+
+#[derive(Debug)]
+pub enum Error {
+  WebSocketError(tungstenite::Error),
+  UrlParseError(url::ParseError),
+  JsonError(serde_json::Error)
+}
+
+pub type Result<T, E = Error> = result::Result<T, E>;
+
+impl From<tungstenite::Error> for Error {
+  fn from(value: tungstenite::Error) -> Self {
+    Error::WebSocketError(value)
+  }
+}
+
+impl From<url::ParseError> for Error {
+  fn from(value: url::ParseError) -> Self {
+    Error::UrlParseError(value)
+  }
+}
+
+impl From<serde_json::Error> for Error {
+  fn from(value: serde_json::Error) -> Self {
+    Error::JsonError(value)
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Error::WebSocketError(e) => e.fmt(fmt),
+      Error::UrlParseError(e) => e.fmt(fmt),
+      Error::JsonError(e) => e.fmt(fmt),
+    }
+  }
+}
+
+type WebSocketSink = pin::Pin<Box<dyn sink::Sink<tungstenite::Message, Error = tungstenite::Error> + Send>>;
+type WebSocketStream = pin::Pin<Box<dyn stream::Stream<Item = Result<tungstenite::Message, tungstenite::Error>> + Send>>;
+
+/// Connect to a WebSocket, returning the halves which send and receive the messages of an endpoint.
+pub async fn connect<T, R>(url: url::Url, endpoint: &'static str) -> Result<(Sender<T>, Receiver<R>)> {
+  let (socket, _) = tokio_tungstenite::connect_async(url).await?;
+  let (sink, stream) = stream::StreamExt::split(socket);
+
+  let sender = Sender {
+    sink: Box::pin(sink),
+    endpoint,
+    marker: marker::PhantomData,
+  };
+
+  let receiver = Receiver {
+    stream: Box::pin(stream),
+    endpoint,
+    marker: marker::PhantomData,
+  };
+
+  Ok((sender, receiver))
+}
+
+/// The half of a bi-directional endpoint which sends requests.
+pub struct Sender<T> {
+  sink: WebSocketSink,
+  endpoint: &'static str,
+  marker: marker::PhantomData<fn(T)>,
+}
+
+impl<T> Sender<T>
+where
+  T: serde::Serialize,
+{
+  /// Send a request.
+  pub async fn send(&mut self, value: &T) -> Result<()> {
+    let mut envelope = json::Map::new();
+    envelope.insert(String::from("endpoint"), json::Value::from(self.endpoint));
+    envelope.insert(String::from("body"), json::to_value(value)?);
+
+    let text = json::to_string(&envelope)?;
+    sink::SinkExt::send(&mut self.sink, tungstenite::Message::Text(text)).await?;
+    Ok(())
+  }
+
+  /// Close the connection, after which no more requests can be sent.
+  pub async fn close(&mut self) -> Result<()> {
+    sink::SinkExt::close(&mut self.sink).await?;
+    Ok(())
+  }
+}
+
+/// The half of a bi-directional endpoint which receives responses.
+pub struct Receiver<T> {
+  stream: WebSocketStream,
+  endpoint: &'static str,
+  marker: marker::PhantomData<fn() -> T>,
+}
+
+impl<T> Receiver<T>
+where
+  T: de::DeserializeOwned,
+{
+  /// Receive the next response, or `None` once the connection has been closed.
+  ///
+  /// Messages for other endpoints are ignored.
+  pub async fn recv(&mut self) -> Result<Option<T>> {
+    while let Some(message) = stream::StreamExt::next(&mut self.stream).await {
+      let text = match message? {
+        tungstenite::Message::Text(text) => text,
+        tungstenite::Message::Close(..) => break,
+        _ => continue,
+      };
+
+      let mut envelope = match json::from_str(&text)? {
+        json::Value::Object(envelope) => envelope,
+        _ => continue,
+      };
+
+      if envelope.get("endpoint").and_then(|e| e.as_str()) != Some(self.endpoint) {
+        continue;
+      }
+
+      if let Some(body) = envelope.remove("body") {
+        return Ok(Some(json::from_value(body)?));
+      }
+    }
+
+    Ok(None)
+  }
+}
@@ -0,0 +1,32 @@
+use crate::reproto_websocket;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+  pub text: String,
+}
+
+#[allow(non_camel_case_types)]
+pub struct MyService_WebSocket {
+  url: url::Url,
+}
+
+impl MyService_WebSocket {
+  pub fn new(url: Option<url::Url>) -> reproto_websocket::Result<Self> {
+    let url = match url {
+      Some(url) => url,
+      None => url::Url::parse("ws://example.com")?,
+    };
+
+    Ok(Self { url })
+  }
+
+  /// BIDI_STREAMING
+  pub async fn chat(&self) -> reproto_websocket::Result<(reproto_websocket::Sender<Message>, reproto_websocket::Receiver<Message>)> {
+    let url_ = self.url.join("/chat")?;
+    reproto_websocket::connect(url_, "chat").await
+  }
+}
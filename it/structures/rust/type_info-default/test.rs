@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub adjacent: Option<Adjacent>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub adjacent_default: Option<AdjacentDefault>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub external: Option<External>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value")]
+pub enum Adjacent {
+  #[serde(rename = "foo")]
+  A(Adjacent_A),
+
+  B(Adjacent_B),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Adjacent_A {
+  pub shared: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Adjacent_B {
+  pub shared: String,
+
+  pub b: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum AdjacentDefault {
+  A(AdjacentDefault_A),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct AdjacentDefault_A {}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum External {
+  #[serde(rename = "foo")]
+  A(External_A),
+
+  B(External_B),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct External_A {
+  pub shared: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct External_B {
+  pub shared: String,
+
+  pub b: String,
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+  pub x: f64,
+
+  pub y: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  /// Either a name or a numeric identifier.
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub value: Option<Entry_Value>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub shape: Option<Entry_Shape>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub flag: Option<Entry_Flag>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Entry_Value {
+  String(String),
+  U64(u64),
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Entry_Shape {
+  Point(Point),
+  DoubleArray(Vec<f64>),
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Entry_Flag {
+  Boolean(bool),
+  String(String),
+}
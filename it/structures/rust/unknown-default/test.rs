@@ -0,0 +1,288 @@
+use serde::{Deserialize, Serialize};
+use serde::de;
+use serde::ser;
+use serde_json as json;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub color: Option<Color>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub priority: Option<Priority>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub tagged: Option<Tagged>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub adjacent: Option<Adjacent>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub external: Option<External>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+  Red,
+  Green,
+  /// A color which is not known to this version of the schema.
+  Other(String),
+}
+
+impl Color {
+  pub fn value(&self) -> &str {
+    match self {
+      Self::Red => "red",
+      Self::Green => "green",
+      Self::Other(value) => value.as_str(),
+    }
+  }
+}
+
+impl serde::Serialize for Color {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    s.serialize_str(self.value())
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for Color {
+  fn deserialize<D>(d: D) -> Result<Color, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    let value = <String as serde::Deserialize<'de>>::deserialize(d)?;
+
+    Ok(match value.as_str() {
+      "red" => Color::Red,
+      "green" => Color::Green,
+      _ => Color::Other(value),
+    })
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Priority {
+  Low,
+  High,
+  Other(u32),
+}
+
+impl Priority {
+  pub fn value(&self) -> u32 {
+    match self {
+      Self::Low => 1,
+      Self::High => 2,
+      Self::Other(value) => *value,
+    }
+  }
+}
+
+impl serde::Serialize for Priority {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    let o = match self {
+      Priority::Low => 1u32,
+      Priority::High => 2u32,
+      Priority::Other(value) => *value,
+    };
+
+    s.serialize_u32(o)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for Priority {
+  fn deserialize<D>(d: D) -> Result<Priority, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    struct Visitor;
+
+    impl<'de> de::Visitor<'de> for Visitor {
+      type Value = Priority;
+
+      fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("Priority, one of: 1, 2")
+      }
+
+      fn visit_u32<E>(self, value: u32) -> Result<Priority, E>
+        where E: de::Error
+      {
+        match value {
+          1u32 => Ok(Priority::Low),
+          2u32 => Ok(Priority::High),
+          value => Ok(Priority::Other(value)),
+        }
+      }
+
+      fn visit_u64<E>(self, value: u64) -> Result<Priority, E>
+        where E: de::Error
+      {
+        self.visit_u32(value as u32)
+      }
+    }
+
+    d.deserialize_u32(Visitor)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tagged {
+  A(Tagged_A),
+
+  B(Tagged_B),
+
+  /// A sub-type which is not known to this version of the schema.
+  Other(json::Value),
+}
+
+impl serde::Serialize for Tagged {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    let (tag, value) = match self {
+      Tagged::A(value) => ("foo", json::to_value(value)),
+      Tagged::B(value) => ("B", json::to_value(value)),
+      Tagged::Other(value) => return serde::Serialize::serialize(value, s),
+    };
+
+    let mut value = value.map_err(ser::Error::custom)?;
+
+    if let json::Value::Object(object) = &mut value {
+      object.insert(String::from("type"), json::Value::from(tag));
+    }
+
+    serde::Serialize::serialize(&value, s)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for Tagged {
+  fn deserialize<D>(d: D) -> Result<Tagged, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    let value = <json::Value as serde::Deserialize<'de>>::deserialize(d)?;
+
+    let result = match value.get("type").and_then(json::Value::as_str) {
+      Some("foo") => json::from_value(value).map(Tagged::A),
+      Some("B") => json::from_value(value).map(Tagged::B),
+      Some(_) => return Ok(Tagged::Other(value)),
+      None => return Err(de::Error::missing_field("type")),
+    };
+
+    result.map_err(de::Error::custom)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Tagged_A {
+  pub shared: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Tagged_B {
+  pub shared: String,
+
+  pub b: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Adjacent {
+  A(Adjacent_A),
+
+  Other(json::Value),
+}
+
+impl serde::Serialize for Adjacent {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    let (tag, value) = match self {
+      Adjacent::A(value) => ("A", json::to_value(value)),
+      Adjacent::Other(value) => return serde::Serialize::serialize(value, s),
+    };
+
+    let value = value.map_err(ser::Error::custom)?;
+
+    let mut object = json::Map::new();
+    object.insert(String::from("type"), json::Value::from(tag));
+    object.insert(String::from("data"), value);
+    serde::Serialize::serialize(&object, s)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for Adjacent {
+  fn deserialize<D>(d: D) -> Result<Adjacent, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    let value = <json::Value as serde::Deserialize<'de>>::deserialize(d)?;
+
+    let result = match value.get("type").and_then(json::Value::as_str) {
+      Some("A") => json::from_value(value["data"].clone()).map(Adjacent::A),
+      Some(_) => return Ok(Adjacent::Other(value)),
+      None => return Err(de::Error::missing_field("type")),
+    };
+
+    result.map_err(de::Error::custom)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct Adjacent_A {}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum External {
+  A(External_A),
+
+  Other(json::Value),
+}
+
+impl serde::Serialize for External {
+  fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer
+  {
+    let (tag, value) = match self {
+      External::A(value) => ("A", json::to_value(value)),
+      External::Other(value) => return serde::Serialize::serialize(value, s),
+    };
+
+    let value = value.map_err(ser::Error::custom)?;
+
+    let mut object = json::Map::new();
+    object.insert(String::from(tag), value);
+    serde::Serialize::serialize(&object, s)
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for External {
+  fn deserialize<D>(d: D) -> Result<External, D::Error>
+  where
+    D: serde::Deserializer<'de>
+  {
+    let value = <json::Value as serde::Deserialize<'de>>::deserialize(d)?;
+
+    let result = match value.as_object().and_then(|o| o.keys().next()).map(String::as_str) {
+      Some("A") => json::from_value(value["A"].clone()).map(External::A),
+      Some(_) => return Ok(External::Other(value)),
+      None => return Err(de::Error::custom("expected an object with a single key")),
+    };
+
+    result.map_err(de::Error::custom)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+pub struct External_A {}
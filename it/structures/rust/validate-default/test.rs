@@ -0,0 +1,65 @@
+use once_cell::sync;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub name: Option<String>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub code: Option<String>,
+
+  pub label: String,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub count: Option<u32>,
+
+  #[serde(skip_serializing_if="Option::is_none")]
+  pub offset: Option<i64>,
+}
+
+impl Entry {
+  /// Check that the fields of `Entry` satisfy their validation rules.
+  pub fn validate(&self) -> Result<(), String> {
+    if let Some(value) = &self.name {
+      if value.chars().count() > 16 {
+        return Err("Entry.name: length must be at most 16".to_string());
+      }
+      static NAME_PATTERN: sync::Lazy<regex::Regex> = sync::Lazy::new(|| regex::Regex::new("^(?:[a-z]+)$").expect("bad pattern"));
+      if !NAME_PATTERN.is_match(value) {
+        return Err("Entry.name: must match pattern `[a-z]+`".to_string());
+      }
+    }
+
+    if let Some(value) = &self.code {
+      if value.chars().count() < 2 {
+        return Err("Entry.code: length must be at least 2".to_string());
+      }
+    }
+
+    let value = &self.label;
+    if value.chars().count() < 1 {
+      return Err("Entry.label: length must be at least 1".to_string());
+    }
+
+    if let Some(value) = &self.count {
+      if *value < 1 {
+        return Err("Entry.count: must be at least 1".to_string());
+      }
+      if *value > 100 {
+        return Err("Entry.count: must be at most 100".to_string());
+      }
+    }
+
+    if let Some(value) = &self.offset {
+      if *value <= -10 {
+        return Err("Entry.offset: must be greater than -10".to_string());
+      }
+      if *value >= 10 {
+        return Err("Entry.offset: must be less than 10".to_string());
+      }
+    }
+
+    Ok(())
+  }
+}
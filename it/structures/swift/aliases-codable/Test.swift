@@ -0,0 +1,13 @@
+public struct Test_Entry: Codable {
+  let id: UInt64?
+  let user: String?
+  let tags: [String]?
+  let owners: [String: UInt64]?
+
+  enum CodingKeys: String, CodingKey {
+    case id = "id"
+    case user = "user"
+    case tags = "tags"
+    case owners = "owners"
+  }
+}
@@ -0,0 +1,59 @@
+public struct Test_Entry {
+  let id: UInt64?
+  let user: String?
+  let tags: [String]?
+  let owners: [String: UInt64]?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var id: UInt64? = Optional.none
+
+    if let value = json["id"] {
+      id = Optional.some(try decode_name(unbox(value, as: UInt64.self), name: "id"))
+    }
+
+    var user: String? = Optional.none
+
+    if let value = json["user"] {
+      user = Optional.some(try decode_name(unbox(value, as: String.self), name: "user"))
+    }
+
+    var tags: [String]? = Optional.none
+
+    if let value = json["tags"] {
+      tags = Optional.some(try decode_array(value, name: "tags", inner: { inner in try decode_name(unbox(inner, as: String.self), name: "tags") }))
+    }
+
+    var owners: [String: UInt64]? = Optional.none
+
+    if let value = json["owners"] {
+      owners = Optional.some(try decode_map(value, name: "owners", value: { value in try decode_name(unbox(value, as: UInt64.self), name: "owners") }))
+    }
+    return Test_Entry(id: id, user: user, tags: tags, owners: owners)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.id {
+      json["id"] = value
+    }
+
+    if let value = self.user {
+      json["user"] = value
+    }
+
+    if let value = self.tags {
+      json["tags"] = try encode_array(value, name: "tags", inner: { inner in inner })
+    }
+
+    if let value = self.owners {
+      json["owners"] = try encode_map(value, name: "owners", value: { value in value })
+    }
+
+    return json
+  }
+}
@@ -0,0 +1,17 @@
+public struct Test_Entry: Codable {
+  let date: String?
+  let time: String?
+  let duration: String?
+  let uuid: String?
+  let decimal: String?
+  let dates: [String]?
+
+  enum CodingKeys: String, CodingKey {
+    case date = "date"
+    case time = "time"
+    case duration = "duration"
+    case uuid = "uuid"
+    case decimal = "decimal"
+    case dates = "dates"
+  }
+}
@@ -0,0 +1,81 @@
+public struct Test_Entry {
+  let date: String?
+  let time: String?
+  let duration: String?
+  let uuid: String?
+  let decimal: String?
+  let dates: [String]?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var date: String? = Optional.none
+
+    if let value = json["date"] {
+      date = Optional.some(try decode_name(unbox(value, as: String.self), name: "date"))
+    }
+
+    var time: String? = Optional.none
+
+    if let value = json["time"] {
+      time = Optional.some(try decode_name(unbox(value, as: String.self), name: "time"))
+    }
+
+    var duration: String? = Optional.none
+
+    if let value = json["duration"] {
+      duration = Optional.some(try decode_name(unbox(value, as: String.self), name: "duration"))
+    }
+
+    var uuid: String? = Optional.none
+
+    if let value = json["uuid"] {
+      uuid = Optional.some(try decode_name(unbox(value, as: String.self), name: "uuid"))
+    }
+
+    var decimal: String? = Optional.none
+
+    if let value = json["decimal"] {
+      decimal = Optional.some(try decode_name(unbox(value, as: String.self), name: "decimal"))
+    }
+
+    var dates: [String]? = Optional.none
+
+    if let value = json["dates"] {
+      dates = Optional.some(try decode_array(value, name: "dates", inner: { inner in try decode_name(unbox(inner, as: String.self), name: "dates") }))
+    }
+    return Test_Entry(date: date, time: time, duration: duration, uuid: uuid, decimal: decimal, dates: dates)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.date {
+      json["date"] = value
+    }
+
+    if let value = self.time {
+      json["time"] = value
+    }
+
+    if let value = self.duration {
+      json["duration"] = value
+    }
+
+    if let value = self.uuid {
+      json["uuid"] = value
+    }
+
+    if let value = self.decimal {
+      json["decimal"] = value
+    }
+
+    if let value = self.dates {
+      json["dates"] = try encode_array(value, name: "dates", inner: { inner in inner })
+    }
+
+    return json
+  }
+}
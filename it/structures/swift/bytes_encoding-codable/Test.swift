@@ -0,0 +1,92 @@
+import Foundation
+
+public struct Test_Entry: Codable {
+  let standard: Data?
+  let url: Data?
+  let token: Data?
+  let digest: Data?
+  let pair: Test_Pair?
+
+  enum CodingKeys: String, CodingKey {
+    case standard = "standard"
+    case url = "url"
+    case token = "token"
+    case digest = "digest"
+    case pair = "pair"
+  }
+}
+
+public extension Test_Entry {
+  init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    self.standard = try values.decodeIfPresent(Data.self, forKey: .standard)
+    if let value = try values.decodeIfPresent(String.self, forKey: .url) {
+      guard let value = decode_bytes(value, encoding: "base64url") else {
+        throw DecodingError.dataCorruptedError(forKey: .url, in: values, debugDescription: "expected bytes encoded as base64url")
+      }
+      self.url = value
+    } else {
+      self.url = nil
+    }
+    if let value = try values.decodeIfPresent(String.self, forKey: .token) {
+      guard let value = decode_bytes(value, encoding: "base64url_nopad") else {
+        throw DecodingError.dataCorruptedError(forKey: .token, in: values, debugDescription: "expected bytes encoded as base64url_nopad")
+      }
+      self.token = value
+    } else {
+      self.token = nil
+    }
+    if let value = try values.decodeIfPresent(String.self, forKey: .digest) {
+      guard let value = decode_bytes(value, encoding: "hex") else {
+        throw DecodingError.dataCorruptedError(forKey: .digest, in: values, debugDescription: "expected bytes encoded as hex")
+      }
+      self.digest = value
+    } else {
+      self.digest = nil
+    }
+    self.pair = try values.decodeIfPresent(Test_Pair.self, forKey: .pair)
+  }
+
+  func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    try values.encodeIfPresent(self.standard, forKey: .standard)
+    try values.encodeIfPresent(self.url.map { encode_bytes($0, encoding: "base64url") }, forKey: .url)
+    try values.encodeIfPresent(self.token.map { encode_bytes($0, encoding: "base64url_nopad") }, forKey: .token)
+    try values.encodeIfPresent(self.digest.map { encode_bytes($0, encoding: "hex") }, forKey: .digest)
+    try values.encodeIfPresent(self.pair, forKey: .pair)
+  }
+}
+
+public struct Test_Pair {
+  let key: Data
+  let value: Data
+
+  enum CodingKeys: String, CodingKey {
+    case key = "key"
+    case value = "value"
+  }
+}
+
+extension Test_Pair: Decodable {
+  public init(from decoder: Decoder) throws {
+    var values = try decoder.unkeyedContainer()
+
+    let value = try values.decode(String.self)
+    guard let value = decode_bytes(value, encoding: "hex") else {
+      throw DecodingError.dataCorruptedError(in: values, debugDescription: "expected bytes encoded as hex")
+    }
+    self.key = value
+    self.value = try values.decode(Data.self)
+  }
+}
+
+extension Test_Pair: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.unkeyedContainer()
+
+    try values.encode(encode_bytes(self.key, encoding: "hex"))
+    try values.encode(self.value)
+  }
+}
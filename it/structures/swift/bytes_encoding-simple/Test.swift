@@ -0,0 +1,106 @@
+import Foundation
+
+public struct Test_Entry {
+  let standard: Data?
+  let url: Data?
+  let token: Data?
+  let digest: Data?
+  let pair: Test_Pair?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var standard: Data? = Optional.none
+
+    if let value = json["standard"] {
+      standard = Optional.some(try decode_name(Data(base64Encoded: try decode_value(value as? String)), name: "standard"))
+    }
+
+    var url: Data? = Optional.none
+
+    if let value = json["url"] {
+      url = Optional.some(try decode_name(decode_bytes(try decode_value(value as? String), encoding: "base64url"), name: "url"))
+    }
+
+    var token: Data? = Optional.none
+
+    if let value = json["token"] {
+      token = Optional.some(try decode_name(decode_bytes(try decode_value(value as? String), encoding: "base64url_nopad"), name: "token"))
+    }
+
+    var digest: Data? = Optional.none
+
+    if let value = json["digest"] {
+      digest = Optional.some(try decode_name(decode_bytes(try decode_value(value as? String), encoding: "hex"), name: "digest"))
+    }
+
+    var pair: Test_Pair? = Optional.none
+
+    if let value = json["pair"] {
+      pair = Optional.some(try Test_Pair.decode(json: value))
+    }
+    return Test_Entry(standard: standard, url: url, token: token, digest: digest, pair: pair)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.standard {
+      json["standard"] = value.base64EncodedString()
+    }
+
+    if let value = self.url {
+      json["url"] = encode_bytes(value, encoding: "base64url")
+    }
+
+    if let value = self.token {
+      json["token"] = encode_bytes(value, encoding: "base64url_nopad")
+    }
+
+    if let value = self.digest {
+      json["digest"] = encode_bytes(value, encoding: "hex")
+    }
+
+    if let value = self.pair {
+      json["pair"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public struct Test_Pair {
+  let key: Data
+  let value: Data
+}
+
+public extension Test_Pair {
+  static func decode(json: Any) throws -> Test_Pair {
+    let json = try decode_value(json as? [Any])
+
+    guard let f_key = Optional.some(json[0]) else {
+      throw SerializationError.missing("[0]")
+    }
+
+    let key = try decode_name(decode_bytes(try decode_value(f_key as? String), encoding: "hex"), name: "[0]")
+
+    guard let f_value = Optional.some(json[1]) else {
+      throw SerializationError.missing("[1]")
+    }
+
+    let value = try decode_name(Data(base64Encoded: try decode_value(f_value as? String)), name: "[1]")
+
+    return Test_Pair(key: key, value: value)
+  }
+
+  func encode() throws -> [Any] {
+    var json = [Any]()
+
+    json.append(encode_bytes(self.key, encoding: "hex"))
+    json.append(self.value.base64EncodedString())
+
+    return json
+  }
+}
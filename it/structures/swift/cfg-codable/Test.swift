@@ -0,0 +1,45 @@
+public struct Test_Entry: Codable {
+  let id: String?
+  // Not available when building for Python or JavaScript.
+  let typed: UInt32?
+  let color: Test_Color?
+
+  enum CodingKeys: String, CodingKey {
+    case id = "id"
+    case typed = "typed"
+    case color = "color"
+  }
+}
+
+public enum Test_Color {
+  case Red
+}
+
+extension Test_Color: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    switch try value.decode(String.self) {
+    case "red":
+      self = .Red
+    default:
+      let context = DecodingError.Context(
+        codingPath: decoder.codingPath,
+        debugDescription: "enum variant"
+      )
+
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Color: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .Red:
+      try value.encode("red")
+    }
+  }
+}
@@ -0,0 +1,74 @@
+public struct Test_Entry {
+  let id: String?
+  // Not available when building for Python or JavaScript.
+  let typed: UInt32?
+  let color: Test_Color?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var id: String? = Optional.none
+
+    if let value = json["id"] {
+      id = Optional.some(try decode_name(unbox(value, as: String.self), name: "id"))
+    }
+
+    var typed: UInt32? = Optional.none
+
+    if let value = json["typed"] {
+      typed = Optional.some(try decode_name(unbox(value, as: UInt32.self), name: "typed"))
+    }
+
+    var color: Test_Color? = Optional.none
+
+    if let value = json["color"] {
+      color = Optional.some(try Test_Color.decode(json: value))
+    }
+    return Test_Entry(id: id, typed: typed, color: color)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.id {
+      json["id"] = value
+    }
+
+    if let value = self.typed {
+      json["typed"] = value
+    }
+
+    if let value = self.color {
+      json["color"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public enum Test_Color {
+  case Red
+}
+
+public extension Test_Color {
+  static func decode(json: Any) throws -> Test_Color {
+    let json = try decode_value(json)
+    let value = try decode_value(unbox(json, as: String.self))
+
+    switch value {
+    case "red":
+      return Test_Color.Red
+    default:
+      throw SerializationError.bad_value
+    }
+  }
+
+  func encode() throws -> String {
+    switch self {
+    case .Red:
+      return "red"
+    }
+  }
+}
@@ -0,0 +1,89 @@
+public struct Test_Entry: Codable {
+  let by_color: [String: UInt32]?
+  let by_id: [String: String]?
+  let tags: [String]?
+  let levels: [Test_Level]?
+
+  enum CodingKeys: String, CodingKey {
+    case by_color = "by_color"
+    case by_id = "by_id"
+    case tags = "tags"
+    case levels = "levels"
+  }
+}
+
+public enum Test_Color {
+  case Red
+  case Green
+}
+
+extension Test_Color: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    switch try value.decode(String.self) {
+    case "red":
+      self = .Red
+    case "green":
+      self = .Green
+    default:
+      let context = DecodingError.Context(
+        codingPath: decoder.codingPath,
+        debugDescription: "enum variant"
+      )
+
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Color: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .Red:
+      try value.encode("red")
+    case .Green:
+      try value.encode("green")
+    }
+  }
+}
+
+public enum Test_Level {
+  case Low
+  case High
+}
+
+extension Test_Level: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    switch try value.decode(UInt32.self) {
+    case 1:
+      self = .Low
+    case 2:
+      self = .High
+    default:
+      let context = DecodingError.Context(
+        codingPath: decoder.codingPath,
+        debugDescription: "enum variant"
+      )
+
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Level: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .Low:
+      try value.encode(1)
+    case .High:
+      try value.encode(2)
+    }
+  }
+}
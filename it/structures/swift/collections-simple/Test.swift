@@ -0,0 +1,119 @@
+public struct Test_Entry {
+  let by_color: [String: UInt32]?
+  let by_id: [String: String]?
+  let tags: [String]?
+  let levels: [Test_Level]?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var by_color: [String: UInt32]? = Optional.none
+
+    if let value = json["by_color"] {
+      by_color = Optional.some(try decode_map(value, name: "by_color", value: { value in try decode_name(unbox(value, as: UInt32.self), name: "by_color") }))
+    }
+
+    var by_id: [String: String]? = Optional.none
+
+    if let value = json["by_id"] {
+      by_id = Optional.some(try decode_map(value, name: "by_id", value: { value in try decode_name(unbox(value, as: String.self), name: "by_id") }))
+    }
+
+    var tags: [String]? = Optional.none
+
+    if let value = json["tags"] {
+      tags = Optional.some(try decode_array(value, name: "tags", inner: { inner in try decode_name(unbox(inner, as: String.self), name: "tags") }))
+    }
+
+    var levels: [Test_Level]? = Optional.none
+
+    if let value = json["levels"] {
+      levels = Optional.some(try decode_array(value, name: "levels", inner: { inner in try Test_Level.decode(json: inner) }))
+    }
+    return Test_Entry(by_color: by_color, by_id: by_id, tags: tags, levels: levels)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.by_color {
+      json["by_color"] = try encode_map(value, name: "by_color", value: { value in value })
+    }
+
+    if let value = self.by_id {
+      json["by_id"] = try encode_map(value, name: "by_id", value: { value in value })
+    }
+
+    if let value = self.tags {
+      json["tags"] = try encode_array(value, name: "tags", inner: { inner in inner })
+    }
+
+    if let value = self.levels {
+      json["levels"] = try encode_array(value, name: "levels", inner: { inner in try inner.encode() })
+    }
+
+    return json
+  }
+}
+
+public enum Test_Color {
+  case Red
+  case Green
+}
+
+public extension Test_Color {
+  static func decode(json: Any) throws -> Test_Color {
+    let json = try decode_value(json)
+    let value = try decode_value(unbox(json, as: String.self))
+
+    switch value {
+    case "red":
+      return Test_Color.Red
+    case "green":
+      return Test_Color.Green
+    default:
+      throw SerializationError.bad_value
+    }
+  }
+
+  func encode() throws -> String {
+    switch self {
+    case .Red:
+      return "red"
+    case .Green:
+      return "green"
+    }
+  }
+}
+
+public enum Test_Level {
+  case Low
+  case High
+}
+
+public extension Test_Level {
+  static func decode(json: Any) throws -> Test_Level {
+    let json = try decode_value(json)
+    let value = try decode_value(unbox(json, as: UInt32.self))
+
+    switch value {
+    case 1:
+      return Test_Level.Low
+    case 2:
+      return Test_Level.High
+    default:
+      throw SerializationError.bad_value
+    }
+  }
+
+  func encode() throws -> UInt32 {
+    switch self {
+    case .Low:
+      return 1
+    case .High:
+      return 2
+    }
+  }
+}
@@ -0,0 +1,26 @@
+// The default number of entries to return.
+public let Test_DefaultLimit: UInt32 = 100
+
+public let Test_MaxOffset: Int64 = -1
+
+public let Test_Greeting: String = "hello"
+
+public let Test_Ratio: Double = 0.5
+
+public let Test_Scale: Float = 2
+
+public let Test_Enabled: Bool = true
+
+@available(*, deprecated, message: "deprecated: use DefaultLimit")
+public let Test_OldLimit: UInt32 = 10
+
+public struct Test_Entry: Codable {
+  let limit: UInt32?
+
+  enum CodingKeys: String, CodingKey {
+    case limit = "limit"
+  }
+}
+
+// Maximum number of entries in a single page.
+public let Test_Entry_PageSize: UInt32 = 20
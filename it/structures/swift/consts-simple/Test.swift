@@ -0,0 +1,45 @@
+// The default number of entries to return.
+public let Test_DefaultLimit: UInt32 = 100
+
+public let Test_MaxOffset: Int64 = -1
+
+public let Test_Greeting: String = "hello"
+
+public let Test_Ratio: Double = 0.5
+
+public let Test_Scale: Float = 2
+
+public let Test_Enabled: Bool = true
+
+@available(*, deprecated, message: "deprecated: use DefaultLimit")
+public let Test_OldLimit: UInt32 = 10
+
+public struct Test_Entry {
+  let limit: UInt32?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var limit: UInt32? = Optional.none
+
+    if let value = json["limit"] {
+      limit = Optional.some(try decode_name(unbox(value, as: UInt32.self), name: "limit"))
+    }
+    return Test_Entry(limit: limit)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.limit {
+      json["limit"] = value
+    }
+
+    return json
+  }
+}
+
+// Maximum number of entries in a single page.
+public let Test_Entry_PageSize: UInt32 = 20
@@ -0,0 +1,63 @@
+public struct Test_Entry: Codable {
+  let name: String?
+  let count: UInt32?
+  let big: Int64?
+  let ratio: Double?
+  let scale: Float?
+  let enabled: Bool?
+  let label: String?
+  let shape: Test_Shape?
+
+  enum CodingKeys: String, CodingKey {
+    case name = "name"
+    case count = "count"
+    case big = "big"
+    case ratio = "ratio"
+    case scale = "scale"
+    case enabled = "enabled"
+    case label = "label"
+    case shape = "shape"
+  }
+}
+
+public enum Test_Shape {
+  case Circle(Test_Shape_Circle)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "type"
+  }
+}
+
+extension Test_Shape: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "Circle":
+      self = try .Circle(Test_Shape_Circle(from: decoder))
+    default:
+      let context = DecodingError.Context(codingPath: [], debugDescription: "type")
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Shape: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .Circle(let d):
+      try values.encode("Circle", forKey: .tag)
+      try d.encode(to: encoder)
+    }
+  }
+}
+
+public struct Test_Shape_Circle: Codable {
+  let radius: Double?
+
+  enum CodingKeys: String, CodingKey {
+    case radius = "radius"
+  }
+}
@@ -0,0 +1,157 @@
+public struct Test_Entry {
+  let name: String?
+  let count: UInt32?
+  let big: Int64?
+  let ratio: Double?
+  let scale: Float?
+  let enabled: Bool?
+  let label: String?
+  let shape: Test_Shape?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var name: String? = Optional.none
+
+    if let value = json["name"] {
+      name = Optional.some(try decode_name(unbox(value, as: String.self), name: "name"))
+    }
+
+    var count: UInt32? = Optional.none
+
+    if let value = json["count"] {
+      count = Optional.some(try decode_name(unbox(value, as: UInt32.self), name: "count"))
+    }
+
+    var big: Int64? = Optional.none
+
+    if let value = json["big"] {
+      big = Optional.some(try decode_name(unbox(value, as: Int64.self), name: "big"))
+    }
+
+    var ratio: Double? = Optional.none
+
+    if let value = json["ratio"] {
+      ratio = Optional.some(try decode_name(unbox(value, as: Double.self), name: "ratio"))
+    }
+
+    var scale: Float? = Optional.none
+
+    if let value = json["scale"] {
+      scale = Optional.some(try decode_name(unbox(value, as: Float.self), name: "scale"))
+    }
+
+    var enabled: Bool? = Optional.none
+
+    if let value = json["enabled"] {
+      enabled = Optional.some(try decode_name(unbox(value, as: Bool.self), name: "enabled"))
+    }
+
+    var label: String? = Optional.none
+
+    if let value = json["label"] {
+      label = Optional.some(try decode_name(unbox(value, as: String.self), name: "label"))
+    }
+
+    var shape: Test_Shape? = Optional.none
+
+    if let value = json["shape"] {
+      shape = Optional.some(try Test_Shape.decode(json: value))
+    }
+    return Test_Entry(name: name, count: count, big: big, ratio: ratio, scale: scale, enabled: enabled, label: label, shape: shape)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.name {
+      json["name"] = value
+    }
+
+    if let value = self.count {
+      json["count"] = value
+    }
+
+    if let value = self.big {
+      json["big"] = value
+    }
+
+    if let value = self.ratio {
+      json["ratio"] = value
+    }
+
+    if let value = self.scale {
+      json["scale"] = value
+    }
+
+    if let value = self.enabled {
+      json["enabled"] = value
+    }
+
+    if let value = self.label {
+      json["label"] = value
+    }
+
+    if let value = self.shape {
+      json["shape"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public enum Test_Shape {
+  case Circle(Test_Shape_Circle)
+}
+
+public extension Test_Shape {
+  static func decode(json: Any) throws -> Test_Shape {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["type"] as? String, name: "type")
+
+    switch type {
+    case "Circle":
+      let v = try Test_Shape_Circle.decode(json: json)
+      return Test_Shape.Circle(v)
+    default:
+      throw SerializationError.invalid(type)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .Circle(let s):
+      var json = try s.encode()
+      json["type"] = "Circle"
+      return json
+    }
+  }
+}
+
+public struct Test_Shape_Circle {
+  let radius: Double?
+}
+
+public extension Test_Shape_Circle {
+  static func decode(json: Any) throws -> Test_Shape_Circle {
+    let json = try decode_value(json as? [String: Any])
+
+    var radius: Double? = Optional.none
+
+    if let value = json["radius"] {
+      radius = Optional.some(try decode_name(unbox(value, as: Double.self), name: "radius"))
+    }
+    return Test_Shape_Circle(radius: radius)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.radius {
+      json["radius"] = value
+    }
+
+    return json
+  }
+}
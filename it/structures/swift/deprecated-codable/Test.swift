@@ -0,0 +1,159 @@
+public struct Test_Entry: Codable {
+  // Kept for compatibility.
+  @available(*, deprecated, message: "deprecated since 1.1.0: use `current` instead")
+  let legacy: Test_Legacy?
+  let current: Test_Current?
+  let status: Test_Status?
+  let point: Test_Point?
+  let shape: Test_Shape?
+
+  enum CodingKeys: String, CodingKey {
+    case legacy = "legacy"
+    case current = "current"
+    case status = "status"
+    case point = "point"
+    case shape = "shape"
+  }
+}
+
+@available(*, deprecated, message: "deprecated: use `Current` instead")
+public struct Test_Legacy: Codable {
+  let value: String
+
+  enum CodingKeys: String, CodingKey {
+    case value = "value"
+  }
+}
+
+public struct Test_Current: Codable {
+  let value: String
+
+  enum CodingKeys: String, CodingKey {
+    case value = "value"
+  }
+}
+
+public enum Test_Status {
+  case Active
+  @available(*, deprecated)
+  case Retired
+}
+
+extension Test_Status: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    switch try value.decode(String.self) {
+    case "active":
+      self = .Active
+    case "retired":
+      self = .Retired
+    default:
+      let context = DecodingError.Context(
+        codingPath: decoder.codingPath,
+        debugDescription: "enum variant"
+      )
+
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Status: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .Active:
+      try value.encode("active")
+    case .Retired:
+      try value.encode("retired")
+    }
+  }
+}
+
+@available(*, deprecated, message: "deprecated since 1.0.0")
+public struct Test_Point {
+  let x: Int32
+  let y: Int32
+
+  enum CodingKeys: String, CodingKey {
+    case x = "x"
+    case y = "y"
+  }
+}
+
+extension Test_Point: Decodable {
+  public init(from decoder: Decoder) throws {
+    var values = try decoder.unkeyedContainer()
+
+    self.x = try values.decode(Int32.self)
+    self.y = try values.decode(Int32.self)
+  }
+}
+
+extension Test_Point: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.unkeyedContainer()
+
+    try values.encode(self.x)
+    try values.encode(self.y)
+  }
+}
+
+public enum Test_Shape {
+  case Circle(Test_Shape_Circle)
+  case Round(Test_Shape_Round)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "type"
+  }
+}
+
+extension Test_Shape: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "Circle":
+      self = try .Circle(Test_Shape_Circle(from: decoder))
+    case "Round":
+      self = try .Round(Test_Shape_Round(from: decoder))
+    default:
+      let context = DecodingError.Context(codingPath: [], debugDescription: "type")
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Shape: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .Circle(let d):
+      try values.encode("Circle", forKey: .tag)
+      try d.encode(to: encoder)
+    case .Round(let d):
+      try values.encode("Round", forKey: .tag)
+      try d.encode(to: encoder)
+    }
+  }
+}
+
+public struct Test_Shape_Circle: Codable {
+  let radius: Double
+
+  enum CodingKeys: String, CodingKey {
+    case radius = "radius"
+  }
+}
+
+@available(*, deprecated, message: "deprecated: use `Circle` instead")
+public struct Test_Shape_Round: Codable {
+  let radius: Double
+
+  enum CodingKeys: String, CodingKey {
+    case radius = "radius"
+  }
+}
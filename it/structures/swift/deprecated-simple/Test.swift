@@ -0,0 +1,275 @@
+public struct Test_Entry {
+  // Kept for compatibility.
+  @available(*, deprecated, message: "deprecated since 1.1.0: use `current` instead")
+  let legacy: Test_Legacy?
+  let current: Test_Current?
+  let status: Test_Status?
+  let point: Test_Point?
+  let shape: Test_Shape?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var legacy: Test_Legacy? = Optional.none
+
+    if let value = json["legacy"] {
+      legacy = Optional.some(try Test_Legacy.decode(json: value))
+    }
+
+    var current: Test_Current? = Optional.none
+
+    if let value = json["current"] {
+      current = Optional.some(try Test_Current.decode(json: value))
+    }
+
+    var status: Test_Status? = Optional.none
+
+    if let value = json["status"] {
+      status = Optional.some(try Test_Status.decode(json: value))
+    }
+
+    var point: Test_Point? = Optional.none
+
+    if let value = json["point"] {
+      point = Optional.some(try Test_Point.decode(json: value))
+    }
+
+    var shape: Test_Shape? = Optional.none
+
+    if let value = json["shape"] {
+      shape = Optional.some(try Test_Shape.decode(json: value))
+    }
+    return Test_Entry(legacy: legacy, current: current, status: status, point: point, shape: shape)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.legacy {
+      json["legacy"] = try value.encode()
+    }
+
+    if let value = self.current {
+      json["current"] = try value.encode()
+    }
+
+    if let value = self.status {
+      json["status"] = try value.encode()
+    }
+
+    if let value = self.point {
+      json["point"] = try value.encode()
+    }
+
+    if let value = self.shape {
+      json["shape"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+@available(*, deprecated, message: "deprecated: use `Current` instead")
+public struct Test_Legacy {
+  let value: String
+}
+
+public extension Test_Legacy {
+  static func decode(json: Any) throws -> Test_Legacy {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_value = json["value"] else {
+      throw SerializationError.missing("value")
+    }
+
+    let value = try decode_name(unbox(f_value, as: String.self), name: "value")
+    return Test_Legacy(value: value)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["value"] = self.value
+
+    return json
+  }
+}
+
+public struct Test_Current {
+  let value: String
+}
+
+public extension Test_Current {
+  static func decode(json: Any) throws -> Test_Current {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_value = json["value"] else {
+      throw SerializationError.missing("value")
+    }
+
+    let value = try decode_name(unbox(f_value, as: String.self), name: "value")
+    return Test_Current(value: value)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["value"] = self.value
+
+    return json
+  }
+}
+
+public enum Test_Status {
+  case Active
+  @available(*, deprecated)
+  case Retired
+}
+
+public extension Test_Status {
+  static func decode(json: Any) throws -> Test_Status {
+    let json = try decode_value(json)
+    let value = try decode_value(unbox(json, as: String.self))
+
+    switch value {
+    case "active":
+      return Test_Status.Active
+    case "retired":
+      return Test_Status.Retired
+    default:
+      throw SerializationError.bad_value
+    }
+  }
+
+  func encode() throws -> String {
+    switch self {
+    case .Active:
+      return "active"
+    case .Retired:
+      return "retired"
+    }
+  }
+}
+
+@available(*, deprecated, message: "deprecated since 1.0.0")
+public struct Test_Point {
+  let x: Int32
+  let y: Int32
+}
+
+public extension Test_Point {
+  static func decode(json: Any) throws -> Test_Point {
+    let json = try decode_value(json as? [Any])
+
+    guard let f_x = Optional.some(json[0]) else {
+      throw SerializationError.missing("[0]")
+    }
+
+    let x = try decode_name(unbox(f_x, as: Int32.self), name: "[0]")
+
+    guard let f_y = Optional.some(json[1]) else {
+      throw SerializationError.missing("[1]")
+    }
+
+    let y = try decode_name(unbox(f_y, as: Int32.self), name: "[1]")
+
+    return Test_Point(x: x, y: y)
+  }
+
+  func encode() throws -> [Any] {
+    var json = [Any]()
+
+    json.append(self.x)
+    json.append(self.y)
+
+    return json
+  }
+}
+
+public enum Test_Shape {
+  case Circle(Test_Shape_Circle)
+  case Round(Test_Shape_Round)
+}
+
+public extension Test_Shape {
+  static func decode(json: Any) throws -> Test_Shape {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["type"] as? String, name: "type")
+
+    switch type {
+    case "Circle":
+      let v = try Test_Shape_Circle.decode(json: json)
+      return Test_Shape.Circle(v)
+    case "Round":
+      let v = try Test_Shape_Round.decode(json: json)
+      return Test_Shape.Round(v)
+    default:
+      throw SerializationError.invalid(type)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .Circle(let s):
+      var json = try s.encode()
+      json["type"] = "Circle"
+      return json
+    case .Round(let s):
+      var json = try s.encode()
+      json["type"] = "Round"
+      return json
+    }
+  }
+}
+
+public struct Test_Shape_Circle {
+  let radius: Double
+}
+
+public extension Test_Shape_Circle {
+  static func decode(json: Any) throws -> Test_Shape_Circle {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_radius = json["radius"] else {
+      throw SerializationError.missing("radius")
+    }
+
+    let radius = try decode_name(unbox(f_radius, as: Double.self), name: "radius")
+    return Test_Shape_Circle(radius: radius)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["radius"] = self.radius
+
+    return json
+  }
+}
+
+@available(*, deprecated, message: "deprecated: use `Circle` instead")
+public struct Test_Shape_Round {
+  let radius: Double
+}
+
+public extension Test_Shape_Round {
+  static func decode(json: Any) throws -> Test_Shape_Round {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_radius = json["radius"] else {
+      throw SerializationError.missing("radius")
+    }
+
+    let radius = try decode_name(unbox(f_radius, as: Double.self), name: "radius")
+    return Test_Shape_Round(radius: radius)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["radius"] = self.radius
+
+    return json
+  }
+}
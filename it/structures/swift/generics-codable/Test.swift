@@ -0,0 +1,109 @@
+public struct Test_Entry: Codable {
+  let page: Test_PageItem?
+  let names: Test_PageString?
+  let pair: Test_PairItemU32?
+  let outcome: Test_OutcomeItem?
+
+  enum CodingKeys: String, CodingKey {
+    case page = "page"
+    case names = "names"
+    case pair = "pair"
+    case outcome = "outcome"
+  }
+}
+
+public struct Test_Item: Codable {
+  let name: String
+
+  enum CodingKeys: String, CodingKey {
+    case name = "name"
+  }
+}
+
+// A single page of results.
+public struct Test_PageItem: Codable {
+  let items: [Test_Item]
+  let next: String?
+
+  enum CodingKeys: String, CodingKey {
+    case items = "items"
+    case next = "next"
+  }
+}
+
+// A single page of results.
+public struct Test_PageString: Codable {
+  let items: [String]
+  let next: String?
+
+  enum CodingKeys: String, CodingKey {
+    case items = "items"
+    case next = "next"
+  }
+}
+
+public struct Test_PairItemU32: Codable {
+  let first: Test_Item
+  let second: UInt32
+
+  enum CodingKeys: String, CodingKey {
+    case first = "first"
+    case second = "second"
+  }
+}
+
+public enum Test_OutcomeItem {
+  case Ok(Test_OutcomeItem_Ok)
+  case Error(Test_OutcomeItem_Error)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "type"
+  }
+}
+
+extension Test_OutcomeItem: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "Ok":
+      self = try .Ok(Test_OutcomeItem_Ok(from: decoder))
+    case "Error":
+      self = try .Error(Test_OutcomeItem_Error(from: decoder))
+    default:
+      let context = DecodingError.Context(codingPath: [], debugDescription: "type")
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_OutcomeItem: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .Ok(let d):
+      try values.encode("Ok", forKey: .tag)
+      try d.encode(to: encoder)
+    case .Error(let d):
+      try values.encode("Error", forKey: .tag)
+      try d.encode(to: encoder)
+    }
+  }
+}
+
+public struct Test_OutcomeItem_Ok: Codable {
+  let value: Test_Item
+
+  enum CodingKeys: String, CodingKey {
+    case value = "value"
+  }
+}
+
+public struct Test_OutcomeItem_Error: Codable {
+  let message: String
+
+  enum CodingKeys: String, CodingKey {
+    case message = "message"
+  }
+}
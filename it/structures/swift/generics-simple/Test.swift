@@ -0,0 +1,277 @@
+public struct Test_Entry {
+  let page: Test_PageItem?
+  let names: Test_PageString?
+  let pair: Test_PairItemU32?
+  let outcome: Test_OutcomeItem?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var page: Test_PageItem? = Optional.none
+
+    if let value = json["page"] {
+      page = Optional.some(try Test_PageItem.decode(json: value))
+    }
+
+    var names: Test_PageString? = Optional.none
+
+    if let value = json["names"] {
+      names = Optional.some(try Test_PageString.decode(json: value))
+    }
+
+    var pair: Test_PairItemU32? = Optional.none
+
+    if let value = json["pair"] {
+      pair = Optional.some(try Test_PairItemU32.decode(json: value))
+    }
+
+    var outcome: Test_OutcomeItem? = Optional.none
+
+    if let value = json["outcome"] {
+      outcome = Optional.some(try Test_OutcomeItem.decode(json: value))
+    }
+    return Test_Entry(page: page, names: names, pair: pair, outcome: outcome)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.page {
+      json["page"] = try value.encode()
+    }
+
+    if let value = self.names {
+      json["names"] = try value.encode()
+    }
+
+    if let value = self.pair {
+      json["pair"] = try value.encode()
+    }
+
+    if let value = self.outcome {
+      json["outcome"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public struct Test_Item {
+  let name: String
+}
+
+public extension Test_Item {
+  static func decode(json: Any) throws -> Test_Item {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_name = json["name"] else {
+      throw SerializationError.missing("name")
+    }
+
+    let name = try decode_name(unbox(f_name, as: String.self), name: "name")
+    return Test_Item(name: name)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["name"] = self.name
+
+    return json
+  }
+}
+
+// A single page of results.
+public struct Test_PageItem {
+  let items: [Test_Item]
+  let next: String?
+}
+
+public extension Test_PageItem {
+  static func decode(json: Any) throws -> Test_PageItem {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_items = json["items"] else {
+      throw SerializationError.missing("items")
+    }
+
+    let items = try decode_array(f_items, name: "items", inner: { inner in try Test_Item.decode(json: inner) })
+
+    var next: String? = Optional.none
+
+    if let value = json["next"] {
+      next = Optional.some(try decode_name(unbox(value, as: String.self), name: "next"))
+    }
+    return Test_PageItem(items: items, next: next)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["items"] = try encode_array(self.items, name: "items", inner: { inner in try inner.encode() })
+
+    if let value = self.next {
+      json["next"] = value
+    }
+
+    return json
+  }
+}
+
+// A single page of results.
+public struct Test_PageString {
+  let items: [String]
+  let next: String?
+}
+
+public extension Test_PageString {
+  static func decode(json: Any) throws -> Test_PageString {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_items = json["items"] else {
+      throw SerializationError.missing("items")
+    }
+
+    let items = try decode_array(f_items, name: "items", inner: { inner in try decode_name(unbox(inner, as: String.self), name: "items") })
+
+    var next: String? = Optional.none
+
+    if let value = json["next"] {
+      next = Optional.some(try decode_name(unbox(value, as: String.self), name: "next"))
+    }
+    return Test_PageString(items: items, next: next)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["items"] = try encode_array(self.items, name: "items", inner: { inner in inner })
+
+    if let value = self.next {
+      json["next"] = value
+    }
+
+    return json
+  }
+}
+
+public struct Test_PairItemU32 {
+  let first: Test_Item
+  let second: UInt32
+}
+
+public extension Test_PairItemU32 {
+  static func decode(json: Any) throws -> Test_PairItemU32 {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_first = json["first"] else {
+      throw SerializationError.missing("first")
+    }
+
+    let first = try Test_Item.decode(json: f_first)
+
+    guard let f_second = json["second"] else {
+      throw SerializationError.missing("second")
+    }
+
+    let second = try decode_name(unbox(f_second, as: UInt32.self), name: "second")
+    return Test_PairItemU32(first: first, second: second)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["first"] = try self.first.encode()
+
+    json["second"] = self.second
+
+    return json
+  }
+}
+
+public enum Test_OutcomeItem {
+  case Ok(Test_OutcomeItem_Ok)
+  case Error(Test_OutcomeItem_Error)
+}
+
+public extension Test_OutcomeItem {
+  static func decode(json: Any) throws -> Test_OutcomeItem {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["type"] as? String, name: "type")
+
+    switch type {
+    case "Ok":
+      let v = try Test_OutcomeItem_Ok.decode(json: json)
+      return Test_OutcomeItem.Ok(v)
+    case "Error":
+      let v = try Test_OutcomeItem_Error.decode(json: json)
+      return Test_OutcomeItem.Error(v)
+    default:
+      throw SerializationError.invalid(type)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .Ok(let s):
+      var json = try s.encode()
+      json["type"] = "Ok"
+      return json
+    case .Error(let s):
+      var json = try s.encode()
+      json["type"] = "Error"
+      return json
+    }
+  }
+}
+
+public struct Test_OutcomeItem_Ok {
+  let value: Test_Item
+}
+
+public extension Test_OutcomeItem_Ok {
+  static func decode(json: Any) throws -> Test_OutcomeItem_Ok {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_value = json["value"] else {
+      throw SerializationError.missing("value")
+    }
+
+    let value = try Test_Item.decode(json: f_value)
+    return Test_OutcomeItem_Ok(value: value)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["value"] = try self.value.encode()
+
+    return json
+  }
+}
+
+public struct Test_OutcomeItem_Error {
+  let message: String
+}
+
+public extension Test_OutcomeItem_Error {
+  static func decode(json: Any) throws -> Test_OutcomeItem_Error {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_message = json["message"] else {
+      throw SerializationError.missing("message")
+    }
+
+    let message = try decode_name(unbox(f_message, as: String.self), name: "message")
+    return Test_OutcomeItem_Error(message: message)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["message"] = self.message
+
+    return json
+  }
+}
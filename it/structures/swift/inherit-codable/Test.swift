@@ -0,0 +1,48 @@
+public struct Test_Entry: Codable {
+  let created_at: String?
+  let updated_at: String?
+  // Who last modified the entry.
+  let updated_by: String?
+  let id: String?
+  let name: String?
+
+  enum CodingKeys: String, CodingKey {
+    case created_at = "created_at"
+    case updated_at = "updated_at"
+    case updated_by = "updated_by"
+    case id = "id"
+    case name = "name"
+  }
+}
+
+// Fields shared by all audited types.
+public struct Test_Auditable: Codable {
+  let created_at: String?
+  let updated_at: String?
+  // Who last modified the entry.
+  let updated_by: String?
+
+  enum CodingKeys: String, CodingKey {
+    case created_at = "created_at"
+    case updated_at = "updated_at"
+    case updated_by = "updated_by"
+  }
+}
+
+public struct Test_Timestamped: Codable {
+  let created_at: String?
+  let updated_at: String?
+
+  enum CodingKeys: String, CodingKey {
+    case created_at = "created_at"
+    case updated_at = "updated_at"
+  }
+}
+
+public struct Test_Identified: Codable {
+  let id: String?
+
+  enum CodingKeys: String, CodingKey {
+    case id = "id"
+  }
+}
@@ -0,0 +1,187 @@
+public struct Test_Entry {
+  let created_at: String?
+  let updated_at: String?
+  // Who last modified the entry.
+  let updated_by: String?
+  let id: String?
+  let name: String?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var created_at: String? = Optional.none
+
+    if let value = json["created_at"] {
+      created_at = Optional.some(try decode_name(unbox(value, as: String.self), name: "created_at"))
+    }
+
+    var updated_at: String? = Optional.none
+
+    if let value = json["updated_at"] {
+      updated_at = Optional.some(try decode_name(unbox(value, as: String.self), name: "updated_at"))
+    }
+
+    var updated_by: String? = Optional.none
+
+    if let value = json["updated_by"] {
+      updated_by = Optional.some(try decode_name(unbox(value, as: String.self), name: "updated_by"))
+    }
+
+    var id: String? = Optional.none
+
+    if let value = json["id"] {
+      id = Optional.some(try decode_name(unbox(value, as: String.self), name: "id"))
+    }
+
+    var name: String? = Optional.none
+
+    if let value = json["name"] {
+      name = Optional.some(try decode_name(unbox(value, as: String.self), name: "name"))
+    }
+    return Test_Entry(created_at: created_at, updated_at: updated_at, updated_by: updated_by, id: id, name: name)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.created_at {
+      json["created_at"] = value
+    }
+
+    if let value = self.updated_at {
+      json["updated_at"] = value
+    }
+
+    if let value = self.updated_by {
+      json["updated_by"] = value
+    }
+
+    if let value = self.id {
+      json["id"] = value
+    }
+
+    if let value = self.name {
+      json["name"] = value
+    }
+
+    return json
+  }
+}
+
+// Fields shared by all audited types.
+public struct Test_Auditable {
+  let created_at: String?
+  let updated_at: String?
+  // Who last modified the entry.
+  let updated_by: String?
+}
+
+public extension Test_Auditable {
+  static func decode(json: Any) throws -> Test_Auditable {
+    let json = try decode_value(json as? [String: Any])
+
+    var created_at: String? = Optional.none
+
+    if let value = json["created_at"] {
+      created_at = Optional.some(try decode_name(unbox(value, as: String.self), name: "created_at"))
+    }
+
+    var updated_at: String? = Optional.none
+
+    if let value = json["updated_at"] {
+      updated_at = Optional.some(try decode_name(unbox(value, as: String.self), name: "updated_at"))
+    }
+
+    var updated_by: String? = Optional.none
+
+    if let value = json["updated_by"] {
+      updated_by = Optional.some(try decode_name(unbox(value, as: String.self), name: "updated_by"))
+    }
+    return Test_Auditable(created_at: created_at, updated_at: updated_at, updated_by: updated_by)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.created_at {
+      json["created_at"] = value
+    }
+
+    if let value = self.updated_at {
+      json["updated_at"] = value
+    }
+
+    if let value = self.updated_by {
+      json["updated_by"] = value
+    }
+
+    return json
+  }
+}
+
+public struct Test_Timestamped {
+  let created_at: String?
+  let updated_at: String?
+}
+
+public extension Test_Timestamped {
+  static func decode(json: Any) throws -> Test_Timestamped {
+    let json = try decode_value(json as? [String: Any])
+
+    var created_at: String? = Optional.none
+
+    if let value = json["created_at"] {
+      created_at = Optional.some(try decode_name(unbox(value, as: String.self), name: "created_at"))
+    }
+
+    var updated_at: String? = Optional.none
+
+    if let value = json["updated_at"] {
+      updated_at = Optional.some(try decode_name(unbox(value, as: String.self), name: "updated_at"))
+    }
+    return Test_Timestamped(created_at: created_at, updated_at: updated_at)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.created_at {
+      json["created_at"] = value
+    }
+
+    if let value = self.updated_at {
+      json["updated_at"] = value
+    }
+
+    return json
+  }
+}
+
+public struct Test_Identified {
+  let id: String?
+}
+
+public extension Test_Identified {
+  static func decode(json: Any) throws -> Test_Identified {
+    let json = try decode_value(json as? [String: Any])
+
+    var id: String? = Optional.none
+
+    if let value = json["id"] {
+      id = Optional.some(try decode_name(unbox(value, as: String.self), name: "id"))
+    }
+    return Test_Identified(id: id)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.id {
+      json["id"] = value
+    }
+
+    return json
+  }
+}
@@ -0,0 +1,80 @@
+public struct Test_Entry: Codable {
+  let id: UInt64
+  let offset: Int64?
+  let size: Int64?
+  let count: UInt64?
+  let pair: Test_Pair?
+
+  enum CodingKeys: String, CodingKey {
+    case id = "id"
+    case offset = "offset"
+    case size = "size"
+    case count = "count"
+    case pair = "pair"
+  }
+}
+
+public extension Test_Entry {
+  init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    let value = try values.decode(String.self, forKey: .id)
+    guard let value = UInt64(value) else {
+      throw DecodingError.dataCorruptedError(forKey: .id, in: values, debugDescription: "expected a number encoded as a string")
+    }
+    self.id = value
+    if let value = try values.decodeIfPresent(String.self, forKey: .offset) {
+      guard let value = Int64(value) else {
+        throw DecodingError.dataCorruptedError(forKey: .offset, in: values, debugDescription: "expected a number encoded as a string")
+      }
+      self.offset = value
+    } else {
+      self.offset = nil
+    }
+    self.size = try values.decodeIfPresent(Int64.self, forKey: .size)
+    self.count = try values.decodeIfPresent(UInt64.self, forKey: .count)
+    self.pair = try values.decodeIfPresent(Test_Pair.self, forKey: .pair)
+  }
+
+  func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    try values.encode(String(self.id), forKey: .id)
+    try values.encodeIfPresent(self.offset.map { String($0) }, forKey: .offset)
+    try values.encodeIfPresent(self.size, forKey: .size)
+    try values.encodeIfPresent(self.count, forKey: .count)
+    try values.encodeIfPresent(self.pair, forKey: .pair)
+  }
+}
+
+public struct Test_Pair {
+  let left_: UInt64
+  let right_: UInt64
+
+  enum CodingKeys: String, CodingKey {
+    case left_ = "left"
+    case right_ = "right"
+  }
+}
+
+extension Test_Pair: Decodable {
+  public init(from decoder: Decoder) throws {
+    var values = try decoder.unkeyedContainer()
+
+    let value = try values.decode(String.self)
+    guard let value = UInt64(value) else {
+      throw DecodingError.dataCorruptedError(in: values, debugDescription: "expected a number encoded as a string")
+    }
+    self.left_ = value
+    self.right_ = try values.decode(UInt64.self)
+  }
+}
+
+extension Test_Pair: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.unkeyedContainer()
+
+    try values.encode(String(self.left_))
+    try values.encode(self.right_)
+  }
+}
@@ -0,0 +1,102 @@
+public struct Test_Entry {
+  let id: UInt64
+  let offset: Int64?
+  let size: Int64?
+  let count: UInt64?
+  let pair: Test_Pair?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_id = json["id"] else {
+      throw SerializationError.missing("id")
+    }
+
+    let id = try decode_name(UInt64(try decode_value(f_id as? String)), name: "id")
+
+    var offset: Int64? = Optional.none
+
+    if let value = json["offset"] {
+      offset = Optional.some(try decode_name(Int64(try decode_value(value as? String)), name: "offset"))
+    }
+
+    var size: Int64? = Optional.none
+
+    if let value = json["size"] {
+      size = Optional.some(try decode_name(unbox(value, as: Int64.self), name: "size"))
+    }
+
+    var count: UInt64? = Optional.none
+
+    if let value = json["count"] {
+      count = Optional.some(try decode_name(unbox(value, as: UInt64.self), name: "count"))
+    }
+
+    var pair: Test_Pair? = Optional.none
+
+    if let value = json["pair"] {
+      pair = Optional.some(try Test_Pair.decode(json: value))
+    }
+    return Test_Entry(id: id, offset: offset, size: size, count: count, pair: pair)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["id"] = String(self.id)
+
+    if let value = self.offset {
+      json["offset"] = String(value)
+    }
+
+    if let value = self.size {
+      json["size"] = value
+    }
+
+    if let value = self.count {
+      json["count"] = value
+    }
+
+    if let value = self.pair {
+      json["pair"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public struct Test_Pair {
+  let left_: UInt64
+  let right_: UInt64
+}
+
+public extension Test_Pair {
+  static func decode(json: Any) throws -> Test_Pair {
+    let json = try decode_value(json as? [Any])
+
+    guard let f_left = Optional.some(json[0]) else {
+      throw SerializationError.missing("[0]")
+    }
+
+    let left_ = try decode_name(UInt64(try decode_value(f_left as? String)), name: "[0]")
+
+    guard let f_right = Optional.some(json[1]) else {
+      throw SerializationError.missing("[1]")
+    }
+
+    let right_ = try decode_name(unbox(f_right, as: UInt64.self), name: "[1]")
+
+    return Test_Pair(left_: left_, right_: right_)
+  }
+
+  func encode() throws -> [Any] {
+    var json = [Any]()
+
+    json.append(String(self.left_))
+    json.append(self.right_)
+
+    return json
+  }
+}
@@ -0,0 +1,170 @@
+public struct Test_Entry: Codable {
+  let adjacent: Test_Adjacent?
+  let adjacent_default: Test_AdjacentDefault?
+  let external: Test_External?
+
+  enum CodingKeys: String, CodingKey {
+    case adjacent = "adjacent"
+    case adjacent_default = "adjacent_default"
+    case external = "external"
+  }
+}
+
+public enum Test_Adjacent {
+  case A(Test_Adjacent_A)
+  case B(Test_Adjacent_B)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "kind"
+    case content = "value"
+  }
+}
+
+extension Test_Adjacent: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "foo":
+      self = try .A(values.decode(Test_Adjacent_A.self, forKey: .content))
+    case "B":
+      self = try .B(values.decode(Test_Adjacent_B.self, forKey: .content))
+    default:
+      let context = DecodingError.Context(codingPath: [], debugDescription: "kind")
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_Adjacent: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .A(let d):
+      try values.encode("foo", forKey: .tag)
+      try values.encode(d, forKey: .content)
+    case .B(let d):
+      try values.encode("B", forKey: .tag)
+      try values.encode(d, forKey: .content)
+    }
+  }
+}
+
+public struct Test_Adjacent_A: Codable {
+  let shared: String
+
+  enum CodingKeys: String, CodingKey {
+    case shared = "shared"
+  }
+}
+
+public struct Test_Adjacent_B: Codable {
+  let shared: String
+  let b: String
+
+  enum CodingKeys: String, CodingKey {
+    case shared = "shared"
+    case b = "b"
+  }
+}
+
+public enum Test_AdjacentDefault {
+  case A(Test_AdjacentDefault_A)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "type"
+    case content = "data"
+  }
+}
+
+extension Test_AdjacentDefault: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "A":
+      self = try .A(values.decode(Test_AdjacentDefault_A.self, forKey: .content))
+    default:
+      let context = DecodingError.Context(codingPath: [], debugDescription: "type")
+      throw DecodingError.dataCorrupted(context)
+    }
+  }
+}
+
+extension Test_AdjacentDefault: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .A(let d):
+      try values.encode("A", forKey: .tag)
+      try values.encode(d, forKey: .content)
+    }
+  }
+}
+
+public struct Test_AdjacentDefault_A: Codable {}
+
+public enum Test_External {
+  case A(Test_External_A)
+  case B(Test_External_B)
+
+  enum CodingKeys: String, CodingKey {
+    case A = "foo"
+    case B = "B"
+  }
+}
+
+extension Test_External: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    guard values.allKeys.count == 1 else {
+      let context = DecodingError.Context(
+        codingPath: decoder.codingPath,
+        debugDescription: "expected exactly one key"
+      )
+
+      throw DecodingError.dataCorrupted(context)
+    }
+
+    switch values.allKeys[0] {
+    case .A:
+      self = try .A(values.decode(Test_External_A.self, forKey: .A))
+    case .B:
+      self = try .B(values.decode(Test_External_B.self, forKey: .B))
+    }
+  }
+}
+
+extension Test_External: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .A(let d):
+      try values.encode(d, forKey: .A)
+    case .B(let d):
+      try values.encode(d, forKey: .B)
+    }
+  }
+}
+
+public struct Test_External_A: Codable {
+  let shared: String
+
+  enum CodingKeys: String, CodingKey {
+    case shared = "shared"
+  }
+}
+
+public struct Test_External_B: Codable {
+  let shared: String
+  let b: String
+
+  enum CodingKeys: String, CodingKey {
+    case shared = "shared"
+    case b = "b"
+  }
+}
@@ -0,0 +1,278 @@
+public struct Test_Entry {
+  let adjacent: Test_Adjacent?
+  let adjacent_default: Test_AdjacentDefault?
+  let external: Test_External?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var adjacent: Test_Adjacent? = Optional.none
+
+    if let value = json["adjacent"] {
+      adjacent = Optional.some(try Test_Adjacent.decode(json: value))
+    }
+
+    var adjacent_default: Test_AdjacentDefault? = Optional.none
+
+    if let value = json["adjacent_default"] {
+      adjacent_default = Optional.some(try Test_AdjacentDefault.decode(json: value))
+    }
+
+    var external: Test_External? = Optional.none
+
+    if let value = json["external"] {
+      external = Optional.some(try Test_External.decode(json: value))
+    }
+    return Test_Entry(adjacent: adjacent, adjacent_default: adjacent_default, external: external)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.adjacent {
+      json["adjacent"] = try value.encode()
+    }
+
+    if let value = self.adjacent_default {
+      json["adjacent_default"] = try value.encode()
+    }
+
+    if let value = self.external {
+      json["external"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public enum Test_Adjacent {
+  case A(Test_Adjacent_A)
+  case B(Test_Adjacent_B)
+}
+
+public extension Test_Adjacent {
+  static func decode(json: Any) throws -> Test_Adjacent {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["kind"] as? String, name: "kind")
+
+    guard let content = json["value"] else {
+      throw SerializationError.missing("value")
+    }
+
+    switch type {
+    case "foo":
+      let v = try Test_Adjacent_A.decode(json: content)
+      return Test_Adjacent.A(v)
+    case "B":
+      let v = try Test_Adjacent_B.decode(json: content)
+      return Test_Adjacent.B(v)
+    default:
+      throw SerializationError.invalid(type)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .A(let s):
+      return ["kind": "foo", "value": try s.encode()]
+    case .B(let s):
+      return ["kind": "B", "value": try s.encode()]
+    }
+  }
+}
+
+public struct Test_Adjacent_A {
+  let shared: String
+}
+
+public extension Test_Adjacent_A {
+  static func decode(json: Any) throws -> Test_Adjacent_A {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_shared = json["shared"] else {
+      throw SerializationError.missing("shared")
+    }
+
+    let shared = try decode_name(unbox(f_shared, as: String.self), name: "shared")
+    return Test_Adjacent_A(shared: shared)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["shared"] = self.shared
+
+    return json
+  }
+}
+
+public struct Test_Adjacent_B {
+  let shared: String
+  let b: String
+}
+
+public extension Test_Adjacent_B {
+  static func decode(json: Any) throws -> Test_Adjacent_B {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_shared = json["shared"] else {
+      throw SerializationError.missing("shared")
+    }
+
+    let shared = try decode_name(unbox(f_shared, as: String.self), name: "shared")
+
+    guard let f_b = json["b"] else {
+      throw SerializationError.missing("b")
+    }
+
+    let b = try decode_name(unbox(f_b, as: String.self), name: "b")
+    return Test_Adjacent_B(shared: shared, b: b)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["shared"] = self.shared
+
+    json["b"] = self.b
+
+    return json
+  }
+}
+
+public enum Test_AdjacentDefault {
+  case A(Test_AdjacentDefault_A)
+}
+
+public extension Test_AdjacentDefault {
+  static func decode(json: Any) throws -> Test_AdjacentDefault {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["type"] as? String, name: "type")
+
+    guard let content = json["data"] else {
+      throw SerializationError.missing("data")
+    }
+
+    switch type {
+    case "A":
+      let v = try Test_AdjacentDefault_A.decode(json: content)
+      return Test_AdjacentDefault.A(v)
+    default:
+      throw SerializationError.invalid(type)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .A(let s):
+      return ["type": "A", "data": try s.encode()]
+    }
+  }
+}
+
+public struct Test_AdjacentDefault_A {}
+
+public extension Test_AdjacentDefault_A {
+  static func decode(json: Any) throws -> Test_AdjacentDefault_A {
+    let _ = try decode_value(json as? [String: Any])
+
+    return Test_AdjacentDefault_A()
+  }
+
+  func encode() throws -> [String: Any] {
+    return [String: Any]()
+  }
+}
+
+public enum Test_External {
+  case A(Test_External_A)
+  case B(Test_External_B)
+}
+
+public extension Test_External {
+  static func decode(json: Any) throws -> Test_External {
+    let json = try decode_value(json as? [String: Any])
+
+    guard json.count == 1, let entry = json.first else {
+      throw SerializationError.invalid("expected exactly one key")
+    }
+
+    switch entry.key {
+    case "foo":
+      let v = try Test_External_A.decode(json: entry.value)
+      return Test_External.A(v)
+    case "B":
+      let v = try Test_External_B.decode(json: entry.value)
+      return Test_External.B(v)
+    default:
+      throw SerializationError.invalid(entry.key)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .A(let s):
+      return ["foo": try s.encode()]
+    case .B(let s):
+      return ["B": try s.encode()]
+    }
+  }
+}
+
+public struct Test_External_A {
+  let shared: String
+}
+
+public extension Test_External_A {
+  static func decode(json: Any) throws -> Test_External_A {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_shared = json["shared"] else {
+      throw SerializationError.missing("shared")
+    }
+
+    let shared = try decode_name(unbox(f_shared, as: String.self), name: "shared")
+    return Test_External_A(shared: shared)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["shared"] = self.shared
+
+    return json
+  }
+}
+
+public struct Test_External_B {
+  let shared: String
+  let b: String
+}
+
+public extension Test_External_B {
+  static func decode(json: Any) throws -> Test_External_B {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_shared = json["shared"] else {
+      throw SerializationError.missing("shared")
+    }
+
+    let shared = try decode_name(unbox(f_shared, as: String.self), name: "shared")
+
+    guard let f_b = json["b"] else {
+      throw SerializationError.missing("b")
+    }
+
+    let b = try decode_name(unbox(f_b, as: String.self), name: "b")
+    return Test_External_B(shared: shared, b: b)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["shared"] = self.shared
+
+    json["b"] = self.b
+
+    return json
+  }
+}
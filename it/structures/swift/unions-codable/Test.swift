@@ -0,0 +1,145 @@
+public struct Test_Point: Codable {
+  let x: Double
+  let y: Double
+
+  enum CodingKeys: String, CodingKey {
+    case x = "x"
+    case y = "y"
+  }
+}
+
+public struct Test_Entry: Codable {
+  // Either a name or a numeric identifier.
+  let value: Test_Entry_Value?
+  let shape: Test_Entry_Shape?
+  let flag: Test_Entry_Flag?
+
+  enum CodingKeys: String, CodingKey {
+    case value = "value"
+    case shape = "shape"
+    case flag = "flag"
+  }
+}
+
+public enum Test_Entry_Value {
+  case string(String)
+  case u64(UInt64)
+}
+
+extension Test_Entry_Value: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    if let v = try? value.decode(String.self) {
+      self = .string(v)
+      return
+    }
+
+    if let v = try? value.decode(UInt64.self) {
+      self = .u64(v)
+      return
+    }
+
+    let context = DecodingError.Context(
+      codingPath: decoder.codingPath,
+      debugDescription: "no alternative of the union matches the value"
+    )
+
+    throw DecodingError.dataCorrupted(context)
+  }
+}
+
+extension Test_Entry_Value: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .string(let v):
+      try value.encode(v)
+    case .u64(let v):
+      try value.encode(v)
+    }
+  }
+}
+
+public enum Test_Entry_Shape {
+  case point(Test_Point)
+  case doubleArray([Double])
+}
+
+extension Test_Entry_Shape: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    if let v = try? value.decode(Test_Point.self) {
+      self = .point(v)
+      return
+    }
+
+    if let v = try? value.decode([Double].self) {
+      self = .doubleArray(v)
+      return
+    }
+
+    let context = DecodingError.Context(
+      codingPath: decoder.codingPath,
+      debugDescription: "no alternative of the union matches the value"
+    )
+
+    throw DecodingError.dataCorrupted(context)
+  }
+}
+
+extension Test_Entry_Shape: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .point(let v):
+      try value.encode(v)
+    case .doubleArray(let v):
+      try value.encode(v)
+    }
+  }
+}
+
+public enum Test_Entry_Flag {
+  case boolean(Bool)
+  case string(String)
+}
+
+extension Test_Entry_Flag: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+
+    if let v = try? value.decode(Bool.self) {
+      self = .boolean(v)
+      return
+    }
+
+    if let v = try? value.decode(String.self) {
+      self = .string(v)
+      return
+    }
+
+    let context = DecodingError.Context(
+      codingPath: decoder.codingPath,
+      debugDescription: "no alternative of the union matches the value"
+    )
+
+    throw DecodingError.dataCorrupted(context)
+  }
+}
+
+extension Test_Entry_Flag: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .boolean(let v):
+      try value.encode(v)
+    case .string(let v):
+      try value.encode(v)
+    }
+  }
+}
@@ -0,0 +1,173 @@
+public struct Test_Point {
+  let x: Double
+  let y: Double
+}
+
+public extension Test_Point {
+  static func decode(json: Any) throws -> Test_Point {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_x = json["x"] else {
+      throw SerializationError.missing("x")
+    }
+
+    let x = try decode_name(unbox(f_x, as: Double.self), name: "x")
+
+    guard let f_y = json["y"] else {
+      throw SerializationError.missing("y")
+    }
+
+    let y = try decode_name(unbox(f_y, as: Double.self), name: "y")
+    return Test_Point(x: x, y: y)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["x"] = self.x
+
+    json["y"] = self.y
+
+    return json
+  }
+}
+
+public struct Test_Entry {
+  // Either a name or a numeric identifier.
+  let value: Test_Entry_Value?
+  let shape: Test_Entry_Shape?
+  let flag: Test_Entry_Flag?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var value: Test_Entry_Value? = Optional.none
+
+    if let value = json["value"] {
+      value = Optional.some(try Test_Entry_Value.decode(json: value))
+    }
+
+    var shape: Test_Entry_Shape? = Optional.none
+
+    if let value = json["shape"] {
+      shape = Optional.some(try Test_Entry_Shape.decode(json: value))
+    }
+
+    var flag: Test_Entry_Flag? = Optional.none
+
+    if let value = json["flag"] {
+      flag = Optional.some(try Test_Entry_Flag.decode(json: value))
+    }
+    return Test_Entry(value: value, shape: shape, flag: flag)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.value {
+      json["value"] = try value.encode()
+    }
+
+    if let value = self.shape {
+      json["shape"] = try value.encode()
+    }
+
+    if let value = self.flag {
+      json["flag"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public enum Test_Entry_Value {
+  case string(String)
+  case u64(UInt64)
+}
+
+public extension Test_Entry_Value {
+  static func decode(json: Any) throws -> Test_Entry_Value {
+    let json = try decode_value(json)
+
+    if let v = try? try decode_name(unbox(json, as: String.self), name: "string") {
+      return Test_Entry_Value.string(v)
+    }
+
+    if let v = try? try decode_name(unbox(json, as: UInt64.self), name: "u64") {
+      return Test_Entry_Value.u64(v)
+    }
+
+    throw SerializationError.invalid("no alternative of the union matches the value")
+  }
+
+  func encode() throws -> Any {
+    switch self {
+    case .string(let v):
+      return v
+    case .u64(let v):
+      return v
+    }
+  }
+}
+
+public enum Test_Entry_Shape {
+  case point(Test_Point)
+  case doubleArray([Double])
+}
+
+public extension Test_Entry_Shape {
+  static func decode(json: Any) throws -> Test_Entry_Shape {
+    let json = try decode_value(json)
+
+    if let v = try? try Test_Point.decode(json: json) {
+      return Test_Entry_Shape.point(v)
+    }
+
+    if let v = try? try decode_array(json, name: "doubleArray", inner: { inner in try decode_name(unbox(inner, as: Double.self), name: "doubleArray") }) {
+      return Test_Entry_Shape.doubleArray(v)
+    }
+
+    throw SerializationError.invalid("no alternative of the union matches the value")
+  }
+
+  func encode() throws -> Any {
+    switch self {
+    case .point(let v):
+      return try v.encode()
+    case .doubleArray(let v):
+      return try encode_array(v, name: "doubleArray", inner: { inner in inner })
+    }
+  }
+}
+
+public enum Test_Entry_Flag {
+  case boolean(Bool)
+  case string(String)
+}
+
+public extension Test_Entry_Flag {
+  static func decode(json: Any) throws -> Test_Entry_Flag {
+    let json = try decode_value(json)
+
+    if let v = try? try decode_name(unbox(json, as: Bool.self), name: "boolean") {
+      return Test_Entry_Flag.boolean(v)
+    }
+
+    if let v = try? try decode_name(unbox(json, as: String.self), name: "string") {
+      return Test_Entry_Flag.string(v)
+    }
+
+    throw SerializationError.invalid("no alternative of the union matches the value")
+  }
+
+  func encode() throws -> Any {
+    switch self {
+    case .boolean(let v):
+      return v
+    case .string(let v):
+      return v
+    }
+  }
+}
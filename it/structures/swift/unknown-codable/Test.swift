@@ -0,0 +1,239 @@
+public struct Test_Entry: Codable {
+  let color: Test_Color?
+  let priority: Test_Priority?
+  let tagged: Test_Tagged?
+  let adjacent: Test_Adjacent?
+  let external: Test_External?
+
+  enum CodingKeys: String, CodingKey {
+    case color = "color"
+    case priority = "priority"
+    case tagged = "tagged"
+    case adjacent = "adjacent"
+    case external = "external"
+  }
+}
+
+public enum Test_Color {
+  case Red
+  case Green
+  // A color which is not known to this version of the schema.
+  case Other(String)
+}
+
+extension Test_Color: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+    let raw = try value.decode(String.self)
+
+    switch raw {
+    case "red":
+      self = .Red
+    case "green":
+      self = .Green
+    default:
+      self = .Other(raw)
+    }
+  }
+}
+
+extension Test_Color: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .Red:
+      try value.encode("red")
+    case .Green:
+      try value.encode("green")
+    case .Other(let raw):
+      try value.encode(raw)
+    }
+  }
+}
+
+public enum Test_Priority {
+  case Low
+  case High
+  case Other(UInt32)
+}
+
+extension Test_Priority: Decodable {
+  public init(from decoder: Decoder) throws {
+    let value = try decoder.singleValueContainer()
+    let raw = try value.decode(UInt32.self)
+
+    switch raw {
+    case 1:
+      self = .Low
+    case 2:
+      self = .High
+    default:
+      self = .Other(raw)
+    }
+  }
+}
+
+extension Test_Priority: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var value = encoder.singleValueContainer()
+
+    switch self {
+    case .Low:
+      try value.encode(1)
+    case .High:
+      try value.encode(2)
+    case .Other(let raw):
+      try value.encode(raw)
+    }
+  }
+}
+
+public enum Test_Tagged {
+  case A(Test_Tagged_A)
+  case B(Test_Tagged_B)
+  // A sub-type which is not known to this version of the schema.
+  case Other(AnyCodable)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "type"
+  }
+}
+
+extension Test_Tagged: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "foo":
+      self = try .A(Test_Tagged_A(from: decoder))
+    case "B":
+      self = try .B(Test_Tagged_B(from: decoder))
+    default:
+      self = try .Other(AnyCodable(from: decoder))
+    }
+  }
+}
+
+extension Test_Tagged: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .A(let d):
+      try values.encode("foo", forKey: .tag)
+      try d.encode(to: encoder)
+    case .B(let d):
+      try values.encode("B", forKey: .tag)
+      try d.encode(to: encoder)
+    case .Other(let d):
+      try d.encode(to: encoder)
+    }
+  }
+}
+
+public struct Test_Tagged_A: Codable {
+  let shared: String
+
+  enum CodingKeys: String, CodingKey {
+    case shared = "shared"
+  }
+}
+
+public struct Test_Tagged_B: Codable {
+  let shared: String
+  let b: String
+
+  enum CodingKeys: String, CodingKey {
+    case shared = "shared"
+    case b = "b"
+  }
+}
+
+public enum Test_Adjacent {
+  case A(Test_Adjacent_A)
+  case Other(AnyCodable)
+
+  enum CodingKeys: String, CodingKey {
+    case tag = "type"
+    case content = "data"
+  }
+}
+
+extension Test_Adjacent: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    switch try values.decode(String.self, forKey: .tag) {
+    case "A":
+      self = try .A(values.decode(Test_Adjacent_A.self, forKey: .content))
+    default:
+      self = try .Other(AnyCodable(from: decoder))
+    }
+  }
+}
+
+extension Test_Adjacent: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .A(let d):
+      try values.encode("A", forKey: .tag)
+      try values.encode(d, forKey: .content)
+    case .Other(let d):
+      try d.encode(to: encoder)
+    }
+  }
+}
+
+public struct Test_Adjacent_A: Codable {}
+
+public enum Test_External {
+  case A(Test_External_A)
+  case Other(AnyCodable)
+
+  enum CodingKeys: String, CodingKey {
+    case A = "A"
+  }
+}
+
+extension Test_External: Decodable {
+  public init(from decoder: Decoder) throws {
+    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+    if values.allKeys.isEmpty {
+      self = try .Other(AnyCodable(from: decoder))
+      return
+    }
+
+    guard values.allKeys.count == 1 else {
+      let context = DecodingError.Context(
+        codingPath: decoder.codingPath,
+        debugDescription: "expected exactly one key"
+      )
+
+      throw DecodingError.dataCorrupted(context)
+    }
+
+    switch values.allKeys[0] {
+    case .A:
+      self = try .A(values.decode(Test_External_A.self, forKey: .A))
+    }
+  }
+}
+
+extension Test_External: Encodable {
+  public func encode(to encoder: Encoder) throws {
+    var values = encoder.container(keyedBy: CodingKeys.self)
+
+    switch self {
+    case .A(let d):
+      try values.encode(d, forKey: .A)
+    case .Other(let d):
+      try d.encode(to: encoder)
+    }
+  }
+}
+
+public struct Test_External_A: Codable {}
@@ -0,0 +1,338 @@
+public struct Test_Entry {
+  let color: Test_Color?
+  let priority: Test_Priority?
+  let tagged: Test_Tagged?
+  let adjacent: Test_Adjacent?
+  let external: Test_External?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var color: Test_Color? = Optional.none
+
+    if let value = json["color"] {
+      color = Optional.some(try Test_Color.decode(json: value))
+    }
+
+    var priority: Test_Priority? = Optional.none
+
+    if let value = json["priority"] {
+      priority = Optional.some(try Test_Priority.decode(json: value))
+    }
+
+    var tagged: Test_Tagged? = Optional.none
+
+    if let value = json["tagged"] {
+      tagged = Optional.some(try Test_Tagged.decode(json: value))
+    }
+
+    var adjacent: Test_Adjacent? = Optional.none
+
+    if let value = json["adjacent"] {
+      adjacent = Optional.some(try Test_Adjacent.decode(json: value))
+    }
+
+    var external: Test_External? = Optional.none
+
+    if let value = json["external"] {
+      external = Optional.some(try Test_External.decode(json: value))
+    }
+    return Test_Entry(color: color, priority: priority, tagged: tagged, adjacent: adjacent, external: external)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.color {
+      json["color"] = try value.encode()
+    }
+
+    if let value = self.priority {
+      json["priority"] = try value.encode()
+    }
+
+    if let value = self.tagged {
+      json["tagged"] = try value.encode()
+    }
+
+    if let value = self.adjacent {
+      json["adjacent"] = try value.encode()
+    }
+
+    if let value = self.external {
+      json["external"] = try value.encode()
+    }
+
+    return json
+  }
+}
+
+public enum Test_Color {
+  case Red
+  case Green
+  // A color which is not known to this version of the schema.
+  case Other(String)
+}
+
+public extension Test_Color {
+  static func decode(json: Any) throws -> Test_Color {
+    let json = try decode_value(json)
+    let value = try decode_value(unbox(json, as: String.self))
+
+    switch value {
+    case "red":
+      return Test_Color.Red
+    case "green":
+      return Test_Color.Green
+    default:
+      return Test_Color.Other(value)
+    }
+  }
+
+  func encode() throws -> String {
+    switch self {
+    case .Red:
+      return "red"
+    case .Green:
+      return "green"
+    case .Other(let value):
+      return value
+    }
+  }
+}
+
+public enum Test_Priority {
+  case Low
+  case High
+  case Other(UInt32)
+}
+
+public extension Test_Priority {
+  static func decode(json: Any) throws -> Test_Priority {
+    let json = try decode_value(json)
+    let value = try decode_value(unbox(json, as: UInt32.self))
+
+    switch value {
+    case 1:
+      return Test_Priority.Low
+    case 2:
+      return Test_Priority.High
+    default:
+      return Test_Priority.Other(value)
+    }
+  }
+
+  func encode() throws -> UInt32 {
+    switch self {
+    case .Low:
+      return 1
+    case .High:
+      return 2
+    case .Other(let value):
+      return value
+    }
+  }
+}
+
+public enum Test_Tagged {
+  case A(Test_Tagged_A)
+  case B(Test_Tagged_B)
+  // A sub-type which is not known to this version of the schema.
+  case Other(Any)
+}
+
+public extension Test_Tagged {
+  static func decode(json: Any) throws -> Test_Tagged {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["type"] as? String, name: "type")
+
+    switch type {
+    case "foo":
+      let v = try Test_Tagged_A.decode(json: json)
+      return Test_Tagged.A(v)
+    case "B":
+      let v = try Test_Tagged_B.decode(json: json)
+      return Test_Tagged.B(v)
+    default:
+      return Test_Tagged.Other(json)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .A(let s):
+      var json = try s.encode()
+      json["type"] = "foo"
+      return json
+    case .B(let s):
+      var json = try s.encode()
+      json["type"] = "B"
+      return json
+    case .Other(let json):
+      guard let json = json as? [String: Any] else {
+        throw SerializationError.bad_value
+      }
+
+      return json
+    }
+  }
+}
+
+public struct Test_Tagged_A {
+  let shared: String
+}
+
+public extension Test_Tagged_A {
+  static func decode(json: Any) throws -> Test_Tagged_A {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_shared = json["shared"] else {
+      throw SerializationError.missing("shared")
+    }
+
+    let shared = try decode_name(unbox(f_shared, as: String.self), name: "shared")
+    return Test_Tagged_A(shared: shared)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["shared"] = self.shared
+
+    return json
+  }
+}
+
+public struct Test_Tagged_B {
+  let shared: String
+  let b: String
+}
+
+public extension Test_Tagged_B {
+  static func decode(json: Any) throws -> Test_Tagged_B {
+    let json = try decode_value(json as? [String: Any])
+
+    guard let f_shared = json["shared"] else {
+      throw SerializationError.missing("shared")
+    }
+
+    let shared = try decode_name(unbox(f_shared, as: String.self), name: "shared")
+
+    guard let f_b = json["b"] else {
+      throw SerializationError.missing("b")
+    }
+
+    let b = try decode_name(unbox(f_b, as: String.self), name: "b")
+    return Test_Tagged_B(shared: shared, b: b)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    json["shared"] = self.shared
+
+    json["b"] = self.b
+
+    return json
+  }
+}
+
+public enum Test_Adjacent {
+  case A(Test_Adjacent_A)
+  case Other(Any)
+}
+
+public extension Test_Adjacent {
+  static func decode(json: Any) throws -> Test_Adjacent {
+    let json = try decode_value(json as? [String: Any])
+    let type = try decode_name(json["type"] as? String, name: "type")
+
+    guard let content = json["data"] else {
+      throw SerializationError.missing("data")
+    }
+
+    switch type {
+    case "A":
+      let v = try Test_Adjacent_A.decode(json: content)
+      return Test_Adjacent.A(v)
+    default:
+      return Test_Adjacent.Other(json)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .A(let s):
+      return ["type": "A", "data": try s.encode()]
+    case .Other(let json):
+      guard let json = json as? [String: Any] else {
+        throw SerializationError.bad_value
+      }
+
+      return json
+    }
+  }
+}
+
+public struct Test_Adjacent_A {}
+
+public extension Test_Adjacent_A {
+  static func decode(json: Any) throws -> Test_Adjacent_A {
+    let _ = try decode_value(json as? [String: Any])
+
+    return Test_Adjacent_A()
+  }
+
+  func encode() throws -> [String: Any] {
+    return [String: Any]()
+  }
+}
+
+public enum Test_External {
+  case A(Test_External_A)
+  case Other(Any)
+}
+
+public extension Test_External {
+  static func decode(json: Any) throws -> Test_External {
+    let json = try decode_value(json as? [String: Any])
+
+    guard json.count == 1, let entry = json.first else {
+      throw SerializationError.invalid("expected exactly one key")
+    }
+
+    switch entry.key {
+    case "A":
+      let v = try Test_External_A.decode(json: entry.value)
+      return Test_External.A(v)
+    default:
+      return Test_External.Other(json)
+    }
+  }
+  func encode() throws -> [String: Any] {
+    switch self {
+    case .A(let s):
+      return ["A": try s.encode()]
+    case .Other(let json):
+      guard let json = json as? [String: Any] else {
+        throw SerializationError.bad_value
+      }
+
+      return json
+    }
+  }
+}
+
+public struct Test_External_A {}
+
+public extension Test_External_A {
+  static func decode(json: Any) throws -> Test_External_A {
+    let _ = try decode_value(json as? [String: Any])
+
+    return Test_External_A()
+  }
+
+  func encode() throws -> [String: Any] {
+    return [String: Any]()
+  }
+}
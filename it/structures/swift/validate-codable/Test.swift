@@ -0,0 +1,15 @@
+public struct Test_Entry: Codable {
+  let name: String?
+  let code: String?
+  let label: String
+  let count: UInt32?
+  let offset: Int64?
+
+  enum CodingKeys: String, CodingKey {
+    case name = "name"
+    case code = "code"
+    case label = "label"
+    case count = "count"
+    case offset = "offset"
+  }
+}
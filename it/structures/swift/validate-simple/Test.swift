@@ -0,0 +1,68 @@
+public struct Test_Entry {
+  let name: String?
+  let code: String?
+  let label: String
+  let count: UInt32?
+  let offset: Int64?
+}
+
+public extension Test_Entry {
+  static func decode(json: Any) throws -> Test_Entry {
+    let json = try decode_value(json as? [String: Any])
+
+    var name: String? = Optional.none
+
+    if let value = json["name"] {
+      name = Optional.some(try decode_name(unbox(value, as: String.self), name: "name"))
+    }
+
+    var code: String? = Optional.none
+
+    if let value = json["code"] {
+      code = Optional.some(try decode_name(unbox(value, as: String.self), name: "code"))
+    }
+
+    guard let f_label = json["label"] else {
+      throw SerializationError.missing("label")
+    }
+
+    let label = try decode_name(unbox(f_label, as: String.self), name: "label")
+
+    var count: UInt32? = Optional.none
+
+    if let value = json["count"] {
+      count = Optional.some(try decode_name(unbox(value, as: UInt32.self), name: "count"))
+    }
+
+    var offset: Int64? = Optional.none
+
+    if let value = json["offset"] {
+      offset = Optional.some(try decode_name(unbox(value, as: Int64.self), name: "offset"))
+    }
+    return Test_Entry(name: name, code: code, label: label, count: count, offset: offset)
+  }
+
+  func encode() throws -> [String: Any] {
+    var json = [String: Any]()
+
+    if let value = self.name {
+      json["name"] = value
+    }
+
+    if let value = self.code {
+      json["code"] = value
+    }
+
+    json["label"] = self.label
+
+    if let value = self.count {
+      json["count"] = value
+    }
+
+    if let value = self.offset {
+      json["offset"] = value
+    }
+
+    return json
+  }
+}
@@ -0,0 +1,73 @@
+import {Message, MyService_WebSocket} from './test.js';
+import WebSocket from 'ws';
+import * as readline from 'readline';
+
+// Echo every text message back, preceded by messages which the client is expected to ignore.
+function echo(socket) {
+  socket.on('message', function(message) {
+    if (typeof message !== 'string') {
+      return;
+    }
+
+    socket.send(Buffer.from([1, 2, 3]));
+    socket.send(JSON.stringify({endpoint: 'other', body: {text: 'ignored'}}));
+    socket.send(message);
+  });
+}
+
+function roundTrip(sender, receiver, messages) {
+  if (messages.length === 0) {
+    return Promise.resolve();
+  }
+
+  sender.send(messages[0]);
+
+  return receiver.recv().then(function(echoed) {
+    if (echoed === null) {
+      throw new Error('connection closed before echo');
+    }
+
+    process.stdout.write(`#<>${JSON.stringify(echoed.encode())}\n`);
+    return roundTrip(sender, receiver, messages.slice(1));
+  });
+}
+
+var messages = [];
+
+var rl = readline.createInterface({
+  input: process.stdin,
+  terminal: false
+});
+
+rl.on('line', function(line) {
+  messages.push(Message.decode(JSON.parse(line)));
+});
+
+rl.on('close', function() {
+  var server = new WebSocket.Server({host: '127.0.0.1', port: 0});
+  server.on('connection', echo);
+
+  new Promise(function(resolve) {
+    server.on('listening', resolve);
+  }).then(function() {
+    var client = new MyService_WebSocket(`ws://127.0.0.1:${server.address().port}`, WebSocket);
+    return client.chat();
+  }).then(function(halves) {
+    var sender = halves[0];
+    var receiver = halves[1];
+
+    return roundTrip(sender, receiver, messages).then(function() {
+      sender.close();
+      return receiver.recv();
+    });
+  }).then(function(message) {
+    if (message !== null) {
+      throw new Error(`unexpected message after close: ${JSON.stringify(message)}`);
+    }
+
+    server.close();
+  }).catch(function(e) {
+    console.error(e);
+    process.exit(1);
+  });
+});
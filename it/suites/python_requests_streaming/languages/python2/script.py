@@ -0,0 +1,89 @@
+import sys
+import json
+import threading
+import test
+
+try:
+  from http.server import BaseHTTPRequestHandler, HTTPServer
+except ImportError:
+  from BaseHTTPServer import BaseHTTPRequestHandler, HTTPServer
+
+def sse(entries):
+  """
+  Encode entries as Server-Sent Events.
+
+  Documents are spread over multiple `data:` fields, interleaved with comments and other fields,
+  and the last event is not terminated by a newline.
+  """
+  out = [": stream of entries\n\n"]
+
+  for i, e in enumerate(entries):
+    if i > 0:
+      out.append("\n\r\n")
+
+    out.append("event: entry\n")
+    out.append("id: {}\n".format(i))
+    out.append(": comment inside of an event\n")
+
+    data = list()
+
+    for n, line in enumerate(json.dumps(e.encode(), indent=2).split("\n")):
+      data.append(("data: " if n % 2 == 0 else "data:") + line)
+
+    out.append("\n:\n".join(data))
+
+  return "".join(out)
+
+def ndjson(entries):
+  """
+  Encode entries as newline-delimited JSON, where the last line is not terminated by a newline.
+  """
+  return "\n\r\n".join(json.dumps(e.encode()) for e in entries)
+
+class Handler(BaseHTTPRequestHandler):
+  def do_GET(self):
+    self.respond("text/event-stream", self.server.sse)
+
+  def do_POST(self):
+    self.rfile.read(int(self.headers.get("Content-Length", 0)))
+    self.respond("application/x-ndjson", self.server.ndjson)
+
+  def respond(self, content_type, body):
+    self.send_response(200)
+    self.send_header("Content-Type", content_type)
+    self.send_header("Connection", "close")
+    self.end_headers()
+
+    body = body.encode("utf-8")
+
+    # Write the body in small pieces, so that lines are split across chunks.
+    for i in range(0, len(body), 7):
+      self.wfile.write(body[i:i + 7])
+      self.wfile.flush()
+
+  def log_message(self, format, *args):
+    pass
+
+entries = [test.Entry.decode(json.loads(line)) for line in sys.stdin]
+
+server = HTTPServer(("127.0.0.1", 0), Handler)
+server.sse = sse(entries)
+server.ndjson = ndjson(entries)
+
+thread = threading.Thread(target=server.serve_forever)
+thread.daemon = True
+thread.start()
+
+client = test.MyService_Requests(url="http://127.0.0.1:{}".format(server.server_address[1]))
+
+events = [e.encode() for e in client.events(42)]
+lines = [e.encode() for e in client.entries(test.Entry(None))]
+
+if lines != events:
+  raise Exception("entries: {!r} != {!r}".format(lines, events))
+
+for e in events:
+  sys.stdout.write("#<>")
+  sys.stdout.write(json.dumps(e))
+  sys.stdout.write("\n")
+  sys.stdout.flush()
@@ -0,0 +1,45 @@
+import asyncio
+import sys
+import json
+import websockets
+import test
+
+async def echo(socket, path=None):
+  """
+  Echo every text message back, preceded by messages which the client is expected to ignore.
+  """
+  async for message in socket:
+    if not isinstance(message, str):
+      continue
+
+    await socket.send(b"\x01\x02\x03")
+    await socket.send(json.dumps(dict(endpoint="other", body=dict(text="ignored"))))
+    await socket.send(message)
+
+async def main(messages):
+  async with websockets.serve(echo, "127.0.0.1", 0) as server:
+    port = server.sockets[0].getsockname()[1]
+
+    client = test.MyService_WebSocket(url="ws://127.0.0.1:{}".format(port))
+    sender, receiver = await client.chat()
+
+    for m in messages:
+      await sender.send(m)
+
+      echoed = await receiver.recv()
+
+      if echoed is None:
+        raise Exception("connection closed before echo")
+
+      sys.stdout.write("#<>")
+      sys.stdout.write(json.dumps(echoed.encode()))
+      sys.stdout.write("\n")
+      sys.stdout.flush()
+
+    await sender.close()
+
+    async for m in receiver:
+      raise Exception("unexpected message after close: {!r}".format(m))
+
+messages = [test.Message.decode(json.loads(line)) for line in sys.stdin]
+asyncio.run(main(messages))
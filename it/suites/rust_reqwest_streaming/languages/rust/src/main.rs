@@ -0,0 +1,159 @@
+use futures::stream::TryStreamExt as _;
+use reproto_it::generated::test::{Entry, MyService_Reqwest};
+use std::io;
+use std::io::{BufRead, BufReader, Read as _, Write as _};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+/// Encode entries as Server-Sent Events.
+///
+/// Documents are spread over multiple `data:` fields, interleaved with comments and other fields,
+/// and the last event is not terminated by a newline.
+fn sse(entries: &[Entry]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut out = String::from(": stream of entries\n\n");
+
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            out.push_str("\n\r\n");
+        }
+
+        out.push_str("event: entry\n");
+        out.push_str(&format!("id: {}\n", i));
+        out.push_str(": comment inside of an event\n");
+
+        let data = serde_json::to_string_pretty(entry)?
+            .lines()
+            .enumerate()
+            .map(|(n, line)| format!("data:{}{}", if n % 2 == 0 { " " } else { "" }, line))
+            .collect::<Vec<_>>();
+
+        out.push_str(&data.join("\n:\n"));
+    }
+
+    Ok(out)
+}
+
+/// Encode entries as newline-delimited JSON, where the last line is not terminated by a newline.
+fn ndjson(entries: &[Entry]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut out = String::new();
+
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            out.push_str("\n\r\n");
+        }
+
+        out.push_str(&serde_json::to_string(entry)?);
+    }
+
+    Ok(out)
+}
+
+/// Serve a single request, responding with the given body and closing the connection.
+fn respond(stream: TcpStream, sse: &str, ndjson: &str) -> io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+
+    let mut content_length = 0;
+
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        let mut parts = header.splitn(2, ':');
+
+        if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or_default();
+            }
+        }
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (content_type, body) = if request.starts_with("POST ") {
+        ("application/x-ndjson", ndjson)
+    } else {
+        ("text/event-stream", sse)
+    };
+
+    let mut stream = reader.into_inner();
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\ncontent-type: {}\r\nconnection: close\r\n\r\n",
+        content_type
+    )?;
+
+    // Write the body in small pieces, so that lines are split across chunks.
+    for chunk in body.as_bytes().chunks(7) {
+        stream.write_all(chunk)?;
+        stream.flush()?;
+    }
+
+    Ok(())
+}
+
+#[tokio::main(basic_scheduler)]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    let mut entries = Vec::new();
+
+    for line in stdin.lock().lines() {
+        entries.push(serde_json::from_str::<Entry>(&line?)?);
+    }
+
+    let sse = sse(&entries)?;
+    let ndjson = ndjson(&entries)?;
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = reqwest::Url::parse(&format!("http://{}", listener.local_addr()?))?;
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if let Err(e) = stream.and_then(|stream| respond(stream, &sse, &ndjson)) {
+                eprintln!("failed to respond: {}", e);
+            }
+        }
+    });
+
+    let client =
+        MyService_Reqwest::new(reqwest::Client::new(), Some(url)).map_err(|e| e.to_string())?;
+
+    let events = client
+        .events(42)
+        .await
+        .map_err(|e| format!("events: {:?}", e))?
+        .try_collect::<Vec<Entry>>()
+        .await
+        .map_err(|e| format!("events: {}", e))?;
+
+    let lines = client
+        .entries(Entry { value: None })
+        .await
+        .map_err(|e| format!("entries: {}", e))?
+        .try_collect::<Vec<Entry>>()
+        .await
+        .map_err(|e| format!("entries: {}", e))?;
+
+    if lines != events {
+        return Err(format!("entries: {:?} != {:?}", lines, events).into());
+    }
+
+    let mut stdout = stdout.lock();
+
+    for entry in events {
+        write!(stdout, "#<>{}\n", serde_json::to_string(&entry)?)?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
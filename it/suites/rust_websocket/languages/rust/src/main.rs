@@ -0,0 +1,82 @@
+use futures::{SinkExt as _, StreamExt as _};
+use reproto_it::generated::test::{Message, MyService_WebSocket};
+use std::io;
+use std::io::{BufRead as _, Write as _};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite;
+
+/// Echo every text message back, preceded by messages which the client is expected to ignore.
+async fn echo(mut listener: TcpListener) -> Result<(), Box<dyn std::error::Error>> {
+    let (socket, _) = listener.accept().await?;
+    let mut socket = tokio_tungstenite::accept_async(socket).await?;
+
+    while let Some(message) = socket.next().await {
+        let text = match message? {
+            tungstenite::Message::Text(text) => text,
+            // The reply to a close is sent while polling for the next message.
+            _ => continue,
+        };
+
+        socket
+            .send(tungstenite::Message::Binary(vec![1, 2, 3]))
+            .await?;
+
+        socket
+            .send(tungstenite::Message::Text(String::from(
+                r#"{"endpoint": "other", "body": {"text": "ignored"}}"#,
+            )))
+            .await?;
+
+        socket.send(tungstenite::Message::Text(text)).await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::main(basic_scheduler)]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+
+    let mut messages = Vec::new();
+
+    for line in stdin.lock().lines() {
+        messages.push(serde_json::from_str::<Message>(&line?)?);
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = url::Url::parse(&format!("ws://{}", listener.local_addr()?))?;
+
+    let server = tokio::spawn(async move {
+        if let Err(e) = echo(listener).await {
+            eprintln!("echo server failed: {}", e);
+        }
+    });
+
+    let client = MyService_WebSocket::new(Some(url)).map_err(|e| e.to_string())?;
+    let (mut sender, mut receiver) = client.chat().await.map_err(|e| e.to_string())?;
+
+    let mut stdout = stdout.lock();
+
+    for message in &messages {
+        sender.send(message).await.map_err(|e| e.to_string())?;
+
+        let echoed = receiver
+            .recv()
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or("connection closed before echo")?;
+
+        write!(stdout, "#<>{}\n", serde_json::to_string(&echoed)?)?;
+        stdout.flush()?;
+    }
+
+    sender.close().await.map_err(|e| e.to_string())?;
+
+    if let Some(message) = receiver.recv().await.map_err(|e| e.to_string())? {
+        return Err(format!("unexpected message after close: {:?}", message).into());
+    }
+
+    server.await?;
+    Ok(())
+}
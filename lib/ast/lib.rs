@@ -34,6 +34,7 @@ impl<'input, T> ops::Deref for Item<'input, T> {
 ///
 /// ```ignore
 /// #[attribute(name = <value>)]
+/// #[attribute(nested(name = <value>))]
 /// ```
 #[derive(Debug, PartialEq, Eq)]
 pub enum AttributeItem<'input> {
@@ -42,6 +43,7 @@ pub enum AttributeItem<'input> {
         name: Spanned<Cow<'input, str>>,
         value: Spanned<Value<'input>>,
     },
+    List(Spanned<Cow<'input, str>>, Vec<AttributeItem<'input>>),
 }
 
 /// An attribute.
@@ -65,7 +67,7 @@ pub enum Attribute<'input> {
 
 /// A type.
 ///
-/// For example: `u32`, `::Relative::Name`, or `bytes`.
+/// For example: `u32`, `::Relative::Name`, `Page<string>`, or `bytes`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Type<'input> {
     Double,
@@ -82,16 +84,38 @@ pub enum Type<'input> {
     Any,
     /// ISO-8601 for date and time.
     DateTime,
+    /// ISO-8601 calendar date, like `2020-06-01`.
+    Date,
+    /// ISO-8601 time of day without a time zone, like `12:30:00`.
+    Time,
+    /// ISO-8601 duration, like `PT1H30M`.
+    Duration,
+    /// UUID in its canonical hyphenated form.
+    Uuid,
+    /// Arbitrary precision decimal number.
+    Decimal,
     Name {
         name: Spanned<Name<'input>>,
+        /// Type arguments, as in `Page<string>`.
+        arguments: Vec<Spanned<Type<'input>>>,
     },
     Array {
         inner: Box<Spanned<Type<'input>>>,
     },
+    /// Set of unique values, as in `#{string}`.
+    Set {
+        inner: Box<Spanned<Type<'input>>>,
+    },
     Map {
         key: Box<Spanned<Type<'input>>>,
         value: Box<Spanned<Type<'input>>>,
     },
+    /// Anonymous union, as in `string | u64`.
+    ///
+    /// Only permitted as the type of a field.
+    Union {
+        alternatives: Vec<Spanned<Type<'input>>>,
+    },
     /// A complete error.
     Error,
 }
@@ -104,6 +128,8 @@ pub enum Decl<'input> {
     Interface(Item<'input, InterfaceBody<'input>>),
     Enum(Item<'input, EnumBody<'input>>),
     Service(Item<'input, ServiceBody<'input>>),
+    Const(Item<'input, ConstBody<'input>>),
+    Alias(Item<'input, AliasBody<'input>>),
 }
 
 impl<'input> Decl<'input> {
@@ -117,6 +143,8 @@ impl<'input> Decl<'input> {
             Interface(ref body) => &body.name,
             Enum(ref body) => &body.name,
             Service(ref body) => &body.name,
+            Const(ref body) => &body.name,
+            Alias(ref body) => &body.name,
         };
 
         Spanned::map(Spanned::as_ref(name), |n| n.as_ref())
@@ -132,6 +160,8 @@ impl<'input> Decl<'input> {
             Interface(ref body) => body.decls(),
             Enum(ref body) => body.decls(),
             Service(ref body) => body.decls(),
+            Const(ref body) => body.decls(),
+            Alias(ref body) => body.decls(),
         };
 
         decls.into_iter()
@@ -147,10 +177,49 @@ impl<'input> Decl<'input> {
             Interface(ref body) => &body.comment,
             Enum(ref body) => &body.comment,
             Service(ref body) => &body.comment,
+            Const(ref body) => &body.comment,
+            Alias(ref body) => &body.comment,
         }
     }
 }
 
+/// The body of a constant declaration.
+///
+/// ```ignore
+/// const <name>: <ty> = <value>;
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstBody<'input> {
+    pub name: Spanned<Cow<'input, str>>,
+    pub ty: Spanned<Type<'input>>,
+    pub value: Spanned<Value<'input>>,
+}
+
+impl<'input> ConstBody<'input> {
+    /// Access all inner declarations.
+    fn decls(&self) -> Vec<&Decl<'input>> {
+        Vec::new()
+    }
+}
+
+/// The body of a type alias.
+///
+/// ```ignore
+/// type <name> = <ty>;
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct AliasBody<'input> {
+    pub name: Spanned<Cow<'input, str>>,
+    pub ty: Spanned<Type<'input>>,
+}
+
+impl<'input> AliasBody<'input> {
+    /// Access all inner declarations.
+    fn decls(&self) -> Vec<&Decl<'input>> {
+        Vec::new()
+    }
+}
+
 /// The body of an enum declaration.
 ///
 /// ```ignore
@@ -192,7 +261,7 @@ pub enum EnumMember<'input> {
 /// A field.
 ///
 /// ```ignore
-/// <name><modifier>: <ty> as <field_as>
+/// <name><modifier>: <ty> as <field_as> = <default>
 /// ```
 #[derive(Debug, PartialEq, Eq)]
 pub struct Field<'input> {
@@ -200,6 +269,8 @@ pub struct Field<'input> {
     pub name: Cow<'input, str>,
     pub ty: Spanned<Type<'input>>,
     pub field_as: Option<String>,
+    /// Default value of the field.
+    pub default: Option<Spanned<Value<'input>>>,
     /// If the end-of-line indicator present.
     /// A `false` value should indicate an error.
     pub endl: bool,
@@ -262,9 +333,12 @@ pub enum Name<'input> {
 ///   <sub_types>
 /// }
 /// ```
+///
+/// Type parameters are declared after the name, as in `interface Shape<T> { .. }`.
 #[derive(Debug, PartialEq, Eq)]
 pub struct InterfaceBody<'input> {
     pub name: Spanned<Cow<'input, str>>,
+    pub type_parameters: Vec<Spanned<Cow<'input, str>>>,
     pub members: Vec<TypeMember<'input>>,
     pub sub_types: Vec<Item<'input, SubType<'input>>>,
 }
@@ -365,6 +439,7 @@ pub enum ServiceMember<'input> {
 #[derive(Debug, PartialEq, Eq)]
 pub struct EndpointArgument<'input> {
     pub ident: Spanned<Cow<'input, str>>,
+    pub required: bool,
     pub channel: Spanned<Channel<'input>>,
 }
 
@@ -472,9 +547,15 @@ impl<'input> TupleBody<'input> {
 ///     <members>
 /// }
 /// ```
+///
+/// Type parameters are declared after the name, as in `type Page<T> { .. }`.
+///
+/// Types to inherit fields from are listed after a colon, as in `type Order: Auditable { .. }`.
 #[derive(Debug, PartialEq, Eq)]
 pub struct TypeBody<'input> {
     pub name: Spanned<Cow<'input, str>>,
+    pub type_parameters: Vec<Spanned<Cow<'input, str>>>,
+    pub parents: Vec<Spanned<Name<'input>>>,
     pub members: Vec<TypeMember<'input>>,
 }
 
@@ -534,13 +615,14 @@ pub struct UseDecl<'input> {
 
 /// A literal value
 ///
-/// For example, `"string"`, `42.0`, and `foo`.
+/// For example, `"string"`, `42.0`, `foo`, `[a, b]`, and `{"key": value}`.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Value<'input> {
     String(String),
     Number(RpNumber),
     Identifier(Cow<'input, str>),
     Array(Vec<Spanned<Value<'input>>>),
+    Object(Vec<(Spanned<String>, Spanned<Value<'input>>)>),
     Name(Spanned<Name<'input>>),
 }
 
@@ -53,8 +53,12 @@ macro_rules! decl_codegen {
 decl_codegen! {
     /// Generator used for classes.
     class<'a> {
+        /// Fields of the class.
+        pub(crate) fields: &'a [Spanned<Field>],
         /// Annotations to add to the class.
         pub(crate) annotations: &'a mut Vec<csharp::Tokens>,
+        /// Inner code generated.
+        pub(crate) inner: &'a mut Vec<csharp::Tokens>,
     }
 
     /// A class field was added.
@@ -80,10 +84,18 @@ decl_codegen! {
 
     /// An enum was added.
     enum_type<'a> {
+        /// The identifier of the enum.
+        pub(crate) ident: &'a str,
         /// Variants associated with the enum.
         pub(crate) variants: &'a RpVariants,
+        /// The type of the enum values.
+        pub(crate) enum_type: &'a Type,
+        /// The unknown variant of the enum, if present.
+        pub(crate) unknown: Option<&'a RpUnknown>,
         /// Annotations to add to the enum typpe.
         pub(crate) annotations: &'a mut Vec<csharp::Tokens>,
+        /// Inner code generated.
+        pub(crate) inner: &'a mut Vec<csharp::Tokens>,
     }
 
     /// An enum variant was added.
@@ -108,6 +120,7 @@ decl_codegen! {
     }
 
     /// Generate code for an interface.
+    #[allow(clippy::too_many_arguments)]
     interface<'a> {
         /// The identifier of the interface.
         pub(crate) ident: &'a str,
@@ -115,6 +128,8 @@ decl_codegen! {
         pub(crate) sub_type_strategy: &'a RpSubTypeStrategy,
         /// All known sub types.
         pub(crate) sub_types: &'a [Spanned<RpSubType>],
+        /// The unknown sub type of the interface, if present.
+        pub(crate) unknown: Option<&'a RpUnknown>,
         /// Annotations generated.
         pub(crate) annotations: &'a mut Vec<csharp::Tokens>,
         /// Annotations to use for the tag.
@@ -123,6 +138,18 @@ decl_codegen! {
         pub(crate) inner: &'a mut Vec<csharp::Tokens>,
     }
 
+    /// Generate code for a union.
+    union<'a> {
+        /// The identifier of the union.
+        pub(crate) ident: &'a str,
+        /// Variants of the union, and the identifier of the class holding each of them.
+        pub(crate) variants: &'a [(String, &'a RpUnionVariant)],
+        /// Annotations generated.
+        pub(crate) annotations: &'a mut Vec<csharp::Tokens>,
+        /// Inner code generated.
+        pub(crate) inner: &'a mut Vec<csharp::Tokens>,
+    }
+
     /// Generate annotations for a tagged constructor.
     interface_tag_constructor_arg<'a> {
         /// The tag.
@@ -4,19 +4,24 @@ use crate::flavored::*;
 use crate::processor::Processor;
 use crate::Options;
 use core::errors::Result;
-use core::{Handle, RelativePathBuf, Spanned};
+use core::{Handle, RelativePathBuf, RpPackage, Span, Spanned};
 use genco::prelude::*;
 use genco::tokens::from_fn;
 use naming::Naming as _;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 use trans::Translated;
 
+/// Name of the class holding top-level constants in each namespace.
+const CONSTANTS: &str = "Constants";
+
 pub struct Compiler {
     opt: Options,
     env: Rc<Translated<CsharpFlavor>>,
     string_builder: csharp::Import,
     string: csharp::Import,
     object: csharp::Import,
+    obsolete: csharp::Import,
     to_upper_snake: naming::ToUpperSnake,
 }
 
@@ -30,22 +35,56 @@ impl Compiler {
             string_builder: csharp::import("System.Text", "StringBuilder"),
             string: csharp::import("System", "String"),
             object: csharp::import("System", "Object"),
+            obsolete: csharp::import("System", "Obsolete"),
             to_upper_snake: naming::to_upper_snake(),
         }
     }
 
     pub(crate) fn compile(&self, handle: &dyn Handle) -> Result<()> {
+        let mut constants = BTreeMap::new();
+
         for decl in self.env.toplevel_decl_iter() {
-            self.compile_decl(handle, decl)?;
+            // top-level constants are grouped into a single class for each namespace.
+            if let RpDecl::Const(body) = decl {
+                constants
+                    .entry(&body.name.package)
+                    .or_insert_with(Vec::new)
+                    .push(body);
+                continue;
+            }
+
+            let file = quote! {
+                #(ref t => self.process_decl(t, decl)?)
+            };
+
+            self.write_file(handle, &decl.name().package, decl.ident(), file)?;
+        }
+
+        for (package, constants) in constants {
+            let file = quote! {
+                public static class #CONSTANTS {
+                    #(for c in constants join (#<line>) {
+                        #(ref t => self.process_const(t, c)?)
+                    })
+                }
+            };
+
+            self.write_file(handle, package, CONSTANTS, file)?;
         }
 
         Ok(())
     }
 
-    fn compile_decl(&self, handle: &dyn Handle, decl: &RpDecl) -> Result<()> {
+    fn write_file(
+        &self,
+        handle: &dyn Handle,
+        package: &RpPackage,
+        ident: &str,
+        file: csharp::Tokens,
+    ) -> Result<()> {
         use genco::fmt;
 
-        let namespace = decl.name().package.join(".");
+        let namespace = package.join(".");
 
         let parts = namespace.split('.').collect::<Vec<_>>();
 
@@ -59,13 +98,9 @@ impl Compiler {
             handle.create_dir_all(&path)?;
         }
 
-        let path = path.join(format!("{}.cs", decl.ident()));
+        let path = path.join(format!("{}.cs", ident));
         log::debug!("+class: {}", path);
 
-        let file = quote! {
-            #(ref t => self.process_decl(t, decl)?)
-        };
-
         let config = csharp::Config::default().with_namespace(namespace);
 
         let fmt = fmt::Config::from_lang::<csharp::Csharp>();
@@ -78,8 +113,24 @@ impl Compiler {
     }
 
     fn process_enum(&self, t: &mut csharp::Tokens, body: &RpEnumBody) -> Result<()> {
+        let enum_type = self.enum_value_type(body.enum_type);
+        let unknown = body.unknown.as_deref();
+
         let mut annotations = Vec::new();
-        self.opt.gen.enum_type(&body.variants, &mut annotations);
+        let mut inner = Vec::new();
+        self.opt.gen.enum_type(
+            &body.ident,
+            &body.variants,
+            &enum_type,
+            unknown,
+            &mut annotations,
+            &mut inner,
+        );
+        self.obsolete(body.deprecated.as_ref(), &mut annotations);
+
+        if let Some(unknown) = unknown {
+            return self.process_open_enum(t, body, &enum_type, unknown, &annotations, &inner);
+        }
 
         quote_in! { *t =>
             #(csharp::block_comment(&body.comment))
@@ -94,12 +145,95 @@ impl Compiler {
         Ok(())
     }
 
+    /// Process an enum with an unknown variant.
+    ///
+    /// Since the value of an unknown variant has to be retained, this is generated as a class
+    /// with one static instance for each known variant.
+    fn process_open_enum(
+        &self,
+        t: &mut csharp::Tokens,
+        body: &RpEnumBody,
+        enum_type: &Type,
+        unknown: &RpUnknown,
+        annotations: &[csharp::Tokens],
+        inner: &[csharp::Tokens],
+    ) -> Result<()> {
+        let ident = &body.ident;
+        let object = &self.object;
+
+        let fields = vec![Spanned::new(
+            Field::required("Value", enum_type.clone()),
+            Span::empty(),
+        )];
+
+        quote_in! { *t =>
+            #(csharp::block_comment(&body.comment))
+            #(for a in annotations join (#<push>) => #a)
+            public class #ident {
+                #(for v in &body.variants join (#<line>) {
+                    #(ref t {
+                        let mut annotations = Vec::new();
+                        self.obsolete(v.deprecated, &mut annotations);
+
+                        quote_in! { *t =>
+                            #(csharp::block_comment(v.comment))
+                            #(for a in annotations join (#<push>) => #a)
+                            public static readonly #ident #(display(self.to_upper_snake.display(v.ident))) = new #ident(#(self.open_variant_value(v)));
+                        }
+                    })
+                })
+
+                public #enum_type Value {
+                    get;
+                }
+
+                private #ident(#enum_type Value) {
+                    this.Value = Value;
+                }
+
+                public static #ident[] Values() {
+                    return new #ident[] {
+                        #(for v in &body.variants join (,#<push>) => #(display(self.to_upper_snake.display(v.ident))))
+                    };
+                }
+
+                #(csharp::block_comment(&unknown.comment))
+                public bool Is#(&unknown.ident) {
+                    get {
+                        foreach (#ident v in Values()) {
+                            if (#object.ReferenceEquals(v, this)) {
+                                return false;
+                            }
+                        }
+
+                        return true;
+                    }
+                }
+
+                #(self.equals(ident, &fields))
+
+                #(self.get_hash_code(&fields))
+
+                #(self.to_string(ident, &fields))
+
+                #(for i in inner join (#<line>) => #i)
+
+                #(for d in &body.decls {
+                    #(ref t => self.process_decl(t, d)?)
+                })
+            }
+        }
+
+        Ok(())
+    }
+
     fn process_tuple(&self, t: &mut csharp::Tokens, body: &RpTupleBody) -> Result<()> {
         let mut annotations = Vec::new();
         let mut inner = Vec::new();
         self.opt
             .gen
             .tuple(&body.ident, &body.fields, &mut annotations, &mut inner);
+        self.obsolete(body.deprecated.as_ref(), &mut annotations);
 
         quote_in! { *t =>
             #(csharp::block_comment(&body.comment))
@@ -128,7 +262,11 @@ impl Compiler {
 
     fn process_type(&self, t: &mut csharp::Tokens, body: &RpTypeBody) -> Result<()> {
         let mut annotations = Vec::new();
-        self.opt.gen.class(&mut annotations);
+        let mut inner = Vec::new();
+        self.opt
+            .gen
+            .class(&body.fields, &mut annotations, &mut inner);
+        self.obsolete(body.deprecated.as_ref(), &mut annotations);
 
         quote_in! { *t =>
             #(csharp::block_comment(&body.comment))
@@ -144,6 +282,8 @@ impl Compiler {
 
                 #(self.to_string(&body.ident, &body.fields))
 
+                #(for i in inner join (#<line>) => #i)
+
                 #(for d in &body.decls {
                     #(ref t => self.process_decl(t, d)?)
                 })
@@ -162,14 +302,16 @@ impl Compiler {
             &body.ident,
             &body.sub_type_strategy,
             &body.sub_types,
+            body.unknown.as_deref(),
             &mut annotations,
             &mut tag_annotations,
             &mut inner,
         );
+        self.obsolete(body.deprecated.as_ref(), &mut annotations);
 
         quote_in! { *t =>
             #(csharp::block_comment(&body.comment))
-            #(for a in annotations => #a)
+            #(for a in annotations join (#<push>) => #a)
             public abstract class #(&body.ident) {
                 #(self.interface_sub_type_strategy(&body.ident, &body.sub_type_strategy, &tag_annotations))
 
@@ -179,6 +321,10 @@ impl Compiler {
                     #(self.sub_type(body, sub_type))
                 })
 
+                #(if let Some(unknown) = &body.unknown {
+                    #(self.unknown_sub_type(body, unknown))
+                })
+
                 #(for d in &body.decls {
                     #(ref t => self.process_decl(t, d)?)
                 })
@@ -192,6 +338,81 @@ impl Compiler {
         Ok(())
     }
 
+    fn process_const(&self, t: &mut csharp::Tokens, body: &RpConstBody) -> Result<()> {
+        let mut annotations = Vec::new();
+        self.obsolete(body.deprecated.as_ref(), &mut annotations);
+
+        quote_in! { *t =>
+            #(csharp::block_comment(&body.comment))
+            #(for a in annotations join (#<push>) => #a)
+            public const #(&body.ty) #(&body.ident) = #(body.ty.literal(&body.value));
+        }
+
+        Ok(())
+    }
+
+    fn process_union(&self, t: &mut csharp::Tokens, body: &RpUnionBody) -> Result<()> {
+        let variants = body
+            .variants
+            .iter()
+            .map(|v| (format!("{}Value", v.ident), v))
+            .collect::<Vec<_>>();
+
+        let mut annotations = Vec::new();
+        let mut inner = Vec::new();
+        self.opt
+            .gen
+            .union(&body.ident, &variants, &mut annotations, &mut inner);
+        self.obsolete(body.deprecated.as_ref(), &mut annotations);
+
+        quote_in! { *t =>
+            #(csharp::block_comment(&body.comment))
+            #(for a in annotations join (#<push>) => #a)
+            public abstract class #(&body.ident) {
+                #(for (ident, v) in &variants join (#<line>) {
+                    #(self.union_variant(&body.ident, ident, v))
+                })
+
+                #(for i in inner join (#<line>) => #i)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generate the class holding a single variant of a union.
+    fn union_variant<'f>(
+        &'f self,
+        union: &'f str,
+        ident: &'f str,
+        variant: &'f RpUnionVariant,
+    ) -> impl FormatInto<Csharp> + 'f {
+        let fields = vec![Spanned::new(
+            Field::required("value", variant.ty.clone()),
+            Span::empty(),
+        )];
+
+        from_fn(move |t| {
+            quote_in! { *t =>
+                public class #ident : #union {
+                    public #(&variant.ty) value {
+                        get;
+                    }
+
+                    public #ident(#(&variant.ty) value) {
+                        this.value = value;
+                    }
+
+                    #(self.equals(ident, &fields))
+
+                    #(self.get_hash_code(&fields))
+
+                    #(self.to_string(ident, &fields))
+                }
+            }
+        })
+    }
+
     fn process_decl(&self, t: &mut csharp::Tokens, decl: &RpDecl) -> Result<()> {
         match decl {
             RpDecl::Interface(interface) => {
@@ -209,6 +430,14 @@ impl Compiler {
             RpDecl::Service(ty) => {
                 self.process_service(t, ty)?;
             }
+            RpDecl::Const(ty) => {
+                self.process_const(t, ty)?;
+            }
+            RpDecl::Union(ty) => {
+                self.process_union(t, ty)?;
+            }
+            // newtype aliases are inlined before compilation.
+            RpDecl::Alias(..) => {}
         }
 
         Ok(())
@@ -253,7 +482,9 @@ impl Compiler {
                 RpSubTypeStrategy::Tagged { tag } => {
                     #(self.tagged_constructor(ident, tag, fields))
                 }
-                RpSubTypeStrategy::Untagged => #(self.constructor(ident, fields)),
+                RpSubTypeStrategy::Adjacent { .. }
+                | RpSubTypeStrategy::External
+                | RpSubTypeStrategy::Untagged => #(self.constructor(ident, fields)),
             })
         }
     }
@@ -271,7 +502,9 @@ impl Compiler {
             .collect::<Vec<_>>();
 
         let mut annotations = Vec::new();
-        self.opt.gen.class(&mut annotations);
+        let mut inner = Vec::new();
+        self.opt.gen.class(&fields, &mut annotations, &mut inner);
+        self.obsolete(sub_type.deprecated.as_ref(), &mut annotations);
 
         quote_fn! {
             #(csharp::block_comment(&sub_type.comment))
@@ -286,6 +519,47 @@ impl Compiler {
                 #(self.get_hash_code(&fields))
 
                 #(self.to_string(&sub_type.ident, &fields))
+
+                #(for i in inner join (#<line>) => #i)
+            }
+        }
+    }
+
+    /// The sub-type holding the raw value of sub-types that are not known.
+    fn unknown_sub_type<'f>(
+        &'f self,
+        body: &'f RpInterfaceBody,
+        unknown: &'f RpUnknown,
+    ) -> impl FormatInto<Csharp> + 'f {
+        let ident = &unknown.ident;
+        let object = &self.object;
+
+        let fields = vec![Spanned::new(
+            Field::required(
+                "Value",
+                Type::Object {
+                    import: Rc::new(self.object.clone()),
+                },
+            ),
+            Span::empty(),
+        )];
+
+        quote_fn! {
+            #(csharp::block_comment(&unknown.comment))
+            public class #ident : #(&body.ident) {
+                public #object Value {
+                    get;
+                }
+
+                public #ident(#object Value)#(if let RpSubTypeStrategy::Tagged { .. } = &body.sub_type_strategy => #<space>: base(null)) {
+                    this.Value = Value;
+                }
+
+                #(self.equals(ident, &fields))
+
+                #(self.get_hash_code(&fields))
+
+                #(self.to_string(ident, &fields))
             }
         }
     }
@@ -309,10 +583,19 @@ impl Compiler {
                     }
                 }
             }
-            RpSubTypeStrategy::Untagged => {}
+            RpSubTypeStrategy::Adjacent { .. }
+            | RpSubTypeStrategy::External
+            | RpSubTypeStrategy::Untagged => {}
         })
     }
 
+    /// Add an `[Obsolete]` attribute if the item is deprecated.
+    fn obsolete(&self, deprecated: Option<&RpDeprecated>, annotations: &mut Vec<csharp::Tokens>) {
+        if let Some(deprecated) = deprecated {
+            annotations.push(quote!([#(&self.obsolete)(#(quoted(deprecated.to_string())))]));
+        }
+    }
+
     /// Format the enum type.
     fn enum_type(&self, enum_type: EnumType) -> impl FormatInto<Csharp> + '_ {
         quote_fn! {
@@ -323,6 +606,27 @@ impl Compiler {
         }
     }
 
+    /// The type of the values of an enum.
+    fn enum_value_type(&self, enum_type: EnumType) -> Type {
+        match enum_type {
+            EnumType::String => Type::String {
+                import: Rc::new(self.string.clone()),
+            },
+            EnumType::Int => Type::Primitive(Primitive::Int),
+            EnumType::Long => Type::Primitive(Primitive::Long),
+        }
+    }
+
+    /// The value of a variant in an enum with an unknown variant.
+    fn open_variant_value<'f>(&'f self, variant: RpVariantRef<'f>) -> impl FormatInto<Csharp> + 'f {
+        quote_fn! {
+            #(match variant.value {
+                RpVariantValue::String(string) => #(quoted(string)),
+                RpVariantValue::Number(number) => #(display(number)),
+            })
+        }
+    }
+
     /// Format a constructor argument.
     fn constructor_arg<'f>(&'f self, f: &'f Spanned<Field>) -> impl FormatInto<Csharp> + 'f {
         let mut ann = Vec::new();
@@ -379,6 +683,7 @@ impl Compiler {
         self.opt
             .gen
             .enum_variant(variant, &mut value, &mut annotations);
+        self.obsolete(variant.deprecated, &mut annotations);
 
         let name = display(self.to_upper_snake.display(&**variant.ident));
 
@@ -392,6 +697,7 @@ impl Compiler {
     fn field<'f>(&'f self, f: &'f Spanned<Field>) -> impl FormatInto<Csharp> + 'f {
         let mut annotations = Vec::new();
         self.opt.gen.class_field(f, &mut annotations);
+        self.obsolete(f.deprecated.as_ref(), &mut annotations);
 
         quote_fn! {
             #(csharp::block_comment(&f.comment))
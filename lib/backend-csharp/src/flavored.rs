@@ -4,13 +4,13 @@
 
 use core::errors::Result;
 use core::{
-    CoreFlavor, Diagnostics, Flavor, FlavorField, FlavorTranslator, PackageTranslator,
+    CoreFlavor, Diagnostics, Flavor, FlavorField, FlavorTranslator, PackageTranslator, RpBytesType,
     RpNumberKind, RpNumberType, RpNumberValidate, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
 use genco::tokens::from_fn;
 use naming::Naming;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use std::rc::Rc;
 use trans::Packages;
@@ -93,6 +93,30 @@ impl Type {
         }
     }
 
+    /// Format a literal value of this type.
+    pub(crate) fn literal(&self, literal: &RpLiteral) -> csharp::Tokens {
+        match (literal, self) {
+            (RpLiteral::String(string), _) => quote!(#(quoted(string))),
+            (RpLiteral::Boolean(boolean), _) => quote!(#(boolean.to_string())),
+            (RpLiteral::Number(number), Type::Primitive(Primitive::Long)) => {
+                quote!(#(number.to_string())L)
+            }
+            (RpLiteral::Number(number), Type::Primitive(Primitive::UInt)) => {
+                quote!(#(number.to_string())U)
+            }
+            (RpLiteral::Number(number), Type::Primitive(Primitive::ULong)) => {
+                quote!(#(number.to_string())UL)
+            }
+            (RpLiteral::Number(number), Type::Primitive(Primitive::Float)) => {
+                quote!(#(number.to_string())F)
+            }
+            (RpLiteral::Number(number), Type::Primitive(Primitive::Double)) => {
+                quote!(#(number.to_string())D)
+            }
+            (RpLiteral::Number(number), _) => quote!(#(number.to_string())),
+        }
+    }
+
     /// Check if the given type is nullable.
     pub(crate) fn is_nullable(&self) -> bool {
         match self {
@@ -146,6 +170,14 @@ pub(crate) struct Field {
 }
 
 impl Field {
+    /// Build a required field with the given identifier and type.
+    pub(crate) fn required(ident: &str, ty: Type) -> Self {
+        Self {
+            var: Rc::new(ident.to_string()),
+            inner: RpField::new(ident, ty),
+        }
+    }
+
     /// Resolve the type of the field.
     pub(crate) fn field_type(&self) -> impl FormatInto<Csharp> + '_ {
         quote_fn! {
@@ -187,6 +219,8 @@ impl Flavor for CsharpFlavor {
 /// Responsible for translating RpType -> Csharp type.
 pub(crate) struct CsharpFlavorTranslator {
     packages: Rc<Packages>,
+    /// Enums with an unknown variant, which are generated as classes.
+    open_enums: HashSet<RpName>,
     object: Rc<csharp::Import>,
     string: Rc<csharp::Import>,
     date_time: Rc<csharp::Import>,
@@ -197,9 +231,10 @@ pub(crate) struct CsharpFlavorTranslator {
 }
 
 impl CsharpFlavorTranslator {
-    pub fn new(packages: Rc<Packages>) -> Self {
+    pub fn new(packages: Rc<Packages>, open_enums: HashSet<RpName>) -> Self {
         Self {
             packages,
+            open_enums,
             object: Rc::new(csharp::import("System", "Object")),
             string: Rc::new(csharp::import("System", "String")),
             date_time: Rc::new(csharp::import("System", "DateTime")),
@@ -275,23 +310,40 @@ impl FlavorTranslator for CsharpFlavorTranslator {
         Ok(Type::dictionary(self.dictionary.clone(), key, value))
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Object {
             import: self.object.clone(),
         })
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::ByteArray)
     }
 
-    fn translate_name(&self, _from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        _from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
+        if !arguments.is_empty() {
+            return Err("generic declarations must be monomorphized".into());
+        }
+
+        let open_enum = self
+            .open_enums
+            .contains(&Spanned::borrow(&name).clone().without_prefix());
         let package_name = Rc::new(name.package.join("."));
         let name = Rc::new(reg.ident(&name, |p| p.join("."), |c| c.join(".")));
 
         let import = csharp::import(package_name, name);
 
-        if reg.is_enum() {
+        if reg.is_enum() && !open_enum {
             return Ok(Type::Enum {
                 import: Rc::new(import),
             });
@@ -300,6 +352,10 @@ impl FlavorTranslator for CsharpFlavorTranslator {
         }
     }
 
+    fn translate_parameter(&self, _: String) -> Result<Type> {
+        Err("generic declarations must be monomorphized".into())
+    }
+
     fn translate_package(&self, source: RpVersionedPackage) -> Result<RpPackage> {
         self.packages.translate_package(source)
     }
@@ -8,9 +8,10 @@ mod processor;
 use crate::compiler::Compiler;
 use crate::options::Options;
 use core::errors::Result;
-use core::{CoreFlavor, Handle};
-use manifest::{checked_modules, Lang, Manifest, NoModule, TryFromToml};
+use core::{CoreFlavor, Handle, PackageTranslator};
+use manifest::{checked_modules, Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
+use std::collections::HashSet;
 use std::path::Path;
 use std::rc::Rc;
 use trans::Session;
@@ -21,6 +22,10 @@ pub struct CsharpLang;
 impl Lang for CsharpLang {
     manifest::lang_base!(CsharpModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Csharp)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("// {}", input))
     }
@@ -156,9 +161,22 @@ fn setup_options<'a>(modules: Vec<CsharpModule>) -> Options {
 }
 
 fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let session = session.monomorphize()?.inline_newtypes()?.lift_unions()?;
     let packages = session.packages()?;
 
-    let session = session.translate(flavored::CsharpFlavorTranslator::new(packages))?;
+    // enums with an unknown variant are generated as classes, and can't be nullable value types.
+    let mut open_enums = HashSet::new();
+
+    for decl in session.for_each_decl() {
+        if let core::RpDecl::Enum(body) = decl {
+            if body.unknown.is_some() {
+                let package = packages.translate_package(body.name.package.clone())?;
+                open_enums.insert(core::RpName::new(None, package, body.name.path.clone()));
+            }
+        }
+    }
+
+    let session = session.translate(flavored::CsharpFlavorTranslator::new(packages, open_enums))?;
     let session = Rc::new(session);
 
     let modules = checked_modules(manifest.modules)?;
@@ -16,6 +16,7 @@ pub fn initialize(opt: &mut Options) {
     opt.gen.tuple.push(codegen.clone());
     opt.gen.interface.push(codegen.clone());
     opt.gen.interface_tag_constructor_arg.push(codegen.clone());
+    opt.gen.union.push(codegen.clone());
 }
 
 struct Codegen {
@@ -34,10 +35,16 @@ struct Codegen {
 
     j_object: csharp::Import,
     j_array: csharp::Import,
+    j_property: csharp::Import,
     j_token: csharp::Import,
+    j_token_type: csharp::Import,
     json_reader: csharp::Import,
     json_writer: csharp::Import,
     json_serializer: csharp::Import,
+    json_token: csharp::Import,
+    culture_info: csharp::Import,
+    format_exception: csharp::Import,
+    string_builder: csharp::Import,
 
     json_sub_types: csharp::Import,
 }
@@ -63,10 +70,16 @@ impl Codegen {
 
             j_object: csharp::import("Newtonsoft.Json.Linq", "JObject"),
             j_array: csharp::import("Newtonsoft.Json.Linq", "JArray"),
+            j_property: csharp::import("Newtonsoft.Json.Linq", "JProperty"),
             j_token: csharp::import("Newtonsoft.Json.Linq", "JToken"),
+            j_token_type: csharp::import("Newtonsoft.Json.Linq", "JTokenType"),
             json_reader: csharp::import("Newtonsoft.Json", "JsonReader"),
             json_writer: csharp::import("Newtonsoft.Json", "JsonWriter"),
             json_serializer: csharp::import("Newtonsoft.Json", "JsonSerializer"),
+            json_token: csharp::import("Newtonsoft.Json", "JsonToken"),
+            culture_info: csharp::import("System.Globalization", "CultureInfo"),
+            format_exception: csharp::import("System", "FormatException"),
+            string_builder: csharp::import("System.Text", "StringBuilder"),
 
             json_sub_types: csharp::import("JsonSubTypes", "JsonSubtypes"),
         }
@@ -78,7 +91,11 @@ impl Codegen {
                 throw new #(&self.invalid_operation_exception)("expected more items in array");
             }
 
-            #(&f.ty) #(f.safe_ident()) = enumerator.Current.ToObject<#(&f.ty)>(serializer);
+            #(if f.bytes_encoding.is_base64() {
+                #(&f.ty) #(f.safe_ident()) = enumerator.Current.ToObject<#(&f.ty)>(serializer);
+            } else {
+                #(&f.ty) #(f.safe_ident()) = Json_Net_BytesConverter.Decode(enumerator.Current.ToObject<string>(serializer), #(quoted(f.bytes_encoding.to_string())));
+            })
         }
     }
 
@@ -97,13 +114,388 @@ impl Codegen {
             }
         }
     }
+
+    /// Generate a converter which encodes bytes with the encoding given to its constructor.
+    fn bytes_converter(&self) -> csharp::Tokens {
+        let object = &self.object;
+        let json_converter = &self.json_converter;
+        let json_reader = &self.json_reader;
+        let json_writer = &self.json_writer;
+        let json_serializer = &self.json_serializer;
+        let json_token = &self.json_token;
+        let format_exception = &self.format_exception;
+        let string_builder = &self.string_builder;
+
+        quote! {
+            public class Json_Net_BytesConverter : #json_converter {
+                private readonly string encoding;
+
+                public Json_Net_BytesConverter(string encoding) {
+                    this.encoding = encoding;
+                }
+
+                public override bool CanConvert(System.Type objectType) {
+                    return objectType == typeof(byte[]);
+                }
+
+                public override void WriteJson(#json_writer writer, #object obj, #json_serializer serializer) {
+                    writer.WriteValue(Encode((byte[])obj, this.encoding));
+                }
+
+                public override #object ReadJson(#json_reader reader, System.Type objectType, #object existingValue, #json_serializer serializer) {
+                    if (reader.TokenType == #json_token.Null) {
+                        return null;
+                    }
+
+                    return Decode((string)reader.Value, this.encoding);
+                }
+
+                public static string Encode(byte[] value, string encoding) {
+                    if (encoding == "hex") {
+                        #string_builder b = new #string_builder(value.Length * 2);
+
+                        foreach (byte v in value) {
+                            b.Append(v.ToString("x2"));
+                        }
+
+                        return b.ToString();
+                    }
+
+                    string base64 = System.Convert.ToBase64String(value).Replace('+', '-').Replace('/', '_');
+                    return encoding == "base64url_nopad" ? base64.TrimEnd('=') : base64;
+                }
+
+                public static byte[] Decode(string value, string encoding) {
+                    if (encoding == "hex") {
+                        if (value.Length % 2 != 0) {
+                            throw new #format_exception("expected an even number of characters");
+                        }
+
+                        byte[] bytes = new byte[value.Length / 2];
+
+                        for (int i = 0; i < bytes.Length; i++) {
+                            bytes[i] = System.Convert.ToByte(value.Substring(i * 2, 2), 16);
+                        }
+
+                        return bytes;
+                    }
+
+                    string base64 = value.Replace('-', '+').Replace('_', '/');
+
+                    switch (base64.Length % 4) {
+                    case 2:
+                        base64 += "==";
+                        break;
+                    case 3:
+                        base64 += "=";
+                        break;
+                    }
+
+                    return System.Convert.FromBase64String(base64);
+                }
+            }
+        }
+    }
+
+    /// Generate a converter which encodes numbers as strings.
+    fn string_converter(&self) -> csharp::Tokens {
+        let object = &self.object;
+        let json_converter = &self.json_converter;
+        let json_reader = &self.json_reader;
+        let json_writer = &self.json_writer;
+        let json_serializer = &self.json_serializer;
+        let json_token = &self.json_token;
+        let culture_info = &self.culture_info;
+
+        quote! {
+            public class Json_Net_StringConverter : #json_converter {
+                public override bool CanConvert(System.Type objectType) {
+                    return true;
+                }
+
+                public override void WriteJson(#json_writer writer, #object obj, #json_serializer serializer) {
+                    writer.WriteValue(System.Convert.ToString(obj, #culture_info.InvariantCulture));
+                }
+
+                public override #object ReadJson(#json_reader reader, System.Type objectType, #object existingValue, #json_serializer serializer) {
+                    if (reader.TokenType == #json_token.Null) {
+                        return null;
+                    }
+
+                    System.Type type = System.Nullable.GetUnderlyingType(objectType) ?? objectType;
+                    return System.Convert.ChangeType(reader.Value, type, #culture_info.InvariantCulture);
+                }
+            }
+        }
+    }
+
+    /// Generate a converter for sub-types which are nested in a wrapping object.
+    ///
+    /// `wrap` adds the serialized sub-type to the wrapping object, and `read` reads the sub-type
+    /// from the wrapping object.
+    fn wrapped_converter<'a, W, R>(
+        &'a self,
+        sub_types: &'a [Spanned<RpSubType>],
+        unknown: Option<&'a RpUnknown>,
+        wrap: W,
+        read: R,
+    ) -> impl FormatInto<Csharp> + 'a
+    where
+        W: 'a + Fn(&'a RpSubType) -> csharp::Tokens,
+        R: 'a + FormatInto<Csharp>,
+    {
+        let object = &self.object;
+        let j_object = &self.j_object;
+        let j_token = &self.j_token;
+        let json_writer = &self.json_writer;
+        let json_reader = &self.json_reader;
+        let json_serializer = &self.json_serializer;
+        let json_converter = &self.json_converter;
+        let invalid_operation_exception = &self.invalid_operation_exception;
+
+        quote_fn! {
+            public class Json_Net_Converter : #json_converter {
+                [ThreadStatic]
+                private static bool _isInside;
+                public override bool CanWrite {
+                    get { return !_isInside; }
+                }
+                public override bool CanRead {
+                    get { return !_isInside; }
+                }
+
+                public override bool CanConvert(System.Type objectType) {
+                    return false;
+                }
+
+                public override void WriteJson(#json_writer writer, #object obj, #json_serializer serializer) {
+                    #(if let Some(unknown) = unknown {
+                        #(self.write_unknown(unknown))
+                        #<line>
+                    })
+                    #j_token value;
+
+                    _isInside = true;
+                    try {
+                        value = #j_token.FromObject(obj, serializer);
+                    } finally {
+                        _isInside = false;
+                    }
+
+                    #j_object o = new #j_object();
+
+                    #(for sub_type in sub_types join (#<line>) {
+                        if (obj is #(&sub_type.ident)) {
+                            #(wrap(sub_type))
+                            o.WriteTo(writer);
+                            return;
+                        }
+                    })
+
+                    throw new #invalid_operation_exception("unsupported sub-type");
+                }
+
+                public override #object ReadJson(#json_reader reader, System.Type objectType, #object existingValue, #json_serializer serializer) {
+                    #j_object o = #j_object.Load(reader);
+
+                    #read
+                }
+
+                private static #object ReadSubType(#j_token value, System.Type type, #json_serializer serializer) {
+                    _isInside = true;
+                    try {
+                        return serializer.Deserialize(value.CreateReader(), type);
+                    } finally {
+                        _isInside = false;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Write the raw value of an unknown sub-type.
+    fn write_unknown<'a>(&'a self, unknown: &'a RpUnknown) -> impl FormatInto<Csharp> + 'a {
+        quote_fn! {
+            if (obj is #(&unknown.ident)) {
+                serializer.Serialize(writer, ((#(&unknown.ident))obj).Value);
+                return;
+            }
+        }
+    }
+
+    /// Read a sub-type which is not known, or fail if the interface has no unknown sub-type.
+    fn read_unknown<'a>(
+        &'a self,
+        unknown: Option<&'a RpUnknown>,
+        tag: impl FormatInto<Csharp> + 'a,
+    ) -> impl FormatInto<Csharp> + 'a {
+        quote_fn! {
+            #(match unknown {
+                Some(unknown) => {
+                    return new #(&unknown.ident)(o);
+                }
+                None => {
+                    throw new #(&self.invalid_operation_exception)("bad type: " + #tag);
+                }
+            })
+        }
+    }
+
+    /// Generate a converter for tagged sub-types, which falls back to the unknown sub-type.
+    fn tagged_converter<'a>(
+        &'a self,
+        tag: &'a str,
+        sub_types: &'a [Spanned<RpSubType>],
+        unknown: &'a RpUnknown,
+    ) -> impl FormatInto<Csharp> + 'a {
+        let object = &self.object;
+        let j_object = &self.j_object;
+        let j_token = &self.j_token;
+        let json_writer = &self.json_writer;
+        let json_reader = &self.json_reader;
+        let json_serializer = &self.json_serializer;
+        let json_converter = &self.json_converter;
+        let invalid_operation_exception = &self.invalid_operation_exception;
+
+        quote_fn! {
+            public class Json_Net_Converter : #json_converter {
+                [ThreadStatic]
+                private static bool _isInside;
+                public override bool CanWrite {
+                    get { return !_isInside; }
+                }
+                public override bool CanRead {
+                    get { return !_isInside; }
+                }
+
+                public override bool CanConvert(System.Type objectType) {
+                    return false;
+                }
+
+                public override void WriteJson(#json_writer writer, #object obj, #json_serializer serializer) {
+                    #(self.write_unknown(unknown))
+
+                    _isInside = true;
+                    try {
+                        serializer.Serialize(writer, obj);
+                    } finally {
+                        _isInside = false;
+                    }
+                }
+
+                public override #object ReadJson(#json_reader reader, System.Type objectType, #object existingValue, #json_serializer serializer) {
+                    #j_object o = #j_object.Load(reader);
+                    #j_token tag = o[#(quoted(tag))];
+
+                    if (tag == null) {
+                        throw new #invalid_operation_exception(#(quoted(format!("expected field `{}`", tag))));
+                    }
+
+                    switch ((string)tag) {
+                    #(for sub_type in sub_types join (#<push>) {
+                        case #(quoted(sub_type.name())):
+                            _isInside = true;
+                            try {
+                                return serializer.Deserialize(o.CreateReader(), typeof(#(&sub_type.ident)));
+                            } finally {
+                                _isInside = false;
+                            }
+                    })
+                    default:
+                        return new #(&unknown.ident)(o);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generate a converter for an enum with an unknown variant, which is encoded as its value.
+    fn open_enum(&self, e: codegen::enum_type::Args<'_>) {
+        let object = &self.object;
+        let json_writer = &self.json_writer;
+        let json_reader = &self.json_reader;
+        let json_serializer = &self.json_serializer;
+        let json_converter = &self.json_converter;
+        let ident = e.ident;
+
+        e.annotations.push(quote! {
+            [#json_converter(typeof(#ident.Json_Net_Converter))]
+        });
+
+        e.inner.push(quote! {
+            public class Json_Net_Converter : #json_converter {
+                public override bool CanConvert(System.Type objectType) {
+                    return objectType == typeof(#ident);
+                }
+
+                public override void WriteJson(#json_writer writer, #object obj, #json_serializer serializer) {
+                    serializer.Serialize(writer, ((#ident)obj).Value);
+                }
+
+                public override #object ReadJson(#json_reader reader, System.Type objectType, #object existingValue, #json_serializer serializer) {
+                    #(e.enum_type) value = serializer.Deserialize<#(e.enum_type)>(reader);
+
+                    foreach (#ident v in #ident.Values()) {
+                        if (v.Value.Equals(value)) {
+                            return v;
+                        }
+                    }
+
+                    return new #ident(value);
+                }
+            }
+        });
+    }
+
+    /// The token types that the given variant of a union is read from.
+    fn union_token_types<'a>(
+        &'a self,
+        variant: &'a RpUnionVariant,
+    ) -> impl FormatInto<Csharp> + 'a {
+        let j_token_type = &self.j_token_type;
+
+        quote_fn! {
+            #(match variant.kind {
+                RpUnionKind::String => {
+                    case #j_token_type.String:
+                    #(if let Type::DateTime { .. } = variant.ty {
+                        #<push>case #j_token_type.Date:
+                    })
+                }
+                RpUnionKind::Integer => {
+                    case #j_token_type.Integer:
+                }
+                RpUnionKind::Number => {
+                    case #j_token_type.Integer:
+                    #<push>case #j_token_type.Float:
+                }
+                RpUnionKind::Boolean => {
+                    case #j_token_type.Boolean:
+                }
+                RpUnionKind::Array => {
+                    case #j_token_type.Array:
+                }
+                RpUnionKind::Object => {
+                    case #j_token_type.Object:
+                }
+            })
+        }
+    }
 }
 
 impl codegen::class::Codegen for Codegen {
     fn generate(&self, e: codegen::class::Args<'_>) {
         e.annotations.push(quote! {
             [#(&self.json_object)(ItemNullValueHandling = #(&self.null_value_handling).Ignore)]
-        })
+        });
+
+        if e.fields.iter().any(|f| f.is_string_encoded()) {
+            e.inner.push(self.string_converter());
+        }
+
+        if e.fields.iter().any(|f| !f.bytes_encoding.is_base64()) {
+            e.inner.push(self.bytes_converter());
+        }
     }
 }
 
@@ -111,7 +503,19 @@ impl codegen::class_field::Codegen for Codegen {
     fn generate(&self, e: codegen::class_field::Args<'_>) {
         e.annotations.push(quote! {
             [#(&self.json_property)(#(quoted(e.field.name())))]
-        })
+        });
+
+        if e.field.is_string_encoded() {
+            e.annotations.push(quote! {
+                [#(&self.json_converter)(typeof(Json_Net_StringConverter))]
+            });
+        }
+
+        if !e.field.bytes_encoding.is_base64() {
+            e.annotations.push(quote! {
+                [#(&self.json_converter)(typeof(Json_Net_BytesConverter), #(quoted(e.field.bytes_encoding.to_string())))]
+            });
+        }
     }
 }
 
@@ -133,6 +537,11 @@ impl codegen::class_constructor_arg::Codegen for Codegen {
 
 impl codegen::enum_type::Codegen for Codegen {
     fn generate(&self, e: codegen::enum_type::Args<'_>) {
+        if e.unknown.is_some() {
+            self.open_enum(e);
+            return;
+        }
+
         match e.variants {
             RpVariants::String { .. } => e.annotations.push(quote! {
                 [#(&self.json_converter)(typeof(#(&self.string_enum_converter)))]
@@ -182,7 +591,15 @@ impl codegen::tuple::Codegen for Codegen {
                     #j_array array = new #j_array();
 
                     #(for f in args.fields join (#<line>) {
-                        array.Add(#j_token.FromObject(o.#(f.safe_ident()), serializer));
+                        #(if f.is_string_encoded() {
+                            array.Add(#j_token.FromObject(System.Convert.ToString(o.#(f.safe_ident()), #(&self.culture_info).InvariantCulture), serializer));
+                        } else {
+                            #(if f.bytes_encoding.is_base64() {
+                                array.Add(#j_token.FromObject(o.#(f.safe_ident()), serializer));
+                            } else {
+                                array.Add(#j_token.FromObject(Json_Net_BytesConverter.Encode(o.#(f.safe_ident()), #(quoted(f.bytes_encoding.to_string()))), serializer));
+                            })
+                        })
                     })
 
                     array.WriteTo(writer);
@@ -200,6 +617,15 @@ impl codegen::tuple::Codegen for Codegen {
                 }
             }
         });
+
+        // fields refer to the converter, even though they are handled by the converter above.
+        if args.fields.iter().any(|f| f.is_string_encoded()) {
+            args.inner.push(self.string_converter());
+        }
+
+        if args.fields.iter().any(|f| !f.bytes_encoding.is_base64()) {
+            args.inner.push(self.bytes_converter());
+        }
     }
 }
 
@@ -211,6 +637,16 @@ impl codegen::interface::Codegen for Codegen {
                     [JsonProperty(#(quoted(tag)), Required = Required.DisallowNull)]
                 });
 
+                if let Some(unknown) = args.unknown {
+                    args.annotations.push(quote! {
+                        [#(&self.json_converter)(typeof(#(args.ident).Json_Net_Converter))]
+                    });
+
+                    args.inner
+                        .push(quote!(#(self.tagged_converter(tag, args.sub_types, unknown))));
+                    return;
+                }
+
                 args.annotations.push(quote! {
                     [#(&self.json_converter)(typeof(#(&self.json_sub_types)), #(quoted(tag)))]
                 });
@@ -221,6 +657,84 @@ impl codegen::interface::Codegen for Codegen {
                     });
                 }
             }
+            RpSubTypeStrategy::Adjacent { tag, content } => {
+                let invalid_operation_exception = &self.invalid_operation_exception;
+
+                args.annotations.push(quote! {
+                    [#(&self.json_converter)(typeof(#(args.ident).Json_Net_Converter))]
+                });
+
+                let wrap = move |sub_type: &RpSubType| {
+                    quote! {
+                        o.Add(#(quoted(tag.as_str())), #(quoted(sub_type.name())));
+                        o.Add(#(quoted(content.as_str())), value);
+                    }
+                };
+
+                let read = quote! {
+                    #(&self.j_token) tag = o[#(quoted(tag.as_str()))];
+                    #(&self.j_token) content = o[#(quoted(content.as_str()))];
+
+                    if (tag == null || content == null) {
+                        throw new #invalid_operation_exception(#(quoted(format!("expected fields `{}` and `{}`", tag, content))));
+                    }
+
+                    switch ((string)tag) {
+                    #(for sub_type in args.sub_types join (#<push>) {
+                        case #(quoted(sub_type.name())):
+                            return ReadSubType(content, typeof(#(&sub_type.ident)), serializer);
+                    })
+                    default:
+                        #(self.read_unknown(args.unknown, quote!(tag)))
+                    }
+                };
+
+                args.inner.push(quote!(#(self.wrapped_converter(
+                    args.sub_types,
+                    args.unknown,
+                    wrap,
+                    read
+                ))));
+            }
+            RpSubTypeStrategy::External => {
+                let invalid_operation_exception = &self.invalid_operation_exception;
+
+                args.annotations.push(quote! {
+                    [#(&self.json_converter)(typeof(#(args.ident).Json_Net_Converter))]
+                });
+
+                let wrap = |sub_type: &RpSubType| {
+                    quote! {
+                        o.Add(#(quoted(sub_type.name())), value);
+                    }
+                };
+
+                let read = quote! {
+                    if (o.Count != 1) {
+                        throw new #invalid_operation_exception("expected exactly one field");
+                    }
+
+                    foreach (#(&self.j_property) property in o.Properties()) {
+                        switch (property.Name) {
+                        #(for sub_type in args.sub_types join (#<push>) {
+                            case #(quoted(sub_type.name())):
+                                return ReadSubType(property.Value, typeof(#(&sub_type.ident)), serializer);
+                        })
+                        default:
+                            #(self.read_unknown(args.unknown, quote!(property.Name)))
+                        }
+                    }
+
+                    throw new #invalid_operation_exception("expected exactly one field");
+                };
+
+                args.inner.push(quote!(#(self.wrapped_converter(
+                    args.sub_types,
+                    args.unknown,
+                    wrap,
+                    read
+                ))));
+            }
             RpSubTypeStrategy::Untagged => {
                 args.annotations.push(quote! {
                     [#(&self.json_converter)(typeof(#(args.ident).Json_Net_Converter))]
@@ -270,3 +784,51 @@ impl codegen::interface_tag_constructor_arg::Codegen for Codegen {
         });
     }
 }
+
+impl codegen::union::Codegen for Codegen {
+    fn generate(&self, args: codegen::union::Args<'_>) {
+        let object = &self.object;
+        let j_token = &self.j_token;
+        let json_writer = &self.json_writer;
+        let json_reader = &self.json_reader;
+        let json_serializer = &self.json_serializer;
+        let json_converter = &self.json_converter;
+        let invalid_operation_exception = &self.invalid_operation_exception;
+
+        args.annotations.push(quote! {
+            [#json_converter(typeof(#(args.ident).Json_Net_Converter))]
+        });
+
+        args.inner.push(quote!{
+            public class Json_Net_Converter : #json_converter {
+                public override bool CanConvert(System.Type objectType) {
+                    return typeof(#(args.ident)).IsAssignableFrom(objectType);
+                }
+
+                public override void WriteJson(#json_writer writer, #object obj, #json_serializer serializer) {
+                    #(for (ident, _) in args.variants join (#<line>) {
+                        if (obj is #ident) {
+                            serializer.Serialize(writer, ((#ident)obj).value);
+                            return;
+                        }
+                    })
+
+                    throw new #invalid_operation_exception("unsupported variant");
+                }
+
+                public override #object ReadJson(#json_reader reader, System.Type objectType, #object existingValue, #json_serializer serializer) {
+                    #j_token token = #j_token.Load(reader);
+
+                    switch (token.Type) {
+                    #(for (ident, v) in args.variants join (#<push>) {
+                        #(self.union_token_types(v))
+                            return new #ident(token.ToObject<#(&v.ty)>(serializer));
+                    })
+                    default:
+                        throw new #invalid_operation_exception("no alternative can be read from: " + token.Type);
+                    }
+                }
+            }
+        });
+    }
+}
@@ -1,15 +1,37 @@
 //! Backend for Dart
 
 use crate::flavored::*;
-use crate::utils::Comments;
+use crate::utils::{Comments, Deprecated};
 use crate::{EXT, TYPE_SEP};
 use backend::PackageProcessor;
 use core::errors::Result;
-use core::{Handle, Spanned};
+use core::{Handle, RpLiteral, Spanned};
 use genco::prelude::*;
 use genco::tokens::ItemStr;
+use naming::Naming;
 use trans::Translated;
 
+/// Convert a literal value into a Dart literal.
+fn literal(literal: &RpLiteral) -> dart::Tokens {
+    match literal {
+        RpLiteral::String(string) => quote!(#(quoted(string))),
+        RpLiteral::Number(number) => quote!(#(number.to_string())),
+        RpLiteral::Boolean(boolean) => quote!(#(boolean.to_string())),
+    }
+}
+
+/// The type that a JSON value of the given kind is decoded as.
+fn union_kind_type(kind: RpUnionKind) -> dart::Tokens {
+    match kind {
+        RpUnionKind::String => quote!(String),
+        RpUnionKind::Integer => quote!(int),
+        RpUnionKind::Number => quote!(num),
+        RpUnionKind::Boolean => quote!(bool),
+        RpUnionKind::Array => quote!(List<dynamic>),
+        RpUnionKind::Object => quote!(Map<String, dynamic>),
+    }
+}
+
 pub struct Compiler<'a> {
     pub env: &'a Translated<DartFlavor>,
     handle: &'a dyn Handle,
@@ -68,6 +90,7 @@ impl<'a> Compiler<'a> {
         quote_in! { *t =>
             #(for field in fields join (#<push>) {
                 #(Comments(&field.comment))
+                #(Deprecated(field.deprecated.as_ref()))
                 #(&field.ty) #(field.safe_ident());
             })
         }
@@ -91,7 +114,7 @@ impl<'a> Compiler<'a> {
                         let id_dyn = &format!("{}_dyn", field.safe_ident());
                         vars.push(id);
 
-                        let (d, e) = field.ty.decode(quote!(#id_dyn));
+                        let (d, e) = decode_field(field, quote!(#id_dyn));
 
                         quote_in!{ *t =>
                             var #id_dyn = _data[#(quoted(field.name()))];
@@ -144,7 +167,7 @@ impl<'a> Compiler<'a> {
                         let id_dyn = &format!("{}_dyn", field.safe_ident());
                         let i = i.to_string();
 
-                        let (d, e) = field.ty.decode(quote!(#id_dyn));
+                        let (d, e) = decode_field(field, quote!(#id_dyn));
 
                         quote_in!{ *t =>
                             var #id_dyn = _data[#i];
@@ -177,6 +200,11 @@ impl<'a> Compiler<'a> {
 
     /// Build a decode function for an interface.
     fn decode_interface_fn(&self, t: &mut dart::Tokens, name: &str, body: &RpInterfaceBody) {
+        let bad_tag = match &body.unknown {
+            Some(unknown) => quote!(return #(self.convert_type_name(&unknown.name))(_data);),
+            None => quote!(throw #_(bad tag: $tag);),
+        };
+
         quote_in! { *t =>
             static #name decode(dynamic _dataDyn) {
                 if (!(_dataDyn is #(&self.map_of_strings))) {
@@ -195,7 +223,36 @@ impl<'a> Compiler<'a> {
                                     return #(self.convert_type_name(&s.name)).decode(_data);
                             })
                             default:
-                                throw #_(bad tag: $tag);
+                                #(&bad_tag)
+                        }
+                    }
+                    RpSubTypeStrategy::Adjacent { tag, content } => {
+                        var tag = _data[#(quoted(tag.as_str()))];
+                        var content = _data[#(quoted(content.as_str()))];
+
+                        switch (tag) {
+                            #(for s in &body.sub_types {
+                                case #(quoted(s.name())):
+                                    return #(self.convert_type_name(&s.name)).decode(content);
+                            })
+                            default:
+                                #(&bad_tag)
+                        }
+                    }
+                    RpSubTypeStrategy::External => {
+                        if (_data.length != 1) {
+                            throw #_(expected exactly one key);
+                        }
+
+                        var tag = _data.keys.first;
+
+                        switch (tag) {
+                            #(for s in &body.sub_types {
+                                case #(quoted(s.name())):
+                                    return #(self.convert_type_name(&s.name)).decode(_data[tag]);
+                            })
+                            default:
+                                #(&bad_tag)
                         }
                     }
                     RpSubTypeStrategy::Untagged => {
@@ -219,25 +276,27 @@ impl<'a> Compiler<'a> {
     }
 
     /// Build an encode function.
+    ///
+    /// `strategy` is the sub-type strategy to encode with, if encoding a sub-type.
     fn encode_fn(
         &self,
         t: &mut dart::Tokens,
         name: &str,
         fields: &[Spanned<RpField>],
-        tag: Option<&str>,
+        strategy: Option<&RpSubTypeStrategy>,
     ) {
         quote_in! { *t =>
             #(&self.map_of_strings) encode() {
                 #(&self.map_of_strings) _data = Map();
 
-                #(if let Some(tag) = tag {
-                    _data[#(quoted(tag))] = #(quoted(name));
+                #(if let Some(RpSubTypeStrategy::Tagged { tag }) = strategy {
+                    _data[#(quoted(tag.as_str()))] = #(quoted(name));
                 })
 
                 #(for field in fields join (#<line>) {
                     #(ref t {
                         let id = &quote!(this.#(field.safe_ident()));
-                        let encoded = field.ty.encode(id.clone());
+                        let encoded = encode_field(field, id.clone());
 
                         quote_in!{ *t =>
                             #(if field.is_optional() {
@@ -251,7 +310,17 @@ impl<'a> Compiler<'a> {
                     })
                 })
 
-                return _data;
+                #(match strategy {
+                    Some(RpSubTypeStrategy::Adjacent { tag, content }) => {
+                        return {#(quoted(tag.as_str())): #(quoted(name)), #(quoted(content.as_str())): _data};
+                    }
+                    Some(RpSubTypeStrategy::External) => {
+                        return {#(quoted(name)): _data};
+                    }
+                    _ => {
+                        return _data;
+                    }
+                })
             }
         }
     }
@@ -262,8 +331,8 @@ impl<'a> Compiler<'a> {
             #(&self.list_of_dynamic) encode() {
                 #(&self.list_of_dynamic) _data = List();
 
-                #(for field in fields {
-                    _data.add(#(field.ty.encode(quote!(this.#(field.safe_ident())))));
+                #(for field in fields join (#<line>) {
+                    _data.add(#(encode_field(field, quote!(this.#(field.safe_ident())))));
                 })
 
                 return _data;
@@ -306,6 +375,7 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
 
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             class #name {
                 #(ref t => self.type_fields(t, &body.fields))
 
@@ -320,11 +390,24 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
         Ok(())
     }
 
+    fn process_const(&self, out: &mut Self::Out, body: &RpConstBody) -> Result<()> {
+        let name = &self.convert_type_name(&body.name);
+
+        quote_in! { *out =>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            const #(&body.ty) #name = #(literal(&body.value));
+        }
+
+        Ok(())
+    }
+
     fn process_enum(&self, out: &mut Self::Out, body: &RpEnumBody) -> Result<()> {
         let name = &self.convert_type_name(&body.name);
 
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             class #name {
                 final _value;
                 const #name._new(this._value);
@@ -333,6 +416,7 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
 
                 #(for v in &body.variants join (#<push>) {
                     #(Comments(v.comment))
+                    #(Deprecated(v.deprecated))
                     #(match v.value {
                         RpVariantValue::String(string) => {
                             static const #(v.ident()) = const #name._new(#(quoted(string)));
@@ -357,7 +441,10 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
                                 return #name.#(v.ident());
                         })
                         default:
-                          throw #_(unexpected #name value: $data);
+                          #(match &body.unknown {
+                              Some(_) => return #name._new(data);,
+                              None => throw #_(unexpected #name value: $data);,
+                          })
                     }
                 }
 
@@ -375,6 +462,7 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
 
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             class #name {
                 #(ref t => self.type_fields(t, &body.fields))
 
@@ -394,6 +482,7 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
 
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             abstract class #super_name {
                 #(ref t => self.decode_interface_fn(t, super_name, body))
 
@@ -411,6 +500,7 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
 
                     quote_in! { *t =>
                         #(Comments(&s.comment))
+                        #(Deprecated(s.deprecated.as_ref()))
                         class #name extends #super_name {
                             #(ref t => self.type_fields(t, &fields))
 
@@ -418,19 +508,7 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
 
                             #(ref t => self.decode_fn(t, name, &fields))
 
-                            #(match &body.sub_type_strategy {
-                                RpSubTypeStrategy::Tagged { tag, .. } => {
-                                    #(ref t => self.encode_fn(
-                                        t,
-                                        s.name(),
-                                        &fields,
-                                        Some(tag.as_str()),
-                                    ))
-                                }
-                                RpSubTypeStrategy::Untagged => {
-                                    #(ref t => self.encode_fn(t, s.name(), &fields, None))
-                                }
-                            })
+                            #(ref t => self.encode_fn(t, s.name(), &fields, Some(&body.sub_type_strategy)))
 
                             #(if backend::code_contains!(&s.codes, RpContext::Dart) {
                                 #(ref t => backend::code_in!(t, &s.codes, RpContext::Dart))
@@ -439,12 +517,109 @@ impl<'el> PackageProcessor<'el, DartFlavor> for Compiler<'el> {
                     }
                 })
             })
+
+            #(if let Some(unknown) = &body.unknown {
+                #(ref t {
+                    let name = &self.convert_type_name(&unknown.name);
+
+                    quote_in! { *t =>
+                        #(Comments(&unknown.comment))
+                        class #name extends #super_name {
+                            #(&self.map_of_strings) data;
+
+                            #name(this.data);
+
+                            #(&self.map_of_strings) encode() {
+                                return data;
+                            }
+                        }
+                    }
+                })
+            })
         }
 
         Ok(())
     }
 
+    fn process_union(&self, out: &mut Self::Out, body: &RpUnionBody) -> Result<()> {
+        let name = &self.convert_type_name(&body.name);
+        let to_lower_camel = naming::to_lower_camel();
+
+        // members are suffixed, since `double` or `bool` would otherwise shadow the types.
+        let variants = body
+            .variants
+            .iter()
+            .map(|v| (format!("{}Value", to_lower_camel.convert(&v.ident)), v))
+            .collect::<Vec<_>>();
+
+        // exactly one member is set, which is the alternative holding the value.
+        quote_in! { *out =>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            class #name {
+                #(for (ident, v) in &variants join (#<push>) {
+                    #(&v.ty) #ident;
+                })
+
+                #name({#(for (ident, _) in &variants join (, ) => this.#ident)});
+
+                static #name decode(dynamic _data) {
+                    #(for (ident, v) in &variants join (#<line>) {
+                        #(ref t {
+                            let (d, e) = v.ty.decode(quote!(_data));
+
+                            quote_in! { *t =>
+                                if (_data is #(union_kind_type(v.kind))) {
+                                    #e
+                                    return #name(#ident: #d);
+                                }
+                            }
+                        })
+                    })
+
+                    throw "no alternative of the union matches the value";
+                }
+
+                dynamic encode() {
+                    #(for (ident, v) in &variants join (#<line>) {
+                        if (this.#ident != null) {
+                            return #(v.ty.encode(quote!(this.#ident)));
+                        }
+                    })
+
+                    throw "no alternative of the union is set";
+                }
+            }
+        };
+
+        Ok(())
+    }
+
     fn process_service(&self, _: &mut Self::Out, _: &RpServiceBody) -> Result<()> {
         Ok(())
     }
 }
+
+/// Encode the value of a field.
+fn encode_field(field: &RpField, i: dart::Tokens) -> dart::Tokens {
+    if field.is_string_encoded() {
+        return quote!(#i.toString());
+    }
+
+    field.ty.encode(i)
+}
+
+/// Decode the value of a field, see `Type::decode`.
+fn decode_field(field: &RpField, i: dart::Tokens) -> (dart::Tokens, dart::Tokens) {
+    if field.is_string_encoded() {
+        let e = quote! {
+            if (!(#(&i) is String)) {
+                throw #_(expected String, but was: $(#(&i)));
+            }
+        };
+
+        return (quote!(int.parse(#i)), e);
+    }
+
+    field.ty.decode(i)
+}
@@ -5,8 +5,8 @@
 use crate::{EXT, TYPE_SEP};
 use core::errors::Result;
 use core::{
-    self, CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpNumberKind,
-    RpNumberType, RpStringType, Spanned, Translate, Translator,
+    self, CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpBytesType,
+    RpNumberKind, RpNumberType, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr};
@@ -82,8 +82,9 @@ impl Type {
             Type::Bool => i,
             Type::String => i,
             Type::Map { key, value } => {
+                let k = key.encode_key(quote!(e.key));
                 let d = value.encode(quote!(e.value));
-                quote!(Map.fromEntries(#i.entries.map((e) => MapEntry(e.key, #d))))
+                quote!(Map.fromEntries(#i.entries.map((e) => MapEntry(#k, #d))))
             }
             Type::List { argument } => {
                 let d = argument.encode(quote!(e));
@@ -92,6 +93,23 @@ impl Type {
         }
     }
 
+    /// Create an encode function for a map key, which is always a string in JSON.
+    fn encode_key(&self, i: dart::Tokens) -> dart::Tokens {
+        match self {
+            Type::Int => quote!(#i.toString()),
+            key => key.encode(i),
+        }
+    }
+
+    /// Create a decode function for a map key, which is always a string in JSON.
+    fn decode_key(&self, i: dart::Tokens) -> dart::Tokens {
+        match self {
+            Type::Int => quote!(int.parse(#i)),
+            Type::Import { .. } | Type::Local { .. } => self.decode(i).0,
+            _ => i,
+        }
+    }
+
     /// Create a decode function appropriate for this type.
     /// The first tuple element returned is the decoding procedure of the argument.
     /// The second optional tuple element is extra validation that needs to be evaluated.
@@ -105,20 +123,21 @@ impl Type {
             }
             Type::Map { key, value } => {
                 let i = &i;
+                let k = key.decode_key(quote!(e.key));
                 let (d, e) = value.decode(quote!(e.value));
 
                 let t = if e.is_empty() {
-                    quote!(Map.fromEntries((#i as Map<#(&**key), dynamic>).entries.map((e) => MapEntry(e.key, #d))))
+                    quote!(Map.fromEntries((#i as Map<String, dynamic>).entries.map((e) => MapEntry(#k, #d))))
                 } else {
                     quote! {
-                        Map.fromEntries((#i as Map<#(&**key), dynamic>).entries.map((e) {
-                            return MapEntry(e.key, #d);
+                        Map.fromEntries((#i as Map<String, dynamic>).entries.map((e) {
+                            return MapEntry(#k, #d);
                         }))
                     }
                 };
 
                 let e = quote! {
-                    if (!(#i is Map<#(&**key), dynamic>)) {
+                    if (!(#i is Map<String, dynamic>)) {
                         throw #_(expected map, but was: $(#i));
                     }
                 };
@@ -243,15 +262,29 @@ impl FlavorTranslator for DartFlavorTranslator {
         })
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Dynamic)
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::String)
     }
 
-    fn translate_name(&self, from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
+        if !arguments.is_empty() {
+            return Err("generic declarations must be monomorphized".into());
+        }
+
         let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |c| c.join(TYPE_SEP));
 
         if let Some(ref prefix) = name.prefix {
@@ -270,6 +303,10 @@ impl FlavorTranslator for DartFlavorTranslator {
         });
     }
 
+    fn translate_parameter(&self, _: String) -> Result<Type> {
+        Err("generic declarations must be monomorphized".into())
+    }
+
     fn translate_endpoint<T>(
         &self,
         translator: &T,
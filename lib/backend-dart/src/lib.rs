@@ -5,7 +5,7 @@ mod utils;
 use crate::compiler::Compiler;
 use core::errors::Result;
 use core::{CoreFlavor, Handle};
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::path::Path;
 use trans::Session;
@@ -19,6 +19,10 @@ pub struct DartLang;
 impl Lang for DartLang {
     manifest::lang_base!(DartModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Dart)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("// {}", input))
     }
@@ -108,6 +112,7 @@ impl TryFromToml for DartModule {
 
 fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest) -> Result<()> {
     let _: Vec<DartModule> = manifest::checked_modules(manifest.modules)?;
+    let session = session.monomorphize()?.inline_newtypes()?.lift_unions()?;
     let packages = session.packages()?;
     let session = session.translate(flavored::DartFlavorTranslator::new(packages.clone()))?;
 
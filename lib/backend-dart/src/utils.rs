@@ -1,5 +1,6 @@
+use core::RpDeprecated;
 use genco::prelude::*;
-use genco::tokens::{static_literal, FormatInto, ItemStr};
+use genco::tokens::{quoted, static_literal, FormatInto, ItemStr};
 
 /// Documentation comments.
 pub struct Comments<I>(pub I);
@@ -25,3 +26,15 @@ where
         }
     }
 }
+
+/// `@Deprecated` annotation, if the item is deprecated.
+pub struct Deprecated<'a>(pub Option<&'a RpDeprecated>);
+
+impl<'a> FormatInto<Dart> for Deprecated<'a> {
+    fn format_into(self, t: &mut Tokens<Dart>) {
+        if let Some(deprecated) = self.0 {
+            t.push();
+            quote_in!(*t => @Deprecated(#(quoted(deprecated.to_string()))));
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Processor for newtype aliases.
+
+use crate::doc_builder::DocBuilder;
+use crate::macros::FormatAttribute;
+use crate::processor::Processor;
+use core::errors::Result;
+use core::flavored::*;
+
+define_processor!(AliasProcessor, RpAliasBody, self,
+    process => {
+        self.write_doc(|| {
+            let id = self.body.name.join("_");
+
+            html!(self, section {id => &id, class => "section-content section-alias"} => {
+                self.section_title("newtype", &self.body.name)?;
+                self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
+
+                html!(self, div {class => "section-body"} => {
+                    html!(self, h2 {class => "alias-title"} => {
+                        html!(self, span {class => "keyword"} ~ "=");
+                        self.write_type(&self.body.ty)?;
+                    });
+                });
+            });
+
+            Ok(())
+        })
+    };
+
+    current_package => &self.body.name.package;
+);
+
+impl<'p> AliasProcessor<'p> {}
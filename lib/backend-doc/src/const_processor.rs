@@ -0,0 +1,37 @@
+//! Processor for constant declarations.
+
+use crate::doc_builder::DocBuilder;
+use crate::escape::Escape;
+use crate::macros::FormatAttribute;
+use crate::processor::Processor;
+use core::errors::Result;
+use core::flavored::*;
+
+define_processor!(ConstProcessor, RpConstBody, self,
+    process => {
+        self.write_doc(|| {
+            let id = self.body.name.join("_");
+
+            html!(self, section {id => &id, class => "section-content section-const"} => {
+                self.section_title("const", &self.body.name)?;
+                self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
+
+                html!(self, div {class => "section-body"} => {
+                    html!(self, h2 {class => "const-title"} => {
+                        self.write_type(&self.body.ty)?;
+                        html!(self, span {class => "keyword"} ~ "=");
+                        html!(self, span {class => "const-value"} ~
+                              Escape(self.body.value.to_string().as_str()));
+                    });
+                });
+            });
+
+            Ok(())
+        })
+    };
+
+    current_package => &self.body.name.package;
+);
+
+impl<'p> ConstProcessor<'p> {}
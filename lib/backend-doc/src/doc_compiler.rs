@@ -1,6 +1,8 @@
 //! Compiler for generating documentation.
 
 use super::{DOC_CSS_NAME, NORMALIZE_CSS_NAME};
+use crate::alias_processor::AliasProcessor;
+use crate::const_processor::ConstProcessor;
 use crate::doc_builder::DocBuilder;
 use crate::enum_processor::EnumProcessor;
 use crate::index_processor::{Data as IndexData, IndexProcessor};
@@ -126,6 +128,24 @@ impl<'a> DocCompiler<'a> {
                 body,
             }
             .process(),
+            RpDecl::Const(ref body) => ConstProcessor {
+                out,
+                session: &self.session,
+                syntax: (self.syntax_theme, self.syntax_set),
+                root: &root,
+                body,
+            }
+            .process(),
+            RpDecl::Alias(ref body) => AliasProcessor {
+                out,
+                session: &self.session,
+                syntax: (self.syntax_theme, self.syntax_set),
+                root: &root,
+                body,
+            }
+            .process(),
+            // unions are documented as part of the field that they are declared in.
+            RpDecl::Union(..) => Ok(()),
         }
     }
 
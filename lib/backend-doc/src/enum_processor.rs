@@ -15,6 +15,7 @@ define_processor!(EnumProcessor, RpEnumBody, self,
             html!(self, section {id => &id, class => "section-content section-enum"} => {
                 self.section_title("enum", &self.body.name)?;
                 self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
 
                 self.variants_overview(&self.body.variants)?;
                 self.nested_decls_overview(&self.body.decls)?;
@@ -24,6 +25,11 @@ define_processor!(EnumProcessor, RpEnumBody, self,
                     self.variants(&self.body.variants)?;
                 }
 
+                if let Some(unknown) = &self.body.unknown {
+                    html!(self, h2 {} ~ "Unknown");
+                    self.unknown(&self.body.name, unknown)?;
+                }
+
                 if !self.body.decls.is_empty() {
                     html!(self, h2 {} ~ "Nested");
                     self.nested_decls(&self.body.decls)?;
@@ -69,6 +75,7 @@ impl<'p> EnumProcessor<'p> {
             });
 
             self.doc(variant.comment)?;
+            self.deprecated(variant.deprecated)?;
         }
 
         Ok(())
@@ -12,8 +12,13 @@ define_processor!(InterfaceProcessor, RpInterfaceBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-interface"} => {
-                self.section_title("interface", &self.body.name)?;
+                self.generic_section_title(
+                    "interface",
+                    &self.body.name,
+                    &self.body.type_parameters,
+                )?;
                 self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
 
                 self.fields_overview(&self.body.fields)?;
 
@@ -35,6 +40,11 @@ define_processor!(InterfaceProcessor, RpInterfaceBody, self,
                     }
                 }
 
+                if let Some(unknown) = &self.body.unknown {
+                    html!(self, h2 {} ~ "Unknown");
+                    self.unknown(&self.body.name, unknown)?;
+                }
+
                 if !self.body.decls.is_empty() {
                     html!(self, h2 {} ~ "Nested");
                     self.nested_decls(&self.body.decls)?;
@@ -58,6 +68,7 @@ impl<'p> InterfaceProcessor<'p> {
         });
 
         self.doc(&self.body.comment)?;
+        self.deprecated(sub_type.deprecated.as_ref())?;
 
         let fields = self.body.fields.iter().chain(sub_type.fields.iter());
         self.fields(fields)?;
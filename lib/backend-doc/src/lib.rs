@@ -2,6 +2,8 @@
 
 #[macro_use]
 mod macros;
+mod alias_processor;
+mod const_processor;
 mod doc_builder;
 mod doc_compiler;
 mod enum_processor;
@@ -44,6 +44,8 @@ define_processor!(PackageProcessor, Data<'session>, self,
             let mut enums = Vec::new();
             let mut tuples = Vec::new();
             let mut services = Vec::new();
+            let mut consts = Vec::new();
+            let mut aliases = Vec::new();
 
             for decl in self.body.file.for_each_decl() {
                 match *decl {
@@ -52,6 +54,9 @@ define_processor!(PackageProcessor, Data<'session>, self,
                     RpDecl::Enum(ref en) => enums.push(en),
                     RpDecl::Tuple(ref tuple) => tuples.push(tuple),
                     RpDecl::Service(ref service) => services.push(service),
+                    RpDecl::Const(ref c) => consts.push(c),
+                    RpDecl::Alias(ref alias) => aliases.push(alias),
+                    RpDecl::Union(..) => {}
                 }
             }
 
@@ -69,6 +74,8 @@ define_processor!(PackageProcessor, Data<'session>, self,
                 types_section!(self, enums, "Enums");
                 types_section!(self, tuples, "Tuples");
                 types_section!(self, services, "Services");
+                types_section!(self, consts, "Constants");
+                types_section!(self, aliases, "Newtypes");
             });
 
             Ok(())
@@ -7,7 +7,7 @@ use crate::macros::FormatAttribute;
 use crate::rendering::markdown_to_html;
 use core::errors::Result;
 use core::flavored::*;
-use core::{AsPackage, CoreFlavor, Spanned};
+use core::{AsPackage, CoreFlavor, RpDeprecated, Spanned};
 use std::ops::DerefMut;
 use syntect::highlighting::Theme;
 use syntect::parsing::SyntaxSet;
@@ -100,6 +100,15 @@ pub trait Processor<'session> {
         Ok(())
     }
 
+    /// Render a deprecation notice, if present.
+    fn deprecated(&self, deprecated: Option<&RpDeprecated>) -> Result<()> {
+        if let Some(deprecated) = deprecated {
+            html!(self, div {class => "deprecated"} ~ Escape(deprecated.to_string().as_str()));
+        }
+
+        Ok(())
+    }
+
     fn primitive(&self, name: &str) -> Result<()> {
         html!(self, span {class => format!("type-{} type-primitive", name)} ~ name);
         Ok(())
@@ -114,13 +123,37 @@ pub trait Processor<'session> {
             RpType::Boolean => self.primitive("boolean")?,
             RpType::String(..) => self.primitive("string")?,
             RpType::DateTime => self.primitive("datetime")?,
-            RpType::Bytes => self.primitive("bytes")?,
+            RpType::Date => self.primitive("date")?,
+            RpType::Time => self.primitive("time")?,
+            RpType::Duration => self.primitive("duration")?,
+            RpType::Uuid => self.primitive("uuid")?,
+            RpType::Decimal => self.primitive("decimal")?,
+            RpType::Bytes(..) => self.primitive("bytes")?,
             RpType::Any => self.primitive("any")?,
             RpType::Number(number) => self.primitive(number.to_string().as_str())?,
-            RpType::Name { name } => {
+            RpType::Name { name, arguments } => {
                 html!(self, span {class => "type-rp-name"} => {
                     self.full_name_without_package(name)?;
                 });
+
+                if !arguments.is_empty() {
+                    html!(self, span {class => "type-arguments"} => {
+                        html!(self, span {class => "type-arguments-left"} ~ "&lt;");
+
+                        for (i, argument) in arguments.iter().enumerate() {
+                            if i > 0 {
+                                html!(self, span {class => "type-arguments-sep"} ~ ",");
+                            }
+
+                            self.write_type(argument)?;
+                        }
+
+                        html!(self, span {class => "type-arguments-right"} ~ "&gt;");
+                    });
+                }
+            }
+            RpType::Parameter { ident } => {
+                html!(self, span {class => "type-parameter"} ~ Escape(ident.as_str()));
             }
             RpType::Array { inner } => {
                 html!(self, span {class => "type-array"} => {
@@ -129,6 +162,13 @@ pub trait Processor<'session> {
                     html!(self, span {class => "type-array-right"} ~ "]");
                 });
             }
+            RpType::Set { inner } => {
+                html!(self, span {class => "type-set"} => {
+                    html!(self, span {class => "type-set-left"} ~ "#{");
+                    self.write_type(inner)?;
+                    html!(self, span {class => "type-set-right"} ~ "}");
+                });
+            }
             RpType::Map { key, value } => {
                 html!(self, span {class => "type-map"} => {
                     html!(self, span {class => "type-map-left"} ~ "{");
@@ -138,6 +178,17 @@ pub trait Processor<'session> {
                     html!(self, span {class => "type-map-right"} ~ "}");
                 });
             }
+            RpType::Union { alternatives } => {
+                html!(self, span {class => "type-union"} => {
+                    for (i, alternative) in alternatives.iter().enumerate() {
+                        if i > 0 {
+                            html!(self, span {class => "type-union-sep"} ~ "|");
+                        }
+
+                        self.write_type(alternative)?;
+                    }
+                });
+            }
         }
 
         write!(self.out(), "</span>")?;
@@ -172,6 +223,11 @@ pub trait Processor<'session> {
                 html!(self, span {class => "keyword"} ~ "as");
                 html!(self, span {class => "field-name"} ~ Escape(field.name()));
             }
+
+            if let Some(default) = field.default.as_ref() {
+                html!(self, span {class => "keyword"} ~ "=");
+                html!(self, span {class => "field-default"} ~ Escape(default.to_string().as_str()));
+            }
         });
 
         self.doc(field.comment.iter().take(1))?;
@@ -217,9 +273,26 @@ pub trait Processor<'session> {
                 html!(self, span {class => "keyword"} ~ "as");
                 html!(self, span {class => "field-name"} ~ Escape(field.name()));
             }
+
+            if let Some(default) = field.default.as_ref() {
+                html!(self, span {class => "keyword"} ~ "=");
+                html!(self, span {class => "field-default"} ~ Escape(default.to_string().as_str()));
+            }
         });
 
+        if let Some(inherited) = field.inherited.as_ref() {
+            html!(self, div {class => "field-inherited"} => {
+                html!(self, span {} ~ "Inherited from");
+                self.full_name_without_package(inherited)?;
+            });
+        }
+
+        for check in field.validate.checks() {
+            html!(self, div {class => "field-validate"} ~ Escape(check.to_string().as_str()));
+        }
+
         self.doc(&field.comment)?;
+        self.deprecated(field.deprecated.as_ref())?;
 
         Ok(())
     }
@@ -281,11 +354,39 @@ pub trait Processor<'session> {
         Ok(())
     }
 
+    /// Render the unknown member of the enum or interface named `parent`.
+    fn unknown(&self, parent: &RpName, unknown: &RpUnknown) -> Result<()> {
+        html!(self, h3 {id => format!("unknown.{}", unknown.ident)} => {
+            html!(self, span {class => "kind"} ~ "unknown");
+            self.full_name_without_package(parent)?;
+            html!(self, span {class => "name-sep"} ~ "::");
+            html!(self, span {class => "name-local"} ~ &unknown.ident);
+        });
+
+        self.doc(&unknown.comment)?;
+        Ok(())
+    }
+
     /// Write a section title.
     fn section_title(&self, kind: &str, name: &RpName) -> Result<()> {
+        self.generic_section_title(kind, name, &[])
+    }
+
+    /// Section title for declarations which might have type parameters.
+    fn generic_section_title(
+        &self,
+        kind: &str,
+        name: &RpName,
+        type_parameters: &[String],
+    ) -> Result<()> {
         html!(self, h1 {class => "section-title"} => {
             html!(self, span {class => "kind"} ~ kind);
             self.full_name(name, Some(name))?;
+
+            if !type_parameters.is_empty() {
+                let type_parameters = format!("<{}>", type_parameters.join(", "));
+                html!(self, span {class => "type-parameters"} ~ Escape(type_parameters.as_str()));
+            }
         });
 
         Ok(())
@@ -17,6 +17,7 @@ define_processor!(ServiceProcessor, RpServiceBody, self,
                 self.section_title("service", &self.body.name)?;
 
                 self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
 
                 for endpoint in &self.body.endpoints {
                     self.endpoint(endpoint)?;
@@ -83,6 +84,7 @@ impl<'p> ServiceProcessor<'p> {
         });
 
         self.doc(&endpoint.comment)?;
+        self.deprecated(endpoint.deprecated.as_ref())?;
         Ok(())
     }
 }
@@ -14,6 +14,7 @@ define_processor!(TupleProcessor, RpTupleBody, self,
             html!(self, section {id => &id, class => "section-content section-tuple"} => {
                 self.section_title("tuple", &self.body.name)?;
                 self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
 
                 html!(self, div {class => "section-body"} => {
                     self.fields_overview(&self.body.fields)?;
@@ -12,8 +12,13 @@ define_processor!(TypeProcessor, RpTypeBody, self,
             let id = self.body.name.join("_");
 
             html!(self, section {id => &id, class => "section-content section-type"} => {
-                self.section_title("type", &self.body.name)?;
+                self.generic_section_title(
+                    "type",
+                    &self.body.name,
+                    &self.body.type_parameters,
+                )?;
                 self.doc(&self.body.comment)?;
+                self.deprecated(self.body.deprecated.as_ref())?;
 
                 html!(self, div {class => "section-body"} => {
                     self.fields_overview(&self.body.fields)?;
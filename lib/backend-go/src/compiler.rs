@@ -1,12 +1,16 @@
 //! Backend for Go
 
 use crate::flavored::*;
-use crate::{EnumAdded, FieldAdded, FileSpec, InterfaceAdded, Options, Tags, TupleAdded, EXT};
+use crate::{
+    EnumAdded, FieldAdded, FileSpec, InterfaceAdded, Options, StructAdded, Tags, TupleAdded,
+    UnionAdded, EXT,
+};
 use backend::PackageProcessor;
 use core::errors::Result;
-use core::{Handle, RelativePathBuf, Spanned};
+use core::{Handle, RelativePathBuf, RpCheck, RpLiteral, Spanned};
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr};
+use std::iter::once;
 use trans::{self, Translated};
 
 /// Documentation comments.
@@ -27,6 +31,44 @@ where
     }
 }
 
+/// Deprecation notice, using the `Deprecated:` paragraph convention.
+pub struct Deprecated<'a>(pub Option<&'a RpDeprecated>);
+
+impl<'a> FormatInto<Go> for Deprecated<'a> {
+    fn format_into(self, t: &mut Tokens<Go>) {
+        let deprecated = match self.0 {
+            Some(deprecated) => deprecated,
+            None => return,
+        };
+
+        let line = match (&deprecated.note, &deprecated.since) {
+            (Some(note), Some(since)) => format!("Deprecated: {} (since {})", note, since),
+            (Some(note), None) => format!("Deprecated: {}", note),
+            (None, Some(since)) => format!("Deprecated: since {}", since),
+            (None, None) => String::from("Deprecated: do not use."),
+        };
+
+        t.push();
+        t.append(ItemStr::Static("//"));
+        t.space();
+        t.append(line);
+    }
+}
+
+/// Convert a literal value into a Go literal.
+fn literal(literal: &RpLiteral) -> Tokens<Go> {
+    match literal {
+        RpLiteral::String(string) => quote!(#(quoted(string))),
+        RpLiteral::Number(number) => quote!(#(number.to_string())),
+        RpLiteral::Boolean(boolean) => quote!(#(boolean.to_string())),
+    }
+}
+
+/// The package-level variable holding the compiled pattern of a field.
+fn pattern_ident(name: &GoName, field: &RpField) -> Tokens<Go> {
+    quote!(#(name)_#(field.safe_ident())Pattern)
+}
+
 pub(crate) struct Compiler<'a> {
     pub(crate) env: &'a Translated<GoFlavor>,
     options: Options,
@@ -51,10 +93,12 @@ impl<'a> Compiler<'a> {
         t: &mut Tokens<Go>,
         name: &GoName,
         comment: &[String],
+        deprecated: Option<&RpDeprecated>,
         fields: &[Spanned<RpField>],
     ) -> Result<()> {
         quote_in! { *t =>
             #(Comments(comment))
+            #(Deprecated(deprecated))
             type #name struct {
                 #(for f in fields.into_iter() join (#<push>) {
                     #(ref t => {
@@ -69,6 +113,7 @@ impl<'a> Compiler<'a> {
 
                         quote_in! { *t =>
                             #(Comments(&f.comment))
+                            #(Deprecated(f.deprecated.as_ref()))
                             #(f.safe_ident()) #(if f.is_optional() {
                                 *#(&f.ty)
                             } else {
@@ -80,6 +125,138 @@ impl<'a> Compiler<'a> {
             }
         };
 
+        if fields.iter().any(|f| !f.validate.is_empty()) {
+            // patterns are compiled once, rather than every time a value is validated.
+            for f in fields {
+                for check in f.validate.checks() {
+                    if let RpCheck::Pattern(pattern) = check {
+                        let must_compile = go::import("regexp", "MustCompile");
+                        let pattern = quoted(format!("^(?:{})$", pattern));
+
+                        quote_in! { *t =>
+                            #<line>
+                            var #(pattern_ident(name, f)) = #must_compile(#pattern)
+                        }
+                    }
+                }
+            }
+
+            quote_in! { *t =>
+                #<line>
+                #(format!("// Validate checks that the fields of {} satisfy their validation rules.", name.name))
+                func (this *#name) Validate() error {
+                    #(for f in fields join (#<push>) {
+                        #(if !f.validate.is_empty() {
+                            #(if f.is_optional() {
+                                if this.#(f.safe_ident()) != nil {
+                                    value := *this.#(f.safe_ident())
+                                    #(ref t => self.checks(t, name, f))
+                                }
+                            } else {
+                                {
+                                    value := this.#(f.safe_ident())
+                                    #(ref t => self.checks(t, name, f))
+                                }
+                            })
+                        })
+                    })
+
+                    return nil
+                }
+            }
+        }
+
+        for g in &self.options.struct_gens {
+            g.generate(StructAdded {
+                container: t,
+                name,
+                fields,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Check the validation rules of a single field, bound to `value`.
+    fn checks(&self, t: &mut Tokens<Go>, name: &GoName, field: &RpField) {
+        let new_error = go::import("errors", "New");
+
+        for check in field.validate.checks() {
+            let cond = match check {
+                RpCheck::MinLength(n) => {
+                    let count = go::import("unicode/utf8", "RuneCountInString");
+                    quote!(#count(value) < #(n.to_string()))
+                }
+                RpCheck::MaxLength(n) => {
+                    let count = go::import("unicode/utf8", "RuneCountInString");
+                    quote!(#count(value) > #(n.to_string()))
+                }
+                RpCheck::Pattern(..) => quote!(!#(pattern_ident(name, field)).MatchString(value)),
+                RpCheck::Min(n) => quote!(value < #(literal(&RpLiteral::Number(n.clone())))),
+                RpCheck::Max(n) => quote!(value > #(literal(&RpLiteral::Number(n.clone())))),
+                RpCheck::ExclusiveMin(n) => {
+                    quote!(value <= #(literal(&RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::ExclusiveMax(n) => {
+                    quote!(value >= #(literal(&RpLiteral::Number(n.clone()))))
+                }
+            };
+
+            let message = quoted(format!("{}.{}: {}", name.name, field.name(), check));
+
+            quote_in! { *t =>
+                if #cond {
+                    return #(&new_error)(#message)
+                }
+                #<push>
+            }
+        }
+    }
+
+    /// Set up an enum which has an unknown variant.
+    ///
+    /// The enum is declared as its underlying type so that it can hold any value, the known
+    /// variants being constants of it.
+    fn process_open_enum(
+        &self,
+        out: &mut FileSpec,
+        body: &RpEnumBody,
+        unknown: &RpUnknown,
+    ) -> Result<()> {
+        quote_in! { out.0 =>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            type #(&body.name) #(&body.enum_type)
+
+            const (
+                #(for v in body.variants.iter() join (#<push>) {
+                    #(Deprecated(v.deprecated))
+                    #(&body.name)_#(v.ident.as_str()) #(&body.name) = #(match v.value {
+                        RpVariantValue::String(string) => #(quoted(string)),
+                        RpVariantValue::Number(number) => #(number.to_string()),
+                    })
+                })
+            )
+
+            #(Comments(once(format!("Is{} reports if the value isn't one of the known variants.", unknown.ident))))
+            func (this #(&body.name)) Is#(&unknown.ident)() bool {
+                switch this {
+                case #(for v in body.variants.iter() join (, ) => #(&body.name)_#(v.ident.as_str())):
+                    return false
+                default:
+                    return true
+                }
+            }
+
+            #(for g in &self.options.enum_gens join (#<line>) {
+                #(ref container => g.generate(EnumAdded {
+                    container,
+                    name: &body.name,
+                    body,
+                })?)
+            })
+        }
+
         Ok(())
     }
 
@@ -139,7 +316,13 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
     }
 
     fn process_type(&self, out: &mut Self::Out, body: &RpTypeBody) -> Result<()> {
-        self.process_struct(&mut out.0, &body.name, &body.comment, &body.fields)?;
+        self.process_struct(
+            &mut out.0,
+            &body.name,
+            &body.comment,
+            body.deprecated.as_ref(),
+            &body.fields,
+        )?;
 
         Ok(())
     }
@@ -147,9 +330,11 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
     fn process_tuple(&self, out: &mut Self::Out, body: &RpTupleBody) -> Result<()> {
         quote_in! { out.0 =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             type #(&body.name) struct {
                 #(for f in &body.fields {
                     #(Comments(&f.comment))
+                    #(Deprecated(f.deprecated.as_ref()))
                     #(f.safe_ident()) #(if f.is_optional() {
                         *#(&f.ty)
                     } else {
@@ -170,9 +355,24 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
         Ok(())
     }
 
+    fn process_const(&self, out: &mut Self::Out, body: &RpConstBody) -> Result<()> {
+        quote_in! { out.0 =>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            const #(&body.name) #(&body.ty) = #(literal(&body.value))
+        }
+
+        Ok(())
+    }
+
     fn process_enum(&self, out: &mut Self::Out, body: &RpEnumBody) -> Result<()> {
+        if let Some(unknown) = &body.unknown {
+            return self.process_open_enum(out, body, unknown);
+        }
+
         quote_in! { out.0 =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             type #(&body.name) int
 
             const (
@@ -181,9 +381,11 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
 
                     quote_in! { *t =>
                         #(if let Some(v) = it.next() {
+                            #(Deprecated(v.deprecated))
                             #(&body.name)_#(v.ident.as_str()) #(&body.name) = iota
                         })
                         #(for v in it join (#<push>) {
+                            #(Deprecated(v.deprecated))
                             #(&body.name)_#(v.ident.as_str())
                         })
                     }
@@ -205,6 +407,7 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
     fn process_interface(&self, out: &mut Self::Out, body: &RpInterfaceBody) -> Result<()> {
         quote_in! { out.0 =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             type #(&body.name) struct {
                 Value interface {
                     Is#(&body.name)()
@@ -219,13 +422,29 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
                         .cloned()
                         .collect::<Vec<_>>();
 
-                    self.process_struct(t, &sub_type.name, &sub_type.comment, &fields)?;
+                    self.process_struct(
+                        t,
+                        &sub_type.name,
+                        &sub_type.comment,
+                        sub_type.deprecated.as_ref(),
+                        &fields,
+                    )?;
                 })
 
                 func (this #(&sub_type.name)) Is#(&body.name)() {
                 }
             })
 
+            #(if let Some(unknown) = &body.unknown {
+                #(Comments(&unknown.comment))
+                type #(&unknown.name) struct {
+                    Value map[string]interface{}
+                }
+
+                func (this #(&unknown.name)) Is#(&body.name)() {
+                }
+            })
+
             #(for g in &self.options.interface_gens join (#<line>) {
                 #(ref container => g.generate(InterfaceAdded {
                     container,
@@ -237,4 +456,27 @@ impl<'el> PackageProcessor<'el, GoFlavor> for Compiler<'el> {
 
         Ok(())
     }
+
+    fn process_union(&self, out: &mut Self::Out, body: &RpUnionBody) -> Result<()> {
+        // exactly one field is set, which is the alternative holding the value.
+        quote_in! { out.0 =>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            type #(&body.name) struct {
+                #(for v in &body.variants join (#<push>) {
+                    #(v.ident.as_str()) *#(&v.ty)
+                })
+            }
+
+            #(for g in &self.options.union_gens join (#<line>) {
+                #(ref container => g.generate(UnionAdded {
+                    container,
+                    name: &body.name,
+                    body,
+                })?)
+            })
+        }
+
+        Ok(())
+    }
 }
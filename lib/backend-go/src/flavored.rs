@@ -6,8 +6,8 @@ use crate::TYPE_SEP;
 use backend::package_processor;
 use core::errors::Result;
 use core::{
-    CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpNumberKind,
-    RpNumberType, RpStringType, Spanned, Translate, Translator,
+    CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpBytesType,
+    RpNumberKind, RpNumberType, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
 use genco::tokens::{FormatInto, Item, ItemStr};
@@ -204,15 +204,29 @@ impl FlavorTranslator for GoFlavorTranslator {
         })
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Interface)
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::String)
     }
 
-    fn translate_name(&self, _from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        _from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
+        if !arguments.is_empty() {
+            return Err("generic declarations must be monomorphized".into());
+        }
+
         let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |c| c.join(TYPE_SEP));
 
         // imported
@@ -227,6 +241,10 @@ impl FlavorTranslator for GoFlavorTranslator {
         return Ok(Type::local(ident));
     }
 
+    fn translate_parameter(&self, _: String) -> Result<Type> {
+        Err("generic declarations must be monomorphized".into())
+    }
+
     fn translate_local_name<T>(
         &self,
         translator: &T,
@@ -6,10 +6,10 @@ use crate::compiler::Compiler;
 use crate::flavored::*;
 use backend::Initializer;
 use core::errors::Result;
-use core::{CoreFlavor, Handle};
+use core::{CoreFlavor, Handle, Spanned};
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr, Tokens};
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use naming::Naming;
 use std::any::Any;
 use std::collections::BTreeMap;
@@ -26,6 +26,10 @@ pub struct GoLang;
 impl Lang for GoLang {
     manifest::lang_base!(GoModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Go)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("// {}", input))
     }
@@ -74,18 +78,22 @@ impl TryFromToml for GoModule {
 
 pub(crate) struct Options {
     pub(crate) field_gens: Vec<Box<dyn FieldCodegen>>,
+    pub(crate) struct_gens: Vec<Box<dyn StructCodegen>>,
     pub(crate) enum_gens: Vec<Box<dyn EnumCodegen>>,
     pub(crate) tuple_gens: Vec<Box<dyn TupleCodegen>>,
     pub(crate) interface_gens: Vec<Box<dyn InterfaceCodegen>>,
+    pub(crate) union_gens: Vec<Box<dyn UnionCodegen>>,
 }
 
 impl Options {
     pub(crate) fn new() -> Options {
         Options {
             field_gens: Vec::new(),
+            struct_gens: Vec::new(),
             enum_gens: Vec::new(),
             tuple_gens: Vec::new(),
             interface_gens: Vec::new(),
+            union_gens: Vec::new(),
         }
     }
 }
@@ -142,6 +150,15 @@ pub(crate) struct FieldAdded<'a> {
 
 codegen!(FieldCodegen, FieldAdded);
 
+/// Event emitted when a struct has been added.
+pub(crate) struct StructAdded<'a> {
+    pub container: &'a mut Tokens<Go>,
+    pub name: &'a GoName,
+    pub fields: &'a [Spanned<RpField>],
+}
+
+codegen!(StructCodegen, StructAdded);
+
 /// Event emitted when an enum has been added
 pub(crate) struct EnumAdded<'a> {
     pub container: &'a mut Tokens<Go>,
@@ -169,6 +186,15 @@ pub(crate) struct InterfaceAdded<'a> {
 
 codegen!(InterfaceCodegen, InterfaceAdded);
 
+/// Event emitted when a union has been added.
+pub(crate) struct UnionAdded<'a> {
+    pub container: &'a mut Tokens<Go>,
+    pub name: &'a GoName,
+    pub body: &'a RpUnionBody,
+}
+
+codegen!(UnionCodegen, UnionAdded);
+
 /// Structure for Tags - a type of Go metadata
 pub(crate) struct Tags {
     values: BTreeMap<ItemStr, Vec<ItemStr>>,
@@ -231,6 +257,7 @@ impl FormatInto<Go> for Tags {
 }
 
 fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let session = session.monomorphize()?.inline_newtypes()?.lift_unions()?;
     let packages = session.packages()?;
 
     let session = session.translate(flavored::GoFlavorTranslator::new(packages))?;
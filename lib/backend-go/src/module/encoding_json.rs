@@ -3,10 +3,11 @@
 use crate::flavored::*;
 use crate::{
     EnumAdded, EnumCodegen, FieldAdded, FieldCodegen, InterfaceAdded, InterfaceCodegen, Options,
-    TupleAdded, TupleCodegen,
+    StructAdded, StructCodegen, TupleAdded, TupleCodegen, UnionAdded, UnionCodegen,
 };
 use backend::Initializer;
 use core::errors::Result;
+use core::Spanned;
 use genco::prelude::*;
 use std::rc::Rc;
 
@@ -24,9 +25,11 @@ impl Initializer for Module {
     fn initialize(&self, options: &mut Self::Options) -> Result<()> {
         let codegen = Rc::new(Codegen::new());
         options.field_gens.push(Box::new(codegen.clone()));
+        options.struct_gens.push(Box::new(codegen.clone()));
         options.enum_gens.push(Box::new(codegen.clone()));
         options.tuple_gens.push(Box::new(codegen.clone()));
         options.interface_gens.push(Box::new(codegen.clone()));
+        options.union_gens.push(Box::new(codegen.clone()));
         Ok(())
     }
 }
@@ -36,6 +39,8 @@ struct Codegen {
     unmarshal: go::Import,
     marshal: go::Import,
     raw_message: go::Import,
+    sscan: go::Import,
+    sprint: go::Import,
 }
 
 impl Codegen {
@@ -45,6 +50,8 @@ impl Codegen {
             unmarshal: go::import("encoding/json", "Unmarshal"),
             marshal: go::import("encoding/json", "Marshal"),
             raw_message: go::import("encoding/json", "RawMessage"),
+            sscan: go::import("fmt", "Sscan"),
+            sprint: go::import("fmt", "Sprint"),
         }
     }
 }
@@ -59,10 +66,46 @@ impl FieldCodegen for Codegen {
             tags.push_str("json", "omitempty");
         }
 
+        if field.is_string_encoded() {
+            tags.push_str("json", "string");
+        }
+
         return Ok(());
     }
 }
 
+impl StructCodegen for Codegen {
+    fn generate(&self, e: StructAdded) -> Result<()> {
+        let StructAdded {
+            container,
+            name,
+            fields,
+        } = e;
+
+        if fields.iter().all(|f| f.validate.is_empty()) {
+            return Ok(());
+        }
+
+        // decode through a type without methods to avoid recursing, then validate.
+        quote_in! { *container =>
+            #<line>
+            func (this *#name) UnmarshalJSON(b []byte) error {
+                type raw #name
+                var value raw
+
+                if err := #(&self.unmarshal)(b, &value); err != nil {
+                    return err
+                }
+
+                *this = #name(value)
+                return this.Validate()
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl EnumCodegen for Codegen {
     fn generate(&self, e: EnumAdded) -> Result<()> {
         let EnumAdded {
@@ -72,6 +115,23 @@ impl EnumCodegen for Codegen {
             ..
         } = e;
 
+        // enums with an unknown variant are encoded as their underlying type.
+        if body.unknown.is_some() {
+            return Ok(());
+        }
+
+        // string enums implement the text interfaces instead, which are also used when they are
+        // map keys.
+        if let RpVariants::String { variants } = &body.variants {
+            quote_in! { *container =>
+                #(ref t => unmarshal_text(t, self, name, variants))
+
+                #(ref t => marshal_text(t, self, name, variants))
+            }
+
+            return Ok(());
+        }
+
         quote_in! { *container =>
             #(ref t => unmarshal_json(t, self, name, body))
 
@@ -80,6 +140,52 @@ impl EnumCodegen for Codegen {
 
         return Ok(());
 
+        fn unmarshal_text(
+            t: &mut Tokens<Go>,
+            c: &Codegen,
+            name: &GoName,
+            variants: &[Spanned<RpVariant<String>>],
+        ) {
+            quote_in! { *t =>
+                func (this *#name) UnmarshalText(b []byte) error {
+                    switch string(b) {
+                    #(for v in variants {
+                        case #(quoted(v.value.as_str())):
+                            *this = #(name)_#(v.ident.as_str())
+                    })
+                    default:
+                        return #(&c.new_error)("bad value")
+                    }
+
+                    return nil
+                }
+            }
+        }
+
+        fn marshal_text(
+            t: &mut Tokens<Go>,
+            c: &Codegen,
+            name: &GoName,
+            variants: &[Spanned<RpVariant<String>>],
+        ) {
+            quote_in! { *t =>
+                func (this #name) MarshalText() ([]byte, error) {
+                    var s string
+
+                    switch this {
+                    #(for v in variants {
+                        case #(name)_#(v.ident.as_str()):
+                            s = #(quoted(v.value.as_str()))
+                    })
+                    default:
+                        return nil, #(&c.new_error)("bad value")
+                    }
+
+                    return []byte(s), nil
+                }
+            }
+        }
+
         fn unmarshal_json(t: &mut Tokens<Go>, c: &Codegen, name: &GoName, body: &RpEnumBody) {
             quote_in! { *t =>
                 func (this *#name) UnmarshalJSON(b []byte) error {
@@ -173,9 +279,21 @@ impl TupleCodegen for Codegen {
                     #(for (i, f) in body.fields.iter().enumerate() join (#<line>) {
                         var #(f.safe_ident()) #(&f.ty)
 
-                        if err := #(&c.unmarshal)(array[#i], &#(f.safe_ident())); err != nil {
-                            return err
-                        }
+                        #(if f.is_string_encoded() {
+                            var #(f.safe_ident())String string
+
+                            if err := #(&c.unmarshal)(array[#i], &#(f.safe_ident())String); err != nil {
+                                return err
+                            }
+
+                            if _, err := #(&c.sscan)(#(f.safe_ident())String, &#(f.safe_ident())); err != nil {
+                                return err
+                            }
+                        } else {
+                            if err := #(&c.unmarshal)(array[#i], &#(f.safe_ident())); err != nil {
+                                return err
+                            }
+                        })
 
                         this.#(f.safe_ident()) = #(f.safe_ident())
                     })
@@ -191,7 +309,11 @@ impl TupleCodegen for Codegen {
                     var array []#(&c.raw_message)
 
                     #(for f in &body.fields join (#<line>) {
-                        #(f.safe_ident()), err := #(&c.marshal)(this.#(f.safe_ident()))
+                        #(if f.is_string_encoded() {
+                            #(f.safe_ident()), err := #(&c.marshal)(#(&c.sprint)(this.#(f.safe_ident())))
+                        } else {
+                            #(f.safe_ident()), err := #(&c.marshal)(this.#(f.safe_ident()))
+                        })
 
                         if err != nil {
                             return nil, err
@@ -231,6 +353,12 @@ impl InterfaceCodegen for Codegen {
                         RpSubTypeStrategy::Tagged { tag } => {
                             #(ref t => unmarshal_tagged(t, c, body, tag))
                         }
+                        RpSubTypeStrategy::Adjacent { tag, content } => {
+                            #(ref t => unmarshal_adjacent(t, c, body, tag, content))
+                        }
+                        RpSubTypeStrategy::External => {
+                            #(ref t => unmarshal_external(t, c, body))
+                        }
                         RpSubTypeStrategy::Untagged => {
                             #(ref t => unmarshal_untagged(t, c, body))
                         }
@@ -238,11 +366,17 @@ impl InterfaceCodegen for Codegen {
                 }
             };
 
-            fn unmarshal_sub_type(t: &mut Tokens<Go>, c: &Codegen, sub_type: &RpSubType) {
+            /// Unmarshal the sub-type from the raw message in the variable `raw`.
+            fn unmarshal_sub_type(
+                t: &mut Tokens<Go>,
+                c: &Codegen,
+                sub_type: &RpSubType,
+                raw: &str,
+            ) {
                 quote_in! { *t =>
                     sub := #(&sub_type.name){}
 
-                    if err = #(&c.unmarshal)(b, &sub); err != nil {
+                    if err = #(&c.unmarshal)(#raw, &sub); err != nil {
                         return err
                     }
 
@@ -251,6 +385,25 @@ impl InterfaceCodegen for Codegen {
                 }
             }
 
+            /// Unmarshal a sub-type which is not known, if the interface permits it.
+            fn unmarshal_unknown(t: &mut Tokens<Go>, c: &Codegen, body: &RpInterfaceBody) {
+                match &body.unknown {
+                    Some(unknown) => quote_in! { *t =>
+                        sub := #(&unknown.name){}
+
+                        if err = #(&c.unmarshal)(b, &sub.Value); err != nil {
+                            return err
+                        }
+
+                        this.Value = &sub
+                        return nil
+                    },
+                    None => quote_in! { *t =>
+                        return #(&c.new_error)("bad tag")
+                    },
+                }
+            }
+
             /// Unmarshal the envelope and extract the type field.
             fn unmarshal_tagged(
                 t: &mut Tokens<Go>,
@@ -282,14 +435,89 @@ impl InterfaceCodegen for Codegen {
                     switch (tag) {
                     #(for sub_type in &body.sub_types {
                         case #(quoted(sub_type.name())):
-                            #(ref t => unmarshal_sub_type(t, c, sub_type))
+                            #(ref t => unmarshal_sub_type(t, c, sub_type, "b"))
                     })
                     default:
-                        return #(&c.new_error)("bad tag")
+                        #(ref t => unmarshal_unknown(t, c, body))
+                    }
+                }
+            }
+
+            /// Unmarshal the envelope and extract the type and content fields.
+            fn unmarshal_adjacent(
+                t: &mut Tokens<Go>,
+                c: &Codegen,
+                body: &RpInterfaceBody,
+                tag: &str,
+                content: &str,
+            ) {
+                quote_in! { *t =>
+                    var err error
+                    var ok bool
+                    env := make(map[string]#(&c.raw_message))
+
+                    if err := #(&c.unmarshal)(b, &env); err != nil {
+                        return err
+                    }
+
+                    var raw_tag #(&c.raw_message)
+
+                    if raw_tag, ok = env[#(quoted(tag))]; !ok {
+                        return #(&c.new_error)("missing tag")
+                    }
+
+                    var tag string
+
+                    if err = #(&c.unmarshal)(raw_tag, &tag); err != nil {
+                        return err
+                    }
+
+                    var content #(&c.raw_message)
+
+                    if content, ok = env[#(quoted(content))]; !ok {
+                        return #(&c.new_error)("missing content")
+                    }
+
+                    switch (tag) {
+                    #(for sub_type in &body.sub_types {
+                        case #(quoted(sub_type.name())):
+                            #(ref t => unmarshal_sub_type(t, c, sub_type, "content"))
+                    })
+                    default:
+                        #(ref t => unmarshal_unknown(t, c, body))
                     }
                 }
             }
 
+            /// Unmarshal the envelope, where the only key is the type.
+            fn unmarshal_external(t: &mut Tokens<Go>, c: &Codegen, body: &RpInterfaceBody) {
+                quote_in! { *t =>
+                    var err error
+                    env := make(map[string]#(&c.raw_message))
+
+                    if err := #(&c.unmarshal)(b, &env); err != nil {
+                        return err
+                    }
+
+                    if len(env) != 1 {
+                        return #(&c.new_error)("expected exactly one key")
+                    }
+
+                    for tag, content := range env {
+                        switch (tag) {
+                        #(for sub_type in &body.sub_types {
+                            case #(quoted(sub_type.name())):
+                                #(ref t => unmarshal_sub_type(t, c, sub_type, "content"))
+                        })
+                        default:
+                            #(ref t => unmarshal_unknown(t, c, body))
+                        }
+                    }
+
+                    return #(&c.new_error)("expected exactly one key")
+                }
+            }
+
             fn unmarshal_untagged(t: &mut Tokens<Go>, c: &Codegen, body: &RpInterfaceBody) {
                 quote_in! { *t =>
                     var err error
@@ -316,7 +544,7 @@ impl InterfaceCodegen for Codegen {
                         }
 
                         if all {
-                            #(ref t => unmarshal_sub_type(t, c, sub_type))
+                            #(ref t => unmarshal_sub_type(t, c, sub_type, "b"))
                         }
                     })
 
@@ -332,6 +560,12 @@ impl InterfaceCodegen for Codegen {
                         RpSubTypeStrategy::Tagged { ref tag } => {
                             #(ref t => marshal_tagged(t, c, body, tag))
                         }
+                        RpSubTypeStrategy::Adjacent { ref tag, ref content } => {
+                            #(ref t => marshal_adjacent(t, c, body, tag, content))
+                        }
+                        RpSubTypeStrategy::External => {
+                            #(ref t => marshal_external(t, c, body))
+                        }
                         RpSubTypeStrategy::Untagged => {
                             #(ref t => marshal_untagged(t, c, body))
                         }
@@ -339,6 +573,16 @@ impl InterfaceCodegen for Codegen {
                 }
             };
 
+            /// Marshal a sub-type which is not known as the object it was decoded from.
+            fn marshal_unknown(t: &mut Tokens<Go>, c: &Codegen, body: &RpInterfaceBody) {
+                if let Some(unknown) = &body.unknown {
+                    quote_in! { *t =>
+                        case *#(&unknown.name):
+                            return #(&c.marshal)(v.Value)
+                    }
+                }
+            }
+
             /// Marshal the envelope and extract the type field.
             fn marshal_tagged(t: &mut Tokens<Go>, c: &Codegen, body: &RpInterfaceBody, tag: &str) {
                 quote_in! { *t =>
@@ -363,6 +607,61 @@ impl InterfaceCodegen for Codegen {
 
                             return #(&c.marshal)(env)
                     })
+                    #(ref t => marshal_unknown(t, c, body))
+                    default:
+                        return nil, #(&c.new_error)(#_(#(&body.name): no sub-type set))
+                    }
+                }
+            }
+
+            /// Marshal the sub-type into the content field of the envelope.
+            fn marshal_adjacent(
+                t: &mut Tokens<Go>,
+                c: &Codegen,
+                body: &RpInterfaceBody,
+                tag: &str,
+                content: &str,
+            ) {
+                quote_in! { *t =>
+                    var err error
+                    env := make(map[string]#(&c.raw_message))
+
+                    switch v := this.Value.(type) {
+                    #(for sub_type in &body.sub_types {
+                        case *#(&sub_type.name):
+                            if env[#(quoted(tag))], err = #(&c.marshal)(#(quoted(sub_type.name()))); err != nil {
+                                return nil, err
+                            }
+
+                            if env[#(quoted(content))], err = #(&c.marshal)(v); err != nil {
+                                return nil, err
+                            }
+
+                            return #(&c.marshal)(env)
+                    })
+                    #(ref t => marshal_unknown(t, c, body))
+                    default:
+                        return nil, #(&c.new_error)(#_(#(&body.name): no sub-type set))
+                    }
+                }
+            }
+
+            /// Marshal the sub-type into the field of the envelope named after the sub-type.
+            fn marshal_external(t: &mut Tokens<Go>, c: &Codegen, body: &RpInterfaceBody) {
+                quote_in! { *t =>
+                    var err error
+                    env := make(map[string]#(&c.raw_message))
+
+                    switch v := this.Value.(type) {
+                    #(for sub_type in &body.sub_types {
+                        case *#(&sub_type.name):
+                            if env[#(quoted(sub_type.name()))], err = #(&c.marshal)(v); err != nil {
+                                return nil, err
+                            }
+
+                            return #(&c.marshal)(env)
+                    })
+                    #(ref t => marshal_unknown(t, c, body))
                     default:
                         return nil, #(&c.new_error)(#_(#(&body.name): no sub-type set))
                     }
@@ -385,3 +684,72 @@ impl InterfaceCodegen for Codegen {
         }
     }
 }
+
+impl UnionCodegen for Codegen {
+    fn generate(&self, e: UnionAdded) -> Result<()> {
+        let UnionAdded {
+            container,
+            name,
+            body,
+            ..
+        } = e;
+
+        quote_in! { *container =>
+            #(ref t => unmarshal_json(t, self, name, body))
+
+            #(ref t => marshal_json(t, self, name, body))
+        }
+
+        return Ok(());
+
+        fn unmarshal_json(t: &mut Tokens<Go>, c: &Codegen, name: &GoName, body: &RpUnionBody) {
+            // the kind of JSON value is determined by decoding it without a type, in which case
+            // all numbers are decoded as float64.
+            quote_in! { *t =>
+                func (this *#name) UnmarshalJSON(b []byte) error {
+                    var value interface{}
+
+                    if err := #(&c.unmarshal)(b, &value); err != nil {
+                        return err
+                    }
+
+                    switch value.(type) {
+                    #(for v in &body.variants {
+                        case #(match v.kind {
+                            RpUnionKind::String => string,
+                            RpUnionKind::Integer | RpUnionKind::Number => float64,
+                            RpUnionKind::Boolean => bool,
+                            RpUnionKind::Array => []interface{},
+                            RpUnionKind::Object => map[string]interface{},
+                        }):
+                            var v #(&v.ty)
+
+                            if err := #(&c.unmarshal)(b, &v); err != nil {
+                                return err
+                            }
+
+                            this.#(v.ident.as_str()) = &v
+                            return nil
+                    })
+                    }
+
+                    return #(&c.new_error)(#_(#(&body.name): no alternative of the union matches the value))
+                }
+            }
+        }
+
+        fn marshal_json(t: &mut Tokens<Go>, c: &Codegen, name: &GoName, body: &RpUnionBody) {
+            quote_in! { *t =>
+                func (this #name) MarshalJSON() ([]byte, error) {
+                    #(for v in &body.variants join (#<line>) {
+                        if this.#(v.ident.as_str()) != nil {
+                            return #(&c.marshal)(this.#(v.ident.as_str()))
+                        }
+                    })
+
+                    return nil, #(&c.new_error)(#_(#(&body.name): no alternative of the union is set))
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::flavored::*;
 use core::Spanned;
 use genco::lang::java;
+use genco::prelude::*;
 use std::rc::Rc;
 
 macro_rules! decl_codegen {
@@ -55,6 +56,8 @@ decl_codegen! {
     class<'a> {
         /// The name of the type being generated for.
         pub(crate) ident: &'a str,
+        /// Type parameters of the type being generated for.
+        pub(crate) type_parameters: &'a [String],
         /// Fields associated with class.
         pub(crate) fields: &'a [Spanned<Field>],
         /// Additional declarations.
@@ -71,6 +74,8 @@ decl_codegen! {
         pub(crate) sub_types: &'a [Spanned<RpSubType>],
         /// The sub type strategy associated with the interface.
         pub(crate) sub_type_strategy: &'a RpSubTypeStrategy,
+        /// The unknown sub type of the interface, if present.
+        pub(crate) unknown: Option<&'a RpUnknown>,
         /// Annotations to add to the interface.
         pub(crate) annotations: &'a mut Vec<java::Tokens>,
         /// Innter content to add to the interface class.
@@ -81,6 +86,8 @@ decl_codegen! {
     interface_sub_type<'a> {
         /// The sub type strategy associated with the interface.
         pub(crate) sub_type_strategy: &'a RpSubTypeStrategy,
+        /// The unknown sub type of the interface, if present.
+        pub(crate) unknown: Option<&'a RpUnknown>,
         /// Annotations to add to the interface.
         pub(crate) annotations: &'a mut Vec<java::Tokens>,
     }
@@ -109,6 +116,8 @@ decl_codegen! {
         pub(crate) ident: &'a str,
         /// The type of the enum.
         pub(crate) enum_type: &'a Type,
+        /// The unknown variant of the enum, if present.
+        pub(crate) unknown: Option<&'a RpUnknown>,
         /// Additional declarations generated.
         pub(crate) inner: &'a mut Vec<java::Tokens>,
     }
@@ -127,6 +136,28 @@ decl_codegen! {
         pub(crate) annotations: &'a mut Vec<java::Tokens>,
     }
 
+    /// Generator used for newtype wrapper classes.
+    newtype<'a> {
+        /// Annotations to add to the constructor.
+        pub(crate) constructor: &'a mut Vec<java::Tokens>,
+        /// Annotations to add to the getter of the wrapped value.
+        pub(crate) getter: &'a mut Vec<java::Tokens>,
+    }
+
+    /// Generator used for unions.
+    union<'a> {
+        /// The identifier of the union.
+        pub(crate) ident: &'a str,
+        /// Variants of the union, and the identifier of the class holding each of them.
+        pub(crate) variants: &'a [(String, &'a RpUnionVariant)],
+        /// Annotations to add to the union class.
+        pub(crate) annotations: &'a mut Vec<java::Tokens>,
+        /// Annotations to add to the class of each variant.
+        pub(crate) variant_annotations: &'a mut Vec<java::Tokens>,
+        /// Inner content to add to the union class.
+        pub(crate) inner: &'a mut Vec<java::Tokens>,
+    }
+
     class_getter<'a> {
         /// Field declaration being generated for.
         pub(crate) field: &'a Spanned<Field>,
@@ -134,3 +165,21 @@ decl_codegen! {
         pub(crate) annotations: &'a mut Vec<java::Tokens>,
     }
 }
+
+/// Format a list of type parameters, as in `<T, U>`, if there are any.
+pub(crate) fn type_parameters(parameters: &[String]) -> java::Tokens {
+    if parameters.is_empty() {
+        return java::Tokens::new();
+    }
+
+    quote!(<#(for p in parameters join (,#<space>) => #p)>)
+}
+
+/// Format the diamond operator for a generic declaration being constructed, if needed.
+pub(crate) fn diamond(parameters: &[String]) -> java::Tokens {
+    if parameters.is_empty() {
+        return java::Tokens::new();
+    }
+
+    quote!(<>)
+}
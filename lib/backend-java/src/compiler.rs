@@ -1,26 +1,33 @@
 //! Java backend for reproto
 
+use crate::codegen::type_parameters;
 use crate::flavored::*;
 use crate::Options;
 use core::errors::Result;
-use core::{Handle, RelativePathBuf, Spanned};
+use core::{Handle, RelativePathBuf, RpCheck, RpLiteral, RpPackage, Span, Spanned};
 use genco::fmt;
 use genco::prelude::*;
 use genco::tokens::from_fn;
 use naming::Naming;
+use std::collections::BTreeMap;
 use trans::Translated;
 
+/// Name of the class holding top-level constants in each package.
+const CONSTANTS: &str = "Constants";
+
 #[allow(unused)]
 pub(crate) struct Compiler<'a> {
     env: &'a Translated<JavaFlavor>,
     options: Options,
     to_upper: naming::ToUpperCamel,
+    to_upper_snake: naming::ToUpperSnake,
     suppress_warnings: java::Import,
     string_builder: java::Import,
     objects: java::Import,
     object: java::Import,
     string: java::Import,
     illegal_argument: java::Import,
+    unsupported_operation: java::Import,
 }
 
 impl<'a> Compiler<'a> {
@@ -29,47 +36,83 @@ impl<'a> Compiler<'a> {
             env,
             options,
             to_upper: naming::to_upper_camel(),
+            to_upper_snake: naming::to_upper_snake(),
             objects: java::import("java.util", "Objects"),
             suppress_warnings: java::import("java.lang", "SuppressWarnings"),
             string_builder: java::import("java.lang", "StringBuilder"),
             object: java::import("java.lang", "Object"),
             string: java::import("java.lang", "String"),
             illegal_argument: java::import("java.lang", "IllegalArgumentException"),
+            unsupported_operation: java::import("java.lang", "UnsupportedOperationException"),
         }
     }
 
     pub(crate) fn compile(&self, handle: &dyn Handle) -> Result<()> {
-        for decl in self.env.toplevel_decl_iter() {
-            let package = decl.name().package.join(".");
+        let mut constants = BTreeMap::new();
 
-            let path = decl
-                .name()
-                .package
-                .parts()
-                .cloned()
-                .fold(RelativePathBuf::new(), |p, part| p.join(part));
-
-            if !handle.is_dir(&path) {
-                log::debug!("+dir: {}", path);
-                handle.create_dir_all(&path)?;
+        for decl in self.env.toplevel_decl_iter() {
+            // top-level constants are grouped into a single class for each package.
+            if let RpDecl::Const(body) = decl {
+                constants
+                    .entry(&body.name.package)
+                    .or_insert_with(Vec::new)
+                    .push(body);
+                continue;
             }
 
-            let path = path.join(format!("{}.java", decl.ident()));
-
             let mut out = java::Tokens::new();
             self.process_decl(&mut out, 0usize, decl)?;
+            self.write_class(handle, &decl.name().package, decl.ident(), out)?;
+        }
 
-            log::debug!("+class: {}", path);
+        for (package, constants) in constants {
+            let mut out = java::Tokens::new();
+
+            quote_in! { out =>
+                public final class #CONSTANTS {
+                    private #CONSTANTS() {
+                    }
+
+                    #(for c in constants join (#<line>) {
+                        #(ref t => self.process_const(t, 1, c)?)
+                    })
+                }
+            }
 
-            let fmt = fmt::Config::from_lang::<java::Java>();
-            let config = java::Config::default().with_package(package);
+            self.write_class(handle, package, CONSTANTS, out)?;
+        }
 
-            let mut file = handle.create(&path)?;
-            let mut w = fmt::IoWriter::new(&mut file);
+        Ok(())
+    }
 
-            out.format_file(&mut w.as_formatter(&fmt), &config)?;
+    fn write_class(
+        &self,
+        handle: &dyn Handle,
+        package: &RpPackage,
+        ident: &str,
+        out: java::Tokens,
+    ) -> Result<()> {
+        let path = package
+            .parts()
+            .cloned()
+            .fold(RelativePathBuf::new(), |p, part| p.join(part));
+
+        if !handle.is_dir(&path) {
+            log::debug!("+dir: {}", path);
+            handle.create_dir_all(&path)?;
         }
 
+        let path = path.join(format!("{}.java", ident));
+
+        log::debug!("+class: {}", path);
+
+        let fmt = fmt::Config::from_lang::<java::Java>();
+        let config = java::Config::default().with_package(package.join("."));
+
+        let mut file = handle.create(&path)?;
+        let mut w = fmt::IoWriter::new(&mut file);
+
+        out.format_file(&mut w.as_formatter(&fmt), &config)?;
         Ok(())
     }
 
@@ -78,6 +121,10 @@ impl<'a> Compiler<'a> {
             let mut ann = Vec::new();
             self.options.gen.class_field(f, &mut ann);
 
+            if f.deprecated.is_some() {
+                ann.push(quote!(@Deprecated));
+            }
+
             quote_in! { *t =>
                 #(for a in ann join (#<push>) => #a)
                 #(if self.options.immutable => final#<space>)#(f.field_type()) #(f.safe_ident())
@@ -118,32 +165,91 @@ impl<'a> Compiler<'a> {
                         #(if !f.is_optional() && !f.ty.is_primitive() {
                             #(&self.objects).requireNonNull(#(f.safe_ident()), #_(#(&f.ident): must not be null));
                         })
-                        this.#(f.safe_ident()) = #(f.safe_ident());
+                        #(ref t => self.checks(t, name, f))
+                        this.#(f.safe_ident()) = #(f.or_default(f.safe_ident().to_string()));
                     })
                 }
             }
         })
     }
 
+    /// Check the validation rules of a single constructor argument.
+    fn checks(&self, t: &mut java::Tokens, name: &str, field: &Field) {
+        let value = if field.is_optional() {
+            quote!(#(field.safe_ident()).get())
+        } else {
+            quote!(#(field.safe_ident()))
+        };
+
+        // lengths are counted in code points, like in the other languages.
+        let length = quote!(#(&value).codePointCount(0, #(&value).length()));
+
+        for check in field.validate.checks() {
+            let cond = match check {
+                RpCheck::MinLength(n) => quote!(#(&length) < #(n.to_string())),
+                RpCheck::MaxLength(n) => quote!(#(&length) > #(n.to_string())),
+                RpCheck::Pattern(pattern) => {
+                    let pattern_class = java::import("java.util.regex", "Pattern");
+                    quote!(!#pattern_class.matches(#(quoted(pattern.to_string())), #(&value)))
+                }
+                RpCheck::Min(n) => {
+                    quote!(#(&value) < #(field.ty.literal(&RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::Max(n) => {
+                    quote!(#(&value) > #(field.ty.literal(&RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::ExclusiveMin(n) => {
+                    quote!(#(&value) <= #(field.ty.literal(&RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::ExclusiveMax(n) => {
+                    quote!(#(&value) >= #(field.ty.literal(&RpLiteral::Number(n.clone()))))
+                }
+            };
+
+            let cond = if field.is_optional() {
+                quote!(#(field.safe_ident()).isPresent() && #cond)
+            } else {
+                cond
+            };
+
+            let message = quoted(format!("{}.{}: {}", name, field.name(), check));
+
+            quote_in! { *t =>
+                if (#cond) {
+                    throw new #(&self.illegal_argument)(#message);
+                }
+                #<push>
+            }
+        }
+    }
+
     fn process_enum(&self, t: &mut java::Tokens, depth: usize, body: &RpEnumBody) -> Result<()> {
         let mut inner = Vec::new();
 
-        self.options
-            .gen
-            .enum_ty(&body.ident, &body.enum_type, &mut inner);
+        self.options.gen.enum_ty(
+            &body.ident,
+            &body.enum_type,
+            body.unknown.as_deref(),
+            &mut inner,
+        );
+
+        if let Some(unknown) = &body.unknown {
+            return self.process_open_enum(t, depth, body, unknown, inner);
+        }
 
         quote_in! {*t =>
             #(java::block_comment(&body.comment))
+            #(if body.deprecated.is_some() => @Deprecated)
             public #(if depth > 0 => static) enum #(&body.ident) {
                 #(match &body.variants {
                     RpVariants::String { variants } => {
                         #(for variant in variants join (,#<push>) {
-                            #(self.to_upper.convert(variant.ident()))(#(quoted(&variant.value)))
+                            #(if variant.deprecated.is_some() => @Deprecated#<space>)#(self.to_upper.convert(variant.ident()))(#(quoted(&variant.value)))
                         })
                     }
                     RpVariants::Number { variants } => {
                         #(for variant in variants join (,#<push>) {
-                            #(self.to_upper.convert(variant.ident()))(#(match body.enum_type.as_primitive() {
+                            #(if variant.deprecated.is_some() => @Deprecated#<space>)#(self.to_upper.convert(variant.ident()))(#(match body.enum_type.as_primitive() {
                                 Some(Primitive::Long) => #(display(&variant.value))L,
                                 _ => #(display(&variant.value)),
                             }))
@@ -164,16 +270,86 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Process an enum with an unknown variant.
+    ///
+    /// Since a Java enum can't hold values which are not known ahead of time, this is a class with
+    /// a constant for each variant instead.
+    fn process_open_enum(
+        &self,
+        t: &mut java::Tokens,
+        depth: usize,
+        body: &RpEnumBody,
+        unknown: &RpUnknown,
+        inner: Vec<java::Tokens>,
+    ) -> Result<()> {
+        let ident = &body.ident;
+        let fields = [Spanned::new(
+            Field::required("value", body.enum_type.clone()),
+            Span::empty(),
+        )];
+
+        quote_in! {*t =>
+            #(java::block_comment(&body.comment))
+            #(if body.deprecated.is_some() => @Deprecated)
+            public #(if depth > 0 => static) final class #ident {
+                #(for variant in body.variants.iter() join (#<push>) {
+                    #(if variant.deprecated.is_some() => @Deprecated#<space>)public static final #ident #(self.to_upper.convert(variant.ident())) = new #ident(#(match variant.value {
+                        RpVariantValue::String(string) => #(quoted(string)),
+                        RpVariantValue::Number(number) => #(match body.enum_type.as_primitive() {
+                            Some(Primitive::Long) => #(display(number))L,
+                            _ => #(display(number)),
+                        }),
+                    }));
+                })
+
+                final #(&body.enum_type) value;
+
+                private #ident(final #(&body.enum_type) value) {
+                    this.value = value;
+                }
+
+                public static #ident[] values() {
+                    return new #ident[] {#(for variant in body.variants.iter() join (, ) => #(self.to_upper.convert(variant.ident())))};
+                }
+
+                #(java::block_comment(&unknown.comment))
+                public boolean is#(&unknown.ident)() {
+                    for (final #ident v : values()) {
+                        if (v == this) {
+                            return false;
+                        }
+                    }
+
+                    return true;
+                }
+
+                #(self.to_string(ident, &fields))
+
+                #(self.hash_code(&fields))
+
+                #(self.equals(ident, &fields))
+
+                #(for i in inner join (#<line>) => #i)
+            }
+        }
+
+        Ok(())
+    }
+
     fn process_tuple(&self, t: &mut java::Tokens, depth: usize, body: &RpTupleBody) -> Result<()> {
         let mut inner = Vec::new();
         let mut annotations = Vec::new();
         self.options
             .gen
-            .class(&body.ident, &body.fields, &mut inner, &mut annotations);
+            .class(&body.ident, &[], &body.fields, &mut inner, &mut annotations);
         self.options
             .gen
             .tuple(&body.ident, &body.fields, &mut inner, &mut annotations);
 
+        if body.deprecated.is_some() {
+            annotations.push(quote!(@Deprecated));
+        }
+
         quote_in! { *t =>
             #(java::block_comment(&body.comment))
             #(for a in annotations join (#<push>) => #a)
@@ -213,14 +389,22 @@ impl<'a> Compiler<'a> {
         let mut inner = Vec::new();
         let mut annotations = Vec::new();
 
-        self.options
-            .gen
-            .class(&body.ident, &body.fields, &mut inner, &mut annotations);
+        self.options.gen.class(
+            &body.ident,
+            &body.type_parameters,
+            &body.fields,
+            &mut inner,
+            &mut annotations,
+        );
+
+        if body.deprecated.is_some() {
+            annotations.push(quote!(@Deprecated));
+        }
 
         quote_in! { *t =>
             #(java::block_comment(&body.comment))
             #(for a in annotations join (#<push>) => #a)
-            public #(if depth > 0 => static) class #(&body.ident) {
+            public #(if depth > 0 => static) class #(&body.ident)#(type_parameters(&body.type_parameters)) {
                 #(for f in &body.fields join (#<push>) {
                     #(self.field(f));
                 })
@@ -264,14 +448,19 @@ impl<'a> Compiler<'a> {
             &body.ident,
             &body.sub_types,
             &body.sub_type_strategy,
+            body.unknown.as_deref(),
             &mut annotations,
             &mut inner,
         );
 
+        if body.deprecated.is_some() {
+            annotations.push(quote!(@Deprecated));
+        }
+
         quote_in! { *t =>
             #(java::block_comment(&body.comment))
             #(for a in annotations join (#<push>) => #a)
-            public #(if depth > 0 => static) interface #(&body.ident) {
+            public #(if depth > 0 => static) interface #(&body.ident)#(type_parameters(&body.type_parameters)) {
                 #(for f in &body.fields join (#<line>) {
                     #(self.getter_without_body(f))
 
@@ -286,14 +475,18 @@ impl<'a> Compiler<'a> {
                         let mut inner = Vec::new();
                         let mut annotations = Vec::new();
 
-                        self.options.gen.class(&s.ident, &fields, &mut inner, &mut annotations);
+                        self.options.gen.class(&s.ident, &s.type_parameters, &fields, &mut inner, &mut annotations);
                         self.options.gen
-                            .interface_sub_type(&body.sub_type_strategy, &mut annotations);
+                            .interface_sub_type(&body.sub_type_strategy, body.unknown.as_deref(), &mut annotations);
+
+                        if s.deprecated.is_some() {
+                            annotations.push(quote!(@Deprecated));
+                        }
 
                         quote_in!{*t =>
                             #(java::block_comment(&s.comment))
                             #(for a in annotations join (#<push>) => #a)
-                            public static class #(&s.ident) implements #(&body.ident) {
+                            public static class #(&s.ident)#(type_parameters(&s.type_parameters)) implements #(&body.ident)#(type_parameters(&s.type_parameters)) {
                                 #(for f in &fields join (#<push>) {
                                     #(self.field(f));
                                 })
@@ -330,6 +523,10 @@ impl<'a> Compiler<'a> {
                     })
                 });
 
+                #(if let Some(unknown) = &body.unknown {
+                    #(ref t => self.unknown_sub_type(t, body, unknown))
+                })
+
                 #(for d in &body.decls join (#<line>) {
                     #(ref t => self.process_decl(t, depth + 1, d)?)
                 })
@@ -341,10 +538,197 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Build the sub-type holding objects whose sub-type is not known.
+    ///
+    /// Fields shared by all sub-types are not available for it.
+    fn unknown_sub_type(&self, t: &mut java::Tokens, body: &RpInterfaceBody, unknown: &RpUnknown) {
+        let ident = &unknown.ident;
+        let fields = [Spanned::new(
+            Field::required("value", Type::Object),
+            Span::empty(),
+        )];
+        let field = &fields[0];
+        let unsupported = &self.unsupported_operation;
+
+        quote_in! { *t =>
+            #(java::block_comment(&unknown.comment))
+            public static class #ident#(type_parameters(&body.type_parameters)) implements #(&body.ident)#(type_parameters(&body.type_parameters)) {
+                final #(&field.ty) value;
+
+                public #ident(final #(&field.ty) value) {
+                    #(&self.objects).requireNonNull(value, "value: must not be null");
+                    this.value = value;
+                }
+
+                public #(&field.ty) getValue() {
+                    return this.value;
+                }
+
+                #(for f in &body.fields join (#<line>) {
+                    #(if self.options.build_getters {
+                        @Override
+                        public #(f.field_type()) get#(self.getter_name(f))() {
+                            throw new #unsupported(#(quoted(format!("{}: not available for unknown sub-types", f.ident))));
+                        }
+                    })
+                    #(if self.options.build_setters && !self.options.immutable {
+                        #<line>
+                        @Override
+                        public void set#(self.to_upper.convert(&f.ident))(final #(&f.ty) #(f.safe_ident())) {
+                            throw new #unsupported(#(quoted(format!("{}: not available for unknown sub-types", f.ident))));
+                        }
+                    })
+                })
+
+                #(self.to_string(ident, &fields))
+
+                #(self.hash_code(&fields))
+
+                #(self.equals(ident, &fields))
+            }
+        }
+    }
+
     fn process_service(&self, _: &mut java::Tokens, _: usize, _: &RpServiceBody) -> Result<()> {
         Ok(())
     }
 
+    fn process_const(&self, t: &mut java::Tokens, _: usize, body: &RpConstBody) -> Result<()> {
+        quote_in! { *t =>
+            #(java::block_comment(&body.comment))
+            #(if body.deprecated.is_some() => @Deprecated)
+            public static final #(&body.ty) #(self.to_upper_snake.convert(&body.ident)) = #(body.ty.literal(&body.value));
+        }
+
+        Ok(())
+    }
+
+    fn process_alias(&self, t: &mut java::Tokens, depth: usize, body: &RpAliasBody) -> Result<()> {
+        let mut annotations = Vec::new();
+
+        if body.deprecated.is_some() {
+            annotations.push(quote!(@Deprecated));
+        }
+
+        quote_in! { *t =>
+            #(java::block_comment(&body.comment))
+            #(for a in annotations join (#<push>) => #a)
+            public #(if depth > 0 => static) final class #(&body.ident) {
+                #(self.wrapper(&body.ident, &body.ty))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_union(&self, t: &mut java::Tokens, depth: usize, body: &RpUnionBody) -> Result<()> {
+        let variants = body
+            .variants
+            .iter()
+            .map(|v| (format!("{}Value", v.ident), v))
+            .collect::<Vec<_>>();
+
+        let mut annotations = Vec::new();
+        let mut variant_annotations = Vec::new();
+        let mut inner = Vec::new();
+
+        self.options.gen.union(
+            &body.ident,
+            &variants,
+            &mut annotations,
+            &mut variant_annotations,
+            &mut inner,
+        );
+
+        if body.deprecated.is_some() {
+            annotations.push(quote!(@Deprecated));
+        }
+
+        let ident = &body.ident;
+
+        // the private constructor makes sure that the variants below are the only sub-classes.
+        quote_in! { *t =>
+            #(java::block_comment(&body.comment))
+            #(for a in annotations join (#<push>) => #a)
+            public #(if depth > 0 => static) abstract class #ident {
+                private #ident() {
+                }
+
+                #(for (variant, v) in &variants join (#<line>) {
+                    #(for a in &variant_annotations join (#<push>) => #a)
+                    public static final class #variant extends #ident {
+                        #(self.wrapper(variant, &v.ty))
+                    }
+                })
+
+                #(for i in inner join (#<line>) => #i)
+
+                #(for d in &body.decls join (#<line>) {
+                    #(ref t => self.process_decl(t, depth + 1, d)?)
+                })
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generate the body of a class wrapping a single value of the given type.
+    fn wrapper<'f>(&'f self, ident: &'f str, ty: &'f Type) -> impl FormatInto<Java> + 'f {
+        let mut constructor = Vec::new();
+        let mut getter = Vec::new();
+
+        self.options.gen.newtype(&mut constructor, &mut getter);
+
+        quote_fn! {
+            private final #ty value;
+
+            #(for a in constructor join (#<push>) => #a)
+            public #ident(final #ty value) {
+                #(if !ty.is_primitive() {
+                    #(&self.objects).requireNonNull(value, "value: must not be null");
+                })
+                this.value = value;
+            }
+
+            #(for a in getter join (#<push>) => #a)
+            public #ty getValue() {
+                return this.value;
+            }
+            #(if self.options.build_to_string {
+                #<line>
+                @Override
+                public String toString() {
+                    return #(match ty.as_primitive() {
+                        Some(primitive) => #(ref t => primitive.to_string(t, quote!(this.value))),
+                        None => this.value.toString(),
+                    });
+                }
+            })
+            #(if self.options.build_hash_code {
+                #<line>
+                @Override
+                public int hashCode() {
+                    return #(match ty.as_primitive() {
+                        Some(primitive) => #(ref t => primitive.hash_code(t, quote!(this.value))),
+                        None => this.value.hashCode(),
+                    });
+                }
+            })
+            #(if self.options.build_equals {
+                #<line>
+                @Override
+                public boolean equals(final Object other_) {
+                    if (!(other_ instanceof #ident)) {
+                        return false;
+                    }
+
+                    final #ident o_ = (#ident)other_;
+                    return #(ty.equals(quote!(this.value), quote!(o_.value)));
+                }
+            })
+        }
+    }
+
     fn process_decl(&self, t: &mut java::Tokens, depth: usize, decl: &RpDecl) -> Result<()> {
         match decl {
             RpDecl::Interface(interface) => {
@@ -362,6 +746,15 @@ impl<'a> Compiler<'a> {
             RpDecl::Service(ty) => {
                 self.process_service(t, depth, ty)?;
             }
+            RpDecl::Const(ty) => {
+                self.process_const(t, depth, ty)?;
+            }
+            RpDecl::Alias(ty) => {
+                self.process_alias(t, depth, ty)?;
+            }
+            RpDecl::Union(ty) => {
+                self.process_union(t, depth, ty)?;
+            }
         }
 
         Ok(())
@@ -400,6 +793,17 @@ impl<'a> Compiler<'a> {
         })
     }
 
+    /// Name of the getter for the given field, without the `get` prefix.
+    fn getter_name(&self, f: &Field) -> String {
+        let name = self.to_upper.convert(&f.ident);
+
+        // Avoid `getClass`, a common built-in method for any Object.
+        match name.as_str() {
+            "Class" => String::from("Class_"),
+            _ => name,
+        }
+    }
+
     /// Create a new getter method without a body.
     fn getter_without_body<'f>(&'f self, f: &'f Field) -> impl FormatInto<Java> + 'f {
         from_fn(move |t| {
@@ -407,16 +811,11 @@ impl<'a> Compiler<'a> {
                 return;
             }
 
-            let name = self.to_upper.convert(&f.ident);
-
-            // Avoid `getClass`, a common built-in method for any Object.
-            let name = match name.as_str() {
-                "Class" => "Class_",
-                accessor => accessor,
-            };
+            let name = self.getter_name(f);
 
             quote_in! {*t =>
                 #(java::block_comment(&f.comment))
+                #(if f.deprecated.is_some() => @Deprecated)
                 public #(f.field_type()) get#name();
             }
         })
@@ -436,14 +835,12 @@ impl<'a> Compiler<'a> {
             let mut ann = Vec::new();
             self.options.gen.class_getter(f, &mut ann);
 
-            let ident = f.safe_ident();
-            let name = self.to_upper.convert(&f.ident);
+            if f.deprecated.is_some() {
+                ann.push(quote!(@Deprecated));
+            }
 
-            // Avoid `getClass`, a common built-in method for any Object.
-            let name = match name.as_str() {
-                "Class" => "Class_",
-                accessor => accessor,
-            };
+            let ident = f.safe_ident();
+            let name = self.getter_name(f);
 
             quote_in! { *t =>
                 #(java::block_comment(&f.comment))
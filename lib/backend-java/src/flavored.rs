@@ -5,7 +5,7 @@
 use backend::package_processor;
 use core::errors::Result;
 use core::{
-    CoreFlavor, Diagnostics, Flavor, FlavorField, FlavorTranslator, PackageTranslator,
+    CoreFlavor, Diagnostics, Flavor, FlavorField, FlavorTranslator, PackageTranslator, RpBytesType,
     RpNumberKind, RpNumberType, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
@@ -87,13 +87,30 @@ pub(crate) enum Type {
     DateTime {
         import: Rc<java::Import>,
     },
+    /// A built-in type like `LocalDate` or `UUID`, which is serialized as a string.
+    Builtin {
+        import: Rc<java::Import>,
+    },
     Import {
         import: Rc<java::Import>,
     },
+    /// A generic declaration with type arguments.
+    Generic {
+        import: Rc<java::Import>,
+        arguments: Vec<Type>,
+    },
+    /// A type parameter of the enclosing declaration.
+    Parameter {
+        ident: Rc<String>,
+    },
     List {
         list: Rc<java::Import>,
         argument: Box<Type>,
     },
+    Set {
+        set: Rc<java::Import>,
+        argument: Box<Type>,
+    },
     Map {
         map: Rc<java::Import>,
         key: Box<Type>,
@@ -141,6 +158,18 @@ impl Type {
         })
     }
 
+    /// Format a literal value of this type.
+    pub(crate) fn literal(&self, literal: &RpLiteral) -> java::Tokens {
+        match (literal, self.as_primitive()) {
+            (RpLiteral::String(string), _) => quote!(#(quoted(string))),
+            (RpLiteral::Boolean(boolean), _) => quote!(#(boolean.to_string())),
+            (RpLiteral::Number(number), Some(Primitive::Long)) => quote!(#(number.to_string())L),
+            (RpLiteral::Number(number), Some(Primitive::Float)) => quote!(#(number.to_string())F),
+            (RpLiteral::Number(number), Some(Primitive::Double)) => quote!(#(number.to_string())D),
+            (RpLiteral::Number(number), _) => quote!(#(number.to_string())),
+        }
+    }
+
     /// Convert the type into a boxed type.
     fn into_boxed(self) -> Self {
         match self {
@@ -176,10 +205,18 @@ impl FormatInto<Java> for Type {
             },
             Type::String => quote_in!(*t => String),
             Type::Import { import } => quote_in!(*t => #(&*import)),
+            Type::Generic { import, arguments } => {
+                quote_in!(*t => #(&*import)<#(for a in arguments join (,#<space>) => #a)>);
+            }
+            Type::Parameter { ident } => quote_in!(*t => #(&*ident)),
             Type::DateTime { import } => quote_in!(*t => #(&*import)),
+            Type::Builtin { import } => quote_in!(*t => #(&*import)),
             Type::List { list, argument } => {
                 quote_in!(*t => #(&*list)<#(&*argument)>);
             }
+            Type::Set { set, argument } => {
+                quote_in!(*t => #(&*set)<#(&*argument)>);
+            }
             Type::Map { map, key, value } => {
                 quote_in!(*t => #(&*map)<#(&*key), #(&*value)>);
             }
@@ -194,6 +231,14 @@ pub(crate) struct Field {
 }
 
 impl Field {
+    /// Build a required field which is not part of any declaration.
+    pub(crate) fn required(ident: &str, ty: Type) -> Self {
+        Self {
+            optional: Rc::new(java::import("java.util", "Optional")),
+            inner: RpField::new(ident, ty),
+        }
+    }
+
     pub(crate) fn field_type(&self) -> impl FormatInto<Java> + '_ {
         quote_fn! {
             #(if self.is_optional() {
@@ -208,6 +253,22 @@ impl Field {
         self.ty.optional_type(&self.optional)
     }
 
+    /// Fall back to the default value of the field, if it has one.
+    pub(crate) fn or_default(
+        &self,
+        arg: impl FormatInto<Java> + 'static,
+    ) -> impl FormatInto<Java> + '_ {
+        from_fn(move |t| match self.default_value() {
+            Some(default) => quote_in!(*t => #(&*self.optional).of(#arg.orElse(#default))),
+            None => quote_in!(*t => #arg),
+        })
+    }
+
+    /// The default value of the field as a literal, if it has one.
+    fn default_value(&self) -> Option<java::Tokens> {
+        Some(self.ty.literal(self.default.as_ref()?))
+    }
+
     pub(crate) fn to_string(
         &self,
         arg: impl FormatInto<Java> + 'static,
@@ -294,8 +355,14 @@ impl Flavor for JavaFlavor {
 pub(crate) struct JavaFlavorTranslator {
     packages: Rc<Packages>,
     list: Rc<java::Import>,
+    set: Rc<java::Import>,
     map: Rc<java::Import>,
     instant: Rc<java::Import>,
+    local_date: Rc<java::Import>,
+    local_time: Rc<java::Import>,
+    duration: Rc<java::Import>,
+    uuid: Rc<java::Import>,
+    big_decimal: Rc<java::Import>,
     byte_buffer: Rc<java::Import>,
     optional: Rc<java::Import>,
     to_upper_camel: naming::ToUpperCamel,
@@ -307,8 +374,14 @@ impl JavaFlavorTranslator {
         Self {
             packages,
             list: Rc::new(java::import("java.util", "List")),
+            set: Rc::new(java::import("java.util", "Set")),
             map: Rc::new(java::import("java.util", "Map")),
             instant: Rc::new(java::import("java.time", "Instant")),
+            local_date: Rc::new(java::import("java.time", "LocalDate")),
+            local_time: Rc::new(java::import("java.time", "LocalTime")),
+            duration: Rc::new(java::import("java.time", "Duration")),
+            uuid: Rc::new(java::import("java.util", "UUID")),
+            big_decimal: Rc::new(java::import("java.math", "BigDecimal")),
             byte_buffer: Rc::new(java::import("java.nio", "ByteBuffer")),
             optional: Rc::new(java::import("java.util", "Optional")),
             to_upper_camel: naming::to_upper_camel(),
@@ -378,6 +451,36 @@ impl FlavorTranslator for JavaFlavorTranslator {
         })
     }
 
+    fn translate_date(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            import: self.local_date.clone(),
+        }))
+    }
+
+    fn translate_time(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            import: self.local_time.clone(),
+        }))
+    }
+
+    fn translate_duration(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            import: self.duration.clone(),
+        }))
+    }
+
+    fn translate_uuid(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            import: self.uuid.clone(),
+        }))
+    }
+
+    fn translate_decimal(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            import: self.big_decimal.clone(),
+        }))
+    }
+
     fn translate_array(&self, argument: Type) -> Result<Type> {
         Ok(Type::List {
             list: self.list.clone(),
@@ -385,6 +488,13 @@ impl FlavorTranslator for JavaFlavorTranslator {
         })
     }
 
+    fn translate_set(&self, argument: Type) -> Result<Type> {
+        Ok(Type::Set {
+            set: self.set.clone(),
+            argument: Box::new(argument.into_boxed()),
+        })
+    }
+
     fn translate_map(&self, key: Type, value: Type) -> Result<Type> {
         Ok(Type::Map {
             map: self.map.clone(),
@@ -393,22 +503,42 @@ impl FlavorTranslator for JavaFlavorTranslator {
         })
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Object)
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::Import {
             import: self.byte_buffer.clone(),
         })
     }
 
-    fn translate_name(&self, _from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        _from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
         let ident = Rc::new(reg.ident(&name, |p| p.join("."), |c| c.join(".")));
         let package = name.package.join(".");
+        let import = Rc::new(java::import(package, ident));
 
-        Ok(Type::Import {
-            import: Rc::new(java::import(package, ident)),
+        if arguments.is_empty() {
+            return Ok(Type::Import { import });
+        }
+
+        let arguments = arguments.into_iter().map(Type::into_boxed).collect();
+        Ok(Type::Generic { import, arguments })
+    }
+
+    fn translate_parameter(&self, ident: String) -> Result<Type> {
+        Ok(Type::Parameter {
+            ident: Rc::new(ident),
         })
     }
 
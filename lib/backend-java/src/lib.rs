@@ -8,7 +8,7 @@ use crate::compiler::Compiler;
 use crate::options::Options;
 use core::errors::Result;
 use core::{CoreFlavor, Handle};
-use manifest::{checked_modules, Lang, Manifest, NoModule, TryFromToml};
+use manifest::{checked_modules, Lang, Language, Manifest, NoModule, TryFromToml};
 use naming::Naming;
 use std::any::Any;
 use std::path::Path;
@@ -21,6 +21,10 @@ pub struct JavaLang;
 impl Lang for JavaLang {
     manifest::lang_base!(Module, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Java)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("// {}", input))
     }
@@ -162,6 +166,7 @@ fn setup_options(modules: Vec<Module>) -> Result<Options> {
 
 fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest) -> Result<()> {
     let packages = session.packages()?;
+    let session = session.lift_unions()?;
     let session = session.translate(flavored::JavaFlavorTranslator::new(packages.clone()))?;
 
     let session = Rc::new(session);
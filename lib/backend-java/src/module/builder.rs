@@ -29,8 +29,11 @@ impl Builder {
 
 impl codegen::class::Codegen for Builder {
     fn generate(&self, e: codegen::class::Args<'_>) {
+        let type_parameters = codegen::type_parameters(e.type_parameters);
+        let diamond = codegen::diamond(e.type_parameters);
+
         e.inner.push(quote! {
-            public static class Builder {
+            public static class Builder#(&type_parameters) {
                 #(for f in e.fields join (#<push>) {
                     private #(f.optional_type()) #(f.safe_ident());
                 })
@@ -41,7 +44,7 @@ impl codegen::class::Codegen for Builder {
                     })
                 }
 
-                public #(e.ident) build() {
+                public #(e.ident)#(&type_parameters) build() {
                     #(for f in e.fields join (#<push>) {
                         #(if f.is_required() {
                             final #(&f.ty) #(f.safe_ident()) = this.#(f.safe_ident())
@@ -49,7 +52,7 @@ impl codegen::class::Codegen for Builder {
                         })
                     })
 
-                    return new #(e.ident)(
+                    return new #(e.ident)#(&diamond)(
                         #(for f in e.fields join (,#<push>) {
                             #(if f.is_optional() {
                                 this.#(f.safe_ident())
@@ -61,7 +64,7 @@ impl codegen::class::Codegen for Builder {
                 }
 
                 #(for f in e.fields join (#<line>) {
-                    public Builder #(f.safe_ident())(final #(&f.ty) #(f.safe_ident())) {
+                    public Builder#(&type_parameters) #(f.safe_ident())(final #(&f.ty) #(f.safe_ident())) {
                         this.#(f.safe_ident()) = #(&*self.optional).of(#(f.safe_ident()));
                         return this;
                     }
@@ -69,8 +72,8 @@ impl codegen::class::Codegen for Builder {
             }
 
             #(java::block_comment(&["Construct a new builder."]))
-            public static Builder builder() {
-                return new Builder();
+            public static #(if !e.type_parameters.is_empty() => #(&type_parameters)#<space>)Builder#(&type_parameters) builder() {
+                return new Builder#(&diamond)();
             }
         });
     }
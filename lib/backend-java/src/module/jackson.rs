@@ -3,7 +3,9 @@
 use crate::codegen;
 use crate::flavored::*;
 use crate::Options;
+use core::{RpBytesEncoding, Spanned};
 use genco::prelude::*;
+use genco::tokens::FormatInto;
 use std::rc::Rc;
 use RpSubTypeStrategy;
 
@@ -13,14 +15,17 @@ impl Module {
     pub fn initialize(self, options: &mut Options) {
         let generator = Rc::new(Generator::new());
 
+        options.gen.class.push(generator.clone());
         options.gen.class_constructor_arg.push(generator.clone());
         options.gen.class_constructor.push(generator.clone());
         options.gen.class_field.push(generator.clone());
         options.gen.class_getter.push(generator.clone());
         options.gen.tuple.push(generator.clone());
         options.gen.enum_ty.push(generator.clone());
+        options.gen.newtype.push(generator.clone());
         options.gen.interface.push(generator.clone());
         options.gen.interface_sub_type.push(generator.clone());
+        options.gen.union.push(generator.clone());
     }
 }
 
@@ -48,12 +53,17 @@ pub struct Generator {
     json_type_info: java::Import,
     json_sub_types: java::Import,
     tree_traversing_parser: java::Import,
+    token_buffer: java::Import,
 
     object_node: java::Import,
+    json_node: java::Import,
 
     hash_set: java::Import,
     set: java::Import,
     iterator: java::Import,
+
+    byte_buffer: java::Import,
+    base64: java::Import,
 }
 
 impl Generator {
@@ -97,13 +107,138 @@ impl Generator {
                 "com.fasterxml.jackson.databind.node",
                 "TreeTraversingParser",
             ),
+            token_buffer: java::import("com.fasterxml.jackson.databind.util", "TokenBuffer"),
 
             object_node: java::import("com.fasterxml.jackson.databind.node", "ObjectNode"),
+            json_node: java::import("com.fasterxml.jackson.databind", "JsonNode"),
             hash_set: java::import("java.util", "HashSet"),
             set: java::import("java.util", "Set"),
             iterator: java::import("java.util", "Iterator"),
+
+            byte_buffer: java::import("java.nio", "ByteBuffer"),
+            base64: java::import("java.util", "Base64"),
+        }
+    }
+
+    /// Build the serializer and deserializer classes for every bytes encoding used by the given
+    /// fields.
+    fn bytes_codecs(&self, fields: &[Spanned<Field>], inner: &mut Vec<java::Tokens>) {
+        let mut encodings = Vec::new();
+
+        for f in fields {
+            if bytes_codec(f.bytes_encoding).is_some() && !encodings.contains(&f.bytes_encoding) {
+                encodings.push(f.bytes_encoding);
+            }
+        }
+
+        for encoding in encodings {
+            inner.push(self.bytes_serializer(encoding));
+            inner.push(self.bytes_deserializer(encoding));
         }
     }
+
+    fn bytes_serializer(&self, encoding: RpBytesEncoding) -> java::Tokens {
+        let byte_buffer = &self.byte_buffer;
+        let base64 = &self.base64;
+
+        let encode = match encoding {
+            RpBytesEncoding::Base64url => {
+                quote!(gen_.writeString(#base64.getUrlEncoder().encodeToString(bytes_));)
+            }
+            RpBytesEncoding::Base64urlNopad => quote! {
+                gen_.writeString(#base64.getUrlEncoder().withoutPadding().encodeToString(bytes_));
+            },
+            _ => quote! {
+                final StringBuilder b = new StringBuilder();
+
+                for (final byte value : bytes_) {
+                    b.append(String.format("%02x", value));
+                }
+
+                gen_.writeString(b.toString());
+            },
+        };
+
+        quote! {
+            public static class #(bytes_codec(encoding))Serializer extends #(&self.json_serializer)<#byte_buffer> {
+                @Override
+                public void serialize(final #byte_buffer value_, final #(&self.json_generator) gen_, final #(&self.json_serializer_provider) provider_) throws #(&self.io_exception) {
+                    final #byte_buffer buffer_ = value_.duplicate();
+                    final byte[] bytes_ = new byte[buffer_.remaining()];
+                    buffer_.get(bytes_);
+
+                    #encode
+                }
+            }
+        }
+    }
+
+    fn bytes_deserializer(&self, encoding: RpBytesEncoding) -> java::Tokens {
+        let byte_buffer = &self.byte_buffer;
+        let base64 = &self.base64;
+
+        let decode = match encoding {
+            RpBytesEncoding::Base64url | RpBytesEncoding::Base64urlNopad => quote! {
+                try {
+                    return #byte_buffer.wrap(#base64.getUrlDecoder().decode(text_));
+                } catch (final IllegalArgumentException e) {
+                    throw ctxt_.weirdStringException(text_, #byte_buffer.class, e.getMessage());
+                }
+            },
+            _ => quote! {
+                if (text_.length() % 2 != 0) {
+                    throw ctxt_.weirdStringException(text_, #byte_buffer.class, "expected an even number of characters");
+                }
+
+                final byte[] bytes_ = new byte[text_.length() / 2];
+
+                for (int i = 0; i < bytes_.length; i++) {
+                    final int high_ = Character.digit(text_.charAt(i * 2), 16);
+                    final int low_ = Character.digit(text_.charAt(i * 2 + 1), 16);
+
+                    if (high_ < 0 || low_ < 0) {
+                        throw ctxt_.weirdStringException(text_, #byte_buffer.class, "expected hexadecimal characters");
+                    }
+
+                    bytes_[i] = (byte) ((high_ << 4) | low_);
+                }
+
+                return #byte_buffer.wrap(bytes_);
+            },
+        };
+
+        quote! {
+            public static class #(bytes_codec(encoding))Deserializer extends #(&self.json_deserializer)<#byte_buffer> {
+                @Override
+                public #byte_buffer deserialize(final #(&self.json_parser) parser_, final #(&self.deserialization_context) ctxt_) throws #(&self.io_exception) {
+                    if (parser_.currentToken() != #(&self.json_token).VALUE_STRING) {
+                        throw ctxt_.wrongTokenException(parser_, #(&self.json_token).VALUE_STRING, null);
+                    }
+
+                    final String text_ = parser_.getText();
+
+                    #decode
+                }
+            }
+        }
+    }
+}
+
+/// Prefix of the classes serializing bytes with the given encoding, if it isn't the standard
+/// base64 that jackson supports out of the box.
+fn bytes_codec(encoding: RpBytesEncoding) -> Option<&'static str> {
+    match encoding {
+        RpBytesEncoding::Base64 => None,
+        RpBytesEncoding::Base64url => Some("Base64Url"),
+        RpBytesEncoding::Base64urlNopad => Some("Base64UrlNopad"),
+        RpBytesEncoding::Hex => Some("Hex"),
+    }
+}
+
+impl codegen::class::Codegen for Generator {
+    fn generate(&self, args: codegen::class::Args<'_>) {
+        self.bytes_codecs(args.fields, args.inner);
+    }
 }
 
 impl codegen::class_constructor_arg::Codegen for Generator {
@@ -120,11 +255,33 @@ impl codegen::class_field::Codegen for Generator {
             @#(&self.json_property)(#(quoted(args.field.name())))
         });
 
-        if let Type::DateTime { .. } = args.field.ty {
+        let string_encoded = match args.field.ty {
+            Type::DateTime { .. } | Type::Builtin { .. } => true,
+            _ => args.field.is_string_encoded(),
+        };
+
+        if string_encoded {
             args.annotations.push(quote! {
                 @#(&self.json_format)(shape = #(&self.json_format).Shape.STRING)
             });
         }
+
+        if let Some(codec) = bytes_codec(args.field.bytes_encoding) {
+            // optional values are serialized as the content of the optional.
+            let using = if args.field.is_optional() {
+                "contentUsing"
+            } else {
+                "using"
+            };
+
+            args.annotations.push(quote! {
+                @#(&self.json_serialize)(#using = #(codec)Serializer.class)
+            });
+
+            args.annotations.push(quote! {
+                @#(&self.json_deserialize)(#using = #(codec)Deserializer.class)
+            });
+        }
     }
 }
 
@@ -150,6 +307,12 @@ impl Generator {
 
         quote_in! { *t =>
             #(match &f.ty {
+                _ if f.is_string_encoded() => {
+                    #d.writeString(String.valueOf(#value.#(f.safe_ident())));
+                },
+                _ if bytes_codec(f.bytes_encoding).is_some() => {
+                    new #(bytes_codec(f.bytes_encoding))Serializer().serialize(#value.#(f.safe_ident()), #d, provider_);
+                },
                 Type::Primitive { primitive } | Type::Boxed { primitive } => #(match primitive {
                     Integer | Long | Float | Double => {
                         #d.writeNumber(#value.#(f.safe_ident()));
@@ -173,6 +336,25 @@ impl Generator {
 
         quote_in! { *t =>
             #(match &f.ty {
+                ty if bytes_codec(f.bytes_encoding).is_some() => {
+                    #parser.nextToken();
+
+                    final #ty #(f.safe_ident()) = new #(bytes_codec(f.bytes_encoding))Deserializer().deserialize(#parser, #ctxt);
+                }
+                Type::Primitive { primitive: Long } | Type::Boxed { primitive: Long } if f.is_string_encoded() => {
+                    if (#parser.nextToken() != #(&self.json_token).VALUE_STRING) {
+                        throw #ctxt.wrongTokenException(#parser, #(&self.json_token).VALUE_STRING, null);
+                    }
+
+                    final long #(f.safe_ident()) = Long.parseLong(#parser.getText());
+                }
+                Type::Primitive { primitive: Integer } | Type::Boxed { primitive: Integer } if f.is_string_encoded() => {
+                    if (#parser.nextToken() != #(&self.json_token).VALUE_STRING) {
+                        throw #ctxt.wrongTokenException(#parser, #(&self.json_token).VALUE_STRING, null);
+                    }
+
+                    final int #(f.safe_ident()) = Integer.parseInt(#parser.getText());
+                }
                 Type::Primitive { primitive } | Type::Boxed { primitive } => #(match primitive {
                     Integer => {
                         if (#parser.nextToken() != #(&self.json_token).VALUE_NUMBER_INT) {
@@ -217,7 +399,10 @@ impl Generator {
 
                     final String #(f.safe_ident()) = #parser.getText();
                 },
-                ty @ Type::Object | ty @ Type::Import { .. } | ty @ Type::DateTime { .. } => {
+                ty @ Type::Object
+                | ty @ Type::Import { .. }
+                | ty @ Type::DateTime { .. }
+                | ty @ Type::Builtin { .. } => {
                     #parser.nextToken();
 
                     final #ty #(f.safe_ident()) = #parser.readValueAs(#ty.class);
@@ -291,7 +476,10 @@ impl codegen::enum_ty::Codegen for Generator {
                     }
                 }
 
-                throw new IllegalArgumentException("value");
+                #(match args.unknown {
+                    Some(_) => return new #(args.ident)(value);,
+                    None => throw new IllegalArgumentException("value");,
+                })
             }
         });
 
@@ -304,8 +492,25 @@ impl codegen::enum_ty::Codegen for Generator {
     }
 }
 
+impl codegen::newtype::Codegen for Generator {
+    fn generate(&self, args: codegen::newtype::Args<'_>) {
+        args.constructor.push(quote! {
+            @#(&self.json_creator)(mode = #(&self.json_creator).Mode.DELEGATING)
+        });
+
+        args.getter.push(quote! {
+            @#(&self.json_value)
+        });
+    }
+}
+
 impl codegen::interface::Codegen for Generator {
     fn generate(&self, args: codegen::interface::Args<'_>) {
+        if let Some(unknown) = args.unknown {
+            self.unknown_interface(args, unknown);
+            return;
+        }
+
         match args.sub_type_strategy {
             RpSubTypeStrategy::Tagged { tag } => {
                 args.annotations.push(quote! {
@@ -320,6 +525,75 @@ impl codegen::interface::Codegen for Generator {
                     })
                 });
             }
+            RpSubTypeStrategy::Adjacent { tag, content } => {
+                args.annotations.push(quote! {
+                    @#(&self.json_serialize)(using = #(args.ident).Serializer.class)
+                });
+
+                args.annotations.push(quote! {
+                    @#(&self.json_deserialize)(using = #(args.ident).Deserializer.class)
+                });
+
+                args.inner.push(quote! {
+                    public static class Serializer extends #(&self.json_serializer)<#(args.ident)> {
+                        @Override
+                        public void serialize(final #(args.ident) value, final #(&self.json_generator) generator, final #(&self.json_serializer_provider) provider) throws #(&self.io_exception) {
+                            final String tag;
+
+                            #(for s in args.sub_types join ( else ) {
+                                if (value instanceof #(args.ident).#(&s.ident)) {
+                                    tag = #(quoted(s.name()));
+                                }
+                            }) else {
+                                throw new IllegalArgumentException("unsupported sub-type: " + value);
+                            }
+
+                            generator.writeStartObject();
+                            generator.writeStringField(#(quoted(tag.as_str())), tag);
+                            generator.writeObjectField(#(quoted(content.as_str())), value);
+                            generator.writeEndObject();
+                        }
+                    }
+                });
+
+                args.inner.push(quote! {
+                    public static class Deserializer extends #(&self.json_deserializer)<#(args.ident)> {
+                        @Override
+                        public #(args.ident) deserialize(final #(&self.json_parser) parser, final #(&self.deserialization_context) ctxt) throws #(&self.io_exception) {
+                            final #(&self.object_node) object = parser.readValueAs(#(&self.object_node).class);
+
+                            final #(&self.json_node) tag = object.get(#(quoted(tag.as_str())));
+                            final #(&self.json_node) content = object.get(#(quoted(content.as_str())));
+
+                            if (tag == null || content == null) {
+                                throw ctxt.mappingException(#(quoted(format!("expected fields `{}` and `{}`", tag, content))));
+                            }
+
+                            switch (tag.asText()) {
+                            #(for s in args.sub_types join (#<push>) {
+                                case #(quoted(s.name())):
+                                    return new #(&self.tree_traversing_parser)(content, parser.getCodec()).readValueAs(#(args.ident).#(&s.ident).class);
+                            })
+                            default:
+                                throw ctxt.mappingException("bad type: " + tag.asText());
+                            }
+                        }
+                    }
+                });
+            }
+            RpSubTypeStrategy::External => {
+                args.annotations.push(quote! {
+                    @#(&self.json_type_info)(use=JsonTypeInfo.Id.NAME, include=JsonTypeInfo.As.WRAPPER_OBJECT)
+                });
+
+                args.annotations.push(quote!{
+                    @#(&self.json_sub_types)({
+                        #(for s in args.sub_types join (#<push>) {
+                            @#(&self.json_sub_types).Type(name=#(quoted(s.name())), value=#(args.ident).#(&s.ident).class),
+                        })
+                    })
+                });
+            }
             RpSubTypeStrategy::Untagged => {
                 args.annotations.push(quote! {
                     @#(&self.json_deserialize)(using = #(args.ident).Deserializer.class)
@@ -356,7 +630,19 @@ impl codegen::interface::Codegen for Generator {
 impl codegen::interface_sub_type::Codegen for Generator {
     fn generate(&self, args: codegen::interface_sub_type::Args<'_>) {
         match args.sub_type_strategy {
-            RpSubTypeStrategy::Tagged { .. } => {}
+            RpSubTypeStrategy::Tagged { .. } | RpSubTypeStrategy::External
+                if args.unknown.is_none() => {}
+            RpSubTypeStrategy::Tagged { .. }
+            | RpSubTypeStrategy::Adjacent { .. }
+            | RpSubTypeStrategy::External => {
+                args.annotations.push(quote! {
+                    @#(&self.json_serialize)(using = #(&self.json_serializer).None.class)
+                });
+
+                args.annotations.push(quote! {
+                    @#(&self.json_deserialize)(using = #(&self.json_deserializer).None.class)
+                });
+            }
             RpSubTypeStrategy::Untagged => {
                 args.annotations.push(quote! {
                     @#(&self.json_deserialize)(using = #(&self.json_deserializer).None.class)
@@ -365,3 +651,193 @@ impl codegen::interface_sub_type::Codegen for Generator {
         }
     }
 }
+
+impl Generator {
+    /// Set up the serializer and deserializer for an interface with an unknown sub-type.
+    ///
+    /// Objects with a sub-type which is not known are held as they are by the unknown sub-type.
+    fn unknown_interface(&self, args: codegen::interface::Args<'_>, unknown: &RpUnknown) {
+        let ident = args.ident;
+
+        args.annotations.push(quote! {
+            @#(&self.json_serialize)(using = #ident.Serializer.class)
+        });
+
+        args.annotations.push(quote! {
+            @#(&self.json_deserialize)(using = #ident.Deserializer.class)
+        });
+
+        args.inner.push(quote! {
+            public static class Serializer extends #(&self.json_serializer)<#ident> {
+                @Override
+                public void serialize(final #ident value, final #(&self.json_generator) generator, final #(&self.json_serializer_provider) provider) throws #(&self.io_exception) {
+                    if (value instanceof #ident.#(&unknown.ident)) {
+                        generator.writeObject(((#ident.#(&unknown.ident)) value).getValue());
+                        return;
+                    }
+
+                    final String tag;
+
+                    #(for s in args.sub_types join ( else ) {
+                        if (value instanceof #ident.#(&s.ident)) {
+                            tag = #(quoted(s.name()));
+                        }
+                    }) else {
+                        throw new IllegalArgumentException("unsupported sub-type: " + value);
+                    }
+
+                    #(match args.sub_type_strategy {
+                        RpSubTypeStrategy::Tagged { tag } => {
+                            final #(&self.token_buffer) buffer = new #(&self.token_buffer)(generator.getCodec(), false);
+                            buffer.writeObject(value);
+
+                            final #(&self.object_node) object = buffer.asParser().readValueAsTree();
+                            object.put(#(quoted(tag.as_str())), tag);
+                            generator.writeTree(object);
+                        }
+                        RpSubTypeStrategy::Adjacent { tag, content } => {
+                            generator.writeStartObject();
+                            generator.writeStringField(#(quoted(tag.as_str())), tag);
+                            generator.writeObjectField(#(quoted(content.as_str())), value);
+                            generator.writeEndObject();
+                        }
+                        _ => {
+                            generator.writeStartObject();
+                            generator.writeObjectField(tag, value);
+                            generator.writeEndObject();
+                        }
+                    })
+                }
+            }
+        });
+
+        args.inner.push(quote! {
+            public static class Deserializer extends #(&self.json_deserializer)<#ident> {
+                @Override
+                public #ident deserialize(final #(&self.json_parser) parser, final #(&self.deserialization_context) ctxt) throws #(&self.io_exception) {
+                    final #(&self.object_node) object = parser.readValueAs(#(&self.object_node).class);
+
+                    #(match args.sub_type_strategy {
+                        RpSubTypeStrategy::Tagged { tag } => {
+                            final #(&self.json_node) tag = object.get(#(quoted(tag.as_str())));
+
+                            if (tag == null) {
+                                throw ctxt.mappingException(#(quoted(format!("expected field `{}`", tag))));
+                            }
+
+                            switch (tag.asText()) {
+                            #(for s in args.sub_types join (#<push>) {
+                                case #(quoted(s.name())):
+                                    object.remove(#(quoted(tag.as_str())));
+                                    return new #(&self.tree_traversing_parser)(object, parser.getCodec()).readValueAs(#ident.#(&s.ident).class);
+                            })
+                            default:
+                                return new #ident.#(&unknown.ident)(object);
+                            }
+                        }
+                        RpSubTypeStrategy::Adjacent { tag, content } => {
+                            final #(&self.json_node) tag = object.get(#(quoted(tag.as_str())));
+                            final #(&self.json_node) content = object.get(#(quoted(content.as_str())));
+
+                            if (tag == null || content == null) {
+                                throw ctxt.mappingException(#(quoted(format!("expected fields `{}` and `{}`", tag, content))));
+                            }
+
+                            switch (tag.asText()) {
+                            #(for s in args.sub_types join (#<push>) {
+                                case #(quoted(s.name())):
+                                    return new #(&self.tree_traversing_parser)(content, parser.getCodec()).readValueAs(#ident.#(&s.ident).class);
+                            })
+                            default:
+                                return new #ident.#(&unknown.ident)(object);
+                            }
+                        }
+                        _ => {
+                            if (object.size() != 1) {
+                                throw ctxt.mappingException("expected exactly one field");
+                            }
+
+                            final String tag = object.fieldNames().next();
+
+                            switch (tag) {
+                            #(for s in args.sub_types join (#<push>) {
+                                case #(quoted(s.name())):
+                                    return new #(&self.tree_traversing_parser)(object.get(tag), parser.getCodec()).readValueAs(#ident.#(&s.ident).class);
+                            })
+                            default:
+                                return new #ident.#(&unknown.ident)(object);
+                            }
+                        }
+                    })
+                }
+            }
+        });
+    }
+
+    /// Read the current value of the parser as the given type.
+    fn read_value<'a>(&'a self, ty: &'a Type, parser: &'a str) -> impl FormatInto<Java> + 'a {
+        use Primitive::*;
+
+        quote_fn! {
+            #(match ty {
+                Type::Primitive { primitive } | Type::Boxed { primitive } => #(match primitive {
+                    Boolean => #parser.getBooleanValue(),
+                    Integer => #parser.getIntValue(),
+                    Long => #parser.getLongValue(),
+                    Float => #parser.getFloatValue(),
+                    Double => #parser.getDoubleValue(),
+                }),
+                Type::String => #parser.getText(),
+                ty @ Type::Object
+                | ty @ Type::Import { .. }
+                | ty @ Type::DateTime { .. }
+                | ty @ Type::Builtin { .. } => {
+                    #parser.readValueAs(#ty.class)
+                },
+                ty => #parser.readValueAs(new #(&self.type_reference)<#ty>() {}),
+            })
+        }
+    }
+}
+
+impl codegen::union::Codegen for Generator {
+    fn generate(&self, args: codegen::union::Args<'_>) {
+        args.annotations.push(quote! {
+            @#(&self.json_deserialize)(using = #(args.ident).Deserializer.class)
+        });
+
+        // variants are serialized through their value, but shouldn't inherit the deserializer of
+        // the union.
+        args.variant_annotations.push(quote! {
+            @#(&self.json_deserialize)(using = #(&self.json_deserializer).None.class)
+        });
+
+        args.inner.push(quote! {
+            public static class Deserializer extends #(&self.json_deserializer)<#(args.ident)> {
+                @Override
+                public #(args.ident) deserialize(final #(&self.json_parser) parser, final #(&self.deserialization_context) ctxt) throws #(&self.io_exception) {
+                    switch (parser.getCurrentToken()) {
+                    #(for (ident, v) in args.variants join (#<push>) {
+                        #(for token in tokens(v.kind) join (#<push>) => case #(*token):)
+                            return new #ident(#(self.read_value(&v.ty, "parser")));
+                    })
+                    default:
+                        throw ctxt.mappingException("no alternative of the union matches the value");
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// The tokens which a value of the given kind starts with.
+fn tokens(kind: RpUnionKind) -> &'static [&'static str] {
+    match kind {
+        RpUnionKind::String => &["VALUE_STRING"],
+        RpUnionKind::Integer => &["VALUE_NUMBER_INT"],
+        RpUnionKind::Number => &["VALUE_NUMBER_INT", "VALUE_NUMBER_FLOAT"],
+        RpUnionKind::Boolean => &["VALUE_TRUE", "VALUE_FALSE"],
+        RpUnionKind::Array => &["START_ARRAY"],
+        RpUnionKind::Object => &["START_OBJECT"],
+    }
+}
@@ -1,9 +1,9 @@
 use crate::flavored::*;
-use crate::utils::{is_defined, is_not_defined};
+use crate::utils::{is_defined, is_not_defined, Deprecated};
 use crate::{FileSpec, Options, EXT};
 use backend::PackageProcessor;
 use core::errors::Result;
-use core::{Handle, Span, Spanned};
+use core::{Handle, RpLiteral, Span, Spanned};
 use genco::prelude::*;
 use genco::tokens::FormatInto;
 use naming::Naming;
@@ -13,8 +13,10 @@ use trans::Translated;
 
 pub(crate) struct Compiler<'a> {
     pub(crate) env: &'a Translated<JavaScriptFlavor>,
+    options: Options,
     handle: &'a dyn Handle,
     to_lower_snake: naming::ToLowerSnake,
+    to_upper_snake: naming::ToUpperSnake,
     values: Tokens<JavaScript>,
     enum_name: Tokens<JavaScript>,
 }
@@ -22,13 +24,15 @@ pub(crate) struct Compiler<'a> {
 impl<'a> Compiler<'a> {
     pub(crate) fn new(
         env: &'a Translated<JavaScriptFlavor>,
-        _: Options,
+        options: Options,
         handle: &'a dyn Handle,
     ) -> Self {
         Self {
             env,
+            options,
             handle,
             to_lower_snake: naming::to_lower_snake(),
+            to_upper_snake: naming::to_upper_snake(),
             values: quote!(values),
             enum_name: quote!(name),
         }
@@ -82,12 +86,49 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    /// Encode the value of a field.
+    ///
+    /// Numbers encoded as strings are represented as a `BigInt`, since a `Number` can't represent
+    /// the full range of 64-bit numbers.
+    fn encode_field(&self, field: &RpField, var: js::Tokens) -> js::Tokens {
+        if field.is_string_encoded() {
+            return quote!(#var.toString());
+        }
+
+        field.ty.encode(var)
+    }
+
+    /// Decode the value of a field, which is stored in `var`.
+    fn decode_field<T>(&self, t: &mut js::Tokens, field: &RpField, var: T)
+    where
+        T: Copy + FormatInto<JavaScript>,
+    {
+        if field.is_string_encoded() {
+            quote_in! { *t =>
+                if (typeof #var !== "string") {
+                    throw Error("expected string");
+                }
+
+                #var = BigInt(#var);
+            }
+
+            return;
+        }
+
+        field.ty.decode(t, quote!(#var))
+    }
+
+    /// Build an encode method.
+    ///
+    /// If `wrap` is set, it is returned instead of the encoded `data`, which it is expected to
+    /// wrap.
     fn encode_method<'el, B, I>(
         &self,
         out: &mut Tokens<JavaScript>,
         fields: I,
         builder: B,
         extra: Option<Tokens<JavaScript>>,
+        wrap: Option<Tokens<JavaScript>>,
     ) where
         B: FormatInto<JavaScript>,
         I: IntoIterator<Item = &'el Spanned<RpField>>,
@@ -107,20 +148,23 @@ impl<'a> Compiler<'a> {
                         if field.is_optional() {
                             quote_in! { *out =>
                                 if (#(is_defined(&field_toks))) {
-                                    data[#(quoted(field.name()))] = #(field.ty.encode(field_toks));
+                                    data[#(quoted(field.name()))] = #(self.encode_field(field, field_toks));
                                 }
                             }
                         } else {
                             quote_in! { *out =>
                                 #(ref o => self.throw_if_null(o, &field_toks, field))
 
-                                data[#(quoted(field.name()))] = #(field.ty.encode(field_toks));
+                                data[#(quoted(field.name()))] = #(self.encode_field(field, field_toks));
                             }
                         }
                     })
                 })
 
-                return data;
+                #(match wrap {
+                    Some(wrap) => return #wrap;,
+                    None => return data;,
+                })
             }
         }
     }
@@ -137,7 +181,7 @@ impl<'a> Compiler<'a> {
                     #(ref out => {
                         let access = quote!(this.#(field.safe_ident()));
                         self.throw_if_null(out, &access, field);
-                        values.push(field.ty.encode(access));
+                        values.push(self.encode_field(field, access));
                     })
                 })
 
@@ -146,7 +190,13 @@ impl<'a> Compiler<'a> {
         }
     }
 
-    fn decode_enum_method(&self, out: &mut js::Tokens, name: &Name, field: &Spanned<RpField>) {
+    fn decode_enum_method(
+        &self,
+        out: &mut js::Tokens,
+        name: &Name,
+        field: &Spanned<RpField>,
+        unknown: Option<&RpUnknown>,
+    ) {
         let members = &quote!(#name.#(&self.values));
 
         quote_in! { *out =>
@@ -159,7 +209,14 @@ impl<'a> Compiler<'a> {
                     }
                 }
 
-                throw new Error(#(quoted(format!("no value matching: "))) + data);
+                #(match unknown {
+                    Some(unknown) => {
+                        return new #name(#(quoted(&unknown.ident)), data);
+                    }
+                    None => {
+                        throw new Error(#(quoted("no value matching: ")) + data);
+                    }
+                })
             }
         }
     }
@@ -186,9 +243,17 @@ impl<'a> Compiler<'a> {
                                 let #var_name = data[#var];
 
                                 if (#(is_defined(var_name))) {
-                                    #(ref t => field.ty.decode(t, quote!(#var_name)))
+                                    #(ref t => self.decode_field(t, field, var_name))
                                 } else {
-                                    #var_name = null;
+                                    #(if let Some(default) = &field.default {
+                                        #var_name = #(if field.is_string_encoded() {
+                                            BigInt(#(quoted(default.to_string())))
+                                        } else {
+                                            #(literal(default))
+                                        });
+                                    } else {
+                                        #var_name = null;
+                                    })
                                 }
                             }
                         } else {
@@ -199,7 +264,7 @@ impl<'a> Compiler<'a> {
                                     throw new Error(#var + ": required field");
                                 }
 
-                                #(ref t => field.ty.decode(t, quote!(#var_name)))
+                                #(ref t => self.decode_field(t, field, var_name))
                             }
                         }
                     })
@@ -295,6 +360,7 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
 
     fn process_tuple(&self, out: &mut Self::Out, body: &RpTupleBody) -> Result<()> {
         quote_in! { out.0 =>
+            #(Deprecated(body.deprecated.as_ref()))
             export class #(&body.name) {
                 #(ref o => self.build_constructor(o, &body.fields))
 
@@ -317,6 +383,15 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
         Ok(())
     }
 
+    fn process_const(&self, out: &mut Self::Out, body: &RpConstBody) -> Result<()> {
+        quote_in! { out.0 =>
+            #(Deprecated(body.deprecated.as_ref()))
+            export const #(self.to_upper_snake.convert(&body.name.ident)) = #(literal(&body.value));
+        }
+
+        Ok(())
+    }
+
     fn process_enum(&self, out: &mut Self::Out, body: &RpEnumBody) -> Result<()> {
         let mut values = Vec::new();
 
@@ -324,12 +399,13 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
             Spanned::new(RpField::new("value", body.enum_type.clone()), Span::empty());
 
         quote_in! { out.0 =>
+            #(Deprecated(body.deprecated.as_ref()))
             export class #(&body.name) {
                 #(ref o => self.build_enum_constructor(o, &variant_field))
 
                 #(ref o => self.enum_encode(o, &variant_field))
 
-                #(ref o => self.decode_enum_method(o, &body.name, &variant_field))
+                #(ref o => self.decode_enum_method(o, &body.name, &variant_field, body.unknown.as_deref()))
 
                 #(if backend::code_contains!(&body.codes, RpContext::Js) {
                     #(ref o => backend::code_in!(o, &body.codes, RpContext::Js))
@@ -341,6 +417,7 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
 
                 quote_in! { *o =>
                     #<push>
+                    #(Deprecated(v.deprecated))
                     #(&body.name).#(v.ident()) = new #(&body.name)(#(quoted(v.ident())), #(match v.value {
                         RpVariantValue::String(string) => {
                             #(quoted(string))
@@ -360,6 +437,7 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
 
     fn process_type(&self, out: &mut Self::Out, body: &RpTypeBody) -> Result<()> {
         quote_in! { out.0 =>
+            #(Deprecated(body.deprecated.as_ref()))
             export class #(&body.name) {
                 #(ref o => self.build_constructor(o, &body.fields))
 
@@ -371,7 +449,7 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
 
                 #(ref o => self.decode_method(o, &body.fields, &body.name, Self::field_by_name))
 
-                #(ref o => self.encode_method(o, &body.fields, "{}", None))
+                #(ref o => self.encode_method(o, &body.fields, "{}", None, None))
 
                 #(if backend::code_contains!(&body.codes, RpContext::Js) {
                     #(ref o => backend::code_in!(o, &body.codes, RpContext::Js))
@@ -382,13 +460,204 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
         Ok(())
     }
 
+    fn process_union(&self, out: &mut Self::Out, body: &RpUnionBody) -> Result<()> {
+        // exactly one member is set, which is the alternative holding the value.
+        let variants = body
+            .variants
+            .iter()
+            .map(|v| (self.to_lower_snake.convert(&v.ident), v))
+            .collect::<Vec<_>>();
+
+        quote_in! { out.0 =>
+            #(Deprecated(body.deprecated.as_ref()))
+            export class #(&body.name) {
+                constructor(values) {
+                    #(for (ident, _) in &variants join (#<push>) {
+                        this.#ident = values.#ident;
+                    })
+                }
+
+                static decode(data) {
+                    #(for (ident, v) in &variants join (#<line>) {
+                        if (#(union_kind_check(v.kind))) {
+                            let v = data;
+
+                            #(ref t => v.ty.decode(t, quote!(v)))
+
+                            return new #(&body.name)({#ident: v});
+                        }
+                    })
+
+                    throw new Error("no alternative of the union matches the value");
+                }
+
+                encode() {
+                    #(for (ident, v) in &variants join (#<line>) {
+                        if (#(is_defined(&quote!(this.#ident)))) {
+                            return #(v.ty.encode(quote!(this.#ident)));
+                        }
+                    })
+
+                    throw new Error("no alternative of the union is set");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_service(&self, out: &mut Self::Out, body: &RpServiceBody) -> Result<()> {
+        let websocket = match &body.websocket {
+            Some(websocket) if self.options.build_websocket => websocket,
+            _ => return Ok(()),
+        };
+
+        let name = &body.name.ident;
+        let sender = &format!("{}_WebSocket_Sender", name);
+        let receiver = &format!("{}_WebSocket_Receiver", name);
+
+        quote_in! { out.0 =>
+            class #sender {
+                constructor(socket, endpoint, encode) {
+                    this.socket = socket;
+                    this.endpoint = endpoint;
+                    this.encode = encode;
+                }
+
+                send(value) {
+                    this.socket.send(JSON.stringify({endpoint: this.endpoint, body: this.encode(value)}));
+                }
+
+                close() {
+                    this.socket.close();
+                }
+            }
+
+            class #receiver {
+                constructor(socket, endpoint, decode) {
+                    this.queue = [];
+                    this.waiting = [];
+                    this.closed = false;
+
+                    socket.addEventListener("message", (event) => {
+                        if (typeof event.data !== "string") {
+                            return;
+                        }
+
+                        let envelope;
+
+                        try {
+                            envelope = JSON.parse(event.data);
+                        } catch (e) {
+                            this.push(() => { throw e; });
+                            return;
+                        }
+
+                        if (envelope === null || typeof envelope !== "object" || envelope.endpoint !== endpoint || !("body" in envelope)) {
+                            return;
+                        }
+
+                        this.push(() => decode(envelope.body));
+                    });
+
+                    socket.addEventListener("close", () => {
+                        this.closed = true;
+
+                        for (const resolve of this.waiting.splice(0)) {
+                            resolve(() => null);
+                        }
+                    });
+                }
+
+                recv() {
+                    if (this.queue.length > 0) {
+                        return Promise.resolve().then(this.queue.shift());
+                    }
+
+                    if (this.closed) {
+                        return Promise.resolve(null);
+                    }
+
+                    return new Promise((resolve) => this.waiting.push(resolve)).then((next) => next());
+                }
+
+                push(next) {
+                    if (this.waiting.length > 0) {
+                        this.waiting.shift()(next);
+                    } else {
+                        this.queue.push(next);
+                    }
+                }
+            }
+
+            #(Deprecated(body.deprecated.as_ref()))
+            export class #(format!("{}_WebSocket", name)) {
+                constructor(url, WebSocketImpl) {
+                    #(match &websocket.url {
+                        Some(url) => {
+                            if (#(is_not_defined("url"))) {
+                                url = #(quoted(url.as_str()));
+                            }
+                        }
+                        None => {
+                            if (#(is_not_defined("url"))) {
+                                throw new Error("missing 'url' argument");
+                            }
+                        }
+                    })
+
+                    this.url = url;
+                    this.WebSocket = WebSocketImpl || WebSocket;
+                }
+
+                #(for e in body.endpoints.iter().filter(|e| e.is_bidirectional()) join (#<line>) {
+                    #(ref t =>
+                        let (req, res) = match (&e.request, &e.response) {
+                            (Some(req), Some(res)) => (req.channel.ty(), res.ty()),
+                            _ => continue,
+                        };
+
+                        quote_in! { *t =>
+                            #(Deprecated(e.deprecated.as_ref()))
+                            #(e.safe_ident())() {
+                                const socket = new this.WebSocket(this.url + #(quoted(websocket.path.as_str())));
+
+                                const sender = new #sender(socket, #(quoted(e.name())), (value) => #(req.encode(quote!(value))));
+
+                                const receiver = new #receiver(socket, #(quoted(e.name())), (data) => {
+                                    #(ref t => res.decode(t, quote!(data)))
+
+                                    return data;
+                                });
+
+                                return new Promise((resolve, reject) => {
+                                    socket.addEventListener("open", () => resolve([sender, receiver]));
+                                    socket.addEventListener("error", (e) => reject(e));
+                                });
+                            }
+                        }
+                    )
+                })
+            }
+        }
+
+        Ok(())
+    }
+
     fn process_interface(&self, out: &mut Self::Out, body: &RpInterfaceBody) -> Result<()> {
         quote_in! { out.0 =>
+            #(Deprecated(body.deprecated.as_ref()))
             export class #(&body.name) {
                 #(match &body.sub_type_strategy {
                     RpSubTypeStrategy::Tagged { tag, .. } => {
                         #(ref o => decode(o, &body, tag.as_str()))
                     }
+                    RpSubTypeStrategy::Adjacent { tag, content } => {
+                        #(ref o => decode_adjacent(o, body, tag.as_str(), content.as_str()))
+                    }
+                    RpSubTypeStrategy::External => {
+                        #(ref o => decode_external(o, body))
+                    }
                     RpSubTypeStrategy::Untagged => {
                         #(ref o => decode_untagged(o, body))
                     }
@@ -400,6 +669,7 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
             }
 
             #(for sub_type in &body.sub_types {
+                #(Deprecated(sub_type.deprecated.as_ref()))
                 export class #(&sub_type.name) {
                     #(ref o => self.build_constructor(o, body.fields.iter().chain(sub_type.fields.iter())))
 
@@ -425,13 +695,36 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
                                     o,
                                     body.fields.iter().chain(sub_type.fields.iter()),
                                     "{}",
-                                    Some(quote!(data[#(quoted(tag))] = #(quoted(sub_type.name()));))
+                                    Some(quote!(data[#(quoted(tag))] = #(quoted(sub_type.name()));)),
+                                    None,
+                                )
+                            })
+                        }
+                        RpSubTypeStrategy::Adjacent { tag, content } => {
+                            #(ref o => {
+                                self.encode_method(
+                                    o,
+                                    body.fields.iter().chain(sub_type.fields.iter()),
+                                    "{}",
+                                    None,
+                                    Some(quote!({#(quoted(tag)): #(quoted(sub_type.name())), #(quoted(content)): data})),
+                                )
+                            })
+                        }
+                        RpSubTypeStrategy::External => {
+                            #(ref o => {
+                                self.encode_method(
+                                    o,
+                                    body.fields.iter().chain(sub_type.fields.iter()),
+                                    "{}",
+                                    None,
+                                    Some(quote!({#(quoted(sub_type.name())): data})),
                                 )
                             })
                         }
                         RpSubTypeStrategy::Untagged => {
                             #(ref o => {
-                                self.encode_method(o, body.fields.iter().chain(sub_type.fields.iter()), "{}", None)
+                                self.encode_method(o, body.fields.iter().chain(sub_type.fields.iter()), "{}", None, None)
                             })
                         }
                     })
@@ -441,6 +734,18 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
                     })
                 }
             })
+
+            #(if let Some(unknown) = &body.unknown {
+                export class #(&unknown.name) {
+                    constructor(data) {
+                        this.data = data;
+                    }
+
+                    encode() {
+                        return this.data;
+                    }
+                }
+            })
         }
 
         return Ok(());
@@ -460,11 +765,71 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
                         }
                     })
 
-                    throw new Error("bad sub-type: " + f_tag);
+                    #(ref o => bad_sub_type(o, body))
                 }
             }
         }
 
+        fn decode_adjacent(out: &mut js::Tokens, body: &RpInterfaceBody, tag: &str, content: &str) {
+            quote_in! { *out =>
+                static decode(data) {
+                    const f_tag = data[#(quoted(tag))];
+
+                    if (#(is_not_defined("f_tag"))) {
+                        throw new Error(#(quoted(format!("missing tag field: {}", tag))));
+                    }
+
+                    const f_content = data[#(quoted(content))];
+
+                    if (#(is_not_defined("f_content"))) {
+                        throw new Error(#(quoted(format!("missing content field: {}", content))));
+                    }
+
+                    #(for sub_type in body.sub_types.iter() join (#<line>) {
+                        if (f_tag === #(quoted(sub_type.name()))) {
+                            return #(&sub_type.name).decode(f_content);
+                        }
+                    })
+
+                    #(ref o => bad_sub_type(o, body))
+                }
+            }
+        }
+
+        fn decode_external(out: &mut js::Tokens, body: &RpInterfaceBody) {
+            quote_in! { *out =>
+                static decode(data) {
+                    const keys = Object.keys(data);
+
+                    if (keys.length !== 1) {
+                        throw new Error("expected exactly one key");
+                    }
+
+                    const f_tag = keys[0];
+
+                    #(for sub_type in body.sub_types.iter() join (#<line>) {
+                        if (f_tag === #(quoted(sub_type.name()))) {
+                            return #(&sub_type.name).decode(data[f_tag]);
+                        }
+                    })
+
+                    #(ref o => bad_sub_type(o, body))
+                }
+            }
+        }
+
+        /// Handle a sub-type which is not known, falling back to the unknown sub-type if present.
+        fn bad_sub_type(out: &mut js::Tokens, body: &RpInterfaceBody) {
+            match &body.unknown {
+                Some(unknown) => quote_in! { *out =>
+                    return new #(&unknown.name)(data);
+                },
+                None => quote_in! { *out =>
+                    throw new Error("bad sub-type: " + f_tag);
+                },
+            }
+        }
+
         fn decode_untagged(out: &mut js::Tokens, body: &RpInterfaceBody) {
             quote_in! { *out =>
                 static decode(data) {
@@ -487,3 +852,26 @@ impl<'a> PackageProcessor<'a, JavaScriptFlavor> for Compiler<'a> {
         }
     }
 }
+
+/// Check if `data` is a JSON value of the given kind.
+fn union_kind_check(kind: RpUnionKind) -> js::Tokens {
+    match kind {
+        RpUnionKind::String => quote!(typeof data === "string"),
+        RpUnionKind::Integer => quote!(Number.isInteger(data)),
+        RpUnionKind::Number => quote!(typeof data === "number"),
+        RpUnionKind::Boolean => quote!(typeof data === "boolean"),
+        RpUnionKind::Array => quote!(Array.isArray(data)),
+        RpUnionKind::Object => {
+            quote!(data !== null && typeof data === "object" && !Array.isArray(data))
+        }
+    }
+}
+
+/// Convert a literal value into a JavaScript literal.
+fn literal(literal: &RpLiteral) -> js::Tokens {
+    match literal {
+        RpLiteral::String(string) => quote!(#(quoted(string))),
+        RpLiteral::Number(number) => quote!(#(number.to_string())),
+        RpLiteral::Boolean(boolean) => quote!(#(boolean.to_string())),
+    }
+}
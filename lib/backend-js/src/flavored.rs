@@ -4,8 +4,8 @@ use crate::TYPE_SEP;
 use backend::package_processor;
 use core::errors::Result;
 use core::{
-    self, CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpNumberType,
-    RpStringType, Spanned, Translate, Translator,
+    self, CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpBytesType,
+    RpNumberType, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr};
@@ -102,7 +102,7 @@ impl Type {
                     let #o = {};
 
                     for (let [#k, #v] of Object.entries(#var)) {
-                        #(ref t => key.decode_depth(t, k, d + 1))
+                        #(ref t => key.decode_key(t, k, d + 1))
                         #(ref t => value.decode_depth(t, v, d + 1))
 
                         #o[#k] = #v;
@@ -120,6 +120,31 @@ impl Type {
         }
     }
 
+    /// Build decode method for a map key.
+    ///
+    /// Keys are kept as the strings they are encoded as, since that is all an object key can be.
+    fn decode_key<T>(&self, t: &mut js::Tokens, var: T, d: usize)
+    where
+        T: FormatInto<JavaScript> + Copy,
+    {
+        match self {
+            Self::Integer => {
+                quote_in! { *t =>
+                    if (!Number.isInteger(Number(#var))) {
+                        throw Error("expected integer");
+                    }
+                }
+            }
+            Self::Import { import } => quote_in! { *t =>
+                #import.decode(#var);
+            },
+            Self::Local { ident } => quote_in! { *t =>
+                #ident.decode(#var);
+            },
+            key => key.decode_depth(t, var, d),
+        }
+    }
+
     /// Build encode method.
     pub(crate) fn encode(&self, var: js::Tokens) -> js::Tokens {
         match self {
@@ -132,16 +157,15 @@ impl Type {
                 let v = argument.encode(quote!(v));
                 quote!(#var.map(function(v) { return #v; }))
             }
-            Self::Map { key, value } => {
-                let k = &key.encode(quote!(k));
-                let v = &value.encode(quote!(data[#k]));
+            Self::Map { value, .. } => {
+                let v = &value.encode(quote!(data[k]));
 
                 quote! {
                     (function(data) {
                         let o = {};
 
                         for (let k in data) {
-                            o[#k] = #v;
+                            o[k] = #v;
                         }
 
                         return o;
@@ -156,8 +180,8 @@ impl Type {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Name {
-    ident: ItemStr,
-    package: RpPackage,
+    pub(crate) ident: ItemStr,
+    pub(crate) package: RpPackage,
 }
 
 impl<'a> FormatInto<JavaScript> for &'a Name {
@@ -238,15 +262,29 @@ impl FlavorTranslator for JavaScriptFlavorTranslator {
         })
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Object)
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::String)
     }
 
-    fn translate_name(&self, _from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        _from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
+        if !arguments.is_empty() {
+            return Err("generic declarations must be monomorphized".into());
+        }
+
         let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |c| c.join(TYPE_SEP));
 
         if let Some(used) = &name.prefix {
@@ -262,6 +300,10 @@ impl FlavorTranslator for JavaScriptFlavorTranslator {
         })
     }
 
+    fn translate_parameter(&self, _: String) -> Result<Type> {
+        Err("generic declarations must be monomorphized".into())
+    }
+
     fn translate_package(&self, source: RpVersionedPackage) -> Result<RpPackage> {
         Ok(self.packages.translate_package(source)?)
     }
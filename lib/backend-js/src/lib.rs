@@ -6,7 +6,7 @@ use crate::compiler::Compiler;
 use core::errors::Result;
 use core::{CoreFlavor, Handle};
 use genco::prelude::*;
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::path::Path;
 use trans::Session;
@@ -20,6 +20,10 @@ pub struct JsLang;
 impl Lang for JsLang {
     manifest::lang_base!(JsModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Js)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("# {}", input))
     }
@@ -98,21 +102,35 @@ impl Lang for JsLang {
 }
 
 #[derive(Debug)]
-pub enum JsModule {}
+pub enum JsModule {
+    WebSocket,
+}
 
 impl TryFromToml for JsModule {
     fn try_from_string(path: &Path, id: &str, value: String) -> Result<Self> {
-        NoModule::illegal(path, id, value)
+        let result = match id {
+            "websocket" => JsModule::WebSocket,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 
     fn try_from_value(path: &Path, id: &str, value: toml::Value) -> Result<Self> {
-        NoModule::illegal(path, id, value)
+        let result = match id {
+            "websocket" => JsModule::WebSocket,
+            _ => return NoModule::illegal(path, id, value),
+        };
+
+        Ok(result)
     }
 }
 
 pub struct Options {
     pub build_getters: bool,
     pub build_constructor: bool,
+    /// Build WebSocket clients for services with bi-directional endpoints.
+    pub build_websocket: bool,
 }
 
 impl Options {
@@ -120,6 +138,7 @@ impl Options {
         Options {
             build_getters: false,
             build_constructor: true,
+            build_websocket: false,
         }
     }
 }
@@ -133,12 +152,19 @@ impl Default for FileSpec {
 }
 
 fn compile(handle: &dyn Handle, env: Session<CoreFlavor>, manifest: Manifest) -> Result<()> {
+    let env = env.monomorphize()?.inline_newtypes()?.lift_unions()?;
     let packages = env.packages()?;
 
     let env = env.translate(flavored::JavaScriptFlavorTranslator::new(packages))?;
 
-    let _modules: Vec<JsModule> = manifest::checked_modules(manifest.modules)?;
-    let options = Options::new();
+    let modules: Vec<JsModule> = manifest::checked_modules(manifest.modules)?;
+    let mut options = Options::new();
+
+    for module in modules {
+        match module {
+            JsModule::WebSocket => options.build_websocket = true,
+        }
+    }
 
     Compiler::new(&env, options, handle).compile()
 }
@@ -1,6 +1,29 @@
+use core::RpDeprecated;
 use genco::prelude::*;
 use genco::tokens::FormatInto;
 
+/// JSDoc comment with a `@deprecated` tag, if the item is deprecated.
+pub struct Deprecated<'a>(pub Option<&'a RpDeprecated>);
+
+impl<'a> FormatInto<JavaScript> for Deprecated<'a> {
+    fn format_into(self, out: &mut Tokens<JavaScript>) {
+        let deprecated = match self.0 {
+            Some(deprecated) => deprecated,
+            None => return,
+        };
+
+        let doc = match (&deprecated.note, &deprecated.since) {
+            (Some(note), Some(since)) => format!("/** @deprecated {} (since {}) */", note, since),
+            (Some(note), None) => format!("/** @deprecated {} */", note),
+            (None, Some(since)) => format!("/** @deprecated since {} */", since),
+            (None, None) => String::from("/** @deprecated */"),
+        };
+
+        out.push();
+        quote_in!(*out => #doc);
+    }
+}
+
 pub fn is_defined<T>(expr: T) -> Tokens<JavaScript>
 where
     T: Copy + FormatInto<JavaScript>,
@@ -1,6 +1,6 @@
 use core::errors::Result;
 use core::{CoreFlavor, Handle, RelativePathBuf};
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::path::Path;
 use trans::Session;
@@ -10,6 +10,10 @@ pub struct JsonLang;
 
 impl Lang for JsonLang {
     manifest::lang_base!(JsonModule, compile);
+
+    fn language(&self) -> Option<Language> {
+        Some(Language::Json)
+    }
 }
 
 #[derive(Debug)]
@@ -20,10 +20,11 @@ use self::spec::*;
 use core::errors::Result;
 use core::flavored::*;
 use core::{
-    CoreFlavor, Handle, RelativePath, RelativePathBuf, RpHttpMethod, RpNumberKind, Spanned,
+    CoreFlavor, Handle, RelativePath, RelativePathBuf, RpBytesEncoding, RpCheck, RpHttpMethod,
+    RpLiteral, RpNumberKind, Spanned,
 };
 use linked_hash_map::LinkedHashMap;
-use manifest::{checked_modules, Lang, Manifest, NoModule, TryFromToml};
+use manifest::{checked_modules, Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::cell::RefCell;
 use std::collections::{hash_map, HashMap, HashSet, VecDeque};
@@ -35,6 +36,10 @@ pub struct OpenApiLang;
 
 impl Lang for OpenApiLang {
     manifest::lang_base!(OpenApiModule, compile);
+
+    fn language(&self) -> Option<Language> {
+        Some(Language::OpenApi)
+    }
 }
 
 #[derive(Debug)]
@@ -67,7 +72,7 @@ impl TryFromToml for OpenApiModule {
 }
 
 fn compile(handle: &dyn Handle, env: Session<CoreFlavor>, manifest: Manifest) -> Result<()> {
-    let env = env.translate_default()?;
+    let env = env.monomorphize()?.inline_newtypes()?.translate_default()?;
 
     let modules = checked_modules(manifest.modules)?;
 
@@ -177,6 +182,8 @@ impl<'handle> Compiler<'handle> {
 pub enum Queued<'a> {
     /// Create an entity for the given sub-type.
     TaggedSubType(&'a str, &'a RpName, usize),
+    /// Create an entity for the given sub-type, with the fields nested in a content field.
+    AdjacentSubType(&'a str, &'a str, &'a RpName, usize),
     Named(&'a RpName),
     Any,
 }
@@ -281,27 +288,59 @@ impl<'builder> SpecBuilder<'builder> {
                 method.parameters.push(param);
             }
 
+            let parameters = e
+                .http
+                .query
+                .iter()
+                .map(|p| (ParameterIn::Query, p))
+                .chain(e.http.headers.iter().map(|p| (ParameterIn::Header, p)));
+
+            for (in_, p) in parameters {
+                let schema = self.type_to_schema(&mut queue, p.argument.channel.ty())?;
+
+                let param = spec::Parameter {
+                    name: p.name.as_str(),
+                    required: p.argument.required,
+                    in_,
+                    description: None,
+                    schema,
+                };
+
+                method.parameters.push(param);
+            }
+
             method.operation_id = Some(e.safe_ident());
 
             if !e.comment.is_empty() {
                 method.description = Some(e.comment.join("\n"));
             }
 
+            method.deprecated = e.deprecated.is_some();
+
             if let Some(req) = e.request.as_ref() {
                 let mut request =
-                    self.channel_to_content(&mut queue, RpAccept::Json, &req.channel)?;
+                    self.channel_to_content(&mut queue, e.http.content_type, &req.channel)?;
                 request.required = true;
                 method.request_body = Some(request);
             }
 
             let response = if let Some(res) = e.response.as_ref() {
-                self.channel_to_content(&mut queue, e.http.accept, res)?
+                match e.http.stream {
+                    Some(stream) => self.stream_to_content(&mut queue, stream, res)?,
+                    None => self.channel_to_content(&mut queue, e.http.accept, res)?,
+                }
             } else {
                 // empty by default
                 Payload::default()
             };
 
-            method.responses.insert("200", response);
+            let status = e.http.status.unwrap_or(200);
+            method.responses.insert(status.to_string(), response);
+
+            for error in &e.http.errors {
+                let response = self.type_to_content(&mut queue, RpAccept::Json, &error.ty)?;
+                method.responses.insert(error.status.to_string(), response);
+            }
         }
 
         self.process_components(queue, &mut spec)?;
@@ -323,19 +362,52 @@ impl<'builder> SpecBuilder<'builder> {
         accept: RpAccept,
         channel: &'builder RpChannel,
     ) -> Result<Payload<'builder>> {
-        let schema = self.type_to_schema(queue, channel.ty())?;
+        self.type_to_content(queue, accept, channel.ty())
+    }
+
+    /// Convert a streaming response into a payload, where the schema describes a single element.
+    fn stream_to_content(
+        &self,
+        queue: &mut VecDeque<Queued<'builder>>,
+        stream: RpHttpStream,
+        channel: &'builder RpChannel,
+    ) -> Result<Payload<'builder>> {
+        let ty = channel.ty();
+        let schema = self.type_to_schema(queue, ty)?;
+
+        if let RpType::Name { ref name, .. } = *ty {
+            queue.push_back(Queued::Named(Spanned::borrow(name)));
+        }
 
-        let content_type = match accept {
-            RpAccept::Text => "text/plain",
-            RpAccept::Json => "application/json",
+        let mut payload = Payload::default();
+        payload.content.insert(stream.as_str(), Content { schema });
+        Ok(payload)
+    }
+
+    /// Convert a type into request/response payload.
+    fn type_to_content(
+        &self,
+        queue: &mut VecDeque<Queued<'builder>>,
+        accept: RpAccept,
+        ty: &'builder RpType,
+    ) -> Result<Payload<'builder>> {
+        let ty = match (accept, ty) {
+            // Each line is a separate document, so the schema describes a single element.
+            (RpAccept::NdJson, RpType::Array { inner }) => &**inner,
+            _ => ty,
         };
 
-        if let RpType::Name { ref name } = *channel.ty() {
+        let schema = match accept {
+            RpAccept::Bytes => string_format(spec::Format::Binary),
+            _ => self.type_to_schema(queue, ty)?,
+        };
+
+        if let RpType::Name { ref name, .. } = *ty {
             queue.push_back(Queued::Named(Spanned::borrow(name)));
         }
 
         let mut payload = Payload::default();
-        payload.content.insert(content_type, Content { schema });
+        payload.content.insert(accept.as_str(), Content { schema });
         Ok(payload)
     }
 
@@ -379,6 +451,9 @@ impl<'builder> SpecBuilder<'builder> {
                 TaggedSubType(tag, name, index) => {
                     self.process_tagged_sub_type(&mut queue, tag, name, index)?
                 }
+                AdjacentSubType(tag, content, name, index) => {
+                    self.process_adjacent_sub_type(&mut queue, tag, content, name, index)?
+                }
                 Any => {
                     let ref_ = self.name_to_ref(self.any_type)?;
                     (ref_, spec::Schema::from(spec::SchemaAny))
@@ -400,15 +475,7 @@ impl<'builder> SpecBuilder<'builder> {
         name: &RpName,
         index: usize,
     ) -> Result<(String, spec::Schema<'builder>)> {
-        let decl = self.env.lookup_decl(name)?;
-
-        let (body, sub_type) = match *decl {
-            RpDecl::Interface(ref body) => match body.sub_types.get(index) {
-                Some(sub_type) => (body, sub_type),
-                None => return Err("bad sub-type index".into()),
-            },
-            _ => return Err("name does not refer to an interface".into()),
-        };
+        let (body, sub_type) = self.lookup_sub_type(name, index)?;
 
         let ref_ = self.name_to_ref(&sub_type.name)?;
 
@@ -420,18 +487,80 @@ impl<'builder> SpecBuilder<'builder> {
         object.required.push(tag);
         object.properties.insert(tag, schema);
 
+        self.populate_sub_type(queue, &mut object, body, sub_type)?;
+        Ok((ref_, spec::Schema::from(object)))
+    }
+
+    /// Process a single adjacently tagged sub-type, creating a component that can be referenced.
+    fn process_adjacent_sub_type(
+        &self,
+        queue: &mut VecDeque<Queued<'builder>>,
+        tag: &'builder str,
+        content: &'builder str,
+        name: &RpName,
+        index: usize,
+    ) -> Result<(String, spec::Schema<'builder>)> {
+        let (body, sub_type) = self.lookup_sub_type(name, index)?;
+
+        let ref_ = self.name_to_ref(&sub_type.name)?;
+
+        let mut inner = spec::Object::default();
+        self.populate_sub_type(queue, &mut inner, body, sub_type)?;
+
+        let mut object = spec::Object {
+            description: inner.description.take(),
+            deprecated: inner.deprecated,
+            ..spec::Object::default()
+        };
+
+        // add the discriminator field
+        object.required.push(tag);
+        object
+            .properties
+            .insert(tag, spec::Schema::from(spec::SchemaString::default()));
+
+        object.required.push(content);
+        object.properties.insert(content, spec::Schema::from(inner));
+
+        Ok((ref_, spec::Schema::from(object)))
+    }
+
+    /// Lookup the sub-type with the given index in the named interface.
+    fn lookup_sub_type(
+        &self,
+        name: &RpName,
+        index: usize,
+    ) -> Result<(&'builder RpInterfaceBody, &'builder RpSubType)> {
+        let decl = self.env.lookup_decl(name)?;
+
+        match *decl {
+            RpDecl::Interface(ref body) => match body.sub_types.get(index) {
+                Some(sub_type) => Ok((body, sub_type)),
+                None => Err("bad sub-type index".into()),
+            },
+            _ => Err("name does not refer to an interface".into()),
+        }
+    }
+
+    /// Populate the given object with the fields, description, and deprecation of a sub-type.
+    fn populate_sub_type(
+        &self,
+        queue: &mut VecDeque<Queued<'builder>>,
+        object: &mut spec::Object<'builder>,
+        body: &'builder RpInterfaceBody,
+        sub_type: &'builder RpSubType,
+    ) -> Result<()> {
         let mut fields = Vec::new();
         fields.extend(body.fields());
-
-        let mut fields = fields.clone();
         fields.extend(sub_type.fields());
 
         if !sub_type.comment.is_empty() {
             object.description = Some(sub_type.comment.join("\n"));
         }
 
-        self.populate_properties(queue, &mut object, fields)?;
-        Ok((ref_, spec::Schema::from(object)))
+        object.deprecated = sub_type.deprecated.is_some();
+
+        self.populate_properties(queue, object, fields)
     }
 
     /// Convert a declaration into a set of properties.
@@ -446,6 +575,8 @@ impl<'builder> SpecBuilder<'builder> {
             object.description = Some(body.comment.join("\n"));
         }
 
+        object.deprecated = body.deprecated.is_some();
+
         self.populate_properties(queue, &mut object, body.fields())?;
         Ok(spec::Schema::from(object))
     }
@@ -462,22 +593,32 @@ impl<'builder> SpecBuilder<'builder> {
             schema.description = Some(body.comment.join("\n"));
         }
 
+        schema.deprecated = body.deprecated.is_some();
+
         match body.sub_type_strategy {
             RpSubTypeStrategy::Untagged => {
-                let mut fields = Vec::new();
-                fields.extend(body.fields());
-
                 for sub_type in &body.sub_types {
-                    let mut fields = fields.clone();
-                    fields.extend(sub_type.fields());
-
                     let mut object = spec::Object::default();
+                    self.populate_sub_type(queue, &mut object, body, sub_type)?;
+                    schema.one_of.push(spec::Schema::from(object));
+                }
+            }
+            RpSubTypeStrategy::External => {
+                for sub_type in &body.sub_types {
+                    let mut inner = spec::Object::default();
+                    self.populate_sub_type(queue, &mut inner, body, sub_type)?;
 
-                    if !sub_type.comment.is_empty() {
-                        object.description = Some(sub_type.comment.join("\n"));
-                    }
+                    let mut object = spec::Object {
+                        description: inner.description.take(),
+                        deprecated: inner.deprecated,
+                        ..spec::Object::default()
+                    };
+
+                    object.required.push(sub_type.name());
+                    object
+                        .properties
+                        .insert(sub_type.name(), spec::Schema::from(inner));
 
-                    self.populate_properties(queue, &mut object, fields)?;
                     schema.one_of.push(spec::Schema::from(object));
                 }
             }
@@ -498,10 +639,45 @@ impl<'builder> SpecBuilder<'builder> {
                     discriminator.mapping.insert(sub_type.name(), ref_);
                 }
 
+                schema.discriminator = Some(discriminator);
+            }
+            RpSubTypeStrategy::Adjacent {
+                ref tag,
+                ref content,
+            } => {
+                let mut discriminator = spec::Discriminator {
+                    property_name: Some(tag),
+                    ..spec::Discriminator::default()
+                };
+
+                for (index, sub_type) in body.sub_types.iter().enumerate() {
+                    let ref_ = self.name_to_ref(&sub_type.name)?;
+                    let ref_ = format!("#/components/schemas/{}", ref_);
+
+                    schema
+                        .one_of
+                        .push(spec::Schema::from(spec::Ref(ref_.to_string())));
+                    queue.push_back(Queued::AdjacentSubType(tag, content, &body.name, index));
+
+                    discriminator.mapping.insert(sub_type.name(), ref_);
+                }
+
                 schema.discriminator = Some(discriminator);
             }
         }
 
+        // an unknown sub-type can be any object, so the known sub-types no longer exclude it.
+        if let Some(unknown) = &body.unknown {
+            let mut object = spec::Object::default();
+
+            if !unknown.comment.is_empty() {
+                object.description = Some(unknown.comment.join("\n"));
+            }
+
+            schema.any_of = std::mem::take(&mut schema.one_of);
+            schema.any_of.push(spec::Schema::from(object));
+        }
+
         Ok(schema)
     }
 
@@ -520,17 +696,19 @@ impl<'builder> SpecBuilder<'builder> {
             array.required.push(index);
 
             // reference to external type, so add to queue.
-            if let RpType::Name { name } = &field.ty {
+            if let RpType::Name { name, .. } = &field.ty {
                 queue.push_back(Queued::Named(name));
             }
         }
 
-        Ok(spec::Schema::from(array))
+        let mut schema = spec::Schema::from(array);
+        schema.deprecated = body.deprecated.is_some();
+        Ok(schema)
     }
 
     /// Convert a declaration into a set of properties.
     fn decl_enum_to_schema(&self, body: &'builder RpEnumBody) -> Result<spec::Schema<'builder>> {
-        let out = match body.variants {
+        let mut out = match body.variants {
             RpVariants::String { ref variants } => {
                 let mut string = spec::SchemaString::default();
 
@@ -552,6 +730,12 @@ impl<'builder> SpecBuilder<'builder> {
             },
         };
 
+        // values of an enum with an unknown variant are not restricted to the known variants.
+        if body.unknown.is_some() {
+            out.enum_ = spec::Enum::None;
+        }
+
+        out.deprecated = body.deprecated.is_some();
         Ok(out)
     }
 
@@ -645,11 +829,14 @@ impl<'builder> SpecBuilder<'builder> {
         ty: &'builder RpType,
     ) -> Result<spec::Schema<'builder>> {
         let out = match ty {
-            RpType::Name { name } => {
+            RpType::Name { name, .. } => {
                 let ref_ = self.name_to_ref(name)?;
                 spec::Schema::from(Ref(format!("#/components/schemas/{}", ref_)))
             }
-            // NB: only string keys are supported right now.
+            RpType::Parameter { .. } => {
+                return Err("type parameters must be monomorphized".into());
+            }
+            // NB: keys are always represented as strings in JSON.
             RpType::Map { value, .. } => {
                 let mut object = spec::Object::default();
                 object.additional_properties = Some(Box::new(self.type_to_schema(queue, value)?));
@@ -660,7 +847,35 @@ impl<'builder> SpecBuilder<'builder> {
                 array.items = Some(Box::new(self.type_to_schema(queue, inner)?));
                 spec::Schema::from(array)
             }
+            RpType::Set { inner } => {
+                let array = spec::SchemaArray {
+                    items: Some(Box::new(self.type_to_schema(queue, inner)?)),
+                    unique_items: true,
+                    ..spec::SchemaArray::default()
+                };
+
+                spec::Schema::from(array)
+            }
+            RpType::Union { alternatives } => {
+                let mut schema = spec::Schema::default();
+
+                for alternative in alternatives {
+                    schema.one_of.push(self.type_to_schema(queue, alternative)?);
+
+                    if let RpType::Name { name, .. } = alternative {
+                        queue.push_back(Queued::Named(name));
+                    }
+                }
+
+                schema
+            }
             RpType::String(..) => spec::Schema::from(spec::SchemaString::default()),
+            RpType::Number(number) if number.encoding.is_string() => match number.kind {
+                RpNumberKind::I32 => string_format(spec::Format::I32),
+                RpNumberKind::I64 => string_format(spec::Format::I64),
+                RpNumberKind::U32 => string_format(spec::Format::U32),
+                RpNumberKind::U64 => string_format(spec::Format::U64),
+            },
             RpType::Number(number) => match number.kind {
                 RpNumberKind::I32 => spec::Schema::from(spec::I32::default()),
                 RpNumberKind::I64 => spec::Schema::from(spec::I64::default()),
@@ -670,16 +885,13 @@ impl<'builder> SpecBuilder<'builder> {
             RpType::Float => spec::Schema::from(spec::Float::default()),
             RpType::Double => spec::Schema::from(spec::Double::default()),
             RpType::Boolean => spec::Schema::from(spec::SchemaBoolean::default()),
-            RpType::DateTime => {
-                let mut string = spec::SchemaString::default();
-                string.format = Some(spec::Format::DateTime);
-                spec::Schema::from(string)
-            }
-            RpType::Bytes => {
-                let mut string = spec::SchemaString::default();
-                string.format = Some(spec::Format::Byte);
-                spec::Schema::from(string)
-            }
+            RpType::DateTime => string_format(spec::Format::DateTime),
+            RpType::Date => string_format(spec::Format::Date),
+            RpType::Time => string_format(spec::Format::Time),
+            RpType::Duration => string_format(spec::Format::Duration),
+            RpType::Uuid => string_format(spec::Format::Uuid),
+            RpType::Decimal => string_format(spec::Format::Decimal),
+            RpType::Bytes(bytes) => bytes_schema(bytes.encoding),
             RpType::Any => {
                 queue.push_back(Queued::Any);
                 let ref_ = self.name_to_ref(&self.any_type)?;
@@ -712,10 +924,38 @@ impl<'builder> SpecBuilder<'builder> {
                 schema.description = Some(field.comment.join("\n"));
             }
 
+            schema.deprecated = field.deprecated.is_some();
+            schema.default = field.default.as_ref();
+
+            // numeric bounds can't be expressed for numbers encoded as strings.
+            let checks = if field.is_string_encoded() {
+                Vec::new()
+            } else {
+                field.validate.checks()
+            };
+
+            for check in checks {
+                match check {
+                    RpCheck::MinLength(n) => schema.min_length = Some(n),
+                    RpCheck::MaxLength(n) => schema.max_length = Some(n),
+                    RpCheck::Pattern(pattern) => schema.pattern = Some(pattern.to_string()),
+                    RpCheck::Min(n) => schema.minimum = Some(RpLiteral::Number(n.clone())),
+                    RpCheck::Max(n) => schema.maximum = Some(RpLiteral::Number(n.clone())),
+                    RpCheck::ExclusiveMin(n) => {
+                        schema.minimum = Some(RpLiteral::Number(n.clone()));
+                        schema.exclusive_minimum = true;
+                    }
+                    RpCheck::ExclusiveMax(n) => {
+                        schema.maximum = Some(RpLiteral::Number(n.clone()));
+                        schema.exclusive_maximum = true;
+                    }
+                }
+            }
+
             object.properties.insert(field.safe_ident(), schema);
 
             // reference to external type, so add to queue.
-            if let RpType::Name { name } = &field.ty {
+            if let RpType::Name { name, .. } = &field.ty {
                 queue.push_back(Queued::Named(name));
             }
         }
@@ -723,3 +963,32 @@ impl<'builder> SpecBuilder<'builder> {
         Ok(())
     }
 }
+
+/// Build a string schema for bytes with the given encoding.
+fn bytes_schema<'a>(encoding: RpBytesEncoding) -> spec::Schema<'a> {
+    let (format, content_encoding) = match encoding {
+        RpBytesEncoding::Base64 => (spec::Format::Byte, None),
+        RpBytesEncoding::Base64url | RpBytesEncoding::Base64urlNopad => {
+            (spec::Format::Base64url, Some("base64url"))
+        }
+        RpBytesEncoding::Hex => (spec::Format::Hex, Some("base16")),
+    };
+
+    let string = spec::SchemaString {
+        format: Some(format),
+        content_encoding,
+        ..spec::SchemaString::default()
+    };
+
+    spec::Schema::from(string)
+}
+
+/// Build a string schema with the given format.
+fn string_format<'a>(format: spec::Format) -> spec::Schema<'a> {
+    let string = spec::SchemaString {
+        format: Some(format),
+        ..spec::SchemaString::default()
+    };
+
+    spec::Schema::from(string)
+}
@@ -1,4 +1,4 @@
-use core::Version;
+use core::{RpLiteral, Version};
 use linked_hash_map::LinkedHashMap;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -72,6 +72,7 @@ numeric_type!(Double, f64);
 pub struct SchemaString<'a> {
     pub enum_: Vec<&'a str>,
     pub format: Option<Format>,
+    pub content_encoding: Option<&'static str>,
 }
 
 impl<'a> From<SchemaString<'a>> for Schema<'a> {
@@ -80,6 +81,7 @@ impl<'a> From<SchemaString<'a>> for Schema<'a> {
             ty: Some("string"),
             enum_: Enum::String(string.enum_),
             format: string.format,
+            content_encoding: string.content_encoding,
             ..Schema::default()
         }
     }
@@ -92,6 +94,7 @@ pub struct Object<'a> {
     pub additional_properties: Option<Box<Schema<'a>>>,
     pub title: Option<&'a str>,
     pub description: Option<String>,
+    pub deprecated: bool,
 }
 
 impl<'a> From<Object<'a>> for Schema<'a> {
@@ -103,6 +106,7 @@ impl<'a> From<Object<'a>> for Schema<'a> {
             additional_properties: object.additional_properties,
             title: object.title,
             description: object.description,
+            deprecated: object.deprecated,
             ..Schema::default()
         }
     }
@@ -112,6 +116,8 @@ impl<'a> From<Object<'a>> for Schema<'a> {
 pub struct SchemaArray<'a> {
     pub items: Option<Box<Schema<'a>>>,
     pub format: Option<Format>,
+    /// Items must be unique, as for sets.
+    pub unique_items: bool,
     pub required: Vec<usize>,
     /// For tuples, map each position to a type.
     pub properties: BTreeMap<usize, Schema<'a>>,
@@ -123,6 +129,7 @@ impl<'a> From<SchemaArray<'a>> for Schema<'a> {
             ty: Some("array"),
             items: array.items,
             format: array.format,
+            unique_items: array.unique_items,
             required: Required::Usize(array.required),
             properties: Properties::Usize(array.properties),
             ..Schema::default()
@@ -230,6 +237,10 @@ pub struct Info<'a> {
 pub enum ParameterIn {
     #[serde(rename = "path")]
     Path,
+    #[serde(rename = "query")]
+    Query,
+    #[serde(rename = "header")]
+    Header,
 }
 
 #[serde(rename_all = "camelCase")]
@@ -270,8 +281,24 @@ pub enum Format {
     Double,
     #[serde(rename = "date-time")]
     DateTime,
+    #[serde(rename = "date")]
+    Date,
+    #[serde(rename = "time")]
+    Time,
+    #[serde(rename = "duration")]
+    Duration,
+    #[serde(rename = "uuid")]
+    Uuid,
+    #[serde(rename = "decimal")]
+    Decimal,
     #[serde(rename = "byte")]
     Byte,
+    #[serde(rename = "binary")]
+    Binary,
+    #[serde(rename = "base64url")]
+    Base64url,
+    #[serde(rename = "hex")]
+    Hex,
     #[serde(rename = "tuple")]
     Tuple,
 }
@@ -289,15 +316,59 @@ pub struct Schema<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
+    /// If the schema is deprecated.
+    #[serde(skip_serializing_if = "is_false")]
+    pub deprecated: bool,
+
+    /// Default value used when the value is absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<&'a RpLiteral>,
+
+    /// Minimum length of a string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// Maximum length of a string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Regular expression that a string must match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Lower bound of a number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<RpLiteral>,
+
+    /// Upper bound of a number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<RpLiteral>,
+
+    /// If `minimum` is exclusive.
+    #[serde(skip_serializing_if = "is_false")]
+    pub exclusive_minimum: bool,
+
+    /// If `maximum` is exclusive.
+    #[serde(skip_serializing_if = "is_false")]
+    pub exclusive_maximum: bool,
+
     /// How arrays specify inner item type.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Box<Schema<'a>>>,
 
+    /// If the items of an array must be unique.
+    #[serde(skip_serializing_if = "is_false")]
+    pub unique_items: bool,
+
     /// Format acts as extra specification of the type when needed.
     /// Also extensible.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<Format>,
 
+    /// How the content of a string is encoded, like `base64url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<&'static str>,
+
     /// Available enumerations.
     #[serde(rename = "enum", skip_serializing_if = "Enum::is_empty")]
     pub enum_: Enum<'a>,
@@ -306,6 +377,10 @@ pub struct Schema<'a> {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub one_of: Vec<Schema<'a>>,
 
+    /// `anyOf` field
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub any_of: Vec<Schema<'a>>,
+
     /// discriminator
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discriminator: Option<Discriminator<'a>>,
@@ -351,13 +426,15 @@ pub struct Method<'a> {
     pub summary: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub deprecated: bool,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub parameters: Vec<Parameter<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_body: Option<Payload<'a>>,
     /// Content by status code.
     #[serde(skip_serializing_if = "LinkedHashMap::is_empty")]
-    pub responses: LinkedHashMap<&'a str, Payload<'a>>,
+    pub responses: LinkedHashMap<String, Payload<'a>>,
 }
 
 #[serde(rename_all = "camelCase")]
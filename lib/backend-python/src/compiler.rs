@@ -2,14 +2,16 @@
 
 use crate::codegen::{ServiceAdded, ServiceCodegen};
 use crate::flavored::*;
-use crate::utils::BlockComment;
+use crate::utils::{BlockComment, DeprecationWarning, VersionHelper};
 use crate::{Options, EXT, INIT_PY};
 use backend::PackageProcessor;
 use core::errors::Result;
-use core::{Handle, RelativePathBuf, Spanned};
+use core::{Handle, RelativePathBuf, RpCheck, RpLiteral, Spanned};
 use genco::prelude::*;
+use genco::tokens::ItemStr;
 use naming::{self, Naming};
 use std::collections::BTreeMap;
+use std::rc::Rc;
 use std::slice;
 use trans::{self, Translated};
 
@@ -17,7 +19,9 @@ pub(crate) struct Compiler<'a> {
     pub(crate) env: &'a Translated<PythonFlavor>,
     variant_field: Spanned<RpField>,
     to_lower_snake: naming::ToLowerSnake,
+    to_upper_snake: naming::ToUpperSnake,
     enum_enum: python::Import,
+    version_helper: Rc<dyn VersionHelper>,
     service_generators: Vec<Box<dyn ServiceCodegen>>,
     handle: &'a dyn Handle,
 }
@@ -33,7 +37,9 @@ impl<'a> Compiler<'a> {
             env,
             variant_field,
             to_lower_snake: naming::to_lower_snake(),
+            to_upper_snake: naming::to_upper_snake(),
             enum_enum: python::import("enum", "Enum").qualified(),
+            version_helper: options.version_helper,
             service_generators: options.service_generators,
             handle,
         }
@@ -63,12 +69,17 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Build an encode method.
+    ///
+    /// If `wrap` is set, it is returned instead of the encoded `data`, which it is expected to
+    /// wrap.
     fn encode_method(
         &self,
         t: &mut python::Tokens,
         fields: &[Spanned<RpField>],
         builder: python::Tokens,
         extra: Option<python::Tokens>,
+        wrap: Option<python::Tokens>,
     ) {
         quote_in! { *t =>
             def encode(self):
@@ -85,21 +96,49 @@ impl<'a> Compiler<'a> {
                         if field.is_optional() {
                             quote_in! { *t =>
                                 if #v is not None:
-                                    data[#(quoted(field.name()))] = #(field.ty.encode(v.clone()))
+                                    data[#(quoted(field.name()))] = #(self.encode_field(field, v.clone()))
                             }
                         } else {
                             quote_in! { *t =>
                                 if #v is None:
                                     raise Exception(#(quoted(format!("missing required field: {}", field.ident))))
 
-                                data[#(quoted(field.name()))] = #(field.ty.encode(v.clone()))
+                                data[#(quoted(field.name()))] = #(self.encode_field(field, v.clone()))
                             }
                         }
                     })
                 )
 
-                return data
+                #(match wrap {
+                    Some(wrap) => return #wrap,
+                    None => return data,
+                })
+        }
+    }
+
+    /// Encode the value of a field.
+    fn encode_field(&self, field: &RpField, var: python::Tokens) -> python::Tokens {
+        if field.is_string_encoded() {
+            return quote!(str(#var));
+        }
+
+        field.ty.encode(var)
+    }
+
+    /// Decode the value of a field, which is stored in the variable `var`.
+    fn decode_field(&self, field: &RpField, var: &str) -> Option<python::Tokens> {
+        if field.is_string_encoded() {
+            let var = ItemStr::from(var.to_string());
+
+            return Some(quote! {
+                if not #(self.version_helper.is_string(&var)):
+                    raise Exception("not a string")
+
+                #(var.clone()) = int(#(var.clone()))
+            });
         }
+
+        field.ty.decode(var.to_string(), 0)
     }
 
     fn encode_tuple_method<'el, I>(&self, t: &mut python::Tokens, fields: I)
@@ -114,7 +153,7 @@ impl<'a> Compiler<'a> {
                     if self.#(field.safe_ident()) is None:
                         raise Exception(#(quoted(format!("missing required field: {}", field.ident))))
 
-                    #(field.safe_ident()) = #(field.ty.encode(quote!(self.#(field.safe_ident()))))
+                    #(field.safe_ident()) = #(self.encode_field(field, quote!(self.#(field.safe_ident()))))
                     #(ref _ => args.push(field.safe_ident()))
                 })
 
@@ -185,18 +224,30 @@ impl<'a> Compiler<'a> {
                                 if #var in data:
                                     #n = data[#var]
 
-                                    #(if let Some(d) = field.ty.decode(n.clone(), 0) {
+                                    #(if let Some(d) = self.decode_field(field, n) {
                                         if #n is not None:
                                             #d
                                     })
+
+                                #(if let Some(default) = &field.default {
+                                    if #n is None:
+                                        #n = #(literal(default))
+                                })
+
+                                #(if !field.validate.is_empty() {
+                                    if #n is not None:
+                                        #(ref t => self.checks(t, name, field, n))
+                                })
                             }
                         } else {
                             quote_in! { *t =>
                                 #n = data[#(variable_fn(i, field))]
 
-                                #(if let Some(d) = field.ty.decode(n.clone(), 0) {
+                                #(if let Some(d) = self.decode_field(field, n) {
                                     #d
                                 })
+
+                                #(ref t => self.checks(t, name, field, n))
                             }
                         }
 
@@ -208,11 +259,49 @@ impl<'a> Compiler<'a> {
         }
     }
 
-    fn build_constructor(&self, t: &mut python::Tokens, fields: &[Spanned<RpField>]) {
+    /// Check the validation rules of a decoded field.
+    fn checks(&self, t: &mut python::Tokens, name: &Name, field: &RpField, var: &str) {
+        for check in field.validate.checks() {
+            let cond = match check {
+                RpCheck::MinLength(n) => quote!(len(#var) < #(n.to_string())),
+                RpCheck::MaxLength(n) => quote!(len(#var) > #(n.to_string())),
+                RpCheck::Pattern(pattern) => {
+                    let re = python::import_module("re");
+                    let pattern = quoted(format!("(?:{})\\Z", pattern));
+                    quote!(#re.match(#pattern, #var) is None)
+                }
+                RpCheck::Min(n) => quote!(#var < #(literal(&RpLiteral::Number(n.clone())))),
+                RpCheck::Max(n) => quote!(#var > #(literal(&RpLiteral::Number(n.clone())))),
+                RpCheck::ExclusiveMin(n) => {
+                    quote!(#var <= #(literal(&RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::ExclusiveMax(n) => {
+                    quote!(#var >= #(literal(&RpLiteral::Number(n.clone()))))
+                }
+            };
+
+            let message = quoted(format!("{}.{}: {}", name.ident, field.name(), check));
+
+            quote_in! { *t =>
+                if #cond:
+                    raise ValueError(#message)
+                #<push>
+            }
+        }
+    }
+
+    fn build_constructor(
+        &self,
+        t: &mut python::Tokens,
+        name: &Name,
+        deprecated: Option<&RpDeprecated>,
+        fields: &[Spanned<RpField>],
+    ) {
         quote_in! { *t =>
             def __init__(self#(for f in fields => , #(f.safe_ident()))):
+                #(DeprecationWarning(&name.ident, deprecated))
                 #(if fields.is_empty() {
-                    pass
+                    #(if deprecated.is_none() => pass)
                 } else {
                     #(for f in fields join (#<push>) {
                         self.__#(&f.ident) = #(f.safe_ident())
@@ -235,6 +324,7 @@ impl<'a> Compiler<'a> {
                         @property
                         def #name(self):
                             #(BlockComment(&field.comment))
+                            #(DeprecationWarning(&field.ident, field.deprecated.as_ref()))
                             return self.__#(&field.ident)
 
                         @#name.setter
@@ -246,6 +336,22 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    /// Check if `data` is a JSON value of the given kind.
+    ///
+    /// Booleans are integers in python, so they have to be excluded when checking for numbers.
+    fn union_kind_check(&self, kind: RpUnionKind) -> python::Tokens {
+        match kind {
+            RpUnionKind::String => self.version_helper.is_string(&ItemStr::Static("data")),
+            RpUnionKind::Integer => quote!(isinstance(data, int) and not isinstance(data, bool)),
+            RpUnionKind::Number => {
+                quote!(isinstance(data, (int, float)) and not isinstance(data, bool))
+            }
+            RpUnionKind::Boolean => quote!(isinstance(data, bool)),
+            RpUnionKind::Array => quote!(isinstance(data, list)),
+            RpUnionKind::Object => quote!(isinstance(data, dict)),
+        }
+    }
+
     fn enum_variants(&self, t: &mut python::Tokens, body: &RpEnumBody) {
         let mut args = Tokens::new();
 
@@ -293,7 +399,7 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
     fn process_tuple(&self, out: &mut Self::Out, body: &RpTupleBody) -> Result<()> {
         quote_in! { *out =>
             class #(&body.name):
-                #(ref t => self.build_constructor(t, &body.fields))
+                #(ref t => self.build_constructor(t, &body.name, body.deprecated.as_ref(), &body.fields))
 
                 #(ref t => self.build_accessors(t, &body.fields))
 
@@ -311,16 +417,24 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
         Ok(())
     }
 
+    fn process_const(&self, out: &mut Self::Out, body: &RpConstBody) -> Result<()> {
+        quote_in! { *out =>
+            #(self.to_upper_snake.convert(&body.name.ident)) = #(literal(&body.value))
+        }
+
+        Ok(())
+    }
+
     fn process_enum(&self, out: &mut Self::Out, body: &RpEnumBody) -> Result<()> {
         quote_in! { *out =>
             class #(&body.name):
-                #(ref t => self.build_constructor(t, slice::from_ref(&self.variant_field)))
+                #(ref t => self.build_constructor(t, &body.name, None, slice::from_ref(&self.variant_field)))
 
                 #(ref t => self.build_accessors(t, slice::from_ref(&self.variant_field)))
 
                 #(ref t => encode_method(t, &self.variant_field))
 
-                #(ref t => decode_method(t, &self.variant_field))
+                #(ref t => decode_method(t, &self.variant_field, body.unknown.as_deref()))
 
                 #(ref t => self.repr_method(t, &body.name, slice::from_ref(&self.variant_field)))
 
@@ -338,7 +452,11 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
             }
         }
 
-        fn decode_method(t: &mut python::Tokens, field: &Spanned<RpField>) {
+        fn decode_method(
+            t: &mut python::Tokens,
+            field: &Spanned<RpField>,
+            unknown: Option<&RpUnknown>,
+        ) {
             quote_in! { *t =>
                 @classmethod
                 def decode(cls, data):
@@ -346,7 +464,18 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                         if value.#(field.safe_ident()) == data:
                             return value
 
-                    raise Exception("data does not match enum")
+                    #(match unknown {
+                        Some(unknown) => {
+                            value = object.__new__(cls)
+                            value._name_ = #(quoted(&unknown.ident))
+                            value._value_ = data
+                            value.#(field.safe_ident()) = data
+                            return value
+                        }
+                        None => {
+                            raise Exception("data does not match enum")
+                        }
+                    })
             }
         }
     }
@@ -354,7 +483,7 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
     fn process_type(&self, out: &mut Self::Out, body: &RpTypeBody) -> Result<()> {
         quote_in! { *out =>
             class #(&body.name):
-                #(ref t => self.build_constructor(t, &body.fields))
+                #(ref t => self.build_constructor(t, &body.name, body.deprecated.as_ref(), &body.fields))
 
                 #(ref t => self.build_accessors(t, &body.fields))
 
@@ -362,7 +491,7 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                     quote!(#(quoted(field.name())))
                 }))
 
-                #(ref t => self.encode_method(t, &body.fields, quote!(dict), None))
+                #(ref t => self.encode_method(t, &body.fields, quote!(dict), None, None))
 
                 #(ref t => self.repr_method(t, &body.name, &body.fields))
 
@@ -381,6 +510,12 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                     RpSubTypeStrategy::Tagged { tag, .. } => {
                         #(ref t => decode_from_tag(t, &body, tag))
                     }
+                    RpSubTypeStrategy::Adjacent { tag, content } => {
+                        #(ref t => decode_from_adjacent(t, body, tag, content))
+                    }
+                    RpSubTypeStrategy::External => {
+                        #(ref t => decode_from_external(t, body))
+                    }
                     RpSubTypeStrategy::Untagged => {
                         #(ref t => decode_from_untagged(t, &body))
                     }
@@ -403,7 +538,7 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                         class #(&sub_type.name)(#(&body.name)):
                             TYPE = #(quoted(sub_type.name()))
 
-                            #(ref t => self.build_constructor(t, &fields))
+                            #(ref t => self.build_constructor(t, &sub_type.name, sub_type.deprecated.as_ref(), &fields))
 
                             #(ref t => self.build_accessors(t, &fields))
 
@@ -418,10 +553,29 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                                         &fields,
                                         quote!(dict),
                                         Some(quote!(data[#(quoted(tag.as_str()))] = #(quoted(sub_type.name())))),
+                                        None,
+                                    ))
+                                }
+                                RpSubTypeStrategy::Adjacent { tag, content } => {
+                                    #(ref t => self.encode_method(
+                                        t,
+                                        &fields,
+                                        quote!(dict),
+                                        None,
+                                        Some(quote!({#(quoted(tag.as_str())): #(quoted(sub_type.name())), #(quoted(content.as_str())): data})),
+                                    ))
+                                }
+                                RpSubTypeStrategy::External => {
+                                    #(ref t => self.encode_method(
+                                        t,
+                                        &fields,
+                                        quote!(dict),
+                                        None,
+                                        Some(quote!({#(quoted(sub_type.name())): data})),
                                     ))
                                 }
                                 RpSubTypeStrategy::Untagged => {
-                                    #(ref t => self.encode_method(t, &fields, quote!(dict), None))
+                                    #(ref t => self.encode_method(t, &fields, quote!(dict), None, None))
                                 }
                             })
 
@@ -433,6 +587,18 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                     }
                 })
             })
+
+            #(if let Some(unknown) = &body.unknown {
+                class #(&unknown.name)(#(&body.name)):
+                    def __init__(self, data):
+                        self.data = data
+
+                    def encode(self):
+                        return self.data
+
+                    def __repr__(self):
+                        return #(quoted(format!("<{} data:{{!r}}>", unknown.name.ident))).format(self.data)
+            })
         }
 
         return Ok(());
@@ -451,7 +617,65 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
                             return #(&sub_type.name).decode(data)
                     )
 
+                    #(ref t => no_sub_type(t, body))
+            }
+        }
+
+        fn decode_from_adjacent(
+            t: &mut python::Tokens,
+            body: &RpInterfaceBody,
+            tag: &str,
+            content: &str,
+        ) {
+            quote_in! { *t =>
+                @staticmethod
+                def decode(data):
+                    if #(quoted(tag)) not in data:
+                        raise Exception(#_(missing tag field #(tag)))
+
+                    if #(quoted(content)) not in data:
+                        raise Exception(#_(missing content field #(content)))
+
+                    f_tag = data[#(quoted(tag))]
+                    f_content = data[#(quoted(content))]
+
+                    #(for sub_type in &body.sub_types join (#<line>) =>
+                        if f_tag == #(quoted(sub_type.name())):
+                            return #(&sub_type.name).decode(f_content)
+                    )
+
+                    #(ref t => no_sub_type(t, body))
+            }
+        }
+
+        fn decode_from_external(t: &mut python::Tokens, body: &RpInterfaceBody) {
+            quote_in! { *t =>
+                @staticmethod
+                def decode(data):
+                    if len(data) != 1:
+                        raise Exception("expected exactly one key")
+
+                    f_tag, f_content = list(data.items())[0]
+
+                    #(for sub_type in &body.sub_types join (#<line>) =>
+                        if f_tag == #(quoted(sub_type.name())):
+                            return #(&sub_type.name).decode(f_content)
+                    )
+
+                    #(ref t => no_sub_type(t, body))
+            }
+        }
+
+        /// Handle a tag which doesn't match any sub-type, falling back to the unknown sub-type if
+        /// present.
+        fn no_sub_type(t: &mut python::Tokens, body: &RpInterfaceBody) {
+            match &body.unknown {
+                Some(unknown) => quote_in! { *t =>
+                    return #(&unknown.name)(data)
+                },
+                None => quote_in! { *t =>
                     raise Exception("no sub type matching tag: " + f_tag)
+                },
             }
         }
 
@@ -499,6 +723,63 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
         }
     }
 
+    fn process_union(&self, out: &mut Self::Out, body: &RpUnionBody) -> Result<()> {
+        // exactly one attribute is set, which is the alternative holding the value.
+        let variants = body
+            .variants
+            .iter()
+            .map(|v| (self.to_lower_snake.convert(&v.ident), v))
+            .collect::<Vec<_>>();
+
+        quote_in! { *out =>
+            class #(&body.name):
+                def __init__(self#(for (ident, _) in &variants => , #ident=None)):
+                    #(DeprecationWarning(&body.name.ident, body.deprecated.as_ref()))
+                    #(for (ident, _) in &variants join (#<push>) {
+                        self.__#ident = #ident
+                    })
+
+                #(for (ident, _) in &variants join (#<line>) {
+                    @property
+                    def #ident(self):
+                        return self.__#ident
+                })
+
+                @staticmethod
+                def decode(data):
+                    #(for (ident, v) in &variants join (#<line>) {
+                        if #(self.union_kind_check(v.kind)):
+                            v = data
+
+                            #(if let Some(d) = v.ty.decode("v", 0) {
+                                #d
+                            })
+
+                            return #(&body.name)(#ident=v)
+                    })
+
+                    raise Exception("no alternative of the union matches the value")
+
+                def encode(self):
+                    #(for (ident, v) in &variants join (#<line>) {
+                        if self.__#ident is not None:
+                            return #(v.ty.encode(quote!(self.__#ident)))
+                    })
+
+                    raise Exception("no alternative of the union is set")
+
+                def __repr__(self):
+                    #(for (ident, _) in &variants join (#<line>) {
+                        if self.__#ident is not None:
+                            return #(quoted(format!("<{} {}:{{!r}}>", body.name.ident, ident))).format(self.__#ident)
+                    })
+
+                    return #(quoted(format!("<{}>", body.name.ident)))
+        }
+
+        Ok(())
+    }
+
     fn process_service(&self, out: &mut Self::Out, body: &RpServiceBody) -> Result<()> {
         for g in &self.service_generators {
             g.generate(ServiceAdded {
@@ -570,3 +851,13 @@ impl<'a> PackageProcessor<'a, PythonFlavor> for Compiler<'a> {
         Ok(full_path)
     }
 }
+
+/// Convert a literal value into a python literal.
+fn literal(literal: &RpLiteral) -> python::Tokens {
+    match literal {
+        RpLiteral::String(string) => quote!(#(quoted(string))),
+        RpLiteral::Number(number) => quote!(#(number.to_string())),
+        RpLiteral::Boolean(true) => quote!(True),
+        RpLiteral::Boolean(false) => quote!(False),
+    }
+}
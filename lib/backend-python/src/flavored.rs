@@ -7,8 +7,8 @@ use crate::{Options, TYPE_SEP};
 use backend::package_processor;
 use core::errors::Result;
 use core::{
-    CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpNumberType,
-    RpStringType, Spanned, Translate, Translator,
+    CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpBytesType,
+    RpNumberType, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
 use genco::tokens::{FormatInto, Item, ItemStr};
@@ -26,11 +26,30 @@ pub(crate) enum Type {
     Integer,
     Float,
     Boolean,
-    String { helper: Rc<dyn VersionHelper> },
-    Array { argument: Box<Type> },
-    Map { key: Box<Type>, value: Box<Type> },
-    Name { import: python::Import },
-    Local { ident: ItemStr },
+    String {
+        helper: Rc<dyn VersionHelper>,
+    },
+    /// A built-in type like `UUID` or `Decimal`, which is constructed from and encoded as a string.
+    Builtin {
+        helper: Rc<dyn VersionHelper>,
+        import: python::Import,
+    },
+    Array {
+        argument: Box<Type>,
+    },
+    Set {
+        argument: Box<Type>,
+    },
+    Map {
+        key: Box<Type>,
+        value: Box<Type>,
+    },
+    Name {
+        import: python::Import,
+    },
+    Local {
+        ident: ItemStr,
+    },
 }
 
 impl Type {
@@ -49,7 +68,11 @@ impl Type {
             Self::Native => true,
             Self::Integer | Self::Float | Self::Boolean | Self::String { .. } => true,
             Self::Array { argument } => argument.is_native(),
-            Self::Map { key, value } => key.is_native() && value.is_native(),
+            // integer keys are converted from and to strings.
+            Self::Map { key, value } => match **key {
+                Self::Integer => false,
+                ref key => key.is_native() && value.is_native(),
+            },
             _ => false,
         }
     }
@@ -86,6 +109,12 @@ impl Type {
                 if not #(helper.is_string(var)):
                     raise Exception("not a string")
             }),
+            Self::Builtin { helper, import } => Some(quote! {
+                if not #(helper.is_string(var)):
+                    raise Exception("not a string")
+
+                #(var.clone()) = #import(#(var.clone()))
+            }),
             Self::Native => None,
             Self::Array { argument } => {
                 let v = &Rc::new(format!("_v{}", l));
@@ -107,6 +136,26 @@ impl Type {
                     #var = #a
                 })
             }
+            Self::Set { argument } => {
+                let v = &Rc::new(format!("_v{}", l));
+                let a = &Rc::new(format!("_a{}", l));
+
+                Some(quote! {
+                    if not isinstance(#var, list):
+                        raise Exception("not an array")
+
+                    #a = set()
+
+                    for #v in #var:
+                        #(if let Some(d) = argument.decode(v.clone(), l + 1) {
+                            #d
+                            #<line>
+                        })
+                        #a.add(#v)
+
+                    #var = #a
+                })
+            }
             Self::Map { key, value } => {
                 let o = &Rc::new(format!("_o{}", l));
                 let k = &Rc::new(format!("_k{}", l));
@@ -119,7 +168,7 @@ impl Type {
                     #o = {}
 
                     for #k, #v in #var.items():
-                        #(if let Some(d) = key.decode(k.clone(), l + 1) =>
+                        #(if let Some(d) = key.decode_key(k.clone(), l + 1) =>
                             #d
                         )
                         #(if let Some(d) = value.decode(v.clone(), l + 1) =>
@@ -135,6 +184,27 @@ impl Type {
         }
     }
 
+    /// Build decode method for a map key, which is always a string in JSON.
+    fn decode_key<V>(&self, var: V, l: usize) -> Option<Tokens<Python>>
+    where
+        V: Into<ItemStr>,
+    {
+        let var = var.into();
+
+        match self {
+            Self::Integer => Some(quote!(#(var.clone()) = int(#(var.clone())))),
+            key => key.decode(var, l),
+        }
+    }
+
+    /// Build encode method for a map key.
+    fn encode_key(&self, var: Tokens<Python>) -> Tokens<Python> {
+        match self {
+            Self::Integer => quote!(str(#var)),
+            key => key.encode(var),
+        }
+    }
+
     /// Build encode method.
     pub(crate) fn encode(&self, var: Tokens<Python>) -> Tokens<Python> {
         match self {
@@ -142,12 +212,13 @@ impl Type {
                 quote!(#var)
             }
             v if v.is_native() => quote!(#var),
-            Self::Array { argument } => {
+            Self::Builtin { .. } => quote!(str(#var)),
+            Self::Array { argument } | Self::Set { argument } => {
                 let v = argument.encode(quote!(v));
                 quote!([#v for v in #var])
             }
             Self::Map { key, value } => {
-                let k = key.encode(quote!(k));
+                let k = key.encode_key(quote!(k));
                 let v = value.encode(quote!(v));
                 quote!(dict((#k, #v) for (k, v) in #var.items()))
             }
@@ -232,12 +303,32 @@ impl FlavorTranslator for PythonFlavorTranslator {
         })
     }
 
+    fn translate_uuid(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            helper: self.helper.clone(),
+            import: python::import("uuid", "UUID"),
+        }))
+    }
+
+    fn translate_decimal(&self) -> Result<Option<Type>> {
+        Ok(Some(Type::Builtin {
+            helper: self.helper.clone(),
+            import: python::import("decimal", "Decimal"),
+        }))
+    }
+
     fn translate_array(&self, argument: Type) -> Result<Type> {
         Ok(Type::Array {
             argument: Box::new(argument),
         })
     }
 
+    fn translate_set(&self, argument: Type) -> Result<Type> {
+        Ok(Type::Set {
+            argument: Box::new(argument),
+        })
+    }
+
     fn translate_map(&self, key: Type, value: Type) -> Result<Type> {
         Ok(Type::Map {
             key: Box::new(key),
@@ -245,17 +336,31 @@ impl FlavorTranslator for PythonFlavorTranslator {
         })
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Native)
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::String {
             helper: self.helper.clone(),
         })
     }
 
-    fn translate_name(&self, _from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        _from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
+        if !arguments.is_empty() {
+            return Err("generic declarations must be monomorphized".into());
+        }
+
         let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |v| v.join(TYPE_SEP));
 
         if let Some(used) = &name.prefix {
@@ -273,6 +378,10 @@ impl FlavorTranslator for PythonFlavorTranslator {
         })
     }
 
+    fn translate_parameter(&self, _: String) -> Result<Type> {
+        Err("generic declarations must be monomorphized".into())
+    }
+
     fn translate_package(&self, source: RpVersionedPackage) -> Result<RpPackage> {
         self.packages.translate_package(source)
     }
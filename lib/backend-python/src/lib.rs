@@ -12,7 +12,7 @@ use core::errors::Result;
 use core::{CoreFlavor, Handle, RpField, Span, Spanned};
 use genco::prelude::*;
 use genco::tokens::ItemStr;
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::path::Path;
 use std::rc::Rc;
@@ -28,6 +28,10 @@ pub struct PythonLang;
 impl Lang for PythonLang {
     manifest::lang_base!(PythonModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Python)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("# {}", input))
     }
@@ -76,6 +80,7 @@ impl Lang for PythonLang {
 pub(crate) enum PythonModule {
     Requests(module::RequestsConfig),
     Python2(module::Python2Config),
+    WebSockets(module::WebSocketsConfig),
 }
 
 impl TryFromToml for PythonModule {
@@ -85,6 +90,7 @@ impl TryFromToml for PythonModule {
         let result = match id {
             "requests" => Requests(module::RequestsConfig::default()),
             "python2" => Python2(module::Python2Config::default()),
+            "websockets" => WebSockets(module::WebSocketsConfig::default()),
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -97,6 +103,7 @@ impl TryFromToml for PythonModule {
         let result = match id {
             "requests" => Requests(value.try_into()?),
             "python2" => Python2(value.try_into()?),
+            "websockets" => WebSockets(value.try_into()?),
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -136,6 +143,7 @@ pub(crate) fn setup_options(modules: Vec<PythonModule>) -> Result<Options> {
         let initializer: Box<dyn Initializer<Options = Options>> = match module {
             Requests(config) => Box::new(module::Requests::new(config)),
             Python2(config) => Box::new(module::Python2::new(config)),
+            WebSockets(config) => Box::new(module::WebSockets::new(config)),
         };
 
         initializer.initialize(&mut options)?;
@@ -148,6 +156,7 @@ fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest
     let modules = manifest::checked_modules(manifest.modules)?;
     let options = setup_options(modules)?;
 
+    let session = session.monomorphize()?.inline_newtypes()?.lift_unions()?;
     let packages = session.packages()?;
 
     let helper = options.version_helper.clone();
@@ -1,5 +1,7 @@
 mod python2;
 mod requests;
+mod websockets;
 
 pub(crate) use self::python2::{Config as Python2Config, Module as Python2};
 pub(crate) use self::requests::{Config as RequestsConfig, Module as Requests};
+pub(crate) use self::websockets::{Config as WebSocketsConfig, Module as WebSockets};
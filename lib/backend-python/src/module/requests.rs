@@ -2,11 +2,13 @@
 
 use crate::codegen::{ServiceAdded, ServiceCodegen};
 use crate::flavored::*;
-use crate::utils::BlockComment;
+use crate::utils::{BlockComment, DeprecationWarning};
 use crate::Options;
 use backend::Initializer;
 use core::errors::Result;
 use genco::prelude::*;
+use genco::tokens::{FormatInto, ItemStr};
+use naming::Naming as _;
 use serde::Deserialize;
 
 #[derive(Debug, Default, Deserialize)]
@@ -25,12 +27,14 @@ impl Module {
 
 struct RequestsServiceCodegen {
     requests: python::ImportModule,
+    json: python::ImportModule,
 }
 
 impl RequestsServiceCodegen {
     pub fn new() -> RequestsServiceCodegen {
         Self {
             requests: python::import_module("requests"),
+            json: python::import_module("json"),
         }
     }
 }
@@ -42,7 +46,46 @@ impl ServiceCodegen for RequestsServiceCodegen {
             body, type_body, ..
         }: ServiceAdded,
     ) -> Result<()> {
+        let to_upper_camel = naming::to_upper_camel();
+
+        // Endpoints with declared errors, and the name of their exception class.
+        let errors = body
+            .endpoints
+            .iter()
+            .filter(|e| e.has_http_support() && !e.http.errors.is_empty())
+            .map(|e| {
+                let error = format!(
+                    "{}_{}_Error",
+                    body.name.ident,
+                    to_upper_camel.convert(e.ident())
+                );
+                (e, ItemStr::from(error))
+            })
+            .collect::<Vec<_>>();
+
+        // Framings of streaming responses, which are decoded by helper methods.
+        let streams = body
+            .endpoints
+            .iter()
+            .filter(|e| e.has_http_support())
+            .flat_map(|e| e.http.stream)
+            .collect::<Vec<_>>();
+
         quote_in! { *type_body =>
+            #(for (e, error) in &errors join (#<line>) =>
+                class #error(Exception):
+                    #(BlockComment(vec![
+                        format!("Error response of `{}`, with the status code and decoded body.", e.ident()),
+                    ]))
+                    def __init__(self, status, body):
+                        Exception.__init__(self, status, body)
+                        self.status = status
+                        self.body = body
+            )
+
+            #(if !errors.is_empty() {
+                #<line>
+            })
             class #(&body.name)_Requests:
                 def __init__(self, **kw):
                     url = kw.pop("url", None)
@@ -63,6 +106,47 @@ impl ServiceCodegen for RequestsServiceCodegen {
                     self.url = url
                     self.session = session
 
+                #(if streams.contains(&RpHttpStream::NdJson) {
+                    @staticmethod
+                    def _ndjson(r):
+                        #(BlockComment(vec!["Decode a response with one JSON document per line."]))
+                        if r.encoding is None:
+                            r.encoding = "utf-8"
+
+                        for line in r.iter_lines(decode_unicode=True):
+                            if line:
+                                yield #(&self.json).loads(line)
+                    #<line>
+                })
+                #(if streams.contains(&RpHttpStream::Sse) {
+                    @staticmethod
+                    def _sse(r):
+                        #(BlockComment(vec!["Decode a response of Server-Sent Events, with one JSON document in the data of each event."]))
+                        if r.encoding is None:
+                            r.encoding = "utf-8"
+
+                        data = None
+
+                        for line in r.iter_lines(decode_unicode=True):
+                            if not line:
+                                if data is not None:
+                                    yield #(&self.json).loads(data)
+                                    data = None
+
+                                continue
+
+                            if line.startswith("data:"):
+                                value = line[5:]
+
+                                if value.startswith(" "):
+                                    value = value[1:]
+
+                                data = value if data is None else data + "\n" + value
+
+                        if data is not None:
+                            yield #(&self.json).loads(data)
+                    #<line>
+                })
                 #(for e in &body.endpoints join (#<line>) {
                     #(ref t =>
                         if !e.has_http_support() {
@@ -76,9 +160,36 @@ impl ServiceCodegen for RequestsServiceCodegen {
                             .unwrap_or(&RpHttpMethod::Get)
                             .as_str();
 
+                        // NB: `requests` sets the content type of JSON and form bodies itself.
+                        let content_type = match e.http.content_type {
+                            RpAccept::Text | RpAccept::Bytes | RpAccept::NdJson if e.request.is_some() => {
+                                Some(e.http.content_type.as_str())
+                            }
+                            _ => None,
+                        };
+
+                        let has_headers = !e.http.headers.is_empty() || content_type.is_some();
+
+                        // NB: optional arguments can only default to `None` if they are trailing.
+                        let required = e
+                            .arguments
+                            .iter()
+                            .rposition(|a| a.required)
+                            .map(|i| i + 1)
+                            .unwrap_or(0);
+
+                        let arguments = e.arguments.iter().enumerate().map(|(i, a)| {
+                            if i < required {
+                                quote!(#(a.safe_ident()))
+                            } else {
+                                quote!(#(a.safe_ident())=None)
+                            }
+                        });
+
                         quote_in! { *t =>
-                            def #(e.safe_ident())(self, #(for a in &e.arguments => #(a.safe_ident()))):
+                            def #(e.safe_ident())(self, #(for a in arguments join (, ) => #a)):
                                 #(BlockComment(&e.comment))
+                                #(DeprecationWarning(e.ident(), e.deprecated.as_ref()))
                                 #(if let Some(ref http_path) = e.http.path {
                                     path = list()
 
@@ -98,15 +209,87 @@ impl ServiceCodegen for RequestsServiceCodegen {
                                     url = self.url
                                 })
 
-                                r = self.session.request(#method, url=url#(if let Some(ref body) = e.http.body {
-                                    , json=#(body.safe_ident()).encode()
+                                #(if !e.http.query.is_empty() {
+                                    params = dict()
+                                    #(for p in &e.http.query join (#<push>) => #(SetParameter("params", p)))
+                                })
+
+                                #(if has_headers {
+                                    headers = dict()
+                                    #(for p in &e.http.headers join (#<push>) => #(SetParameter("headers", p)))
+                                    #(if let Some(content_type) = content_type {
+                                        headers["Content-Type"] = #(quoted(content_type))
+                                    })
+                                })
+
+                                #(if let Some(req) = &e.request {
+                                    #(match e.http.content_type {
+                                        RpAccept::Form => {
+                                            form = dict()
+
+                                            for key, value in #(req.channel.ty().encode(quote!(#(req.safe_ident())))).items():
+                                                if value is not None:
+                                                    form[key] = #(&self.json).dumps(value) if isinstance(value, bool) else value
+                                        }
+                                        RpAccept::Multipart => {
+                                            files = dict()
+
+                                            for key, value in #(req.channel.ty().encode(quote!(#(req.safe_ident())))).items():
+                                                if value is not None:
+                                                    files[key] = (None, value if isinstance(value, str) else #(&self.json).dumps(value))
+                                        }
+                                        RpAccept::NdJson => {
+                                            data = "".join(#(&self.json).dumps(v) + "\n" for v in #(req.channel.ty().encode(quote!(#(req.safe_ident())))))
+                                        }
+                                        _ => {}
+                                    })
+                                    #<line>
+                                })
+                                r = self.session.request(#(quoted(method)), url=url#(if e.http.stream.is_some() {
+                                    , stream=True
+                                })#(if !e.http.query.is_empty() {
+                                    , params=params
+                                })#(if has_headers {
+                                    , headers=headers
+                                })#(if let Some(req) = &e.request {
+                                    , #(match e.http.content_type {
+                                        RpAccept::Json => json=#(req.channel.ty().encode(quote!(#(req.safe_ident())))),
+                                        RpAccept::Text | RpAccept::Bytes => data=#(req.safe_ident()),
+                                        RpAccept::Form => data=form,
+                                        RpAccept::Multipart => files=files,
+                                        RpAccept::NdJson => data=data,
+                                    })
                                 }))
 
+                                #(if let Some((_, error)) = errors.iter().find(|(other, _)| other.ident() == e.ident()) {
+                                    #(for err in &e.http.errors join (#<line>) =>
+                                        if r.status_code == #(err.status):
+                                            data = r.json()
+
+                                            #(if let Some(d) = err.ty.decode("data", 0) {
+                                                #d
+                                            })
+
+                                            raise #error(#(err.status), data)
+                                    )
+                                    #<line>
+                                })
                                 r.raise_for_status()
 
                                 #(if let Some(res) = &e.response =>
-                                    #(match e.http.accept {
-                                        RpAccept::Json => {
+                                    #(match (e.http.stream, e.http.accept) {
+                                        (Some(stream), _) => {
+                                            for data in self.#(match stream {
+                                                RpHttpStream::Sse => _sse,
+                                                RpHttpStream::NdJson => _ndjson,
+                                            })(r):
+                                                #(if let Some(d) = res.ty().decode("data", 0) {
+                                                    #d
+                                                    #<line>
+                                                })
+                                                yield data
+                                        }
+                                        (None, RpAccept::Json) => {
                                             data = r.json();
 
                                             #(if let Some(d) = res.ty().decode("data", 0) {
@@ -115,9 +298,25 @@ impl ServiceCodegen for RequestsServiceCodegen {
 
                                             return data
                                         }
-                                        RpAccept::Text => {
+                                        (None, RpAccept::Text) => {
                                             return r.text
                                         }
+                                        (None, RpAccept::Bytes) => {
+                                            return r.content
+                                        }
+                                        (None, RpAccept::NdJson) => {
+                                            data = [#(&self.json).loads(line) for line in r.text.splitlines() if line]
+
+                                            #(if let Some(d) = res.ty().decode("data", 0) {
+                                                #d
+                                            })
+
+                                            return data
+                                        }
+                                        // NB: form responses are rejected when translating.
+                                        (None, RpAccept::Form) | (None, RpAccept::Multipart) => {
+                                            return r.json()
+                                        }
                                     })
                                 )
                         }
@@ -129,6 +328,34 @@ impl ServiceCodegen for RequestsServiceCodegen {
     }
 }
 
+/// Set a query parameter or header in the dictionary `var`, skipping optional arguments which
+/// are absent.
+struct SetParameter<'a>(&'a str, &'a RpHttpParameter);
+
+impl<'a> FormatInto<Python> for SetParameter<'a> {
+    fn format_into(self, t: &mut Tokens<Python>) {
+        let SetParameter(var, p) = self;
+        let ident = p.argument.safe_ident();
+
+        // NB: booleans are sent in lowercase, like they are in JSON.
+        let value = match *p.argument.channel.ty() {
+            Type::Boolean => quote!("true" if #ident else "false"),
+            _ => quote!(str(#ident)),
+        };
+
+        if p.argument.is_optional() {
+            quote_in! { *t =>
+                if #ident is not None:
+                    #var[#(quoted(p.name.as_str()))] = #value
+            }
+        } else {
+            quote_in! { *t =>
+                #var[#(quoted(p.name.as_str()))] = #value
+            }
+        }
+    }
+}
+
 impl Initializer for Module {
     type Options = Options;
 
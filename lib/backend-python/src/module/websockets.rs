@@ -0,0 +1,168 @@
+//! Module that generates WebSocket clients for bi-directional endpoints, using websockets.
+
+use crate::codegen::{ServiceAdded, ServiceCodegen};
+use crate::utils::{BlockComment, DeprecationWarning};
+use crate::Options;
+use backend::Initializer;
+use core::errors::Result;
+use genco::prelude::*;
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {}
+
+pub(crate) struct Module {
+    #[allow(dead_code)]
+    config: Config,
+}
+
+impl Module {
+    pub(crate) fn new(config: Config) -> Module {
+        Module { config }
+    }
+}
+
+struct WebSocketsServiceCodegen {
+    websockets: python::ImportModule,
+    connection_closed_ok: python::Import,
+    json: python::ImportModule,
+}
+
+impl WebSocketsServiceCodegen {
+    pub fn new() -> WebSocketsServiceCodegen {
+        Self {
+            websockets: python::import_module("websockets"),
+            connection_closed_ok: python::import("websockets.exceptions", "ConnectionClosedOK"),
+            json: python::import_module("json"),
+        }
+    }
+}
+
+impl ServiceCodegen for WebSocketsServiceCodegen {
+    fn generate(
+        &self,
+        ServiceAdded {
+            body, type_body, ..
+        }: ServiceAdded,
+    ) -> Result<()> {
+        let websocket = match &body.websocket {
+            Some(websocket) => websocket,
+            None => return Ok(()),
+        };
+
+        quote_in! { *type_body =>
+            class #(&body.name)_WebSocket:
+                def __init__(self, **kw):
+                    url = kw.pop("url", None)
+
+                    #(if let Some(ref url) = websocket.url {
+                        if url is None:
+                            url = #(quoted(url.as_str()))
+                    } else {
+                        if url is None:
+                            raise Exception("Missing 'url' argument")
+                    })
+
+                    self.url = url
+
+                class Sender:
+                    #(BlockComment(vec!["The half of a bi-directional endpoint which sends requests."]))
+                    def __init__(self, socket, endpoint, encode):
+                        self.socket = socket
+                        self.endpoint = endpoint
+                        self.encode = encode
+
+                    async def send(self, value):
+                        #(BlockComment(vec!["Send a request."]))
+                        await self.socket.send(#(&self.json).dumps(dict(endpoint=self.endpoint, body=self.encode(value))))
+
+                    async def close(self):
+                        #(BlockComment(vec!["Close the connection, after which no more requests can be sent."]))
+                        await self.socket.close()
+
+                class Receiver:
+                    #(BlockComment(vec![
+                        "The half of a bi-directional endpoint which receives responses.",
+                        "Responses can also be received with `async for`.",
+                    ]))
+                    def __init__(self, socket, endpoint, decode):
+                        self.socket = socket
+                        self.endpoint = endpoint
+                        self.decode = decode
+
+                    async def recv(self):
+                        #(BlockComment(vec![
+                            "Receive the next response, or None once the connection has been closed.",
+                            "Messages for other endpoints are ignored.",
+                        ]))
+                        while True:
+                            try:
+                                message = await self.socket.recv()
+                            except #(&self.connection_closed_ok):
+                                return None
+
+                            if not isinstance(message, str):
+                                continue
+
+                            envelope = #(&self.json).loads(message)
+
+                            if not isinstance(envelope, dict) or envelope.get("endpoint") != self.endpoint or "body" not in envelope:
+                                continue
+
+                            return self.decode(envelope["body"])
+
+                    def __aiter__(self):
+                        return self
+
+                    async def __anext__(self):
+                        value = await self.recv()
+
+                        if value is None:
+                            raise StopAsyncIteration
+
+                        return value
+
+                #(for e in body.endpoints.iter().filter(|e| e.is_bidirectional()) join (#<line>) {
+                    #(ref t =>
+                        let (req, res) = match (&e.request, &e.response) {
+                            (Some(req), Some(res)) => (req.channel.ty(), res.ty()),
+                            _ => continue,
+                        };
+
+                        quote_in! { *t =>
+                            async def #(e.safe_ident())(self):
+                                #(BlockComment(&e.comment))
+                                #(DeprecationWarning(e.ident(), e.deprecated.as_ref()))
+                                socket = await #(&self.websockets).connect(self.url + #(quoted(websocket.path.as_str())))
+
+                                def encode(value):
+                                    return #(req.encode(quote!(value)))
+
+                                def decode(data):
+                                    #(if let Some(d) = res.decode("data", 0) {
+                                        #d
+                                        #<line>
+                                    })
+                                    return data
+
+                                return (self.Sender(socket, #(quoted(e.name())), encode), self.Receiver(socket, #(quoted(e.name())), decode))
+                        }
+                    )
+                })
+        }
+
+        Ok(())
+    }
+}
+
+impl Initializer for Module {
+    type Options = Options;
+
+    fn initialize(&self, options: &mut Options) -> Result<()> {
+        options
+            .service_generators
+            .push(Box::new(WebSocketsServiceCodegen::new()));
+
+        Ok(())
+    }
+}
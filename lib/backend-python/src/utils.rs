@@ -1,5 +1,7 @@
-use genco::lang::Python;
-use genco::tokens::{FormatInto, ItemStr};
+use core::RpDeprecated;
+use genco::lang::{python, Python};
+use genco::quote_in;
+use genco::tokens::{quoted, FormatInto, ItemStr};
 use genco::Tokens;
 use std::fmt;
 
@@ -29,6 +31,25 @@ where
     }
 }
 
+/// Emit a `DeprecationWarning` if the given item is deprecated.
+pub struct DeprecationWarning<'a>(pub &'a str, pub Option<&'a RpDeprecated>);
+
+impl<'a> FormatInto<Python> for DeprecationWarning<'a> {
+    fn format_into(self, out: &mut Tokens<Python>) {
+        let deprecated = match self.1 {
+            Some(deprecated) => deprecated,
+            None => return,
+        };
+
+        let warnings = python::import_module("warnings");
+        let message = format!("{} is {}", self.0, deprecated);
+
+        quote_in! { *out =>
+            #warnings.warn(#(quoted(message)), DeprecationWarning, stacklevel=2)
+        }
+    }
+}
+
 /// Utilities for handling differences between python versions.
 pub trait VersionHelper: fmt::Debug {
     /// Check if the given variable is a string.
@@ -1,10 +1,10 @@
 use core::errors::Result;
 use core::flavored::*;
-use core::{CoreFlavor, Handle, RelativePathBuf, Spanned, DEFAULT_TAG};
+use core::{CoreFlavor, Handle, RelativePathBuf, RpDeprecated, Spanned, DEFAULT_TAG};
 use genco::fmt;
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr};
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::fmt::Write as _;
 use std::path::Path;
@@ -14,6 +14,7 @@ pub enum Interior<'a> {
     Field(&'a Spanned<RpField>),
     Decl(&'a RpDecl),
     SubType(&'a Spanned<RpSubType>),
+    Unknown(&'a Spanned<RpUnknown>),
 }
 
 impl FormatInto<Reproto> for Interior<'_> {
@@ -32,6 +33,7 @@ impl FormatInto<Reproto> for Interior<'_> {
                     .chain(sub_type.decls.iter().map(Interior::Decl));
 
                 quote_in! { *t =>
+                    #(Deprecated(sub_type.deprecated.as_ref()))
                     #(if let Some(ref alias) = sub_type.sub_type_name {
                         #(sub_type.ident.as_str()) as #(quoted(alias.as_str()))
                     } else {
@@ -41,7 +43,67 @@ impl FormatInto<Reproto> for Interior<'_> {
                     }
                 }
             }
+            Self::Unknown(unknown) => {
+                quote_in! { *t =>
+                    #(Comments(&unknown.comment))
+                    #[unknown]
+                    #(unknown.ident.as_str());
+                }
+            }
+        }
+    }
+}
+
+pub struct Deprecated<'a>(Option<&'a RpDeprecated>);
+
+impl FormatInto<Reproto> for Deprecated<'_> {
+    fn format_into(self, t: &mut Tokens<Reproto>) {
+        let deprecated = match self.0 {
+            Some(deprecated) => deprecated,
+            None => return,
+        };
+
+        let mut args = Vec::new();
+
+        if let Some(since) = deprecated.since.as_ref() {
+            args.push(quote!(since = #(quoted(since.as_str()))));
+        }
+
+        if let Some(note) = deprecated.note.as_ref() {
+            args.push(quote!(note = #(quoted(note.as_str()))));
+        }
+
+        t.push();
+
+        if args.is_empty() {
+            quote_in!(*t => #[deprecated]);
+        } else {
+            quote_in!(*t => #[deprecated(#(for a in args join (, ) => #a))]);
+        }
+    }
+}
+
+pub struct TypeParameters<'a>(&'a [String]);
+
+impl FormatInto<Reproto> for TypeParameters<'_> {
+    fn format_into(self, t: &mut Tokens<Reproto>) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        quote_in!(*t => <#(for p in self.0 join (, ) => #(p.as_str()))>);
+    }
+}
+
+pub struct Parents<'a>(&'a [Spanned<RpName>]);
+
+impl FormatInto<Reproto> for Parents<'_> {
+    fn format_into(self, t: &mut Tokens<Reproto>) {
+        if self.0.is_empty() {
+            return;
         }
+
+        quote_in!(*t => : #(for p in self.0 join (, ) => #(p.to_string())));
     }
 }
 
@@ -75,6 +137,10 @@ pub struct ReprotoLang;
 impl Lang for ReprotoLang {
     manifest::lang_base!(ReprotoModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Reproto)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("//{}", input.to_string()))
     }
@@ -180,19 +246,43 @@ pub fn format(out: &mut Tokens<Reproto>, decl: &RpDecl) {
         RpDecl::Tuple(tuple) => format_tuple(out, tuple),
         RpDecl::Enum(en) => format_enum(out, en),
         RpDecl::Service(service) => format_service(out, service),
+        RpDecl::Const(body) => format_const(out, body),
+        RpDecl::Alias(body) => format_alias(out, body),
+        // unions are written as the type of the field that declares them.
+        RpDecl::Union(..) => {}
+    }
+}
+
+fn format_alias(out: &mut Tokens<Reproto>, body: &RpAliasBody) {
+    quote_in! { *out =>
+        #(Comments(&body.comment))
+        #(Deprecated(body.deprecated.as_ref()))
+        #(if body.newtype => #[newtype])
+        type #(body.ident.as_str()) = #(body.ty.to_string());
+    }
+}
+
+fn format_const(out: &mut Tokens<Reproto>, body: &RpConstBody) {
+    quote_in! { *out =>
+        #(Comments(&body.comment))
+        #(Deprecated(body.deprecated.as_ref()))
+        const #(body.ident.as_str()): #(body.ty.to_string()) = #(body.value.to_string());
     }
 }
 
 fn format_type(out: &mut Tokens<Reproto>, body: &RpTypeBody) {
+    // inherited fields are provided by the parents.
     let interior = body
         .fields
         .iter()
+        .filter(|f| f.inherited.is_none())
         .map(Interior::Field)
         .chain(body.decls.iter().map(Interior::Decl));
 
     quote_in! { *out =>
         #(Comments(&body.comment))
-        type #(body.ident.as_str()) {
+        #(Deprecated(body.deprecated.as_ref()))
+        type #(body.ident.as_str())#(TypeParameters(&body.type_parameters))#(Parents(&body.parents)) {
             #(for i in interior join (#<line>) => #i)
         }
     }
@@ -203,6 +293,7 @@ fn format_interface(out: &mut Tokens<Reproto>, body: &RpInterfaceBody) {
         .sub_types
         .iter()
         .map(Interior::SubType)
+        .chain(body.unknown.iter().map(Interior::Unknown))
         .chain(body.decls.iter().map(Interior::Decl));
 
     quote_in! { *out =>
@@ -210,13 +301,20 @@ fn format_interface(out: &mut Tokens<Reproto>, body: &RpInterfaceBody) {
             RpSubTypeStrategy::Tagged { tag, .. } if tag != DEFAULT_TAG => {
                 #[type_info(strategy = "tagged", tag = #(quoted(tag.as_str())))]
             }
+            RpSubTypeStrategy::Adjacent { tag, content } => {
+                #[type_info(strategy = "adjacent", tag = #(quoted(tag.as_str())), content = #(quoted(content.as_str())))]
+            }
+            RpSubTypeStrategy::External => {
+                #[type_info(strategy = "external")]
+            }
             RpSubTypeStrategy::Untagged => {
                 #[type_info(strategy = "untagged")]
             }
             _ => {}
         })
         #(Comments(&body.comment))
-        interface #(body.ident.as_str()) {
+        #(Deprecated(body.deprecated.as_ref()))
+        interface #(body.ident.as_str())#(TypeParameters(&body.type_parameters)) {
             #(for i in interior join (#<line>) => #i)
         }
     }
@@ -231,6 +329,7 @@ fn format_tuple(out: &mut Tokens<Reproto>, body: &RpTupleBody) {
 
     quote_in! { *out =>
         #(Comments(&body.comment))
+        #(Deprecated(body.deprecated.as_ref()))
         tuple #(body.ident.as_str()) {
             #(for i in interior join (#<line>) => #i)
         }
@@ -240,10 +339,15 @@ fn format_tuple(out: &mut Tokens<Reproto>, body: &RpTupleBody) {
 fn format_enum(out: &mut Tokens<Reproto>, body: &RpEnumBody) {
     quote_in! { *out =>
         #(Comments(&body.comment))
+        #(Deprecated(body.deprecated.as_ref()))
         enum #(&body.ident) as #(body.enum_type.to_string()) {
             #(for v in &body.variants join (#<line>) =>
                 #(ref out => format_variant(out, v))
             )
+            #(if let Some(unknown) = &body.unknown {
+                #<line>
+                #(Interior::Unknown(unknown))
+            })
         }
     }
 }
@@ -251,9 +355,11 @@ fn format_enum(out: &mut Tokens<Reproto>, body: &RpEnumBody) {
 fn format_service(out: &mut Tokens<Reproto>, body: &RpServiceBody) {
     quote_in! { *out =>
         #(Comments(&body.comment))
+        #(Deprecated(body.deprecated.as_ref()))
         service #(body.ident.as_str()) {
             #(for e in &body.endpoints join (#<line>) =>
                 #(Comments(&e.comment))
+                #(Deprecated(e.deprecated.as_ref()))
                 #(ref out => format_endpoint(out, e))
             )
         }
@@ -280,12 +386,15 @@ fn format_field(out: &mut Tokens<Reproto>, field: &RpField) {
 
     quote_in! { *out =>
         #(Comments(&field.comment))
+        #(Deprecated(field.deprecated.as_ref()))
         #(if field.is_optional() {
             #(field_name)?: #(&field.ty.to_string())
         } else {
             #(field_name): #(field.ty.to_string())
         })#(if let Some(ref field_as) = field.field_as {
             #<space>as #(quoted(field_as.as_str()))
+        })#(if let Some(ref default) = field.default {
+            #<space>= #(default.to_string())
         })
     }
 }
@@ -293,6 +402,7 @@ fn format_field(out: &mut Tokens<Reproto>, field: &RpField) {
 fn format_variant(out: &mut Tokens<Reproto>, variant: RpVariantRef<'_>) {
     quote_in! { *out =>
         #(Comments(variant.comment))
+        #(Deprecated(variant.deprecated))
         #(variant.ident()) as #(match variant.value {
             RpVariantValue::String(string) => {
                 #(quoted(string))
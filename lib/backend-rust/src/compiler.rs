@@ -5,9 +5,10 @@ use crate::utils::Comments;
 use crate::{Options, Root, Service, EXT, MOD, TYPE_SEP};
 use backend::PackageProcessor;
 use core::errors::Result;
-use core::{Handle, RelativePathBuf, Spanned};
+use core::{Handle, RelativePathBuf, RpCheck, RpLiteral, Spanned};
 use genco::prelude::*;
 use genco::tokens::FormatInto;
+use naming::Naming as _;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::rc::Rc;
@@ -38,7 +39,21 @@ pub(crate) struct EnumDerives;
 
 impl FormatInto<Rust> for EnumDerives {
     fn format_into(self, tokens: &mut Tokens<Rust>) {
-        quote_in! { *tokens => #[derive(Clone, Copy, Debug, PartialEq, Eq)] }
+        quote_in! { *tokens => #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)] }
+    }
+}
+
+/// Derives for string enums, which also permits them to be used as map keys and in sets.
+pub(crate) struct StringEnumDerives<'a>(&'a Derives);
+
+impl<'a> FormatInto<Rust> for StringEnumDerives<'a> {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        let Derives {
+            serialize,
+            deserialize,
+        } = self.0;
+
+        quote_in! { *tokens => #[derive(Clone, Debug, PartialEq, Eq, Hash, #serialize, #deserialize)] }
     }
 }
 
@@ -51,6 +66,62 @@ impl<'a> FormatInto<Rust> for Rename<'a> {
     }
 }
 
+/// A deprecation attribute, if the item is deprecated.
+pub(crate) struct Deprecated<'a>(pub(crate) Option<&'a RpDeprecated>);
+
+impl<'a> FormatInto<Rust> for Deprecated<'a> {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        let deprecated = match self.0 {
+            Some(deprecated) => deprecated,
+            None => return,
+        };
+
+        let mut args = Vec::new();
+
+        if let Some(since) = &deprecated.since {
+            args.push(quote!(since = #(quoted(since))));
+        }
+
+        if let Some(note) = &deprecated.note {
+            args.push(quote!(note = #(quoted(note))));
+        }
+
+        if args.is_empty() {
+            quote_in!(*tokens => #[deprecated]);
+        } else {
+            quote_in!(*tokens => #[deprecated(#(for a in args join (, ) => #a))]);
+        }
+    }
+}
+
+/// Type parameters of a generic declaration, if there are any.
+pub(crate) struct TypeParameters<'a>(pub(crate) &'a [String]);
+
+impl<'a> FormatInto<Rust> for TypeParameters<'a> {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        quote_in!(*tokens => <#(for p in self.0 join (, ) => #p)>);
+    }
+}
+
+/// Format a literal value of the given type.
+fn literal(ty: &Type, literal: &RpLiteral) -> Tokens<Rust> {
+    match (literal, ty) {
+        (RpLiteral::String(string), _) => quote!(#(quoted(string))),
+        (RpLiteral::Boolean(boolean), _) => quote!(#(boolean.to_string())),
+        (RpLiteral::Number(number), Type::Primitive(Primitive::F32)) => {
+            quote!(#(format!("{}f32", number)))
+        }
+        (RpLiteral::Number(number), Type::Primitive(Primitive::F64)) => {
+            quote!(#(format!("{}f64", number)))
+        }
+        (RpLiteral::Number(number), _) => quote!(#(number.to_string())),
+    }
+}
+
 /// Tag attribute.
 pub(crate) struct Tag<'a>(&'a str);
 
@@ -60,6 +131,15 @@ impl<'a> FormatInto<Rust> for Tag<'a> {
     }
 }
 
+/// Adjacent tag attribute.
+pub(crate) struct Adjacent<'a>(&'a str, &'a str);
+
+impl<'a> FormatInto<Rust> for Adjacent<'a> {
+    fn format_into(self, tokens: &mut Tokens<Rust>) {
+        quote_in!(*tokens => #[serde(tag = #(quoted(self.0)), content = #(quoted(self.1)))])
+    }
+}
+
 /// Untagged attribute.
 pub(crate) struct Untagged;
 
@@ -74,6 +154,7 @@ pub(crate) struct Compiler<'el> {
     options: Options,
     handle: &'el dyn Handle,
     derives: Derives,
+    to_upper_snake: naming::ToUpperSnake,
 }
 
 impl<'el> Compiler<'el> {
@@ -87,6 +168,7 @@ impl<'el> Compiler<'el> {
             options,
             handle,
             derives: Derives::new(),
+            to_upper_snake: naming::to_upper_snake(),
         }
     }
 
@@ -119,7 +201,10 @@ impl<'el> Compiler<'el> {
         variants: &RpVariants,
     ) {
         quote_in! { *out =>
-            pub fn value(&self) -> #(&body.enum_type) {
+            pub fn value(&self) -> #(match (&body.variants, &body.unknown) {
+                (RpVariants::String { .. }, Some(..)) => &str,
+                _ => #(&body.enum_type),
+            }) {
                 match self {
                     #(for v in variants join (#<push>) =>
                         #(match v.value {
@@ -131,26 +216,272 @@ impl<'el> Compiler<'el> {
                             }
                         })
                     )
+                    #(if let Some(unknown) = &body.unknown {
+                        #(match &body.variants {
+                            RpVariants::String { .. } => {
+                                Self::#(&unknown.ident)(value) => value.as_str(),
+                            }
+                            RpVariants::Number { .. } => {
+                                Self::#(&unknown.ident)(value) => *value,
+                            }
+                        })
+                    })
                 }
             }
         }
     }
 
     // Build the corresponding element out of a field declaration.
-    fn field_element(&self, out: &mut Tokens<Rust>, field: &RpField) {
+    fn field_element(&self, out: &mut Tokens<Rust>, name: &str, field: &RpField) {
         let ident = field.safe_ident().to_string();
 
         quote_in! { *out =>
+            #(Deprecated(field.deprecated.as_ref()))
             #(if field.is_optional() {
                 #[serde(skip_serializing_if="Option::is_none")]
             })
+            #(if field.default.is_some() {
+                #[serde(default = #(quoted(format!("{}::default_{}", name, ident))))]
+            })
             #(if field.name() != ident {
                 #(Rename(field.name()))
             })
+            #(ref out => self.string_encoded_attributes(out, name, field))
+            #(if field.is_string_encoded() && field.is_optional() && field.default.is_none() {
+                #[serde(default)]
+            })
             pub #ident: #(ref out => self.write_type(out, field))
         }
     }
 
+    fn tuple_field(&self, out: &mut Tokens<Rust>, name: &str, field: &RpField) {
+        if field.is_string_encoded() {
+            quote_in! { *out =>
+                #(ref out => self.string_encoded_attributes(out, name, field)) pub #(ref out => self.write_type(out, field))
+            }
+        } else {
+            quote_in!(*out => pub #(ref out => self.write_type(out, field)));
+        }
+    }
+
+    /// Attributes which serialize number fields as strings through the functions built by
+    /// `string_encoded_fns`.
+    fn string_encoded_attributes(&self, out: &mut Tokens<Rust>, name: &str, field: &RpField) {
+        if !field.is_string_encoded() {
+            return;
+        }
+
+        let ident = field.safe_ident();
+
+        quote_in! { *out =>
+            #[serde(serialize_with = #(quoted(format!("{}::serialize_{}", name, ident))), deserialize_with = #(quoted(format!("{}::deserialize_{}", name, ident))))]
+        }
+    }
+
+    // Build functions which serialize number fields as strings.
+    fn string_encoded_fns<'f>(
+        &self,
+        out: &mut Tokens<Rust>,
+        name: &str,
+        type_parameters: &[String],
+        fields: impl IntoIterator<Item = &'f Spanned<RpField>>,
+    ) {
+        let fields = fields
+            .into_iter()
+            .filter(|f| f.is_string_encoded())
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            return;
+        }
+
+        quote_in! { *out =>
+            #<line>
+            impl#(TypeParameters(type_parameters)) #name#(TypeParameters(type_parameters)) {
+                #(for field in fields join (#<line>) =>
+                    #(ref out => self.string_encoded_fn(out, field))
+                )
+            }
+        }
+    }
+
+    fn string_encoded_fn(&self, out: &mut Tokens<Rust>, field: &RpField) {
+        let ident = field.safe_ident();
+        let serializer = rust::import("serde", "Serializer");
+        let deserializer = rust::import("serde", "Deserializer");
+        let des = rust::import("serde", "Deserialize");
+        let error = rust::import("serde::de", "Error");
+
+        if field.is_optional() {
+            quote_in! { *out =>
+                fn #(format!("serialize_{}", ident))<S>(value: &Option<#(&field.ty)>, s: S) -> Result<S::Ok, S::Error>
+                where
+                    S: #serializer,
+                {
+                    match value {
+                        Some(value) => s.collect_str(value),
+                        None => s.serialize_none(),
+                    }
+                }
+
+                fn #(format!("deserialize_{}", ident))<'de, D>(d: D) -> Result<Option<#(&field.ty)>, D::Error>
+                where
+                    D: #deserializer<'de>,
+                {
+                    match <Option<String> as #des>::deserialize(d)? {
+                        Some(value) => value.parse().map(Some).map_err(#error::custom),
+                        None => Ok(None),
+                    }
+                }
+            }
+        } else {
+            quote_in! { *out =>
+                fn #(format!("serialize_{}", ident))<S>(value: &#(&field.ty), s: S) -> Result<S::Ok, S::Error>
+                where
+                    S: #serializer,
+                {
+                    s.collect_str(value)
+                }
+
+                fn #(format!("deserialize_{}", ident))<'de, D>(d: D) -> Result<#(&field.ty), D::Error>
+                where
+                    D: #deserializer<'de>,
+                {
+                    <String as #des>::deserialize(d)?.parse().map_err(#error::custom)
+                }
+            }
+        }
+    }
+
+    // Build functions providing the default values of fields, used through `#[serde(default)]`.
+    fn default_fns<'f>(
+        &self,
+        out: &mut Tokens<Rust>,
+        name: &str,
+        type_parameters: &[String],
+        fields: impl IntoIterator<Item = &'f Spanned<RpField>>,
+    ) {
+        let fields = fields
+            .into_iter()
+            .filter(|f| f.default.is_some())
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            return;
+        }
+
+        quote_in! { *out =>
+            #<line>
+            impl#(TypeParameters(type_parameters)) #name#(TypeParameters(type_parameters)) {
+                #(for field in fields join (#<line>) =>
+                    #(ref out => self.default_fn(out, field))
+                )
+            }
+        }
+    }
+
+    fn default_fn(&self, out: &mut Tokens<Rust>, field: &RpField) {
+        let default = match &field.default {
+            Some(default) => default,
+            None => return,
+        };
+
+        let value = match default {
+            RpLiteral::String(..) => quote!(#(literal(&field.ty, default)).to_string()),
+            _ => literal(&field.ty, default),
+        };
+
+        quote_in! { *out =>
+            fn #(format!("default_{}", field.safe_ident()))() -> #(ref out => self.write_type(out, field)) {
+                Some(#value)
+            }
+        }
+    }
+
+    /// Build a `validate` method checking all fields which have validation rules.
+    fn validate_fn<'f>(
+        &self,
+        out: &mut Tokens<Rust>,
+        name: &str,
+        type_parameters: &[String],
+        fields: impl IntoIterator<Item = &'f Spanned<RpField>>,
+    ) {
+        let fields = fields
+            .into_iter()
+            .filter(|f| !f.validate.is_empty())
+            .collect::<Vec<_>>();
+
+        if fields.is_empty() {
+            return;
+        }
+
+        quote_in! { *out =>
+            #<line>
+            impl#(TypeParameters(type_parameters)) #name#(TypeParameters(type_parameters)) {
+                #(format!("/// Check that the fields of `{}` satisfy their validation rules.", name))
+                pub fn validate(&self) -> Result<(), String> {
+                    #(for field in fields join (#<line>) =>
+                        #(if field.is_optional() {
+                            if let Some(value) = &self.#(field.safe_ident()) {
+                                #(ref out => self.checks(out, name, field))
+                            }
+                        } else {
+                            let value = &self.#(field.safe_ident());
+                            #(ref out => self.checks(out, name, field))
+                        })
+                    )
+
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Check the validation rules of a single field against `value`.
+    fn checks(&self, out: &mut Tokens<Rust>, name: &str, field: &RpField) {
+        for check in field.validate.checks() {
+            let cond = match check {
+                RpCheck::MinLength(n) => quote!(value.chars().count() < #(n.to_string())),
+                RpCheck::MaxLength(n) => quote!(value.chars().count() > #(n.to_string())),
+                RpCheck::Pattern(pattern) => {
+                    let lazy = rust::import("once_cell::sync", "Lazy");
+                    let regex = rust::import("regex", "Regex");
+                    let pattern = quoted(format!("^(?:{})$", pattern));
+                    let ident = format!("{}_PATTERN", self.to_upper_snake.convert(&field.ident));
+
+                    // compiled once, on first use.
+                    quote_in! { *out =>
+                        #<push>
+                        static #(&ident): #(&lazy)<#(&regex)> = #(&lazy)::new(|| #(&regex)::new(#pattern).expect("bad pattern"));
+                    }
+
+                    quote!(!#ident.is_match(value))
+                }
+                RpCheck::Min(n) => {
+                    quote!(*value < #(literal(&field.ty, &RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::Max(n) => {
+                    quote!(*value > #(literal(&field.ty, &RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::ExclusiveMin(n) => {
+                    quote!(*value <= #(literal(&field.ty, &RpLiteral::Number(n.clone()))))
+                }
+                RpCheck::ExclusiveMax(n) => {
+                    quote!(*value >= #(literal(&field.ty, &RpLiteral::Number(n.clone()))))
+                }
+            };
+
+            let message = quoted(format!("{}.{}: {}", name, field.name(), check));
+
+            quote_in! { *out =>
+                #<push>
+                if #cond {
+                    return Err(#message.to_string());
+                }
+            }
+        }
+    }
+
     pub fn compile(&self) -> Result<()> {
         use genco::fmt;
 
@@ -261,13 +592,79 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
 
     fn process_tuple(&self, out: &mut Self::Out, body: &RpTupleBody) -> Result<()> {
         let (name, attributes) = self.convert_type_name(&body.name);
+        let name = &name;
+
+        quote_in! { *out =>
+            #<line>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            #attributes
+            #(&self.derives)
+            pub struct #name(#(for f in &body.fields join (, ) => #(ref out => self.tuple_field(out, name, f))));
+            #(ref out => self.string_encoded_fns(out, name, &[], &body.fields))
+        };
+
+        Ok(())
+    }
+
+    fn process_alias(&self, out: &mut Self::Out, body: &RpAliasBody) -> Result<()> {
+        let (name, attributes) = self.convert_type_name(&body.name);
+
+        // serde serializes newtype structs as the value they wrap.
+        quote_in! { *out =>
+            #<line>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            #attributes
+            #(&self.derives)
+            pub struct #name(pub #(&body.ty));
+        };
+
+        Ok(())
+    }
+
+    fn process_union(&self, out: &mut Self::Out, body: &RpUnionBody) -> Result<()> {
+        let (name, attributes) = self.convert_type_name(&body.name);
 
+        // serde tries each variant in order, the alternatives of a union are known to be decoded
+        // from different kinds of JSON values.
         quote_in! { *out =>
             #<line>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             #attributes
             #(&self.derives)
-            pub struct #name(#(for f in &body.fields join (, ) => pub #(ref out => self.write_type(out, f))));
+            #Untagged
+            pub enum #name {
+                #(for v in &body.variants join (#<push>) {
+                    #(&v.ident)(#(&v.ty)),
+                })
+            }
+        };
+
+        Ok(())
+    }
+
+    fn process_const(&self, out: &mut Self::Out, body: &RpConstBody) -> Result<()> {
+        let name = body
+            .name
+            .path
+            .iter()
+            .map(|part| self.to_upper_snake.convert(part))
+            .collect::<Vec<_>>()
+            .join(TYPE_SEP);
+
+        // string constants can't be owned.
+        let ty = match body.ty {
+            Type::String => &Type::StaticStr,
+            ref ty => ty,
+        };
+
+        quote_in! { *out =>
+            #<line>
+            #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
+            pub const #name: #ty = #(literal(ty, &body.value));
         };
 
         Ok(())
@@ -283,25 +680,39 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
             // attributes.push(Repr(body.enum_type.clone()));
             attributes.push();
             attributes.append(EnumDerives);
+        } else if body.unknown.is_some() {
+            // the unknown variant holds a `String`, so the enum can't be `Copy`.
+            attributes.push();
+            quote_in!(attributes => #[derive(Clone, Debug, PartialEq, Eq, Hash)]);
         } else {
             attributes.push();
-            attributes.append(&self.derives);
+            attributes.append(StringEnumDerives(&self.derives));
         }
 
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             #attributes
             pub enum #name {
                 #(for v in &body.variants join (#<push>) =>
                     #(Comments(v.comment))
+                    #(Deprecated(v.deprecated))
                     #(match v.value {
-                        RpVariantValue::String(string) if string != v.ident() => {
+                        // renames are only used by the derived serde implementations.
+                        RpVariantValue::String(string) if string != v.ident() && body.unknown.is_none() => {
                             #(Rename(string))
                         }
                         _ => {}
                     })
                     #(v.ident()),
                 )
+                #(if let Some(unknown) = &body.unknown {
+                    #(Comments(&unknown.comment))
+                    #(&unknown.ident)(#(match &body.variants {
+                        RpVariants::String { .. } => String,
+                        RpVariants::Number { .. } => #(&body.enum_type),
+                    })),
+                })
             }
 
             impl #name {
@@ -312,15 +723,71 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
                 })
             }
 
-            #(if let RpVariants::Number { variants } = &body.variants {
-                #(ref t => numeric_serialize(t, body, &name, variants))
+            #(match &body.variants {
+                RpVariants::Number { variants } => {
+                    #(ref t => numeric_serialize(t, body, name, variants))
+
+                    #(ref t => numeric_deserialize(t, body, name, variants))
+                }
+                RpVariants::String { variants } => {
+                    #(if let Some(unknown) = &body.unknown {
+                        #(ref t => string_serialize(t, name))
 
-                #(ref t => numeric_deserialize(t, body, &name, variants))
+                        #(ref t => string_deserialize(t, name, variants, unknown))
+                    })
+                }
             })
         }
 
         return Ok(());
 
+        /// Build a string serialize implementation, used when the enum has an unknown variant.
+        fn string_serialize(out: &mut Tokens<Rust>, name: &Rc<String>) {
+            let ser = rust::import("serde", "Serialize");
+            let serializer = rust::import("serde", "Serializer");
+
+            quote_in! { *out =>
+                impl #ser for #name {
+                    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: #serializer
+                    {
+                        s.serialize_str(self.value())
+                    }
+                }
+            }
+        }
+
+        /// Build a string deserialize implementation, which decodes any value not matching a
+        /// variant as the unknown variant.
+        fn string_deserialize<'el>(
+            out: &mut Tokens<Rust>,
+            name: &Rc<String>,
+            variants: &'el Vec<Spanned<RpVariant<String>>>,
+            unknown: &'el RpUnknown,
+        ) {
+            let des = rust::import("serde", "Deserialize");
+            let deserializer = rust::import("serde", "Deserializer");
+
+            quote_in! { *out =>
+                impl<'de> #(&des)<'de> for #name {
+                    fn deserialize<D>(d: D) -> Result<#name, D::Error>
+                    where
+                        D: #deserializer<'de>
+                    {
+                        let value = <String as #(&des)<'de>>::deserialize(d)?;
+
+                        Ok(match value.as_str() {
+                            #(for v in variants =>
+                                #(quoted(&v.value)) => #name::#(v.ident()),#<push>
+                            )
+                            _ => #name::#(&unknown.ident)(value),
+                        })
+                    }
+                }
+            }
+        }
+
         /// Build a numeric serialize implementation.
         fn numeric_serialize<'el, T>(
             out: &mut Tokens<Rust>,
@@ -344,6 +811,9 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
                             #(for v in variants =>
                                 #name::#(v.ident()) => #(v.value.to_string())#(ty),#<push>
                             )
+                            #(if let Some(unknown) = &body.unknown {
+                                #name::#(&unknown.ident)(value) => *value,
+                            })
                         };
 
                         s.serialize_#(&body.enum_type)(o)
@@ -399,7 +869,7 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
                     #(ref out => {
                         numeric_expecting(out, parent, variants);
                         out.line();
-                        numeric_visit(out, &body.enum_type, parent, variants);
+                        numeric_visit(out, &body.enum_type, parent, variants, body.unknown.as_deref());
 
                         if body.enum_type == Type::Primitive(Primitive::I32) {
                             out.line();
@@ -451,6 +921,7 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
             ty: &'el Type,
             parent: &Rc<String>,
             variants: &'el Vec<Spanned<RpVariant<T>>>,
+            unknown: Option<&'el RpUnknown>,
         ) where
             T: fmt::Display,
         {
@@ -465,7 +936,14 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
                         #(for v in &*variants join(,#<push>) =>
                             #(v.value.to_string())#ty => Ok(#(parent.clone())::#(v.ident()))
                         ),
-                        value => Err(E::custom(format!(#error_fmt, value))),
+                        #(match unknown {
+                            Some(unknown) => {
+                                value => Ok(#(parent.clone())::#(&unknown.ident)(value)),
+                            }
+                            None => {
+                                value => Err(E::custom(format!(#error_fmt, value))),
+                            }
+                        })
                     }
                 }
             }
@@ -496,17 +974,21 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
         quote_in! { *out =>
             #<line>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             #attributes
             #(&self.derives)
-            pub struct #name {
+            pub struct #name#(TypeParameters(&body.type_parameters)) {
                 #(for field in &body.fields join (#<line>) =>
                     #(Comments(&field.comment))
-                    #(ref out => self.field_element(out, field)),
+                    #(ref out => self.field_element(out, name, field)),
                 )
             }
+            #(ref out => self.default_fns(out, name, &body.type_parameters, &body.fields))
+            #(ref out => self.string_encoded_fns(out, name, &body.type_parameters, &body.fields))
+            #(ref out => self.validate_fn(out, name, &body.type_parameters, &body.fields))
 
             #(if backend::code_contains!(body.codes, RpContext::Rust) {
-                impl #name {
+                impl#(TypeParameters(&body.type_parameters)) #name#(TypeParameters(&body.type_parameters)) {
                     #(ref out => backend::code_in!(out, &body.codes, RpContext::Rust))
                 }
             })
@@ -518,19 +1000,47 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
     fn process_interface(&self, out: &mut Self::Out, body: &RpInterfaceBody) -> Result<()> {
         let (name, attributes) = self.convert_type_name(&body.name);
 
+        // sub-types only declare the type parameters they use, since unused type parameters
+        // are not permitted.
+        let sub_type_parameters = body
+            .sub_types
+            .iter()
+            .map(|s| {
+                s.type_parameters
+                    .iter()
+                    .filter(|p| {
+                        body.fields
+                            .iter()
+                            .chain(&s.fields)
+                            .any(|f| f.ty.uses_parameter(p))
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let json_value = rust::import("serde_json", "Value").with_module_alias("json");
+
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Deprecated(body.deprecated.as_ref()))
             #(attributes)
-            #(&self.derives)
-            #(match &body.sub_type_strategy {
-                RpSubTypeStrategy::Tagged { tag, .. } => #(Tag(tag.as_str())),
-                RpSubTypeStrategy::Untagged => #Untagged,
+            #(if body.unknown.is_some() {
+                #[derive(Clone, Debug, PartialEq)]
+            } else {
+                #(&self.derives)
+                #(match &body.sub_type_strategy {
+                    RpSubTypeStrategy::Tagged { tag, .. } => #(Tag(tag.as_str())),
+                    RpSubTypeStrategy::Adjacent { tag, content } => #(Adjacent(tag.as_str(), content.as_str())),
+                    RpSubTypeStrategy::External => {}
+                    RpSubTypeStrategy::Untagged => #Untagged,
+                })
             })
-            pub enum #(&name) {
-                #(for s in &body.sub_types join (#<line>) => #(ref out =>
+            pub enum #(&name)#(TypeParameters(&body.type_parameters)) {
+                #(for (s, type_parameters) in body.sub_types.iter().zip(&sub_type_parameters) join (#<line>) => #(ref out =>
                     let (sub_name, _) = self.convert_type_name(&s.name);
 
-                    if let Some(name) = &s.sub_type_name {
+                    if let (Some(name), None) = (&s.sub_type_name, &body.unknown) {
                         if name.as_str() != s.ident.as_str() {
                             out.push();
                             out.append(Rename(name));
@@ -538,34 +1048,172 @@ impl<'el> PackageProcessor<'el, RustFlavor> for Compiler<'el> {
                     }
 
                     out.push();
-                    quote_in!(*out => #(&s.ident)(#sub_name),);
+                    quote_in!(*out => #(&s.ident)(#sub_name#(TypeParameters(type_parameters))),);
                 ))
+                #(if let Some(unknown) = &body.unknown {
+                    #<line>
+                    #(Comments(&unknown.comment))
+                    #(&unknown.ident)(#(&json_value)),
+                })
             }
 
+            #(if let Some(unknown) = &body.unknown {
+                #(ref out => unknown_serialize(out, body, &name, unknown))
+
+                #(ref out => unknown_deserialize(out, body, &name, unknown))
+            })
+
             #(if backend::code_contains!(body.codes, RpContext::Rust) {
-                impl #name {
+                impl#(TypeParameters(&body.type_parameters)) #(&name)#(TypeParameters(&body.type_parameters)) {
                     #(ref out => backend::code_in!(out, &body.codes, RpContext::Rust))
                 }
             })
 
-            #(for s in &body.sub_types join (#<line>) => #(ref out =>
+            #(for (s, type_parameters) in body.sub_types.iter().zip(&sub_type_parameters) join (#<line>) => #(ref out =>
                 let (sub_name, attributes) = self.convert_type_name(&s.name);
 
                 quote_in! { *out =>
                     #(Comments(&s.comment))
+                    #(Deprecated(s.deprecated.as_ref()))
                     #(&self.derives)
                     #attributes
-                    pub struct #sub_name {
+                    pub struct #(&sub_name)#(TypeParameters(type_parameters)) {
                         #(for field in body.fields.iter().chain(&s.fields) join (#<line>) =>
                             #(Comments(&field.comment))
-                            #(ref out => self.field_element(out, field)),
+                            #(ref out => self.field_element(out, &sub_name, field)),
                         )
                     }
+                    #(ref out => self.default_fns(out, &sub_name, type_parameters, body.fields.iter().chain(&s.fields)))
+                    #(ref out => self.string_encoded_fns(out, &sub_name, type_parameters, body.fields.iter().chain(&s.fields)))
+                    #(ref out => self.validate_fn(out, &sub_name, type_parameters, body.fields.iter().chain(&s.fields)))
                 }
             ))
         };
 
-        Ok(())
+        return Ok(());
+
+        /// Build a serialize implementation for an interface with an unknown sub-type, which
+        /// encodes the unknown sub-type as the raw value it was decoded from.
+        fn unknown_serialize(
+            out: &mut Tokens<Rust>,
+            body: &RpInterfaceBody,
+            name: &Rc<String>,
+            unknown: &RpUnknown,
+        ) {
+            let ser = rust::import("serde", "Serialize");
+            let serializer = rust::import("serde", "Serializer");
+            let error = rust::import("serde::ser", "Error");
+            let json = rust::import("serde_json", "Value").with_module_alias("json");
+            let map = rust::import("serde_json", "Map").with_module_alias("json");
+            let to_value = rust::import("serde_json", "to_value").with_module_alias("json");
+
+            quote_in! { *out =>
+                impl#(TypeParameters(&body.type_parameters)) #(&ser) for #name#(TypeParameters(&body.type_parameters))#(if !body.type_parameters.is_empty() {
+                    #<space>where #(for p in &body.type_parameters join (, ) => #p: #(&ser))
+                }) {
+                    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: #serializer
+                    {
+                        let (tag, value) = match self {
+                            #(for sub_type in &body.sub_types =>
+                                #name::#(&sub_type.ident)(value) => (#(quoted(sub_type.name())), #(&to_value)(value)),#<push>
+                            )
+                            #name::#(&unknown.ident)(value) => return #(&ser)::serialize(value, s),
+                        };
+
+                        #(match &body.sub_type_strategy {
+                            RpSubTypeStrategy::Tagged { tag } => {
+                                let mut value = value.map_err(#(&error)::custom)?;
+
+                                if let #(&json)::Object(object) = &mut value {
+                                    object.insert(String::from(#(quoted(tag))), #(&json)::from(tag));
+                                }
+
+                                #(&ser)::serialize(&value, s)
+                            }
+                            RpSubTypeStrategy::Adjacent { tag, content } => {
+                                let value = value.map_err(#(&error)::custom)?;
+
+                                let mut object = #(&map)::new();
+                                object.insert(String::from(#(quoted(tag))), #(&json)::from(tag));
+                                object.insert(String::from(#(quoted(content))), value);
+                                #(&ser)::serialize(&object, s)
+                            }
+                            _ => {
+                                let value = value.map_err(#(&error)::custom)?;
+
+                                let mut object = #(&map)::new();
+                                object.insert(String::from(tag), value);
+                                #(&ser)::serialize(&object, s)
+                            }
+                        })
+                    }
+                }
+            }
+        }
+
+        /// Build a deserialize implementation for an interface with an unknown sub-type, which
+        /// decodes objects with an unrecognized tag as the unknown sub-type.
+        fn unknown_deserialize(
+            out: &mut Tokens<Rust>,
+            body: &RpInterfaceBody,
+            name: &Rc<String>,
+            unknown: &RpUnknown,
+        ) {
+            let des = rust::import("serde", "Deserialize");
+            let deserializer = rust::import("serde", "Deserializer");
+            let owned = rust::import("serde::de", "DeserializeOwned");
+            let error = rust::import("serde::de", "Error");
+            let json = rust::import("serde_json", "Value").with_module_alias("json");
+            let from_value = rust::import("serde_json", "from_value").with_module_alias("json");
+
+            let tag = match &body.sub_type_strategy {
+                RpSubTypeStrategy::Tagged { tag } => {
+                    quote!(value.get(#(quoted(tag))).and_then(#(&json)::as_str))
+                }
+                RpSubTypeStrategy::Adjacent { tag, .. } => {
+                    quote!(value.get(#(quoted(tag))).and_then(#(&json)::as_str))
+                }
+                _ => quote! {
+                    value.as_object().and_then(|o| o.keys().next()).map(String::as_str)
+                },
+            };
+
+            quote_in! { *out =>
+                impl<'de#(for p in &body.type_parameters => , #p)> #(&des)<'de> for #name#(TypeParameters(&body.type_parameters))#(if !body.type_parameters.is_empty() {
+                    #<space>where #(for p in &body.type_parameters join (, ) => #p: #(&owned))
+                }) {
+                    fn deserialize<D>(d: D) -> Result<#name#(TypeParameters(&body.type_parameters)), D::Error>
+                    where
+                        D: #deserializer<'de>
+                    {
+                        let value = <#(&json) as #(&des)<'de>>::deserialize(d)?;
+
+                        let result = match #tag {
+                            #(for sub_type in &body.sub_types =>
+                                Some(#(quoted(sub_type.name()))) => #(&from_value)(#(match &body.sub_type_strategy {
+                                    RpSubTypeStrategy::Tagged { .. } => value,
+                                    RpSubTypeStrategy::Adjacent { content, .. } => value[#(quoted(content))].clone(),
+                                    _ => value[#(quoted(sub_type.name()))].clone(),
+                                })).map(#name::#(&sub_type.ident)),#<push>
+                            )
+                            Some(_) => return Ok(#name::#(&unknown.ident)(value)),
+                            None => #(match &body.sub_type_strategy {
+                                RpSubTypeStrategy::Tagged { tag } | RpSubTypeStrategy::Adjacent { tag, .. } => {
+                                    return Err(#(&error)::missing_field(#(quoted(tag)))),
+                                }
+                                _ => {
+                                    return Err(#(&error)::custom("expected an object with a single key")),
+                                }
+                            })
+                        };
+
+                        result.map_err(#(&error)::custom)
+                    }
+                }
+            }
+        }
     }
 
     fn process_service(&self, out: &mut Self::Out, body: &RpServiceBody) -> Result<()> {
@@ -1,10 +1,10 @@
 //! Rust flavor.
 
-use crate::{SCOPE_SEP, TYPE_SEP};
+use crate::{Options, SCOPE_SEP, TYPE_SEP};
 use core::errors::Result;
 use core::{
-    CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpNumberKind,
-    RpNumberType, RpStringType, Spanned, Translate, Translator,
+    CoreFlavor, Diagnostics, Flavor, FlavorTranslator, PackageTranslator, RpBytesType,
+    RpNumberKind, RpNumberType, RpStringType, Spanned, Translate, Translator,
 };
 use genco::prelude::*;
 use genco::tokens;
@@ -47,6 +47,8 @@ pub(crate) enum Type {
     Option(Box<Type>),
     Custom(Rc<rust::Import>),
     Generic(Rc<rust::Import>, Box<Type>),
+    /// A generic declaration with type arguments.
+    Arguments(Box<Type>, Vec<Type>),
     /// `&'static str`.
     StaticStr,
 }
@@ -71,6 +73,23 @@ impl Type {
     }
 }
 
+impl Type {
+    /// Check if the given type parameter is used by this type.
+    pub(crate) fn uses_parameter(&self, parameter: &str) -> bool {
+        match self {
+            Type::Local(local) => &**local == parameter,
+            Type::Vec(inner) | Type::Option(inner) | Type::Generic(_, inner) => {
+                inner.uses_parameter(parameter)
+            }
+            Type::Map(_, key, value) => {
+                key.uses_parameter(parameter) || value.uses_parameter(parameter)
+            }
+            Type::Arguments(_, arguments) => arguments.iter().any(|a| a.uses_parameter(parameter)),
+            _ => false,
+        }
+    }
+}
+
 impl<'a> tokens::FormatInto<Rust> for &'a Type {
     fn format_into(self, t: &mut rust::Tokens) {
         match self {
@@ -111,6 +130,9 @@ impl<'a> tokens::FormatInto<Rust> for &'a Type {
             Type::Generic(base, a) => {
                 quote_in!(*t => #(&**base)<#(&**a)>);
             }
+            Type::Arguments(base, arguments) => {
+                quote_in!(*t => #(&**base)<#(for a in arguments join (, ) => #a)>);
+            }
         }
     }
 }
@@ -131,17 +153,27 @@ impl Flavor for RustFlavor {
 pub(crate) struct RustFlavorTranslator {
     packages: Rc<Packages>,
     map: Rc<rust::Import>,
+    set: Rc<rust::Import>,
     json_value: Rc<rust::Import>,
     datetime: Option<Type>,
+    date: Option<Type>,
+    time: Option<Type>,
+    uuid: Option<Type>,
+    decimal: Option<Type>,
 }
 
 impl RustFlavorTranslator {
-    pub(crate) fn new(packages: Rc<Packages>, datetime: Option<Type>) -> Self {
+    pub(crate) fn new(packages: Rc<Packages>, options: &Options) -> Self {
         Self {
             packages,
             map: Rc::new(rust::import("std::collections", "HashMap")),
+            set: Rc::new(rust::import("std::collections", "HashSet")),
             json_value: Rc::new(rust::import("serde_json", "Value").with_module_alias("json")),
-            datetime,
+            datetime: options.datetime.clone(),
+            date: options.date.clone(),
+            time: options.time.clone(),
+            uuid: options.uuid.clone(),
+            decimal: options.decimal.clone(),
         }
     }
 }
@@ -187,33 +219,70 @@ impl FlavorTranslator for RustFlavorTranslator {
         Err("Missing implementation for `datetime`, try: -m chrono".into())
     }
 
+    fn translate_date(&self) -> Result<Option<Type>> {
+        Ok(self.date.clone())
+    }
+
+    fn translate_time(&self) -> Result<Option<Type>> {
+        Ok(self.time.clone())
+    }
+
+    fn translate_uuid(&self) -> Result<Option<Type>> {
+        Ok(self.uuid.clone())
+    }
+
+    fn translate_decimal(&self) -> Result<Option<Type>> {
+        Ok(self.decimal.clone())
+    }
+
     fn translate_array(&self, argument: Type) -> Result<Type> {
         Ok(Type::Vec(Box::new(argument)))
     }
 
+    fn translate_set(&self, argument: Type) -> Result<Type> {
+        Ok(Type::Generic(self.set.clone(), Box::new(argument)))
+    }
+
     fn translate_map(&self, key: Type, value: Type) -> Result<Type> {
         Ok(Type::Map(self.map.clone(), Box::new(key), Box::new(value)))
     }
 
+    fn translate_union(&self, _: Vec<Type>) -> Result<Type> {
+        Err("anonymous unions must be lifted".into())
+    }
+
     fn translate_any(&self) -> Result<Type> {
         Ok(Type::Custom(self.json_value.clone()))
     }
 
-    fn translate_bytes(&self) -> Result<Type> {
+    fn translate_bytes(&self, _: RpBytesType) -> Result<Type> {
         Ok(Type::String)
     }
 
-    fn translate_name(&self, _from: &RpPackage, reg: RpReg, name: Spanned<RpName>) -> Result<Type> {
+    fn translate_name(
+        &self,
+        _from: &RpPackage,
+        reg: RpReg,
+        name: Spanned<RpName>,
+        arguments: Vec<Type>,
+    ) -> Result<Type> {
         let ident = reg.ident(&name, |p| p.join(TYPE_SEP), |c| c.join(SCOPE_SEP));
 
-        if let Some(ref prefix) = name.prefix {
+        let ty = if let Some(ref prefix) = name.prefix {
             let package_name = format!("crate::{}", name.package.join("::"));
+            Type::from(rust::import(package_name, ident).with_module_alias(prefix.to_string()))
+        } else {
+            Type::local(ident)
+        };
 
-            return Ok(Type::from(
-                rust::import(package_name, ident).with_module_alias(prefix.to_string()),
-            ));
+        if arguments.is_empty() {
+            return Ok(ty);
         }
 
+        Ok(Type::Arguments(Box::new(ty), arguments))
+    }
+
+    fn translate_parameter(&self, ident: String) -> Result<Type> {
         Ok(Type::local(ident))
     }
 
@@ -11,7 +11,7 @@ use core::errors::Result;
 use core::{CoreFlavor, Handle};
 use genco::prelude::*;
 use genco::tokens::ItemStr;
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::collections::BTreeMap;
 use std::path::Path;
@@ -29,6 +29,10 @@ pub struct RustLang;
 impl Lang for RustLang {
     manifest::lang_base!(Module, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Rust)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("// {}", input))
     }
@@ -96,6 +100,9 @@ impl Lang for RustLang {
 pub(crate) enum Module {
     Chrono,
     Reqwest,
+    Uuid,
+    RustDecimal,
+    TokioTungstenite,
 }
 
 impl TryFromToml for Module {
@@ -103,6 +110,9 @@ impl TryFromToml for Module {
         let result = match id {
             "chrono" => Module::Chrono,
             "reqwest" => Module::Reqwest,
+            "uuid" => Module::Uuid,
+            "rust_decimal" => Module::RustDecimal,
+            "tokio_tungstenite" => Module::TokioTungstenite,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -113,6 +123,9 @@ impl TryFromToml for Module {
         let result = match id {
             "chrono" => Module::Chrono,
             "reqwest" => Module::Reqwest,
+            "uuid" => Module::Uuid,
+            "rust_decimal" => Module::RustDecimal,
+            "tokio_tungstenite" => Module::TokioTungstenite,
             _ => return NoModule::illegal(path, id, value),
         };
 
@@ -122,6 +135,10 @@ impl TryFromToml for Module {
 
 pub(crate) struct Options {
     pub(crate) datetime: Option<Type>,
+    pub(crate) date: Option<Type>,
+    pub(crate) time: Option<Type>,
+    pub(crate) uuid: Option<Type>,
+    pub(crate) decimal: Option<Type>,
     pub(crate) root: Vec<Box<dyn RootCodegen>>,
     pub(crate) service: Vec<Box<dyn ServiceCodegen>>,
     pub(crate) packages: Rc<Packages>,
@@ -151,6 +168,10 @@ pub(crate) trait ServiceCodegen {
 fn options(modules: Vec<Module>, packages: Rc<Packages>) -> Result<Options> {
     let mut options = Options {
         datetime: None,
+        date: None,
+        time: None,
+        uuid: None,
+        decimal: None,
         root: Vec::new(),
         service: Vec::new(),
         packages,
@@ -162,6 +183,9 @@ fn options(modules: Vec<Module>, packages: Rc<Packages>) -> Result<Options> {
         match m {
             Module::Chrono => module::chrono::initialize(&mut options)?,
             Module::Reqwest => module::reqwest::initialize(&mut options)?,
+            Module::Uuid => module::uuid::initialize(&mut options)?,
+            Module::RustDecimal => module::rust_decimal::initialize(&mut options)?,
+            Module::TokioTungstenite => module::tokio_tungstenite::initialize(&mut options)?,
         }
     }
 
@@ -173,9 +197,10 @@ fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest
     let packages = session.packages()?;
     let options = options(modules, packages.clone())?;
 
+    let session = session.lift_unions()?;
     let session = session.translate(flavored::RustFlavorTranslator::new(
         packages.clone(),
-        options.datetime.clone(),
+        &options,
     ))?;
 
     Compiler::new(&session, options, handle).compile()
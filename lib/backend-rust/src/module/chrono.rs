@@ -10,5 +10,7 @@ pub(crate) fn initialize(options: &mut Options) -> Result<()> {
         rust::import("chrono", "DateTime"),
         rust::import("chrono::offset", "Utc"),
     ));
+    options.date = Some(Type::from(rust::import("chrono", "NaiveDate")));
+    options.time = Some(Type::from(rust::import("chrono", "NaiveTime")));
     Ok(())
 }
@@ -1,2 +1,5 @@
 pub(crate) mod chrono;
 pub(crate) mod reqwest;
+pub(crate) mod rust_decimal;
+pub(crate) mod tokio_tungstenite;
+pub(crate) mod uuid;
@@ -1,11 +1,13 @@
 //! gRPC module for Rust.
 
+use crate::compiler::Deprecated;
 use crate::flavored::*;
 use crate::utils::Comments;
 use crate::{Options, Root, RootCodegen, Service, ServiceCodegen, SCOPE_SEP};
 use core::errors::Result;
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr, Tokens};
+use naming::Naming as _;
 use std::rc::Rc;
 
 pub(crate) fn initialize(options: &mut Options) -> Result<()> {
@@ -13,11 +15,18 @@ pub(crate) fn initialize(options: &mut Options) -> Result<()> {
 
     let imported_utils_package = Rc::new(format!("crate::{}", utils_package.join(SCOPE_SEP)));
     let result = Type::from(rust::import(imported_utils_package.clone(), "Result"));
+    let error = Type::from(rust::import(imported_utils_package.clone(), "Error"));
     let path_encode = Type::from(rust::import(imported_utils_package.clone(), "PathEncode"));
+    let ndjson = Type::from(rust::import(imported_utils_package.clone(), "ndjson"));
+    let sse = Type::from(rust::import(imported_utils_package.clone(), "sse"));
 
-    options
-        .service
-        .push(Box::new(ReqwestService::new(result, path_encode)));
+    options.service.push(Box::new(ReqwestService::new(
+        result,
+        error,
+        path_encode,
+        ndjson,
+        sse,
+    )));
 
     options
         .root
@@ -26,6 +35,16 @@ pub(crate) fn initialize(options: &mut Options) -> Result<()> {
     Ok(())
 }
 
+/// Errors which can be converted into the utility `Error` type, with the name of their variant.
+fn errors() -> Vec<(rust::Import, &'static str)> {
+    vec![
+        (rust::import("reqwest", "Error"), "ReqwestError"),
+        (rust::import("url", "ParseError"), "UrlParseError"),
+        (rust::import("std::fmt", "Error"), "FormatError"),
+        (rust::import("serde_json", "Error"), "JsonError"),
+    ]
+}
+
 struct ReqwestUtils {
     utils_package: RpPackage,
 }
@@ -38,11 +57,7 @@ impl ReqwestUtils {
     fn reproto(&self) -> Result<rust::Tokens> {
         let mut t = rust::Tokens::new();
 
-        let errors = vec![
-            (rust::import("reqwest", "Error"), "ReqwestError"),
-            (rust::import("url", "ParseError"), "UrlParseError"),
-            (rust::import("std::fmt", "Error"), "FormatError"),
-        ];
+        let errors = errors();
 
         // basic impl and conversions.
         {
@@ -110,6 +125,107 @@ impl ReqwestUtils {
             };
         }
 
+        // decoding of streaming responses.
+        {
+            let stream = &rust::import("futures::stream", "Stream");
+            let try_unfold = &rust::import("futures::stream", "try_unfold");
+            let try_stream_ext = &rust::import("futures::stream", "TryStreamExt");
+            let response = &rust::import("reqwest", "Response");
+            let deserialize_owned = &rust::import("serde::de", "DeserializeOwned");
+            let from_str = &rust::import("serde_json", "from_str").with_module_alias("json");
+            let take = &rust::import("std::mem", "take");
+
+            t.line();
+
+            quote_in! { t =>
+                #("/// Read the lines of a response as they arrive, without their line endings.")
+                fn lines(res: #response) -> impl #stream<Item = Result<String>> {
+                    #try_unfold((res, Vec::new(), false), |(mut res, mut buf, mut done)| async move {
+                        loop {
+                            if let Some(n) = buf.iter().position(|b| *b == b'\n') {
+                                let mut line = buf.drain(..=n).collect::<Vec<u8>>();
+                                line.pop();
+
+                                if line.last() == Some(&b'\r') {
+                                    line.pop();
+                                }
+
+                                let line = String::from_utf8_lossy(&line).into_owned();
+                                return Ok(Some((line, (res, buf, done))));
+                            }
+
+                            if done {
+                                if buf.is_empty() {
+                                    return Ok(None);
+                                }
+
+                                let line = String::from_utf8_lossy(&#take(&mut buf)).into_owned();
+                                return Ok(Some((line, (res, buf, done))));
+                            }
+
+                            match res.chunk().await? {
+                                Some(chunk) => buf.extend_from_slice(&chunk),
+                                None => done = true,
+                            }
+                        }
+                    })
+                }
+
+                #("/// Decode a response with one JSON document per line.")
+                pub fn ndjson<T>(res: #response) -> impl #stream<Item = Result<T>>
+                where
+                    T: #deserialize_owned,
+                {
+                    #try_unfold(Box::pin(lines(res)), |mut lines| async move {
+                        while let Some(line) = #try_stream_ext::try_next(&mut lines).await? {
+                            if !line.is_empty() {
+                                return Ok(Some((#from_str(&line)?, lines)));
+                            }
+                        }
+
+                        Ok(None)
+                    })
+                }
+
+                #("/// Decode a response of Server-Sent Events, with one JSON document in the data of each event.")
+                pub fn sse<T>(res: #response) -> impl #stream<Item = Result<T>>
+                where
+                    T: #deserialize_owned,
+                {
+                    #try_unfold(Box::pin(lines(res)), |mut lines| async move {
+                        let mut data = None::<String>;
+
+                        while let Some(line) = #try_stream_ext::try_next(&mut lines).await? {
+                            if line.is_empty() {
+                                if let Some(data) = data.take() {
+                                    return Ok(Some((#from_str(&data)?, lines)));
+                                }
+
+                                continue;
+                            }
+
+                            if let Some(value) = line.strip_prefix("data:") {
+                                let value = value.strip_prefix(' ').unwrap_or(value);
+
+                                match &mut data {
+                                    Some(data) => {
+                                        data.push('\n');
+                                        data.push_str(value);
+                                    }
+                                    None => data = Some(value.to_string()),
+                                }
+                            }
+                        }
+
+                        match data {
+                            Some(data) => Ok(Some((#from_str(&data)?, lines))),
+                            None => Ok(None),
+                        }
+                    })
+                }
+            };
+        }
+
         Ok(t)
     }
 }
@@ -124,16 +240,24 @@ impl RootCodegen for ReqwestUtils {
 
 struct ReqwestService {
     result: Type,
+    error: Type,
     path_encode: Type,
+    ndjson: Type,
+    sse: Type,
     client: Type,
+    stream: Type,
 }
 
 impl ReqwestService {
-    pub fn new(result: Type, path_encode: Type) -> Self {
+    pub fn new(result: Type, error: Type, path_encode: Type, ndjson: Type, sse: Type) -> Self {
         Self {
             result,
+            error,
             path_encode,
+            ndjson,
+            sse,
             client: rust::import("reqwest", "Client").into(),
+            stream: rust::import("futures::stream", "Stream").into(),
         }
     }
 }
@@ -148,6 +272,19 @@ impl ServiceCodegen for ReqwestService {
             ..
         } = service;
 
+        let to_upper_camel = naming::to_upper_camel();
+
+        // Endpoints with declared errors, and the name of their error type.
+        let errors = body
+            .endpoints
+            .iter()
+            .filter(|e| e.http1.is_some() && !e.http.errors.is_empty())
+            .map(|e| {
+                let error = format!("{}_{}_Error", name, to_upper_camel.convert(e.ident()));
+                (e, ItemStr::from(error))
+            })
+            .collect::<Vec<_>>();
+
         let name = &ItemStr::from(format!("{}_Reqwest", name));
         let url_ty = &Type::from(rust::import("reqwest", "Url"));
 
@@ -174,18 +311,37 @@ impl ServiceCodegen for ReqwestService {
                             None => continue,
                         };
 
+                        let error = errors
+                            .iter()
+                            .find(|(other, _)| other.ident() == e.ident())
+                            .map(|(_, error)| error);
+
                         quote_in! { *tokens =>
                             #(Comments(&e.comment))
+                            #(Deprecated(e.deprecated.as_ref()))
                             #(Endpoint {
                                 result: &self.result,
                                 path_encode: &self.path_encode,
+                                ndjson: &self.ndjson,
+                                sse: &self.sse,
+                                stream: &self.stream,
                                 e,
                                 http,
+                                error,
                             })
                         };
                     )
                 )
             }
+
+            #(for (e, error) in &errors join (#<line>) =>
+                #(ErrorEnum {
+                    base: &self.error,
+                    name,
+                    e,
+                    error,
+                })
+            )
         };
 
         Ok(())
@@ -267,12 +423,66 @@ impl<'el> FormatInto<Rust> for WritePath<'el> {
     }
 }
 
+/// Build the error type of an endpoint with declared errors.
+struct ErrorEnum<'el> {
+    /// The utility error type, for any errors which aren't declared.
+    base: &'el Type,
+    /// Name of the service struct.
+    name: &'el ItemStr,
+    e: &'el RustEndpoint,
+    error: &'el ItemStr,
+}
+
+impl<'el> FormatInto<Rust> for ErrorEnum<'el> {
+    fn format_into(self, t: &mut Tokens<Rust>) {
+        let ErrorEnum {
+            base,
+            name,
+            e,
+            error,
+        } = self;
+
+        quote_in! { *t =>
+            #(format!("/// Errors returned by `{}::{}`.", name, e.safe_ident()))
+            #[derive(Debug)]
+            #[allow(non_camel_case_types)]
+            pub enum #error {
+                #(for e in &e.http.errors join (#<push>) =>
+                    #(format!("/// Error response with status `{}`.", e.status))
+                    #(format!("Status{}", e.status))(#(&e.ty)),
+                )
+                #("/// Any other error, including unexpected responses.")
+                Other(#base),
+            }
+
+            impl From<#base> for #error {
+                fn from(value: #base) -> Self {
+                    #error::Other(value)
+                }
+            }
+
+            #(for (ty, _) in errors() join (#<line>) =>
+                impl From<#(&ty)> for #error {
+                    fn from(value: #(&ty)) -> Self {
+                        #error::Other(value.into())
+                    }
+                }
+            )
+        }
+    }
+}
+
 /// Build an endpoint method for the service struct.
 struct Endpoint<'el> {
     result: &'el Type,
     path_encode: &'el Type,
+    ndjson: &'el Type,
+    sse: &'el Type,
+    stream: &'el Type,
     e: &'el RustEndpoint,
     http: &'el RpEndpointHttp1,
+    /// Error type of the endpoint, if it declares any errors.
+    error: Option<&'el ItemStr>,
 }
 
 impl<'el> FormatInto<Rust> for Endpoint<'el> {
@@ -280,23 +490,65 @@ impl<'el> FormatInto<Rust> for Endpoint<'el> {
         let Endpoint {
             result,
             path_encode,
+            ndjson,
+            sse,
+            stream,
             e,
             http,
+            error,
         } = self;
 
         // import trait
         t.register(rust::import("std::fmt", "Write"));
 
-        let res = if let Some(res) = &http.response {
-            quote!(#result<#res>)
-        } else {
-            quote!(#result<()>)
+        // NB: `application/octet-stream` bodies are raw bytes, rather than the string encoding of
+        // `bytes`.
+        let response = http
+            .response
+            .as_ref()
+            .map(|res| match (e.http.stream, e.http.accept) {
+                (Some(_), _) => quote!(impl #stream<Item = #result<#res>>),
+                (None, RpAccept::Bytes) => quote!(Vec<u8>),
+                (None, _) => quote!(#res),
+            });
+
+        let response_body = ResponseBody {
+            accept: e.http.accept,
+            stream: e.http.stream.map(|stream| match stream {
+                RpHttpStream::Sse => sse,
+                RpHttpStream::NdJson => ndjson,
+            }),
         };
 
-        let args = e
-            .arguments
-            .iter()
-            .map(|a| quote!(#(a.safe_ident()): #(a.channel.ty())));
+        let res = match (response, error) {
+            (Some(res), Some(error)) => quote!(#result<#res, #error>),
+            (None, Some(error)) => quote!(#result<(), #error>),
+            (Some(res), None) => quote!(#result<#res>),
+            (None, None) => quote!(#result<()>),
+        };
+
+        let args = e.arguments.iter().map(|a| {
+            let is_request = e
+                .request
+                .as_ref()
+                .map(|r| r.ident() == a.ident())
+                .unwrap_or(false);
+
+            if is_request && e.http.content_type == RpAccept::Bytes {
+                quote!(#(a.safe_ident()): Vec<u8>)
+            } else if a.is_optional() {
+                quote!(#(a.safe_ident()): #(&Type::option(a.channel.ty().clone())))
+            } else {
+                quote!(#(a.safe_ident()): #(a.channel.ty()))
+            }
+        });
+
+        let has_parameters = e.http.parameters().next().is_some();
+        let req_var = if has_parameters {
+            quote!(mut req_)
+        } else {
+            quote!(req_)
+        };
 
         let method = match http.method {
             RpHttpMethod::Get => "GET",
@@ -310,11 +562,21 @@ impl<'el> FormatInto<Rust> for Endpoint<'el> {
 
         let method_ty = rust::import("reqwest", "Method");
 
+        // Variables in the path are written with `write!`.
+        let has_path_vars = e
+            .http
+            .path
+            .as_ref()
+            .map(|path| path.vars().next().is_some())
+            .unwrap_or(false);
+
         quote_in! { *t =>
             pub async fn #(e.safe_ident())(&self, #(for a in args join(, ) => #a)) -> #res {
-                use std::fmt::Write as _;
-
                 #(if let Some(path) = &e.http.path {
+                    #(if has_path_vars {
+                        use std::fmt::Write as _;
+                        #<line>
+                    })
                     let mut path_ = String::new();
 
                     #(WritePath {
@@ -329,23 +591,215 @@ impl<'el> FormatInto<Rust> for Endpoint<'el> {
                 })
 
                 #(if let Some(req) = &e.request {
-                    let req_ = self.client
-                        .request(#method_ty::#method, url_)
-                        .json(&#(req.safe_ident()));
+                    #(RequestBody {
+                        req_var: &req_var,
+                        method: quote!(#method_ty::#method),
+                        content_type: e.http.content_type,
+                        req: req.safe_ident(),
+                    })
                 } else {
-                    let req_ = self.client
+                    let #(&req_var) = self.client
                         .request(#method_ty::#method, url_);
                 })
 
-                #(if e.response.is_some() {
+                #(for p in &e.http.query join (#<push>) => #(SetParameter::Query(p)))
+
+                #(for p in &e.http.headers join (#<push>) => #(SetParameter::Header(p)))
+
+                #(if let Some(error) = error {
                     let res_ = req_.send().await?;
-                    let body_ = res_.json().await?;
-                    Ok(body_)
+
+                    match res_.status().as_u16() {
+                        #(for e in &e.http.errors join (#<push>) =>
+                            #(e.status) => return Err(#error::#(format!("Status{}", e.status))(res_.json().await?)),
+                        )
+                        _ => {}
+                    }
+
+                    #(if e.response.is_some() {
+                        let res_ = res_.error_for_status()?;
+                        #(&response_body)
+                    } else {
+                        res_.error_for_status()?;
+                        Ok(())
+                    })
                 } else {
-                    req_.send().await?;
-                    Ok(())
+                    #(if e.response.is_some() {
+                        #(if e.http.stream.is_some() {
+                            let res_ = req_.send().await?.error_for_status()?;
+                        } else {
+                            let res_ = req_.send().await?;
+                        })
+                        #(&response_body)
+                    } else {
+                        req_.send().await?;
+                        Ok(())
+                    })
                 })
             }
         }
     }
 }
+
+/// Build the request `req_var`, with a body encoded with the given media type.
+struct RequestBody<'el> {
+    req_var: &'el Tokens<Rust>,
+    method: Tokens<Rust>,
+    content_type: RpAccept,
+    /// Identifier of the request argument.
+    req: &'el str,
+}
+
+impl<'el> FormatInto<Rust> for RequestBody<'el> {
+    fn format_into(self, t: &mut Tokens<Rust>) {
+        let RequestBody {
+            req_var,
+            method,
+            content_type,
+            req,
+        } = self;
+
+        let json = &rust::import("serde_json", "Value").with_module_alias("json");
+        let to_value = rust::import("serde_json", "to_value").with_module_alias("json");
+        let to_string = rust::import("serde_json", "to_string").with_module_alias("json");
+        let form = rust::import("reqwest::multipart", "Form");
+        let content_type_header = quoted(content_type.as_str());
+
+        match content_type {
+            RpAccept::Json => quote_in! { *t =>
+                let #req_var = self.client
+                    .request(#method, url_)
+                    .json(&#req);
+            },
+            RpAccept::Text | RpAccept::Bytes => quote_in! { *t =>
+                let #req_var = self.client
+                    .request(#method, url_)
+                    .header("Content-Type", #content_type_header)
+                    .body(#req);
+            },
+            RpAccept::Form => quote_in! { *t =>
+                let #req_var = self.client
+                    .request(#method, url_)
+                    .form(&#req);
+            },
+            // NB: each field is sent as a text part, absent optional fields are skipped.
+            RpAccept::Multipart => quote_in! { *t =>
+                let mut form_ = #form::new();
+
+                if let #json::Object(fields_) = #to_value(&#req)? {
+                    for (key_, value_) in fields_ {
+                        form_ = match value_ {
+                            #json::Null => continue,
+                            #json::String(value_) => form_.text(key_, value_),
+                            value_ => form_.text(key_, value_.to_string()),
+                        };
+                    }
+                }
+
+                let #req_var = self.client
+                    .request(#method, url_)
+                    .multipart(form_);
+            },
+            RpAccept::NdJson => quote_in! { *t =>
+                let mut ndjson_ = String::new();
+
+                for item_ in &#req {
+                    ndjson_.push_str(&#to_string(item_)?);
+                    ndjson_.push('\n');
+                }
+
+                let #req_var = self.client
+                    .request(#method, url_)
+                    .header("Content-Type", #content_type_header)
+                    .body(ndjson_);
+            },
+        }
+    }
+}
+
+/// Decode the body of the response `res_` with the given media type, and return it.
+struct ResponseBody<'el> {
+    accept: RpAccept,
+    /// Function decoding the elements of a streaming response, which has a successful status.
+    stream: Option<&'el Type>,
+}
+
+impl<'el> FormatInto<Rust> for &ResponseBody<'el> {
+    fn format_into(self, t: &mut Tokens<Rust>) {
+        let from_str = rust::import("serde_json", "from_str").with_module_alias("json");
+
+        if let Some(stream) = self.stream {
+            quote_in! { *t =>
+                Ok(#stream(res_))
+            }
+
+            return;
+        }
+
+        match self.accept {
+            RpAccept::Text => quote_in! { *t =>
+                let body_ = res_.text().await?;
+                Ok(body_)
+            },
+            RpAccept::Bytes => quote_in! { *t =>
+                let body_ = res_.bytes().await?.to_vec();
+                Ok(body_)
+            },
+            RpAccept::NdJson => quote_in! { *t =>
+                let mut body_ = Vec::new();
+
+                for line_ in res_.text().await?.lines() {
+                    if !line_.is_empty() {
+                        body_.push(#from_str(line_)?);
+                    }
+                }
+
+                Ok(body_)
+            },
+            // NB: form responses are rejected when translating.
+            RpAccept::Json | RpAccept::Form | RpAccept::Multipart => quote_in! { *t =>
+                let body_ = res_.json().await?;
+                Ok(body_)
+            },
+        }
+    }
+}
+
+/// Add a query parameter or a header to the request, skipping optional arguments which are absent.
+enum SetParameter<'el> {
+    Query(&'el RpHttpParameter),
+    Header(&'el RpHttpParameter),
+}
+
+impl<'el> FormatInto<Rust> for SetParameter<'el> {
+    fn format_into(self, t: &mut Tokens<Rust>) {
+        let p = match self {
+            SetParameter::Query(p) | SetParameter::Header(p) => p,
+        };
+
+        let value = if p.argument.is_optional() {
+            "value_"
+        } else {
+            p.argument.safe_ident()
+        };
+
+        let name = quoted(p.name.as_str());
+
+        let set = match self {
+            SetParameter::Query(..) => quote!(req_ = req_.query(&[(#name, #value.to_string())]);),
+            SetParameter::Header(..) => quote!(req_ = req_.header(#name, #value.to_string());),
+        };
+
+        if p.argument.is_optional() {
+            quote_in! { *t =>
+                if let Some(value_) = &#(p.argument.safe_ident()) {
+                    #set
+                }
+            }
+        } else {
+            quote_in! { *t =>
+                #set
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Decimal module for Rust, using the rust_decimal crate.
+
+use crate::flavored::Type;
+use crate::Options;
+use core::errors::Result;
+use genco::lang::rust;
+
+pub(crate) fn initialize(options: &mut Options) -> Result<()> {
+    options.decimal = Some(Type::from(rust::import("rust_decimal", "Decimal")));
+    Ok(())
+}
@@ -0,0 +1,307 @@
+//! WebSocket module for Rust, using tokio-tungstenite.
+
+use crate::compiler::Deprecated;
+use crate::flavored::*;
+use crate::utils::Comments;
+use crate::{Options, Root, RootCodegen, Service, ServiceCodegen, SCOPE_SEP};
+use core::errors::Result;
+use genco::prelude::*;
+use genco::tokens::ItemStr;
+use std::rc::Rc;
+
+pub(crate) fn initialize(options: &mut Options) -> Result<()> {
+    let utils_package = options.packages.new("reproto_websocket")?;
+
+    let imported_utils_package = Rc::new(format!("crate::{}", utils_package.join(SCOPE_SEP)));
+    let result = Type::from(rust::import(imported_utils_package.clone(), "Result"));
+    let sender = Type::from(rust::import(imported_utils_package.clone(), "Sender"));
+    let receiver = Type::from(rust::import(imported_utils_package.clone(), "Receiver"));
+    let connect = Type::from(rust::import(imported_utils_package.clone(), "connect"));
+
+    options.service.push(Box::new(WebSocketService::new(
+        result, sender, receiver, connect,
+    )));
+
+    options
+        .root
+        .push(Box::new(WebSocketUtils::new(utils_package)));
+
+    Ok(())
+}
+
+/// Errors which can be converted into the utility `Error` type, with the name of their variant.
+fn errors() -> Vec<(rust::Import, &'static str)> {
+    vec![
+        (
+            rust::import("tokio_tungstenite::tungstenite", "Error"),
+            "WebSocketError",
+        ),
+        (rust::import("url", "ParseError"), "UrlParseError"),
+        (rust::import("serde_json", "Error"), "JsonError"),
+    ]
+}
+
+struct WebSocketUtils {
+    utils_package: RpPackage,
+}
+
+impl WebSocketUtils {
+    pub fn new(utils_package: RpPackage) -> Self {
+        Self { utils_package }
+    }
+
+    fn reproto_websocket(&self) -> Result<rust::Tokens> {
+        let mut t = rust::Tokens::new();
+
+        let errors = errors();
+
+        // basic impl and conversions.
+        {
+            let result = rust::import("std::result", "Result");
+
+            quote_in! { t =>
+                #[derive(Debug)]
+                pub enum Error {
+                    #(for (ty, v) in errors.iter().cloned() join (,#<push>) => #v(#ty))
+                }
+
+                pub type Result<T, E = Error> = #result<T, E>;
+
+                #(for (ref ty, variant) in errors.iter().cloned() join (#<line>) =>
+                    impl From<#ty> for Error {
+                        fn from(value: #ty) -> Self {
+                            Error::#variant(value)
+                        }
+                    }
+                )
+            };
+        }
+
+        // fmt::Display implementation for Error
+        {
+            let display = rust::import("std::fmt", "Display");
+            let formatter = rust::import("std::fmt", "Formatter");
+            let result = rust::import("std::fmt", "Result");
+
+            t.line();
+
+            quote_in! { t =>
+                impl #display for Error {
+                    fn fmt(&self, fmt: &mut #formatter) -> #result {
+                        match self {
+                            #(for (_, variant) in &errors =>
+                                #<push>Error::#(*variant)(e) => e.fmt(fmt),
+                            )
+                        }
+                    }
+                }
+            };
+        }
+
+        // the halves of a connection.
+        {
+            let sink = &rust::import("futures::sink", "Sink");
+            let sink_ext = &rust::import("futures::sink", "SinkExt");
+            let stream = &rust::import("futures::stream", "Stream");
+            let stream_ext = &rust::import("futures::stream", "StreamExt");
+            let serialize = &rust::import("serde", "Serialize");
+            let deserialize_owned = &rust::import("serde::de", "DeserializeOwned");
+            let json = &rust::import("serde_json", "Value").with_module_alias("json");
+            let map = &rust::import("serde_json", "Map").with_module_alias("json");
+            let to_value = &rust::import("serde_json", "to_value").with_module_alias("json");
+            let to_string = &rust::import("serde_json", "to_string").with_module_alias("json");
+            let from_str = &rust::import("serde_json", "from_str").with_module_alias("json");
+            let from_value = &rust::import("serde_json", "from_value").with_module_alias("json");
+            let phantom_data = &rust::import("std::marker", "PhantomData");
+            let pin = &rust::import("std::pin", "Pin");
+            let connect_async = &rust::import("tokio_tungstenite", "connect_async");
+            let message = &rust::import("tokio_tungstenite::tungstenite", "Message");
+            let tungstenite_error = &rust::import("tokio_tungstenite::tungstenite", "Error");
+            let url = &rust::import("url", "Url");
+
+            t.line();
+
+            quote_in! { t =>
+                type WebSocketSink = #pin<Box<dyn #sink<#message, Error = #tungstenite_error> + Send>>;
+                type WebSocketStream = #pin<Box<dyn #stream<Item = Result<#message, #tungstenite_error>> + Send>>;
+
+                #("/// Connect to a WebSocket, returning the halves which send and receive the messages of an endpoint.")
+                pub async fn connect<T, R>(url: #url, endpoint: &'static str) -> Result<(Sender<T>, Receiver<R>)> {
+                    let (socket, _) = #connect_async(url).await?;
+                    let (sink, stream) = #stream_ext::split(socket);
+
+                    let sender = Sender {
+                        sink: Box::pin(sink),
+                        endpoint,
+                        marker: #phantom_data,
+                    };
+
+                    let receiver = Receiver {
+                        stream: Box::pin(stream),
+                        endpoint,
+                        marker: #phantom_data,
+                    };
+
+                    Ok((sender, receiver))
+                }
+
+                #("/// The half of a bi-directional endpoint which sends requests.")
+                pub struct Sender<T> {
+                    sink: WebSocketSink,
+                    endpoint: &'static str,
+                    marker: #phantom_data<fn(T)>,
+                }
+
+                impl<T> Sender<T>
+                where
+                    T: #serialize,
+                {
+                    #("/// Send a request.")
+                    pub async fn send(&mut self, value: &T) -> Result<()> {
+                        let mut envelope = #map::new();
+                        envelope.insert(String::from("endpoint"), #json::from(self.endpoint));
+                        envelope.insert(String::from("body"), #to_value(value)?);
+
+                        let text = #to_string(&envelope)?;
+                        #sink_ext::send(&mut self.sink, #message::Text(text)).await?;
+                        Ok(())
+                    }
+
+                    #("/// Close the connection, after which no more requests can be sent.")
+                    pub async fn close(&mut self) -> Result<()> {
+                        #sink_ext::close(&mut self.sink).await?;
+                        Ok(())
+                    }
+                }
+
+                #("/// The half of a bi-directional endpoint which receives responses.")
+                pub struct Receiver<T> {
+                    stream: WebSocketStream,
+                    endpoint: &'static str,
+                    marker: #phantom_data<fn() -> T>,
+                }
+
+                impl<T> Receiver<T>
+                where
+                    T: #deserialize_owned,
+                {
+                    #("/// Receive the next response, or `None` once the connection has been closed.")
+                    #("///")
+                    #("/// Messages for other endpoints are ignored.")
+                    pub async fn recv(&mut self) -> Result<Option<T>> {
+                        while let Some(message) = #stream_ext::next(&mut self.stream).await {
+                            let text = match message? {
+                                #message::Text(text) => text,
+                                #message::Close(..) => break,
+                                _ => continue,
+                            };
+
+                            let mut envelope = match #from_str(&text)? {
+                                #json::Object(envelope) => envelope,
+                                _ => continue,
+                            };
+
+                            if envelope.get("endpoint").and_then(|e| e.as_str()) != Some(self.endpoint) {
+                                continue;
+                            }
+
+                            if let Some(body) = envelope.remove("body") {
+                                return Ok(Some(#from_value(body)?));
+                            }
+                        }
+
+                        Ok(None)
+                    }
+                }
+            };
+        }
+
+        Ok(t)
+    }
+}
+
+impl RootCodegen for WebSocketUtils {
+    fn generate(&self, root: Root) -> Result<()> {
+        let Root { files, .. } = root;
+        files.insert(self.utils_package.clone(), self.reproto_websocket()?);
+        Ok(())
+    }
+}
+
+struct WebSocketService {
+    result: Type,
+    sender: Type,
+    receiver: Type,
+    connect: Type,
+    url: Type,
+}
+
+impl WebSocketService {
+    pub fn new(result: Type, sender: Type, receiver: Type, connect: Type) -> Self {
+        Self {
+            result,
+            sender,
+            receiver,
+            connect,
+            url: rust::import("url", "Url").into(),
+        }
+    }
+}
+
+impl ServiceCodegen for WebSocketService {
+    fn generate(&self, service: Service) -> Result<()> {
+        let Service {
+            body,
+            container,
+            name,
+            attributes,
+            ..
+        } = service;
+
+        let websocket = match &body.websocket {
+            Some(websocket) => websocket,
+            None => return Ok(()),
+        };
+
+        let name = ItemStr::from(format!("{}_WebSocket", name));
+        let result = &self.result;
+        let url_ty = &self.url;
+
+        let option_url_ty = match websocket.url {
+            Some(_) => Type::option(url_ty.clone()),
+            None => url_ty.clone(),
+        };
+
+        quote_in! { *container =>
+            #attributes
+            #[allow(non_camel_case_types)]
+            pub struct #(&name) {
+                url: #url_ty,
+            }
+
+            impl #(&name) {
+                pub fn new(url: #(&option_url_ty)) -> #result<Self> {
+                    #(if let Some(url) = &websocket.url {
+                        let url = match url {
+                            Some(url) => url,
+                            None => #url_ty::parse(#(quoted(&**url)))?,
+                        };
+                    })
+
+                    Ok(Self { url })
+                }
+
+                #(for e in body.endpoints.iter().filter(|e| e.is_bidirectional()) join (#<line>) =>
+                    #(Comments(&e.comment))
+                    #(Deprecated(e.deprecated.as_ref()))
+                    pub async fn #(e.safe_ident())(&self) -> #result<(#(&self.sender)<#(e.request.as_ref().map(|r| r.channel.ty()))>, #(&self.receiver)<#(e.response.as_ref().map(|r| r.ty()))>)> {
+                        let url_ = self.url.join(#(quoted(&*websocket.path)))?;
+                        #(&self.connect)(url_, #(quoted(e.name()))).await
+                    }
+                )
+            }
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Uuid module for Rust.
+
+use crate::flavored::Type;
+use crate::Options;
+use core::errors::Result;
+use genco::lang::rust;
+
+pub(crate) fn initialize(options: &mut Options) -> Result<()> {
+    options.uuid = Some(Type::from(rust::import("uuid", "Uuid")));
+    Ok(())
+}
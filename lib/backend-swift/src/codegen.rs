@@ -93,6 +93,14 @@ decl_codegen! {
         pub body: &'a RpInterfaceBody,
     }
 
+    /// Event emitted when a union has been added.
+    union_added<'a> {
+        pub container: &'a mut Vec<swift::Tokens>,
+        pub name: &'a Name,
+        /// Variants of the union, and the identifier of the case holding each of them.
+        pub variants: &'a [(String, &'a RpUnionVariant)],
+    }
+
     /// Event emitted when an interface model has been added.
     package_added<'a> {
         pub files: &'a mut Vec<(RpPackage, swift::Tokens)>,
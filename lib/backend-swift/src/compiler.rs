@@ -4,9 +4,10 @@ use crate::flavored::*;
 use crate::{Options, EXT};
 use backend::PackageProcessor;
 use core::errors::Result;
-use core::{Handle, Spanned};
+use core::{Handle, RpLiteral, Spanned};
 use genco::prelude::*;
 use genco::tokens::{FormatInto, ItemStr};
+use naming::Naming;
 use trans::{self, Packages, Translated};
 
 /// Documentation comments.
@@ -27,6 +28,33 @@ where
     }
 }
 
+/// Availability attribute marking an item as deprecated.
+pub(crate) struct Available<'a>(pub(crate) Option<&'a RpDeprecated>);
+
+impl<'a> FormatInto<Swift> for Available<'a> {
+    fn format_into(self, t: &mut swift::Tokens) {
+        let deprecated = match self.0 {
+            Some(deprecated) => deprecated,
+            None => return,
+        };
+
+        if deprecated.since.is_none() && deprecated.note.is_none() {
+            quote_in!(*t => @available(*, deprecated));
+        } else {
+            quote_in!(*t => @available(*, deprecated, message: #(quoted(deprecated.to_string()))));
+        }
+    }
+}
+
+/// Convert a literal value into a Swift literal.
+fn literal(literal: &RpLiteral) -> swift::Tokens {
+    match literal {
+        RpLiteral::String(string) => quote!(#(quoted(string))),
+        RpLiteral::Number(number) => quote!(#(number.to_string())),
+        RpLiteral::Boolean(boolean) => quote!(#(boolean.to_string())),
+    }
+}
+
 pub(crate) struct Compiler<'a> {
     pub(crate) env: &'a Translated<SwiftFlavor>,
     opt: Options,
@@ -42,12 +70,21 @@ impl<'a> Compiler<'a> {
         Ok(Self { env, opt, handle })
     }
 
+    /// The type used to hold values of an unknown sub-type.
+    fn any_type(&self) -> Type {
+        match self.opt.any_type.first() {
+            Some((_, any_type)) => any_type.clone(),
+            None => Type::Any,
+        }
+    }
+
     /// Set up a model structure for the given fields.
     fn model_struct(
         &self,
         t: &mut swift::Tokens,
         name: &Name,
         comment: &[String],
+        deprecated: Option<&RpDeprecated>,
         fields: &[Spanned<Field>],
         extends: bool,
     ) -> Result<()> {
@@ -62,9 +99,11 @@ impl<'a> Compiler<'a> {
 
         quote_in! { *t =>
             #(Comments(comment))
+            #(Available(deprecated))
             public struct #(&name.name)#extends {
                 #(for field in fields join (#<push>) {
                     #(Comments(&field.comment))
+                    #(Available(field.deprecated.as_ref()))
                     let #(field.safe_ident()): #(field.field_type())
                 })
 
@@ -81,9 +120,10 @@ impl<'a> Compiler<'a> {
         t: &mut swift::Tokens,
         name: &Name,
         comment: &[String],
+        deprecated: Option<&RpDeprecated>,
         fields: &[Spanned<Field>],
     ) -> Result<()> {
-        self.model_struct(t, name, comment, fields, true)?;
+        self.model_struct(t, name, comment, deprecated, fields, true)?;
 
         let mut container = Vec::new();
         self.opt.gen.type_added(&mut container, name, fields);
@@ -158,7 +198,13 @@ impl<'a> PackageProcessor<'a, SwiftFlavor> for Compiler<'a> {
     }
 
     fn process_type(&self, out: &mut Self::Out, body: &RpTypeBody) -> Result<()> {
-        self.model_type(out, &body.name, &body.comment, &body.fields)?;
+        self.model_type(
+            out,
+            &body.name,
+            &body.comment,
+            body.deprecated.as_ref(),
+            &body.fields,
+        )?;
 
         Ok(())
     }
@@ -174,6 +220,7 @@ impl<'a> PackageProcessor<'a, SwiftFlavor> for Compiler<'a> {
                 o,
                 &body.name,
                 &body.comment,
+                body.deprecated.as_ref(),
                 &body.fields,
                 false,
             )?)
@@ -184,16 +231,32 @@ impl<'a> PackageProcessor<'a, SwiftFlavor> for Compiler<'a> {
         Ok(())
     }
 
+    fn process_const(&self, out: &mut Self::Out, body: &RpConstBody) -> Result<()> {
+        quote_in! { *out =>
+            #(Comments(&body.comment))
+            #(Available(body.deprecated.as_ref()))
+            public let #(&body.name): #(&body.ty) = #(literal(&body.value))
+        }
+
+        Ok(())
+    }
+
     fn process_enum(&self, out: &mut Self::Out, body: &RpEnumBody) -> Result<()> {
         let mut containers = Vec::new();
         self.opt.gen.enum_added(&mut containers, &body.name, body);
 
         quote_in! { *out =>
+            #(Available(body.deprecated.as_ref()))
             public enum #(&body.name.name) {
                 #(for v in &body.variants join (#<push>) {
                     #(Comments(v.comment))
+                    #(Available(v.deprecated))
                     case #(v.ident())
                 })
+                #(if let Some(unknown) = &body.unknown {
+                    #(Comments(&unknown.comment))
+                    case #(&unknown.ident)(#(&body.enum_type))
+                })
             }
 
             #(for c in containers join (#<line>) => #c)
@@ -213,10 +276,15 @@ impl<'a> PackageProcessor<'a, SwiftFlavor> for Compiler<'a> {
 
         quote_in! { *out =>
             #(Comments(&body.comment))
+            #(Available(body.deprecated.as_ref()))
             public enum #(body.name.name.clone()) {
                 #(for sub_type in &body.sub_types join (#<push>) {
                     case #(&sub_type.ident)(#(sub_type.name.name.clone()))
                 })
+                #(if let Some(unknown) = &body.unknown {
+                    #(Comments(&unknown.comment))
+                    case #(&unknown.ident)(#(&self.any_type()))
+                })
 
                 #(for c in inner join (#<line>) => #c)
             }
@@ -232,10 +300,45 @@ impl<'a> PackageProcessor<'a, SwiftFlavor> for Compiler<'a> {
                     .collect::<Vec<_>>();
 
                 o.line();
-                self.model_type(o, &sub_type.name, &sub_type.comment, &fields)?;
+                self.model_type(
+                    o,
+                    &sub_type.name,
+                    &sub_type.comment,
+                    sub_type.deprecated.as_ref(),
+                    &fields,
+                )?;
             })
         };
 
         Ok(())
     }
+
+    fn process_union(&self, out: &mut Self::Out, body: &RpUnionBody) -> Result<()> {
+        let to_lower_camel = naming::to_lower_camel();
+
+        let variants = body
+            .variants
+            .iter()
+            .map(|v| (to_lower_camel.convert(&v.ident), v))
+            .collect::<Vec<_>>();
+
+        let mut containers = Vec::new();
+        self.opt
+            .gen
+            .union_added(&mut containers, &body.name, &variants);
+
+        quote_in! { *out =>
+            #(Comments(&body.comment))
+            #(Available(body.deprecated.as_ref()))
+            public enum #(&body.name.name) {
+                #(for (ident, v) in &variants join (#<push>) {
+                    case #ident(#(&v.ty))
+                })
+            }
+
+            #(for c in containers join (#<line>) => #c)
+        }
+
+        Ok(())
+    }
 }
@@ -9,7 +9,7 @@ use backend::Initializer;
 use core::errors::Result;
 use core::{CoreFlavor, Handle};
 use genco::prelude::*;
-use manifest::{Lang, Manifest, NoModule, TryFromToml};
+use manifest::{Lang, Language, Manifest, NoModule, TryFromToml};
 use std::any::Any;
 use std::path::Path;
 use trans::Session;
@@ -23,6 +23,10 @@ pub struct SwiftLang;
 impl Lang for SwiftLang {
     manifest::lang_base!(SwiftModule, compile);
 
+    fn language(&self) -> Option<Language> {
+        Some(Language::Swift)
+    }
+
     fn comment(&self, input: &str) -> Option<String> {
         Some(format!("// {}", input))
     }
@@ -191,6 +195,7 @@ fn compile(handle: &dyn Handle, session: Session<CoreFlavor>, manifest: Manifest
     let modules = manifest::checked_modules(manifest.modules)?;
     let options = options(modules)?;
 
+    let session = session.monomorphize()?.inline_newtypes()?.lift_unions()?;
     let packages = session.packages()?;
 
     let session = session.translate(flavored::SwiftFlavorTranslator::new(
@@ -0,0 +1,77 @@
+//! Helpers for bytes which are encoded as something other than standard base64.
+
+use crate::flavored::*;
+use core::RpBytesEncoding;
+use genco::prelude::*;
+
+/// Test if the field holds bytes which are encoded as something other than standard base64.
+pub(crate) fn is_encoded(field: &Field) -> bool {
+    !field.bytes_encoding.is_base64()
+}
+
+/// Encode the `Data` in `value` as a string with the given encoding.
+pub(crate) fn encode(encoding: RpBytesEncoding, value: swift::Tokens) -> swift::Tokens {
+    quote!(encode_bytes(#value, encoding: #(quoted(encoding.to_string()))))
+}
+
+/// Decode the string in `value` into an optional `Data` with the given encoding.
+pub(crate) fn decode(encoding: RpBytesEncoding, value: swift::Tokens) -> swift::Tokens {
+    quote!(decode_bytes(#value, encoding: #(quoted(encoding.to_string()))))
+}
+
+/// Build the functions used by `encode` and `decode`.
+pub(crate) fn funcs() -> swift::Tokens {
+    let data = swift::import("Foundation", "Data");
+
+    quote! {
+        func encode_bytes(_ data: #(&data), encoding: String) -> String {
+            if encoding == "hex" {
+                return data.map { String(format: "%02x", $0) }.joined()
+            }
+
+            var string = data.base64EncodedString()
+                .replacingOccurrences(of: "+", with: "-")
+                .replacingOccurrences(of: "/", with: "_")
+
+            if encoding == "base64url_nopad" {
+                string = string.replacingOccurrences(of: "=", with: "")
+            }
+
+            return string
+        }
+
+        func decode_bytes(_ string: String, encoding: String) -> #(&data)? {
+            if encoding == "hex" {
+                guard string.count % 2 == 0 else {
+                    return nil
+                }
+
+                var data = #(&data)()
+                var index = string.startIndex
+
+                while index < string.endIndex {
+                    let next = string.index(index, offsetBy: 2)
+
+                    guard let byte = UInt8(string[index..<next], radix: 16) else {
+                        return nil
+                    }
+
+                    data.append(byte)
+                    index = next
+                }
+
+                return data
+            }
+
+            var base64 = string
+                .replacingOccurrences(of: "-", with: "+")
+                .replacingOccurrences(of: "_", with: "/")
+
+            while base64.count % 4 != 0 {
+                base64.append("=")
+            }
+
+            return #(&data)(base64Encoded: base64)
+        }
+    }
+}
@@ -2,11 +2,13 @@
 
 use crate::codegen;
 use crate::flavored::*;
+use crate::module::bytes;
 use crate::Options;
 use backend::Initializer;
 use core::errors::Result;
 use core::Spanned;
 use genco::prelude::*;
+use std::cell::Cell;
 use std::collections::BTreeSet;
 use std::rc::Rc;
 
@@ -26,22 +28,33 @@ impl Initializer for Module {
     type Options = Options;
 
     fn initialize(&self, opt: &mut Self::Options) -> Result<()> {
-        let codegen = Rc::new(Codegen);
+        let codegen = Rc::new(Codegen::new());
         opt.struct_model_extends.push(quote!(Codable));
+        opt.gen.type_added.push(codegen.clone());
         opt.gen.tuple_added.push(codegen.clone());
         opt.gen.struct_model_added.push(codegen.clone());
         opt.gen.enum_added.push(codegen.clone());
         opt.gen.interface_added.push(codegen.clone());
         opt.gen.interface_model_added.push(codegen.clone());
+        opt.gen.union_added.push(codegen.clone());
         opt.gen.package_added.push(codegen.clone());
         opt.any_type.push(("codable", Type::local("AnyCodable")));
         Ok(())
     }
 }
 
-struct Codegen;
+struct Codegen {
+    /// If any field needs the functions to encode and decode bytes.
+    bytes: Cell<bool>,
+}
 
 impl Codegen {
+    fn new() -> Codegen {
+        Codegen {
+            bytes: Cell::new(false),
+        }
+    }
+
     fn utils_package(&self) -> RpPackage {
         RpPackage::parse("reproto_codable")
     }
@@ -49,6 +62,12 @@ impl Codegen {
     fn utils(&self) -> swift::Tokens {
         let mut out = swift::Tokens::default();
         any_codable(&mut out);
+
+        if self.bytes.get() {
+            out.line();
+            out.append(bytes::funcs());
+        }
+
         return out;
 
         fn any_codable(t: &mut swift::Tokens) {
@@ -387,6 +406,10 @@ impl codegen::tuple_added::Codegen for Codegen {
             ..
         } = e;
 
+        if fields.iter().any(|f| bytes::is_encoded(f)) {
+            self.bytes.set(true);
+        }
+
         container.push(quote! {
             #(decodable(name, fields))
 
@@ -403,10 +426,26 @@ impl codegen::tuple_added::Codegen for Codegen {
                         var values = try decoder.unkeyedContainer()
 
                         #(for field in fields join (#<push>) {
-                            #(if field.is_optional() {
-                                self.#(field.safe_ident()) = try values.decodeIfPresent(#(&field.ty).self)
-                            } else {
-                                self.#(field.safe_ident()) = try values.decode(#(&field.ty).self)
+                            #(match (is_string_coded(field), field.is_optional()) {
+                                (true, true) => {
+                                    if let value = try values.decodeIfPresent(String.self) {
+                                        #(parse(field))
+                                        self.#(field.safe_ident()) = value
+                                    } else {
+                                        self.#(field.safe_ident()) = nil
+                                    }
+                                }
+                                (true, false) => {
+                                    let value = try values.decode(String.self)
+                                    #(parse(field))
+                                    self.#(field.safe_ident()) = value
+                                }
+                                (false, true) => {
+                                    self.#(field.safe_ident()) = try values.decodeIfPresent(#(&field.ty).self)
+                                }
+                                (false, false) => {
+                                    self.#(field.safe_ident()) = try values.decode(#(&field.ty).self)
+                                }
                             })
                         })
                     }
@@ -414,6 +453,15 @@ impl codegen::tuple_added::Codegen for Codegen {
             }
         }
 
+        /// Parse the string `value` into the type of the field.
+        fn parse<'f>(field: &'f Field) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                guard let value = #(from_string(field)) else {
+                    throw DecodingError.dataCorruptedError(in: values, debugDescription: #(quoted(expected(field))))
+                }
+            }
+        }
+
         fn encodable<'f>(
             name: &'f Name,
             fields: &'f [Spanned<Field>],
@@ -424,12 +472,23 @@ impl codegen::tuple_added::Codegen for Codegen {
                         var values = encoder.unkeyedContainer()
 
                         #(for field in fields join (#<push>) {
-                            #(if field.is_optional() {
-                                if let #(field.safe_ident()) = self.#(field.safe_ident()) {
-                                    try values.encode(#(field.safe_ident()))
+                            #(match (is_string_coded(field), field.is_optional()) {
+                                (true, true) => {
+                                    if let #(field.safe_ident()) = self.#(field.safe_ident()) {
+                                        try values.encode(#(to_string(field, quote!(#(field.safe_ident())))))
+                                    }
+                                }
+                                (true, false) => {
+                                    try values.encode(#(to_string(field, quote!(self.#(field.safe_ident())))))
+                                }
+                                (false, true) => {
+                                    if let #(field.safe_ident()) = self.#(field.safe_ident()) {
+                                        try values.encode(#(field.safe_ident()))
+                                    }
+                                }
+                                (false, false) => {
+                                    try values.encode(self.#(field.safe_ident()))
                                 }
-                            } else {
-                                try values.encode(self.#(field.safe_ident()))
                             })
                         })
                     }
@@ -459,8 +518,11 @@ impl codegen::enum_added::Codegen for Codegen {
                 extension #name: Decodable {
                     public init(from decoder: Decoder) throws {
                         let value = try decoder.singleValueContainer()
+                        #(if body.unknown.is_some() {
+                            let raw = try value.decode(#(&body.enum_type).self)
+                        })
 
-                        switch try value.decode(#(&body.enum_type).self) {
+                        switch #(if body.unknown.is_some() { raw } else { try value.decode(#(&body.enum_type).self) }) {
                         #(match &body.variants {
                             RpVariants::String { variants } => {
                                 #(for v in variants join (#<push>) {
@@ -476,12 +538,19 @@ impl codegen::enum_added::Codegen for Codegen {
                             }
                         })
                         default:
-                            let context = DecodingError.Context(
-                                codingPath: decoder.codingPath,
-                                debugDescription: "enum variant"
-                            )
+                            #(match &body.unknown {
+                                Some(unknown) => {
+                                    self = .#(&unknown.ident)(raw)
+                                }
+                                None => {
+                                    let context = DecodingError.Context(
+                                        codingPath: decoder.codingPath,
+                                        debugDescription: "enum variant"
+                                    )
 
-                            throw DecodingError.dataCorrupted(context)
+                                    throw DecodingError.dataCorrupted(context)
+                                }
+                            })
                         }
                     }
                 }
@@ -509,6 +578,10 @@ impl codegen::enum_added::Codegen for Codegen {
                                 })
                             }
                         })
+                        #(if let Some(unknown) = &body.unknown {
+                            case .#(&unknown.ident)(let raw):
+                                try value.encode(raw)
+                        })
                         }
                     }
                 }
@@ -517,6 +590,100 @@ impl codegen::enum_added::Codegen for Codegen {
     }
 }
 
+/// Numbers encoded as strings and bytes which aren't encoded as standard base64 can't use the
+/// synthesized implementations, so an extension which implements them is added instead.
+impl codegen::type_added::Codegen for Codegen {
+    fn generate(&self, e: codegen::type_added::Args<'_>) {
+        let codegen::type_added::Args {
+            container,
+            name,
+            fields,
+        } = e;
+
+        if fields.iter().any(|f| bytes::is_encoded(f)) {
+            self.bytes.set(true);
+        }
+
+        if !fields.iter().any(|f| is_string_coded(f)) {
+            return;
+        }
+
+        container.push(quote! {
+            public extension #name {
+                #(decodable(fields))
+
+                #(encodable(fields))
+            }
+        });
+
+        fn decodable<'f>(fields: &'f [Spanned<Field>]) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                init(from decoder: Decoder) throws {
+                    let values = try decoder.container(keyedBy: CodingKeys.self)
+
+                    #(for field in fields join (#<push>) {
+                        #(match (is_string_coded(field), field.is_optional()) {
+                            (true, true) => {
+                                if let value = try values.decodeIfPresent(String.self, forKey: .#(field.safe_ident())) {
+                                    #(parse(field))
+                                    self.#(field.safe_ident()) = value
+                                } else {
+                                    self.#(field.safe_ident()) = nil
+                                }
+                            }
+                            (true, false) => {
+                                let value = try values.decode(String.self, forKey: .#(field.safe_ident()))
+                                #(parse(field))
+                                self.#(field.safe_ident()) = value
+                            }
+                            (false, true) => {
+                                self.#(field.safe_ident()) = try values.decodeIfPresent(#(&field.ty).self, forKey: .#(field.safe_ident()))
+                            }
+                            (false, false) => {
+                                self.#(field.safe_ident()) = try values.decode(#(&field.ty).self, forKey: .#(field.safe_ident()))
+                            }
+                        })
+                    })
+                }
+            }
+        }
+
+        /// Parse the string `value` into the type of the field.
+        fn parse<'f>(field: &'f Field) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                guard let value = #(from_string(field)) else {
+                    throw DecodingError.dataCorruptedError(forKey: .#(field.safe_ident()), in: values, debugDescription: #(quoted(expected(field))))
+                }
+            }
+        }
+
+        fn encodable<'f>(fields: &'f [Spanned<Field>]) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                func encode(to encoder: Encoder) throws {
+                    var values = encoder.container(keyedBy: CodingKeys.self)
+
+                    #(for field in fields join (#<push>) {
+                        #(match (is_string_coded(field), field.is_optional()) {
+                            (true, true) => {
+                                try values.encodeIfPresent(self.#(field.safe_ident()).map { #(to_string(field, quote!($0))) }, forKey: .#(field.safe_ident()))
+                            }
+                            (true, false) => {
+                                try values.encode(#(to_string(field, quote!(self.#(field.safe_ident())))), forKey: .#(field.safe_ident()))
+                            }
+                            (false, true) => {
+                                try values.encodeIfPresent(self.#(field.safe_ident()), forKey: .#(field.safe_ident()))
+                            }
+                            (false, false) => {
+                                try values.encode(self.#(field.safe_ident()), forKey: .#(field.safe_ident()))
+                            }
+                        })
+                    })
+                }
+            }
+        }
+    }
+}
+
 impl codegen::struct_model_added::Codegen for Codegen {
     fn generate(&self, e: codegen::struct_model_added::Args<'_>) {
         let codegen::struct_model_added::Args {
@@ -557,6 +724,12 @@ impl codegen::interface_added::Codegen for Codegen {
                         RpSubTypeStrategy::Tagged { tag, .. } => {
                             #(ref o => tagged_init(o, body, tag))
                         }
+                        RpSubTypeStrategy::Adjacent { tag, .. } => {
+                            #(ref o => adjacent_init(o, body, tag))
+                        }
+                        RpSubTypeStrategy::External => {
+                            #(ref o => external_init(o, body))
+                        }
                         RpSubTypeStrategy::Untagged => {
                             #(ref o => untagged_init(o, body))
                         }
@@ -564,6 +737,62 @@ impl codegen::interface_added::Codegen for Codegen {
                 }
             };
 
+            fn adjacent_init(t: &mut swift::Tokens, body: &RpInterfaceBody, tag: &str) {
+                quote_in! { *t =>
+                    public init(from decoder: Decoder) throws {
+                        let values = try decoder.container(keyedBy: CodingKeys.self)
+
+                        switch try values.decode(String.self, forKey: .tag) {
+                        #(for sub_type in &body.sub_types join (#<push>) {
+                            case #(quoted(sub_type.name())):
+                                self = try .#(sub_type.ident.as_str())(values.decode(#(&sub_type.name).self, forKey: .content))
+                        })
+                        default:
+                            #(match &body.unknown {
+                                Some(unknown) => {
+                                    self = try .#(&unknown.ident)(AnyCodable(from: decoder))
+                                }
+                                None => {
+                                    let context = DecodingError.Context(codingPath: [], debugDescription: #(quoted(tag)))
+                                    throw DecodingError.dataCorrupted(context)
+                                }
+                            })
+                        }
+                    }
+                }
+            }
+
+            fn external_init(t: &mut swift::Tokens, body: &RpInterfaceBody) {
+                quote_in! { *t =>
+                    public init(from decoder: Decoder) throws {
+                        let values = try decoder.container(keyedBy: CodingKeys.self)
+                        #(if let Some(unknown) = &body.unknown {
+                            #<line>
+                            if values.allKeys.isEmpty {
+                                self = try .#(&unknown.ident)(AnyCodable(from: decoder))
+                                return
+                            }
+                        })
+
+                        guard values.allKeys.count == 1 else {
+                            let context = DecodingError.Context(
+                                codingPath: decoder.codingPath,
+                                debugDescription: "expected exactly one key"
+                            )
+
+                            throw DecodingError.dataCorrupted(context)
+                        }
+
+                        switch values.allKeys[0] {
+                        #(for sub_type in &body.sub_types join (#<push>) {
+                            case .#(sub_type.ident.as_str()):
+                                self = try .#(sub_type.ident.as_str())(values.decode(#(&sub_type.name).self, forKey: .#(sub_type.ident.as_str())))
+                        })
+                        }
+                    }
+                }
+            }
+
             fn tagged_init(t: &mut swift::Tokens, body: &RpInterfaceBody, tag: &str) {
                 quote_in! { *t =>
                     public init(from decoder: Decoder) throws {
@@ -575,8 +804,15 @@ impl codegen::interface_added::Codegen for Codegen {
                                 self = try .#(sub_type.ident.as_str())(#(&sub_type.name)(from: decoder))
                         })
                         default:
-                            let context = DecodingError.Context(codingPath: [], debugDescription: #(quoted(tag)))
-                            throw DecodingError.dataCorrupted(context)
+                            #(match &body.unknown {
+                                Some(unknown) => {
+                                    self = try .#(&unknown.ident)(AnyCodable(from: decoder))
+                                }
+                                None => {
+                                    let context = DecodingError.Context(codingPath: [], debugDescription: #(quoted(tag)))
+                                    throw DecodingError.dataCorrupted(context)
+                                }
+                            })
                         }
                     }
                 }
@@ -620,6 +856,12 @@ impl codegen::interface_added::Codegen for Codegen {
                         RpSubTypeStrategy::Tagged { .. } => {
                             encode_tagged(o, body);
                         }
+                        RpSubTypeStrategy::Adjacent { .. } => {
+                            encode_adjacent(o, body);
+                        }
+                        RpSubTypeStrategy::External => {
+                            encode_external(o, body);
+                        }
                         RpSubTypeStrategy::Untagged => {
                             encode_untagged(o, body);
                         }
@@ -627,6 +869,45 @@ impl codegen::interface_added::Codegen for Codegen {
                 }
             };
 
+            fn encode_adjacent(t: &mut swift::Tokens, body: &RpInterfaceBody) {
+                quote_in! { *t =>
+                    public func encode(to encoder: Encoder) throws {
+                        var values = encoder.container(keyedBy: CodingKeys.self)
+
+                        switch self {
+                        #(for sub_type in &body.sub_types join (#<push>) {
+                            case .#(&sub_type.ident)(let d):
+                                try values.encode(#(quoted(sub_type.name())), forKey: .tag)
+                                try values.encode(d, forKey: .content)
+                        })
+                        #(if let Some(unknown) = &body.unknown {
+                            case .#(&unknown.ident)(let d):
+                                try d.encode(to: encoder)
+                        })
+                        }
+                    }
+                }
+            }
+
+            fn encode_external(t: &mut swift::Tokens, body: &RpInterfaceBody) {
+                quote_in! { *t =>
+                    public func encode(to encoder: Encoder) throws {
+                        var values = encoder.container(keyedBy: CodingKeys.self)
+
+                        switch self {
+                        #(for sub_type in &body.sub_types join (#<push>) {
+                            case .#(&sub_type.ident)(let d):
+                                try values.encode(d, forKey: .#(&sub_type.ident))
+                        })
+                        #(if let Some(unknown) = &body.unknown {
+                            case .#(&unknown.ident)(let d):
+                                try d.encode(to: encoder)
+                        })
+                        }
+                    }
+                }
+            }
+
             fn encode_tagged(t: &mut swift::Tokens, body: &RpInterfaceBody) {
                 quote_in! { *t =>
                     public func encode(to encoder: Encoder) throws {
@@ -638,6 +919,10 @@ impl codegen::interface_added::Codegen for Codegen {
                                 try values.encode(#(quoted(sub_type.name())), forKey: .tag)
                                 try d.encode(to: encoder)
                         })
+                        #(if let Some(unknown) = &body.unknown {
+                            case .#(&unknown.ident)(let d):
+                                try d.encode(to: encoder)
+                        })
                         }
                     }
                 }
@@ -673,6 +958,23 @@ impl codegen::interface_model_added::Codegen for Codegen {
                     }
                 });
             }
+            RpSubTypeStrategy::Adjacent { tag, content } => {
+                container.push(quote! {
+                    enum CodingKeys: String, CodingKey {
+                        case tag = #(quoted(tag.as_str()))
+                        case content = #(quoted(content.as_str()))
+                    }
+                });
+            }
+            RpSubTypeStrategy::External => {
+                container.push(quote! {
+                    enum CodingKeys: String, CodingKey {
+                        #(for sub_type in &body.sub_types join (#<push>) {
+                            case #(&sub_type.ident) = #(quoted(sub_type.name()))
+                        })
+                    }
+                });
+            }
             RpSubTypeStrategy::Untagged => {
                 let all = body
                     .sub_types
@@ -707,6 +1009,104 @@ impl codegen::interface_model_added::Codegen for Codegen {
     }
 }
 
+impl codegen::union_added::Codegen for Codegen {
+    fn generate(&self, e: codegen::union_added::Args<'_>) {
+        let codegen::union_added::Args {
+            container,
+            name,
+            variants,
+            ..
+        } = e;
+
+        container.push(quote! {
+            #(decodable(name, variants))
+
+            #(encodable(name, variants))
+        });
+
+        // alternatives are decoded from different kinds of JSON values, so at most one of them
+        // can succeed.
+        fn decodable<'f>(
+            name: &'f Name,
+            variants: &'f [(String, &'f RpUnionVariant)],
+        ) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                extension #name: Decodable {
+                    public init(from decoder: Decoder) throws {
+                        let value = try decoder.singleValueContainer()
+
+                        #(for (ident, v) in variants join (#<line>) {
+                            if let v = try? value.decode(#(&v.ty).self) {
+                                self = .#ident(v)
+                                return
+                            }
+                        })
+
+                        let context = DecodingError.Context(
+                            codingPath: decoder.codingPath,
+                            debugDescription: "no alternative of the union matches the value"
+                        )
+
+                        throw DecodingError.dataCorrupted(context)
+                    }
+                }
+            }
+        }
+
+        fn encodable<'f>(
+            name: &'f Name,
+            variants: &'f [(String, &'f RpUnionVariant)],
+        ) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                extension #name: Encodable {
+                    public func encode(to encoder: Encoder) throws {
+                        var value = encoder.singleValueContainer()
+
+                        switch self {
+                        #(for (ident, _) in variants join (#<push>) {
+                            case .#ident(let v):
+                                try value.encode(v)
+                        })
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Test if the field is encoded as a string which has to be converted by hand.
+fn is_string_coded(field: &Field) -> bool {
+    field.is_string_encoded() || bytes::is_encoded(field)
+}
+
+/// Convert the `value` of a string coded field into a string.
+fn to_string(field: &Field, value: swift::Tokens) -> swift::Tokens {
+    if bytes::is_encoded(field) {
+        bytes::encode(field.bytes_encoding, value)
+    } else {
+        quote!(String(#value))
+    }
+}
+
+/// Convert the string `value` into an optional value of the string coded field.
+fn from_string(field: &Field) -> swift::Tokens {
+    if bytes::is_encoded(field) {
+        bytes::decode(field.bytes_encoding, quote!(value))
+    } else {
+        quote!(#(&field.ty)(value))
+    }
+}
+
+/// Describe what was expected when a string coded field can't be parsed.
+fn expected(field: &Field) -> String {
+    if bytes::is_encoded(field) {
+        format!("expected bytes encoded as {}", field.bytes_encoding)
+    } else {
+        String::from("expected a number encoded as a string")
+    }
+}
+
 impl codegen::package_added::Codegen for Codegen {
     fn generate(&self, e: codegen::package_added::Args<'_>) {
         e.files.push((self.utils_package(), self.utils()));
@@ -1,3 +1,4 @@
+mod bytes;
 mod codable;
 mod grpc;
 pub(crate) mod simple;
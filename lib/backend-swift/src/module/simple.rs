@@ -3,12 +3,14 @@
 use crate::codegen;
 use crate::compiler::Comments;
 use crate::flavored::*;
+use crate::module::bytes;
 use crate::Options;
 use backend::Initializer;
 use core::errors::Result;
 use core::Spanned;
 use genco::prelude::*;
 use genco::tokens::ItemStr;
+use std::cell::Cell;
 use std::rc::Rc;
 
 static NUMERICS: [&str; 6] = ["Int", "UInt", "Int32", "Int64", "UInt32", "UInt64"];
@@ -32,16 +34,22 @@ impl Initializer for Module {
         opt.gen.tuple_added.push(codegen.clone());
         opt.gen.enum_added.push(codegen.clone());
         opt.gen.interface_added.push(codegen.clone());
+        opt.gen.union_added.push(codegen.clone());
         opt.gen.package_added.push(codegen.clone());
         Ok(())
     }
 }
 
-struct Codegen {}
+struct Codegen {
+    /// If any field needs the functions to encode and decode bytes.
+    bytes: Cell<bool>,
+}
 
 impl Codegen {
     pub(crate) fn new() -> Codegen {
-        Self {}
+        Self {
+            bytes: Cell::new(false),
+        }
     }
 
     // Setup a field initializer.
@@ -56,13 +64,27 @@ impl Codegen {
         let ident = field.safe_ident();
         let name = field.name();
 
+        if bytes::is_encoded(field) {
+            self.bytes.set(true);
+        }
+
+        let encode = move |var: swift::Tokens| {
+            if bytes::is_encoded(field) {
+                bytes::encode(field.bytes_encoding, var)
+            } else if field.is_string_encoded() {
+                quote!(String(#var))
+            } else {
+                field.ty.encode_value(name, var)
+            }
+        };
+
         quote_fn! {
             #(if field.is_optional() {
                 if let value = self.#ident {
-                    #(append(field.ty.encode_value(name, quote!(value))))
+                    #(append(encode(quote!(value))))
                 }
             } else {
-                #(append(field.ty.encode_value(name, quote!(self.#ident))))
+                #(append(encode(quote!(self.#ident))))
             })
         }
     }
@@ -76,19 +98,36 @@ impl Codegen {
 
         let (name, index) = index(field, ItemStr::from("json"));
 
+        let decode = {
+            let name = name.clone();
+
+            move |var: swift::Tokens| {
+                if bytes::is_encoded(field) {
+                    let string = quote!(try decode_value(#var as? String));
+                    let data = bytes::decode(field.bytes_encoding, string);
+                    quote!(try decode_name(#data, name: #(quoted(name.clone()))))
+                } else if field.is_string_encoded() {
+                    let string = quote!(try decode_value(#var as? String));
+                    quote!(try decode_name(#(&field.ty)(#string), name: #(quoted(name.clone()))))
+                } else {
+                    field.ty.decode_value(name.clone(), var)
+                }
+            }
+        };
+
         quote_fn! {
             #(if field.is_optional() {
                 var #ident: #(field.field_type()) = Optional.none
 
                 if let value = #index {
-                    #ident = Optional.some(#(field.ty.decode_value(name, quote!(value))))
+                    #ident = Optional.some(#(decode(quote!(value))))
                 }
             } else {
                 guard let #(&*f_ident) = #index else {
                     throw SerializationError.missing(#(quoted(name.clone())))
                 }
 
-                let #ident = #(field.ty.decode_value(name, quote!(#(&*f_ident))))
+                let #ident = #(decode(quote!(#(&*f_ident))))
             })
         }
     }
@@ -131,6 +170,10 @@ impl Codegen {
             #(decode_map_func())
 
             #(encode_map_func())
+
+            #(if self.bytes.get() {
+                #(bytes::funcs())
+            })
         };
 
         /// Build a simple unboxing functions.
@@ -443,7 +486,14 @@ impl codegen::enum_added::Codegen for Codegen {
                         }
                     })
                     default:
-                        throw SerializationError.bad_value
+                        #(match &body.unknown {
+                            Some(unknown) => {
+                                return #name.#(&unknown.ident)(value)
+                            }
+                            None => {
+                                throw SerializationError.bad_value
+                            }
+                        })
                     }
                 }
             }
@@ -467,6 +517,10 @@ impl codegen::enum_added::Codegen for Codegen {
                             })
                         }
                     })
+                    #(if let Some(unknown) = &body.unknown {
+                        case .#(&unknown.ident)(let value):
+                            return value
+                    })
                     }
                 }
             }
@@ -483,13 +537,23 @@ impl codegen::interface_added::Codegen for Codegen {
             ..
         } = e;
 
+        let unknown = body.unknown.as_deref();
+
         container.push(quote! {
             #(Comments(&body.comment))
             public extension #name {
                 #(match &body.sub_type_strategy {
                     RpSubTypeStrategy::Tagged { tag, .. } => {
-                        #(decode_tag(name, tag.as_str(), &body.sub_types))
-                        #(encode_tag(tag.as_str(), &body.sub_types))
+                        #(decode_tag(name, tag.as_str(), &body.sub_types, unknown))
+                        #(encode_tag(tag.as_str(), &body.sub_types, unknown))
+                    }
+                    RpSubTypeStrategy::Adjacent { tag, content } => {
+                        #(decode_adjacent(name, tag.as_str(), content.as_str(), &body.sub_types, unknown))
+                        #(encode_adjacent(tag.as_str(), content.as_str(), &body.sub_types, unknown))
+                    }
+                    RpSubTypeStrategy::External => {
+                        #(decode_external(name, &body.sub_types, unknown))
+                        #(encode_external(&body.sub_types, unknown))
                     }
                     RpSubTypeStrategy::Untagged => {
                         #(decode_untagged(name, body))
@@ -504,6 +568,7 @@ impl codegen::interface_added::Codegen for Codegen {
             name: &'f Name,
             tag: &'f str,
             sub_types: S,
+            unknown: Option<&'f RpUnknown>,
         ) -> impl FormatInto<Swift> + 'f
         where
             S: IntoIterator<Item = &'el Spanned<RpSubType>>,
@@ -520,14 +585,25 @@ impl codegen::interface_added::Codegen for Codegen {
                             return #name.#(&sub_type.ident)(v)
                     })
                     default:
-                        throw SerializationError.invalid(type)
+                        #(match unknown {
+                            Some(unknown) => {
+                                return #name.#(&unknown.ident)(json)
+                            }
+                            None => {
+                                throw SerializationError.invalid(type)
+                            }
+                        })
                     }
                 }
             }
         }
 
         /// Build a method to decode a tagged interface.
-        fn encode_tag<'f, 'el: 'f, S: 'f>(tag: &'f str, sub_types: S) -> impl FormatInto<Swift> + 'f
+        fn encode_tag<'f, 'el: 'f, S: 'f>(
+            tag: &'f str,
+            sub_types: S,
+            unknown: Option<&'f RpUnknown>,
+        ) -> impl FormatInto<Swift> + 'f
         where
             S: IntoIterator<Item = &'el Spanned<RpSubType>>,
         {
@@ -540,6 +616,149 @@ impl codegen::interface_added::Codegen for Codegen {
                             json[#(quoted(tag))] = #(quoted(sub_type.name()))
                             return json
                     })
+                    #(if let Some(unknown) = unknown {
+                        case .#(&unknown.ident)(let json):
+                            guard let json = json as? [String: Any] else {
+                                throw SerializationError.bad_value
+                            }
+
+                            return json
+                    })
+                    }
+                }
+            }
+        }
+
+        /// Build a method to decode an adjacently tagged interface.
+        fn decode_adjacent<'f, 'el: 'f, S>(
+            name: &'f Name,
+            tag: &'f str,
+            content: &'f str,
+            sub_types: S,
+            unknown: Option<&'f RpUnknown>,
+        ) -> impl FormatInto<Swift> + 'f
+        where
+            S: 'f + IntoIterator<Item = &'el Spanned<RpSubType>>,
+        {
+            quote_fn! {
+                static func decode(json: Any) throws -> #name {
+                    let json = try decode_value(json as? [String: Any])
+                    let type = try decode_name(json[#(quoted(tag))] as? String, name: #(quoted(tag)))
+
+                    guard let content = json[#(quoted(content))] else {
+                        throw SerializationError.missing(#(quoted(content)))
+                    }
+
+                    switch type {
+                    #(for sub_type in sub_types.into_iter() {
+                        case #(quoted(sub_type.name())):
+                            let v = try #(&sub_type.name).decode(json: content)
+                            return #name.#(&sub_type.ident)(v)
+                    })
+                    default:
+                        #(match unknown {
+                            Some(unknown) => {
+                                return #name.#(&unknown.ident)(json)
+                            }
+                            None => {
+                                throw SerializationError.invalid(type)
+                            }
+                        })
+                    }
+                }
+            }
+        }
+
+        /// Build a method to encode an adjacently tagged interface.
+        fn encode_adjacent<'f, 'el: 'f, S>(
+            tag: &'f str,
+            content: &'f str,
+            sub_types: S,
+            unknown: Option<&'f RpUnknown>,
+        ) -> impl FormatInto<Swift> + 'f
+        where
+            S: 'f + IntoIterator<Item = &'el Spanned<RpSubType>>,
+        {
+            quote_fn! {
+                func encode() throws -> [String: Any] {
+                    switch self {
+                    #(for sub_type in sub_types.into_iter() join (#<push>) {
+                        case .#(&sub_type.ident)(let s):
+                            return [#(quoted(tag)): #(quoted(sub_type.name())), #(quoted(content)): try s.encode()]
+                    })
+                    #(if let Some(unknown) = unknown {
+                        case .#(&unknown.ident)(let json):
+                            guard let json = json as? [String: Any] else {
+                                throw SerializationError.bad_value
+                            }
+
+                            return json
+                    })
+                    }
+                }
+            }
+        }
+
+        /// Build a method to decode an externally tagged interface.
+        fn decode_external<'f, 'el: 'f, S>(
+            name: &'f Name,
+            sub_types: S,
+            unknown: Option<&'f RpUnknown>,
+        ) -> impl FormatInto<Swift> + 'f
+        where
+            S: 'f + IntoIterator<Item = &'el Spanned<RpSubType>>,
+        {
+            quote_fn! {
+                static func decode(json: Any) throws -> #name {
+                    let json = try decode_value(json as? [String: Any])
+
+                    guard json.count == 1, let entry = json.first else {
+                        throw SerializationError.invalid("expected exactly one key")
+                    }
+
+                    switch entry.key {
+                    #(for sub_type in sub_types.into_iter() {
+                        case #(quoted(sub_type.name())):
+                            let v = try #(&sub_type.name).decode(json: entry.value)
+                            return #name.#(&sub_type.ident)(v)
+                    })
+                    default:
+                        #(match unknown {
+                            Some(unknown) => {
+                                return #name.#(&unknown.ident)(json)
+                            }
+                            None => {
+                                throw SerializationError.invalid(entry.key)
+                            }
+                        })
+                    }
+                }
+            }
+        }
+
+        /// Build a method to encode an externally tagged interface.
+        fn encode_external<'f, 'el: 'f, S>(
+            sub_types: S,
+            unknown: Option<&'f RpUnknown>,
+        ) -> impl FormatInto<Swift> + 'f
+        where
+            S: 'f + IntoIterator<Item = &'el Spanned<RpSubType>>,
+        {
+            quote_fn! {
+                func encode() throws -> [String: Any] {
+                    switch self {
+                    #(for sub_type in sub_types.into_iter() join (#<push>) {
+                        case .#(&sub_type.ident)(let s):
+                            return [#(quoted(sub_type.name())): try s.encode()]
+                    })
+                    #(if let Some(unknown) = unknown {
+                        case .#(&unknown.ident)(let json):
+                            guard let json = json as? [String: Any] else {
+                                throw SerializationError.bad_value
+                            }
+
+                            return json
+                    })
                     }
                 }
             }
@@ -620,6 +839,58 @@ impl codegen::interface_added::Codegen for Codegen {
     }
 }
 
+impl codegen::union_added::Codegen for Codegen {
+    fn generate(&self, e: codegen::union_added::Args<'_>) {
+        let codegen::union_added::Args {
+            container,
+            name,
+            variants,
+            ..
+        } = e;
+
+        container.push(quote! {
+            public extension #name {
+                #(decode(name, variants))
+
+                #(encode(variants))
+            }
+        });
+
+        /// Build a method which tries to decode each alternative in turn.
+        fn decode<'f>(
+            name: &'f Name,
+            variants: &'f [(String, &'f RpUnionVariant)],
+        ) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                static func decode(json: Any) throws -> #name {
+                    let json = try decode_value(json)
+
+                    #(for (ident, v) in variants join (#<line>) {
+                        if let v = try? #(v.ty.decode_value(ItemStr::from(ident.as_str()), quote!(json))) {
+                            return #name.#ident(v)
+                        }
+                    })
+
+                    throw SerializationError.invalid("no alternative of the union matches the value")
+                }
+            }
+        }
+
+        fn encode<'f>(variants: &'f [(String, &'f RpUnionVariant)]) -> impl FormatInto<Swift> + 'f {
+            quote_fn! {
+                func encode() throws -> Any {
+                    switch self {
+                    #(for (ident, v) in variants join (#<push>) {
+                        case .#ident(let v):
+                            return #(v.ty.encode_value(ident, quote!(v)))
+                    })
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl codegen::package_added::Codegen for Codegen {
     fn generate(&self, e: codegen::package_added::Args<'_>) {
         e.files.push((self.utils_package(), self.utils()));
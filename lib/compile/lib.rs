@@ -78,7 +78,10 @@ where
 
     let handle = fs.open_root(manifest.output.as_ref().map(AsRef::as_ref))?;
 
-    let mut session = lang.into_session(package_prefix, reporter, resolver)?;
+    let mut session = lang
+        .into_session(package_prefix, reporter, resolver)?
+        .with_cfg(manifest.cfg())
+        .with_lints(manifest.lints());
 
     match input {
         Input::File(file, package) => {
@@ -3,13 +3,33 @@
 //! These structures are all map-like.
 
 use crate::errors::Result;
-use crate::{Diagnostics, Flavor, RpValue, Span, Spanned, Translate, Translator};
+use crate::{closest, Diagnostics, Flavor, RpValue, Span, Spanned, Translate, Translator};
 use serde::Serialize;
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::mem;
 
+/// An attribute which was not used.
+#[derive(Debug, Clone)]
+pub struct Unused {
+    /// Where the attribute is.
+    pub span: Span,
+    /// Where the name of the attribute is, and a similar name of an attribute which would have
+    /// been used.
+    pub suggestion: Option<(Span, String)>,
+}
+
+impl Unused {
+    /// Build an unused attribute, with a suggestion from the names which would have been used.
+    fn new(span: Span, name: &str, name_span: Span, known: &[String]) -> Self {
+        let suggestion = closest(name, known.iter().map(String::as_str))
+            .map(|known| (name_span, known.to_string()));
+
+        Self { span, suggestion }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(bound = "F::Package: Serialize")]
 pub struct Selection<F>
@@ -20,6 +40,10 @@ where
     words: Vec<Spanned<RpValue<F>>>,
     /// Storing values and their locations.
     values: HashMap<String, (Spanned<String>, Spanned<RpValue<F>>)>,
+    /// Keys of values which have been asked for but are not present, used to suggest
+    /// alternatives to unused values.
+    #[serde(skip)]
+    known: Vec<String>,
 }
 
 impl<F> Selection<F>
@@ -30,16 +54,26 @@ where
         words: Vec<Spanned<RpValue<F>>>,
         values: HashMap<String, (Spanned<String>, Spanned<RpValue<F>>)>,
     ) -> Selection<F> {
-        Selection { words, values }
+        Selection {
+            words,
+            values,
+            known: Vec::new(),
+        }
     }
 
     /// Take the given value, removing it in the process.
     pub fn take<Q: ?Sized>(&mut self, key: &Q) -> Option<Spanned<RpValue<F>>>
     where
         String: Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Hash + Eq + ToString,
     {
-        self.values.remove(key).map(|v| v.1)
+        let value = self.values.remove(key).map(|v| v.1);
+
+        if value.is_none() {
+            self.known.push(key.to_string());
+        }
+
+        value
     }
 
     /// Take the given value, removing it in the process.
@@ -52,12 +86,20 @@ where
         self.words.pop()
     }
 
-    /// Get an iterator over unused positions.
-    pub fn unused(&self) -> impl Iterator<Item = Span> {
-        let mut positions = Vec::new();
-        positions.extend(self.words.iter().map(|v| v.span()));
-        positions.extend(self.values.values().map(|v| v.0.span()));
-        positions.into_iter()
+    /// Get an iterator over unused values.
+    pub fn unused(&self) -> impl Iterator<Item = Unused> {
+        let mut unused = Vec::new();
+
+        unused.extend(self.words.iter().map(|v| Unused {
+            span: v.span(),
+            suggestion: None,
+        }));
+
+        for (key, _) in self.values.values() {
+            unused.push(Unused::new(key.span(), key, key.span(), &self.known));
+        }
+
+        unused.into_iter()
     }
 }
 
@@ -72,6 +114,7 @@ where
         Ok(Selection {
             words: self.words.translate(diag, translator)?,
             values: self.values.translate(diag, translator)?,
+            known: self.known,
         })
     }
 }
@@ -84,6 +127,13 @@ where
 {
     words: HashMap<String, Span>,
     selections: HashMap<String, Spanned<Selection<F>>>,
+    /// Where the names of selections are, like `http` in `#[http(...)]`.
+    #[serde(skip)]
+    selection_names: HashMap<String, Span>,
+    /// Names of attributes which have been asked for but are not present, used to suggest
+    /// alternatives to unused attributes.
+    #[serde(skip)]
+    known: Vec<String>,
 }
 
 impl<F> Attributes<F>
@@ -93,34 +143,65 @@ where
     pub fn new(
         words: HashMap<String, Span>,
         selections: HashMap<String, Spanned<Selection<F>>>,
+        selection_names: HashMap<String, Span>,
     ) -> Attributes<F> {
-        Attributes { words, selections }
+        Attributes {
+            words,
+            selections,
+            selection_names,
+            known: Vec::new(),
+        }
     }
 
     /// Take the given selection, removing it in the process.
     pub fn take_word<Q: ?Sized>(&mut self, key: &Q) -> bool
     where
         String: Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Hash + Eq + ToString,
     {
-        self.words.remove(key).is_some()
+        let taken = self.words.remove(key).is_some();
+
+        if !taken {
+            self.known.push(key.to_string());
+        }
+
+        taken
     }
 
     /// Take the given selection, removing it in the process.
     pub fn take_selection<Q: ?Sized>(&mut self, key: &Q) -> Option<Spanned<Selection<F>>>
     where
         String: Borrow<Q>,
-        Q: Hash + Eq,
+        Q: Hash + Eq + ToString,
     {
-        self.selections.remove(key)
+        let selection = self.selections.remove(key);
+
+        if selection.is_none() {
+            self.known.push(key.to_string());
+        }
+
+        selection
     }
 
-    /// Get an iterator over unused positions.
-    pub fn unused(&self) -> impl Iterator<Item = Span> {
-        let mut positions = Vec::new();
-        positions.extend(self.words.values());
-        positions.extend(self.selections.values().map(Spanned::span));
-        positions.into_iter()
+    /// Get an iterator over unused attributes.
+    pub fn unused(&self) -> impl Iterator<Item = Unused> {
+        let mut unused = Vec::new();
+
+        for (word, span) in &self.words {
+            unused.push(Unused::new(*span, word, *span, &self.known));
+        }
+
+        for (key, selection) in &self.selections {
+            let name_span = self
+                .selection_names
+                .get(key)
+                .cloned()
+                .unwrap_or_else(|| selection.span());
+
+            unused.push(Unused::new(selection.span(), key, name_span, &self.known));
+        }
+
+        unused.into_iter()
     }
 }
 
@@ -136,6 +217,8 @@ where
         Ok(Attributes {
             words: self.words,
             selections: self.selections.translate(diag, translator)?,
+            selection_names: self.selection_names,
+            known: self.known,
         })
     }
 }
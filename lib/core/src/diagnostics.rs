@@ -16,6 +16,10 @@ pub enum SymbolKind {
     Enum,
     #[serde(rename = "service")]
     Service,
+    #[serde(rename = "const")]
+    Const,
+    #[serde(rename = "alias")]
+    Alias,
 }
 
 /// A single diagnostic emitted by the compiler.
@@ -23,14 +27,24 @@ pub enum SymbolKind {
 pub enum Diagnostic {
     /// A positional error.
     Error { span: Span, message: String },
+    /// A positional warning, which does not prevent compilation.
+    Warning { span: Span, message: String },
     /// A positional information string.
     Info { span: Span, message: String },
+    /// A suggested replacement for the code at the given span, like a correctly spelled name.
+    Suggestion {
+        span: Span,
+        message: String,
+        replacement: String,
+    },
     /// A symbol that was encountered, and its location.
     Symbol {
         kind: SymbolKind,
         span: Span,
         name: RpName,
     },
+    /// A region which was excluded through `#[cfg(...)]`.
+    Inactive { span: Span },
 }
 
 /// A collection of diagnostics emitted by the compiler.
@@ -65,6 +79,21 @@ impl Diagnostics {
         });
     }
 
+    /// Check if diagnostics contains any warnings.
+    pub fn has_warnings(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(*item, Diagnostic::Warning { .. }))
+    }
+
+    /// Report a warning.
+    pub fn warn<S: Into<Span>, W: fmt::Display>(&mut self, span: S, warning: W) {
+        self.items.push(Diagnostic::Warning {
+            span: span.into(),
+            message: warning.to_string(),
+        });
+    }
+
     /// Report information.
     pub fn info<S: Into<Span>, I: fmt::Display>(&mut self, span: S, info: I) {
         self.items.push(Diagnostic::Info {
@@ -73,6 +102,20 @@ impl Diagnostics {
         });
     }
 
+    /// Suggest a replacement for the code at the given span.
+    pub fn suggest<S: Into<Span>, M: fmt::Display, R: Into<String>>(
+        &mut self,
+        span: S,
+        message: M,
+        replacement: R,
+    ) {
+        self.items.push(Diagnostic::Suggestion {
+            span: span.into(),
+            message: message.to_string(),
+            replacement: replacement.into(),
+        });
+    }
+
     /// Register a symbol.
     pub fn symbol<P: Into<Span>>(&mut self, kind: SymbolKind, span: P, name: &RpName) {
         self.items.push(Diagnostic::Symbol {
@@ -82,6 +125,18 @@ impl Diagnostics {
         });
     }
 
+    /// Check if diagnostics contains any inactive regions.
+    pub fn has_inactive(&self) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(*item, Diagnostic::Inactive { .. }))
+    }
+
+    /// Mark a region as inactive.
+    pub fn inactive<S: Into<Span>>(&mut self, span: S) {
+        self.items.push(Diagnostic::Inactive { span: span.into() });
+    }
+
     /// Iterate over all reporter items.
     pub fn items(&self) -> impl Iterator<Item = &Diagnostic> {
         self.items.iter()
@@ -97,6 +152,12 @@ pub enum SourceDiagnostic {
         span: Span,
         message: String,
     },
+    /// A positional warning, which does not prevent compilation.
+    Warning {
+        source: Source,
+        span: Span,
+        message: String,
+    },
     /// A positional information string.
     Info {
         source: Source,
@@ -143,6 +204,17 @@ impl SourceDiagnostics {
         ));
     }
 
+    /// Report a warning.
+    pub fn warn<S: Into<Span>, W: fmt::Display>(&mut self, source: &Source, span: S, warning: W) {
+        self.items.push((
+            source.clone(),
+            Diagnostic::Warning {
+                span: span.into(),
+                message: warning.to_string(),
+            },
+        ));
+    }
+
     /// Report information.
     pub fn info<S: Into<Span>, I: fmt::Display>(&mut self, source: &Source, span: S, info: I) {
         self.items.push((
@@ -1,11 +1,16 @@
 //! Trait used to handle imports.
 
 use crate::errors::Result;
-use crate::{RpRequiredPackage, RpVersionedPackage};
+use crate::{CoreFlavor, RpDecl, RpName, RpRequiredPackage, RpVersionedPackage};
 
 pub trait Import {
     /// Perform the import.
     fn import(&mut self, _: &RpRequiredPackage) -> Result<Option<RpVersionedPackage>>;
+
+    /// Lookup a declaration in a package which has already been imported.
+    fn lookup_decl(&self, _: &RpName<CoreFlavor>) -> Option<&RpDecl<CoreFlavor>> {
+        None
+    }
 }
 
 /// no-op implementation.
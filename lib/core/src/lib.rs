@@ -20,14 +20,18 @@ mod options;
 pub mod regex;
 mod reporter;
 mod resolver;
+mod rp_alias;
 mod rp_channel;
 mod rp_code;
+mod rp_const;
 mod rp_decl;
+mod rp_deprecated;
 mod rp_endpoint;
 mod rp_enum;
 mod rp_field;
 mod rp_file;
 mod rp_interface;
+mod rp_literal;
 mod rp_name;
 mod rp_number;
 mod rp_package;
@@ -39,8 +43,11 @@ mod rp_service;
 mod rp_tuple;
 mod rp_type;
 mod rp_type_model;
+mod rp_union;
+mod rp_unknown;
 mod rp_value;
 mod rp_versioned_package;
+mod similar;
 mod source;
 mod span;
 mod spanned;
@@ -48,7 +55,7 @@ pub mod translator;
 pub mod utils;
 mod with_span;
 
-pub use self::attributes::{Attributes, Selection};
+pub use self::attributes::{Attributes, Selection, Unused};
 pub use self::diagnostics::{
     Diagnostic, Diagnostics, SourceDiagnostic, SourceDiagnostics, SymbolKind,
 };
@@ -60,18 +67,25 @@ pub use self::option_entry::OptionEntry;
 pub use self::options::Options;
 pub use self::reporter::{Reported, Reporter};
 pub use self::resolver::{EmptyResolver, Resolved, ResolvedByPrefix, Resolver};
+pub use self::rp_alias::RpAliasBody;
 pub use self::rp_channel::RpChannel;
 pub use self::rp_code::{RpCode, RpContext};
+pub use self::rp_const::RpConstBody;
 pub use self::rp_decl::{RpDecl, RpNamed};
+pub use self::rp_deprecated::RpDeprecated;
 pub use self::rp_endpoint::{
-    RpAccept, RpEndpoint, RpEndpointArgument, RpEndpointHttp, RpEndpointHttp1, RpHttpMethod,
+    RpAccept, RpEndpoint, RpEndpointArgument, RpEndpointHttp, RpEndpointHttp1, RpHttpError,
+    RpHttpMethod, RpHttpParameter, RpHttpStream,
 };
 pub use self::rp_enum::{
     RpEnumBody, RpEnumType, RpVariant, RpVariantRef, RpVariantValue, RpVariants,
 };
 pub use self::rp_field::RpField;
 pub use self::rp_file::{RpEnabledFeature, RpFile};
-pub use self::rp_interface::{RpInterfaceBody, RpSubType, RpSubTypeStrategy, DEFAULT_TAG};
+pub use self::rp_interface::{
+    RpInterfaceBody, RpSubType, RpSubTypeStrategy, DEFAULT_CONTENT, DEFAULT_TAG,
+};
+pub use self::rp_literal::RpLiteral;
 pub use self::rp_name::RpName;
 pub use self::rp_number::RpNumber;
 pub use self::rp_package::RpPackage;
@@ -79,14 +93,18 @@ pub use self::rp_package_format::RpPackageFormat;
 pub use self::rp_path_spec::{RpPathPart, RpPathSpec, RpPathStep};
 pub use self::rp_reg::RpReg;
 pub use self::rp_required_package::RpRequiredPackage;
-pub use self::rp_service::{RpServiceBody, RpServiceBodyHttp};
+pub use self::rp_service::{RpServiceBody, RpServiceBodyHttp, RpServiceBodyWebSocket};
 pub use self::rp_tuple::RpTupleBody;
 pub use self::rp_type::{
-    RpNumberKind, RpNumberType, RpNumberValidate, RpStringType, RpStringValidate, RpType,
+    RpBytesEncoding, RpBytesType, RpCheck, RpNumberEncoding, RpNumberKind, RpNumberType,
+    RpNumberValidate, RpStringType, RpStringValidate, RpType, RpValidate,
 };
 pub use self::rp_type_model::RpTypeBody;
+pub use self::rp_union::{RpUnionBody, RpUnionKind, RpUnionVariant};
+pub use self::rp_unknown::RpUnknown;
 pub use self::rp_value::RpValue;
 pub use self::rp_versioned_package::RpVersionedPackage;
+pub use self::similar::closest;
 pub use self::source::Source;
 pub use self::span::Span;
 pub use self::spanned::Spanned;
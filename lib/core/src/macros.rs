@@ -2,13 +2,15 @@
 macro_rules! decl_body {
     (pub struct $name:ident<$f:ident> { $($rest:tt)* }) => {
         #[derive(Debug, Clone, serde::Serialize)]
-        #[serde(bound = "F: serde::Serialize, F::Field: serde::Serialize, F::Endpoint: serde::Serialize, F::Package: serde::Serialize, F::Name: serde::Serialize, F::EnumType: serde::Serialize")]
+        #[serde(bound = "F: serde::Serialize, F::Type: serde::Serialize, F::Field: serde::Serialize, F::Endpoint: serde::Serialize, F::Package: serde::Serialize, F::Name: serde::Serialize, F::EnumType: serde::Serialize")]
         pub struct $name<$f> where $f: $crate::Flavor {
             pub name: $f::Name,
             pub ident: String,
             pub comment: Vec<String>,
             pub decls: Vec<$crate::RpDecl<$f>>,
             pub decl_idents: ::linked_hash_map::LinkedHashMap<String, usize>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub deprecated: Option<$crate::RpDeprecated>,
             $($rest)*
         }
     };
@@ -18,9 +20,12 @@ macro_rules! decl_body {
 macro_rules! decl_flavor {
     ($vis:vis $flavor:ident) => {
         $vis type RpAccept = $crate::RpAccept;
+        $vis type RpAliasBody<F = $flavor> = $crate::RpAliasBody<F>;
         $vis type RpCode = $crate::RpCode;
+        $vis type RpConstBody<F = $flavor> = $crate::RpConstBody<F>;
         $vis type RpContext = $crate::RpContext;
         $vis type RpDecl<F = $flavor> = $crate::RpDecl<F>;
+        $vis type RpDeprecated = $crate::RpDeprecated;
         $vis type RpEndpoint<F = $flavor> = $crate::RpEndpoint<F>;
         $vis type RpEndpointArgument<F = $flavor> = $crate::RpEndpointArgument<F>;
         $vis type RpEndpointHttp<F = $flavor> = $crate::RpEndpointHttp<F>;
@@ -28,7 +33,10 @@ macro_rules! decl_flavor {
         $vis type RpEnumBody<F = $flavor> = $crate::RpEnumBody<F>;
         $vis type RpField<F = $flavor> = $crate::RpField<F>;
         $vis type RpFile<F = $flavor> = $crate::RpFile<F>;
+        $vis type RpHttpError<F = $flavor> = $crate::RpHttpError<F>;
         $vis type RpHttpMethod = $crate::RpHttpMethod;
+        $vis type RpHttpParameter<F = $flavor> = $crate::RpHttpParameter<F>;
+        $vis type RpHttpStream = $crate::RpHttpStream;
         $vis type RpInterfaceBody<F = $flavor> = $crate::RpInterfaceBody<F>;
         $vis type RpPathPart<F = $flavor> = $crate::RpPathPart<F>;
         $vis type RpPathSpec<F = $flavor> = $crate::RpPathSpec<F>;
@@ -38,14 +46,20 @@ macro_rules! decl_flavor {
         $vis type RpSubType<F = $flavor> = $crate::RpSubType<F>;
         $vis type RpTupleBody<F = $flavor> = $crate::RpTupleBody<F>;
         $vis type RpTypeBody<F = $flavor> = $crate::RpTypeBody<F>;
+        $vis type RpUnionBody<F = $flavor> = $crate::RpUnionBody<F>;
+        $vis type RpUnionKind = $crate::RpUnionKind;
+        $vis type RpUnionVariant<F = $flavor> = $crate::RpUnionVariant<F>;
+        $vis type RpUnknown<F = $flavor> = $crate::RpUnknown<F>;
         $vis type RpChannel<F = $flavor> = $crate::RpChannel<F>;
         $vis type RpEnumType = $crate::RpEnumType;
+        $vis type RpLiteral = $crate::RpLiteral;
         $vis type RpName<F = $flavor> = $crate::RpName<F>;
         $vis type RpNumber = $crate::RpNumber;
         $vis type RpPackage = $crate::RpPackage;
         $vis type RpRequiredPackage = $crate::RpRequiredPackage;
         $vis type RpServiceBody<F = $flavor> = $crate::RpServiceBody<F>;
         $vis type RpServiceBodyHttp = $crate::RpServiceBodyHttp;
+        $vis type RpServiceBodyWebSocket = $crate::RpServiceBodyWebSocket;
         $vis type RpSubTypeStrategy = $crate::RpSubTypeStrategy;
         $vis type RpType<F = $flavor> = $crate::RpType<F>;
         $vis type RpValue<F = $flavor> = $crate::RpValue<F>;
@@ -139,12 +153,38 @@ macro_rules! translator_defaults {
             Ok(RpType::DateTime)
         }
 
+        fn translate_date(&self) -> Result<Option<RpType<$slf::Target>>> {
+            Ok(Some(RpType::Date))
+        }
+
+        fn translate_time(&self) -> Result<Option<RpType<$slf::Target>>> {
+            Ok(Some(RpType::Time))
+        }
+
+        fn translate_duration(&self) -> Result<Option<RpType<$slf::Target>>> {
+            Ok(Some(RpType::Duration))
+        }
+
+        fn translate_uuid(&self) -> Result<Option<RpType<$slf::Target>>> {
+            Ok(Some(RpType::Uuid))
+        }
+
+        fn translate_decimal(&self) -> Result<Option<RpType<$slf::Target>>> {
+            Ok(Some(RpType::Decimal))
+        }
+
         fn translate_array(&self, inner: RpType<$slf::Target>) -> Result<RpType<$slf::Target>> {
             Ok(RpType::Array {
                 inner: Box::new(inner),
             })
         }
 
+        fn translate_set(&self, inner: RpType<$slf::Target>) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Set {
+                inner: Box::new(inner),
+            })
+        }
+
         fn translate_map(
             &self,
             key: RpType<$slf::Target>,
@@ -156,12 +196,19 @@ macro_rules! translator_defaults {
             })
         }
 
+        fn translate_union(
+            &self,
+            alternatives: Vec<RpType<$slf::Target>>,
+        ) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Union { alternatives })
+        }
+
         fn translate_any(&self) -> Result<RpType<$slf::Target>> {
             Ok(RpType::Any)
         }
 
-        fn translate_bytes(&self) -> Result<RpType<$slf::Target>> {
-            Ok(RpType::Bytes)
+        fn translate_bytes(&self, bytes: RpBytesType) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Bytes(bytes))
         }
 
         fn translate_name(
@@ -169,8 +216,13 @@ macro_rules! translator_defaults {
             _from: &<$slf::Target as Flavor>::Package,
             _reg: RpReg,
             name: Spanned<RpName<$slf::Target>>,
+            arguments: Vec<RpType<$slf::Target>>,
         ) -> Result<<$slf::Target as Flavor>::Type> {
-            Ok(RpType::Name { name })
+            Ok(RpType::Name { name, arguments })
+        }
+
+        fn translate_parameter(&self, ident: String) -> Result<RpType<$slf::Target>> {
+            Ok(RpType::Parameter { ident })
         }
 
         $crate::translator_defaults!(@internal $slf $($rest)*);
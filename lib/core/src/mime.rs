@@ -8,6 +8,13 @@ use std::str::FromStr;
 #[derive(Debug, Clone)]
 pub struct Mime(extern_mime::Mime);
 
+impl Mime {
+    /// The type and subtype of the mime, like `text/plain`, without any parameters.
+    pub fn essence(&self) -> &str {
+        self.0.essence_str()
+    }
+}
+
 impl serde::Serialize for Mime {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
     where
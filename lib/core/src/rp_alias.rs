@@ -0,0 +1,41 @@
+//! Model for type aliases.
+
+use crate::errors::Result;
+use crate::{Diagnostics, RpReg, Translate, Translator};
+
+decl_body!(
+    pub struct RpAliasBody<F> {
+        #[serde(rename = "type")]
+        pub ty: F::Type,
+        /// If the alias should generate a distinct wrapper type, instead of being inlined as the
+        /// type it is an alias for.
+        pub newtype: bool,
+    }
+);
+
+impl<T> Translate<T> for RpAliasBody<T::Source>
+where
+    T: Translator,
+{
+    type Out = RpAliasBody<T::Target>;
+
+    /// Translate into different flavor.
+    fn translate(self, diag: &mut Diagnostics, translator: &T) -> Result<RpAliasBody<T::Target>> {
+        translator.visit(diag, &self.name)?;
+
+        let name = translator.translate_local_name(diag, RpReg::Alias, self.name)?;
+        let decls = self.decls.translate(diag, translator)?;
+        let ty = translator.translate_type(diag, self.ty)?;
+
+        Ok(RpAliasBody {
+            name,
+            ident: self.ident,
+            comment: self.comment,
+            decls,
+            decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
+            ty,
+            newtype: self.newtype,
+        })
+    }
+}
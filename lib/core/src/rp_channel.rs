@@ -30,6 +30,15 @@ where
         }
     }
 
+    /// Get a mutable reference to the type of the channel.
+    pub fn ty_mut(&mut self) -> &mut F::Type {
+        use self::RpChannel::*;
+
+        match *self {
+            Unary { ref mut ty, .. } | Streaming { ref mut ty, .. } => ty,
+        }
+    }
+
     /// Check if channel is streaming.
     pub fn is_streaming(&self) -> bool {
         use self::RpChannel::*;
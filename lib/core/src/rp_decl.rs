@@ -2,8 +2,9 @@
 
 use crate::errors::Result;
 use crate::{
-    Diagnostics, Flavor, RpEnumBody, RpInterfaceBody, RpReg, RpServiceBody, RpSubType, RpTupleBody,
-    RpTypeBody, RpVariantRef, Span, Spanned, Translate, Translator,
+    Diagnostics, Flavor, RpAliasBody, RpConstBody, RpDeprecated, RpEnumBody, RpInterfaceBody,
+    RpReg, RpServiceBody, RpSubType, RpTupleBody, RpTypeBody, RpUnionBody, RpVariantRef, Span,
+    Spanned, Translate, Translator,
 };
 use serde::Serialize;
 use std::fmt;
@@ -20,6 +21,9 @@ where
     Enum(&'a Spanned<RpEnumBody<F>>),
     EnumVariant(RpVariantRef<'a, F>),
     Service(&'a Spanned<RpServiceBody<F>>),
+    Const(&'a Spanned<RpConstBody<F>>),
+    Alias(&'a Spanned<RpAliasBody<F>>),
+    Union(&'a Spanned<RpUnionBody<F>>),
 }
 
 impl<'a, F> RpNamed<'a, F>
@@ -31,13 +35,46 @@ where
         use self::RpNamed::*;
 
         match *self {
-            Type(ref body) => &body.name,
-            Tuple(ref tuple) => &tuple.name,
-            Interface(ref interface) => &interface.name,
-            SubType(ref sub_type) => &sub_type.name,
-            Enum(ref en) => &en.name,
-            EnumVariant(ref variant) => variant.name,
-            Service(ref service) => &service.name,
+            Type(body) => &body.name,
+            Tuple(tuple) => &tuple.name,
+            Interface(interface) => &interface.name,
+            SubType(sub_type) => &sub_type.name,
+            Enum(en) => &en.name,
+            EnumVariant(variant) => variant.name,
+            Service(service) => &service.name,
+            Const(body) => &body.name,
+            Alias(body) => &body.name,
+            Union(body) => &body.name,
+        }
+    }
+
+    /// Get the deprecation information of the named element, if deprecated.
+    pub fn deprecated(&self) -> Option<&'a RpDeprecated> {
+        use self::RpNamed::*;
+
+        match *self {
+            Type(body) => body.deprecated.as_ref(),
+            Tuple(tuple) => tuple.deprecated.as_ref(),
+            Interface(interface) => interface.deprecated.as_ref(),
+            SubType(sub_type) => sub_type.deprecated.as_ref(),
+            Enum(en) => en.deprecated.as_ref(),
+            EnumVariant(variant) => variant.deprecated,
+            Service(service) => service.deprecated.as_ref(),
+            Const(body) => body.deprecated.as_ref(),
+            Alias(body) => body.deprecated.as_ref(),
+            Union(body) => body.deprecated.as_ref(),
+        }
+    }
+
+    /// Get the type parameters of the named element, empty unless it is generic.
+    pub fn type_parameters(&self) -> &'a [String] {
+        use self::RpNamed::*;
+
+        match *self {
+            Type(body) => &body.type_parameters,
+            Interface(interface) => &interface.type_parameters,
+            SubType(sub_type) => &sub_type.type_parameters,
+            _ => &[],
         }
     }
 
@@ -46,21 +83,24 @@ where
         use self::RpNamed::*;
 
         match *self {
-            Type(ref body) => body.span(),
-            Tuple(ref tuple) => tuple.span(),
-            Interface(ref interface) => interface.span(),
-            SubType(ref sub_type) => sub_type.span(),
-            Enum(ref en) => en.span(),
-            EnumVariant(ref variant) => variant.span,
-            Service(ref service) => service.span(),
+            Type(body) => body.span(),
+            Tuple(tuple) => tuple.span(),
+            Interface(interface) => interface.span(),
+            SubType(sub_type) => sub_type.span(),
+            Enum(en) => en.span(),
+            EnumVariant(variant) => variant.span,
+            Service(service) => service.span(),
+            Const(body) => body.span(),
+            Alias(body) => body.span(),
+            Union(body) => body.span(),
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(
-    bound = "F: Serialize, F::Field: Serialize, F::Endpoint: Serialize, F::Package: Serialize, \
-             F::Name: Serialize, F::EnumType: Serialize"
+    bound = "F: Serialize, F::Type: Serialize, F::Field: Serialize, F::Endpoint: Serialize, \
+             F::Package: Serialize, F::Name: Serialize, F::EnumType: Serialize"
 )]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RpDecl<F>
@@ -72,6 +112,9 @@ where
     Interface(Spanned<RpInterfaceBody<F>>),
     Enum(Spanned<RpEnumBody<F>>),
     Service(Spanned<RpServiceBody<F>>),
+    Const(Spanned<RpConstBody<F>>),
+    Alias(Spanned<RpAliasBody<F>>),
+    Union(Spanned<RpUnionBody<F>>),
 }
 
 impl<F> RpDecl<F>
@@ -92,6 +135,32 @@ where
             Enum(ref body) => body.decls.iter().collect::<Vec<_>>(),
             Tuple(ref body) => body.decls.iter().collect::<Vec<_>>(),
             Service(ref body) => body.decls.iter().collect::<Vec<_>>(),
+            Const(ref body) => body.decls.iter().collect::<Vec<_>>(),
+            Alias(ref body) => body.decls.iter().collect::<Vec<_>>(),
+            Union(ref body) => body.decls.iter().collect::<Vec<_>>(),
+        };
+
+        decls.into_iter()
+    }
+
+    /// Access all declarations mutably as an iterator.
+    pub fn decls_mut(&mut self) -> impl Iterator<Item = &mut RpDecl<F>> {
+        use self::RpDecl::*;
+
+        let decls = match *self {
+            Type(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
+            Interface(ref mut body) => {
+                let body = &mut **body;
+                let mut decls = body.decls.iter_mut().collect::<Vec<_>>();
+                decls.extend(body.sub_types.iter_mut().flat_map(|s| s.decls.iter_mut()));
+                decls
+            }
+            Enum(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
+            Tuple(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
+            Service(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
+            Const(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
+            Alias(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
+            Union(ref mut body) => body.decls.iter_mut().collect::<Vec<_>>(),
         };
 
         decls.into_iter()
@@ -107,6 +176,9 @@ where
             Enum(ref body) => body.ident.as_str(),
             Tuple(ref body) => body.ident.as_str(),
             Service(ref body) => body.ident.as_str(),
+            Const(ref body) => body.ident.as_str(),
+            Alias(ref body) => body.ident.as_str(),
+            Union(ref body) => body.ident.as_str(),
         }
     }
 
@@ -120,6 +192,9 @@ where
             Enum(ref body) => &body.name,
             Tuple(ref body) => &body.name,
             Service(ref body) => &body.name,
+            Const(ref body) => &body.name,
+            Alias(ref body) => &body.name,
+            Union(ref body) => &body.name,
         }
     }
 
@@ -133,6 +208,25 @@ where
             Enum(ref body) => &body.comment,
             Tuple(ref body) => &body.comment,
             Service(ref body) => &body.comment,
+            Const(ref body) => &body.comment,
+            Alias(ref body) => &body.comment,
+            Union(ref body) => &body.comment,
+        }
+    }
+
+    /// Get the deprecation information of the declaration, if deprecated.
+    pub fn deprecated(&self) -> Option<&RpDeprecated> {
+        use self::RpDecl::*;
+
+        match *self {
+            Type(ref body) => body.deprecated.as_ref(),
+            Interface(ref body) => body.deprecated.as_ref(),
+            Enum(ref body) => body.deprecated.as_ref(),
+            Tuple(ref body) => body.deprecated.as_ref(),
+            Service(ref body) => body.deprecated.as_ref(),
+            Const(ref body) => body.deprecated.as_ref(),
+            Alias(ref body) => body.deprecated.as_ref(),
+            Union(ref body) => body.deprecated.as_ref(),
         }
     }
 
@@ -166,6 +260,15 @@ where
             Service(ref service) => {
                 out.push((&service.name, service.span(), RpReg::Service));
             }
+            Const(ref body) => {
+                out.push((&body.name, body.span(), RpReg::Const));
+            }
+            Alias(ref body) => {
+                out.push((&body.name, body.span(), RpReg::Alias));
+            }
+            Union(ref body) => {
+                out.push((&body.name, body.span(), RpReg::Union));
+            }
         }
 
         out.extend(self.decls().flat_map(|d| d.to_reg()));
@@ -202,6 +305,15 @@ where
             Service(ref service) => {
                 out.push(RpNamed::Service(service));
             }
+            Const(ref body) => {
+                out.push(RpNamed::Const(body));
+            }
+            Alias(ref body) => {
+                out.push(RpNamed::Alias(body));
+            }
+            Union(ref body) => {
+                out.push(RpNamed::Union(body));
+            }
         }
 
         out.extend(self.decls().flat_map(|d| d.to_named()));
@@ -218,6 +330,9 @@ where
             Enum(_) => "enum",
             Tuple(_) => "tuple",
             Service(_) => "service",
+            Const(_) => "const",
+            Alias(_) => "alias",
+            Union(_) => "union",
         }
     }
 
@@ -231,6 +346,9 @@ where
             Enum(ref body) => body.span(),
             Tuple(ref body) => body.span(),
             Service(ref body) => body.span(),
+            Const(ref body) => body.span(),
+            Alias(ref body) => body.span(),
+            Union(ref body) => body.span(),
         }
     }
 
@@ -244,6 +362,9 @@ where
             Enum(ref body) => (&body.decls, &body.decl_idents),
             Tuple(ref body) => (&body.decls, &body.decl_idents),
             Service(ref body) => (&body.decls, &body.decl_idents),
+            Const(ref body) => (&body.decls, &body.decl_idents),
+            Alias(ref body) => (&body.decls, &body.decl_idents),
+            Union(ref body) => (&body.decls, &body.decl_idents),
         };
 
         match decl_idents.get(ident) {
@@ -269,6 +390,9 @@ where
             Interface(body) => Interface(body.translate(diag, translator)?),
             Enum(body) => Enum(body.translate(diag, translator)?),
             Service(body) => Service(body.translate(diag, translator)?),
+            Const(body) => Const(body.translate(diag, translator)?),
+            Alias(body) => Alias(body.translate(diag, translator)?),
+            Union(body) => Union(body.translate(diag, translator)?),
         };
 
         Ok(out)
@@ -289,6 +413,9 @@ where
             Enum(ref body) => write!(f, "enum {}", body.name),
             Tuple(ref body) => write!(f, "tuple {}", body.name),
             Service(ref body) => write!(f, "service {}", body.name),
+            Const(ref body) => write!(f, "const {}", body.name),
+            Alias(ref body) => write!(f, "alias {}", body.name),
+            Union(ref body) => write!(f, "union {}", body.name),
         }
     }
 }
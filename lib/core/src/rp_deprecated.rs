@@ -0,0 +1,26 @@
+//! Deprecation information for declarations and their members.
+
+use serde::Serialize;
+use std::fmt;
+
+/// Information attached through the `#[deprecated(..)]` attribute.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RpDeprecated {
+    /// Version since which the item is deprecated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Note describing why the item is deprecated, or what to use instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl fmt::Display for RpDeprecated {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match (self.since.as_ref(), self.note.as_ref()) {
+            (Some(since), Some(note)) => write!(fmt, "deprecated since {}: {}", since, note),
+            (Some(since), None) => write!(fmt, "deprecated since {}", since),
+            (None, Some(note)) => write!(fmt, "deprecated: {}", note),
+            (None, None) => write!(fmt, "deprecated"),
+        }
+    }
+}
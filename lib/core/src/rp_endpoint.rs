@@ -2,7 +2,8 @@
 
 use crate::errors::Result;
 use crate::{
-    Attributes, Diagnostics, Flavor, RpChannel, RpPathSpec, Spanned, Translate, Translator,
+    Attributes, Diagnostics, Flavor, Mime, RpChannel, RpDeprecated, RpPathSpec, Spanned, Translate,
+    Translator,
 };
 use serde::Serialize;
 use std::default;
@@ -36,12 +37,60 @@ impl RpHttpMethod {
     }
 }
 
+/// Media type of a request or response body.
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 pub enum RpAccept {
+    /// `application/json`
     #[serde(rename = "json")]
     Json,
+    /// `text/plain`
     #[serde(rename = "text")]
     Text,
+    /// `application/octet-stream`, for `bytes` bodies.
+    #[serde(rename = "bytes")]
+    Bytes,
+    /// `application/x-www-form-urlencoded`, built from the fields of a `type`.
+    #[serde(rename = "form")]
+    Form,
+    /// `multipart/form-data`, built from the fields of a `type`.
+    #[serde(rename = "multipart")]
+    Multipart,
+    /// `application/x-ndjson`, one JSON document per line for each element of an array.
+    #[serde(rename = "ndjson")]
+    NdJson,
+}
+
+impl RpAccept {
+    /// Find the supported media type matching the given mime, ignoring any parameters.
+    pub fn from_mime(mime: &Mime) -> Option<Self> {
+        use self::RpAccept::*;
+
+        let accept = match mime.essence() {
+            "application/json" => Json,
+            "text/plain" => Text,
+            "application/octet-stream" => Bytes,
+            "application/x-www-form-urlencoded" => Form,
+            "multipart/form-data" => Multipart,
+            "application/x-ndjson" => NdJson,
+            _ => return None,
+        };
+
+        Some(accept)
+    }
+
+    /// The media type as it is sent in `Content-Type` and `Accept` headers.
+    pub fn as_str(&self) -> &'static str {
+        use self::RpAccept::*;
+
+        match *self {
+            Json => "application/json",
+            Text => "text/plain",
+            Bytes => "application/octet-stream",
+            Form => "application/x-www-form-urlencoded",
+            Multipart => "multipart/form-data",
+            NdJson => "application/x-ndjson",
+        }
+    }
 }
 
 impl default::Default for RpAccept {
@@ -50,6 +99,27 @@ impl default::Default for RpAccept {
     }
 }
 
+/// Framing of the elements of a streaming response.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum RpHttpStream {
+    /// Server-Sent Events, with one JSON document in the `data` of each event.
+    #[serde(rename = "sse")]
+    Sse,
+    /// Newline-delimited JSON, with one JSON document per line.
+    #[serde(rename = "ndjson")]
+    NdJson,
+}
+
+impl RpHttpStream {
+    /// The media type of the streaming response.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            RpHttpStream::Sse => "text/event-stream",
+            RpHttpStream::NdJson => "application/x-ndjson",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(bound = "F::Type: Serialize")]
 pub struct RpEndpointHttp<F>
@@ -62,11 +132,28 @@ where
     /// Argument that is the body of the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<RpEndpointArgument<F>>,
+    /// Arguments that are sent as query parameters.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<RpHttpParameter<F>>,
+    /// Arguments that are sent as headers.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<RpHttpParameter<F>>,
     /// HTTP method.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<RpHttpMethod>,
     /// Accepted media types.
     pub accept: RpAccept,
+    /// Media type of the request body.
+    pub content_type: RpAccept,
+    /// Framing of a streaming response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<RpHttpStream>,
+    /// Status code of a successful response, if not the default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Declared error responses, ordered by status code.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<Spanned<RpHttpError<F>>>,
 }
 
 impl<F> default::Default for RpEndpointHttp<F>
@@ -77,8 +164,14 @@ where
         Self {
             path: Default::default(),
             body: Default::default(),
+            query: Default::default(),
+            headers: Default::default(),
             method: Default::default(),
             accept: Default::default(),
+            content_type: Default::default(),
+            stream: Default::default(),
+            status: Default::default(),
+            errors: Default::default(),
         }
     }
 }
@@ -98,8 +191,84 @@ where
         Ok(RpEndpointHttp {
             path: self.path.translate(diag, translator)?,
             body: self.body.translate(diag, translator)?,
+            query: self.query.translate(diag, translator)?,
+            headers: self.headers.translate(diag, translator)?,
             method: self.method,
             accept: self.accept,
+            content_type: self.content_type,
+            stream: self.stream,
+            status: self.status,
+            errors: self.errors.translate(diag, translator)?,
+        })
+    }
+}
+
+impl<F> RpEndpointHttp<F>
+where
+    F: Flavor,
+{
+    /// Iterate over all arguments which are sent as query parameters or headers.
+    pub fn parameters(&self) -> impl Iterator<Item = &RpHttpParameter<F>> {
+        self.query.iter().chain(self.headers.iter())
+    }
+}
+
+/// An error response which an endpoint declares for a given status code.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "F::Type: Serialize")]
+pub struct RpHttpError<F>
+where
+    F: Flavor,
+{
+    /// Status code of the error response.
+    pub status: u16,
+    /// Type of the body of the error response.
+    pub ty: F::Type,
+}
+
+impl<T> Translate<T> for RpHttpError<T::Source>
+where
+    T: Translator,
+{
+    type Out = RpHttpError<T::Target>;
+
+    /// Translate into different flavor.
+    fn translate(self, diag: &mut Diagnostics, translator: &T) -> Result<RpHttpError<T::Target>> {
+        Ok(RpHttpError {
+            status: self.status,
+            ty: translator.translate_type(diag, self.ty)?,
+        })
+    }
+}
+
+/// An endpoint argument which is sent as a query parameter or a header.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "F::Type: Serialize")]
+pub struct RpHttpParameter<F>
+where
+    F: Flavor,
+{
+    /// Name of the query parameter or header, as it is sent over the wire.
+    pub name: Spanned<String>,
+    /// Argument bound to the parameter.
+    pub argument: RpEndpointArgument<F>,
+}
+
+impl<T> Translate<T> for RpHttpParameter<T::Source>
+where
+    T: Translator,
+{
+    type Out = RpHttpParameter<T::Target>;
+
+    /// Translate into different flavor.
+    fn translate(
+        self,
+        diag: &mut Diagnostics,
+        translator: &T,
+    ) -> Result<RpHttpParameter<T::Target>> {
+        Ok(RpHttpParameter {
+            name: self.name,
+            argument: self.argument.translate(diag, translator)?,
         })
     }
 }
@@ -115,6 +284,8 @@ where
     pub ident: Rc<Spanned<String>>,
     /// Safe identifier for the argument.
     pub safe_ident: Rc<Option<String>>,
+    /// If the argument is required, optional arguments may be omitted.
+    pub required: bool,
     /// Channel of the argument.
     pub channel: Spanned<RpChannel<F>>,
 }
@@ -134,6 +305,7 @@ where
         Ok(RpEndpointArgument {
             ident: self.ident,
             safe_ident: self.safe_ident,
+            required: self.required,
             channel: self.channel.translate(diag, translator)?,
         })
     }
@@ -154,6 +326,11 @@ where
             .map(|s| s.as_str())
             .unwrap_or_else(|| self.ident.as_str())
     }
+
+    /// Check if the argument is optional.
+    pub fn is_optional(&self) -> bool {
+        !self.required
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -172,6 +349,9 @@ where
     pub name: Option<String>,
     /// Comments for documentation.
     pub comment: Vec<String>,
+    /// Deprecation information of the endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
     /// Attributes associated with the endpoint.
     pub attributes: Attributes<F>,
     /// Arguments that this endpoint accepts.
@@ -222,6 +402,22 @@ where
     pub fn has_http_support(&self) -> bool {
         self.http.path.is_some()
     }
+
+    /// If the endpoint is bi-directional, with a streaming request as its only argument and a
+    /// streaming response.
+    pub fn is_bidirectional(&self) -> bool {
+        let request = match self.request.as_ref() {
+            Some(request) => request,
+            None => return false,
+        };
+
+        let response = match self.response.as_ref() {
+            Some(response) => response,
+            None => return false,
+        };
+
+        self.arguments.len() == 1 && request.channel.is_streaming() && response.is_streaming()
+    }
 }
 
 impl<T> Translate<T> for RpEndpoint<T::Source>
@@ -237,6 +433,7 @@ where
             safe_ident: self.safe_ident,
             name: self.name,
             comment: self.comment,
+            deprecated: self.deprecated,
             attributes: self.attributes.translate(diag, translator)?,
             arguments: self.arguments.translate(diag, translator)?,
             request: self.request.translate(diag, translator)?,
@@ -285,6 +482,15 @@ where
             (None, Some(&Unary { ty: ref response })) => (None, Some(response.clone())),
             (Some(&Unary { ty: ref request }), None) => (Some(request.clone()), None),
             (None, None) => (None, None),
+            // Streaming responses are supported with a framing.
+            (Some(Unary { ty: request }), Some(Streaming { ty: response }))
+                if endpoint.http.stream.is_some() =>
+            {
+                (Some(request.clone()), Some(response.clone()))
+            }
+            (None, Some(Streaming { ty: response })) if endpoint.http.stream.is_some() => {
+                (None, Some(response.clone()))
+            }
             _ => return None,
         };
 
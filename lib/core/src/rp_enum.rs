@@ -2,8 +2,8 @@
 
 use crate::errors::Result;
 use crate::{
-    Diagnostics, Flavor, RpCode, RpNumber, RpNumberType, RpReg, RpStringType, RpValue, Span,
-    Spanned, Translate, Translator,
+    Diagnostics, Flavor, RpCode, RpDeprecated, RpNumber, RpNumberType, RpReg, RpStringType,
+    RpUnknown, RpValue, Span, Spanned, Translate, Translator,
 };
 use serde::Serialize;
 use std::fmt;
@@ -17,6 +17,9 @@ decl_body!(
         pub variants: RpVariants<F>,
         /// Custom code blocks in the enum.
         pub codes: Vec<Spanned<RpCode>>,
+        /// Variant which holds values not known to this version of the enum.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unknown: Option<Spanned<RpUnknown<F>>>,
     }
 );
 
@@ -34,6 +37,7 @@ where
         let enum_type = translator.translate_enum_type(diag, self.enum_type)?;
         let decls = self.decls.translate(diag, translator)?;
         let variants = self.variants.translate(diag, translator)?;
+        let unknown = RpUnknown::translate(self.unknown, diag, translator, RpReg::EnumVariant)?;
 
         Ok(RpEnumBody {
             name,
@@ -41,9 +45,11 @@ where
             comment: self.comment,
             decls,
             decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
             enum_type,
             variants,
             codes: self.codes,
+            unknown,
         })
     }
 }
@@ -89,6 +95,7 @@ where
     pub name: &'a F::Name,
     pub ident: &'a Spanned<String>,
     pub comment: &'a Vec<String>,
+    pub deprecated: Option<&'a RpDeprecated>,
     pub value: RpVariantValue<'a>,
 }
 
@@ -112,6 +119,7 @@ where
             name: self.name,
             ident: self.ident,
             comment: self.comment,
+            deprecated: self.deprecated,
             value: self.value,
         }
     }
@@ -139,6 +147,8 @@ where
     pub name: F::Name,
     pub ident: Spanned<String>,
     pub comment: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
     pub value: V,
 }
 
@@ -179,6 +189,7 @@ where
             name,
             ident: self.ident,
             comment: self.comment,
+            deprecated: self.deprecated,
             value: self.value,
         })
     }
@@ -289,6 +300,7 @@ where
                             name: &value.name,
                             ident: &value.ident,
                             comment: &value.comment,
+                            deprecated: value.deprecated.as_ref(),
                             value: RpVariantValue::from(&value.value),
                         })
                     }
@@ -1,11 +1,14 @@
 //! Data Models for fields
 
 use crate::errors::Result;
-use crate::{Diagnostics, Flavor, FlavorField, Translate, Translator};
+use crate::{
+    Diagnostics, Flavor, FlavorField, RpBytesEncoding, RpDeprecated, RpLiteral, RpName,
+    RpNumberEncoding, RpValidate, Translate, Translator,
+};
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
-#[serde(bound = "F::Type: serde::Serialize")]
+#[serde(bound = "F::Type: serde::Serialize, F::Package: serde::Serialize")]
 pub struct RpField<F>
 where
     F: Flavor,
@@ -24,6 +27,29 @@ where
     /// Alias of field in JSON.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub field_as: Option<String>,
+    /// Default value of the field, used when it is absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<RpLiteral>,
+    /// Deprecation information of the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
+    /// Type that the field was inherited from, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited: Option<RpName<F>>,
+    /// Validation rules of the field.
+    ///
+    /// These are lifted out of the type of the field when it's translated, since translated types
+    /// generally don't carry them.
+    #[serde(skip_serializing_if = "RpValidate::is_empty")]
+    pub validate: RpValidate,
+    /// How the value of the field is encoded if it's a number, lifted out of the type of the
+    /// field like `validate`.
+    #[serde(skip_serializing_if = "RpNumberEncoding::is_number")]
+    pub number_encoding: RpNumberEncoding,
+    /// How the value of the field is encoded if it's bytes, lifted out of the type of the field
+    /// like `validate`.
+    #[serde(skip_serializing_if = "RpBytesEncoding::is_base64")]
+    pub bytes_encoding: RpBytesEncoding,
 }
 
 impl<F> FlavorField for RpField<F>
@@ -50,6 +76,12 @@ where
             comment: Vec::new(),
             ty,
             field_as: None,
+            default: None,
+            deprecated: None,
+            inherited: None,
+            validate: RpValidate::None,
+            number_encoding: RpNumberEncoding::Number,
+            bytes_encoding: RpBytesEncoding::Base64,
         }
     }
 
@@ -81,6 +113,11 @@ where
         self.field_as.as_ref().unwrap_or(&self.ident)
     }
 
+    /// Check if the field is a number which is encoded as a string.
+    pub fn is_string_encoded(&self) -> bool {
+        self.number_encoding.is_string()
+    }
+
     pub fn display(&self) -> String {
         self.name().to_owned()
     }
@@ -101,6 +138,12 @@ where
             comment: self.comment,
             ty: translator.translate_type(diag, self.ty)?,
             field_as: self.field_as,
+            default: self.default,
+            deprecated: self.deprecated,
+            inherited: self.inherited.translate(diag, translator)?,
+            validate: self.validate,
+            number_encoding: self.number_encoding,
+            bytes_encoding: self.bytes_encoding,
         })
     }
 }
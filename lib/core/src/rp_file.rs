@@ -14,8 +14,8 @@ pub struct RpEnabledFeature {
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(
-    bound = "F: Serialize, F::Field: Serialize, F::Endpoint: Serialize, F::Package: Serialize, \
-             F::Name: Serialize, F::EnumType: Serialize"
+    bound = "F: Serialize, F::Type: Serialize, F::Field: Serialize, F::Endpoint: Serialize, \
+             F::Package: Serialize, F::Name: Serialize, F::EnumType: Serialize"
 )]
 pub struct RpFile<F>
 where
@@ -3,7 +3,8 @@
 use crate::errors::Result;
 use crate::translator;
 use crate::{
-    Diagnostics, Flavor, FlavorField, RpCode, RpDecl, RpReg, Spanned, Translate, Translator,
+    Diagnostics, Flavor, FlavorField, RpCode, RpDecl, RpDeprecated, RpReg, RpUnknown, Spanned,
+    Translate, Translator,
 };
 use linked_hash_map::LinkedHashMap;
 use serde::Serialize;
@@ -11,11 +12,19 @@ use serde::Serialize;
 /// Default key to use for tagged sub type strategy.
 pub const DEFAULT_TAG: &str = "type";
 
+/// Default key to use for the content of the adjacent sub type strategy.
+pub const DEFAULT_CONTENT: &str = "data";
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RpSubTypeStrategy {
     /// An object, with a single tag key indicating which sub-type to use.
     Tagged { tag: String },
+    /// An object, with a tag key indicating which sub-type to use and a content key holding the
+    /// sub-type.
+    Adjacent { tag: String, content: String },
+    /// An object, with a single key which is the name of the sub-type holding the sub-type.
+    External,
     /// An sub-type is distinguished by its set of unique fields.
     /// This requires a sub-type to actually _have_ a unique set of fields, which is validates
     /// during translation.
@@ -32,10 +41,17 @@ impl Default for RpSubTypeStrategy {
 
 decl_body!(
     pub struct RpInterfaceBody<F> {
+        /// Type parameters of a generic declaration, like `T` in `interface Shape<T>`.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub type_parameters: Vec<String>,
         pub fields: Vec<Spanned<F::Field>>,
         pub codes: Vec<Spanned<RpCode>>,
         pub sub_types: Vec<Spanned<RpSubType<F>>>,
         pub sub_type_strategy: RpSubTypeStrategy,
+        /// Sub-type which holds objects with a sub-type not known to this version of the
+        /// interface.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub unknown: Option<Spanned<RpUnknown<F>>>,
     }
 );
 
@@ -46,6 +62,11 @@ where
     pub fn fields(&self) -> impl Iterator<Item = &Spanned<F::Field>> {
         self.fields.iter()
     }
+
+    /// Check if the interface is generic.
+    pub fn is_generic(&self) -> bool {
+        !self.type_parameters.is_empty()
+    }
 }
 
 impl<T> Translate<T> for RpInterfaceBody<T::Source>
@@ -66,6 +87,7 @@ where
         let decls = self.decls.translate(diag, translator)?;
         let fields = translator::Fields(self.fields).translate(diag, translator)?;
         let sub_types = self.sub_types.translate(diag, translator)?;
+        let unknown = RpUnknown::translate(self.unknown, diag, translator, RpReg::SubType)?;
 
         Ok(RpInterfaceBody {
             name,
@@ -73,18 +95,21 @@ where
             comment: self.comment,
             decls,
             decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
+            type_parameters: self.type_parameters,
             fields,
             codes: self.codes,
             sub_types,
             sub_type_strategy: self.sub_type_strategy,
+            unknown,
         })
     }
 }
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(
-    bound = "F: Serialize, F::Field: Serialize, F::Endpoint: Serialize, F::Package: Serialize, \
-             F::Name: Serialize, F::EnumType: Serialize"
+    bound = "F: Serialize, F::Type: Serialize, F::Field: Serialize, F::Endpoint: Serialize, \
+             F::Package: Serialize, F::Name: Serialize, F::EnumType: Serialize"
 )]
 pub struct RpSubType<F>
 where
@@ -96,6 +121,11 @@ where
     /// Inner declarations.
     pub decls: Vec<RpDecl<F>>,
     pub decl_idents: LinkedHashMap<String, usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<RpDeprecated>,
+    /// Type parameters inherited from the interface.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub fields: Vec<Spanned<F::Field>>,
     pub codes: Vec<Spanned<RpCode>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -150,6 +180,8 @@ where
             comment: self.comment,
             decls,
             decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
+            type_parameters: self.type_parameters,
             fields,
             codes: self.codes,
             sub_type_name: self.sub_type_name,
@@ -0,0 +1,74 @@
+//! Literal values, as used in field defaults and constants.
+
+use crate::RpNumber;
+use num_traits::cast::ToPrimitive;
+use serde::Serialize;
+use std::fmt;
+use std::result;
+
+/// A literal value which has been checked against its declared type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RpLiteral {
+    String(String),
+    Number(RpNumber),
+    Boolean(bool),
+}
+
+impl RpLiteral {
+    /// Access the literal as a string, if it is one.
+    pub fn as_string(&self) -> Option<&str> {
+        match *self {
+            RpLiteral::String(ref string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Access the literal as a number, if it is one.
+    pub fn as_number(&self) -> Option<&RpNumber> {
+        match *self {
+            RpLiteral::Number(ref number) => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Access the literal as a boolean, if it is one.
+    pub fn as_boolean(&self) -> Option<bool> {
+        match *self {
+            RpLiteral::Boolean(boolean) => Some(boolean),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RpLiteral {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpLiteral::String(ref string) => write!(f, "{:?}", string),
+            RpLiteral::Number(ref number) => number.fmt(f),
+            RpLiteral::Boolean(boolean) => boolean.fmt(f),
+        }
+    }
+}
+
+impl Serialize for RpLiteral {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            RpLiteral::String(ref string) => serializer.serialize_str(string),
+            RpLiteral::Number(ref number) => {
+                // Keep whole numbers integral, to avoid rendering them as `1.0`.
+                match number.to_bigint() {
+                    Some(n) => match (n.to_i64(), n.to_u64()) {
+                        (Some(n), _) => serializer.serialize_i64(n),
+                        (None, Some(n)) => serializer.serialize_u64(n),
+                        (None, None) => number.serialize(serializer),
+                    },
+                    None => number.serialize(serializer),
+                }
+            }
+            RpLiteral::Boolean(boolean) => serializer.serialize_bool(boolean),
+        }
+    }
+}
@@ -84,6 +84,24 @@ where
     pub fn is_same(&self, other: &Self) -> bool {
         self.package == other.package && self.path == other.path
     }
+
+    /// Find the name among the candidates which is most similar to this one, if any is similar
+    /// enough to be a likely typo.
+    ///
+    /// Only names in the same package, and at the same depth, are considered.
+    pub fn closest<'a, I>(&self, candidates: I) -> Option<&'a Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        let candidates = candidates
+            .into_iter()
+            .filter(|c| c.package == self.package && c.path.len() == self.path.len())
+            .map(|c| (c.join("::"), c))
+            .collect::<Vec<_>>();
+
+        let closest = crate::closest(&self.join("::"), candidates.iter().map(|c| c.0.as_str()))?;
+        candidates.iter().find(|c| c.0 == closest).map(|c| c.1)
+    }
 }
 
 impl RpName<CoreFlavor> {
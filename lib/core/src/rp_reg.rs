@@ -13,6 +13,9 @@ pub enum RpReg {
     Enum,
     EnumVariant,
     Service,
+    Const,
+    Alias,
+    Union,
 }
 
 impl RpReg {
@@ -30,7 +33,7 @@ impl RpReg {
         use self::RpReg::*;
 
         match *self {
-            Type | Interface | Enum | Tuple | Service => {
+            Type | Interface | Enum | Tuple | Service | Const | Alias | Union => {
                 let p = name.path.iter().map(String::as_str).collect();
                 package_fn(p)
             }
@@ -68,6 +71,9 @@ impl fmt::Display for RpReg {
             Enum => write!(fmt, "enum"),
             Tuple => write!(fmt, "tuple"),
             Service => write!(fmt, "service"),
+            Const => write!(fmt, "const"),
+            Alias => write!(fmt, "alias"),
+            Union => write!(fmt, "union"),
             SubType => write!(fmt, "subtype"),
             EnumVariant => write!(fmt, "variant"),
         }
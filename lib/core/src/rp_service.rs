@@ -11,9 +11,21 @@ pub struct RpServiceBodyHttp {
     pub url: Option<Spanned<String>>,
 }
 
+/// WebSocket configuration of a service, used by its bi-directional endpoints.
+#[derive(Debug, Clone, Serialize)]
+pub struct RpServiceBodyWebSocket {
+    /// Path of the WebSocket, relative to the URL of the service.
+    pub path: Spanned<String>,
+    /// Default URL to use for service, like `ws://localhost:8080`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Spanned<String>>,
+}
+
 decl_body!(
     pub struct RpServiceBody<F> {
         pub http: RpServiceBodyHttp,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub websocket: Option<RpServiceBodyWebSocket>,
         pub endpoints: Vec<Spanned<F::Endpoint>>,
     }
 );
@@ -44,7 +56,9 @@ where
             comment: self.comment,
             decls,
             decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
             http: self.http,
+            websocket: self.websocket,
             endpoints,
         })
     }
@@ -40,6 +40,7 @@ where
             comment: self.comment,
             decls,
             decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
             fields,
             codes: self.codes,
         })
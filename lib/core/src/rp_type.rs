@@ -9,14 +9,23 @@ use std::fmt;
 /// Describes number validation.
 #[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
 pub struct RpNumberValidate {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub min: Option<RpNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max: Option<RpNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_min: Option<RpNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_max: Option<RpNumber>,
 }
 
 impl RpNumberValidate {
     /// Check if the validation rules are empty.
     pub fn is_empty(&self) -> bool {
-        self.min.is_none() && self.max.is_none()
+        self.min.is_none()
+            && self.max.is_none()
+            && self.exclusive_min.is_none()
+            && self.exclusive_max.is_none()
     }
 }
 
@@ -38,6 +47,84 @@ impl RpStringValidate {
     }
 }
 
+/// Validation rules of a value, detached from its type so that they survive translation into
+/// other flavors.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RpValidate {
+    #[default]
+    None,
+    String(RpStringValidate),
+    Number(RpNumberValidate),
+}
+
+impl RpValidate {
+    /// Check if there are no validation rules.
+    pub fn is_empty(&self) -> bool {
+        match *self {
+            RpValidate::None => true,
+            RpValidate::String(ref validate) => validate.is_empty(),
+            RpValidate::Number(ref validate) => validate.is_empty(),
+        }
+    }
+
+    /// All checks implied by the validation rules, in the order they should be performed.
+    pub fn checks(&self) -> Vec<RpCheck<'_>> {
+        let mut out = Vec::new();
+
+        match *self {
+            RpValidate::None => {}
+            RpValidate::String(ref validate) => {
+                out.extend(validate.min_length.map(RpCheck::MinLength));
+                out.extend(validate.max_length.map(RpCheck::MaxLength));
+                out.extend(validate.pattern.as_ref().map(RpCheck::Pattern));
+            }
+            RpValidate::Number(ref validate) => {
+                out.extend(validate.min.as_ref().map(RpCheck::Min));
+                out.extend(validate.exclusive_min.as_ref().map(RpCheck::ExclusiveMin));
+                out.extend(validate.max.as_ref().map(RpCheck::Max));
+                out.extend(validate.exclusive_max.as_ref().map(RpCheck::ExclusiveMax));
+            }
+        }
+
+        out
+    }
+}
+
+/// A single check implied by a set of validation rules.
+#[derive(Debug, Clone, Copy)]
+pub enum RpCheck<'a> {
+    /// The string must be at least the given number of characters long.
+    MinLength(usize),
+    /// The string must be at most the given number of characters long.
+    MaxLength(usize),
+    /// The entire string must match the given pattern.
+    Pattern(&'a Regex),
+    /// The number must be greater than or equal to the given number.
+    Min(&'a RpNumber),
+    /// The number must be less than or equal to the given number.
+    Max(&'a RpNumber),
+    /// The number must be greater than the given number.
+    ExclusiveMin(&'a RpNumber),
+    /// The number must be less than the given number.
+    ExclusiveMax(&'a RpNumber),
+}
+
+impl<'a> fmt::Display for RpCheck<'a> {
+    /// Describe the check, as it should be presented in error messages.
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpCheck::MinLength(n) => write!(fmt, "length must be at least {}", n),
+            RpCheck::MaxLength(n) => write!(fmt, "length must be at most {}", n),
+            RpCheck::Pattern(pattern) => write!(fmt, "must match pattern `{}`", pattern),
+            RpCheck::Min(n) => write!(fmt, "must be at least {}", n),
+            RpCheck::Max(n) => write!(fmt, "must be at most {}", n),
+            RpCheck::ExclusiveMin(n) => write!(fmt, "must be greater than {}", n),
+            RpCheck::ExclusiveMax(n) => write!(fmt, "must be less than {}", n),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 pub enum RpNumberKind {
     #[serde(rename = "u32")]
@@ -63,28 +150,75 @@ impl fmt::Display for RpNumberKind {
     }
 }
 
+/// How a number is encoded in JSON.
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RpNumberEncoding {
+    /// Encoded as a JSON number.
+    #[default]
+    Number,
+    /// Encoded as a JSON string, which permits the full range of 64-bit numbers to be represented.
+    String,
+}
+
+impl RpNumberEncoding {
+    pub fn is_number(&self) -> bool {
+        *self == RpNumberEncoding::Number
+    }
+
+    pub fn is_string(&self) -> bool {
+        *self == RpNumberEncoding::String
+    }
+}
+
+impl fmt::Display for RpNumberEncoding {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpNumberEncoding::Number => "number".fmt(fmt),
+            RpNumberEncoding::String => "string".fmt(fmt),
+        }
+    }
+}
+
 /// A number type.
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct RpNumberType {
     pub kind: RpNumberKind,
     #[serde(skip_serializing_if = "RpNumberValidate::is_empty")]
     pub validate: RpNumberValidate,
+    #[serde(skip_serializing_if = "RpNumberEncoding::is_number")]
+    pub encoding: RpNumberEncoding,
 }
 
-impl RpNumberType {
-    /// Validate that the given number doesn't violate expected numeric bounds.
-    pub fn validate_number(&self, number: &RpNumber) -> Result<()> {
+impl RpNumberKind {
+    /// The inclusive range of numbers which can be represented by this kind, with the given
+    /// encoding.
+    pub fn bounds(&self, encoding: RpNumberEncoding) -> (BigInt, BigInt) {
         // max contiguous whole number that can be represented with a double: 2^53 - 1
         const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991i64;
         const MIN_SAFE_INTEGER: i64 = -9_007_199_254_740_991i64;
 
         // TODO: calculate numeric bounds instead of switching over a couple of well-known ones.
-        let (mn, mx): (BigInt, BigInt) = match self.kind {
-            RpNumberKind::U32 => (0u32.into(), i32::max_value().into()),
-            RpNumberKind::U64 => (0u64.into(), MAX_SAFE_INTEGER.into()),
-            RpNumberKind::I32 => (i32::min_value().into(), i32::max_value().into()),
-            RpNumberKind::I64 => (MIN_SAFE_INTEGER.into(), MAX_SAFE_INTEGER.into()),
-        };
+        match (*self, encoding) {
+            (RpNumberKind::U32, _) => (0u32.into(), i32::MAX.into()),
+            (RpNumberKind::U64, RpNumberEncoding::Number) => (0u64.into(), MAX_SAFE_INTEGER.into()),
+            (RpNumberKind::U64, RpNumberEncoding::String) => (0u64.into(), i64::MAX.into()),
+            (RpNumberKind::I32, _) => (i32::MIN.into(), i32::MAX.into()),
+            (RpNumberKind::I64, RpNumberEncoding::Number) => {
+                (MIN_SAFE_INTEGER.into(), MAX_SAFE_INTEGER.into())
+            }
+            (RpNumberKind::I64, RpNumberEncoding::String) => (i64::MIN.into(), i64::MAX.into()),
+        }
+    }
+
+    /// Check if this is a 64-bit kind, which can't be represented in full by a JSON number.
+    pub fn is_64_bit(&self) -> bool {
+        matches!(*self, RpNumberKind::U64 | RpNumberKind::I64)
+    }
+
+    /// Validate that the given number can be represented by this kind, with the given encoding.
+    pub fn validate_number(&self, encoding: RpNumberEncoding, number: &RpNumber) -> Result<()> {
+        let (mn, mx) = self.bounds(encoding);
 
         let n = number.to_bigint().ok_or_else(|| "not a whole number")?;
 
@@ -97,6 +231,32 @@ impl RpNumberType {
     }
 }
 
+impl RpNumberType {
+    /// Validate that the given number doesn't violate expected numeric bounds, or the validation
+    /// rules of the type.
+    pub fn validate_number(&self, number: &RpNumber) -> Result<()> {
+        self.kind.validate_number(self.encoding, number)?;
+
+        let n = number.to_bigint().ok_or("not a whole number")?;
+
+        for check in RpValidate::Number(self.validate.clone()).checks() {
+            let ok = match check {
+                RpCheck::Min(bound) => bound.to_bigint().map(|b| n >= b),
+                RpCheck::Max(bound) => bound.to_bigint().map(|b| n <= b),
+                RpCheck::ExclusiveMin(bound) => bound.to_bigint().map(|b| n > b),
+                RpCheck::ExclusiveMax(bound) => bound.to_bigint().map(|b| n < b),
+                _ => None,
+            };
+
+            if ok == Some(false) {
+                return Err(format!("number {}", check).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl fmt::Display for RpNumberType {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         self.kind.fmt(fmt)
@@ -110,6 +270,45 @@ pub struct RpStringType {
     pub validate: RpStringValidate,
 }
 
+/// How bytes are encoded in a JSON string.
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RpBytesEncoding {
+    /// Standard base64, using `+` and `/` as supplementary characters and `=` for padding.
+    #[default]
+    Base64,
+    /// URL-safe base64, using `-` and `_` as supplementary characters and `=` for padding.
+    Base64url,
+    /// URL-safe base64 without padding.
+    Base64urlNopad,
+    /// Lowercase hexadecimal, two characters per byte.
+    Hex,
+}
+
+impl RpBytesEncoding {
+    pub fn is_base64(&self) -> bool {
+        *self == RpBytesEncoding::Base64
+    }
+}
+
+impl fmt::Display for RpBytesEncoding {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RpBytesEncoding::Base64 => "base64".fmt(fmt),
+            RpBytesEncoding::Base64url => "base64url".fmt(fmt),
+            RpBytesEncoding::Base64urlNopad => "base64url_nopad".fmt(fmt),
+            RpBytesEncoding::Hex => "hex".fmt(fmt),
+        }
+    }
+}
+
+/// Describes a bytes type.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct RpBytesType {
+    #[serde(skip_serializing_if = "RpBytesEncoding::is_base64")]
+    pub encoding: RpBytesEncoding,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 #[serde(bound = "F::Package: Serialize")]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -124,18 +323,43 @@ where
     String(RpStringType),
     /// ISO-8601 datetime
     DateTime,
-    Bytes,
+    /// ISO-8601 calendar date
+    Date,
+    /// ISO-8601 time of day, without a time zone
+    Time,
+    /// ISO-8601 duration
+    Duration,
+    /// UUID in its canonical hyphenated form
+    Uuid,
+    /// Arbitrary precision decimal number
+    Decimal,
+    Bytes(RpBytesType),
     Any,
     Name {
         name: Spanned<RpName<F>>,
+        /// Type arguments, as in `Page<string>`.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        arguments: Vec<RpType<F>>,
+    },
+    /// Reference to a type parameter of the enclosing declaration.
+    Parameter {
+        ident: String,
     },
     Array {
         inner: Box<RpType<F>>,
     },
+    /// Set of unique values, encoded as an array.
+    Set {
+        inner: Box<RpType<F>>,
+    },
     Map {
         key: Box<RpType<F>>,
         value: Box<RpType<F>>,
     },
+    /// Anonymous union, as in `string | u64`.
+    Union {
+        alternatives: Vec<RpType<F>>,
+    },
 }
 
 impl<F> RpType<F>
@@ -153,6 +377,33 @@ where
         }
     }
 
+    /// How values of this type are encoded, if it is a number.
+    pub fn number_encoding(&self) -> RpNumberEncoding {
+        match *self {
+            RpType::Number(ref number) => number.encoding,
+            _ => RpNumberEncoding::Number,
+        }
+    }
+
+    /// How values of this type are encoded, if it is bytes.
+    pub fn bytes_encoding(&self) -> RpBytesEncoding {
+        match *self {
+            RpType::Bytes(ref bytes) => bytes.encoding,
+            _ => RpBytesEncoding::Base64,
+        }
+    }
+
+    /// Validation rules that apply to values of this type.
+    pub fn validate(&self) -> RpValidate {
+        use self::RpType::*;
+
+        match *self {
+            String(ref string) => RpValidate::String(string.validate.clone()),
+            Number(ref number) => RpValidate::Number(number.validate.clone()),
+            _ => RpValidate::None,
+        }
+    }
+
     /// Modify any name components with the given operation.
     fn with_name<M>(self, f: M) -> Self
     where
@@ -161,16 +412,29 @@ where
         use self::RpType::*;
 
         match self {
-            Name { name } => Name {
-                name: Spanned::map(name, f),
+            Name { name, arguments } => Name {
+                name: Spanned::map(name, f.clone()),
+                arguments: arguments
+                    .into_iter()
+                    .map(|a| a.with_name(f.clone()))
+                    .collect(),
             },
             Array { inner } => Array {
                 inner: Box::new(inner.with_name(f)),
             },
+            Set { inner } => Set {
+                inner: Box::new(inner.with_name(f)),
+            },
             Map { key, value } => Map {
                 key: Box::new(key.with_name(f.clone())),
                 value: Box::new(value.with_name(f.clone())),
             },
+            Union { alternatives } => Union {
+                alternatives: alternatives
+                    .into_iter()
+                    .map(|a| a.with_name(f.clone()))
+                    .collect(),
+            },
             ty => ty,
         }
     }
@@ -204,11 +468,50 @@ where
             Boolean => write!(f, "boolean"),
             String(..) => write!(f, "string"),
             DateTime => write!(f, "datetime"),
-            Name { ref name } => write!(f, "{}", name),
+            Date => write!(f, "date"),
+            Time => write!(f, "time"),
+            Duration => write!(f, "duration"),
+            Uuid => write!(f, "uuid"),
+            Decimal => write!(f, "decimal"),
+            Name {
+                ref name,
+                ref arguments,
+            } => {
+                write!(f, "{}", name)?;
+
+                if !arguments.is_empty() {
+                    write!(f, "<")?;
+
+                    for (i, a) in arguments.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+
+                        write!(f, "{}", a)?;
+                    }
+
+                    write!(f, ">")?;
+                }
+
+                Ok(())
+            }
+            Parameter { ref ident } => write!(f, "{}", ident),
             Array { ref inner } => write!(f, "[{}]", inner),
+            Set { ref inner } => write!(f, "#{{{}}}", inner),
             Map { ref key, ref value } => write!(f, "{{{}: {}}}", key, value),
+            Union { ref alternatives } => {
+                for (i, a) in alternatives.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " | ")?;
+                    }
+
+                    write!(f, "{}", a)?;
+                }
+
+                Ok(())
+            }
             Any => write!(f, "any"),
-            Bytes => write!(f, "bytes"),
+            Bytes(..) => write!(f, "bytes"),
         }
     }
 }
@@ -2,10 +2,17 @@
 
 use crate::errors::Result;
 use crate::translator;
-use crate::{Diagnostics, Flavor, RpCode, RpReg, Spanned, Translate, Translator};
+use crate::{Diagnostics, Flavor, RpCode, RpName, RpReg, Spanned, Translate, Translator};
 
 decl_body!(
     pub struct RpTypeBody<F> {
+        /// Type parameters of a generic declaration, like `T` in `type Page<T>`.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub type_parameters: Vec<String>,
+        /// Types that fields are inherited from, like `Auditable` in `type Order: Auditable`.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub parents: Vec<Spanned<RpName<F>>>,
+        /// All fields, including inherited ones.
         pub fields: Vec<Spanned<F::Field>>,
         pub codes: Vec<Spanned<RpCode>>,
     }
@@ -18,6 +25,11 @@ where
     pub fn fields(&self) -> impl Iterator<Item = &Spanned<F::Field>> {
         self.fields.iter()
     }
+
+    /// Check if the type is generic.
+    pub fn is_generic(&self) -> bool {
+        !self.type_parameters.is_empty()
+    }
 }
 
 impl<F, T> Translate<T> for RpTypeBody<F>
@@ -33,6 +45,7 @@ where
 
         let name = translator.translate_local_name(diag, RpReg::Type, self.name)?;
         let decls = self.decls.translate(diag, translator)?;
+        let parents = self.parents.translate(diag, translator)?;
         let fields = translator::Fields(self.fields).translate(diag, translator)?;
 
         Ok(RpTypeBody {
@@ -41,6 +54,9 @@ where
             comment: self.comment,
             decls,
             decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
+            type_parameters: self.type_parameters,
+            parents,
             fields,
             codes: self.codes,
         })
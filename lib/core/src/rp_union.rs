@@ -0,0 +1,105 @@
+//! Model for anonymous unions.
+//!
+//! Unions are written as field types, like `value: string | u64;`. Backends which need a named
+//! type to represent them get them lifted into union declarations.
+
+use crate::errors::Result;
+use crate::{Diagnostics, Flavor, RpReg, Translate, Translator};
+use serde::Serialize;
+
+/// The kind of JSON value that an alternative of a union is decoded from.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RpUnionKind {
+    String,
+    /// A whole number.
+    Integer,
+    /// Any number, whole or not.
+    Number,
+    Boolean,
+    Array,
+    Object,
+}
+
+impl RpUnionKind {
+    /// Check if two alternatives can't be told apart, since they are decoded from the same kind
+    /// of JSON value.
+    pub fn conflicts_with(self, other: RpUnionKind) -> bool {
+        use self::RpUnionKind::*;
+
+        match (self, other) {
+            (Integer, Number) | (Number, Integer) => true,
+            (a, b) => a == b,
+        }
+    }
+
+    /// Describe the JSON value that the alternative is decoded from.
+    pub fn description(self) -> &'static str {
+        use self::RpUnionKind::*;
+
+        match self {
+            String => "a JSON string",
+            Integer | Number => "a JSON number",
+            Boolean => "a JSON boolean",
+            Array => "a JSON array",
+            Object => "a JSON object",
+        }
+    }
+}
+
+/// A single alternative of a union.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "F::Type: Serialize")]
+pub struct RpUnionVariant<F>
+where
+    F: Flavor,
+{
+    /// Identifier of the alternative, derived from its type.
+    pub ident: String,
+    pub kind: RpUnionKind,
+    #[serde(rename = "type")]
+    pub ty: F::Type,
+}
+
+decl_body!(
+    pub struct RpUnionBody<F> {
+        pub variants: Vec<RpUnionVariant<F>>,
+    }
+);
+
+impl<T> Translate<T> for RpUnionBody<T::Source>
+where
+    T: Translator,
+{
+    type Out = RpUnionBody<T::Target>;
+
+    /// Translate into different flavor.
+    fn translate(self, diag: &mut Diagnostics, translator: &T) -> Result<RpUnionBody<T::Target>> {
+        translator.visit(diag, &self.name)?;
+
+        let name = translator.translate_local_name(diag, RpReg::Union, self.name)?;
+        let decls = self.decls.translate(diag, translator)?;
+
+        let variants = self
+            .variants
+            .into_iter()
+            .map(|v| {
+                Ok(RpUnionVariant {
+                    ident: v.ident,
+                    kind: v.kind,
+                    ty: translator.translate_type(diag, v.ty)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RpUnionBody {
+            name,
+            ident: self.ident,
+            comment: self.comment,
+            decls,
+            decl_idents: self.decl_idents,
+            deprecated: self.deprecated,
+            variants,
+        })
+    }
+}
@@ -0,0 +1,52 @@
+//! Model for unknown variants and sub-types.
+
+use crate::errors::Result;
+use crate::{Diagnostics, Flavor, RpReg, Spanned, Translator};
+use serde::Serialize;
+
+/// The catch-all member of an enum or an interface, marked with `#[unknown]`.
+///
+/// Values which are not recognized while decoding are decoded into this member, which holds on to
+/// the raw value so that it can be encoded again.
+#[derive(Debug, Clone, Serialize)]
+#[serde(bound = "F::Name: Serialize")]
+pub struct RpUnknown<F>
+where
+    F: Flavor,
+{
+    pub name: F::Name,
+    pub ident: String,
+    pub comment: Vec<String>,
+}
+
+impl<F> RpUnknown<F>
+where
+    F: Flavor,
+{
+    /// Translate into different flavor, `reg` being the kind of the member.
+    pub(crate) fn translate<T>(
+        unknown: Option<Spanned<Self>>,
+        diag: &mut Diagnostics,
+        translator: &T,
+        reg: RpReg,
+    ) -> Result<Option<Spanned<RpUnknown<T::Target>>>>
+    where
+        T: Translator<Source = F>,
+    {
+        let (unknown, span) = match unknown {
+            Some(unknown) => Spanned::take_pair(unknown),
+            None => return Ok(None),
+        };
+
+        let name = translator.translate_local_name(diag, reg, unknown.name)?;
+
+        Ok(Some(Spanned::new(
+            RpUnknown {
+                name,
+                ident: unknown.ident,
+                comment: unknown.comment,
+            },
+            span,
+        )))
+    }
+}
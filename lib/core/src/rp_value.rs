@@ -20,6 +20,7 @@ where
     Number(RpNumber),
     Identifier(String),
     Array(Vec<Spanned<RpValue<F>>>),
+    Object(Vec<(Spanned<String>, Spanned<RpValue<F>>)>),
     Name(Spanned<RpName<F>>),
 }
 
@@ -131,6 +132,22 @@ where
                 write!(f, "]")?;
                 Ok(())
             }
+            RpValue::Object(ref entries) => {
+                write!(f, "{{")?;
+
+                let mut it = entries.iter().peekable();
+
+                while let Some((k, v)) = it.next() {
+                    write!(f, "\"{}\": {}", k, v)?;
+
+                    if it.peek().is_some() {
+                        write!(f, ", ")?;
+                    }
+                }
+
+                write!(f, "}}")?;
+                Ok(())
+            }
             RpValue::Name(ref name) => name.fmt(f),
         }
     }
@@ -150,6 +167,15 @@ where
             Number(number) => Number(number),
             Identifier(string) => Identifier(string),
             Array(array) => Array(array.translate(diag, translator)?),
+            Object(entries) => {
+                let mut out = Vec::new();
+
+                for (key, value) in entries {
+                    out.push((key, value.translate(diag, translator)?));
+                }
+
+                Object(out)
+            }
             Name(name) => Name(name.translate(diag, translator)?),
         };
 
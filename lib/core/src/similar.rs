@@ -0,0 +1,89 @@
+//! Finding similar names, to suggest alternatives for names which could not be resolved.
+
+/// Find the candidate which is most similar to the given name, if any is similar enough to be a
+/// likely typo.
+///
+/// Candidates which differ only in case are always considered similar.
+pub fn closest<'a, I>(name: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max = usize::max(1, name.chars().count().div_ceil(3));
+
+    let mut best = None::<(usize, &'a str)>;
+
+    for candidate in candidates {
+        if candidate == name {
+            continue;
+        }
+
+        let distance = if candidate.to_lowercase() == name.to_lowercase() {
+            0
+        } else {
+            distance(name, candidate)
+        };
+
+        if distance > max {
+            continue;
+        }
+
+        // NB: ties are broken by name, since candidates often come from unordered collections.
+        best = match best {
+            Some((d, c)) if (d, c) <= (distance, candidate) => Some((d, c)),
+            _ => Some((distance, candidate)),
+        };
+    }
+
+    best.map(|(_, candidate)| candidate)
+}
+
+/// The number of single character insertions, deletions, or substitutions required to turn `a`
+/// into `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + usize::min(previous, usize::min(row[j], current))
+            };
+
+            previous = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, distance};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(0, distance("foo", "foo"));
+        assert_eq!(1, distance("foo", "fo"));
+        assert_eq!(1, distance("foo", "fooo"));
+        assert_eq!(1, distance("foo", "fob"));
+        assert_eq!(3, distance("kitten", "sitting"));
+    }
+
+    #[test]
+    fn test_closest() {
+        let candidates = ["Entry", "Entries", "Point", "url"];
+
+        assert_eq!(Some("Entry"), closest("Entyr", candidates.iter().cloned()));
+        assert_eq!(Some("Point"), closest("point", candidates.iter().cloned()));
+        assert_eq!(Some("url"), closest("uri", candidates.iter().cloned()));
+        assert_eq!(None, closest("Unrelated", candidates.iter().cloned()));
+        assert_eq!(None, closest("Entry", candidates.iter().cloned()));
+    }
+}
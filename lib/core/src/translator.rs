@@ -3,8 +3,8 @@
 use crate::errors::Result;
 use crate::Flavor;
 use crate::{
-    CoreFlavor, Diagnostics, RpEndpoint, RpEnumType, RpField, RpName, RpNumberType, RpReg,
-    RpStringType, RpType, RpVersionedPackage, Spanned,
+    CoreFlavor, Diagnostics, RpBytesType, RpEndpoint, RpEnumType, RpField, RpName, RpNumberType,
+    RpReg, RpStringType, RpType, RpVersionedPackage, Spanned,
 };
 use linked_hash_map::LinkedHashMap;
 use std::cell::RefCell;
@@ -41,20 +41,76 @@ where
 
     fn translate_datetime(&self) -> Result<<Self::Target as Flavor>::Type>;
 
+    /// Translate a calendar date.
+    ///
+    /// Returning `None` indicates that the target has no native representation for the type, in
+    /// which case it is represented as a string.
+    fn translate_date(&self) -> Result<Option<<Self::Target as Flavor>::Type>> {
+        Ok(None)
+    }
+
+    /// Translate a time of day, see `translate_date`.
+    fn translate_time(&self) -> Result<Option<<Self::Target as Flavor>::Type>> {
+        Ok(None)
+    }
+
+    /// Translate a duration, see `translate_date`.
+    fn translate_duration(&self) -> Result<Option<<Self::Target as Flavor>::Type>> {
+        Ok(None)
+    }
+
+    /// Translate a UUID, see `translate_date`.
+    fn translate_uuid(&self) -> Result<Option<<Self::Target as Flavor>::Type>> {
+        Ok(None)
+    }
+
+    /// Translate a decimal number, see `translate_date`.
+    fn translate_decimal(&self) -> Result<Option<<Self::Target as Flavor>::Type>> {
+        Ok(None)
+    }
+
+    /// Translate a built-in type which isn't natively supported by the target, and is therefore
+    /// represented as a string.
+    fn translate_fallback(
+        &self,
+        native: Option<<Self::Target as Flavor>::Type>,
+    ) -> Result<<Self::Target as Flavor>::Type> {
+        match native {
+            Some(native) => Ok(native),
+            None => self.translate_string(RpStringType::default()),
+        }
+    }
+
     fn translate_array(
         &self,
         _: <Self::Target as Flavor>::Type,
     ) -> Result<<Self::Target as Flavor>::Type>;
 
+    /// Translate a set of unique values.
+    ///
+    /// Targets without a native set type represent it as an array.
+    fn translate_set(
+        &self,
+        inner: <Self::Target as Flavor>::Type,
+    ) -> Result<<Self::Target as Flavor>::Type> {
+        self.translate_array(inner)
+    }
+
     fn translate_map(
         &self,
         _: <Self::Target as Flavor>::Type,
         _: <Self::Target as Flavor>::Type,
     ) -> Result<<Self::Target as Flavor>::Type>;
 
+    /// Translate an anonymous union with the given alternatives.
+    fn translate_union(
+        &self,
+        _: Vec<<Self::Target as Flavor>::Type>,
+    ) -> Result<<Self::Target as Flavor>::Type>;
+
     fn translate_any(&self) -> Result<<Self::Target as Flavor>::Type>;
 
-    fn translate_bytes(&self) -> Result<<Self::Target as Flavor>::Type>;
+    fn translate_bytes(&self, bytes: RpBytesType) -> Result<<Self::Target as Flavor>::Type>;
 
     /// Translate the given package.
     fn translate_package(
@@ -62,14 +118,18 @@ where
         package: <Self::Source as Flavor>::Package,
     ) -> Result<<Self::Target as Flavor>::Package>;
 
-    /// Translate the given name.
+    /// Translate the given name, with the type arguments it was applied to.
     fn translate_name(
         &self,
         from: &<Self::Target as Flavor>::Package,
         reg: RpReg,
         name: Spanned<RpName<Self::Target>>,
+        arguments: Vec<<Self::Target as Flavor>::Type>,
     ) -> Result<<Self::Target as Flavor>::Type>;
 
+    /// Translate a reference to a type parameter.
+    fn translate_parameter(&self, ident: String) -> Result<<Self::Target as Flavor>::Type>;
+
     /// Translate the given field.
     fn translate_field<T>(
         &self,
@@ -358,8 +418,8 @@ where
 {
     /// Lookup and cause the given name to be registered.
     fn lookup(&self, diag: &mut Diagnostics, key: &Spanned<RpName<T::Source>>) -> Result<RpReg> {
-        let (key, span) = Spanned::borrow_pair(key);
-        let key = key.clone().without_prefix();
+        let (name, span) = Spanned::borrow_pair(key);
+        let key = name.clone().without_prefix();
 
         let decls = self.decls.as_ref().ok_or_else(|| "no declarations")?;
         let mut decls = decls.try_borrow_mut()?;
@@ -372,6 +432,17 @@ where
             Some(reg) => Spanned::borrow(reg).clone(),
             None => {
                 diag.err(span, format!("`{}` does not exist", key));
+
+                if let Some(similar) = key.closest(self.types.keys()) {
+                    let similar = RpName {
+                        prefix: name.prefix.clone(),
+                        ..similar.clone()
+                    };
+
+                    let message = format!("did you mean `{}`?", similar);
+                    diag.suggest(span, message, similar.to_string());
+                }
+
                 return Err(format!("no such type: {}", key).into());
             }
         };
@@ -411,7 +482,27 @@ where
         let out = match source {
             String(string) => self.flavor.translate_string(string)?,
             DateTime => self.flavor.translate_datetime()?,
-            Bytes => self.flavor.translate_bytes()?,
+            Date => {
+                let native = self.flavor.translate_date()?;
+                self.flavor.translate_fallback(native)?
+            }
+            Time => {
+                let native = self.flavor.translate_time()?;
+                self.flavor.translate_fallback(native)?
+            }
+            Duration => {
+                let native = self.flavor.translate_duration()?;
+                self.flavor.translate_fallback(native)?
+            }
+            Uuid => {
+                let native = self.flavor.translate_uuid()?;
+                self.flavor.translate_fallback(native)?
+            }
+            Decimal => {
+                let native = self.flavor.translate_decimal()?;
+                self.flavor.translate_fallback(native)?
+            }
+            Bytes(bytes) => self.flavor.translate_bytes(bytes)?,
             Number(number) => self.flavor.translate_number(number)?,
             Float => self.flavor.translate_float()?,
             Double => self.flavor.translate_double()?,
@@ -420,16 +511,41 @@ where
                 let inner = self.translate_type(diag, *inner)?;
                 self.flavor.translate_array(inner)?
             }
-            Name { name } => {
+            Set { inner } => {
+                let inner = self.translate_type(diag, *inner)?;
+                self.flavor.translate_set(inner)?
+            }
+            Name { name, arguments } => {
                 let reg = self.lookup(diag, &name)?;
+
+                if let RpReg::Const = reg {
+                    diag.err(name.span(), format!("`{}` is a constant, not a type", name));
+                    return Err(format!("constant used as type: {}", name).into());
+                }
+
+                let arguments = arguments
+                    .into_iter()
+                    .map(|a| self.translate_type(diag, a))
+                    .collect::<Result<Vec<_>>>()?;
+
                 let name = name.translate(diag, self)?;
-                self.flavor.translate_name(&self.from, reg, name)?
+                self.flavor
+                    .translate_name(self.from, reg, name, arguments)?
             }
+            Parameter { ident } => self.flavor.translate_parameter(ident)?,
             Map { key, value } => {
                 let key = self.translate_type(diag, *key)?;
                 let value = self.translate_type(diag, *value)?;
                 self.flavor.translate_map(key, value)?
             }
+            Union { alternatives } => {
+                let alternatives = alternatives
+                    .into_iter()
+                    .map(|a| self.translate_type(diag, a))
+                    .collect::<Result<Vec<_>>>()?;
+
+                self.flavor.translate_union(alternatives)?
+            }
             Any => self.flavor.translate_any()?,
         };
 
@@ -439,8 +555,12 @@ where
     fn translate_field(
         &self,
         diag: &mut Diagnostics,
-        source: <Self::Source as Flavor>::Field,
+        mut source: <Self::Source as Flavor>::Field,
     ) -> Result<<Self::Target as Flavor>::Field> {
+        // all passes which modify types have run at this point.
+        source.validate = source.ty.validate();
+        source.number_encoding = source.ty.number_encoding();
+        source.bytes_encoding = source.ty.bytes_encoding();
         self.flavor.translate_field(self, diag, source)
     }
 
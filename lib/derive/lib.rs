@@ -221,6 +221,7 @@ impl<'a, 'input> FieldInit<'a, 'input> {
 
                 Type::Name {
                     name: Spanned::new(name, Span::empty()),
+                    arguments: Vec::new(),
                 }
             }
         };
@@ -236,6 +237,7 @@ impl<'a, 'input> FieldInit<'a, 'input> {
             name: name.clone().into(),
             ty: Spanned::new(ty.into(), self.span.clone()),
             field_as: field_as,
+            default: None,
             endl: true,
         };
 
@@ -338,6 +340,8 @@ impl<'a, 'input> TypeRefiner<'a, 'input> {
     ) -> Result<Item<'input, TypeBody<'input>>> {
         let mut body = TypeBody {
             name: Spanned::new(self.ctx.ident()?.to_string().into(), Span::empty()),
+            type_parameters: Vec::new(),
+            parents: Vec::new(),
             members: Vec::new(),
         };
 
@@ -446,6 +450,7 @@ impl<'a, 'input> InterfaceRefiner<'a, 'input> {
 
         let mut body = InterfaceBody {
             name: Spanned::new(self.ctx.ident()?.to_string().into(), Span::empty()),
+            type_parameters: Vec::new(),
             members: Vec::new(),
             sub_types: Vec::new(),
         };
@@ -386,7 +386,8 @@ where
             fs: RealFilesystem::new(),
             expected: HashMap::new(),
             built_ins: vec![
-                "string", "bytes", "u32", "u64", "i32", "i64", "float", "double", "datetime", "any",
+                "string", "bytes", "u32", "u64", "i32", "i64", "float", "double", "datetime",
+                "date", "time", "duration", "uuid", "decimal", "any",
             ],
         }
     }
@@ -552,6 +553,10 @@ where
                 let params = ty::ReferenceParams::deserialize(request.params)?;
                 self.text_document_references(request.id, params)?;
             }
+            "textDocument/codeAction" => {
+                let params = ty::CodeActionParams::deserialize(request.params)?;
+                self.text_document_code_action(request.id, params)?;
+            }
             "workspace/symbol" => {
                 let params = ty::WorkspaceSymbolParams::deserialize(request.params)?;
                 self.workspace_symbol(request.id, params)?;
@@ -714,6 +719,7 @@ where
                 document_symbol_provider: Some(true),
                 workspace_symbol_provider: Some(true),
                 references_provider: Some(true),
+                code_action_provider: Some(ty::CodeActionProviderCapability::Simple(true)),
                 ..ty::ServerCapabilities::default()
             },
             server_info: Some(ty::ServerInfo {
@@ -866,6 +872,90 @@ where
         Ok(())
     }
 
+    /// Handler for `textDocument/codeAction`.
+    ///
+    /// Provides quick fixes for the suggestions made in diagnostics, like a similar name for a
+    /// name which could not be resolved.
+    fn text_document_code_action(
+        &mut self,
+        request_id: Option<envelope::RequestId>,
+        params: ty::CodeActionParams,
+    ) -> Result<()> {
+        let url = params.text_document.uri;
+
+        let mut actions: Vec<ty::CodeActionOrCommand> = Vec::new();
+
+        if let Some(workspace) = &self.workspace {
+            let mut items = Vec::new();
+
+            if let Some(file) = workspace.file(&url) {
+                items.extend(file.diag.items().map(|d| (&file.diag.source, d)));
+            }
+
+            for diagnostics in &workspace.reporter {
+                match *diagnostics {
+                    Reported::Diagnostics(ref diagnostics) => {
+                        if diagnostics.source.url().as_ref() == Some(&url) {
+                            items.extend(diagnostics.items().map(|d| (&diagnostics.source, d)));
+                        }
+                    }
+                    Reported::SourceDiagnostics(ref diagnostics) => {
+                        for d in diagnostics.items() {
+                            if d.0.url().as_ref() == Some(&url) {
+                                items.push((&d.0, &d.1));
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (source, d) in items {
+                let (span, replacement) = match *d {
+                    core::Diagnostic::Suggestion {
+                        ref span,
+                        ref replacement,
+                        ..
+                    } => (span, replacement),
+                    _ => continue,
+                };
+
+                let (start, end) = source.span_to_range(*span, Encoding::Utf16)?;
+                let range = convert_range((start, end));
+
+                if range.end < params.range.start || params.range.end < range.start {
+                    continue;
+                }
+
+                let edits = vec![ty::TextEdit {
+                    range,
+                    new_text: replacement.to_string(),
+                }];
+
+                let changes = vec![ty::TextDocumentEdit {
+                    text_document: ty::VersionedTextDocumentIdentifier {
+                        uri: url.clone(),
+                        version: None,
+                    },
+                    edits,
+                }];
+
+                actions.push(ty::CodeActionOrCommand::CodeAction(ty::CodeAction {
+                    title: format!("Replace with `{}`", replacement),
+                    kind: Some(ty::code_action_kind::QUICKFIX.to_string()),
+                    edit: Some(ty::WorkspaceEdit {
+                        document_changes: Some(ty::DocumentChanges::Edits(changes)),
+                        ..ty::WorkspaceEdit::default()
+                    }),
+                    is_preferred: Some(true),
+                    ..ty::CodeAction::default()
+                }));
+            }
+        }
+
+        self.channel.send(request_id, Some(actions))?;
+        Ok(())
+    }
+
     /// Handler for `workspace/didChangeConfiguration`.
     fn workspace_did_change_configuration(
         &mut self,
@@ -979,6 +1069,22 @@ where
 
                     out.push(d);
                 }
+                core::Diagnostic::Warning {
+                    ref span,
+                    ref message,
+                } => {
+                    let (start, end) = source.span_to_range(*span, Encoding::Utf16)?;
+                    let range = convert_range((start, end));
+
+                    let d = ty::Diagnostic {
+                        range: range,
+                        message: message.to_string(),
+                        severity: Some(ty::DiagnosticSeverity::Warning),
+                        ..ty::Diagnostic::default()
+                    };
+
+                    out.push(d);
+                }
                 core::Diagnostic::Info {
                     ref span,
                     ref message,
@@ -995,6 +1101,21 @@ where
 
                     out.push(d);
                 }
+                core::Diagnostic::Inactive { ref span } => {
+                    let (start, end) = source.span_to_range(*span, Encoding::Utf16)?;
+                    let range = convert_range((start, end));
+
+                    // rendered by clients as faded out code.
+                    let d = ty::Diagnostic {
+                        range: range,
+                        message: "code is inactive due to `#[cfg]`".to_string(),
+                        severity: Some(ty::DiagnosticSeverity::Hint),
+                        tags: Some(vec![ty::DiagnosticTag::Unnecessary]),
+                        ..ty::Diagnostic::default()
+                    };
+
+                    out.push(d);
+                }
                 _ => {}
             }
         }
@@ -303,7 +303,10 @@ impl Workspace {
         let package_prefix = manifest.package_prefix.clone();
 
         self.reporter.clear();
-        let mut session = lang.into_session(package_prefix, &mut self.reporter, resolver)?;
+        let mut session = lang
+            .into_session(package_prefix, &mut self.reporter, resolver)?
+            .with_cfg(manifest.cfg())
+            .with_lints(manifest.lints());
 
         for s in &sources {
             let manifest::Source {
@@ -442,9 +445,11 @@ impl Workspace {
             content
         };
 
-        let file = match parser::parse(&mut loaded.diag, content.as_str()) {
-            Ok(file) => file,
-            Err(()) => {
+        // NB: symbols and completions are still provided for the parts of the file which could be
+        // parsed.
+        let file = match parser::parse_partial(&mut loaded.diag, content.as_str()) {
+            Some(file) => file,
+            None => {
                 return Ok(());
             }
         };
@@ -615,6 +620,18 @@ impl Workspace {
 
         match *decl {
             Type(ref ty) => {
+                for parent in &ty.parents {
+                    self.process_name(parent, current, loaded)?;
+
+                    let (_, span) = Spanned::borrow_pair(parent);
+                    let range = loaded.range(span)?;
+
+                    let content = &content[span.start..span.end];
+                    let completion = self.type_completion(current, content)?;
+
+                    loaded.completion_triggers.insert(range, completion);
+                }
+
                 for f in ty.fields() {
                     self.process_ty(current, loaded, content, &f.ty)?;
                 }
@@ -630,6 +647,12 @@ impl Workspace {
                 }
             }
             Enum(ref _en) => {}
+            Const(ref body) => {
+                self.process_ty(current, loaded, content, &body.ty)?;
+            }
+            Alias(ref body) => {
+                self.process_ty(current, loaded, content, &body.ty)?;
+            }
             Service(ref service) => {
                 for e in service.endpoints() {
                     for a in &e.arguments {
@@ -656,17 +679,30 @@ impl Workspace {
         let (ty, span) = Spanned::borrow_pair(ty);
 
         match *ty {
-            ast::Type::Array { ref inner } => {
+            ast::Type::Array { ref inner } | ast::Type::Set { ref inner } => {
                 self.process_ty(current, loaded, content, inner.as_ref())?;
             }
             ast::Type::Map { ref key, ref value } => {
                 self.process_ty(current, loaded, content, key.as_ref())?;
                 self.process_ty(current, loaded, content, value.as_ref())?;
             }
+            ast::Type::Union { ref alternatives } => {
+                for alternative in alternatives {
+                    self.process_ty(current, loaded, content, alternative)?;
+                }
+            }
             ref ty => {
                 // load jump-to definitions
-                if let ast::Type::Name { ref name } = *ty {
+                if let ast::Type::Name {
+                    ref name,
+                    ref arguments,
+                } = *ty
+                {
                     self.process_name(name, current, loaded)?;
+
+                    for argument in arguments {
+                        self.process_ty(current, loaded, content, argument)?;
+                    }
                 }
 
                 let (start, end) = loaded.diag.source.span_to_range(span, Encoding::Utf16)?;
@@ -28,6 +28,7 @@ pub fn match_keyword(content: &str) -> Option<Keyword> {
         "enum" => Keyword::Enum,
         "tuple" => Keyword::Tuple,
         "service" => Keyword::Service,
+        "const" => Keyword::Const,
         "use" => Keyword::Use,
         "as" => Keyword::As,
         "float" => Keyword::Float,
@@ -39,6 +40,11 @@ pub fn match_keyword(content: &str) -> Option<Keyword> {
         "boolean" => Keyword::Boolean,
         "string" => Keyword::String,
         "datetime" => Keyword::Datetime,
+        "date" => Keyword::Date,
+        "time" => Keyword::Time,
+        "duration" => Keyword::Duration,
+        "uuid" => Keyword::Uuid,
+        "decimal" => Keyword::Decimal,
         "bytes" => Keyword::Bytes,
         "stream" => Keyword::Stream,
         _ => return None,
@@ -446,6 +452,8 @@ impl<'input> Lexer<'input> {
                     '}' => Token::RightCurly,
                     '[' => Token::LeftBracket,
                     ']' => Token::RightBracket,
+                    '<' => Token::LeftAngle,
+                    '>' => Token::RightAngle,
                     '(' => Token::LeftParen,
                     ')' => Token::RightParen,
                     ';' => Token::SemiColon,
@@ -455,6 +463,7 @@ impl<'input> Lexer<'input> {
                     '?' => Token::QuestionMark,
                     '#' => Token::Hash,
                     '!' => Token::Bang,
+                    '|' => Token::Pipe,
                     '=' => Token::Equal,
                     '_' | 'a'..='z' => return Some(self.identifier(start)),
                     'A'..='Z' => return Some(self.type_identifier(start)),
@@ -578,6 +587,20 @@ pub mod tests {
         assert_eq!(expected, tokenize("foo::Bar.Baz").unwrap());
     }
 
+    #[test]
+    pub fn test_generic_name() {
+        let expected = vec![
+            (0, TypeIdentifier("Page".into()), 4),
+            (4, LeftAngle, 5),
+            (5, TypeIdentifier("Foo".into()), 8),
+            (8, Comma, 9),
+            (10, Keyword(String), 16),
+            (16, RightAngle, 17),
+        ];
+
+        assert_eq!(expected, tokenize("Page<Foo, string>").unwrap());
+    }
+
     #[test]
     pub fn test_strings() {
         let expected = vec![(0, QuotedString("foo\nbar".to_owned()), 10)];
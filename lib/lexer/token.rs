@@ -8,7 +8,11 @@ pub enum Keyword {
     As,
     Boolean,
     Bytes,
+    Const,
+    Date,
     Datetime,
+    Decimal,
+    Duration,
     Enum,
     Float,
     Double,
@@ -18,11 +22,13 @@ pub enum Keyword {
     Service,
     Stream,
     String,
+    Time,
     Tuple,
     Type,
     U32,
     U64,
     Use,
+    Uuid,
 }
 
 impl Keyword {
@@ -33,7 +39,11 @@ impl Keyword {
             Self::As => "_as",
             Self::Boolean => "_boolean",
             Self::Bytes => "_bytes",
+            Self::Const => "_const",
+            Self::Date => "_date",
             Self::Datetime => "_datetime",
+            Self::Decimal => "_decimal",
+            Self::Duration => "_duration",
             Self::Enum => "_enum",
             Self::Float => "_float",
             Self::Double => "_double",
@@ -43,11 +53,13 @@ impl Keyword {
             Self::Service => "_service",
             Self::Stream => "_stream",
             Self::String => "_string",
+            Self::Time => "_time",
             Self::Tuple => "_tuple",
             Self::Type => "_type",
             Self::U32 => "_u32",
             Self::U64 => "_u64",
             Self::Use => "_use",
+            Self::Uuid => "_uuid",
         }
     }
 
@@ -60,6 +72,7 @@ impl Keyword {
             Self::Enum => "enum",
             Self::Tuple => "tuple",
             Self::Service => "service",
+            Self::Const => "const",
             Self::Use => "use",
             Self::As => "as",
             Self::Float => "float",
@@ -71,6 +84,11 @@ impl Keyword {
             Self::Boolean => "boolean",
             Self::String => "string",
             Self::Datetime => "datetime",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::Duration => "duration",
+            Self::Uuid => "uuid",
+            Self::Decimal => "decimal",
             Self::Bytes => "bytes",
             Self::Stream => "stream",
         }
@@ -88,6 +106,8 @@ pub enum Token<'input> {
     RightCurly,
     LeftBracket,
     RightBracket,
+    LeftAngle,
+    RightAngle,
     LeftParen,
     RightParen,
     SemiColon,
@@ -99,6 +119,7 @@ pub enum Token<'input> {
     QuestionMark,
     Hash,
     Bang,
+    Pipe,
     RightArrow,
     CodeOpen,
     CodeClose,
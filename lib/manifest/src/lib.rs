@@ -12,7 +12,7 @@ use naming::Naming;
 use relative_path::{RelativePath, RelativePathBuf};
 use serde::Deserialize;
 use std::any::Any;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::io::Read;
 use std::path::{Path, PathBuf};
@@ -82,6 +82,11 @@ pub trait Lang: fmt::Debug {
         manifest: Manifest,
     ) -> Result<()>;
 
+    /// The language being implemented, used when evaluating `#[cfg(lang = "...")]`.
+    fn language(&self) -> Option<Language> {
+        None
+    }
+
     /// Comment the given string.
     fn comment(&self, _input: &str) -> Option<String> {
         None
@@ -313,6 +318,25 @@ impl Language {
 
         Some(language)
     }
+
+    /// The name of the language, as accepted by `parse`.
+    pub fn name(self) -> &'static str {
+        use self::Language::*;
+
+        match self {
+            Csharp => "csharp",
+            Dart => "dart",
+            Go => "go",
+            Java => "java",
+            Js => "js",
+            Json => "json",
+            OpenApi => "openapi",
+            Python => "python",
+            Reproto => "reproto",
+            Rust => "rust",
+            Swift => "swift",
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -523,6 +547,10 @@ pub struct Manifest {
     pub repository: Repository,
     /// Documentation settings.
     pub doc: Doc,
+    /// Features enabled for `#[cfg(feature = "...")]`.
+    pub features: Vec<String>,
+    /// Levels of lints, as configured in the `[lints]` table.
+    pub lints: trans::Lints,
 }
 
 impl Manifest {
@@ -574,6 +602,24 @@ impl Manifest {
         self.lang().unwrap_or_else(|| Box::new(NoLang))
     }
 
+    /// Configuration to evaluate `#[cfg(...)]` conditions against.
+    pub fn cfg(&self) -> trans::Cfg {
+        let lang = self
+            .lang
+            .as_ref()
+            .and_then(|l| l.language())
+            .map(Language::name);
+
+        trans::Cfg::new()
+            .with_lang(lang)
+            .with_features(&self.features)
+    }
+
+    /// Levels of lints to report diagnostics at.
+    pub fn lints(&self) -> trans::Lints {
+        self.lints.clone()
+    }
+
     /// Check if manifest has nothing to build.
     pub fn is_build_empty(&self) -> bool {
         if !self.files.as_ref().map(Vec::is_empty).unwrap_or(true) {
@@ -708,6 +754,25 @@ pub fn load_common_manifest(
         manifest.doc = doc;
     }
 
+    manifest
+        .features
+        .extend(take_field::<Vec<String>>(value, "features")?);
+
+    for (name, level) in take_field::<BTreeMap<String, String>>(value, "lints")? {
+        let level = trans::LintLevel::parse(&level).ok_or_else(|| {
+            format!(
+                "lints: {}: expected one of `allow`, `warn`, or `deny`, but got `{}`",
+                name, level
+            )
+        })?;
+
+        manifest.lints = manifest
+            .lints
+            .clone()
+            .with_level(&name, level)
+            .map_err(|e| format!("lints: {}", e.display()))?;
+    }
+
     return Ok(());
 
     /// Load and apply all repository-specific information.
@@ -889,6 +954,16 @@ mod tests {
         assert_eq!(None, manifest.files.map(|f| f.len()));
     }
 
+    #[test]
+    pub fn test_features() {
+        let manifest = include_manifest!("tests/features.reproto");
+
+        assert_eq!(
+            vec!["internal".to_string(), "debug".to_string()],
+            manifest.features
+        );
+    }
+
     #[test]
     pub fn test_repository() {
         let manifest = include_manifest!("tests/repository.reproto");
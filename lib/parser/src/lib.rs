@@ -6,6 +6,7 @@ mod utils;
 
 use core::errors::Result;
 use core::Diagnostics;
+use lalrpop_util::ParseError;
 use std::io::Read;
 use std::result;
 
@@ -20,79 +21,112 @@ where
 }
 
 /// Parse the given object.
+///
+/// Fails if the input contains any syntax errors, all of which are reported to `diag`.
 pub fn parse<'input>(
     diag: &mut Diagnostics,
     input: &'input str,
 ) -> result::Result<ast::File<'input>, ()> {
-    use lalrpop_util::ParseError::*;
-    use lexer::errors::Error::*;
+    match parse_file(diag, input) {
+        (Some(file), false) => Ok(file),
+        _ => Err(()),
+    }
+}
+
+/// Parse the given object, recovering from syntax errors at the boundaries of declarations and
+/// members.
+///
+/// All syntax errors are reported to `diag`, and the parts of the file which could be parsed are
+/// returned.
+/// Nothing is returned if the parser could not recover from an error.
+pub fn parse_partial<'input>(
+    diag: &mut Diagnostics,
+    input: &'input str,
+) -> Option<ast::File<'input>> {
+    parse_file(diag, input).0
+}
+
+/// Parse the given file.
+///
+/// Returns the parsed file, if any, and whether the parser had to recover from any errors.
+fn parse_file<'input>(
+    diag: &mut Diagnostics,
+    input: &'input str,
+) -> (Option<ast::File<'input>>, bool) {
+    let mut errors = Vec::new();
 
     let lexer = lexer::lex(input);
     let parser = parser::FileParser::new();
+    let result = parser.parse(&mut errors, lexer);
+
+    let recovered = !errors.is_empty();
+
+    for e in errors {
+        report(diag, e.error);
+    }
+
+    match result {
+        Ok(file) => (Some(file), recovered),
+        Err(e) => {
+            report(diag, e);
+            (None, recovered)
+        }
+    }
+}
 
-    match parser.parse(lexer) {
-        Ok(file) => Ok(file),
-        Err(e) => match e {
-            InvalidToken { location } => {
-                let span = (location, location);
-                diag.err(span, "syntax error");
-                Err(())
+/// Report the given parse error.
+fn report(diag: &mut Diagnostics, e: ParseError<usize, lexer::Token<'_>, lexer::Error>) {
+    use lalrpop_util::ParseError::*;
+    use lexer::errors::Error::*;
+
+    match e {
+        InvalidToken { location } => {
+            let span = (location, location);
+            diag.err(span, "syntax error");
+        }
+        ExtraToken {
+            token: (start, token, end),
+        } => {
+            diag.err((start, end), format!("extra token: {:?}", token));
+        }
+        UnrecognizedToken {
+            token: (start, token, end),
+            expected,
+        } => {
+            diag.err(
+                (start, end),
+                format!(
+                    "syntax error, got token {:?}, expected: {}",
+                    token,
+                    expected.join(", ")
+                ),
+            );
+        }
+        UnrecognizedEOF { location, expected } => {
+            diag.err(
+                (location, location),
+                format!("unexpected eof, expected: {}", expected.join(", ")),
+            );
+        }
+        User { error } => match error {
+            UnterminatedString { start } => {
+                diag.err((start, start), "unterminated string");
             }
-            ExtraToken {
-                token: (start, token, end),
-            } => {
-                diag.err((start, end), format!("extra token: {:?}", token));
-                Err(())
+            UnterminatedEscape { start } => {
+                diag.err((start, start), "unterminated escape sequence");
             }
-            UnrecognizedToken {
-                token: (start, token, end),
-                expected,
-            } => {
-                diag.err(
-                    (start, end),
-                    format!(
-                        "syntax error, got token {:?}, expected: {}",
-                        token,
-                        expected.join(", ")
-                    ),
-                );
-
-                return Err(());
+            InvalidEscape { pos, message } => {
+                diag.err((pos, pos), message);
             }
-            UnrecognizedEOF { location, expected } => {
-                diag.err(
-                    (location, location),
-                    format!("unexpected eof, expected: {}", expected.join(", ")),
-                );
-
-                return Err(());
+            UnterminatedCodeBlock { start } => {
+                diag.err((start, start), "unterminated code block");
+            }
+            InvalidNumber { pos, message } => {
+                diag.err((pos, pos), message);
+            }
+            Unexpected { pos } => {
+                diag.err((pos, pos), "unexpected input");
             }
-            User { error } => match error {
-                UnterminatedString { start } => {
-                    diag.err((start, start), "unterminated string");
-                    return Err(());
-                }
-                UnterminatedEscape { start } => {
-                    diag.err((start, start), "unterminated escape sequence");
-                    return Err(());
-                }
-                InvalidEscape { pos, message } => {
-                    diag.err((pos, pos), message);
-                    return Err(());
-                }
-                UnterminatedCodeBlock { start } => {
-                    diag.err((start, start), "unterminated code block");
-                    return Err(());
-                }
-                InvalidNumber { pos, message } => {
-                    diag.err((pos, pos), message);
-                    return Err(());
-                }
-                Unexpected { pos } => {
-                    diag.err((pos, pos), "unexpected input");
-                    return Err(());
-                }
-            },
         },
     }
 }
@@ -106,14 +140,18 @@ mod tests {
     /// Check that a parsed value equals expected.
     macro_rules! assert_value_eq {
         ($expected:expr, $input:expr) => {{
-            let v = parser::ValueParser::new().parse(parse($input)).unwrap();
+            let v = parser::ValueParser::new()
+                .parse(&mut Vec::new(), parse($input))
+                .unwrap();
             assert_eq!($expected, v);
         }};
     }
 
     macro_rules! assert_type {
         ($expected:expr, $input:expr) => {{
-            let v = parser::TypeParser::new().parse(parse($input)).unwrap();
+            let v = parser::TypeParser::new()
+                .parse(&mut Vec::new(), parse($input))
+                .unwrap();
             assert_eq!($expected, v);
         }};
     }
@@ -129,19 +167,19 @@ mod tests {
 
     fn parse_file(input: &'static str) -> File {
         parser::FileParser::new()
-            .parse(parse(input))
+            .parse(&mut Vec::new(), parse(input))
             .expect("bad file")
     }
 
     fn parse_member(input: &'static str) -> TypeMember {
         parser::TypeMemberParser::new()
-            .parse(parse(input))
+            .parse(&mut Vec::new(), parse(input))
             .expect("bad type member")
     }
 
     fn parse_type(input: &'static str) -> Type {
         parser::TypeParser::new()
-            .parse(parse(input))
+            .parse(&mut Vec::new(), parse(input))
             .expect("bad type")
     }
 
@@ -164,6 +202,19 @@ mod tests {
         panic!("Expected Type::Array(Type::String)");
     }
 
+    #[test]
+    fn test_set() {
+        let ty = parse_type("#{string}");
+
+        if let Type::Set { inner } = ty {
+            if let Type::String = *Spanned::borrow(inner.as_ref()) {
+                return;
+            }
+        }
+
+        panic!("Expected Type::Set(Type::String)");
+    }
+
     #[test]
     fn test_map() {
         let ty = parse_type("{string: u32}");
@@ -205,11 +256,55 @@ mod tests {
         assert_eq!(1, file.decls.len());
     }
 
+    #[test]
+    fn test_recover() {
+        let input = "type A { a string; b: u32; }\n\
+                     type B { x: ; }\n\
+                     tuple C { ?; }\n\
+                     type = ;}\n\
+                     type D {}";
+
+        let mut diag = Diagnostics::new(Source::empty("test"));
+        let file = super::parse_partial(&mut diag, input).expect("partial file");
+
+        let names = file
+            .decls
+            .iter()
+            .map(|d| d.name().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["A", "B", "C", "D"], names);
+
+        let errors = diag
+            .items
+            .iter()
+            .filter(|item| matches!(**item, Diagnostic::Error { .. }))
+            .count();
+
+        assert_eq!(3, errors);
+        assert!(super::parse(&mut diag, input).is_err());
+    }
+
     #[test]
     fn test_strings() {
         assert_value_eq!(Value::String("foo\nbar".to_owned()), "\"foo\\nbar\"");
     }
 
+    #[test]
+    fn test_arrays_and_objects() {
+        let ident = |id: &'static str| Spanned::new(Value::Identifier(id.into()), Span::empty());
+
+        assert_value_eq!(Value::Array(vec![ident("a"), ident("b")]), "[a, b,]");
+
+        assert_value_eq!(
+            Value::Object(vec![(
+                Spanned::new("X-Request-Id".to_string(), Span::empty()),
+                ident("request_id"),
+            )]),
+            "{\"X-Request-Id\": request_id}"
+        );
+    }
+
     #[test]
     fn test_numbers() {
         assert_value_eq!(Value::Number(1.into()), "1");
@@ -260,9 +355,44 @@ mod tests {
         assert_type!(Type::String, "string");
         assert_type!(
             Type::Name {
-                name: Spanned::new(c, Span::empty())
+                name: Spanned::new(c, Span::empty()),
+                arguments: vec![],
             },
             "Hello::World"
         );
+
+        let page = Name::Absolute {
+            prefix: None,
+            path: vec![Spanned::new("Page".into(), Span::empty())],
+        };
+
+        assert_type!(
+            Type::Name {
+                name: Spanned::new(page, Span::empty()),
+                arguments: vec![Spanned::new(Type::String, Span::empty())],
+            },
+            "Page<string>"
+        );
+    }
+
+    #[test]
+    fn test_union() {
+        let field = match parse_member("value: string | u64;") {
+            TypeMember::Field(field) => field,
+            _ => panic!("expected field"),
+        };
+
+        let alternatives = match *Spanned::borrow(&field.ty) {
+            Type::Union { ref alternatives } => alternatives
+                .iter()
+                .map(|a| Spanned::borrow(a).clone())
+                .collect::<Vec<_>>(),
+            _ => panic!("expected Type::Union"),
+        };
+
+        assert_eq!(
+            vec![Type::String, Type::Unsigned { size: 64 }],
+            alternatives
+        );
     }
 }
@@ -2,7 +2,10 @@ use self::Component::*;
 use self::Violation::*;
 use core::errors::Result;
 use core::flavored::*;
-use core::{Span, Spanned, Version};
+use core::{
+    BigInt, RpBytesEncoding, RpBytesType, RpNumberEncoding, RpNumberType, RpNumberValidate,
+    RpStringType, RpValidate, Span, Spanned, Version,
+};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -35,6 +38,8 @@ pub enum Violation {
     AddField(Component, Span),
     /// Variant added.
     AddVariant(Component, Span),
+    /// Sub-type added.
+    AddSubType(Component, Span),
     /// Field type was changed from one to another.
     FieldTypeChange(Component, RpType, Span, RpType, Span),
     /// Field name was changed from one to another.
@@ -43,6 +48,12 @@ pub enum Violation {
     VariantOrdinalChange(Component, String, Span, String, Span),
     /// Field made required.
     FieldRequiredChange(Component, Span, Span),
+    /// Validation rules of a field were tightened.
+    FieldValidateTightened(Component, Span, Span),
+    /// Encoding of a number field was changed.
+    FieldEncodingChange(Component, RpNumberEncoding, Span, RpNumberEncoding, Span),
+    /// Encoding of a bytes field was changed.
+    FieldBytesEncodingChange(Component, RpBytesEncoding, Span, RpBytesEncoding, Span),
     /// Required field added.
     AddRequiredField(Component, Span),
     /// Field modifier changed.
@@ -55,6 +66,10 @@ pub enum Violation {
     EndpointRequestChange(Component, Option<RpChannel>, Span, Option<RpChannel>, Span),
     /// Endpoint response type changed.
     EndpointResponseChange(Component, Option<RpChannel>, Span, Option<RpChannel>, Span),
+    /// Endpoint error response removed.
+    RemoveEndpointError(Component, u16, Span),
+    /// Endpoint error response type changed.
+    EndpointErrorChange(Component, u16, RpType, Span, RpType, Span),
 }
 
 fn fields<'a>(named: &RpNamed<'a>) -> Vec<&'a Spanned<RpField>> {
@@ -74,6 +89,27 @@ fn enum_variants<'a>(named: &'a RpNamed) -> Vec<RpVariantRef<'a>> {
     }
 }
 
+/// Check if the given declaration has an unknown variant or sub-type, which values added in a
+/// later version are decoded as.
+fn has_unknown(named: &RpNamed) -> bool {
+    match *named {
+        RpNamed::Enum(target) => target.unknown.is_some(),
+        RpNamed::Interface(target) => target.unknown.is_some(),
+        _ => false,
+    }
+}
+
+fn sub_types_to_map<'a>(named: &RpNamed<'a>) -> HashMap<RpName, &'a Spanned<RpSubType>> {
+    match *named {
+        RpNamed::Interface(target) => target
+            .sub_types
+            .iter()
+            .map(|s| (Spanned::borrow(&s.name).clone().localize(), s))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
 fn endpoints_to_map<'a>(named: &RpNamed<'a>) -> HashMap<&'a str, &'a Spanned<RpEndpoint>> {
     match *named {
         RpNamed::Service(target) => target.endpoints.iter().map(|e| (e.ident(), e)).collect(),
@@ -101,6 +137,122 @@ where
     storage
 }
 
+/// Type aliases declared in a file, by name.
+fn aliases_to_map<'a, I>(decls: I) -> HashMap<RpName, &'a RpType>
+where
+    I: IntoIterator<Item = &'a RpDecl>,
+{
+    let mut storage = HashMap::new();
+
+    for decl in decls {
+        for named in decl.to_named() {
+            if let RpNamed::Alias(alias) = named {
+                storage.insert(Spanned::borrow(&alias.name).clone().localize(), &alias.ty);
+            }
+        }
+    }
+
+    storage
+}
+
+/// Strip the validation rules, number encoding, and bytes encoding from the given type, since
+/// changes to them are checked separately.
+fn without_details(ty: RpType) -> RpType {
+    match ty {
+        RpType::String(..) => RpType::String(RpStringType::default()),
+        RpType::Number(number) => RpType::Number(RpNumberType {
+            kind: number.kind,
+            validate: RpNumberValidate::default(),
+            encoding: RpNumberEncoding::default(),
+        }),
+        RpType::Bytes(..) => RpType::Bytes(RpBytesType::default()),
+        ty => ty,
+    }
+}
+
+/// Check if the validation rules have been tightened, which would cause values that used to be
+/// valid to be rejected.
+fn is_tightened(from: &RpValidate, to: &RpValidate) -> bool {
+    return match (from, to) {
+        (_, RpValidate::None) => false,
+        (RpValidate::String(from), RpValidate::String(to)) => {
+            (to.pattern.is_some() && to.pattern != from.pattern)
+                || bound(from.min_length, to.min_length, |a, b| a > b)
+                || bound(from.max_length, to.max_length, |a, b| a < b)
+        }
+        (RpValidate::Number(from), RpValidate::Number(to)) => {
+            let from_lower = number_bound(from.min.as_ref(), from.exclusive_min.as_ref());
+            let to_lower = number_bound(to.min.as_ref(), to.exclusive_min.as_ref());
+            let from_upper = number_bound(from.max.as_ref(), from.exclusive_max.as_ref());
+            let to_upper = number_bound(to.max.as_ref(), to.exclusive_max.as_ref());
+
+            bound(from_lower, to_lower, |a, b| (&a.0, a.1) > (&b.0, b.1))
+                || bound(from_upper, to_upper, |a, b| (&a.0, !a.1) < (&b.0, !b.1))
+        }
+        (_, to) => !to.is_empty(),
+    };
+
+    /// Test if a bound has been added, or tightened according to the given comparison.
+    fn bound<T>(from: Option<T>, to: Option<T>, tighter: impl Fn(&T, &T) -> bool) -> bool {
+        match (from, to) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(from), Some(to)) => tighter(&to, &from),
+        }
+    }
+
+    /// A numeric bound, and if it is exclusive.
+    fn number_bound(
+        inclusive: Option<&RpNumber>,
+        exclusive: Option<&RpNumber>,
+    ) -> Option<(BigInt, bool)> {
+        match (inclusive, exclusive) {
+            (Some(n), _) => n.to_bigint().map(|n| (n.clone(), false)),
+            (None, Some(n)) => n.to_bigint().map(|n| (n.clone(), true)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Resolve all references to aliases in the given type.
+///
+/// This causes a change to the target of an alias to be treated as a change to every field using
+/// it.
+fn resolve(aliases: &HashMap<RpName, &RpType>, ty: RpType, stack: &mut Vec<RpName>) -> RpType {
+    match ty {
+        RpType::Name { name, arguments } => {
+            let arguments = arguments
+                .into_iter()
+                .map(|a| resolve(aliases, a, stack))
+                .collect();
+
+            let key = Spanned::borrow(&name).clone().localize();
+
+            match aliases.get(&key) {
+                // recursive aliases are left as-is.
+                Some(target) if !stack.contains(&key) => {
+                    stack.push(key);
+                    let out = resolve(aliases, (*target).clone().localize(), stack);
+                    stack.pop();
+                    out
+                }
+                _ => RpType::Name { name, arguments },
+            }
+        }
+        RpType::Array { inner } => RpType::Array {
+            inner: Box::new(resolve(aliases, *inner, stack)),
+        },
+        RpType::Set { inner } => RpType::Set {
+            inner: Box::new(resolve(aliases, *inner, stack)),
+        },
+        RpType::Map { key, value } => RpType::Map {
+            key: Box::new(resolve(aliases, *key, stack)),
+            value: Box::new(resolve(aliases, *value, stack)),
+        },
+        ty => ty,
+    }
+}
+
 fn variants_to_map<'a, I>(variants: I) -> HashMap<RpName, RpVariantRef<'a>>
 where
     I: IntoIterator<Item = RpVariantRef<'a>>,
@@ -202,9 +354,60 @@ fn check_endpoint_type(
         EndpointResponseChange,
     )?;
 
+    let mut to_errors = to_endpoint
+        .http
+        .errors
+        .iter()
+        .map(|e| (e.status, e))
+        .collect::<HashMap<_, _>>();
+
+    for from_error in &from_endpoint.http.errors {
+        let to_error = match to_errors.remove(&from_error.status) {
+            Some(to_error) => to_error,
+            None => {
+                violations.push(RemoveEndpointError(
+                    component.clone(),
+                    from_error.status,
+                    from_error.span(),
+                ));
+
+                continue;
+            }
+        };
+
+        let from_ty = from_error.ty.clone().localize();
+        let to_ty = to_error.ty.clone().localize();
+
+        if from_ty != to_ty {
+            violations.push(EndpointErrorChange(
+                component.clone(),
+                from_error.status,
+                from_ty,
+                from_error.span(),
+                to_ty,
+                to_error.span(),
+            ));
+        }
+    }
+
     Ok(())
 }
 
+/// Aliases declared in the files being compared.
+struct Aliases<'a> {
+    from: HashMap<RpName, &'a RpType>,
+    to: HashMap<RpName, &'a RpType>,
+}
+
+impl<'a> Aliases<'a> {
+    fn new(from: &'a RpFile, to: &'a RpFile) -> Self {
+        Self {
+            from: aliases_to_map(&from.decls),
+            to: aliases_to_map(&to.decls),
+        }
+    }
+}
+
 fn common_check_variant(
     component: Component,
     violations: &mut Vec<Violation>,
@@ -227,10 +430,14 @@ fn common_check_variant(
 fn common_check_field(
     component: Component,
     violations: &mut Vec<Violation>,
+    aliases: &Aliases,
     from_field: &Spanned<RpField>,
     to_field: &Spanned<RpField>,
 ) -> Result<()> {
-    if to_field.ty.clone().localize() != from_field.ty.clone().localize() {
+    let from_ty = from_field.ty.clone().localize();
+    let to_ty = to_field.ty.clone().localize();
+
+    if without_details(to_ty.clone()) != without_details(from_ty.clone()) {
         violations.push(FieldTypeChange(
             component.clone(),
             from_field.ty.clone(),
@@ -238,6 +445,41 @@ fn common_check_field(
             to_field.ty.clone(),
             to_field.span().into(),
         ));
+    } else {
+        let from_ty = resolve(&aliases.from, from_ty, &mut Vec::new());
+        let to_ty = resolve(&aliases.to, to_ty, &mut Vec::new());
+
+        if without_details(to_ty.clone()) != without_details(from_ty.clone()) {
+            violations.push(FieldTypeChange(
+                component.clone(),
+                from_ty,
+                from_field.span(),
+                to_ty,
+                to_field.span(),
+            ));
+        } else if from_ty.number_encoding() != to_ty.number_encoding() {
+            violations.push(FieldEncodingChange(
+                component.clone(),
+                from_ty.number_encoding(),
+                from_field.span(),
+                to_ty.number_encoding(),
+                to_field.span(),
+            ));
+        } else if from_ty.bytes_encoding() != to_ty.bytes_encoding() {
+            violations.push(FieldBytesEncodingChange(
+                component.clone(),
+                from_ty.bytes_encoding(),
+                from_field.span(),
+                to_ty.bytes_encoding(),
+                to_field.span(),
+            ));
+        } else if is_tightened(&from_ty.validate(), &to_ty.validate()) {
+            violations.push(FieldValidateTightened(
+                component.clone(),
+                from_field.span(),
+                to_field.span(),
+            ));
+        }
     }
 
     // not permitted to rename fields.
@@ -258,6 +500,7 @@ fn common_check_field(
 fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
     let mut violations = Vec::new();
 
+    let aliases = Aliases::new(from, to);
     let from_storage = decls_to_map(&from.decls);
     let mut to_storage = decls_to_map(&to.decls);
 
@@ -268,7 +511,7 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
             for (name, from_field) in from_fields.into_iter() {
                 if let Some(to_field) = to_fields.remove(&name) {
-                    check_field(&mut violations, from_field, to_field)?;
+                    check_field(&mut violations, &aliases, from_field, to_field)?;
                 } else {
                     violations.push(RemoveField(Minor, from_field.span().into()));
                 }
@@ -292,6 +535,25 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
                 }
             }
 
+            let from_sub_types = sub_types_to_map(&from_named);
+            let mut to_sub_types = sub_types_to_map(&to_named);
+
+            for name in from_sub_types.keys() {
+                to_sub_types.remove(name);
+            }
+
+            // added variants and sub-types can't be decoded by older clients, unless they are
+            // decoded as the unknown variant or sub-type.
+            if !has_unknown(&to_named) {
+                for (_, to_variant) in to_variants.into_iter() {
+                    violations.push(AddVariant(Minor, to_variant.span));
+                }
+
+                for (_, to_sub_type) in to_sub_types.into_iter() {
+                    violations.push(AddSubType(Minor, to_sub_type.span()));
+                }
+            }
+
             let from_endpoints = endpoints_to_map(&from_named);
             let mut to_endpoints = endpoints_to_map(&to_named);
 
@@ -311,10 +573,11 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
     fn check_field(
         violations: &mut Vec<Violation>,
+        aliases: &Aliases,
         from_field: &Spanned<RpField>,
         to_field: &Spanned<RpField>,
     ) -> Result<()> {
-        common_check_field(Minor, violations, from_field, to_field)?;
+        common_check_field(Minor, violations, aliases, from_field, to_field)?;
 
         // Minor patch may make fields optional, but not required.
         if from_field.is_optional() && to_field.is_required() {
@@ -350,6 +613,7 @@ fn check_minor(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
     let mut violations = Vec::new();
 
+    let aliases = Aliases::new(from, to);
     let from_storage = decls_to_map(&from.decls);
     let mut to_storage = decls_to_map(&to.decls);
 
@@ -360,7 +624,7 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
             for (name, from_field) in from_fields.into_iter() {
                 if let Some(to_field) = to_fields.remove(&name) {
-                    check_field(&mut violations, from_field, to_field)?;
+                    check_field(&mut violations, &aliases, from_field, to_field)?;
                 } else {
                     violations.push(RemoveField(Patch, from_field.span().into()));
                 }
@@ -415,10 +679,11 @@ fn check_patch(from: &RpFile, to: &RpFile) -> Result<Vec<Violation>> {
 
     fn check_field(
         violations: &mut Vec<Violation>,
+        aliases: &Aliases,
         from_field: &Spanned<RpField>,
         to_field: &Spanned<RpField>,
     ) -> Result<()> {
-        common_check_field(Patch, violations, from_field, to_field)?;
+        common_check_field(Patch, violations, aliases, from_field, to_field)?;
 
         if to_field.required != from_field.required {
             violations.push(FieldModifierChange(
@@ -466,3 +731,282 @@ pub fn check(from: (&Version, &RpFile), to: (&Version, &RpFile)) -> Result<Vec<V
 
     Ok(vec![])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{CoreFlavor, EmptyResolver, RpPackage, RpVersionedPackage, Source};
+
+    fn load(input: &str) -> RpFile {
+        let mut reporter = Vec::new();
+        let mut resolver = EmptyResolver;
+
+        let mut session = trans::Session::<CoreFlavor>::new(None, &mut reporter, &mut resolver)
+            .expect("failed to build session");
+
+        let package = RpVersionedPackage::new(RpPackage::parse("test"), None);
+        let source = Source::bytes("test", input.as_bytes().to_vec());
+        session
+            .load_source(source, &package)
+            .expect("failed to load source")
+    }
+
+    fn minor(from: &str, to: &str) -> Vec<Violation> {
+        check_minor(&load(from), &load(to)).expect("failed to check")
+    }
+
+    fn patch(from: &str, to: &str) -> Vec<Violation> {
+        check_patch(&load(from), &load(to)).expect("failed to check")
+    }
+
+    #[test]
+    fn test_no_change() {
+        let input = "type Foo { a: string; b?: u32; }";
+        assert!(minor(input, input).is_empty());
+        assert!(patch(input, input).is_empty());
+    }
+
+    #[test]
+    fn test_added_variant() {
+        let from = "enum Color as string { Red; }";
+        let to = "enum Color as string { Red; Green; }";
+
+        assert!(matches!(
+            minor(from, to).as_slice(),
+            [AddVariant(Minor, ..)]
+        ));
+        assert!(matches!(
+            patch(from, to).as_slice(),
+            [AddVariant(Patch, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_added_variant_with_unknown() {
+        let from = "enum Color as string { Red; #[unknown] Other; }";
+        let to = "enum Color as string { Red; Green; #[unknown] Other; }";
+
+        assert!(minor(from, to).is_empty());
+    }
+
+    #[test]
+    fn test_added_sub_type() {
+        let from = "interface Shape { Circle; }";
+        let to = "interface Shape { Circle; Square; }";
+
+        assert!(matches!(
+            minor(from, to).as_slice(),
+            [AddSubType(Minor, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_added_sub_type_with_unknown() {
+        let from = "interface Shape { Circle; #[unknown] Other; }";
+        let to = "interface Shape { Circle; Square; #[unknown] Other; }";
+
+        assert!(minor(from, to).is_empty());
+    }
+
+    #[test]
+    fn test_removed_variant() {
+        let from = "enum Color as string { Red; Green; }";
+        let to = "enum Color as string { Red; }";
+
+        assert!(matches!(
+            minor(from, to).as_slice(),
+            [RemoveVariant(Minor, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_added_required_field() {
+        let from = "type Foo { a: string; }";
+
+        assert!(minor(from, "type Foo { a: string; b?: string; }").is_empty());
+
+        assert!(matches!(
+            minor(from, "type Foo { a: string; b: string; }").as_slice(),
+            [AddRequiredField(Minor, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_alias_resolution() {
+        let from = "type Id = string; type Foo { id?: Id; }";
+
+        // changing the target of an alias changes every field using it.
+        assert!(matches!(
+            minor(from, "type Id = u64; type Foo { id?: Id; }").as_slice(),
+            [FieldTypeChange(Minor, ..)]
+        ));
+
+        // recursive aliases don't loop forever.
+        let from = "type A = [A]; type Foo { a?: A; }";
+        assert!(minor(from, from).is_empty());
+    }
+
+    fn field_type(input: &str) -> RpType {
+        let file = load(input);
+
+        for decl in &file.decls {
+            if let RpDecl::Type(body) = decl {
+                return body.fields[0].ty.clone().localize();
+            }
+        }
+
+        panic!("no type declaration in: {}", input);
+    }
+
+    #[test]
+    fn test_is_tightened() {
+        fn validate(input: &str) -> RpValidate {
+            field_type(input).validate()
+        }
+
+        let none = validate("type Foo { a: string; }");
+        let pattern = validate("type Foo { #[validate(pattern = \"[a-z]+\")] a: string; }");
+        let min_1 = validate("type Foo { #[validate(min_length = 1)] a: string; }");
+        let min_2 = validate("type Foo { #[validate(min_length = 2)] a: string; }");
+
+        assert!(!is_tightened(&none, &none));
+        assert!(!is_tightened(&pattern, &none));
+        assert!(!is_tightened(&pattern, &pattern));
+        assert!(is_tightened(&none, &pattern));
+        assert!(is_tightened(&min_1, &min_2));
+        assert!(!is_tightened(&min_2, &min_1));
+
+        let max_10 = validate("type Foo { #[validate(max = 10)] a: u32; }");
+        let max_20 = validate("type Foo { #[validate(max = 20)] a: u32; }");
+        let exclusive_max_10 = validate("type Foo { #[validate(exclusive_max = 10)] a: u32; }");
+
+        assert!(is_tightened(&max_20, &max_10));
+        assert!(!is_tightened(&max_10, &max_20));
+        assert!(is_tightened(&max_10, &exclusive_max_10));
+        assert!(!is_tightened(&exclusive_max_10, &max_10));
+    }
+
+    #[test]
+    fn test_validate_tightened() {
+        assert!(matches!(
+            minor(
+                "type Foo { a?: u64; }",
+                "type Foo { #[validate(min = 1)] a?: u64; }"
+            )
+            .as_slice(),
+            [FieldValidateTightened(Minor, ..)]
+        ));
+
+        assert!(matches!(
+            minor(
+                "type Id = string; type Foo { id?: Id; }",
+                "#[validate(max_length = 16)] type Id = string; type Foo { id?: Id; }"
+            )
+            .as_slice(),
+            [FieldValidateTightened(Minor, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_without_details() {
+        let from = field_type("type Foo { #[validate(min_length = 1)] a: string; }");
+        let to = field_type("type Foo { a: string; }");
+        assert_ne!(from, to);
+        assert_eq!(without_details(from), without_details(to));
+
+        let from = field_type("type Foo { #[format(string)] a: u64; }");
+        let to = field_type("type Foo { a: u64; }");
+        assert_ne!(from, to);
+        assert_eq!(without_details(from), without_details(to));
+
+        let from = field_type("type Foo { a: u64; }");
+        let to = field_type("type Foo { a: u32; }");
+        assert_ne!(without_details(from), without_details(to));
+    }
+
+    #[test]
+    fn test_number_encoding_change() {
+        assert!(matches!(
+            minor(
+                "type Foo { a?: u64; }",
+                "type Foo { #[format(string)] a?: u64; }"
+            )
+            .as_slice(),
+            [FieldEncodingChange(Minor, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_bytes_encoding_change() {
+        let from = field_type("type Foo { #[format(bytes, encoding = \"hex\")] a: bytes; }");
+        let to = field_type("type Foo { a: bytes; }");
+        assert_ne!(from, to);
+        assert_eq!(without_details(from), without_details(to));
+
+        assert!(matches!(
+            minor(
+                "type Foo { a?: bytes; }",
+                "type Foo { #[format(bytes, encoding = \"hex\")] a?: bytes; }"
+            )
+            .as_slice(),
+            [FieldBytesEncodingChange(Minor, ..)]
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_errors() {
+        let from = r#"
+            type NotFound { message: string; }
+            type Gone { message: string; }
+            service Foo {
+                #[http(path = "/foo", errors = {"404": NotFound})]
+                get() -> string;
+            }
+        "#;
+
+        assert!(minor(from, from).is_empty());
+
+        let removed = r#"
+            type NotFound { message: string; }
+            type Gone { message: string; }
+            service Foo {
+                #[http(path = "/foo")]
+                get() -> string;
+            }
+        "#;
+
+        assert!(matches!(
+            minor(from, removed).as_slice(),
+            [RemoveEndpointError(Minor, 404, ..)]
+        ));
+
+        let changed = r#"
+            type NotFound { message: string; }
+            type Gone { message: string; }
+            service Foo {
+                #[http(path = "/foo", errors = {"404": Gone})]
+                get() -> string;
+            }
+        "#;
+
+        assert!(matches!(
+            minor(from, changed).as_slice(),
+            [EndpointErrorChange(Minor, 404, ..)]
+        ));
+
+        let added = r#"
+            type NotFound { message: string; }
+            type Gone { message: string; }
+            service Foo {
+                #[http(path = "/foo", errors = {"404": NotFound, "410": Gone})]
+                get() -> string;
+            }
+        "#;
+
+        assert!(minor(from, added).is_empty());
+        assert!(matches!(
+            patch(added, from).as_slice(),
+            [RemoveEndpointError(Patch, 410, ..)]
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+//! Inlining of type aliases.
+//!
+//! Transparent aliases are always replaced with the type that they are an alias for. Newtype
+//! aliases are only replaced for backends which can't generate a wrapper type for them.
+
+use core::{
+    CoreFlavor, Diagnostics, RpDecl, RpEndpoint, RpName, RpNamed, RpPathPart, RpType, Span, Spanned,
+};
+use linked_hash_map::LinkedHashMap;
+use std::collections::HashMap;
+use std::result;
+
+/// All aliases that should be inlined.
+pub struct Aliases {
+    aliases: HashMap<RpName<CoreFlavor>, RpType<CoreFlavor>>,
+}
+
+impl Aliases {
+    /// Collect all aliases to inline from the given declarations.
+    ///
+    /// If `newtypes` is set, newtype aliases are collected as well.
+    pub fn new<'a, I>(decls: I, newtypes: bool) -> Self
+    where
+        I: IntoIterator<Item = &'a RpDecl<CoreFlavor>>,
+    {
+        let mut out = HashMap::new();
+
+        for decl in decls {
+            for named in decl.to_named() {
+                if let RpNamed::Alias(body) = named {
+                    if newtypes || !body.newtype {
+                        let name = Spanned::borrow(&body.name).clone().without_prefix();
+                        out.insert(name, body.ty.clone());
+                    }
+                }
+            }
+        }
+
+        Aliases { aliases: out }
+    }
+
+    /// Check if there are no aliases to inline.
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// Check if the given declaration is an alias which is inlined.
+    fn is_inlined(&self, decl: &RpDecl<CoreFlavor>) -> bool {
+        match *decl {
+            RpDecl::Alias(ref body) => {
+                let name = Spanned::borrow(&body.name).clone().without_prefix();
+                self.aliases.contains_key(&name)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Inline all aliases in the declarations of a single package.
+///
+/// Declarations of inlined aliases are removed.
+pub fn inline(
+    diag: &mut Diagnostics,
+    aliases: &Aliases,
+    decls: &mut Vec<RpDecl<CoreFlavor>>,
+    decl_idents: &mut LinkedHashMap<String, usize>,
+) -> result::Result<(), ()> {
+    let mut inline = Inline {
+        aliases,
+        stack: Vec::new(),
+    };
+
+    inline.decls(diag, decls, decl_idents)
+}
+
+struct Inline<'a> {
+    aliases: &'a Aliases,
+    /// Aliases which are currently being inlined, used to detect recursive aliases.
+    stack: Vec<RpName<CoreFlavor>>,
+}
+
+impl<'a> Inline<'a> {
+    /// Inline aliases in the given declarations, and remove the declarations of inlined aliases.
+    fn decls(
+        &mut self,
+        diag: &mut Diagnostics,
+        decls: &mut Vec<RpDecl<CoreFlavor>>,
+        decl_idents: &mut LinkedHashMap<String, usize>,
+    ) -> result::Result<(), ()> {
+        for decl in decls.iter_mut() {
+            self.decl(diag, decl)?;
+        }
+
+        let aliases = self.aliases;
+
+        if decls.iter().any(|d| aliases.is_inlined(d)) {
+            decls.retain(|d| !aliases.is_inlined(d));
+
+            *decl_idents = decls
+                .iter()
+                .enumerate()
+                .map(|(i, d)| (d.ident().to_string(), i))
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    fn decl(
+        &mut self,
+        diag: &mut Diagnostics,
+        decl: &mut RpDecl<CoreFlavor>,
+    ) -> result::Result<(), ()> {
+        match *decl {
+            RpDecl::Type(ref mut body) => {
+                let body = &mut **body;
+
+                for f in &mut body.fields {
+                    self.ty(diag, &mut f.ty, None)?;
+                }
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Tuple(ref mut body) => {
+                let body = &mut **body;
+
+                for f in &mut body.fields {
+                    self.ty(diag, &mut f.ty, None)?;
+                }
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Interface(ref mut body) => {
+                let body = &mut **body;
+
+                for f in &mut body.fields {
+                    self.ty(diag, &mut f.ty, None)?;
+                }
+
+                for sub_type in &mut body.sub_types {
+                    let sub_type = &mut **sub_type;
+
+                    for f in &mut sub_type.fields {
+                        self.ty(diag, &mut f.ty, None)?;
+                    }
+
+                    self.decls(diag, &mut sub_type.decls, &mut sub_type.decl_idents)?;
+                }
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Service(ref mut body) => {
+                let body = &mut **body;
+
+                for e in &mut body.endpoints {
+                    self.endpoint(diag, e)?;
+                }
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Enum(ref mut body) => {
+                let body = &mut **body;
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Const(ref mut body) => {
+                let body = &mut **body;
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Alias(ref mut body) => {
+                let body = &mut **body;
+
+                self.ty(diag, &mut body.ty, None)?;
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+            RpDecl::Union(ref mut body) => {
+                let body = &mut **body;
+
+                for v in &mut body.variants {
+                    self.ty(diag, &mut v.ty, None)?;
+                }
+
+                self.decls(diag, &mut body.decls, &mut body.decl_idents)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn endpoint(
+        &mut self,
+        diag: &mut Diagnostics,
+        e: &mut RpEndpoint<CoreFlavor>,
+    ) -> result::Result<(), ()> {
+        let mut types = Vec::new();
+
+        types.extend(e.arguments.iter_mut().map(|a| a.channel.ty_mut()));
+        types.extend(e.request.iter_mut().map(|a| a.channel.ty_mut()));
+        types.extend(e.response.iter_mut().map(|r| r.ty_mut()));
+        types.extend(e.http.body.iter_mut().map(|a| a.channel.ty_mut()));
+        types.extend(
+            e.http
+                .query
+                .iter_mut()
+                .chain(e.http.headers.iter_mut())
+                .map(|p| p.argument.channel.ty_mut()),
+        );
+        types.extend(e.http.errors.iter_mut().map(|e| &mut e.ty));
+
+        if let Some(path) = e.http.path.as_mut() {
+            for part in path.steps.iter_mut().flat_map(|s| s.parts.iter_mut()) {
+                if let RpPathPart::Variable(ref mut var) = *part {
+                    types.push(var.channel.ty_mut());
+                }
+            }
+        }
+
+        for ty in types {
+            self.ty(diag, ty, None)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace any references to inlined aliases in the given type.
+    ///
+    /// `span` is the position of the outermost reference, which is the only one guaranteed to be
+    /// part of the package being processed.
+    fn ty(
+        &mut self,
+        diag: &mut Diagnostics,
+        ty: &mut RpType<CoreFlavor>,
+        span: Option<Span>,
+    ) -> result::Result<(), ()> {
+        let (target, key, span) = match *ty {
+            RpType::Name {
+                ref name,
+                ref mut arguments,
+            } => {
+                for a in arguments.iter_mut() {
+                    self.ty(diag, a, span)?;
+                }
+
+                let key = Spanned::borrow(name).clone().without_prefix();
+
+                let target = match self.aliases.aliases.get(&key) {
+                    Some(target) => target,
+                    None => return Ok(()),
+                };
+
+                let span = span.unwrap_or_else(|| name.span());
+
+                if self.stack.contains(&key) {
+                    diag.err(span, format!("`{}` is a recursive alias", key));
+                    return Err(());
+                }
+
+                let target = with_prefix(target.clone(), name.prefix.as_ref(), &key);
+                (target, key, span)
+            }
+            RpType::Array { ref mut inner } | RpType::Set { ref mut inner } => {
+                return self.ty(diag, inner, span)
+            }
+            RpType::Map {
+                ref mut key,
+                ref mut value,
+            } => {
+                self.ty(diag, key, span)?;
+                return self.ty(diag, value, span);
+            }
+            RpType::Union {
+                ref mut alternatives,
+            } => {
+                for a in alternatives.iter_mut() {
+                    self.ty(diag, a, span)?;
+                }
+
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+
+        let mut target = target;
+
+        self.stack.push(key);
+        let result = self.ty(diag, &mut target, Some(span));
+        self.stack.pop();
+        result?;
+
+        *ty = target;
+        Ok(())
+    }
+}
+
+/// Names local to an alias must be imported through the same prefix as the alias itself.
+fn with_prefix(
+    ty: RpType<CoreFlavor>,
+    prefix: Option<&Spanned<String>>,
+    alias: &RpName<CoreFlavor>,
+) -> RpType<CoreFlavor> {
+    match ty {
+        RpType::Name {
+            mut name,
+            arguments,
+        } => {
+            if name.prefix.is_none() && name.package == alias.package {
+                name.prefix = prefix.cloned();
+            }
+
+            RpType::Name {
+                name,
+                arguments: arguments
+                    .into_iter()
+                    .map(|a| with_prefix(a, prefix, alias))
+                    .collect(),
+            }
+        }
+        RpType::Array { inner } => RpType::Array {
+            inner: Box::new(with_prefix(*inner, prefix, alias)),
+        },
+        RpType::Set { inner } => RpType::Set {
+            inner: Box::new(with_prefix(*inner, prefix, alias)),
+        },
+        RpType::Map { key, value } => RpType::Map {
+            key: Box::new(with_prefix(*key, prefix, alias)),
+            value: Box::new(with_prefix(*value, prefix, alias)),
+        },
+        RpType::Union { alternatives } => RpType::Union {
+            alternatives: alternatives
+                .into_iter()
+                .map(|a| with_prefix(a, prefix, alias))
+                .collect(),
+        },
+        ty => ty,
+    }
+}
@@ -5,8 +5,13 @@ use crate::into_model::IntoModel;
 use crate::scope::Scope;
 use core::errors::Error;
 use core::flavored::*;
-use core::{Diagnostics, Import, RpStringValidate, Span, Spanned, Version, WithSpan};
+use core::{
+    Diagnostics, Import, Mime, RpBytesEncoding, RpDeprecated, RpNumber, RpNumberEncoding,
+    RpNumberKind, RpNumberValidate, RpStringValidate, Span, Spanned, Version, WithSpan,
+};
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
 
 /// `#![feature(..)]` attributes.
 pub fn features<'s, I>(
@@ -115,6 +120,38 @@ pub fn reserved(
     Ok(reserved)
 }
 
+/// `#[deprecated]` or `#[deprecated(since = "..", note = "..")]` attribute.
+pub fn deprecated(
+    diag: &mut Diagnostics,
+    attributes: &mut Attributes,
+) -> Result<Option<RpDeprecated>, ()> {
+    if attributes.take_word("deprecated") {
+        return Ok(Some(RpDeprecated::default()));
+    }
+
+    let selection = match attributes.take_selection("deprecated") {
+        Some(selection) => selection,
+        None => return Ok(None),
+    };
+
+    let (mut selection, _) = Spanned::take_pair(selection);
+
+    let mut deprecated = RpDeprecated::default();
+
+    if let Some(since) = selection.take("since") {
+        let (since, span) = Spanned::take_pair(since);
+        deprecated.since = Some(since.into_string().with_span(diag, span)?);
+    }
+
+    if let Some(note) = selection.take("note") {
+        let (note, span) = Spanned::take_pair(note);
+        deprecated.note = Some(note.into_string().with_span(diag, span)?);
+    }
+
+    check_selection!(diag, selection);
+    Ok(Some(deprecated))
+}
+
 /// `#[http(..)]` attribute for endpoints.
 pub fn endpoint_http<I>(
     diag: &mut Diagnostics,
@@ -150,30 +187,88 @@ where
         http.method = Some(parse_method(diag, method)?);
     }
 
-    if let Some(accept) = selection.take("accept") {
-        let (accept, span) = Spanned::take_pair(accept);
+    if let Some(query) = selection.take("query") {
+        http.query = parse_parameters(diag, query, arguments, &mut args)?;
+    }
+
+    if let Some(headers) = selection.take("headers") {
+        http.headers = parse_parameters(diag, headers, arguments, &mut args)?;
+    }
+
+    if let Some(status) = selection.take("status") {
+        let (status, span) = Spanned::take_pair(status);
+        let status = status.as_number().with_span(diag, span)?;
 
-        let a = accept.as_string().with_span(diag, span)?;
+        let status = status
+            .to_u32()
+            .filter(|_| status.to_bigint().is_some())
+            .and_then(|s| u16::try_from(s).ok());
+
+        http.status = Some(check_status(diag, status, span, 200..=299)?);
+    }
 
-        let accept = match a {
-            "application/json" => RpAccept::Json,
-            "text/plain" => RpAccept::Text,
+    if let Some(errors) = selection.take("errors") {
+        http.errors = parse_errors(diag, errors)?;
+    }
+
+    let accept = selection.take("accept");
+
+    if let Some(stream) = selection.take("stream") {
+        let (stream, span) = Spanned::take_pair(stream);
+
+        http.stream = Some(match stream.as_string().with_span(diag, span)? {
+            "sse" => RpHttpStream::Sse,
+            "ndjson" => RpHttpStream::NdJson,
             _ => {
-                diag.err(span, "unsupported media type");
+                diag.err(span, "expected `\"sse\"` or `\"ndjson\"`");
                 return Err(());
             }
-        };
+        });
+
+        match response {
+            Some(response) if response.is_streaming() => {}
+            _ => {
+                diag.err(
+                    span,
+                    "only streaming responses, like `stream T`, can be framed",
+                );
+                return Err(());
+            }
+        }
+
+        if let Some(ref accept) = accept {
+            diag.err(accept.span(), "media type is determined by the framing");
+            diag.info(span, "framing specified here");
+            return Err(());
+        }
+    }
+
+    if let Some(accept) = accept {
+        let accept = parse_media_type(diag, accept)?;
+
+        if let Some(response) = response {
+            http_verify_body(diag, &accept, response, false)?;
+        }
 
-        let accept = Spanned::new(accept, span);
-        http_verify_accept(diag, &accept, response)?;
         http.accept = Spanned::take(accept);
     }
 
+    let content_type = match selection.take("content_type") {
+        Some(content_type) => Some(parse_media_type(diag, content_type)?),
+        None => None,
+    };
+
     // All arguments used, no request body.
     if args.is_empty() {
         *request = None;
     }
 
+    // The first argument is the body of the request, unless it is bound elsewhere.
+    let request_bound = request
+        .as_ref()
+        .map(|r| !args.contains_key(r.ident()))
+        .unwrap_or(false);
+
     // Assert that all arguments are used somehow.
     if !args.is_empty() {
         for arg in args.values() {
@@ -187,6 +282,14 @@ where
                 &arg.ident.span(),
                 "Argument not used in #[http(...)] attribute",
             );
+
+            if let Some(request) = request.as_ref().filter(|_| request_bound) {
+                diag.info(
+                    request.ident.span(),
+                    "HINT: the body of the request must be the first argument, but this argument \
+                     is already bound",
+                );
+            }
         }
 
         if diag.has_errors() {
@@ -194,6 +297,30 @@ where
         }
     }
 
+    if let (Some(_), Some(request)) = (http.stream, request.as_ref()) {
+        if request.channel.is_streaming() {
+            diag.err(
+                request.channel.span(),
+                "streaming requests can't be sent over HTTP",
+            );
+            return Err(());
+        }
+    }
+
+    if let Some(content_type) = content_type {
+        let request = match *request {
+            Some(ref request) => request,
+            None => {
+                diag.err(content_type.span(), "endpoint has no request body");
+                return Err(());
+            }
+        };
+
+        http_verify_body(diag, &content_type, &request.channel, true)?;
+
+        http.content_type = Spanned::take(content_type);
+    }
+
     check_selection!(diag, selection);
     return Ok(http);
 
@@ -211,18 +338,187 @@ where
 
         let path = path.as_string().with_span(diag, span)?;
 
-        let path = match path_parser::parse(path) {
-            Ok(path) => path,
+        let spec = match path_parser::parse(path) {
+            Ok(spec) => spec,
             Err(e) => {
                 diag.err(span, format!("bad path: {}", e.display()));
                 return Err(());
             }
         };
 
-        let path = (span, args, path).into_model(diag, scope)?;
+        let path = (Spanned::new(path, span), args, spec).into_model(diag, scope)?;
         Ok(path)
     }
 
+    /// Parse query parameters or headers, either as a list of arguments like `[limit, cursor]`, or
+    /// as an object mapping names to arguments like `{"X-Request-Id": request_id}`.
+    fn parse_parameters<'a>(
+        diag: &mut Diagnostics,
+        parameters: Spanned<RpValue>,
+        arguments: &'a [RpEndpointArgument],
+        args: &mut HashMap<&'a str, &'a RpEndpointArgument>,
+    ) -> Result<Vec<RpHttpParameter>, ()> {
+        let (parameters, span) = Spanned::take_pair(parameters);
+
+        let entries = match parameters {
+            RpValue::Array(values) => values
+                .into_iter()
+                .map(|value| {
+                    let (value, span) = Spanned::take_pair(value);
+                    let name = value.as_identifier().with_span(diag, span)?.to_string();
+                    Ok((Spanned::new(name, span), Spanned::new(value, span)))
+                })
+                .collect::<Result<Vec<_>, ()>>()?,
+            RpValue::Object(entries) => entries,
+            _ => {
+                diag.err(
+                    span,
+                    "expected array like `[a, b]` or object like `{\"a\": a}`",
+                );
+                return Err(());
+            }
+        };
+
+        let mut out = Vec::new();
+        let mut seen = HashMap::new();
+
+        for (name, value) in entries {
+            let (value, span) = Spanned::take_pair(value);
+            let ident = value.as_identifier().with_span(diag, span)?;
+
+            let argument = match args.remove(ident) {
+                Some(argument) => argument,
+                None => {
+                    if arguments.iter().any(|a| a.ident() == ident) {
+                        diag.err(span, format!("argument `{}` is already used", ident));
+                    } else {
+                        diag.err(span, format!("`{}` is not an argument to endpoint", ident));
+
+                        let candidates = arguments.iter().map(|a| a.ident());
+
+                        if let Some(similar) = core::closest(ident, candidates) {
+                            diag.suggest(span, format!("did you mean `{}`?", similar), similar);
+                        }
+                    }
+
+                    return Err(());
+                }
+            };
+
+            if argument.channel.is_streaming() {
+                diag.err(span, "streaming arguments can't be sent as parameters");
+                diag.info(argument.channel.span(), "argument is streamed here");
+                return Err(());
+            }
+
+            match *argument.channel.ty() {
+                RpType::String(..)
+                | RpType::Number(..)
+                | RpType::Boolean
+                | RpType::Float
+                | RpType::Double => {}
+                _ => {
+                    diag.err(
+                        span,
+                        "only `string`, number, and `boolean` arguments can be sent as parameters",
+                    );
+                    diag.info(argument.channel.span(), "argument type specified here");
+                    return Err(());
+                }
+            }
+
+            if let Some(other) = seen.insert(Spanned::borrow(&name).clone(), name.span()) {
+                diag.err(name.span(), format!("`{}` is already present", name));
+                diag.info(other, "present here");
+                return Err(());
+            }
+
+            out.push(RpHttpParameter {
+                name,
+                argument: argument.clone(),
+            });
+        }
+
+        Ok(out)
+    }
+
+    /// Parse error responses, like `{"404": NotFound, "409": Conflict}`.
+    fn parse_errors(
+        diag: &mut Diagnostics,
+        errors: Spanned<RpValue>,
+    ) -> Result<Vec<Spanned<RpHttpError>>, ()> {
+        let (errors, span) = Spanned::take_pair(errors);
+
+        let entries = match errors {
+            RpValue::Object(entries) => entries,
+            _ => {
+                diag.err(span, "expected object like `{\"404\": NotFound}`");
+                return Err(());
+            }
+        };
+
+        let mut out = Vec::new();
+        let mut seen = HashMap::new();
+
+        for (status, value) in entries {
+            let (status, status_span) = Spanned::take_pair(status);
+
+            let status = check_status(diag, status.parse().ok(), status_span, 400..=599)?;
+
+            if let Some(other) = seen.insert(status, status_span) {
+                diag.err(
+                    status_span,
+                    format!("error for status `{}` already present", status),
+                );
+                diag.info(other, "present here");
+                return Err(());
+            }
+
+            let (value, span) = Spanned::take_pair(value);
+
+            let name = match value {
+                RpValue::Name(name) => name,
+                _ => {
+                    diag.err(span, "expected the name of a type, like `NotFound`");
+                    return Err(());
+                }
+            };
+
+            let ty = RpType::Name {
+                name,
+                arguments: Vec::new(),
+            };
+
+            out.push(Spanned::new(RpHttpError { status, ty }, span));
+        }
+
+        out.sort_by_key(|e| e.status);
+        Ok(out)
+    }
+
+    /// Check that a status code is in the given range.
+    fn check_status(
+        diag: &mut Diagnostics,
+        status: Option<u16>,
+        span: Span,
+        range: RangeInclusive<u16>,
+    ) -> Result<u16, ()> {
+        match status {
+            Some(status) if range.contains(&status) => Ok(status),
+            _ => {
+                diag.err(
+                    span,
+                    format!(
+                        "expected a status code between {} and {}",
+                        range.start(),
+                        range.end()
+                    ),
+                );
+                Err(())
+            }
+        }
+    }
+
     /// Parse a method.
     fn parse_method(diag: &mut Diagnostics, method: Spanned<RpValue>) -> Result<RpHttpMethod, ()> {
         let (method, span) = Spanned::take_pair(method);
@@ -244,36 +540,74 @@ where
         Ok(m)
     }
 
-    /// Check that accept matches response.
-    fn http_verify_accept(
+    /// Parse a media type, like `"application/json"`.
+    fn parse_media_type(
         diag: &mut Diagnostics,
-        accept: &Spanned<RpAccept>,
-        response: Option<&Spanned<RpChannel>>,
-    ) -> Result<(), ()> {
-        let response = match response {
-            Some(response) => response,
-            None => return Ok(()),
+        media_type: Spanned<RpValue>,
+    ) -> Result<Spanned<RpAccept>, ()> {
+        let (media_type, span) = Spanned::take_pair(media_type);
+
+        let media_type = media_type.as_string().with_span(diag, span)?;
+
+        let mime = match media_type.parse::<Mime>() {
+            Ok(mime) => mime,
+            Err(e) => {
+                diag.err(span, format!("bad media type: {}", e.display()));
+                return Err(());
+            }
         };
 
+        match RpAccept::from_mime(&mime) {
+            Some(accept) => Ok(Spanned::new(accept, span)),
+            None => {
+                diag.err(span, format!("unsupported media type `{}`", mime.essence()));
+                Err(())
+            }
+        }
+    }
+
+    /// Check that the type of a request (`request` is `true`) or response body can be encoded with
+    /// the given media type.
+    ///
+    /// Form bodies are further checked to be built from the fields of a `type` once all
+    /// declarations are known.
+    fn http_verify_body(
+        diag: &mut Diagnostics,
+        accept: &Spanned<RpAccept>,
+        channel: &Spanned<RpChannel>,
+        request: bool,
+    ) -> Result<(), ()> {
         let (accept, span) = Spanned::borrow_pair(&accept);
 
-        match *accept {
+        let expected = match (*accept, channel.ty()) {
             // Can handle complex data types.
-            ref accept if *accept == RpAccept::Json => return Ok(()),
-            _ => {
-                if let RpType::String(..) = *response.ty() {
-                    return Ok(());
-                }
-
-                diag.err(
-                    response.span(),
-                    "only `string` responses are supported for the given `accept`",
-                );
-
-                diag.info(span, "Specified here");
+            (RpAccept::Json, _) => return Ok(()),
+            (RpAccept::Text, &RpType::String(..)) => return Ok(()),
+            (RpAccept::Text, _) => "`string`",
+            (RpAccept::Bytes, &RpType::Bytes(..)) => return Ok(()),
+            (RpAccept::Bytes, _) => "`bytes`",
+            (RpAccept::Form, _) | (RpAccept::Multipart, _) if !request => {
+                diag.err(span, "media type is only supported for requests");
                 return Err(());
             }
-        }
+            (RpAccept::Form, &RpType::Name { .. }) => return Ok(()),
+            (RpAccept::Multipart, &RpType::Name { .. }) => return Ok(()),
+            (RpAccept::Form, _) | (RpAccept::Multipart, _) => "a `type`",
+            (RpAccept::NdJson, &RpType::Array { .. }) => return Ok(()),
+            (RpAccept::NdJson, _) => "an array",
+        };
+
+        diag.err(
+            channel.span(),
+            format!(
+                "only {} {} are supported for the given media type",
+                expected,
+                if request { "requests" } else { "responses" },
+            ),
+        );
+
+        diag.info(span, "Specified here");
+        Err(())
     }
 }
 
@@ -299,7 +633,12 @@ pub fn import(
 
 pub enum StringFormat {
     DateTime,
-    Bytes,
+    Date,
+    Time,
+    Duration,
+    Uuid,
+    Decimal,
+    Bytes(RpBytesEncoding),
 }
 
 /// `#[format(..)]` attributes on string fields.
@@ -324,7 +663,8 @@ pub fn string_format(
 
     let (format, span) = Spanned::take_pair(format);
 
-    let format = match format.into_string() {
+    // formats are written either as identifiers, like `bytes`, or as strings, like `"bytes"`.
+    let format = match format.as_identifier().or_else(|_| format.as_string()) {
         Ok(format) => format,
         Err(e) => {
             diag.err(span, e.display());
@@ -332,20 +672,198 @@ pub fn string_format(
         }
     };
 
-    let format = match format.as_str() {
+    let format = match format {
         "datetime" => StringFormat::DateTime,
-        "bytes" => StringFormat::Bytes,
+        "date" => StringFormat::Date,
+        "time" => StringFormat::Time,
+        "duration" => StringFormat::Duration,
+        "uuid" => StringFormat::Uuid,
+        "decimal" => StringFormat::Decimal,
+        "bytes" => StringFormat::Bytes(RpBytesEncoding::Base64),
         _ => {
             diag.err(span, "unexpected format");
-            diag.info(span, "HINT: expected one of `datetime` or `bytes`");
+            diag.info(
+                span,
+                "HINT: expected one of `datetime`, `date`, `time`, `duration`, `uuid`, \
+                 `decimal`, or `bytes`",
+            );
             return Err(());
         }
     };
 
+    let format = match (format, selection.take("encoding")) {
+        (StringFormat::Bytes(_), Some(encoding)) => {
+            StringFormat::Bytes(bytes_encoding(diag, encoding)?)
+        }
+        (_, Some(encoding)) => {
+            diag.err(encoding.span(), "encoding is only supported for `bytes`");
+            return Err(());
+        }
+        (format, None) => format,
+    };
+
     check_selection!(diag, selection);
     Ok(Some(Spanned::new(format, attribute_span)))
 }
 
+/// The `encoding` argument of a `#[format(bytes, ..)]` attribute.
+fn bytes_encoding(
+    diag: &mut Diagnostics,
+    encoding: Spanned<RpValue>,
+) -> Result<RpBytesEncoding, ()> {
+    let (encoding, span) = Spanned::take_pair(encoding);
+
+    let encoding = match encoding.as_string() {
+        Ok("base64") => RpBytesEncoding::Base64,
+        Ok("base64url") => RpBytesEncoding::Base64url,
+        Ok("base64url_nopad") => RpBytesEncoding::Base64urlNopad,
+        Ok("hex") => RpBytesEncoding::Hex,
+        _ => {
+            diag.err(span, "unexpected encoding");
+            diag.info(
+                span,
+                "HINT: expected one of `\"base64\"`, `\"base64url\"`, `\"base64url_nopad\"`, or \
+                 `\"hex\"`",
+            );
+            return Err(());
+        }
+    };
+
+    Ok(encoding)
+}
+
+/// `#![number_encoding(..)]` attribute, specifying the default encoding of 64-bit number fields.
+pub fn number_encoding(
+    diag: &mut Diagnostics,
+    attributes: &mut Attributes,
+) -> Result<RpNumberEncoding, ()> {
+    let selection = match attributes.take_selection("number_encoding") {
+        Some(selection) => selection,
+        None => return Ok(RpNumberEncoding::Number),
+    };
+
+    let (mut selection, span) = Spanned::take_pair(selection);
+    let encoding = encoding_word(diag, &mut selection, span)?;
+    check_selection!(diag, selection);
+    Ok(encoding)
+}
+
+/// `#[format(..)]` attributes on number fields.
+pub fn number_format(
+    diag: &mut Diagnostics,
+    attributes: &mut Attributes,
+) -> Result<Option<RpNumberEncoding>, ()> {
+    let selection = match attributes.take_selection("format") {
+        Some(selection) => selection,
+        None => return Ok(None),
+    };
+
+    let (mut selection, span) = Spanned::take_pair(selection);
+    let encoding = encoding_word(diag, &mut selection, span)?;
+    check_selection!(diag, selection);
+    Ok(Some(encoding))
+}
+
+/// Take the single `string` or `number` argument of a number encoding attribute.
+fn encoding_word(
+    diag: &mut Diagnostics,
+    selection: &mut Selection,
+    attribute_span: Span,
+) -> Result<RpNumberEncoding, ()> {
+    let (encoding, span) = match selection.take_word() {
+        Some(encoding) => Spanned::take_pair(encoding),
+        None => {
+            diag.err(attribute_span, "expected argument");
+            return Err(());
+        }
+    };
+
+    let encoding = match encoding.as_identifier().or_else(|_| encoding.as_string()) {
+        Ok("string") => RpNumberEncoding::String,
+        Ok("number") => RpNumberEncoding::Number,
+        _ => {
+            diag.err(span, "unexpected encoding");
+            diag.info(span, "HINT: expected one of `string` or `number`");
+            return Err(());
+        }
+    };
+
+    Ok(encoding)
+}
+
+/// `#[validate(min = 0, max = 100)]` attributes on number fields.
+pub fn number_validate(
+    diag: &mut Diagnostics,
+    attributes: &mut Attributes,
+    kind: RpNumberKind,
+    encoding: RpNumberEncoding,
+) -> Result<RpNumberValidate, ()> {
+    let mut out = RpNumberValidate::default();
+
+    let mut validate = match attributes.take_selection("validate") {
+        Some(validate) => validate,
+        None => return Ok(out),
+    };
+
+    out.min = bound(diag, &mut validate, kind, encoding, "min")?;
+    out.max = bound(diag, &mut validate, kind, encoding, "max")?;
+    out.exclusive_min = bound(diag, &mut validate, kind, encoding, "exclusive_min")?;
+    out.exclusive_max = bound(diag, &mut validate, kind, encoding, "exclusive_max")?;
+
+    if out.min.is_some() && out.exclusive_min.is_some() {
+        diag.err(
+            validate.span(),
+            "`min` and `exclusive_min` can't be used together",
+        );
+        return Err(());
+    }
+
+    if out.max.is_some() && out.exclusive_max.is_some() {
+        diag.err(
+            validate.span(),
+            "`max` and `exclusive_max` can't be used together",
+        );
+        return Err(());
+    }
+
+    let lower = out.min.as_ref().or(out.exclusive_min.as_ref());
+    let upper = out.max.as_ref().or(out.exclusive_max.as_ref());
+
+    if let (Some(lower), Some(upper)) = (lower, upper) {
+        let exclusive = out.exclusive_min.is_some() || out.exclusive_max.is_some();
+
+        // bounds have been checked to be whole numbers.
+        let (lower, upper) = (lower.to_bigint(), upper.to_bigint());
+
+        if lower > upper || (exclusive && lower == upper) {
+            diag.err(validate.span(), "bounds don't permit any values");
+            return Err(());
+        }
+    }
+
+    check_selection!(diag, validate);
+    return Ok(out);
+
+    /// Take a bound from the selection, which must be representable by the kind of the number.
+    fn bound(
+        diag: &mut Diagnostics,
+        validate: &mut Selection,
+        kind: RpNumberKind,
+        encoding: RpNumberEncoding,
+        key: &str,
+    ) -> Result<Option<RpNumber>, ()> {
+        let (bound, span) = match validate.take(key) {
+            Some(bound) => Spanned::take_pair(bound),
+            None => return Ok(None),
+        };
+
+        let bound = bound.as_number().with_span(diag, span)?;
+        kind.validate_number(encoding, bound)
+            .with_span(diag, span)?;
+        Ok(Some(bound.clone()))
+    }
+}
+
 /// `#[validate(pattern = "[a-z]+")]` attributes on string fields.
 pub fn string_validate(
     diag: &mut Diagnostics,
@@ -373,6 +891,41 @@ pub fn string_validate(
         out.pattern = Some(regex);
     }
 
+    out.min_length = length(diag, &mut validate, "min_length")?;
+    out.max_length = length(diag, &mut validate, "max_length")?;
+
+    if let (Some(min), Some(max)) = (out.min_length, out.max_length) {
+        if min > max {
+            diag.err(
+                validate.span(),
+                "`min_length` must not be greater than `max_length`",
+            );
+            return Err(());
+        }
+    }
+
     check_selection!(diag, validate);
-    Ok(out)
+    return Ok(out);
+
+    /// Take a length from the selection.
+    fn length(
+        diag: &mut Diagnostics,
+        validate: &mut Selection,
+        key: &str,
+    ) -> Result<Option<usize>, ()> {
+        let (length, span) = match validate.take(key) {
+            Some(length) => Spanned::take_pair(length),
+            None => return Ok(None),
+        };
+
+        let length = length.as_number().with_span(diag, span)?;
+
+        match length.to_usize() {
+            Some(value) if length.to_bigint().is_some() => Ok(Some(value)),
+            _ => {
+                diag.err(span, "expected a non-negative whole number");
+                Err(())
+            }
+        }
+    }
 }
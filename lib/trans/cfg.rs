@@ -0,0 +1,189 @@
+//! Evaluation of `#[cfg(...)]` conditions.
+//!
+//! Conditions are evaluated against the language being built for, and the set of enabled
+//! features:
+//!
+//! ```ignore
+//! #[cfg(lang = "java")]
+//! #[cfg(not(lang = "js"))]
+//! #[cfg(feature = "internal")]
+//! #[cfg(any(feature = "internal", all(lang = "rust", feature = "debug")))]
+//! ```
+
+use ast::{Attribute, AttributeItem, Value};
+use core::{Diagnostics, Span, Spanned};
+use std::collections::HashSet;
+use std::result;
+
+type Result<T> = result::Result<T, ()>;
+
+/// The configuration that `#[cfg(...)]` conditions are evaluated against.
+#[derive(Debug, Clone, Default)]
+pub struct Cfg {
+    /// Language being built for, if any.
+    lang: Option<String>,
+    /// The set of enabled features.
+    features: HashSet<String>,
+}
+
+impl Cfg {
+    /// Create a new, empty configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the language that is being built for.
+    pub fn with_lang<S: AsRef<str>>(self, lang: Option<S>) -> Self {
+        Self {
+            lang: lang.map(|l| l.as_ref().to_string()),
+            ..self
+        }
+    }
+
+    /// Enable the given features.
+    pub fn with_features<I>(mut self, features: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.features
+            .extend(features.into_iter().map(|f| f.as_ref().to_string()));
+        self
+    }
+}
+
+/// Take all `#[cfg(...)]` attributes from the given set of attributes and evaluate them.
+///
+/// Returns `true` if all conditions hold and the item they belong to should be kept.
+pub(crate) fn take<'input>(
+    diag: &mut Diagnostics,
+    cfg: &Cfg,
+    attributes: &mut Vec<Spanned<Attribute<'input>>>,
+) -> Result<bool> {
+    let mut enabled = true;
+    let mut failed = false;
+    let mut rest = Vec::new();
+
+    for attribute in attributes.drain(..) {
+        let (attr, span) = Spanned::take_pair(attribute);
+
+        match attr {
+            Attribute::List(ref key, ref items) if &**key == "cfg" => {
+                let mut items = items.iter();
+
+                let item = match (items.next(), items.next()) {
+                    (Some(item), None) => item,
+                    (None, _) => {
+                        diag.err(
+                            span,
+                            "expected a condition, like: #[cfg(feature = \"...\")]",
+                        );
+                        failed = true;
+                        continue;
+                    }
+                    (Some(_), Some(_)) => {
+                        diag.err(
+                            span,
+                            "expected a single condition, use `all(..)` or `any(..)` to combine \
+                             conditions",
+                        );
+                        failed = true;
+                        continue;
+                    }
+                };
+
+                match evaluate(diag, cfg, item) {
+                    Ok(result) => enabled = result && enabled,
+                    Err(()) => failed = true,
+                }
+            }
+            Attribute::Word(ref word) if &**word == "cfg" => {
+                diag.err(
+                    span,
+                    "expected a condition, like: #[cfg(feature = \"...\")]",
+                );
+                failed = true;
+            }
+            attr => rest.push(Spanned::new(attr, span)),
+        }
+    }
+
+    *attributes = rest;
+
+    if failed {
+        return Err(());
+    }
+
+    Ok(enabled)
+}
+
+/// Evaluate a single condition.
+fn evaluate<'input>(
+    diag: &mut Diagnostics,
+    cfg: &Cfg,
+    item: &AttributeItem<'input>,
+) -> Result<bool> {
+    match *item {
+        AttributeItem::NameValue {
+            ref name,
+            ref value,
+        } => {
+            let (value, span) = Spanned::borrow_pair(value);
+
+            let value = match *value {
+                Value::String(ref value) => value.as_str(),
+                _ => {
+                    diag.err(span, "expected string");
+                    return Err(());
+                }
+            };
+
+            match Spanned::borrow(name).as_ref() {
+                "lang" => Ok(cfg.lang.as_ref().map(|l| l == value).unwrap_or(false)),
+                "feature" => Ok(cfg.features.contains(value)),
+                _ => unsupported(diag, name.span()),
+            }
+        }
+        AttributeItem::List(ref name, ref items) => match Spanned::borrow(name).as_ref() {
+            "not" => {
+                if items.len() != 1 {
+                    diag.err(name.span(), "`not(..)` expects exactly one condition");
+                    return Err(());
+                }
+
+                Ok(!evaluate(diag, cfg, &items[0])?)
+            }
+            "all" => {
+                let mut result = true;
+
+                for item in items {
+                    result = evaluate(diag, cfg, item)? && result;
+                }
+
+                Ok(result)
+            }
+            "any" => {
+                let mut result = false;
+
+                for item in items {
+                    result = evaluate(diag, cfg, item)? || result;
+                }
+
+                Ok(result)
+            }
+            _ => unsupported(diag, name.span()),
+        },
+        AttributeItem::Word(ref word) => unsupported(diag, word.span()),
+    }
+}
+
+/// Report an unsupported condition.
+fn unsupported(diag: &mut Diagnostics, span: Span) -> Result<bool> {
+    diag.err(
+        span,
+        "unsupported condition, expected one of: `lang = \"..\"`, `feature = \"..\"`, `not(..)`, \
+         `all(..)`, or `any(..)`",
+    );
+
+    Err(())
+}
@@ -1,18 +1,22 @@
 //! IntoModel is the trait that performs AST to RpIR translation.
 
 use crate::attributes;
+use crate::cfg;
+use crate::lints::{self, LintLevel};
 use crate::scope::Scope;
 use ast::*;
 use core::errors::Error;
 use core::flavored::*;
 use core::{
-    BigInt, Diagnostics, Import, Range, RpEnabledFeature, RpNumberKind, RpNumberType,
-    RpNumberValidate, RpStringType, RpStringValidate, Span, Spanned, SymbolKind, WithSpan,
+    BigInt, Diagnostics, Import, Range, RpBytesEncoding, RpBytesType, RpEnabledFeature, RpLiteral,
+    RpNumberEncoding, RpNumberKind, RpNumberType, RpNumberValidate, RpStringType, RpStringValidate,
+    RpValidate, Span, Spanned, SymbolKind, WithSpan,
 };
 use linked_hash_map::LinkedHashMap;
 use naming::Naming;
 use std::borrow::Cow;
 use std::collections::{hash_map, BTreeSet, HashMap};
+use std::mem;
 use std::option;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
@@ -53,6 +57,25 @@ macro_rules! check_conflict {
     };
 }
 
+/// Checks that only a single unknown member is defined.
+/// This assumes that it's being called in a loop, and will continue on errors.
+/// NOTE: it is critical that `diag.has_errors()` is checked _after_ the loop.
+macro_rules! check_unknown {
+    ($diag:expr, $existing:expr, $item:expr, $what:expr) => {
+        if let Some(other) = $existing.as_ref().map(|u: &Spanned<RpUnknown>| u.span()) {
+            $diag.err(
+                Span::from(&$item),
+                concat!("only one unknown ", $what, " is permitted"),
+            );
+
+            $diag.info(other, "previously defined here");
+            continue;
+        }
+
+        $existing = Some($item);
+    };
+}
+
 /// Checks if a given field matches a sub-type tag.
 /// This assumes that it's being called in a loop, and will continue on errors.
 /// NOTE: it is critical that `diag.has_errors()` is checked _after_ the loop.
@@ -98,10 +121,13 @@ macro_rules! check_field_reserved {
 pub struct MemberConstraint<'input> {
     sub_type_strategy: Option<&'input RpSubTypeStrategy>,
     reserved: Option<&'input HashMap<String, Span>>,
+    /// If the members belong to a tuple.
+    tuple: bool,
 }
 
 #[derive(Debug)]
 pub struct SubTypeConstraint<'input> {
+    type_parameters: &'input [String],
     sub_type_strategy: &'input RpSubTypeStrategy,
     reserved: &'input HashMap<String, Span>,
     field_idents: &'input HashMap<String, Span>,
@@ -109,6 +135,12 @@ pub struct SubTypeConstraint<'input> {
     untagged: &'input mut LinkedHashMap<BTreeSet<String>, Span>,
 }
 
+/// A member of an enum or an interface, which might be marked as `#[unknown]`.
+pub enum MaybeUnknown<T> {
+    Known(T),
+    Unknown(Box<Spanned<RpUnknown>>),
+}
+
 #[derive(Debug)]
 pub struct Members {
     fields: Vec<Spanned<RpField>>,
@@ -275,22 +307,10 @@ impl<'input> IntoModel for (Option<&'input mut Attributes>, Spanned<Type<'input>
         let out = match ty {
             Double => RpType::Double,
             Float => RpType::Float,
-            Unsigned { size: 32 } => RpType::Number(RpNumberType {
-                kind: RpNumberKind::U32,
-                validate: RpNumberValidate::default(),
-            }),
-            Unsigned { size: 64 } => RpType::Number(RpNumberType {
-                kind: RpNumberKind::U64,
-                validate: RpNumberValidate::default(),
-            }),
-            Signed { size: 32 } => RpType::Number(RpNumberType {
-                kind: RpNumberKind::I32,
-                validate: RpNumberValidate::default(),
-            }),
-            Signed { size: 64 } => RpType::Number(RpNumberType {
-                kind: RpNumberKind::I64,
-                validate: RpNumberValidate::default(),
-            }),
+            Unsigned { size: 32 } => number(diag, scope, attributes, RpNumberKind::U32)?,
+            Unsigned { size: 64 } => number(diag, scope, attributes, RpNumberKind::U64)?,
+            Signed { size: 32 } => number(diag, scope, attributes, RpNumberKind::I32)?,
+            Signed { size: 64 } => number(diag, scope, attributes, RpNumberKind::I64)?,
             Boolean => RpType::Boolean,
             String => {
                 let validate = match attributes {
@@ -301,18 +321,60 @@ impl<'input> IntoModel for (Option<&'input mut Attributes>, Spanned<Type<'input>
                 RpType::String(RpStringType { validate })
             }
             DateTime => RpType::DateTime,
-            Name { name } => RpType::Name {
-                name: name.into_model(diag, scope)?,
-            },
+            Date => RpType::Date,
+            Time => RpType::Time,
+            Duration => RpType::Duration,
+            Uuid => RpType::Uuid,
+            Decimal => RpType::Decimal,
+            Name { name, arguments } => {
+                if let Some(ident) = type_parameter(&name, scope) {
+                    if !arguments.is_empty() {
+                        diag.err(span, "type parameters can't take type arguments");
+                        return Err(());
+                    }
+
+                    RpType::Parameter { ident }
+                } else {
+                    RpType::Name {
+                        name: name.into_model(diag, scope)?,
+                        arguments: arguments.into_model(diag, scope)?,
+                    }
+                }
+            }
             Array { inner } => RpType::Array {
                 inner: inner.into_model(diag, scope)?,
             },
-            Map { key, value } => RpType::Map {
-                key: key.into_model(diag, scope)?,
-                value: value.into_model(diag, scope)?,
-            },
+            Set { inner } => {
+                let span = inner.span();
+                let inner = inner.into_model(diag, scope)?;
+                key_type(diag, span, &inner, false)?;
+                RpType::Set { inner }
+            }
+            Map { key, value } => {
+                let span = key.span();
+                let key = key.into_model(diag, scope)?;
+                key_type(diag, span, &key, true)?;
+
+                RpType::Map {
+                    key,
+                    value: value.into_model(diag, scope)?,
+                }
+            }
+            Union { alternatives } => {
+                if !scope.type_parameters.is_empty() {
+                    diag.err(
+                        span,
+                        "anonymous unions can't be used in generic declarations",
+                    );
+                    return Err(());
+                }
+
+                RpType::Union {
+                    alternatives: alternatives.into_model(diag, scope)?,
+                }
+            }
             Any => RpType::Any,
-            Bytes => RpType::Bytes,
+            Bytes => RpType::Bytes(RpBytesType::default()),
             Error { .. } => {
                 diag.err(span, "expected type, like: `string`, `u32`, or `MyType`");
                 return Err(());
@@ -337,21 +399,160 @@ impl<'input> IntoModel for Decl<'input> {
         use self::Decl::*;
 
         scope.push(Spanned::take(self.name()));
+        // type parameters are not visible in nested declarations.
+        let type_parameters = mem::take(&mut scope.type_parameters);
+
+        let out = with_lints(diag, scope, self, |diag, scope, decl| {
+            if decl.comment().is_empty() {
+                let kind = match decl {
+                    Type(..) => "type",
+                    Interface(..) => "interface",
+                    Enum(..) => "enum",
+                    Tuple(..) => "tuple",
+                    Service(..) => "service",
+                    Const(..) => "const",
+                    Alias(..) => "alias",
+                };
 
-        let out = match self {
-            Type(body) => body.into_model(diag, scope).map(RpDecl::Type),
-            Interface(body) => body.into_model(diag, scope).map(RpDecl::Interface),
-            Enum(body) => body.into_model(diag, scope).map(RpDecl::Enum),
-            Tuple(body) => body.into_model(diag, scope).map(RpDecl::Tuple),
-            Service(body) => body.into_model(diag, scope).map(RpDecl::Service),
-        };
+                let (name, span) = Spanned::take_pair(decl.name());
+                let message = format!("missing documentation for {} `{}`", kind, name);
+                scope.lint(diag, &lints::MISSING_DOCS, span, message);
+            }
 
+            // services and constants are not referenced by other declarations.
+            match decl {
+                Service(..) | Const(..) => {}
+                _ => {
+                    let level = scope.lint_level(&lints::UNUSED_DECLARATION);
+
+                    if level != LintLevel::Allow {
+                        let name = scope.as_name(decl.name().span());
+                        scope.declared.push((name, level));
+                    }
+                }
+            }
+
+            match decl {
+                Type(body) => body.into_model(diag, scope).map(RpDecl::Type),
+                Interface(body) => body.into_model(diag, scope).map(RpDecl::Interface),
+                Enum(body) => body.into_model(diag, scope).map(RpDecl::Enum),
+                Tuple(body) => body.into_model(diag, scope).map(RpDecl::Tuple),
+                Service(body) => body.into_model(diag, scope).map(RpDecl::Service),
+                Const(body) => body.into_model(diag, scope).map(RpDecl::Const),
+                Alias(body) => body.into_model(diag, scope).map(RpDecl::Alias),
+            }
+        });
+
+        scope.type_parameters = type_parameters;
         scope.pop();
 
         out
     }
 }
 
+impl<'input> IntoModel for Item<'input, ConstBody<'input>> {
+    type Output = Spanned<RpConstBody>;
+
+    fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
+    where
+        I: Import,
+    {
+        let Item {
+            comment,
+            attributes,
+            item,
+        } = self;
+
+        let (item, span) = Spanned::take_pair(item);
+
+        let name = scope.as_name(item.name.span());
+
+        diag.symbol(SymbolKind::Const, &span, &name);
+
+        let mut attributes = attributes.into_model(diag, scope)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
+        check_attributes!(diag, attributes);
+
+        let ty = item.ty.into_model(diag, scope)?;
+
+        let (value, value_span) = Spanned::take_pair(item.value);
+        let value = value.into_model(diag, scope)?;
+        let value = literal(&ty, value).with_span(diag, value_span)?;
+
+        Ok(Spanned::new(
+            RpConstBody {
+                name,
+                ident: item.name.to_string(),
+                comment: Comment(&comment).into_model(diag, scope)?,
+                decls: Vec::new(),
+                decl_idents: LinkedHashMap::new(),
+                deprecated,
+                ty,
+                value,
+            },
+            span,
+        ))
+    }
+}
+
+impl<'input> IntoModel for Item<'input, AliasBody<'input>> {
+    type Output = Spanned<RpAliasBody>;
+
+    fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
+    where
+        I: Import,
+    {
+        let Item {
+            comment,
+            attributes,
+            item,
+        } = self;
+
+        let (item, span) = Spanned::take_pair(item);
+
+        let name = scope.as_name(item.name.span());
+
+        diag.symbol(SymbolKind::Alias, &span, &name);
+
+        let mut attributes = attributes.into_model(diag, scope)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
+        let newtype = attributes.take_word("newtype");
+
+        let ty_span = item.ty.span();
+
+        // only fields can encode numbers as strings, so the file default doesn't apply here.
+        let number_encoding = mem::replace(&mut scope.number_encoding, RpNumberEncoding::Number);
+        let ty = (Some(&mut attributes), item.ty).into_model(diag, scope);
+        scope.number_encoding = number_encoding;
+        let ty = ty?;
+
+        if ty.number_encoding().is_string() {
+            diag.err(ty_span, "numbers in aliases can't be encoded as strings");
+            diag.info(
+                ty_span,
+                "HINT: use `#[format(string)]` on a field of this type instead",
+            );
+            return Err(());
+        }
+
+        check_attributes!(diag, attributes);
+
+        Ok(Spanned::new(
+            RpAliasBody {
+                name,
+                ident: item.name.to_string(),
+                comment: Comment(&comment).into_model(diag, scope)?,
+                decls: Vec::new(),
+                decl_idents: LinkedHashMap::new(),
+                deprecated,
+                ty,
+                newtype,
+            },
+            span,
+        ))
+    }
+}
+
 impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
     type Output = Spanned<RpEnumBody>;
 
@@ -361,7 +562,7 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
     {
         macro_rules! variants {
             (
-                $diag:expr, $enum_type:expr, $variants:expr, $type_field:ident,
+                $diag:expr, $enum_type:expr, $variants:expr, $unknown:ident, $type_field:ident,
                 $(($ty:ident, $out:ident, $default:ident)),*
             ) => {
             match $enum_type {
@@ -374,7 +575,15 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
                     let mut default = $default::new($type_field);
 
                     for v in $variants {
-                        let v = try_loop!((v, &mut default).into_model(diag, scope));
+                        let v = match try_loop!((v, &mut default).into_model(diag, scope)) {
+                            MaybeUnknown::Known(v) => v,
+                            MaybeUnknown::Unknown(v) => {
+                                let v = *v;
+                                check_conflict!($diag, idents, v, v.ident, "variant");
+                                check_unknown!($diag, $unknown, v, "variant");
+                                continue;
+                            }
+                        };
 
                         check_conflict!($diag, idents, v, v.ident, "variant");
                         check_conflict!($diag, values, v, v.value(), "variant value");
@@ -431,16 +640,20 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
             }
         };
 
+        let mut unknown = None;
+
         let variants = variants!(
             diag,
             enum_type,
-            item.variants,
+            cfg_retain(diag, scope, item.variants)?,
+            unknown,
             ty,
             (String, String, StringDefaultVariant),
             (Number, Number, NumberDefaultVariant)
         );
 
-        let attributes = attributes.into_model(diag, scope)?;
+        let mut attributes = attributes.into_model(diag, scope)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
         check_attributes!(diag, attributes);
 
         return Ok(Spanned::new(
@@ -450,9 +663,11 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
                 comment: Comment(&comment).into_model(diag, scope)?,
                 decls: vec![],
                 decl_idents: LinkedHashMap::new(),
+                deprecated,
                 enum_type: enum_type,
                 variants: variants,
                 codes: codes,
+                unknown,
             },
             span,
         ));
@@ -490,7 +705,7 @@ impl<'input> IntoModel for Item<'input, EnumBody<'input>> {
                         .to_bigint()
                         .ok_or_else(|| "value can't be used with generator")?;
 
-                    self.state = value.clone();
+                    self.state = value.clone() + BigInt::from(1);
                 }
 
                 self.number_type.validate_number(&number)?;
@@ -542,7 +757,7 @@ impl<'input, 'a, D> IntoModel for (Item<'input, EnumVariant<'input>>, &'a mut D)
 where
     D: DefaultVariant,
 {
-    type Output = Spanned<RpVariant<D::Type>>;
+    type Output = MaybeUnknown<Spanned<RpVariant<D::Type>>>;
 
     fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
     where
@@ -562,6 +777,26 @@ where
             n.push(item.name.to_string())
         });
 
+        let mut attributes = attributes.into_model(diag, scope)?;
+
+        if attributes.take_word("unknown") {
+            check_attributes!(diag, attributes);
+
+            if let Some(argument) = item.argument {
+                diag.err(argument.span(), "unknown variants can't have a value");
+                return Err(());
+            }
+
+            return Ok(MaybeUnknown::Unknown(Box::new(Spanned::new(
+                RpUnknown {
+                    name,
+                    ident: item.name.to_string(),
+                    comment: Comment(&comment).into_model(diag, scope)?,
+                },
+                span,
+            ))));
+        }
+
         let value = if let Some(argument) = item.argument {
             let (value, span) = Spanned::take_pair(argument.into_model(diag, scope)?);
 
@@ -576,18 +811,434 @@ where
             default.next(&item).with_span(diag, span)?
         };
 
-        let attributes = attributes.into_model(diag, scope)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
         check_attributes!(diag, attributes);
 
-        Ok(Spanned::new(
+        Ok(MaybeUnknown::Known(Spanned::new(
             RpVariant {
                 name,
                 ident: Spanned::map(item.name.clone(), |s| s.to_string()),
                 comment: Comment(&comment).into_model(diag, scope)?,
+                deprecated,
                 value: value,
             },
             span,
-        ))
+        )))
+    }
+}
+
+/// Build a number type, with validation rules and encoding taken from the attributes of the
+/// field.
+///
+/// The encoding declared through `#![number_encoding(..)]` applies to 64-bit fields which don't
+/// specify one.
+fn number<I>(
+    diag: &mut Diagnostics,
+    scope: &Scope<I>,
+    attributes: Option<&mut Attributes>,
+    kind: RpNumberKind,
+) -> Result<RpType> {
+    let (validate, encoding) = match attributes {
+        Some(attributes) => {
+            let encoding = match attributes::number_format(diag, attributes)? {
+                Some(encoding) => encoding,
+                None if kind.is_64_bit() => scope.number_encoding,
+                None => RpNumberEncoding::Number,
+            };
+
+            let validate = attributes::number_validate(diag, attributes, kind, encoding)?;
+            (validate, encoding)
+        }
+        None => (RpNumberValidate::default(), RpNumberEncoding::Number),
+    };
+
+    Ok(RpType::Number(RpNumberType {
+        kind,
+        validate,
+        encoding,
+    }))
+}
+
+/// Check that the given type can be used as a map key (`map` is `true`) or a set value.
+///
+/// Named types are checked to be enums once all declarations are known.
+fn key_type(diag: &mut Diagnostics, span: Span, ty: &RpType, map: bool) -> Result<()> {
+    let what = if map { "map keys" } else { "set values" };
+
+    match *ty {
+        RpType::String(..) | RpType::Number(..) | RpType::Name { .. } => return Ok(()),
+        RpType::Parameter { .. } => {
+            diag.err(span, format!("type parameters can't be used as {}", what));
+        }
+        _ if map => {
+            diag.err(
+                span,
+                "map keys must be `string`, an integer, or an enum with string values",
+            );
+        }
+        _ => {
+            diag.err(span, "set values must be `string`, an integer, or an enum");
+        }
+    }
+
+    Err(())
+}
+
+/// Check that a literal value is compatible with the given type.
+fn literal(ty: &RpType, value: RpValue) -> result::Result<RpLiteral, Error> {
+    let literal = match *ty {
+        RpType::String(..) => RpLiteral::String(value.into_string()?),
+        RpType::Number(ref number_type) => {
+            let number = value.into_number()?;
+            number_type.validate_number(&number)?;
+            RpLiteral::Number(number)
+        }
+        RpType::Float | RpType::Double => RpLiteral::Number(value.into_number()?),
+        RpType::Boolean => match value.as_identifier() {
+            Ok("true") => RpLiteral::Boolean(true),
+            Ok("false") => RpLiteral::Boolean(false),
+            _ => return Err("expected `true` or `false`".into()),
+        },
+        ref ty => {
+            return Err(format!("literal values are not supported for type `{}`", ty).into());
+        }
+    };
+
+    Ok(literal)
+}
+
+/// Look up if the given name refers to a type parameter which is in scope.
+fn type_parameter<I>(name: &Name, scope: &Scope<I>) -> Option<String> {
+    let path = match *name {
+        Name::Absolute {
+            prefix: None,
+            ref path,
+        } if path.len() == 1 => path,
+        _ => return None,
+    };
+
+    let ident = Spanned::borrow(&path[0]).as_ref();
+
+    scope
+        .type_parameters
+        .iter()
+        .find(|p| p.as_str() == ident)
+        .cloned()
+}
+
+/// Convert the type parameters of a generic declaration.
+fn type_parameters<'input>(
+    diag: &mut Diagnostics,
+    name: &Spanned<RpName>,
+    parameters: Vec<Spanned<Cow<'input, str>>>,
+) -> Result<Vec<String>> {
+    if !parameters.is_empty() && name.path.len() > 1 {
+        diag.err(
+            name.span(),
+            "generic declarations must be declared at the top level",
+        );
+        return Err(());
+    }
+
+    let mut out = Vec::new();
+    let mut existing = HashMap::new();
+
+    for p in parameters {
+        let (p, span) = Spanned::take_pair(p);
+
+        if let Some(other) = existing.insert(p.to_string(), span) {
+            diag.err(span, format!("type parameter `{}` is already defined", p));
+            diag.info(other, "previously defined here");
+            continue;
+        }
+
+        out.push(p.to_string());
+    }
+
+    if diag.has_errors() {
+        return Err(());
+    }
+
+    Ok(out)
+}
+
+/// Generic declarations are not permitted to have inner declarations.
+fn check_generic_decls(diag: &mut Diagnostics, type_parameters: &[String], decls: &[RpDecl]) {
+    if type_parameters.is_empty() {
+        return;
+    }
+
+    for d in decls {
+        diag.err(
+            d.span(),
+            "generic declarations can't contain inner declarations",
+        );
+    }
+}
+
+/// Items which can be excluded through `#[cfg(...)]` attributes, and which can change the levels of
+/// lints through `#[allow(...)]`, `#[warn(...)]`, and `#[deny(...)]` attributes.
+trait Conditional<'input> {
+    /// Access the attributes of the item, and the span it covers.
+    fn cfg_parts(&mut self) -> Option<(&mut Vec<Spanned<Attribute<'input>>>, Span)>;
+}
+
+impl<'input, T> Conditional<'input> for Item<'input, T> {
+    fn cfg_parts(&mut self) -> Option<(&mut Vec<Spanned<Attribute<'input>>>, Span)> {
+        let span = self.item.span();
+        Some((&mut self.attributes, span))
+    }
+}
+
+impl<'input> Conditional<'input> for Decl<'input> {
+    fn cfg_parts(&mut self) -> Option<(&mut Vec<Spanned<Attribute<'input>>>, Span)> {
+        use self::Decl::*;
+
+        match *self {
+            Type(ref mut item) => item.cfg_parts(),
+            Tuple(ref mut item) => item.cfg_parts(),
+            Interface(ref mut item) => item.cfg_parts(),
+            Enum(ref mut item) => item.cfg_parts(),
+            Service(ref mut item) => item.cfg_parts(),
+            Const(ref mut item) => item.cfg_parts(),
+            Alias(ref mut item) => item.cfg_parts(),
+        }
+    }
+}
+
+impl<'input> Conditional<'input> for TypeMember<'input> {
+    fn cfg_parts(&mut self) -> Option<(&mut Vec<Spanned<Attribute<'input>>>, Span)> {
+        match *self {
+            TypeMember::Field(ref mut field) => field.cfg_parts(),
+            TypeMember::Code(_) => None,
+            TypeMember::InnerDecl(ref mut decl) => decl.cfg_parts(),
+        }
+    }
+}
+
+impl<'input> Conditional<'input> for ServiceMember<'input> {
+    fn cfg_parts(&mut self) -> Option<(&mut Vec<Spanned<Attribute<'input>>>, Span)> {
+        match *self {
+            ServiceMember::Endpoint(ref mut endpoint) => endpoint.cfg_parts(),
+            ServiceMember::InnerDecl(ref mut decl) => decl.cfg_parts(),
+        }
+    }
+}
+
+/// Strip all items which are excluded through `#[cfg(...)]`, and mark them as inactive.
+fn cfg_retain<'input, I, T>(
+    diag: &mut Diagnostics,
+    scope: &Scope<I>,
+    items: Vec<T>,
+) -> Result<Vec<T>>
+where
+    T: Conditional<'input>,
+{
+    let mut out = Vec::new();
+
+    for mut item in items {
+        if let Some((attributes, span)) = item.cfg_parts() {
+            let start = attributes
+                .first()
+                .map(|a| a.span().start)
+                .unwrap_or(span.start);
+
+            if !cfg::take(diag, &scope.cfg, attributes)? {
+                diag.inactive(Span {
+                    start,
+                    end: span.end,
+                });
+                continue;
+            }
+        }
+
+        out.push(item);
+    }
+
+    Ok(out)
+}
+
+/// Convert the given item with the levels of lints declared in its attributes in effect.
+fn with_lints<'input, I, T, O, F>(
+    diag: &mut Diagnostics,
+    scope: &mut Scope<I>,
+    mut item: T,
+    f: F,
+) -> Result<O>
+where
+    T: Conditional<'input>,
+    F: FnOnce(&mut Diagnostics, &mut Scope<I>, T) -> Result<O>,
+{
+    let levels = match item.cfg_parts() {
+        Some((attributes, _)) => lints::take(diag, attributes)?,
+        None => Vec::new(),
+    };
+
+    let marker = scope.push_lints(levels);
+    let out = f(diag, scope, item);
+    scope.restore_lints(marker);
+    out
+}
+
+/// Resolve the fields that types inherit from their parents, as in `type Order: Auditable`.
+///
+/// Parents are either declared in the same file, or in a package which has already been imported.
+/// Inherited fields are placed before the fields declared in the type itself.
+fn inherit_fields<I>(diag: &mut Diagnostics, scope: &Scope<I>, decls: &mut [RpDecl]) -> Result<()>
+where
+    I: Import,
+{
+    let mut resolved = HashMap::new();
+
+    {
+        let mut local = HashMap::new();
+
+        for named in decls.iter().flat_map(|d| d.to_named()) {
+            local.insert(named.name().path.clone(), named);
+        }
+
+        for named in decls.iter().flat_map(|d| d.to_named()) {
+            if let RpNamed::Type(body) = named {
+                flatten(diag, scope, &local, &mut resolved, &mut Vec::new(), body)?;
+            }
+        }
+    }
+
+    assign(decls.iter_mut(), &mut resolved);
+    return Ok(());
+
+    /// Flatten the fields of the given type, including all the fields of its parents.
+    fn flatten<'a, I>(
+        diag: &mut Diagnostics,
+        scope: &Scope<I>,
+        local: &HashMap<Vec<String>, RpNamed<'a>>,
+        resolved: &mut HashMap<Vec<String>, Vec<Spanned<RpField>>>,
+        visiting: &mut Vec<Vec<String>>,
+        body: &'a RpTypeBody,
+    ) -> Result<Vec<Spanned<RpField>>>
+    where
+        I: Import,
+    {
+        if body.parents.is_empty() {
+            return Ok(body.fields.clone());
+        }
+
+        if let Some(fields) = resolved.get(&body.name.path) {
+            return Ok(fields.clone());
+        }
+
+        visiting.push(body.name.path.clone());
+
+        let mut fields = Vec::new();
+
+        for parent in &body.parents {
+            let (name, span) = Spanned::borrow_pair(parent);
+
+            let parent_fields = if name.package == scope.package() {
+                if visiting.contains(&name.path) {
+                    diag.err(span, format!("`{}` can't inherit from itself", name));
+                    return Err(());
+                }
+
+                let parent = match local.get(&name.path) {
+                    Some(RpNamed::Type(parent)) => *parent,
+                    Some(_) => {
+                        diag.err(span, "fields can only be inherited from types");
+                        return Err(());
+                    }
+                    None => {
+                        diag.err(span, format!("missing declaration `{}`", name));
+                        return Err(());
+                    }
+                };
+
+                check_parent(diag, span, parent)?;
+                flatten(diag, scope, local, resolved, visiting, parent)?
+            } else {
+                let parent = match scope.lookup_decl(name) {
+                    Some(RpDecl::Type(parent)) => parent,
+                    Some(_) => {
+                        diag.err(span, "fields can only be inherited from types");
+                        return Err(());
+                    }
+                    None => {
+                        diag.err(span, format!("missing declaration `{}`", name));
+                        return Err(());
+                    }
+                };
+
+                check_parent(diag, span, parent)?;
+
+                // spans and names in other packages are meaningless here, so refer to the parent
+                // instead.
+                parent
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let mut f = Spanned::borrow(f).clone();
+                        f.inherited = None;
+                        Spanned::new(f, span)
+                    })
+                    .collect()
+            };
+
+            for field in parent_fields {
+                let (mut field, field_span) = Spanned::take_pair(field);
+
+                if field.inherited.is_none() {
+                    field.inherited = Some(name.clone());
+                }
+
+                fields.push(Spanned::new(field, field_span));
+            }
+        }
+
+        visiting.pop();
+
+        fields.extend(body.fields.iter().cloned());
+
+        let mut out = Vec::new();
+        let mut field_idents = HashMap::new();
+        let mut field_names = HashMap::new();
+
+        for field in fields {
+            check_conflict!(diag, field_idents, field, &field.ident, "field");
+            check_conflict!(diag, field_names, field, field.name(), "field with name");
+            out.push(field);
+        }
+
+        if diag.has_errors() {
+            return Err(());
+        }
+
+        resolved.insert(body.name.path.clone(), out.clone());
+        Ok(out)
+    }
+
+    /// Check that the given type can be inherited from.
+    fn check_parent(diag: &mut Diagnostics, span: Span, parent: &RpTypeBody) -> Result<()> {
+        if parent.is_generic() {
+            diag.err(span, "fields can't be inherited from generic types");
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    /// Assign the resolved fields to their types.
+    fn assign<'a>(
+        decls: impl Iterator<Item = &'a mut RpDecl>,
+        resolved: &mut HashMap<Vec<String>, Vec<Spanned<RpField>>>,
+    ) {
+        for decl in decls {
+            if let RpDecl::Type(ref mut body) = *decl {
+                if let Some(fields) = resolved.remove(&body.name.path) {
+                    body.fields = fields;
+                }
+            }
+
+            assign(decl.decls_mut(), resolved);
+        }
     }
 }
 
@@ -662,6 +1313,21 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
             diag.err(span.end(), "missing `;`");
         }
 
+        if comment.is_empty() {
+            let message = format!("missing documentation for field `{}`", item.name);
+            scope.lint(diag, &lints::MISSING_DOCS, span, message);
+        }
+
+        let snake_case = naming::to_lower_snake().convert(item.name.as_ref());
+
+        if snake_case != item.name.as_ref() {
+            let message = format!(
+                "field `{}` should have a snake case name, like `{}`",
+                item.name, snake_case
+            );
+            scope.lint(diag, &lints::NON_SNAKE_CASE_FIELD, span, message);
+        }
+
         let field_as = item.field_as.into_model(diag, scope)?;
 
         let (ident, safe_ident, field_as) = build_item_name(
@@ -674,9 +1340,33 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
 
         let mut attributes = attributes.into_model(diag, scope)?;
 
-        let ty = handle_format_attribute(diag, scope, &mut attributes, item.ty)?;
+        let (ty, bytes_encoding) = handle_format_attribute(diag, scope, &mut attributes, item.ty)?;
+
+        let mut ty = (Some(&mut attributes), ty).into_model(diag, scope)?;
+
+        if let RpType::Bytes(ref mut bytes) = ty {
+            bytes.encoding = bytes_encoding;
+        }
+
+        let default = match item.default {
+            Some(default) => {
+                let (default, default_span) = Spanned::take_pair(default);
+
+                if item.required {
+                    diag.err(
+                        default_span,
+                        "default values are only permitted on optional fields",
+                    );
+                    return Err(());
+                }
 
-        let ty = (Some(&mut attributes), ty).into_model(diag, scope)?;
+                let default = default.into_model(diag, scope)?;
+                Some(literal(&ty, default).with_span(diag, default_span)?)
+            }
+            None => None,
+        };
+
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
 
         check_attributes!(diag, attributes);
 
@@ -688,6 +1378,12 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
                 comment: Comment(&comment).into_model(diag, scope)?,
                 ty,
                 field_as,
+                default,
+                deprecated,
+                inherited: None,
+                validate: RpValidate::None,
+                number_encoding: RpNumberEncoding::Number,
+                bytes_encoding: RpBytesEncoding::Base64,
             },
             span,
         ));
@@ -697,27 +1393,43 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
             scope: &mut Scope<I>,
             attributes: &mut Attributes,
             ty: Spanned<Type<'input>>,
-        ) -> Result<Spanned<Type<'input>>>
+        ) -> Result<(Spanned<Type<'input>>, RpBytesEncoding)>
         where
             I: Import,
         {
+            // numbers take `#[format(..)]` when they are converted.
+            if let Type::Signed { .. } | Type::Unsigned { .. } = *ty {
+                return Ok((ty, RpBytesEncoding::Base64));
+            }
+
             let format = attributes::string_format(diag, attributes)?;
 
             // TODO: convert String into a richer type instead of just punting it.
             let feature = match scope.feature("format_attribute") {
                 None => {
-                    // not allowed unless feature is active.
-                    if let Some(span) = format.as_ref().map(Spanned::span) {
+                    if let Some(format) = format {
+                        let (format, span) = Spanned::take_pair(format);
+
+                        // `bytes` fields can specify their encoding without the feature.
+                        if let (Type::Bytes, attributes::StringFormat::Bytes(encoding)) =
+                            (&*ty, format)
+                        {
+                            return Ok((ty, encoding));
+                        }
+
+                        // not allowed unless feature is active.
                         diag.err(span, "attribute not supported");
                         diag.info(span, "HINT: use #![feature(format_attribute)] to enable");
                         return Err(());
                     }
 
-                    return Ok(ty);
+                    return Ok((ty, RpBytesEncoding::Base64));
                 }
                 Some(feature) => feature,
             };
 
+            let mut bytes_encoding = RpBytesEncoding::Base64;
+
             let (ty, span) = Spanned::take_pair(ty);
 
             // report error on types that should be declared using a format attribute.
@@ -731,12 +1443,29 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
                     );
                     return Err(());
                 }
-                Type::DateTime => {
+                Type::DateTime
+                | Type::Date
+                | Type::Time
+                | Type::Duration
+                | Type::Uuid
+                | Type::Decimal => {
                     scope.feature_err(diag, feature, span, "type not supported");
 
+                    let format = match ty {
+                        Type::DateTime => "datetime",
+                        Type::Date => "date",
+                        Type::Time => "time",
+                        Type::Duration => "duration",
+                        Type::Uuid => "uuid",
+                        _ => "decimal",
+                    };
+
                     diag.info(
                         span,
-                        "HINT: use #[format(\"datetime\")] attribute on a `string` field instead",
+                        format!(
+                            "HINT: use #[format(\"{}\")] attribute on a `string` field instead",
+                            format
+                        ),
                     );
                     return Err(());
                 }
@@ -744,7 +1473,15 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
                     if let Some(format) = format.map(Spanned::take) {
                         match format {
                             attributes::StringFormat::DateTime => Type::DateTime,
-                            attributes::StringFormat::Bytes => Type::Bytes,
+                            attributes::StringFormat::Date => Type::Date,
+                            attributes::StringFormat::Time => Type::Time,
+                            attributes::StringFormat::Duration => Type::Duration,
+                            attributes::StringFormat::Uuid => Type::Uuid,
+                            attributes::StringFormat::Decimal => Type::Decimal,
+                            attributes::StringFormat::Bytes(encoding) => {
+                                bytes_encoding = encoding;
+                                Type::Bytes
+                            }
                         }
                     } else {
                         Type::String
@@ -757,14 +1494,14 @@ impl<'input> IntoModel for Item<'input, Field<'input>> {
                 return Err(());
             }
 
-            Ok(Spanned::new(ty, span))
+            Ok((Spanned::new(ty, span), bytes_encoding))
         }
     }
 }
 
 /// Process use declarations found at the top of each object.
 impl<'input> IntoModel for Vec<Spanned<UseDecl<'input>>> {
-    type Output = HashMap<String, RpVersionedPackage>;
+    type Output = HashMap<String, Spanned<RpVersionedPackage>>;
 
     fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
     where
@@ -825,7 +1562,7 @@ impl<'input> IntoModel for Vec<Spanned<UseDecl<'input>>> {
                     };
 
                     match prefixes.entry(alias.to_string()) {
-                        Entry::Vacant(entry) => entry.insert(use_package.clone()),
+                        Entry::Vacant(entry) => entry.insert(Spanned::new(use_package, span)),
                         Entry::Occupied(_) => {
                             diag.err(span, format!("alias {} already in use", alias));
                             continue;
@@ -860,7 +1597,11 @@ impl<'input> IntoModel for File<'input> {
         let prefixes = self.uses.into_model(diag, scope)?;
         scope.prefixes = prefixes;
 
-        let mut attributes = self.attributes.into_model(diag, scope)?;
+        let mut attributes = self.attributes;
+        let levels = lints::take(diag, &mut attributes)?;
+        scope.push_lints(levels);
+
+        let mut attributes = attributes.into_model(diag, scope)?;
 
         let reproto = attributes::reproto(diag, &mut attributes)?;
         scope.declared_version = reproto.version;
@@ -898,6 +1639,8 @@ impl<'input> IntoModel for File<'input> {
             check_selection!(diag, endpoint_naming);
         }
 
+        scope.number_encoding = attributes::number_encoding(diag, &mut attributes)?;
+
         if let Some(field_naming) = attributes.take_selection("field_naming") {
             let (mut field_naming, span) = Spanned::take_pair(field_naming);
 
@@ -919,7 +1662,7 @@ impl<'input> IntoModel for File<'input> {
         let mut decls = Vec::new();
         let mut decl_idents = LinkedHashMap::new();
 
-        for (index, d) in self.decls.into_iter().enumerate() {
+        for (index, d) in cfg_retain(diag, scope, self.decls)?.into_iter().enumerate() {
             let d = try_loop!(d.into_model(diag, scope));
             decl_idents.insert(d.ident().to_string(), index);
             decls.push(d);
@@ -929,6 +1672,13 @@ impl<'input> IntoModel for File<'input> {
             return Err(());
         }
 
+        for (prefix, span) in scope.unused_prefixes() {
+            let message = format!("unused import `{}`", prefix);
+            scope.lint(diag, &lints::UNUSED_IMPORT, span, message);
+        }
+
+        inherit_fields(diag, scope, &mut decls)?;
+
         return Ok(RpFile {
             comment: Comment(&self.comment).into_model(diag, scope)?,
             version: scope.version().clone(),
@@ -976,6 +1726,7 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
         let mut attributes = attributes.into_model(diag, scope)?;
 
         let reserved = attributes::reserved(diag, &mut attributes)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
 
         let mut sub_type_strategy = RpSubTypeStrategy::default();
 
@@ -986,6 +1737,9 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
 
         check_attributes!(diag, attributes);
 
+        let type_parameters = type_parameters(diag, &name, item.type_parameters)?;
+        scope.type_parameters = type_parameters.clone();
+
         let Members {
             fields,
             codes,
@@ -1006,10 +1760,12 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
         let mut names = HashMap::new();
         let mut idents = HashMap::new();
         let mut sub_types = Vec::new();
+        let mut unknown = None;
         let mut untagged = LinkedHashMap::new();
 
-        for sub_type in item.sub_types {
+        for sub_type in cfg_retain(diag, scope, item.sub_types)? {
             let constraint = SubTypeConstraint {
+                type_parameters: &type_parameters,
                 sub_type_strategy: &sub_type_strategy,
                 reserved: &reserved,
                 field_idents: &field_idents,
@@ -1018,10 +1774,20 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
             };
 
             scope.push(Spanned::borrow(&sub_type.name));
-            let out = (sub_type, constraint).into_model(diag, scope);
+            let out = with_lints(diag, scope, sub_type, |diag, scope, sub_type| {
+                (sub_type, constraint).into_model(diag, scope)
+            });
             scope.pop();
 
-            let sub_type = try_loop!(out);
+            let sub_type = match try_loop!(out) {
+                MaybeUnknown::Known(sub_type) => sub_type,
+                MaybeUnknown::Unknown(sub_type) => {
+                    let sub_type = *sub_type;
+                    check_conflict!(diag, idents, sub_type, sub_type.ident, "sub-type");
+                    check_unknown!(diag, unknown, sub_type, "sub-type");
+                    continue;
+                }
+            };
 
             check_conflict!(diag, idents, sub_type, sub_type.ident, "sub-type");
             check_conflict!(diag, names, sub_type, sub_type.name(), "sub-type with name");
@@ -1029,6 +1795,8 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
             sub_types.push(sub_type);
         }
 
+        check_generic_decls(diag, &type_parameters, &decls);
+
         if diag.has_errors() {
             return Err(());
         }
@@ -1093,10 +1861,13 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
                 comment: Comment(&comment).into_model(diag, scope)?,
                 decls,
                 decl_idents,
+                deprecated,
+                type_parameters,
                 fields,
                 codes,
                 sub_types,
                 sub_type_strategy,
+                unknown,
             },
             span,
         ));
@@ -1166,6 +1937,34 @@ impl<'input> IntoModel for Item<'input, InterfaceBody<'input>> {
                             });
                         }
                     }
+                    "adjacent" => {
+                        let tag = match selection.take("tag") {
+                            Some(tag) => {
+                                let (tag, span) = Spanned::take_pair(tag);
+                                tag.as_string().with_span(diag, span)?.to_string()
+                            }
+                            None => core::DEFAULT_TAG.to_string(),
+                        };
+
+                        let (content, content_span) = match selection.take("content") {
+                            Some(content) => {
+                                let (content, span) = Spanned::take_pair(content);
+                                let content = content.as_string().with_span(diag, span)?;
+                                (content.to_string(), span)
+                            }
+                            None => (core::DEFAULT_CONTENT.to_string(), span),
+                        };
+
+                        if tag == content {
+                            diag.err(content_span, "`content` must be different from `tag`");
+                            return Err(());
+                        }
+
+                        return Ok(RpSubTypeStrategy::Adjacent { tag, content });
+                    }
+                    "external" => {
+                        return Ok(RpSubTypeStrategy::External);
+                    }
                     "untagged" => {
                         return Ok(RpSubTypeStrategy::Untagged);
                     }
@@ -1218,6 +2017,12 @@ impl<'input> IntoModel for Spanned<Name<'input>> {
                             }
                             None => {
                                 diag.err(span, format!("missing prefix `{}`", prefix.clone()));
+
+                                if let Some(similar) = scope.similar_prefix(prefix.as_ref()) {
+                                    let message = format!("did you mean `{}`?", similar);
+                                    diag.suggest(span, message, similar);
+                                }
+
                                 return Err(());
                             }
                         }
@@ -1275,10 +2080,12 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
         let mut endpoints = Vec::new();
         let mut decls = Vec::new();
 
-        for member in item.members {
+        for member in cfg_retain(diag, scope, item.members)? {
             match member {
                 ServiceMember::Endpoint(e) => {
-                    let e = try_loop!(e.into_model(diag, scope));
+                    let e = try_loop!(with_lints(diag, scope, e, |diag, scope, e| {
+                        e.into_model(diag, scope)
+                    }));
 
                     check_conflict!(diag, endpoint_idents, e, e.ident(), "endpoint");
                     check_conflict!(diag, endpoint_names, e, e.name(), "endpoint with name");
@@ -1300,6 +2107,8 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
 
         let mut attributes = attributes.into_model(diag, scope)?;
 
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
+
         let mut http = RpServiceBodyHttp::default();
 
         if let Some(selection) = attributes.take_selection("http") {
@@ -1308,6 +2117,16 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
             check_selection!(diag, selection);
         }
 
+        let websocket = match attributes.take_selection("websocket") {
+            Some(selection) => {
+                let (mut selection, span) = Spanned::take_pair(selection);
+                let websocket = websocket(diag, span, &mut selection, &endpoints)?;
+                check_selection!(diag, selection);
+                Some(websocket)
+            }
+            None => None,
+        };
+
         check_attributes!(diag, attributes);
 
         return Ok(Spanned::new(
@@ -1317,7 +2136,9 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
                 comment: Comment(&comment).into_model(diag, scope)?,
                 decls,
                 decl_idents,
+                deprecated,
                 http,
+                websocket,
                 endpoints,
             },
             span,
@@ -1336,6 +2157,45 @@ impl<'input> IntoModel for Item<'input, ServiceBody<'input>> {
 
             Ok(())
         }
+
+        fn websocket(
+            diag: &mut Diagnostics,
+            span: Span,
+            selection: &mut Selection,
+            endpoints: &[Spanned<RpEndpoint>],
+        ) -> Result<RpServiceBodyWebSocket> {
+            let path = match selection.take("path") {
+                Some(path) => {
+                    let (path, span) = Spanned::take_pair(path);
+                    let path = path.as_string().with_span(diag, span)?.to_string();
+                    Spanned::new(path, span)
+                }
+                None => {
+                    diag.err(span, "missing `path`");
+                    return Err(());
+                }
+            };
+
+            let url = match selection.take("url") {
+                Some(url) => {
+                    let (url, span) = Spanned::take_pair(url);
+                    let url = url.as_string().with_span(diag, span)?.to_string();
+                    Some(Spanned::new(url, span))
+                }
+                None => None,
+            };
+
+            if !endpoints.iter().any(|e| e.is_bidirectional()) {
+                diag.err(
+                    span,
+                    "WebSocket services need a bi-directional endpoint, like `chat(request: stream \
+                     Message) -> stream Message`",
+                );
+                return Err(());
+            }
+
+            Ok(RpServiceBodyWebSocket { path, url })
+        }
     }
 }
 
@@ -1352,6 +2212,7 @@ impl<'input> IntoModel for EndpointArgument<'input> {
         let argument = RpEndpointArgument {
             ident: Rc::new(ident),
             safe_ident: Rc::new(safe_ident),
+            required: self.required,
             channel: self.channel.into_model(diag, scope)?,
         };
 
@@ -1374,6 +2235,20 @@ impl<'input> IntoModel for Item<'input, Endpoint<'input>> {
 
         let (item, span) = Spanned::take_pair(item);
 
+        if comment.is_empty() {
+            let message = format!("missing documentation for endpoint `{}`", item.id);
+            scope.lint(diag, &lints::MISSING_DOCS, span, message);
+        }
+
+        let has_http = attributes.iter().any(|a| match *Spanned::borrow(a) {
+            Attribute::Word(ref key) | Attribute::List(ref key, _) => &**key == "http",
+        });
+
+        if !has_http {
+            let message = format!("endpoint `{}` has no `#[http(...)]` attribute", item.id);
+            scope.lint(diag, &lints::ENDPOINT_WITHOUT_HTTP, span, message);
+        }
+
         let id = item.id.into_model(diag, scope)?;
         let alias = item.alias.into_model(diag, scope)?;
 
@@ -1414,6 +2289,24 @@ impl<'input> IntoModel for Item<'input, Endpoint<'input>> {
             &arguments,
         )?;
 
+        for argument in arguments.iter().filter(|a| a.is_optional()) {
+            if !http
+                .parameters()
+                .any(|p| p.argument.ident() == argument.ident())
+            {
+                diag.err(
+                    argument.ident.span(),
+                    "optional arguments must be sent as a query parameter or header",
+                );
+            }
+        }
+
+        if diag.has_errors() {
+            return Err(());
+        }
+
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
+
         check_attributes!(diag, attributes);
 
         Ok(Spanned::new(
@@ -1422,6 +2315,7 @@ impl<'input> IntoModel for Item<'input, Endpoint<'input>> {
                 safe_ident: safe_ident,
                 name: name,
                 comment: Comment(&comment).into_model(diag, scope)?,
+                deprecated,
                 attributes: attributes,
                 arguments: arguments,
                 request: request,
@@ -1456,7 +2350,7 @@ impl<'input> IntoModel for Channel<'input> {
 }
 
 impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'input>) {
-    type Output = Spanned<RpSubType>;
+    type Output = MaybeUnknown<Spanned<RpSubType>>;
 
     fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
     where
@@ -1467,6 +2361,7 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
         let (item, constraint) = self;
 
         let SubTypeConstraint {
+            type_parameters,
             reserved: interface_reserved,
             field_idents,
             field_names,
@@ -1485,7 +2380,46 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
         let name = scope.as_name(item.name.span());
 
         let mut attributes = attributes.into_model(diag, scope)?;
+
+        if attributes.take_word("unknown") {
+            check_attributes!(diag, attributes);
+
+            if let RpSubTypeStrategy::Untagged = *sub_type_strategy {
+                diag.err(
+                    span,
+                    "unknown sub-types are not supported by the `untagged` strategy",
+                );
+                return Err(());
+            }
+
+            if let Some(alias) = item.alias {
+                diag.err(alias.span(), "unknown sub-types can't have a name");
+                return Err(());
+            }
+
+            if let Some(member) = item.members.first() {
+                let span = match *member {
+                    Field(ref field) => field.item.span(),
+                    Code(ref code) => code.span(),
+                    InnerDecl(ref decl) => decl.name().span(),
+                };
+
+                diag.err(span, "unknown sub-types can't have members");
+                return Err(());
+            }
+
+            return Ok(MaybeUnknown::Unknown(Box::new(Spanned::new(
+                RpUnknown {
+                    name,
+                    ident: item.name.to_string(),
+                    comment: Comment(&comment).into_model(diag, scope)?,
+                },
+                span,
+            ))));
+        }
+
         let reserved = attributes::reserved(diag, &mut attributes)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
         check_attributes!(diag, attributes);
 
         let mut fields = Vec::new();
@@ -1498,10 +2432,12 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
         let mut field_idents = field_idents.clone();
         let mut field_names = field_names.clone();
 
-        for member in item.members {
+        for member in cfg_retain(diag, scope, item.members)? {
             match member {
                 Field(field) => {
-                    let field = try_loop!(field.into_model(diag, scope));
+                    let field = try_loop!(with_lints(diag, scope, field, |diag, scope, field| {
+                        field.into_model(diag, scope)
+                    }));
 
                     check_conflict!(diag, field_idents, field, &field.ident, "field");
                     check_conflict!(diag, field_names, field, field.name(), "field with name");
@@ -1525,6 +2461,8 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
             }
         }
 
+        check_generic_decls(diag, type_parameters, &decls);
+
         if diag.has_errors() {
             return Err(());
         }
@@ -1548,19 +2486,21 @@ impl<'input> IntoModel for (Item<'input, SubType<'input>>, SubTypeConstraint<'in
             _ => {}
         }
 
-        return Ok(Spanned::new(
+        return Ok(MaybeUnknown::Known(Spanned::new(
             RpSubType {
                 name,
                 ident: item.name.to_string(),
                 comment: Comment(&comment).into_model(diag, scope)?,
                 decls,
                 decl_idents,
+                deprecated,
+                type_parameters: type_parameters.to_vec(),
                 fields,
                 codes,
                 sub_type_name,
             },
             span,
-        ));
+        )));
 
         /// Extract all names provided.
         fn alias_name<'input, I>(
@@ -1624,9 +2564,17 @@ impl<'input> IntoModel for Item<'input, TupleBody<'input>> {
             decls,
             decl_idents,
             ..
-        } = item.members.into_model(diag, scope)?;
+        } = {
+            let constraint = MemberConstraint {
+                tuple: true,
+                ..MemberConstraint::default()
+            };
 
-        let attributes = attributes.into_model(diag, scope)?;
+            (item.members, constraint).into_model(diag, scope)?
+        };
+
+        let mut attributes = attributes.into_model(diag, scope)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
         check_attributes!(diag, attributes);
 
         Ok(Spanned::new(
@@ -1636,6 +2584,7 @@ impl<'input> IntoModel for Item<'input, TupleBody<'input>> {
                 comment: Comment(&comment).into_model(diag, scope)?,
                 decls,
                 decl_idents,
+                deprecated,
                 fields,
                 codes,
             },
@@ -1665,9 +2614,15 @@ impl<'input> IntoModel for Item<'input, TypeBody<'input>> {
 
         let mut attributes = attributes.into_model(diag, scope)?;
         let reserved = attributes::reserved(diag, &mut attributes)?;
+        let deprecated = attributes::deprecated(diag, &mut attributes)?;
 
         check_attributes!(diag, attributes);
 
+        let type_parameters = type_parameters(diag, &name, item.type_parameters)?;
+        scope.type_parameters = type_parameters.clone();
+
+        let parents = item.parents.into_model(diag, scope)?;
+
         let Members {
             fields,
             codes,
@@ -1683,6 +2638,12 @@ impl<'input> IntoModel for Item<'input, TypeBody<'input>> {
             (item.members, constraint).into_model(diag, scope)?
         };
 
+        check_generic_decls(diag, &type_parameters, &decls);
+
+        if diag.has_errors() {
+            return Err(());
+        }
+
         Ok(Spanned::new(
             RpTypeBody {
                 name,
@@ -1690,6 +2651,9 @@ impl<'input> IntoModel for Item<'input, TypeBody<'input>> {
                 comment: Comment(&comment).into_model(diag, scope)?,
                 decls,
                 decl_idents,
+                deprecated,
+                type_parameters,
+                parents,
                 fields,
                 codes,
             },
@@ -1724,6 +2688,7 @@ impl<'input> IntoModel for (Vec<TypeMember<'input>>, MemberConstraint<'input>) {
         let MemberConstraint {
             sub_type_strategy,
             reserved,
+            tuple,
         } = constraint;
 
         let mut fields: Vec<Spanned<RpField>> = Vec::new();
@@ -1735,10 +2700,21 @@ impl<'input> IntoModel for (Vec<TypeMember<'input>>, MemberConstraint<'input>) {
         let mut field_names = HashMap::new();
         let mut idents = HashMap::new();
 
-        for member in members {
+        for member in cfg_retain(diag, scope, members)? {
             match member {
                 Field(field) => {
-                    let field = try_loop!(field.into_model(diag, scope));
+                    let field = try_loop!(with_lints(diag, scope, field, |diag, scope, field| {
+                        if tuple && field.is_optional() {
+                            let message = format!(
+                                "tuple field `{}` is optional, but tuples are encoded as arrays \
+                                 which can't omit values",
+                                field.name
+                            );
+                            scope.lint(diag, &lints::OPTIONAL_IN_TUPLE, field.item.span(), message);
+                        }
+
+                        field.into_model(diag, scope)
+                    }));
 
                     check_conflict!(diag, field_idents, field, &field.ident, "field");
                     check_conflict!(diag, field_names, field, field.name(), "field with name");
@@ -1834,6 +2810,15 @@ impl<'input> IntoModel for Value<'input> {
             Number(number) => RpValue::Number(number),
             Identifier(identifier) => RpValue::Identifier(identifier.to_string()),
             Array(inner) => RpValue::Array(inner.into_model(diag, scope)?),
+            Object(entries) => {
+                let mut out = Vec::new();
+
+                for (key, value) in entries {
+                    out.push((key, value.into_model(diag, scope)?));
+                }
+
+                RpValue::Object(out)
+            }
             Name(name) => RpValue::Name(name.into_model(diag, scope)?),
         };
 
@@ -1852,6 +2837,7 @@ impl<'input> IntoModel for Vec<Spanned<Attribute<'input>>> {
 
         let mut words = HashMap::new();
         let mut selections = HashMap::new();
+        let mut selection_names = HashMap::new();
 
         for attribute in self {
             let (attr, attr_pos) = Spanned::take_pair(attribute);
@@ -1867,10 +2853,11 @@ impl<'input> IntoModel for Vec<Spanned<Attribute<'input>>> {
                     }
                 }
                 List(key, name_values) => {
-                    let key = Spanned::take(key.into_model(diag, scope)?);
+                    let (key, key_span) = Spanned::take_pair(key.into_model(diag, scope)?);
 
                     match selections.entry(key) {
                         hash_map::Entry::Vacant(entry) => {
+                            selection_names.insert(entry.key().clone(), key_span);
                             let mut words = Vec::new();
                             let mut values = HashMap::new();
 
@@ -1885,6 +2872,13 @@ impl<'input> IntoModel for Vec<Spanned<Attribute<'input>>> {
                                         values
                                             .insert(Spanned::borrow(&name).clone(), (name, value));
                                     }
+                                    AttributeItem::List(name, _) => {
+                                        diag.err(
+                                            name.span(),
+                                            "nested attribute lists are only supported in `#[cfg]`",
+                                        );
+                                        return Err(());
+                                    }
                                 }
                             }
 
@@ -1901,59 +2895,78 @@ impl<'input> IntoModel for Vec<Spanned<Attribute<'input>>> {
             }
         }
 
-        Ok(Attributes::new(words, selections))
+        Ok(Attributes::new(words, selections, selection_names))
     }
 }
 
 #[allow(unused)]
 type Variables<'a> = HashMap<&'a str, &'a RpEndpointArgument>;
 
-impl<'input, 'a: 'input> IntoModel for (Span, &'input mut Variables<'a>, PathSpec<'input>) {
+impl<'input, 'a: 'input> IntoModel
+    for (
+        Spanned<&'input str>,
+        &'input mut Variables<'a>,
+        PathSpec<'input>,
+    )
+{
     type Output = RpPathSpec;
 
     fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
     where
         I: Import,
     {
-        let (span, vars, spec) = self;
+        let (path, vars, spec) = self;
 
         let mut out = Vec::new();
 
         for s in spec.steps {
-            out.push((span, &mut *vars, s).into_model(diag, scope)?);
+            out.push((path.clone(), &mut *vars, s).into_model(diag, scope)?);
         }
 
         Ok(RpPathSpec { steps: out })
     }
 }
 
-impl<'input, 'a: 'input> IntoModel for (Span, &'input mut Variables<'a>, PathStep<'input>) {
+impl<'input, 'a: 'input> IntoModel
+    for (
+        Spanned<&'input str>,
+        &'input mut Variables<'a>,
+        PathStep<'input>,
+    )
+{
     type Output = RpPathStep;
 
     fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
     where
         I: Import,
     {
-        let (span, vars, step) = self;
+        let (path, vars, step) = self;
 
         let mut out = Vec::new();
 
         for p in step.parts {
-            out.push((span, &mut *vars, p).into_model(diag, scope)?);
+            out.push((path.clone(), &mut *vars, p).into_model(diag, scope)?);
         }
 
         Ok(RpPathStep { parts: out })
     }
 }
 
-impl<'input, 'a: 'input> IntoModel for (Span, &'input mut Variables<'a>, PathPart<'input>) {
+impl<'input, 'a: 'input> IntoModel
+    for (
+        Spanned<&'input str>,
+        &'input mut Variables<'a>,
+        PathPart<'input>,
+    )
+{
     type Output = RpPathPart;
 
     fn into_model<I>(self, diag: &mut Diagnostics, scope: &mut Scope<I>) -> Result<Self::Output>
     where
         I: Import,
     {
-        let (span, vars, part) = self;
+        let (path, vars, part) = self;
+        let (path, span) = Spanned::take_pair(path);
 
         use self::PathPart::*;
 
@@ -1969,6 +2982,25 @@ impl<'input, 'a: 'input> IntoModel for (Span, &'input mut Variables<'a>, PathPar
                             format!("path variable `{}` is not an argument to endpoint", var),
                         );
 
+                        if let Some(similar) = core::closest(&var, vars.keys().cloned()) {
+                            let message = format!("did you mean `{}`?", similar);
+
+                            // NB: only point at the variable if the path is written exactly as it
+                            // is parsed, without any escape sequences.
+                            let literal =
+                                !path.contains(|c: char| c == '"' || c == '\\' || c.is_control());
+
+                            match path.find(&format!("{{{}}}", var)) {
+                                Some(offset) if literal => {
+                                    // skip the opening quote and curly brace.
+                                    let start = span.start + offset + 2;
+                                    let var_span = Span::from((start, start + var.len()));
+                                    diag.suggest(var_span, message, similar);
+                                }
+                                _ => diag.info(span, message),
+                            }
+                        }
+
                         return Err(());
                     }
                 };
@@ -2,7 +2,11 @@
 macro_rules! check_attributes {
     ($diag:expr, $attr:expr) => {{
         for unused in $attr.unused() {
-            $diag.err(unused, "unknown attribute");
+            $diag.err(unused.span, "unknown attribute");
+
+            if let Some((span, name)) = unused.suggestion {
+                $diag.suggest(span, format!("did you mean `{}`?", name), name);
+            }
         }
 
         if $diag.has_errors() {
@@ -15,7 +19,11 @@ macro_rules! check_attributes {
 macro_rules! check_selection {
     ($diag:expr, $sel:expr) => {{
         for unused in $sel.unused() {
-            $diag.err(unused, "unknown attribute");
+            $diag.err(unused.span, "unknown attribute");
+
+            if let Some((span, name)) = unused.suggestion {
+                $diag.suggest(span, format!("did you mean `{}`?", name), name);
+            }
         }
 
         if $diag.has_errors() {
@@ -24,12 +32,20 @@ macro_rules! check_selection {
     }};
 }
 
+mod aliases;
 mod attributes;
+mod cfg;
 mod features;
 mod into_model;
+pub mod lints;
+mod monomorphize;
 mod scope;
 pub mod session;
 pub mod translated;
+mod unions;
+mod unused;
 
+pub use self::cfg::Cfg;
+pub use self::lints::{LintLevel, Lints};
 pub use self::session::{Packages, Session};
 pub use self::translated::Translated;
@@ -0,0 +1,249 @@
+//! Declares the built-in lints, and the levels they are reported at.
+//!
+//! The level of a lint can be changed for a whole file, a declaration, or a single member through
+//! attributes:
+//!
+//! ```ignore
+//! #![deny(missing_docs)]
+//!
+//! #[allow(optional_in_tuple)]
+//! tuple Point { ... }
+//! ```
+//!
+//! The `[lints]` table of a manifest changes the default level for a whole build.
+
+use ast::{Attribute, AttributeItem, Value};
+use core::errors::Error;
+use core::{Diagnostics, Span, Spanned};
+use std::collections::HashMap;
+use std::fmt;
+use std::result;
+
+type Result<T> = result::Result<T, ()>;
+
+/// The level that a lint is reported at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// The lint is not reported.
+    Allow,
+    /// The lint is reported as a warning.
+    Warn,
+    /// The lint is reported as an error, which prevents compilation.
+    Deny,
+}
+
+impl LintLevel {
+    /// Parse a lint level, as used in attributes and manifests.
+    pub fn parse(level: &str) -> Option<LintLevel> {
+        match level {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match *self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        };
+
+        level.fmt(fmt)
+    }
+}
+
+/// A built-in lint.
+#[derive(Debug)]
+pub struct Lint {
+    /// Name of the lint.
+    pub name: &'static str,
+    /// Level the lint is reported at, unless configured otherwise.
+    pub default: LintLevel,
+    /// Short description of what the lint checks.
+    pub description: &'static str,
+}
+
+/// Declarations, fields, and endpoints without documentation.
+pub static MISSING_DOCS: Lint = Lint {
+    name: "missing_docs",
+    default: LintLevel::Allow,
+    description: "declarations, fields, and endpoints without documentation",
+};
+
+/// Fields whose names are not in snake case.
+pub static NON_SNAKE_CASE_FIELD: Lint = Lint {
+    name: "non_snake_case_field",
+    default: LintLevel::Warn,
+    description: "fields whose names are not in snake case, like `user_id`",
+};
+
+/// Optional fields in tuples.
+pub static OPTIONAL_IN_TUPLE: Lint = Lint {
+    name: "optional_in_tuple",
+    default: LintLevel::Warn,
+    description: "optional fields in tuples, which can't be omitted from the array they are \
+                  encoded as",
+};
+
+/// Endpoints without an `#[http(...)]` attribute.
+pub static ENDPOINT_WITHOUT_HTTP: Lint = Lint {
+    name: "endpoint_without_http",
+    default: LintLevel::Allow,
+    description: "endpoints without an `#[http(...)]` attribute",
+};
+
+/// Import aliases which are never referenced.
+pub static UNUSED_IMPORT: Lint = Lint {
+    name: "unused_import",
+    default: LintLevel::Warn,
+    description: "import aliases which are never referenced",
+};
+
+/// Declarations which are never referenced from any package in the build.
+pub static UNUSED_DECLARATION: Lint = Lint {
+    name: "unused_declaration",
+    default: LintLevel::Allow,
+    description: "declarations which are never referenced from any package in the build",
+};
+
+/// All built-in lints.
+static LINTS: [&Lint; 6] = [
+    &MISSING_DOCS,
+    &NON_SNAKE_CASE_FIELD,
+    &OPTIONAL_IN_TUPLE,
+    &ENDPOINT_WITHOUT_HTTP,
+    &UNUSED_IMPORT,
+    &UNUSED_DECLARATION,
+];
+
+/// Iterate over all built-in lints.
+pub fn all() -> impl Iterator<Item = &'static Lint> {
+    LINTS.iter().copied()
+}
+
+/// Lookup a built-in lint by name.
+pub fn lookup(name: &str) -> Option<&'static Lint> {
+    all().find(|lint| lint.name == name)
+}
+
+/// The levels of lints for a build, like the ones configured in the `[lints]` table of a manifest.
+#[derive(Debug, Clone, Default)]
+pub struct Lints {
+    levels: HashMap<&'static str, LintLevel>,
+}
+
+impl Lints {
+    /// Create a new configuration where all lints are reported at their default level.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the level of the lint with the given name.
+    pub fn with_level(mut self, name: &str, level: LintLevel) -> result::Result<Self, Error> {
+        let lint = lookup(name).ok_or_else(|| format!("unknown lint `{}`", name))?;
+        self.levels.insert(lint.name, level);
+        Ok(self)
+    }
+
+    /// Get the configured level of the given lint.
+    pub fn level(&self, lint: &Lint) -> LintLevel {
+        self.levels.get(lint.name).copied().unwrap_or(lint.default)
+    }
+}
+
+/// Report the given lint at the given level.
+pub(crate) fn report<S, M>(
+    diag: &mut Diagnostics,
+    lint: &Lint,
+    level: LintLevel,
+    span: S,
+    message: M,
+) where
+    S: Into<Span>,
+    M: fmt::Display,
+{
+    match level {
+        LintLevel::Allow => {}
+        LintLevel::Warn => diag.warn(span, format!("{} [{}]", message, lint.name)),
+        LintLevel::Deny => diag.err(span, format!("{} [{}]", message, lint.name)),
+    }
+}
+
+/// Take all `#[allow(...)]`, `#[warn(...)]`, and `#[deny(...)]` attributes from the given set of
+/// attributes.
+///
+/// Returns the level of each lint listed, in the order they are declared.
+pub(crate) fn take<'input>(
+    diag: &mut Diagnostics,
+    attributes: &mut Vec<Spanned<Attribute<'input>>>,
+) -> Result<Vec<(&'static Lint, LintLevel)>> {
+    let mut levels = Vec::new();
+    let mut failed = false;
+    let mut rest = Vec::new();
+
+    for attribute in attributes.drain(..) {
+        let (attr, span) = Spanned::take_pair(attribute);
+
+        let level = match attr {
+            Attribute::List(ref key, _) | Attribute::Word(ref key) => LintLevel::parse(key),
+        };
+
+        let level = match level {
+            Some(level) => level,
+            None => {
+                rest.push(Spanned::new(attr, span));
+                continue;
+            }
+        };
+
+        let items = match attr {
+            Attribute::List(_, ref items) if !items.is_empty() => items,
+            _ => {
+                diag.err(
+                    span,
+                    format!("expected a list of lints, like: #[{}(missing_docs)]", level),
+                );
+                failed = true;
+                continue;
+            }
+        };
+
+        for item in items {
+            let (name, span) = match *item {
+                AttributeItem::Word(ref word) => match *Spanned::borrow(word) {
+                    Value::Identifier(ref name) => (name, word.span()),
+                    _ => {
+                        diag.err(word.span(), "expected the name of a lint");
+                        failed = true;
+                        continue;
+                    }
+                },
+                AttributeItem::NameValue { ref name, .. } | AttributeItem::List(ref name, _) => {
+                    diag.err(name.span(), "expected the name of a lint");
+                    failed = true;
+                    continue;
+                }
+            };
+
+            match lookup(name) {
+                Some(lint) => levels.push((lint, level)),
+                None => {
+                    diag.err(span, format!("unknown lint `{}`", name));
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    *attributes = rest;
+
+    if failed {
+        return Err(());
+    }
+
+    Ok(levels)
+}
@@ -0,0 +1,500 @@
+//! Monomorphization of generic declarations.
+//!
+//! Backends which can't represent generic declarations use this to replace every reference to a
+//! generic declaration with a concrete copy of it. A reference like `Page<Entry>` causes the
+//! declaration `PageEntry` to be generated in the package where the reference is made.
+
+use core::{
+    CoreFlavor, Diagnostics, RpDecl, RpEndpoint, RpName, RpNumberKind, RpPathPart, RpReg, RpType,
+    RpVersionedPackage, Spanned,
+};
+use linked_hash_map::LinkedHashMap;
+use std::collections::{HashMap, VecDeque};
+use std::result;
+
+/// Limit on how deeply generic declarations can instantiate each other.
+const MAX_DEPTH: usize = 16;
+
+/// All generic declarations that are available.
+pub struct Generics {
+    decls: HashMap<RpName<CoreFlavor>, RpDecl<CoreFlavor>>,
+}
+
+impl Generics {
+    /// Collect all generic declarations from the given files.
+    pub fn new<'a, I>(decls: I) -> Self
+    where
+        I: IntoIterator<Item = &'a RpDecl<CoreFlavor>>,
+    {
+        let mut out = HashMap::new();
+
+        for decl in decls {
+            if is_generic(decl) {
+                let name = Spanned::borrow(decl.name()).clone().without_prefix();
+                out.insert(name, decl.clone());
+            }
+        }
+
+        Generics { decls: out }
+    }
+
+    /// Check if there are no generic declarations.
+    pub fn is_empty(&self) -> bool {
+        self.decls.is_empty()
+    }
+}
+
+/// Monomorphize all declarations of a single package.
+///
+/// Generic declarations are removed, and concrete instances of them are added as new top-level
+/// declarations. The names of all added declarations are registered in `types`.
+pub fn monomorphize(
+    diag: &mut Diagnostics,
+    generics: &Generics,
+    package: &RpVersionedPackage,
+    decls: Vec<RpDecl<CoreFlavor>>,
+    types: &mut LinkedHashMap<RpName<CoreFlavor>, Spanned<RpReg>>,
+) -> result::Result<Vec<RpDecl<CoreFlavor>>, ()> {
+    let mut decls = strip_generics(decls);
+
+    let mut m = Monomorphize {
+        generics,
+        package,
+        instances: HashMap::new(),
+        queue: VecDeque::new(),
+    };
+
+    for decl in &mut decls {
+        m.decl(diag, decl, 0)?;
+    }
+
+    while let Some((mut decl, depth)) = m.queue.pop_front() {
+        m.decl(diag, &mut decl, depth)?;
+
+        for (name, span, reg) in decl.to_reg() {
+            let key = Spanned::borrow(name).clone().without_prefix();
+
+            if types.contains_key(&key) {
+                diag.err(
+                    span,
+                    format!("`{}` conflicts with existing declaration", key),
+                );
+                continue;
+            }
+
+            types.insert(key, Spanned::new(reg, span));
+        }
+
+        decls.push(decl);
+    }
+
+    if diag.has_errors() {
+        return Err(());
+    }
+
+    Ok(decls)
+}
+
+struct Monomorphize<'a> {
+    generics: &'a Generics,
+    package: &'a RpVersionedPackage,
+    /// Instances which have been generated, and the reference they were generated for.
+    instances: HashMap<String, String>,
+    /// Instances which needs to be processed.
+    queue: VecDeque<(RpDecl<CoreFlavor>, usize)>,
+}
+
+impl<'a> Monomorphize<'a> {
+    /// Replace all generic references in the given declaration.
+    fn decl(
+        &mut self,
+        diag: &mut Diagnostics,
+        decl: &mut RpDecl<CoreFlavor>,
+        depth: usize,
+    ) -> result::Result<(), ()> {
+        match *decl {
+            RpDecl::Type(ref mut body) => {
+                for f in &mut body.fields {
+                    self.ty(diag, &mut f.ty, depth)?;
+                }
+
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Tuple(ref mut body) => {
+                for f in &mut body.fields {
+                    self.ty(diag, &mut f.ty, depth)?;
+                }
+
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Interface(ref mut body) => {
+                for f in &mut body.fields {
+                    self.ty(diag, &mut f.ty, depth)?;
+                }
+
+                for sub_type in &mut body.sub_types {
+                    for f in &mut sub_type.fields {
+                        self.ty(diag, &mut f.ty, depth)?;
+                    }
+
+                    self.decls(diag, &mut sub_type.decls, depth)?;
+                }
+
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Service(ref mut body) => {
+                for e in &mut body.endpoints {
+                    self.endpoint(diag, e, depth)?;
+                }
+
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Enum(ref mut body) => {
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Const(ref mut body) => {
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Alias(ref mut body) => {
+                self.ty(diag, &mut body.ty, depth)?;
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+            RpDecl::Union(ref mut body) => {
+                for v in &mut body.variants {
+                    self.ty(diag, &mut v.ty, depth)?;
+                }
+
+                self.decls(diag, &mut body.decls, depth)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn decls(
+        &mut self,
+        diag: &mut Diagnostics,
+        decls: &mut Vec<RpDecl<CoreFlavor>>,
+        depth: usize,
+    ) -> result::Result<(), ()> {
+        for decl in decls {
+            self.decl(diag, decl, depth)?;
+        }
+
+        Ok(())
+    }
+
+    fn endpoint(
+        &mut self,
+        diag: &mut Diagnostics,
+        e: &mut RpEndpoint<CoreFlavor>,
+        depth: usize,
+    ) -> result::Result<(), ()> {
+        let mut types = Vec::new();
+
+        types.extend(e.arguments.iter_mut().map(|a| a.channel.ty_mut()));
+        types.extend(e.request.iter_mut().map(|a| a.channel.ty_mut()));
+        types.extend(e.response.iter_mut().map(|r| r.ty_mut()));
+        types.extend(e.http.body.iter_mut().map(|a| a.channel.ty_mut()));
+        types.extend(
+            e.http
+                .query
+                .iter_mut()
+                .chain(e.http.headers.iter_mut())
+                .map(|p| p.argument.channel.ty_mut()),
+        );
+        types.extend(e.http.errors.iter_mut().map(|e| &mut e.ty));
+
+        if let Some(path) = e.http.path.as_mut() {
+            for part in path.steps.iter_mut().flat_map(|s| s.parts.iter_mut()) {
+                if let RpPathPart::Variable(ref mut var) = *part {
+                    types.push(var.channel.ty_mut());
+                }
+            }
+        }
+
+        for ty in types {
+            self.ty(diag, ty, depth)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace any references to generic declarations in the given type.
+    fn ty(
+        &mut self,
+        diag: &mut Diagnostics,
+        ty: &mut RpType<CoreFlavor>,
+        depth: usize,
+    ) -> result::Result<(), ()> {
+        let instance = match *ty {
+            RpType::Name {
+                ref name,
+                ref mut arguments,
+            } => {
+                if arguments.is_empty() {
+                    return Ok(());
+                }
+
+                for a in arguments.iter_mut() {
+                    self.ty(diag, a, depth)?;
+                }
+
+                self.instance(diag, name, arguments, depth)?
+            }
+            RpType::Array { ref mut inner } | RpType::Set { ref mut inner } => {
+                return self.ty(diag, inner, depth)
+            }
+            RpType::Map {
+                ref mut key,
+                ref mut value,
+            } => {
+                self.ty(diag, key, depth)?;
+                return self.ty(diag, value, depth);
+            }
+            RpType::Union {
+                ref mut alternatives,
+            } => {
+                for a in alternatives.iter_mut() {
+                    self.ty(diag, a, depth)?;
+                }
+
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+
+        *ty = RpType::Name {
+            name: instance,
+            arguments: Vec::new(),
+        };
+
+        Ok(())
+    }
+
+    /// Build the name of the instance of a generic declaration, and queue the instance for
+    /// generation if this is the first time it has been seen.
+    fn instance(
+        &mut self,
+        diag: &mut Diagnostics,
+        name: &Spanned<RpName<CoreFlavor>>,
+        arguments: &[RpType<CoreFlavor>],
+        depth: usize,
+    ) -> result::Result<Spanned<RpName<CoreFlavor>>, ()> {
+        let span = name.span();
+
+        let key = Spanned::borrow(name).clone().without_prefix();
+
+        let generic = match self.generics.decls.get(&key) {
+            Some(generic) => generic,
+            None => {
+                diag.err(span, format!("`{}` is not a generic declaration", name));
+                return Err(());
+            }
+        };
+
+        let mut ident = name.path.join("");
+
+        for a in arguments {
+            ident.push_str(&mangle(a));
+        }
+
+        let reference = RpType::Name {
+            name: name.clone(),
+            arguments: arguments.to_vec(),
+        }
+        .to_string();
+
+        let instance = Spanned::new(
+            RpName::new(None, self.package.clone(), vec![ident.clone()]),
+            span,
+        );
+
+        if let Some(existing) = self.instances.get(&ident) {
+            if *existing != reference {
+                diag.err(
+                    span,
+                    format!(
+                        "`{}` and `{}` would both generate the declaration `{}`",
+                        reference, existing, ident
+                    ),
+                );
+                return Err(());
+            }
+
+            return Ok(instance);
+        }
+
+        if depth >= MAX_DEPTH {
+            diag.err(
+                span,
+                format!("generic declaration `{}` is instantiated too deeply", name),
+            );
+            return Err(());
+        }
+
+        let parameters = type_parameters(generic)
+            .iter()
+            .cloned()
+            .zip(arguments.iter().cloned())
+            .collect::<HashMap<_, _>>();
+
+        let sub = Substitute {
+            parameters: &parameters,
+            prefix: name.prefix.as_ref(),
+            package: &name.package,
+        };
+
+        let decl = sub.decl(generic.clone(), &instance, ident.as_str());
+
+        self.instances.insert(ident, reference);
+        self.queue.push_back((decl, depth + 1));
+        Ok(instance)
+    }
+}
+
+/// Substitute type parameters in a generic declaration.
+struct Substitute<'a> {
+    parameters: &'a HashMap<String, RpType<CoreFlavor>>,
+    /// Prefix that the generic declaration was imported through.
+    prefix: Option<&'a Spanned<String>>,
+    /// Package of the generic declaration.
+    package: &'a RpVersionedPackage,
+}
+
+impl<'a> Substitute<'a> {
+    fn decl(
+        &self,
+        decl: RpDecl<CoreFlavor>,
+        instance: &Spanned<RpName<CoreFlavor>>,
+        ident: &str,
+    ) -> RpDecl<CoreFlavor> {
+        match decl {
+            RpDecl::Type(mut body) => {
+                body.name = Spanned::new(Spanned::borrow(instance).clone(), body.name.span());
+                body.ident = ident.to_string();
+                body.type_parameters.clear();
+
+                for f in &mut body.fields {
+                    f.ty = self.ty(f.ty.clone());
+                }
+
+                RpDecl::Type(body)
+            }
+            RpDecl::Interface(mut body) => {
+                body.name = Spanned::new(Spanned::borrow(instance).clone(), body.name.span());
+                body.ident = ident.to_string();
+                body.type_parameters.clear();
+
+                for f in &mut body.fields {
+                    f.ty = self.ty(f.ty.clone());
+                }
+
+                for sub_type in &mut body.sub_types {
+                    let name = instance.push(sub_type.ident.clone());
+                    sub_type.name = Spanned::new(name, sub_type.name.span());
+                    sub_type.type_parameters.clear();
+
+                    for f in &mut sub_type.fields {
+                        f.ty = self.ty(f.ty.clone());
+                    }
+                }
+
+                RpDecl::Interface(body)
+            }
+            decl => decl,
+        }
+    }
+
+    fn ty(&self, ty: RpType<CoreFlavor>) -> RpType<CoreFlavor> {
+        match ty {
+            RpType::Parameter { ident } => match self.parameters.get(&ident) {
+                Some(argument) => argument.clone(),
+                None => RpType::Parameter { ident },
+            },
+            RpType::Name {
+                mut name,
+                arguments,
+            } => {
+                // names local to the generic declaration must be imported through the same
+                // prefix as the generic declaration itself.
+                if name.prefix.is_none() && name.package == *self.package {
+                    name.prefix = self.prefix.cloned();
+                }
+
+                RpType::Name {
+                    name,
+                    arguments: arguments.into_iter().map(|a| self.ty(a)).collect(),
+                }
+            }
+            RpType::Array { inner } => RpType::Array {
+                inner: Box::new(self.ty(*inner)),
+            },
+            RpType::Set { inner } => RpType::Set {
+                inner: Box::new(self.ty(*inner)),
+            },
+            RpType::Map { key, value } => RpType::Map {
+                key: Box::new(self.ty(*key)),
+                value: Box::new(self.ty(*value)),
+            },
+            ty => ty,
+        }
+    }
+}
+
+/// Check if the given declaration is generic.
+fn is_generic(decl: &RpDecl<CoreFlavor>) -> bool {
+    match *decl {
+        RpDecl::Type(ref body) => body.is_generic(),
+        RpDecl::Interface(ref body) => body.is_generic(),
+        _ => false,
+    }
+}
+
+/// Type parameters of the given declaration.
+fn type_parameters(decl: &RpDecl<CoreFlavor>) -> &[String] {
+    match *decl {
+        RpDecl::Type(ref body) => &body.type_parameters,
+        RpDecl::Interface(ref body) => &body.type_parameters,
+        _ => &[],
+    }
+}
+
+/// Remove all generic declarations.
+fn strip_generics(decls: Vec<RpDecl<CoreFlavor>>) -> Vec<RpDecl<CoreFlavor>> {
+    decls.into_iter().filter(|d| !is_generic(d)).collect()
+}
+
+/// Build the identifier component for a single type argument.
+pub fn mangle(ty: &RpType<CoreFlavor>) -> String {
+    match *ty {
+        RpType::Double => "Double".to_string(),
+        RpType::Float => "Float".to_string(),
+        RpType::Number(ref number) => match number.kind {
+            RpNumberKind::U32 => "U32".to_string(),
+            RpNumberKind::U64 => "U64".to_string(),
+            RpNumberKind::I32 => "I32".to_string(),
+            RpNumberKind::I64 => "I64".to_string(),
+        },
+        RpType::Boolean => "Boolean".to_string(),
+        RpType::String(..) => "String".to_string(),
+        RpType::DateTime => "DateTime".to_string(),
+        RpType::Date => "Date".to_string(),
+        RpType::Time => "Time".to_string(),
+        RpType::Duration => "Duration".to_string(),
+        RpType::Uuid => "Uuid".to_string(),
+        RpType::Decimal => "Decimal".to_string(),
+        RpType::Bytes(..) => "Bytes".to_string(),
+        RpType::Any => "Any".to_string(),
+        RpType::Name { ref name, .. } => name.path.join(""),
+        RpType::Parameter { ref ident } => ident.to_string(),
+        RpType::Array { ref inner } => format!("{}Array", mangle(inner)),
+        RpType::Set { ref inner } => format!("{}Set", mangle(inner)),
+        RpType::Map { ref key, ref value } => format!("{}{}Map", mangle(key), mangle(value)),
+        RpType::Union { ref alternatives } => alternatives
+            .iter()
+            .map(mangle)
+            .collect::<Vec<_>>()
+            .join("Or"),
+    }
+}
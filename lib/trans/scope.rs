@@ -1,13 +1,15 @@
 //! Propagates scope-specific information to `into_model` transformations.
 
+use crate::cfg::Cfg;
 use crate::features::{Feature, Features};
+use crate::lints::{self, Lint, LintLevel, Lints};
 use core::errors::Error;
 use core::{
-    CoreFlavor, Diagnostics, Import, RpName, RpRequiredPackage, RpVersionedPackage, Span, Spanned,
-    Version,
+    CoreFlavor, Diagnostics, Import, RpDecl, RpName, RpNumberEncoding, RpRequiredPackage,
+    RpVersionedPackage, Span, Spanned, Version,
 };
 use naming::Naming;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::rc::Rc;
 
@@ -29,9 +31,27 @@ pub struct Scope<I> {
     import: I,
     pub endpoint_naming: Option<Box<dyn Naming>>,
     pub field_naming: Option<Box<dyn Naming>>,
-    pub prefixes: HashMap<String, RpVersionedPackage>,
+    /// Encoding of 64-bit number fields, unless specified by the field.
+    pub number_encoding: RpNumberEncoding,
+    /// Import aliases, and the packages they refer to.
+    ///
+    /// The span is where the alias is declared.
+    pub prefixes: HashMap<String, Spanned<RpVersionedPackage>>,
+    /// Import aliases which have been referenced.
+    used_prefixes: HashSet<String>,
+    /// Type parameters of the generic declaration being processed.
+    pub type_parameters: Vec<String>,
     /// Path of the current scope.
     path: Vec<String>,
+    /// Configuration to evaluate `#[cfg(...)]` conditions against.
+    pub cfg: Rc<Cfg>,
+    /// Levels of lints configured for the build.
+    pub lints: Rc<Lints>,
+    /// Levels of lints set through attributes, innermost last.
+    lint_levels: Vec<(&'static Lint, LintLevel)>,
+    /// Declarations to report if they are never referenced from any package in the build, and
+    /// the level to report them at.
+    pub declared: Vec<(Spanned<RpName<CoreFlavor>>, LintLevel)>,
 }
 
 impl<I> Scope<I> {
@@ -56,8 +76,15 @@ impl<I> Scope<I> {
             import,
             endpoint_naming: None,
             field_naming: None,
+            number_encoding: RpNumberEncoding::Number,
             prefixes: HashMap::new(),
+            used_prefixes: HashSet::new(),
+            type_parameters: Vec::new(),
             path: vec![],
+            cfg: Rc::new(Cfg::default()),
+            lints: Rc::new(Lints::default()),
+            lint_levels: Vec::new(),
+            declared: Vec::new(),
         }
     }
 
@@ -78,9 +105,29 @@ impl<I> Scope<I> {
         self.path.pop();
     }
 
-    /// Lookup what package a given prefix belongs to.
-    pub fn lookup_prefix(&self, prefix: &str) -> Option<RpVersionedPackage> {
-        self.prefixes.get(prefix).map(Clone::clone)
+    /// Lookup what package a given prefix belongs to, and mark the prefix as used.
+    pub fn lookup_prefix(&mut self, prefix: &str) -> Option<RpVersionedPackage> {
+        let package = Spanned::borrow(self.prefixes.get(prefix)?).clone();
+        self.used_prefixes.insert(prefix.to_string());
+        Some(package)
+    }
+
+    /// Find an import alias with a name similar to the given prefix.
+    pub fn similar_prefix(&self, prefix: &str) -> Option<&str> {
+        core::closest(prefix, self.prefixes.keys().map(String::as_str))
+    }
+
+    /// Get all import aliases which have not been referenced, in the order they are declared.
+    pub fn unused_prefixes(&self) -> Vec<(&str, Span)> {
+        let mut unused = self
+            .prefixes
+            .iter()
+            .filter(|(prefix, _)| !self.used_prefixes.contains(prefix.as_str()))
+            .map(|(prefix, package)| (prefix.as_str(), package.span()))
+            .collect::<Vec<_>>();
+
+        unused.sort_by_key(|(_, span)| *span);
+        unused
     }
 
     /// Get the package that this scope belongs to.
@@ -125,6 +172,42 @@ impl<I> Scope<I> {
         self.keywords.get(identifier).map(|s| s.as_str())
     }
 
+    /// Set the levels of lints for everything processed until they are restored.
+    ///
+    /// Returns a marker which is passed to `restore_lints` to restore the previous levels.
+    pub fn push_lints<L>(&mut self, levels: L) -> usize
+    where
+        L: IntoIterator<Item = (&'static Lint, LintLevel)>,
+    {
+        let marker = self.lint_levels.len();
+        self.lint_levels.extend(levels);
+        marker
+    }
+
+    /// Restore the levels of lints to what they were before the corresponding `push_lints`.
+    pub fn restore_lints(&mut self, marker: usize) {
+        self.lint_levels.truncate(marker);
+    }
+
+    /// Get the level that the given lint is currently reported at.
+    pub fn lint_level(&self, lint: &Lint) -> LintLevel {
+        self.lint_levels
+            .iter()
+            .rev()
+            .find(|(l, _)| l.name == lint.name)
+            .map(|(_, level)| *level)
+            .unwrap_or_else(|| self.lints.level(lint))
+    }
+
+    /// Report the given lint at its current level.
+    pub fn lint<S, M>(&self, diag: &mut Diagnostics, lint: &Lint, span: S, message: M)
+    where
+        S: Into<Span>,
+        M: fmt::Display,
+    {
+        lints::report(diag, lint, self.lint_level(lint), span, message);
+    }
+
     /// Check if a given feature is active and return its specification if it is.
     pub fn feature(&self, name: &'static str) -> Option<&Feature> {
         let feature = match self.features.get(name) {
@@ -200,6 +283,11 @@ where
     ) -> Result<Option<RpVersionedPackage>, Error> {
         self.import.import(package)
     }
+
+    /// Lookup a declaration in a package which has already been imported.
+    pub fn lookup_decl(&self, name: &RpName<CoreFlavor>) -> Option<&RpDecl<CoreFlavor>> {
+        self.import.lookup_decl(name)
+    }
 }
 
 #[cfg(test)]
@@ -1,18 +1,26 @@
+use crate::aliases;
+use crate::cfg::Cfg;
 use crate::features::Features;
 use crate::into_model::IntoModel;
+use crate::lints::{self, LintLevel, Lints};
+use crate::monomorphize;
 use crate::scope::Scope;
 use crate::translated::Translated;
+use crate::unions;
+use crate::unused;
 use core::errors::{Error, Result};
 use core::{
     translator, CoreFlavor, Diagnostics, Flavor, FlavorTranslator, Import, PackageTranslator,
-    Reporter, Resolved, Resolver, RpFile, RpName, RpPackage, RpReg, RpRequiredPackage,
-    RpVersionedPackage, Source, Spanned, Translate, Version,
+    Reporter, Resolved, Resolver, RpAccept, RpChannel, RpDecl, RpEnumType, RpFile, RpName, RpNamed,
+    RpPackage, RpReg, RpRequiredPackage, RpType, RpUnionKind, RpVersionedPackage, Source, Span,
+    Spanned, Translate, Version,
 };
 use linked_hash_map::LinkedHashMap;
 use naming::Naming;
 use parser;
 use std::cell::RefCell;
-use std::collections::{btree_map, BTreeMap, HashMap, HashSet};
+use std::collections::{btree_map, BTreeMap, HashMap, HashSet, VecDeque};
+use std::mem;
 use std::path::Path;
 use std::rc::Rc;
 use std::result;
@@ -31,12 +39,19 @@ macro_rules! try_with_diag {
                     return Err("error in environment".into());
                 }
 
+                if $diag.has_warnings() || $diag.has_inactive() {
+                    $reporter.diagnostics($diag);
+                }
+
                 ok
             }
         }
     };
 }
 
+/// Declarations to report if they are never referenced, and the level to report them at.
+type Declared<F> = Vec<(Spanned<RpName<F>>, LintLevel)>;
+
 #[derive(Clone, Debug)]
 pub struct File<F>
 where
@@ -81,6 +96,12 @@ where
     endpoint_ident_naming: Option<Box<dyn Naming>>,
     /// Hook to provide to paths that were loaded.
     path_hook: Option<Box<dyn Fn(&Path) -> Result<()>>>,
+    /// Configuration to evaluate `#[cfg(...)]` conditions against.
+    cfg: Rc<Cfg>,
+    /// Levels of lints configured for the build.
+    lints: Rc<Lints>,
+    /// Declarations in each package to report if they are never referenced.
+    declared: HashMap<RpVersionedPackage, Declared<F>>,
 }
 
 /// Environment containing all loaded declarations.
@@ -112,6 +133,9 @@ where
             field_ident_naming: None,
             endpoint_ident_naming: None,
             path_hook: None,
+            cfg: Rc::new(Cfg::default()),
+            lints: Rc::new(Lints::default()),
+            declared: HashMap::new(),
         })
     }
 
@@ -126,6 +150,22 @@ where
         }
     }
 
+    /// Build the session with the given configuration for `#[cfg(...)]` conditions.
+    pub fn with_cfg(self, cfg: Cfg) -> Self {
+        Self {
+            cfg: Rc::new(cfg),
+            ..self
+        }
+    }
+
+    /// Build the session with the given levels of lints.
+    pub fn with_lints(self, lints: Lints) -> Self {
+        Self {
+            lints: Rc::new(lints),
+            ..self
+        }
+    }
+
     /// Configure a new session on how to use safe packages or not.
     pub fn with_safe_packages(self, safe_packages: bool) -> Self {
         Self {
@@ -236,33 +276,42 @@ impl<'a> Session<'a, CoreFlavor> {
         T: FlavorTranslator<Source = CoreFlavor>,
     {
         // Report all collected errors.
-        if self.reporter.has_diagnostics() {
+        if self.reporter.has_errors() {
             return Err(Error::new("error in context"));
         }
 
+        let this = self.inline_aliases(false)?;
+
         let mut files = BTreeMap::new();
         let collected = Rc::new(RefCell::new(LinkedHashMap::new()));
 
-        for (package, file) in self.files {
+        for (package, file) in this.files {
             let package = flavor.translate_package(package)?;
 
             let file = {
                 let ctx = translator::Context {
                     from: &package,
                     flavor: &flavor,
-                    types: Rc::clone(&self.types),
+                    types: Rc::clone(&this.types),
                     decls: Some(collected.clone()),
                 };
 
                 let mut diag = Diagnostics::new(file.source.clone());
+                check_builtins(&mut diag, &flavor, &file.file)?;
 
-                match file.file.translate(&mut diag, &ctx) {
+                let file = match file.file.translate(&mut diag, &ctx) {
                     Ok(file) => file,
                     Err(e) => {
-                        self.reporter.diagnostics(diag);
+                        this.reporter.diagnostics(diag);
                         return Err(e);
                     }
+                };
+
+                if diag.has_warnings() {
+                    this.reporter.diagnostics(diag);
                 }
+
+                file
             };
 
             files.insert(package, file);
@@ -282,7 +331,7 @@ impl<'a> Session<'a, CoreFlavor> {
             let ctx = translator::Context {
                 from: &package,
                 flavor: &flavor,
-                types: Rc::clone(&self.types),
+                types: Rc::clone(&this.types),
                 decls: None,
             };
 
@@ -295,6 +344,13 @@ impl<'a> Session<'a, CoreFlavor> {
         Ok(Translated::new(decls, files))
     }
 
+    /// Iterate over all declarations in the session, including inner declarations.
+    pub fn for_each_decl(&self) -> impl Iterator<Item = &RpDecl<CoreFlavor>> + '_ {
+        self.files
+            .values()
+            .flat_map(|file| file.file.for_each_decl())
+    }
+
     /// Translation to simplified packages.
     pub fn packages(&self) -> Result<Rc<Packages>> {
         let mut queue = self
@@ -348,6 +404,105 @@ impl<'a> Session<'a, CoreFlavor> {
         }))
     }
 
+    /// Replace all references to generic declarations with concrete instances of them.
+    ///
+    /// This is used by backends which can't represent generic declarations.
+    pub fn monomorphize(mut self) -> Result<Self> {
+        let generics =
+            monomorphize::Generics::new(self.files.values().flat_map(|f| f.file.decls.iter()));
+
+        if generics.is_empty() {
+            return Ok(self);
+        }
+
+        let types = Rc::get_mut(&mut self.types)
+            .ok_or_else(|| Error::from("non-unique access to environment"))?;
+
+        for (package, file) in &mut self.files {
+            let mut diag = Diagnostics::new(file.source.clone());
+            let decls = mem::take(&mut file.file.decls);
+
+            let decls = try_with_diag!(self.reporter, diag, {
+                monomorphize::monomorphize(&mut diag, &generics, package, decls, types)
+            });
+
+            file.file.decl_idents = decls
+                .iter()
+                .enumerate()
+                .map(|(i, d)| (d.ident().to_string(), i))
+                .collect();
+            file.file.decls = decls;
+        }
+
+        Ok(self)
+    }
+
+    /// Inline all newtype aliases as the type they are an alias for.
+    ///
+    /// This is used by backends which can't generate wrapper types for newtype aliases.
+    pub fn inline_newtypes(self) -> Result<Self> {
+        self.inline_aliases(true)
+    }
+
+    /// Inline aliases, and remove their declarations.
+    ///
+    /// Transparent aliases are always inlined, newtype aliases only if `newtypes` is set.
+    fn inline_aliases(mut self, newtypes: bool) -> Result<Self> {
+        let aliases = aliases::Aliases::new(
+            self.files.values().flat_map(|f| f.file.decls.iter()),
+            newtypes,
+        );
+
+        if aliases.is_empty() {
+            return Ok(self);
+        }
+
+        for file in self.files.values_mut() {
+            let mut diag = Diagnostics::new(file.source.clone());
+            let file = &mut file.file;
+
+            try_with_diag!(self.reporter, diag, {
+                aliases::inline(&mut diag, &aliases, &mut file.decls, &mut file.decl_idents)
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Lift all anonymous unions in field types into union declarations.
+    ///
+    /// This is used by backends which need a named type to represent unions.
+    pub fn lift_unions(mut self) -> Result<Self> {
+        let mut kinds = HashMap::new();
+
+        for file in self.files.values() {
+            for decl in file.file.for_each_decl() {
+                for a in unions::alternatives(decl) {
+                    if let RpType::Name { ref name, .. } = *a {
+                        let name = Spanned::borrow(name).clone().without_prefix();
+
+                        if let Some(kind) = unions::json_kind(a, &|name| self.named(name)) {
+                            kinds.insert(name, kind);
+                        }
+                    }
+                }
+            }
+        }
+
+        let types = Rc::get_mut(&mut self.types)
+            .ok_or_else(|| Error::from("non-unique access to environment"))?;
+
+        for file in self.files.values_mut() {
+            let mut diag = Diagnostics::new(file.source.clone());
+
+            try_with_diag!(self.reporter, diag, {
+                unions::lift(&mut diag, &kinds, &mut file.file.decls, types)
+            });
+        }
+
+        Ok(self)
+    }
+
     /// Translate without changing the flavor.
     pub fn translate_default(self) -> Result<Translated<CoreFlavor>> {
         self.translate(translator::CoreFlavorTranslator::<_, CoreFlavor>::new(()))
@@ -409,7 +564,37 @@ impl<'a> Session<'a, CoreFlavor> {
     }
 
     /// Verify all declarations.
+    ///
+    /// This reports declarations which are never referenced from any package in the build.
     pub fn verify(&mut self) -> Result<()> {
+        if self.declared.is_empty() {
+            return Ok(());
+        }
+
+        let references = unused::references(self.files.values().flat_map(|f| f.file.decls.iter()));
+
+        for (package, file) in &self.files {
+            let declared = match self.declared.get(package) {
+                Some(declared) => declared,
+                None => continue,
+            };
+
+            let mut diag = Diagnostics::new(file.source.clone());
+
+            try_with_diag!(self.reporter, diag, {
+                for (name, level) in declared {
+                    let (name, span) = Spanned::borrow_pair(name);
+
+                    if !references.contains(name) {
+                        let message = format!("unused declaration `{}`", name.join("::"));
+                        lints::report(&mut diag, &lints::UNUSED_DECLARATION, *level, span, message);
+                    }
+                }
+
+                Ok::<(), ()>(())
+            });
+        }
+
         Ok(())
     }
 
@@ -487,6 +672,9 @@ impl<'a> Session<'a, CoreFlavor> {
         file: ast::File,
         package: &RpVersionedPackage,
     ) -> result::Result<RpFile<CoreFlavor>, ()> {
+        let cfg = Rc::clone(&self.cfg);
+        let lints = Rc::clone(&self.lints);
+
         let mut scope = Scope::new(
             Rc::clone(&self.undeclared_version),
             Rc::clone(&self.features),
@@ -494,10 +682,20 @@ impl<'a> Session<'a, CoreFlavor> {
             self.keywords.clone(),
             self.field_ident_naming.as_ref().map(|n| n.clone_box()),
             self.endpoint_ident_naming.as_ref().map(|n| n.clone_box()),
-            self,
+            &mut *self,
         );
 
-        file.into_model(diag, &mut scope)
+        scope.cfg = Rc::clone(&cfg);
+        scope.lints = lints;
+        let file = file.into_model(diag, &mut scope);
+        let declared = mem::take(&mut scope.declared);
+        drop(scope);
+
+        if !declared.is_empty() {
+            self.declared.insert(package.clone(), declared);
+        }
+
+        file
     }
 
     /// Process a single file, populating the session.
@@ -509,7 +707,7 @@ impl<'a> Session<'a, CoreFlavor> {
     ) -> result::Result<(), ()> {
         use linked_hash_map::Entry::*;
 
-        let file = match self.files.entry(package) {
+        let file = match self.files.entry(package.clone()) {
             btree_map::Entry::Vacant(entry) => entry.insert(File {
                 file,
                 source: diag.source.clone(),
@@ -550,8 +748,384 @@ impl<'a> Session<'a, CoreFlavor> {
             return Err(());
         }
 
+        self.check_references(diag, &package);
+
+        if diag.has_errors() {
+            return Err(());
+        }
+
         Ok(())
     }
+
+    /// Check all type references in the given package.
+    ///
+    /// This makes sure that generic declarations are referenced with the correct number of type
+    /// arguments, that named map keys and set values are enums, and warns about references to
+    /// deprecated declarations in other packages.
+    fn check_references(&self, diag: &mut Diagnostics, package: &RpVersionedPackage) {
+        let file = match self.files.get(package) {
+            Some(file) => file,
+            None => return,
+        };
+
+        let mut fields = Vec::new();
+        let mut types = VecDeque::new();
+
+        for decl in file.file.for_each_decl() {
+            match *decl {
+                RpDecl::Type(ref body) => {
+                    fields.extend(body.fields.iter());
+                }
+                RpDecl::Tuple(ref body) => {
+                    fields.extend(body.fields.iter());
+                }
+                RpDecl::Interface(ref body) => {
+                    fields.extend(body.fields.iter());
+
+                    for sub_type in &body.sub_types {
+                        fields.extend(sub_type.fields.iter());
+                    }
+                }
+                RpDecl::Service(ref body) => {
+                    for e in &body.endpoints {
+                        types.extend(e.arguments.iter().map(|a| a.channel.ty()));
+                        types.extend(e.response.iter().map(|r| r.ty()));
+
+                        if let Some(request) = e.request.as_ref() {
+                            self.check_form(diag, e.http.content_type, &request.channel);
+                        }
+                    }
+                }
+                RpDecl::Alias(ref body) => {
+                    let name = Spanned::borrow(&body.name).clone().without_prefix();
+
+                    if !body.newtype && self.refers_to_alias(&name, &body.ty, &mut Vec::new()) {
+                        diag.err(body.name.span(), format!("`{}` is a recursive alias", name));
+                    }
+
+                    types.push_back(&body.ty);
+                }
+                RpDecl::Union(ref body) => {
+                    types.extend(body.variants.iter().map(|v| &v.ty));
+                }
+                RpDecl::Enum(..) | RpDecl::Const(..) => {}
+            }
+        }
+
+        for field in fields {
+            if let RpType::Union { ref alternatives } = field.ty {
+                self.check_union(diag, field.span(), alternatives);
+            }
+
+            types.push_back(&field.ty);
+        }
+
+        while let Some(ty) = types.pop_front() {
+            let (name, arguments) = match *ty {
+                RpType::Name {
+                    ref name,
+                    ref arguments,
+                } => {
+                    types.extend(arguments.iter());
+                    (name, arguments)
+                }
+                RpType::Array { ref inner } => {
+                    types.push_back(inner);
+                    continue;
+                }
+                RpType::Set { ref inner } => {
+                    self.check_key(diag, inner, false);
+                    types.push_back(inner);
+                    continue;
+                }
+                RpType::Map { ref key, ref value } => {
+                    self.check_key(diag, key, true);
+                    types.push_back(key);
+                    types.push_back(value);
+                    continue;
+                }
+                RpType::Union { ref alternatives } => {
+                    types.extend(alternatives.iter());
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let named = match self.named(name) {
+                Some(named) => named,
+                None => continue,
+            };
+
+            let (name, span) = Spanned::borrow_pair(name);
+            let expected = named.type_parameters().len();
+
+            if expected != arguments.len() {
+                diag.err(
+                    span,
+                    format!(
+                        "`{}` takes {} type argument{}, but {} {} given",
+                        name,
+                        expected,
+                        if expected == 1 { "" } else { "s" },
+                        arguments.len(),
+                        if arguments.len() == 1 { "was" } else { "were" },
+                    ),
+                );
+            }
+
+            if name.package == *package {
+                continue;
+            }
+
+            if let Some(deprecated) = named.deprecated() {
+                diag.warn(span, format!("`{}` is {}", name, deprecated));
+            }
+        }
+    }
+
+    /// Check that a form request body is a `type` with fields that can be encoded as form fields.
+    ///
+    /// Form fields are strings, numbers, or booleans. Multipart forms can also have `bytes` fields,
+    /// which are sent as files.
+    fn check_form(
+        &self,
+        diag: &mut Diagnostics,
+        content_type: RpAccept,
+        channel: &Spanned<RpChannel<CoreFlavor>>,
+    ) {
+        let multipart = match content_type {
+            RpAccept::Form => false,
+            RpAccept::Multipart => true,
+            _ => return,
+        };
+
+        let name = match *channel.ty() {
+            RpType::Name { ref name, .. } => name,
+            _ => return,
+        };
+
+        let body = match self.named(name) {
+            Some(RpNamed::Type(body)) => body,
+            Some(_) => {
+                diag.err(
+                    name.span(),
+                    format!("`{}` must be a `type` to be sent as a form", name),
+                );
+                return;
+            }
+            None => return,
+        };
+
+        for field in &body.fields {
+            match field.ty {
+                RpType::String(..)
+                | RpType::Number(..)
+                | RpType::Boolean
+                | RpType::Float
+                | RpType::Double => continue,
+                RpType::Bytes(..) if multipart => continue,
+                _ => {}
+            }
+
+            diag.err(
+                name.span(),
+                format!(
+                    "`{}` can't be sent as a form, since field `{}` is not {}",
+                    name,
+                    field.ident,
+                    if multipart {
+                        "`string`, `bytes`, a number, or `boolean`"
+                    } else {
+                        "`string`, a number, or `boolean`"
+                    }
+                ),
+            );
+
+            diag.info(field.span(), "field specified here");
+        }
+    }
+
+    /// Check that a named map key (`map` is `true`) or set value refers to an enum.
+    ///
+    /// Map keys are encoded as JSON object keys, so only enums with string values are permitted.
+    fn check_key(&self, diag: &mut Diagnostics, ty: &RpType<CoreFlavor>, map: bool) {
+        let name = match *ty {
+            RpType::Name { ref name, .. } => name,
+            _ => return,
+        };
+
+        if self.is_key(ty, map, &mut Vec::new()) {
+            return;
+        }
+
+        let (name, span) = Spanned::borrow_pair(name);
+
+        if map {
+            diag.err(
+                span,
+                format!(
+                    "`{}` can't be used as a map key, expected `string`, an integer, or an enum \
+                     with string values",
+                    name
+                ),
+            );
+        } else {
+            diag.err(
+                span,
+                format!(
+                    "`{}` can't be used as a set value, expected `string`, an integer, or an enum",
+                    name
+                ),
+            );
+        }
+    }
+
+    /// Check if the given type is permitted as a map key or set value, following transparent
+    /// aliases.
+    fn is_key(
+        &self,
+        ty: &RpType<CoreFlavor>,
+        map: bool,
+        visited: &mut Vec<RpName<CoreFlavor>>,
+    ) -> bool {
+        let name = match *ty {
+            RpType::String(..) | RpType::Number(..) => return true,
+            RpType::Name { ref name, .. } => name,
+            _ => return false,
+        };
+
+        // unresolved names are reported elsewhere.
+        let named = match self.named(name) {
+            Some(named) => named,
+            None => return true,
+        };
+
+        match named {
+            RpNamed::Enum(body) => match body.enum_type {
+                RpEnumType::String(..) => true,
+                RpEnumType::Number(..) => !map,
+            },
+            RpNamed::Alias(body) if !body.newtype => {
+                let name = Spanned::borrow(name).clone().without_prefix();
+
+                // recursive aliases are reported elsewhere.
+                if visited.contains(&name) {
+                    return true;
+                }
+
+                visited.push(name);
+                self.is_key(&body.ty, map, visited)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if the given type refers to `alias`, directly or through other transparent aliases.
+    fn refers_to_alias(
+        &self,
+        alias: &RpName<CoreFlavor>,
+        ty: &RpType<CoreFlavor>,
+        visited: &mut Vec<RpName<CoreFlavor>>,
+    ) -> bool {
+        match *ty {
+            RpType::Name {
+                ref name,
+                ref arguments,
+            } => {
+                if arguments
+                    .iter()
+                    .any(|a| self.refers_to_alias(alias, a, visited))
+                {
+                    return true;
+                }
+
+                let name = Spanned::borrow(name).clone().without_prefix();
+
+                if name == *alias {
+                    return true;
+                }
+
+                if visited.contains(&name) {
+                    return false;
+                }
+
+                let file = match self.files.get(&name.package) {
+                    Some(file) => file,
+                    None => return false,
+                };
+
+                let target = file.file.for_each_decl().find_map(|d| match *d {
+                    RpDecl::Alias(ref body) if !body.newtype && body.name.path == name.path => {
+                        Some(&body.ty)
+                    }
+                    _ => None,
+                });
+
+                visited.push(name);
+
+                match target {
+                    Some(target) => self.refers_to_alias(alias, target, visited),
+                    None => false,
+                }
+            }
+            RpType::Array { ref inner } | RpType::Set { ref inner } => {
+                self.refers_to_alias(alias, inner, visited)
+            }
+            RpType::Map { ref key, ref value } => {
+                self.refers_to_alias(alias, key, visited)
+                    || self.refers_to_alias(alias, value, visited)
+            }
+            RpType::Union { ref alternatives } => alternatives
+                .iter()
+                .any(|a| self.refers_to_alias(alias, a, visited)),
+            _ => false,
+        }
+    }
+
+    /// Check that all alternatives of a union can be told apart when decoding JSON.
+    fn check_union(&self, diag: &mut Diagnostics, span: Span, alternatives: &[RpType<CoreFlavor>]) {
+        let mut seen: Vec<(RpUnionKind, &RpType<CoreFlavor>)> = Vec::new();
+
+        for a in alternatives {
+            if let RpType::Any = *a {
+                diag.err(
+                    span,
+                    "`any` can't be used in a union, since it matches every value",
+                );
+                continue;
+            }
+
+            let kind = match unions::json_kind(a, &|name| self.named(name)) {
+                Some(kind) => kind,
+                None => continue,
+            };
+
+            if let Some(&(_, other)) = seen.iter().find(|s| s.0.conflicts_with(kind)) {
+                diag.err(
+                    span,
+                    format!(
+                        "`{}` and `{}` can't be told apart, since both are decoded from {}",
+                        other,
+                        a,
+                        kind.description()
+                    ),
+                );
+                continue;
+            }
+
+            seen.push((kind, a));
+        }
+    }
+
+    /// Find the named element with the given name.
+    fn named(&self, name: &RpName<CoreFlavor>) -> Option<RpNamed<'_, CoreFlavor>> {
+        let file = self.files.get(&name.package)?;
+
+        file.file
+            .for_each_decl()
+            .flat_map(|d| d.to_named())
+            .find(|n| n.name().path == name.path)
+    }
 }
 
 impl<'e> Import for Session<'e, CoreFlavor> {
@@ -596,6 +1170,12 @@ impl<'e> Import for Session<'e, CoreFlavor> {
 
         Ok(Some(package))
     }
+
+    /// Lookup a declaration in a package which has already been loaded.
+    fn lookup_decl(&self, name: &RpName<CoreFlavor>) -> Option<&RpDecl<CoreFlavor>> {
+        let file = self.files.get(&name.package)?;
+        file.file.decl_by_path(name.path.iter().map(String::as_str))
+    }
 }
 
 /// Forward implementation for a mutable reference to the session.
@@ -603,6 +1183,10 @@ impl<'a, 'e> Import for &'a mut Session<'e, CoreFlavor> {
     fn import(&mut self, required: &RpRequiredPackage) -> Result<Option<RpVersionedPackage>> {
         (*self).import(required)
     }
+
+    fn lookup_decl(&self, name: &RpName<CoreFlavor>) -> Option<&RpDecl<CoreFlavor>> {
+        (**self).lookup_decl(name)
+    }
 }
 
 /// Package translation to use.
@@ -653,3 +1237,89 @@ impl PackageTranslator<RpVersionedPackage, RpPackage> for Packages {
         self.package(package.clone())
     }
 }
+
+/// Warn about built-in types which the target flavor doesn't support natively, and therefore
+/// represents as strings.
+fn check_builtins<T>(diag: &mut Diagnostics, flavor: &T, file: &RpFile<CoreFlavor>) -> Result<()>
+where
+    T: FlavorTranslator<Source = CoreFlavor>,
+{
+    let mut types = VecDeque::new();
+
+    for decl in file.for_each_decl() {
+        match *decl {
+            RpDecl::Type(ref body) => {
+                types.extend(body.fields.iter().map(|f| (f.span(), &f.ty)));
+            }
+            RpDecl::Tuple(ref body) => {
+                types.extend(body.fields.iter().map(|f| (f.span(), &f.ty)));
+            }
+            RpDecl::Interface(ref body) => {
+                types.extend(body.fields.iter().map(|f| (f.span(), &f.ty)));
+
+                for sub_type in &body.sub_types {
+                    types.extend(sub_type.fields.iter().map(|f| (f.span(), &f.ty)));
+                }
+            }
+            RpDecl::Service(ref body) => {
+                for e in &body.endpoints {
+                    for a in &e.arguments {
+                        types.push_back((a.channel.span(), a.channel.ty()));
+                    }
+
+                    if let Some(ref r) = e.response {
+                        types.push_back((r.span(), r.ty()));
+                    }
+                }
+            }
+            RpDecl::Alias(ref body) => {
+                types.push_back((body.name.span(), &body.ty));
+            }
+            RpDecl::Union(ref body) => {
+                let span = body.name.span();
+                types.extend(body.variants.iter().map(|v| (span, &v.ty)));
+            }
+            RpDecl::Enum(..) | RpDecl::Const(..) => {}
+        }
+    }
+
+    while let Some((span, ty)) = types.pop_front() {
+        let supported = match *ty {
+            RpType::Name { ref arguments, .. } => {
+                types.extend(arguments.iter().map(|a| (span, a)));
+                continue;
+            }
+            RpType::Array { ref inner } | RpType::Set { ref inner } => {
+                types.push_back((span, inner));
+                continue;
+            }
+            RpType::Map { ref key, ref value } => {
+                types.push_back((span, key));
+                types.push_back((span, value));
+                continue;
+            }
+            RpType::Union { ref alternatives } => {
+                types.extend(alternatives.iter().map(|a| (span, a)));
+                continue;
+            }
+            RpType::Date => flavor.translate_date()?.is_some(),
+            RpType::Time => flavor.translate_time()?.is_some(),
+            RpType::Duration => flavor.translate_duration()?.is_some(),
+            RpType::Uuid => flavor.translate_uuid()?.is_some(),
+            RpType::Decimal => flavor.translate_decimal()?.is_some(),
+            _ => continue,
+        };
+
+        if !supported {
+            diag.warn(
+                span,
+                format!(
+                    "`{}` is not supported by this backend, and is represented as a string",
+                    ty
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
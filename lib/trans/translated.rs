@@ -35,19 +35,34 @@ where
             return Ok(registered);
         }
 
-        return Err(format!("no such type: {}", name).into());
+        Err(self.no_such_type(name).into())
     }
 
     /// Lookup the declaration matching the given name.
     pub fn lookup_decl<'a>(&'a self, name: &RpName<F>) -> Result<&'a RpDecl<F>> {
         let file = match self.files.get(&name.package) {
             Some(file) => file,
-            None => return Err(format!("no such type: {}", name).into()),
+            None => return Err(self.no_such_type(name).into()),
         };
 
         match file.decl_by_path(name.path.iter().map(|s| s.as_str())) {
             Some(decl) => Ok(decl),
-            None => Err(format!("no such type: {}", name).into()),
+            None => Err(self.no_such_type(name).into()),
+        }
+    }
+
+    /// Build an error message for a type which doesn't exist, suggesting a similar type if there
+    /// is one.
+    fn no_such_type(&self, name: &RpName<F>) -> String {
+        let key = name.clone().without_prefix();
+
+        match key.closest(self.decls.keys()) {
+            Some(similar) => format!(
+                "no such type: {}, did you mean `{}`?",
+                name,
+                similar.join("::")
+            ),
+            None => format!("no such type: {}", name),
         }
     }
 
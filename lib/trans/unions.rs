@@ -0,0 +1,301 @@
+//! Lifting of anonymous unions into declarations.
+//!
+//! A field like `value: string | u64;` in `Entry` is rewritten to refer to a union declaration
+//! named `Entry::Value`, which is added as an inner declaration of `Entry`.
+
+use crate::monomorphize::mangle;
+use core::{
+    CoreFlavor, Diagnostics, RpDecl, RpEnumType, RpField, RpName, RpNamed, RpReg, RpType,
+    RpUnionBody, RpUnionKind, RpUnionVariant, Spanned,
+};
+use linked_hash_map::LinkedHashMap;
+use naming::{self, Naming};
+use std::collections::HashMap;
+use std::mem;
+use std::result;
+
+/// Get the kind of JSON value that the given type is decoded from, if it is known.
+///
+/// `lookup` is used to find the declaration of named types.
+pub fn json_kind<'a, L>(ty: &'a RpType<CoreFlavor>, lookup: &L) -> Option<RpUnionKind>
+where
+    L: Fn(&RpName<CoreFlavor>) -> Option<RpNamed<'a, CoreFlavor>>,
+{
+    let mut visited = Vec::new();
+    let mut ty = ty;
+
+    loop {
+        let name = match *ty {
+            RpType::Name { ref name, .. } => Spanned::borrow(name).clone().without_prefix(),
+            ref ty => return primitive_kind(ty),
+        };
+
+        if visited.contains(&name) {
+            return None;
+        }
+
+        let named = lookup(&name)?;
+        visited.push(name);
+
+        return match named {
+            RpNamed::Type(..) | RpNamed::Interface(..) | RpNamed::SubType(..) => {
+                Some(RpUnionKind::Object)
+            }
+            RpNamed::Tuple(..) => Some(RpUnionKind::Array),
+            RpNamed::Enum(body) => match body.enum_type {
+                RpEnumType::String(..) => Some(RpUnionKind::String),
+                RpEnumType::Number(..) => Some(RpUnionKind::Integer),
+            },
+            RpNamed::Alias(body) => {
+                ty = &body.ty;
+                continue;
+            }
+            _ => None,
+        };
+    }
+}
+
+/// Get the alternatives of all unions in the fields of the given declaration.
+///
+/// Inner declarations are not included.
+pub fn alternatives(decl: &RpDecl<CoreFlavor>) -> Vec<&RpType<CoreFlavor>> {
+    let mut fields = Vec::new();
+
+    match *decl {
+        RpDecl::Type(ref body) => fields.extend(body.fields.iter()),
+        RpDecl::Tuple(ref body) => fields.extend(body.fields.iter()),
+        RpDecl::Interface(ref body) => {
+            fields.extend(body.fields.iter());
+
+            for sub_type in &body.sub_types {
+                fields.extend(sub_type.fields.iter());
+            }
+        }
+        _ => {}
+    }
+
+    let mut out = Vec::new();
+
+    for field in fields {
+        if let RpType::Union { ref alternatives } = field.ty {
+            out.extend(alternatives.iter());
+        }
+    }
+
+    out
+}
+
+/// Get the kind of JSON value that the given type is decoded from, unless it is a named type.
+fn primitive_kind(ty: &RpType<CoreFlavor>) -> Option<RpUnionKind> {
+    match *ty {
+        RpType::String(..)
+        | RpType::DateTime
+        | RpType::Date
+        | RpType::Time
+        | RpType::Duration
+        | RpType::Uuid
+        | RpType::Decimal
+        | RpType::Bytes(..) => Some(RpUnionKind::String),
+        RpType::Number(..) => Some(RpUnionKind::Integer),
+        RpType::Float | RpType::Double => Some(RpUnionKind::Number),
+        RpType::Boolean => Some(RpUnionKind::Boolean),
+        RpType::Array { .. } | RpType::Set { .. } => Some(RpUnionKind::Array),
+        RpType::Map { .. } => Some(RpUnionKind::Object),
+        _ => None,
+    }
+}
+
+/// Lift all anonymous unions in the declarations of a single package.
+///
+/// `kinds` holds the kind of every named type used in a union. Lifted declarations are registered
+/// in `types`.
+pub fn lift(
+    diag: &mut Diagnostics,
+    kinds: &HashMap<RpName<CoreFlavor>, RpUnionKind>,
+    decls: &mut Vec<RpDecl<CoreFlavor>>,
+    types: &mut LinkedHashMap<RpName<CoreFlavor>, Spanned<RpReg>>,
+) -> result::Result<(), ()> {
+    let mut lift = Lift {
+        kinds,
+        types,
+        naming: naming::to_upper_camel(),
+    };
+
+    for decl in decls {
+        lift.decl(diag, decl)?;
+    }
+
+    Ok(())
+}
+
+struct Lift<'a> {
+    kinds: &'a HashMap<RpName<CoreFlavor>, RpUnionKind>,
+    types: &'a mut LinkedHashMap<RpName<CoreFlavor>, Spanned<RpReg>>,
+    naming: naming::ToUpperCamel,
+}
+
+impl<'a> Lift<'a> {
+    fn decl(
+        &mut self,
+        diag: &mut Diagnostics,
+        decl: &mut RpDecl<CoreFlavor>,
+    ) -> result::Result<(), ()> {
+        match *decl {
+            RpDecl::Type(ref mut body) => {
+                let body = &mut **body;
+
+                self.decls(diag, &mut body.decls)?;
+                self.fields(
+                    diag,
+                    &body.name,
+                    &mut body.fields,
+                    &mut body.decls,
+                    &mut body.decl_idents,
+                )?;
+            }
+            RpDecl::Tuple(ref mut body) => {
+                let body = &mut **body;
+
+                self.decls(diag, &mut body.decls)?;
+                self.fields(
+                    diag,
+                    &body.name,
+                    &mut body.fields,
+                    &mut body.decls,
+                    &mut body.decl_idents,
+                )?;
+            }
+            RpDecl::Interface(ref mut body) => {
+                let body = &mut **body;
+
+                for sub_type in &mut body.sub_types {
+                    let sub_type = &mut **sub_type;
+
+                    self.decls(diag, &mut sub_type.decls)?;
+                    self.fields(
+                        diag,
+                        &sub_type.name,
+                        &mut sub_type.fields,
+                        &mut sub_type.decls,
+                        &mut sub_type.decl_idents,
+                    )?;
+                }
+
+                self.decls(diag, &mut body.decls)?;
+                self.fields(
+                    diag,
+                    &body.name,
+                    &mut body.fields,
+                    &mut body.decls,
+                    &mut body.decl_idents,
+                )?;
+            }
+            RpDecl::Service(ref mut body) => self.decls(diag, &mut body.decls)?,
+            RpDecl::Enum(ref mut body) => self.decls(diag, &mut body.decls)?,
+            RpDecl::Const(ref mut body) => self.decls(diag, &mut body.decls)?,
+            RpDecl::Alias(ref mut body) => self.decls(diag, &mut body.decls)?,
+            RpDecl::Union(ref mut body) => self.decls(diag, &mut body.decls)?,
+        }
+
+        Ok(())
+    }
+
+    fn decls(
+        &mut self,
+        diag: &mut Diagnostics,
+        decls: &mut Vec<RpDecl<CoreFlavor>>,
+    ) -> result::Result<(), ()> {
+        for decl in decls {
+            self.decl(diag, decl)?;
+        }
+
+        Ok(())
+    }
+
+    /// Lift the unions of all the given fields into inner declarations of `parent`.
+    fn fields(
+        &mut self,
+        diag: &mut Diagnostics,
+        parent: &Spanned<RpName<CoreFlavor>>,
+        fields: &mut Vec<Spanned<RpField<CoreFlavor>>>,
+        decls: &mut Vec<RpDecl<CoreFlavor>>,
+        decl_idents: &mut LinkedHashMap<String, usize>,
+    ) -> result::Result<(), ()> {
+        for field in fields {
+            let span = field.span();
+            let field = &mut **field;
+
+            let alternatives = match field.ty {
+                RpType::Union {
+                    ref mut alternatives,
+                } => mem::take(alternatives),
+                _ => continue,
+            };
+
+            let ident = self.naming.convert(&field.ident);
+            let name = parent.push(ident.clone());
+
+            if decl_idents.contains_key(&ident) {
+                diag.err(
+                    span,
+                    format!(
+                        "union of field `{}` conflicts with the declaration `{}`",
+                        field.ident, name
+                    ),
+                );
+                return Err(());
+            }
+
+            let mut variants = Vec::new();
+
+            for ty in alternatives {
+                let kind = match ty {
+                    RpType::Name { ref name, .. } => {
+                        let name = Spanned::borrow(name).clone().without_prefix();
+                        self.kinds.get(&name).cloned()
+                    }
+                    ref ty => primitive_kind(ty),
+                };
+
+                let kind = match kind {
+                    Some(kind) => kind,
+                    None => {
+                        diag.err(span, format!("`{}` can't be used in a union", ty));
+                        return Err(());
+                    }
+                };
+
+                variants.push(RpUnionVariant {
+                    ident: mangle(&ty),
+                    kind,
+                    ty,
+                });
+            }
+
+            let body = RpUnionBody {
+                name: Spanned::new(name.clone(), span),
+                ident: ident.clone(),
+                comment: Vec::new(),
+                decls: Vec::new(),
+                decl_idents: LinkedHashMap::new(),
+                deprecated: None,
+                variants,
+            };
+
+            self.types.insert(
+                name.clone().without_prefix(),
+                Spanned::new(RpReg::Union, span),
+            );
+
+            decl_idents.insert(ident, decls.len());
+            decls.push(RpDecl::Union(Spanned::new(body, span)));
+
+            field.ty = RpType::Name {
+                name: Spanned::new(name, span),
+                arguments: Vec::new(),
+            };
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Finding declarations which are never referenced.
+//!
+//! A reference to an inner declaration like `Outer::Inner` also counts as a reference to `Outer`,
+//! since it can't be removed without removing `Inner`.
+
+use core::{CoreFlavor, RpDecl, RpEndpoint, RpName, RpPathPart, RpType, Spanned};
+use std::collections::HashSet;
+
+/// Collect the names of all declarations referenced by the given declarations.
+///
+/// References a declaration makes to itself are not included.
+pub fn references<'a, I>(decls: I) -> HashSet<RpName<CoreFlavor>>
+where
+    I: IntoIterator<Item = &'a RpDecl<CoreFlavor>>,
+{
+    let mut references = References {
+        names: HashSet::new(),
+    };
+
+    for decl in decls {
+        references.decl(decl);
+    }
+
+    references.names
+}
+
+struct References {
+    names: HashSet<RpName<CoreFlavor>>,
+}
+
+impl References {
+    fn decl(&mut self, decl: &RpDecl<CoreFlavor>) {
+        let current = Spanned::borrow(decl.name());
+
+        match *decl {
+            RpDecl::Type(ref body) => {
+                for parent in &body.parents {
+                    self.name(current, parent);
+                }
+
+                for f in &body.fields {
+                    self.ty(current, &f.ty);
+                }
+            }
+            RpDecl::Tuple(ref body) => {
+                for f in &body.fields {
+                    self.ty(current, &f.ty);
+                }
+            }
+            RpDecl::Interface(ref body) => {
+                for f in &body.fields {
+                    self.ty(current, &f.ty);
+                }
+
+                for sub_type in &body.sub_types {
+                    for f in &sub_type.fields {
+                        self.ty(current, &f.ty);
+                    }
+                }
+            }
+            RpDecl::Service(ref body) => {
+                for e in &body.endpoints {
+                    self.endpoint(current, e);
+                }
+            }
+            RpDecl::Const(ref body) => {
+                self.ty(current, &body.ty);
+            }
+            RpDecl::Alias(ref body) => {
+                self.ty(current, &body.ty);
+            }
+            RpDecl::Union(ref body) => {
+                for v in &body.variants {
+                    self.ty(current, &v.ty);
+                }
+            }
+            RpDecl::Enum(..) => {}
+        }
+
+        for d in decl.decls() {
+            self.decl(d);
+        }
+    }
+
+    fn endpoint(&mut self, current: &RpName<CoreFlavor>, e: &RpEndpoint<CoreFlavor>) {
+        let mut types = Vec::new();
+
+        types.extend(e.arguments.iter().map(|a| a.channel.ty()));
+        types.extend(e.request.iter().map(|a| a.channel.ty()));
+        types.extend(e.response.iter().map(|r| r.ty()));
+        types.extend(e.http.body.iter().map(|a| a.channel.ty()));
+        types.extend(e.http.parameters().map(|p| p.argument.channel.ty()));
+        types.extend(e.http.errors.iter().map(|e| &e.ty));
+
+        if let Some(path) = e.http.path.as_ref() {
+            for part in path.steps.iter().flat_map(|s| s.parts.iter()) {
+                if let RpPathPart::Variable(ref var) = *part {
+                    types.push(var.channel.ty());
+                }
+            }
+        }
+
+        for ty in types {
+            self.ty(current, ty);
+        }
+    }
+
+    fn name(&mut self, current: &RpName<CoreFlavor>, name: &RpName<CoreFlavor>) {
+        let name = name.clone().without_prefix();
+
+        if name == *current {
+            return;
+        }
+
+        // referencing an inner declaration also references all of its parents.
+        for len in 1..=name.path.len() {
+            let path = name.path[..len].to_vec();
+            self.names.insert(name.clone().with_parts(path));
+        }
+    }
+
+    fn ty(&mut self, current: &RpName<CoreFlavor>, ty: &RpType<CoreFlavor>) {
+        match *ty {
+            RpType::Name {
+                ref name,
+                ref arguments,
+            } => {
+                for a in arguments {
+                    self.ty(current, a);
+                }
+
+                self.name(current, name);
+            }
+            RpType::Array { ref inner } | RpType::Set { ref inner } => self.ty(current, inner),
+            RpType::Map { ref key, ref value } => {
+                self.ty(current, key);
+                self.ty(current, value);
+            }
+            RpType::Union { ref alternatives } => {
+                for a in alternatives {
+                    self.ty(current, a);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -152,6 +152,13 @@ impl ProjectRunner {
 
         utils::copy_dir(&self.source_languages, &self.target_languages)?;
 
+        // Files provided by the suite replace the ones of the base project.
+        let suite_languages = self.suite.dir.join("languages").join(&self.language.name);
+
+        if suite_languages.is_dir() {
+            utils::copy_dir(&suite_languages, &self.target_languages)?;
+        }
+
         let name = format!("{}-{}", self.suite.name, self.instance.name);
 
         self.reproto.build(self.manifest()).await?;